@@ -0,0 +1,338 @@
+//! Baselines of known diagnostics, used to adopt Rome on an existing codebase incrementally.
+//!
+//! A baseline records the diagnostics of a set of files. Later runs only report the diagnostics
+//! that aren't part of the baseline. Diagnostics are identified by their code, message and the
+//! source text of the lines they point to rather than by their position, so that editing
+//! unrelated parts of a file doesn't turn known diagnostics into new ones.
+//!
+//! Files are identified by their path relative to the directory of the baseline file, see [file_key],
+//! so that a baseline can be shared between machines and used from any working directory.
+use rome_rowan::StableHasher;
+use rslint_errors::Diagnostic;
+use serde_json::{json, Map, Value};
+use std::{collections::BTreeMap, env, fs, path::Path};
+
+/// The version of the baseline file format
+const BASELINE_VERSION: u64 = 1;
+
+/// A diagnostic recorded in a baseline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaselineEntry {
+	pub code: Option<String>,
+	pub message: String,
+	/// The trimmed source text of the lines the diagnostic points to
+	pub context: String,
+	/// A stable hash of the code, message and context of the diagnostic
+	pub hash: String,
+}
+
+impl BaselineEntry {
+	/// Creates the entry of a diagnostic of the file with the given `source`
+	pub fn new(diagnostic: &Diagnostic, source: &str) -> Self {
+		let context = diagnostic
+			.primary
+			.as_ref()
+			.map(|primary| context_of(source, primary.span.range.clone()))
+			.unwrap_or_default();
+		let code = diagnostic.code.clone();
+		let hash = content_hash(&[
+			code.as_deref().unwrap_or_default(),
+			&diagnostic.title,
+			&context,
+		]);
+
+		BaselineEntry {
+			code,
+			message: diagnostic.title.clone(),
+			context,
+			hash,
+		}
+	}
+
+	fn to_json(&self) -> Value {
+		json!({
+			"code": self.code,
+			"message": self.message,
+			"context": self.context,
+			"hash": self.hash,
+		})
+	}
+
+	fn from_json(value: &Value) -> Option<Self> {
+		Some(BaselineEntry {
+			code: value.get("code")?.as_str().map(String::from),
+			message: value.get("message")?.as_str()?.to_string(),
+			context: value.get("context")?.as_str()?.to_string(),
+			hash: value.get("hash")?.as_str()?.to_string(),
+		})
+	}
+}
+
+/// The recorded diagnostics of a file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BaselineFile {
+	pub diagnostics: Vec<BaselineEntry>,
+}
+
+/// The known diagnostics of a set of files, keyed by the file path
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+	files: BTreeMap<String, BaselineFile>,
+}
+
+impl Baseline {
+	/// Reads a baseline written by [Baseline::write]
+	pub fn read(path: &Path) -> Result<Self, String> {
+		let content = fs::read_to_string(path)
+			.map_err(|error| format!("Cannot read \"{}\": {}", path.display(), error))?;
+		let value: Value = serde_json::from_str(&content)
+			.map_err(|error| format!("Cannot parse \"{}\": {}", path.display(), error))?;
+		Self::from_json(&value)
+			.ok_or_else(|| format!("\"{}\" isn't a valid baseline file", path.display()))
+	}
+
+	/// Writes the baseline to `path`, the files are sorted so that the output is stable
+	pub fn write(&self, path: &Path) -> Result<(), String> {
+		let content = format!("{:#}\n", self.to_json());
+		fs::write(path, content)
+			.map_err(|error| format!("Cannot write \"{}\": {}", path.display(), error))
+	}
+
+	/// Replaces the recorded diagnostics of the file at `path`
+	pub fn record(&mut self, path: &str, source: &str, diagnostics: &[Diagnostic]) {
+		let file = BaselineFile {
+			diagnostics: diagnostics
+				.iter()
+				.map(|diagnostic| BaselineEntry::new(diagnostic, source))
+				.collect(),
+		};
+		self.files.insert(path.to_string(), file);
+	}
+
+	/// Returns the recorded diagnostics of the file at `path`
+	pub fn file(&self, path: &str) -> Option<&BaselineFile> {
+		self.files.get(path)
+	}
+
+	/// Returns the diagnostics of the file at `path` that aren't part of the baseline.
+	/// Every entry of the baseline matches at most one diagnostic, so a diagnostic that
+	/// appears more often than it was recorded is reported.
+	pub fn new_diagnostics<'a>(
+		&self,
+		path: &str,
+		source: &str,
+		diagnostics: &'a [Diagnostic],
+	) -> Vec<&'a Diagnostic> {
+		let mut known: BTreeMap<&str, usize> = BTreeMap::new();
+		for entry in self
+			.files
+			.get(path)
+			.into_iter()
+			.flat_map(|file| &file.diagnostics)
+		{
+			*known.entry(&entry.hash).or_default() += 1;
+		}
+
+		diagnostics
+			.iter()
+			.filter(|diagnostic| {
+				let entry = BaselineEntry::new(diagnostic, source);
+				match known.get_mut(entry.hash.as_str()) {
+					Some(count) if *count > 0 => {
+						*count -= 1;
+						false
+					}
+					_ => true,
+				}
+			})
+			.collect()
+	}
+
+	fn to_json(&self) -> Value {
+		let files: Map<String, Value> = self
+			.files
+			.iter()
+			.map(|(path, file)| {
+				let diagnostics: Vec<_> = file
+					.diagnostics
+					.iter()
+					.map(BaselineEntry::to_json)
+					.collect();
+				(path.clone(), json!({ "diagnostics": diagnostics }))
+			})
+			.collect();
+
+		json!({ "version": BASELINE_VERSION, "files": files })
+	}
+
+	fn from_json(value: &Value) -> Option<Self> {
+		if value.get("version")?.as_u64()? != BASELINE_VERSION {
+			return None;
+		}
+
+		let mut files = BTreeMap::new();
+		for (path, file) in value.get("files")?.as_object()? {
+			let diagnostics = file
+				.get("diagnostics")?
+				.as_array()?
+				.iter()
+				.map(BaselineEntry::from_json)
+				.collect::<Option<_>>()?;
+			let file = BaselineFile { diagnostics };
+			files.insert(path.clone(), file);
+		}
+
+		Some(Baseline { files })
+	}
+}
+
+/// The lines of `source` covered by `range` with the whitespace of every line trimmed
+fn context_of(source: &str, range: std::ops::Range<usize>) -> String {
+	let start = range.start.min(source.len());
+	let end = range.end.clamp(start, source.len());
+	let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+	let line_end = source[end..]
+		.find('\n')
+		.map_or(source.len(), |index| end + index);
+
+	source
+		.get(line_start..line_end)
+		.unwrap_or_default()
+		.lines()
+		.map(str::trim)
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// The key of the file at `path` in the baseline at `baseline_path`: the path of the file relative to
+/// the directory of the baseline, which is the root of the workspace, with `/` as the separator.
+/// Files outside of the workspace are keyed by their absolute path.
+pub fn file_key(baseline_path: &Path, path: &Path) -> String {
+	let root = match baseline_path.parent() {
+		Some(parent) if !parent.as_os_str().is_empty() => parent,
+		_ => Path::new("."),
+	};
+	let root = absolute_path(root);
+	let path = absolute_path(path);
+
+	match path.strip_prefix(&root) {
+		Ok(relative) => relative
+			.components()
+			.map(|component| component.as_os_str().to_string_lossy())
+			.collect::<Vec<_>>()
+			.join("/"),
+		Err(_) => path.to_string_lossy().replace('\\', "/"),
+	}
+}
+
+fn absolute_path(path: &Path) -> std::path::PathBuf {
+	fs::canonicalize(path).unwrap_or_else(|_| match env::current_dir() {
+		Ok(current_dir) => current_dir.join(path),
+		Err(_) => path.to_path_buf(),
+	})
+}
+
+/// A stable hash of `parts`, see [StableHasher]
+fn content_hash(parts: &[&str]) -> String {
+	let mut hasher = StableHasher::new();
+	for (index, part) in parts.iter().enumerate() {
+		if index > 0 {
+			hasher.write(&[0]);
+		}
+		hasher.write(part.as_bytes());
+	}
+	format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod test {
+	use super::{content_hash, context_of, file_key, Baseline};
+	use rslint_errors::Diagnostic;
+	use std::path::Path;
+
+	fn diagnostic(message: &str, range: std::ops::Range<usize>) -> Diagnostic {
+		Diagnostic::error(0, "SyntaxError", message).primary(range, "")
+	}
+
+	#[test]
+	fn context_is_the_trimmed_lines() {
+		let source = "let a;\n  let b = ;\nlet c;";
+		assert_eq!("let b = ;", context_of(source, 11..16));
+		assert_eq!("let a;\nlet b = ;", context_of(source, 4..10));
+		assert_eq!("let c;", context_of(source, 100..200));
+	}
+
+	#[test]
+	fn content_hash_is_stable() {
+		assert_eq!("cbf29ce484222325", content_hash(&[""]));
+		assert_ne!(content_hash(&["ab", "c"]), content_hash(&["a", "bc"]));
+	}
+
+	#[test]
+	fn files_are_keyed_relative_to_the_baseline() {
+		let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+		assert_eq!(
+			"src/baseline.rs",
+			file_key(&root.join("baseline.json"), &root.join("src/baseline.rs"))
+		);
+		// Tests run in the directory of the package
+		assert_eq!(
+			"src/baseline.rs",
+			file_key(
+				Path::new("baseline.json"),
+				Path::new("./src/../src/baseline.rs")
+			)
+		);
+		assert_eq!(
+			"baseline.rs",
+			file_key(
+				&root.join("src/baseline.json"),
+				Path::new("src/baseline.rs")
+			)
+		);
+	}
+
+	#[test]
+	fn only_reports_new_diagnostics() {
+		let old_source = "let a = ;\n";
+		let mut baseline = Baseline::default();
+		baseline.record(
+			"a.js",
+			old_source,
+			&[diagnostic("Expected an expression", 8..9)],
+		);
+
+		// The known diagnostic moved to another line and a new one was introduced
+		let new_source = "\nlet b = 1 +;\nlet a = ;\n";
+		let diagnostics = vec![
+			diagnostic("Expected an expression", 12..13),
+			diagnostic("Expected an expression", 22..23),
+		];
+
+		let new_diagnostics = baseline.new_diagnostics("a.js", new_source, &diagnostics);
+		assert_eq!(vec![&diagnostics[0]], new_diagnostics);
+		assert_eq!(
+			2,
+			baseline
+				.new_diagnostics("b.js", new_source, &diagnostics)
+				.len()
+		);
+	}
+
+	#[test]
+	fn round_trips_through_json() {
+		let mut baseline = Baseline::default();
+		baseline.record(
+			"a.js",
+			"let a = ;",
+			&[diagnostic("Expected an expression", 8..9)],
+		);
+
+		let json = baseline.to_json();
+		assert_eq!(Some(baseline), Baseline::from_json(&json));
+		assert_eq!(
+			None,
+			Baseline::from_json(&serde_json::json!({ "version": 2 }))
+		);
+	}
+}
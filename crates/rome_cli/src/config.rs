@@ -0,0 +1,128 @@
+//! Loading of the `rome.json` configuration file.
+//!
+//! The file currently only configures the formatter:
+//!
+//! ```json
+//! { "formatter": { "indentStyle": "space", "indentSize": 4, "lineWidth": 100 } }
+//! ```
+use rome_formatter::{FormatOptions, IndentStyle};
+use serde_json::Value;
+use std::{
+	convert::TryFrom,
+	fs,
+	path::{Path, PathBuf},
+};
+
+/// The name of the configuration file
+pub const CONFIG_FILE: &str = "rome.json";
+
+/// Finds the `rome.json` file inside `directory` or the closest of its ancestors that has one, and reads
+/// the formatter options from it. Returns [None] if there's no configuration file.
+pub fn load_format_options(directory: &Path) -> Result<Option<(PathBuf, FormatOptions)>, String> {
+	let path = match directory
+		.ancestors()
+		.map(|directory| directory.join(CONFIG_FILE))
+		.find(|path| path.is_file())
+	{
+		Some(path) => path,
+		None => return Ok(None),
+	};
+
+	let content = fs::read_to_string(&path)
+		.map_err(|error| format!("Cannot read \"{}\": {}", path.display(), error))?;
+	let options = serde_json::from_str(&content)
+		.map_err(|error| error.to_string())
+		.and_then(|config| format_options(&config))
+		.map_err(|error| format!("Invalid configuration \"{}\": {}", path.display(), error))?;
+	Ok(Some((path, options)))
+}
+
+/// Reads the formatter options of the content of a `rome.json` file, the options that aren't set
+/// keep their default value
+pub fn format_options(config: &Value) -> Result<FormatOptions, String> {
+	let mut options = FormatOptions::default();
+	let config = config
+		.as_object()
+		.ok_or("the configuration is not an object")?;
+
+	for name in config.keys() {
+		if name != "formatter" {
+			return Err(format!("unknown option \"{}\"", name));
+		}
+	}
+
+	let formatter = match config.get("formatter") {
+		Some(Value::Object(formatter)) => formatter,
+		Some(_) => return Err("\"formatter\" is not an object".to_string()),
+		None => return Ok(options),
+	};
+
+	let mut indent_size = None;
+	for (name, value) in formatter {
+		match name.as_str() {
+			"indentStyle" => {
+				options.indent_style = match value.as_str() {
+					Some("tab") => IndentStyle::Tab,
+					Some("space") => IndentStyle::Space(2),
+					_ => return Err(invalid_value(name, value)),
+				}
+			}
+			"indentSize" => {
+				let size = value.as_u64().and_then(|size| u8::try_from(size).ok());
+				indent_size = Some(size.ok_or_else(|| invalid_value(name, value))?);
+			}
+			"lineWidth" => {
+				let width = value.as_u64().and_then(|width| u16::try_from(width).ok());
+				options.line_width = width.ok_or_else(|| invalid_value(name, value))?;
+			}
+			_ => return Err(format!("unknown formatter option \"{}\"", name)),
+		}
+	}
+
+	// The size only applies to indentation with spaces
+	if let IndentStyle::Space(size) = &mut options.indent_style {
+		*size = indent_size.unwrap_or(*size);
+	}
+
+	Ok(options)
+}
+
+fn invalid_value(name: &str, value: &Value) -> String {
+	format!("{} is not a valid value of \"{}\"", value, name)
+}
+
+#[cfg(test)]
+mod test {
+	use super::format_options;
+	use rome_formatter::IndentStyle;
+	use serde_json::json;
+
+	#[test]
+	fn reads_formatter_options() {
+		let options = format_options(
+			&json!({ "formatter": { "indentStyle": "space", "indentSize": 4, "lineWidth": 100 } }),
+		)
+		.unwrap();
+		assert_eq!(IndentStyle::Space(4), options.indent_style);
+		assert_eq!(100, options.line_width);
+
+		let options = format_options(&json!({ "formatter": { "indentSize": 4 } })).unwrap();
+		assert_eq!(IndentStyle::Tab, options.indent_style);
+		assert_eq!(80, options.line_width);
+	}
+
+	#[test]
+	fn rejects_unknown_options_and_invalid_values() {
+		assert_eq!(
+			Err("unknown formatter option \"quoteStyle\"".to_string()),
+			format_options(&json!({ "formatter": { "quoteStyle": "single" } }))
+				.map(|options| options.line_width)
+		);
+		assert_eq!(
+			Err("70000 is not a valid value of \"lineWidth\"".to_string()),
+			format_options(&json!({ "formatter": { "lineWidth": 70000 } }))
+				.map(|options| options.line_width)
+		);
+		assert!(format_options(&json!({ "linter": {} })).is_err());
+	}
+}
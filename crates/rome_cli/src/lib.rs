@@ -1,8 +1,24 @@
+use baseline::Baseline;
 use clap::{crate_version, App, AppSettings, Arg};
-use rome_core::create_app;
+use rome_core::{create_app, file_handlers::Language, pragma::Pragmas};
 use rome_formatter::{format_file_and_save, FormatOptions, IndentStyle};
 use rome_path::RomePath;
-use std::{path::PathBuf, str::FromStr};
+use rslint_errors::{
+	file::{FileId, SimpleFiles},
+	source_map::{RawSourceMap, SourceMap, SourceMapRemapper},
+	Emitter,
+};
+use rslint_parser::{ast::JsRoot, parse, FileKind, Syntax};
+use std::{
+	convert::TryFrom,
+	fs,
+	path::{Path, PathBuf},
+	str::FromStr,
+};
+
+pub mod baseline;
+pub mod config;
+pub mod migrate;
 
 /// Main function to run Rome CLI
 pub fn run_cli() {
@@ -16,17 +32,15 @@ pub fn run_cli() {
 				.arg(
 					Arg::new("indent_style")
 						.long("indent-style")
-						.about("The style of indentation")
+						.about("The style of indentation, overrides the style of rome.json")
 						.value_name("tab|space")
-						.default_value("tab")
 						.validator(|value| IndentStyle::from_str(value).map(|_| ())),
 				)
 				.arg(
 					Arg::new("indent_size")
 						.long("indent-size")
-						.about("The size of the indent.")
+						.about("The size of the indent, overrides the size of rome.json")
 						.value_name("NUMBER")
-						.default_value("2")
 						.validator(|value| {
 							value
 								.parse::<u8>()
@@ -46,6 +60,77 @@ pub fn run_cli() {
 						}),
 				),
 		)
+		.subcommand(
+			App::new("check")
+				.about("Report the syntax errors of a file")
+				.arg(
+					Arg::new("remap_diagnostics")
+						.long("remap-diagnostics")
+						.about(
+						"Report the errors against the original sources of the file's source map",
+					),
+				)
+				.arg(
+					Arg::new("baseline")
+						.long("baseline")
+						.about("Only report the errors that aren't part of the baseline")
+						.value_name("FILE")
+						.conflicts_with("write_baseline"),
+				)
+				.arg(
+					Arg::new("write_baseline")
+						.long("write-baseline")
+						.about("Record the errors of the file in the baseline instead of reporting them")
+						.value_name("FILE"),
+				)
+				.arg(
+					Arg::new("input")
+						.about("File to check")
+						.required(true)
+						.validator(|value| {
+							let path = PathBuf::from(&value);
+							if !path.exists() {
+								return Err(format!("The file \"{}\" doesn't exist.", value));
+							}
+							Ok(())
+						}),
+				),
+		)
+		.subcommand(
+			App::new("migrate")
+				.about("Migrate the configuration of other tools to a rome.json file")
+				.setting(AppSettings::SubcommandRequiredElseHelp)
+				.subcommand(
+					App::new("eslint")
+						.about("Migrate .eslintrc and .prettierrc files written in JSON, YAML, or JavaScript")
+						.arg(
+							Arg::new("dry_run")
+								.long("dry-run")
+								.about("Print the configuration without writing rome.json"),
+						)
+						.arg(
+							Arg::new("force")
+								.long("force")
+								.about("Overwrite the rome.json file if it already exists")
+								.conflicts_with("dry_run"),
+						)
+						.arg(
+							Arg::new("directory")
+								.about("Directory containing the configuration files")
+								.default_value(".")
+								.validator(|value| {
+									let path = PathBuf::from(&value);
+									if !path.is_dir() {
+										return Err(format!(
+											"The directory \"{}\" doesn't exist.",
+											value
+										));
+									}
+									Ok(())
+								}),
+						),
+				),
+		)
 		.try_get_matches();
 	let subcommand_matches = match &matches {
 		Ok(r) => r.subcommand(),
@@ -59,20 +144,53 @@ pub fn run_cli() {
 			let size = matches.value_of("indent_size");
 			let style = matches.value_of("indent_style");
 			let input = matches.value_of("input").unwrap();
-			let options: IndentStyle = style
-				.map(|s| match s {
-					"tab" => IndentStyle::Tab,
-					"space" => {
-						let size = size.unwrap_or("2");
-						IndentStyle::Space(size.parse::<u8>().unwrap_or(2))
-					}
-					_ => IndentStyle::default(),
-				})
-				.unwrap_or_default();
+
+			let directory = Path::new(input).parent().unwrap_or_else(|| Path::new(""));
+			let directory = fs::canonicalize(directory).unwrap_or_else(|_| directory.to_path_buf());
+			let mut options = match config::load_format_options(&directory) {
+				Ok(Some((_, options))) => options,
+				Ok(None) => FormatOptions::default(),
+				Err(error) => clap::Error::with_description(error, clap::ErrorKind::Io).exit(),
+			};
+
+			let size = size.and_then(|size| size.parse::<u8>().ok());
+			match (style, &mut options.indent_style) {
+				(Some("tab"), _) => options.indent_style = IndentStyle::Tab,
+				(Some("space"), _) => options.indent_style = IndentStyle::Space(size.unwrap_or(2)),
+				(_, IndentStyle::Space(configured)) => *configured = size.unwrap_or(*configured),
+				_ => {}
+			}
 
 			let mut file = RomePath::new(input).deduce_handler(&app);
-			format_file_and_save(&mut file, FormatOptions::new(options));
+			format_file_and_save(&mut file, options);
+		}
+		Some(("check", matches)) => {
+			let input = Path::new(matches.value_of("input").unwrap());
+			let baseline = match (
+				matches.value_of("baseline"),
+				matches.value_of("write_baseline"),
+			) {
+				(Some(path), _) => BaselineMode::Compare(PathBuf::from(path)),
+				(_, Some(path)) => BaselineMode::Write(PathBuf::from(path)),
+				_ => BaselineMode::None,
+			};
+			check_file(input, matches.is_present("remap_diagnostics"), baseline);
 		}
+		Some(("migrate", matches)) => match matches.subcommand() {
+			Some(("eslint", matches)) => {
+				let directory = Path::new(matches.value_of("directory").unwrap());
+				migrate_eslint(
+					directory,
+					matches.is_present("dry_run"),
+					matches.is_present("force"),
+				);
+			}
+			_ => clap::Error::with_description(
+				"Sub command not found".to_string(),
+				clap::ErrorKind::InvalidSubcommand,
+			)
+			.exit(),
+		},
 		// Thanks to the settings AppSettings::SubcommandRequiredElseHelp we should not be there
 		_ => clap::Error::with_description(
 			"Sub command not found".to_string(),
@@ -81,3 +199,249 @@ pub fn run_cli() {
 		.exit(),
 	}
 }
+
+fn migrate_eslint(directory: &Path, dry_run: bool, force: bool) {
+	let migration = match migrate::Migration::from_directory(directory) {
+		Ok(migration) => migration,
+		Err(error) => clap::Error::with_description(error, clap::ErrorKind::Io).exit(),
+	};
+
+	for source in migration.sources() {
+		println!("Migrated \"{}\"", source.display());
+	}
+
+	if dry_run {
+		println!("{:#}", migration.to_config());
+	} else {
+		match migration.write(directory, force) {
+			Ok(path) => println!("Created \"{}\"", path.display()),
+			Err(error) => clap::Error::with_description(error, clap::ErrorKind::Io).exit(),
+		}
+	}
+
+	let unmapped = migration.unmapped_settings();
+	if !unmapped.is_empty() {
+		println!("The following settings couldn't be migrated:");
+		for setting in unmapped {
+			println!("- {}", setting);
+		}
+	}
+
+	let conflicts = migration.conflicts();
+	if !conflicts.is_empty() {
+		println!("The following settings conflict with another setting and were ignored:");
+		for conflict in conflicts {
+			println!("- {}", conflict);
+		}
+	}
+}
+
+/// How the `check` command uses a baseline of known diagnostics
+enum BaselineMode {
+	None,
+	/// Only report the diagnostics that aren't part of the baseline
+	Compare(PathBuf),
+	/// Record the diagnostics in the baseline, creating it if it doesn't exist
+	Write(PathBuf),
+}
+
+fn check_file(path: &Path, remap_diagnostics: bool, baseline: BaselineMode) {
+	let source = match fs::read_to_string(path) {
+		Ok(source) => source,
+		Err(error) => clap::Error::with_description(error.to_string(), clap::ErrorKind::Io).exit(),
+	};
+
+	let pragmas = Pragmas::scan(&source);
+	if pragmas.disable_lint {
+		println!(
+			"Skipped \"{}\", checks are disabled by a pragma",
+			path.display()
+		);
+		return;
+	}
+	let syntax = match check_syntax(path, &pragmas) {
+		Some(syntax) => syntax,
+		None => {
+			println!(
+				"Skipped \"{}\", only JavaScript and TypeScript files are checked",
+				path.display()
+			);
+			return;
+		}
+	};
+
+	let mut files = SimpleFiles::new();
+	let file_id = files.add(path.display().to_string(), source.clone());
+	let parse = parse(&source, file_id, syntax);
+
+	let remapper = if remap_diagnostics {
+		source_map_remapper(path, &parse.tree(), file_id, &mut files)
+	} else {
+		None
+	};
+
+	let diagnostics: Vec<_> = match baseline {
+		BaselineMode::None => parse.errors().iter().collect(),
+		BaselineMode::Compare(baseline_path) => match Baseline::read(&baseline_path) {
+			Ok(baseline) => {
+				let key = baseline::file_key(&baseline_path, path);
+				baseline.new_diagnostics(&key, &source, parse.errors())
+			}
+			Err(error) => clap::Error::with_description(error, clap::ErrorKind::Io).exit(),
+		},
+		BaselineMode::Write(baseline_path) => {
+			let mut baseline = if baseline_path.exists() {
+				match Baseline::read(&baseline_path) {
+					Ok(baseline) => baseline,
+					Err(error) => clap::Error::with_description(error, clap::ErrorKind::Io).exit(),
+				}
+			} else {
+				Baseline::default()
+			};
+			let key = baseline::file_key(&baseline_path, path);
+			baseline.record(&key, &source, parse.errors());
+			if let Err(error) = baseline.write(&baseline_path) {
+				clap::Error::with_description(error, clap::ErrorKind::Io).exit();
+			}
+			println!(
+				"Recorded {} errors of \"{}\" in \"{}\"",
+				parse.errors().len(),
+				key,
+				baseline_path.display()
+			);
+			return;
+		}
+	};
+
+	let has_errors = !diagnostics.is_empty();
+	let mut emitter = Emitter::new(&files);
+	for diagnostic in diagnostics {
+		let diagnostic = match &remapper {
+			Some(remapper) => remapper.remap(&files, diagnostic.clone()),
+			None => diagnostic.clone(),
+		};
+		emitter
+			.emit_stderr(&diagnostic, true)
+			.expect("failed to print the diagnostic");
+	}
+
+	if has_errors {
+		std::process::exit(1);
+	}
+}
+
+/// The syntax to check a file with, derived from the extension of the file and the language chosen by
+/// a `rome-language` pragma. Returns [None] if the file isn't written in JavaScript or TypeScript.
+fn check_syntax(path: &Path, pragmas: &Pragmas) -> Option<Syntax> {
+	let from_extension = path
+		.extension()
+		.and_then(|extension| extension.to_str())
+		.and_then(Syntax::from_extension);
+	match pragmas.language {
+		None => from_extension,
+		Some(Language::Js) => Some(
+			from_extension
+				.filter(|syntax| syntax.file_kind != FileKind::TypeScript)
+				.unwrap_or_default(),
+		),
+		Some(Language::Ts) => Some(
+			from_extension
+				.filter(|syntax| syntax.file_kind == FileKind::TypeScript)
+				.unwrap_or_else(|| Syntax::default().typescript()),
+		),
+		Some(Language::Json) | Some(Language::Unknown) => None,
+	}
+}
+
+/// Loads the source map referenced by the `//# sourceMappingURL` comment of the file,
+/// or the `.map` file next to it.
+fn source_map_remapper(
+	path: &Path,
+	root: &JsRoot,
+	file_id: FileId,
+	files: &mut SimpleFiles,
+) -> Option<SourceMapRemapper> {
+	let directory = path.parent().unwrap_or_else(|| Path::new(""));
+	let map_path = match root.source_mapping_url() {
+		Some(comment) if !comment.url.contains(':') => directory.join(comment.url),
+		_ => PathBuf::from(format!("{}.map", path.display())),
+	};
+
+	let map = fs::read_to_string(&map_path)
+		.map_err(|error| error.to_string())
+		.and_then(|map| parse_source_map(&map));
+	let map = match map {
+		Ok(map) => map,
+		Err(error) => {
+			eprintln!(
+				"Couldn't load the source map \"{}\": {}",
+				map_path.display(),
+				error
+			);
+			return None;
+		}
+	};
+
+	let map_directory = map_path.parent().unwrap_or_else(|| Path::new(""));
+	Some(SourceMapRemapper::new(map, file_id, files, |source| {
+		fs::read_to_string(map_directory.join(source)).ok()
+	}))
+}
+
+/// Parses the JSON of a source map
+fn parse_source_map(text: &str) -> Result<SourceMap, String> {
+	let map: RawSourceMap = serde_json::from_str(text).map_err(|error| error.to_string())?;
+	SourceMap::try_from(map).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod test {
+	use super::{check_syntax, parse_source_map};
+	use rome_core::pragma::Pragmas;
+	use rslint_parser::Syntax;
+	use std::path::Path;
+
+	#[test]
+	fn language_pragma_selects_the_syntax() {
+		let syntax = |text| check_syntax(Path::new("file.js"), &Pragmas::scan(text));
+
+		assert_eq!(Some(Syntax::default()), syntax("let a;"));
+		assert_eq!(
+			Some(Syntax::default().typescript()),
+			syntax("// rome-language ts\nlet a: number;")
+		);
+		assert_eq!(None, syntax("/* rome-language json */ {}"));
+	}
+
+	#[test]
+	fn extension_selects_the_syntax() {
+		let syntax = |path| check_syntax(Path::new(path), &Pragmas::scan("let a;"));
+
+		assert_eq!(Some(Syntax::default().tsx()), syntax("component.tsx"));
+		assert_eq!(Some(Syntax::default().jsx()), syntax("component.jsx"));
+		assert_eq!(Some(Syntax::default().module()), syntax("index.mjs"));
+		assert_eq!(None, syntax("package.json"));
+		assert_eq!(None, syntax("README"));
+
+		let pragmas = Pragmas::scan("// rome-language ts\nlet a: number;");
+		assert_eq!(
+			Some(Syntax::default().tsx()),
+			check_syntax(Path::new("component.tsx"), &pragmas)
+		);
+	}
+
+	#[test]
+	fn parses_source_maps() {
+		let map = parse_source_map(
+			r#"{"version":3,"sourceRoot":"src/","sources":["a.ts"],"names":[],"mappings":"AAAA"}"#,
+		)
+		.unwrap();
+		assert_eq!(vec!["src/a.ts"], map.sources);
+
+		assert_eq!(
+			Err("index source maps are not supported".to_string()),
+			parse_source_map(r#"{"version":3,"sections":[]}"#).map(|map| map.sources)
+		);
+		assert!(parse_source_map("{").is_err());
+	}
+}
@@ -0,0 +1,660 @@
+//! Conversion of ESLint and Prettier configuration files to a `rome.json` file.
+//!
+//! Only the options that have an equivalent in Rome are converted, everything else
+//! is collected as [Unmapped] so that it can be reported back to the user. The options are
+//! the formatter options read by [crate::config::format_options].
+//!
+//! The configuration files can be written in JSON, YAML, or JavaScript. A JavaScript configuration
+//! is read without running it, its `module.exports` or `export default` must be a static value.
+use crate::config::CONFIG_FILE;
+use rslint_parser::{
+	ast::{
+		AssignExpr, ExportDefaultExpr, JsAnyArrayElement, JsAnyExpression, JsAnyLiteralExpression,
+		JsAnyObjectMember, JsAnyObjectMemberName, JsExpressionStatement, JsUnaryExpression,
+	},
+	parse_module, AstNode, SyntaxNodeExt, T,
+};
+use serde_json::{json, Map, Number, Value};
+use std::{
+	collections::HashMap,
+	fmt,
+	fs::{self, OpenOptions},
+	io::{ErrorKind, Write},
+	path::{Path, PathBuf},
+};
+
+/// The ESLint configuration files that can be migrated, in order of precedence.
+const ESLINT_FILES: [&str; 6] = [
+	".eslintrc.js",
+	".eslintrc.cjs",
+	".eslintrc.yaml",
+	".eslintrc.yml",
+	".eslintrc.json",
+	".eslintrc",
+];
+
+/// The Prettier configuration files that can be migrated, in order of precedence.
+const PRETTIER_FILES: [&str; 8] = [
+	".prettierrc",
+	".prettierrc.json",
+	".prettierrc.yaml",
+	".prettierrc.yml",
+	".prettierrc.js",
+	".prettierrc.cjs",
+	"prettier.config.js",
+	"prettier.config.cjs",
+];
+
+/// A setting of a source configuration that has no equivalent in Rome
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unmapped {
+	/// The configuration file the setting comes from
+	pub source: String,
+	/// The name of the option or rule
+	pub name: String,
+	/// Why the setting couldn't be migrated
+	pub reason: &'static str,
+}
+
+impl fmt::Display for Unmapped {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}: \"{}\" {}", self.source, self.name, self.reason)
+	}
+}
+
+/// A setting of a source configuration together with the value it has
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setting {
+	/// The configuration file the setting comes from
+	pub source: String,
+	/// The name of the option or rule
+	pub name: String,
+	/// The value the setting maps to
+	pub value: Value,
+}
+
+/// Two settings that map to the same Rome option with different values
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+	/// The name of the Rome option
+	pub option: String,
+	/// The setting whose value is used
+	pub kept: Setting,
+	/// The setting that is ignored
+	pub ignored: Setting,
+}
+
+impl fmt::Display for Conflict {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{}: \"{}\" sets \"{}\" to {} but {}: \"{}\" sets it to {}, {} is used",
+			self.ignored.source,
+			self.ignored.name,
+			self.option,
+			self.ignored.value,
+			self.kept.source,
+			self.kept.name,
+			self.kept.value,
+			self.kept.value
+		)
+	}
+}
+
+/// The result of a migration
+#[derive(Debug, Default)]
+pub struct Migration {
+	/// The formatter options of the resulting `rome.json`
+	formatter: Map<String, Value>,
+	/// The setting every formatter option was migrated from
+	origins: HashMap<String, Setting>,
+	/// The settings that couldn't be migrated
+	unmapped: Vec<Unmapped>,
+	/// The settings that conflict with a setting that was migrated before
+	conflicts: Vec<Conflict>,
+	/// The configuration files that were read
+	sources: Vec<PathBuf>,
+}
+
+impl Migration {
+	/// Looks for ESLint and Prettier configuration files inside `directory` and migrates them.
+	///
+	/// Prettier is migrated first, its options take precedence over the ESLint rules that set
+	/// the same option because Prettier is the tool that formats the code.
+	pub fn from_directory(directory: &Path) -> Result<Self, String> {
+		let mut migration = Migration::default();
+
+		if let Some(path) = find_file(directory, &PRETTIER_FILES) {
+			let config = read_config(&path)?;
+			migration.sources.push(path.clone());
+			migration.migrate_prettier(&file_name(&path), &config);
+		}
+
+		if let Some(path) = find_file(directory, &ESLINT_FILES) {
+			let config = read_config(&path)?;
+			migration.sources.push(path.clone());
+			migration.migrate_eslint(&file_name(&path), &config);
+		}
+
+		if migration.sources.is_empty() {
+			return Err(format!(
+				"No ESLint or Prettier configuration found in \"{}\". Supported files are: {}",
+				directory.display(),
+				ESLINT_FILES
+					.iter()
+					.chain(PRETTIER_FILES.iter())
+					.copied()
+					.collect::<Vec<_>>()
+					.join(", ")
+			));
+		}
+
+		Ok(migration)
+	}
+
+	/// Maps the options of a `.prettierrc` file
+	pub fn migrate_prettier(&mut self, source: &str, config: &Value) {
+		let options = match config.as_object() {
+			Some(options) => options,
+			None => {
+				self.unmapped(source, "<root>", "is not an object");
+				return;
+			}
+		};
+
+		for (name, value) in options {
+			match (name.as_str(), value) {
+				("useTabs", Value::Bool(use_tabs)) => {
+					let style = if *use_tabs { "tab" } else { "space" };
+					self.set_option(source, name, "indentStyle", json!(style));
+				}
+				("tabWidth", Value::Number(width)) if fits(width, u8::MAX.into()) => {
+					self.set_option(source, name, "indentSize", value.clone());
+				}
+				("printWidth", Value::Number(width)) if fits(width, u16::MAX.into()) => {
+					self.set_option(source, name, "lineWidth", value.clone());
+				}
+				("useTabs", _) | ("tabWidth", _) | ("printWidth", _) => {
+					self.unmapped(source, name, "has an unsupported value")
+				}
+				_ => self.unmapped(source, name, "has no equivalent in Rome"),
+			}
+		}
+	}
+
+	/// Maps the rules of an `.eslintrc` file.
+	///
+	/// Rome doesn't have a linter yet, so only the stylistic rules that map to formatter
+	/// options are migrated.
+	pub fn migrate_eslint(&mut self, source: &str, config: &Value) {
+		let config = match config.as_object() {
+			Some(config) => config,
+			None => {
+				self.unmapped(source, "<root>", "is not an object");
+				return;
+			}
+		};
+
+		for (name, value) in config {
+			match name.as_str() {
+				"rules" => {
+					if let Some(rules) = value.as_object() {
+						for (rule, options) in rules {
+							self.migrate_eslint_rule(source, rule, options);
+						}
+					} else {
+						self.unmapped(source, name, "is not an object");
+					}
+				}
+				// These don't affect the output of the migration
+				"root" | "$schema" => {}
+				_ => self.unmapped(source, name, "has no equivalent in Rome"),
+			}
+		}
+	}
+
+	fn migrate_eslint_rule(&mut self, source: &str, rule: &str, options: &Value) {
+		// A rule is either configured with its severity only or with an array: [severity, ...options]
+		let (severity, options) = match options {
+			Value::Array(array) => match array.split_first() {
+				Some((severity, options)) => (severity, options),
+				None => return self.unmapped(source, rule, "has an unsupported value"),
+			},
+			severity => (severity, &[][..]),
+		};
+
+		if is_rule_off(severity) {
+			return;
+		}
+
+		match rule {
+			"indent" => match options.first() {
+				Some(Value::String(tab)) if tab == "tab" => {
+					self.set_option(source, rule, "indentStyle", json!("tab"));
+				}
+				Some(Value::Number(size)) if fits(size, u8::MAX.into()) => {
+					self.set_option(source, rule, "indentStyle", json!("space"));
+					self.set_option(source, rule, "indentSize", json!(size));
+				}
+				_ => self.unmapped(source, rule, "has an unsupported value"),
+			},
+			"max-len" => {
+				let code = match options.first() {
+					Some(Value::Object(options)) => options.get("code"),
+					code => code,
+				};
+
+				match code {
+					Some(Value::Number(code)) if fits(code, u16::MAX.into()) => {
+						self.set_option(source, rule, "lineWidth", json!(code));
+					}
+					_ => self.unmapped(source, rule, "has an unsupported value"),
+				}
+			}
+			_ => self.unmapped(source, rule, "has no equivalent in Rome"),
+		}
+	}
+
+	/// Sets the formatter `option` migrated from the setting `name` of `source`, unless a previous
+	/// setting already set the option to a different value
+	fn set_option(&mut self, source: &str, name: &str, option: &str, value: Value) {
+		let setting = Setting {
+			source: source.to_string(),
+			name: name.to_string(),
+			value,
+		};
+
+		match self.origins.get(option) {
+			Some(kept) if kept.value != setting.value => {
+				self.conflicts.push(Conflict {
+					option: option.to_string(),
+					kept: kept.clone(),
+					ignored: setting,
+				});
+			}
+			_ => {
+				self.formatter
+					.insert(option.to_string(), setting.value.clone());
+				self.origins.insert(option.to_string(), setting);
+			}
+		}
+	}
+
+	fn unmapped(&mut self, source: &str, name: &str, reason: &'static str) {
+		self.unmapped.push(Unmapped {
+			source: source.to_string(),
+			name: name.to_string(),
+			reason,
+		});
+	}
+
+	/// The settings that couldn't be migrated
+	pub fn unmapped_settings(&self) -> &[Unmapped] {
+		&self.unmapped
+	}
+
+	/// The settings that were ignored because they conflict with another setting
+	pub fn conflicts(&self) -> &[Conflict] {
+		&self.conflicts
+	}
+
+	/// The configuration files that were read during the migration
+	pub fn sources(&self) -> &[PathBuf] {
+		&self.sources
+	}
+
+	/// Returns the content of the `rome.json` file
+	pub fn to_config(&self) -> Value {
+		let mut config = Map::new();
+		if !self.formatter.is_empty() {
+			config.insert(
+				"formatter".to_string(),
+				Value::Object(self.formatter.clone()),
+			);
+		}
+		Value::Object(config)
+	}
+
+	/// Writes the `rome.json` file inside `directory` and returns its path. An existing `rome.json`
+	/// is only replaced if `overwrite` is `true`.
+	pub fn write(&self, directory: &Path, overwrite: bool) -> Result<PathBuf, String> {
+		let path = directory.join(CONFIG_FILE);
+		let content = serde_json::to_string_pretty(&self.to_config())
+			.map_err(|error| format!("Cannot serialize the configuration: {}", error))?;
+
+		let mut options = OpenOptions::new();
+		if overwrite {
+			options.write(true).create(true).truncate(true);
+		} else {
+			options.write(true).create_new(true);
+		}
+		options
+			.open(&path)
+			.and_then(|mut file| file.write_all((content + "\n").as_bytes()))
+			.map_err(|error| match error.kind() {
+				ErrorKind::AlreadyExists => {
+					format!(
+						"\"{}\" already exists, use --force to overwrite it",
+						path.display()
+					)
+				}
+				_ => format!("Cannot write \"{}\": {}", path.display(), error),
+			})?;
+		Ok(path)
+	}
+}
+
+/// Returns `true` if `number` is an integer between 0 and `max`
+fn fits(number: &Number, max: u64) -> bool {
+	matches!(number.as_u64(), Some(number) if number <= max)
+}
+
+fn is_rule_off(severity: &Value) -> bool {
+	match severity {
+		Value::String(severity) => severity == "off",
+		Value::Number(severity) => severity.as_u64() == Some(0),
+		_ => false,
+	}
+}
+
+fn find_file(directory: &Path, names: &[&str]) -> Option<PathBuf> {
+	names
+		.iter()
+		.map(|name| directory.join(name))
+		.find(|path| path.is_file())
+}
+
+fn file_name(path: &Path) -> String {
+	path.file_name()
+		.map(|name| name.to_string_lossy().to_string())
+		.unwrap_or_default()
+}
+
+/// Reads a configuration file, its format is inferred from its extension. The files without an
+/// extension, e.g. `.eslintrc`, are either JSON or YAML.
+fn read_config(path: &Path) -> Result<Value, String> {
+	let content = fs::read_to_string(path)
+		.map_err(|error| format!("Cannot read \"{}\": {}", path.display(), error))?;
+	let extension = path.extension().and_then(|extension| extension.to_str());
+
+	let config = match extension {
+		Some("js" | "cjs" | "mjs") => read_js(&content),
+		Some("yaml" | "yml") => read_yaml(&content),
+		Some("json") => serde_json::from_str(&content).map_err(|error| error.to_string()),
+		_ => serde_json::from_str(&content).or_else(|_| read_yaml(&content)),
+	};
+
+	config.map_err(|error| format!("Cannot parse \"{}\": {}", path.display(), error))
+}
+
+fn read_yaml(content: &str) -> Result<Value, String> {
+	serde_yaml::from_str(content).map_err(|error| error.to_string())
+}
+
+/// Reads the value that a JavaScript configuration file exports with `module.exports = ` or `export default`
+fn read_js(content: &str) -> Result<Value, String> {
+	let parse = parse_module(content, 0);
+	if let Some(error) = parse.errors().first() {
+		return Err(error.title.clone());
+	}
+
+	let exported = parse.tree().statements().iter().find_map(|statement| {
+		let statement = statement.syntax();
+		if let Some(export) = statement.try_to::<ExportDefaultExpr>() {
+			return export.expr().ok();
+		}
+
+		let assignment = statement
+			.try_to::<JsExpressionStatement>()?
+			.expression()
+			.ok()?
+			.syntax()
+			.try_to::<AssignExpr>()?;
+		let is_module_exports = matches!(
+			assignment.lhs(),
+			Some(lhs) if lhs.syntax().text_trimmed() == "module.exports"
+		);
+		Some(assignment.rhs()?).filter(|_| is_module_exports)
+	});
+
+	match exported {
+		Some(exported) => static_value(exported),
+		None => Err("The file has no `module.exports` or `export default`".to_string()),
+	}
+}
+
+/// Converts a JavaScript expression that only consists of literals, arrays, and objects to a value
+fn static_value(expression: JsAnyExpression) -> Result<Value, String> {
+	let not_static = |expression: &JsAnyExpression| {
+		format!(
+			"`{}` is not a static value",
+			expression.syntax().text_trimmed()
+		)
+	};
+
+	let value = match &expression {
+		JsAnyExpression::JsAnyLiteralExpression(literal) => match literal {
+			JsAnyLiteralExpression::JsStringLiteralExpression(string) => string
+				.value()
+				.map(Value::String)
+				.map_err(|_| not_static(&expression))?,
+			JsAnyLiteralExpression::JsNumberLiteralExpression(number) => number
+				.as_f64()
+				.and_then(number_value)
+				.ok_or_else(|| not_static(&expression))?,
+			JsAnyLiteralExpression::JsBooleanLiteralExpression(boolean) => {
+				let value = boolean.value_token().map_err(|_| not_static(&expression))?;
+				Value::Bool(value.kind() == T![true])
+			}
+			JsAnyLiteralExpression::JsNullLiteralExpression(_) => Value::Null,
+			_ => return Err(not_static(&expression)),
+		},
+		JsAnyExpression::JsUnaryExpression(unary) => negative_number(unary)
+			.and_then(number_value)
+			.ok_or_else(|| not_static(&expression))?,
+		JsAnyExpression::JsParenthesizedExpression(parenthesized) => {
+			let inner = parenthesized
+				.expression()
+				.map_err(|_| not_static(&expression))?;
+			static_value(inner)?
+		}
+		JsAnyExpression::JsArrayExpression(array) => {
+			let mut elements = vec![];
+			for element in array.elements().iter() {
+				match element {
+					JsAnyArrayElement::JsAnyExpression(element) => {
+						elements.push(static_value(element)?)
+					}
+					_ => return Err(not_static(&expression)),
+				}
+			}
+			Value::Array(elements)
+		}
+		JsAnyExpression::JsObjectExpression(object) => {
+			let mut properties = Map::new();
+			for member in object.members().iter() {
+				let property = match member {
+					JsAnyObjectMember::JsPropertyObjectMember(property) => property,
+					_ => return Err(not_static(&expression)),
+				};
+				let name = match property.name() {
+					Ok(JsAnyObjectMemberName::JsLiteralMemberName(name)) => name.name().ok(),
+					_ => None,
+				};
+				let name = name.ok_or_else(|| not_static(&expression))?;
+				let value = property.value().map_err(|_| not_static(&expression))?;
+				properties.insert(name, static_value(value)?);
+			}
+			Value::Object(properties)
+		}
+		_ => return Err(not_static(&expression)),
+	};
+
+	Ok(value)
+}
+
+/// The value of a negated number literal, e.g. `-1`
+fn negative_number(unary: &JsUnaryExpression) -> Option<f64> {
+	if unary.operator().ok()?.kind() != T![-] {
+		return None;
+	}
+	match unary.argument().ok()? {
+		JsAnyExpression::JsAnyLiteralExpression(
+			JsAnyLiteralExpression::JsNumberLiteralExpression(number),
+		) => number.as_f64().map(|number| -number),
+		_ => None,
+	}
+}
+
+/// Converts a number to a JSON number, preferring integers so that `4` stays `4` instead of `4.0`
+fn number_value(number: f64) -> Option<Value> {
+	if number.fract() == 0.0 && number.abs() < u32::MAX as f64 {
+		Some(json!(number as i64))
+	} else {
+		Number::from_f64(number).map(Value::Number)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{read_js, read_yaml, Migration};
+	use crate::config::format_options;
+	use rome_formatter::IndentStyle;
+	use serde_json::json;
+	use std::fs;
+
+	#[test]
+	fn migrate_prettier_options() {
+		let mut migration = Migration::default();
+		migration.migrate_prettier(
+			".prettierrc",
+			&json!({ "useTabs": false, "tabWidth": 4, "printWidth": 100, "semi": false }),
+		);
+
+		assert_eq!(
+			migration.to_config(),
+			json!({ "formatter": { "indentStyle": "space", "indentSize": 4, "lineWidth": 100 } })
+		);
+		let options = format_options(&migration.to_config()).unwrap();
+		assert_eq!(IndentStyle::Space(4), options.indent_style);
+		assert_eq!(100, options.line_width);
+
+		let unmapped: Vec<_> = migration
+			.unmapped_settings()
+			.iter()
+			.map(|unmapped| unmapped.name.as_str())
+			.collect();
+		assert_eq!(unmapped, vec!["semi"]);
+	}
+
+	#[test]
+	fn migrate_eslint_rules() {
+		let mut migration = Migration::default();
+		migration.migrate_eslint(
+			".eslintrc",
+			&json!({
+				"root": true,
+				"extends": "eslint:recommended",
+				"rules": {
+					"indent": ["error", "tab"],
+					"max-len": ["warn", { "code": 120 }],
+					"no-console": "off",
+					"no-unused-vars": "error"
+				}
+			}),
+		);
+
+		assert_eq!(
+			migration.to_config(),
+			json!({ "formatter": { "indentStyle": "tab", "lineWidth": 120 } })
+		);
+
+		let unmapped: Vec<_> = migration
+			.unmapped_settings()
+			.iter()
+			.map(|unmapped| unmapped.name.as_str())
+			.collect();
+		assert_eq!(unmapped, vec!["extends", "no-unused-vars"]);
+	}
+
+	#[test]
+	fn unsupported_widths_are_not_migrated() {
+		let mut migration = Migration::default();
+		migration.migrate_prettier(
+			".prettierrc",
+			&json!({ "tabWidth": 300, "printWidth": 100000 }),
+		);
+		assert_eq!(migration.to_config(), json!({}));
+		assert_eq!(2, migration.unmapped_settings().len());
+	}
+
+	#[test]
+	fn write_does_not_replace_an_existing_config() {
+		let directory = std::env::temp_dir().join("rome_migrate_write_test");
+		fs::create_dir_all(&directory).unwrap();
+		let path = directory.join("rome.json");
+		fs::write(&path, "{}\n").unwrap();
+
+		let mut migration = Migration::default();
+		migration.migrate_prettier(".prettierrc", &json!({ "printWidth": 100 }));
+		assert!(migration.write(&directory, false).is_err());
+		assert_eq!("{}\n", fs::read_to_string(&path).unwrap());
+
+		migration.write(&directory, true).unwrap();
+		let written = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+		assert_eq!(100, format_options(&written).unwrap().line_width);
+		fs::remove_dir_all(&directory).unwrap();
+	}
+
+	#[test]
+	fn prettier_options_take_precedence_over_eslint_rules() {
+		let mut migration = Migration::default();
+		migration.migrate_prettier(".prettierrc", &json!({ "useTabs": true, "printWidth": 80 }));
+		migration.migrate_eslint(
+			".eslintrc",
+			&json!({ "rules": { "indent": ["error", 2], "max-len": ["error", 80] } }),
+		);
+
+		assert_eq!(
+			migration.to_config(),
+			json!({ "formatter": { "indentStyle": "tab", "indentSize": 2, "lineWidth": 80 } })
+		);
+
+		let conflicts: Vec<_> = migration
+			.conflicts()
+			.iter()
+			.map(|conflict| conflict.to_string())
+			.collect();
+		assert_eq!(
+			conflicts,
+			vec![".eslintrc: \"indent\" sets \"indentStyle\" to \"space\" but .prettierrc: \"useTabs\" sets it to \"tab\", \"tab\" is used"]
+		);
+	}
+
+	#[test]
+	fn read_yaml_config() {
+		let config = read_yaml("rules:\n  indent:\n    - error\n    - tab\n").unwrap();
+		assert_eq!(config, json!({ "rules": { "indent": ["error", "tab"] } }));
+	}
+
+	#[test]
+	fn read_js_config() {
+		let config = read_js(
+			"// Prettier\nmodule.exports = { useTabs: true, 'tabWidth': 4, printWidth: -1, semi: [false, null] };",
+		)
+		.unwrap();
+		assert_eq!(
+			config,
+			json!({ "useTabs": true, "tabWidth": 4, "printWidth": -1, "semi": [false, null] })
+		);
+
+		let config = read_js("export default { printWidth: (100) };").unwrap();
+		assert_eq!(config, json!({ "printWidth": 100 }));
+
+		assert_eq!(
+			read_js("module.exports = { plugins: [require('a')] };"),
+			Err("`require('a')` is not a static value".to_string())
+		);
+		assert!(read_js("const config = {};").is_err());
+	}
+}
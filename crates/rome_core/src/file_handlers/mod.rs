@@ -2,6 +2,7 @@ pub mod javascript;
 pub mod json;
 pub mod unknown;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Language {
 	Js,
 	Json,
@@ -1,8 +1,9 @@
 use crate::file_handlers::{javascript::JsFileHandler, unknown::UnknownFileHandler};
-use file_handlers::{json::JsonFileHandler, ExtensionHandler};
+use file_handlers::{json::JsonFileHandler, ExtensionHandler, Language};
 use std::collections::HashMap;
 
 pub mod file_handlers;
+pub mod pragma;
 
 // these strings will live for the whole App, so it makes sense to have them as static
 pub type Handlers = HashMap<&'static str, Box<dyn ExtensionHandler>>;
@@ -17,6 +18,7 @@ impl Default for App {
 		let mut map: Handlers = HashMap::new();
 		map.insert("js", Box::new(JsFileHandler {}));
 		map.insert("ts", Box::new(JsFileHandler {}));
+		map.insert("tsx", Box::new(JsFileHandler {}));
 		map.insert("json", Box::new(JsonFileHandler {}));
 		Self {
 			handlers: map,
@@ -38,6 +40,17 @@ impl App {
 		};
 		handler.map(|handler| handler.as_ref())
 	}
+
+	/// Returns the handler of the files written in `language`
+	pub fn get_handler_for_language(&self, language: Language) -> Option<&dyn ExtensionHandler> {
+		let extension = match language {
+			Language::Js => "js",
+			Language::Ts => "ts",
+			Language::Json => "json",
+			Language::Unknown => return None,
+		};
+		self.get_handler(extension)
+	}
 }
 
 pub fn create_app() -> App {
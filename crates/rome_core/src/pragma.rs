@@ -0,0 +1,138 @@
+//! Pragma comments at the top of a file that adjust how Rome treats the file.
+//!
+//! The supported pragmas are:
+//! - `// rome-disable format`, `// rome-disable lint` or `// rome-disable` to turn off
+//!   the capabilities of a file. Several capabilities can be separated by a comma.
+//! - `// rome-language <js|ts|json>` to choose the language of a file, e.g. a script without extension.
+//!
+//! Only the comments before the first token of the file are scanned, a shebang is skipped.
+use crate::file_handlers::{Capabilities, Language};
+
+/// The pragmas found at the top of a file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Pragmas {
+	pub disable_format: bool,
+	pub disable_lint: bool,
+	/// The language chosen by a `rome-language` pragma
+	pub language: Option<Language>,
+}
+
+impl Pragmas {
+	/// Scans the comments at the top of `text` for pragmas.
+	///
+	/// ```
+	/// use rome_core::{file_handlers::Language, pragma::Pragmas};
+	///
+	/// let pragmas = Pragmas::scan("#!/usr/bin/env node\n// rome-disable format\n/* rome-language ts */\nlet a;");
+	/// assert!(pragmas.disable_format);
+	/// assert!(!pragmas.disable_lint);
+	/// assert_eq!(Some(Language::Ts), pragmas.language);
+	/// ```
+	pub fn scan(text: &str) -> Self {
+		let mut pragmas = Pragmas::default();
+		for comment in leading_comments(text) {
+			pragmas.apply_comment(comment.trim());
+		}
+		pragmas
+	}
+
+	/// Turns off the capabilities disabled by the pragmas
+	pub fn restrict(&self, capabilities: Capabilities) -> Capabilities {
+		Capabilities {
+			format: capabilities.format && !self.disable_format,
+			lint: capabilities.lint && !self.disable_lint,
+		}
+	}
+
+	fn apply_comment(&mut self, comment: &str) {
+		if let Some(rest) = strip_pragma(comment, "rome-disable") {
+			if rest.is_empty() {
+				self.disable_format = true;
+				self.disable_lint = true;
+			}
+			for capability in rest.split(',').map(str::trim) {
+				match capability {
+					"format" => self.disable_format = true,
+					"lint" => self.disable_lint = true,
+					_ => {}
+				}
+			}
+		} else if let Some(rest) = strip_pragma(comment, "rome-language") {
+			self.language = match rest {
+				"js" | "javascript" => Some(Language::Js),
+				"ts" | "typescript" => Some(Language::Ts),
+				"json" => Some(Language::Json),
+				_ => self.language,
+			};
+		}
+	}
+}
+
+/// Returns the arguments of the pragma `name` if `comment` is that pragma
+fn strip_pragma<'a>(comment: &'a str, name: &str) -> Option<&'a str> {
+	let rest = comment.strip_prefix(name)?;
+	if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+		Some(rest.trim())
+	} else {
+		None
+	}
+}
+
+/// The content of the comments before the first token of `text`, without the comment delimiters
+fn leading_comments(text: &str) -> impl Iterator<Item = &str> {
+	let mut rest = text.strip_prefix('\u{feff}').unwrap_or(text);
+	if rest.starts_with("#!") {
+		rest = rest.find('\n').map_or("", |end| &rest[end..]);
+	}
+
+	std::iter::from_fn(move || {
+		rest = rest.trim_start();
+		if let Some(comment) = rest.strip_prefix("//") {
+			let end = comment.find('\n').unwrap_or(comment.len());
+			rest = &comment[end..];
+			Some(&comment[..end])
+		} else if let Some(comment) = rest.strip_prefix("/*") {
+			let end = comment.find("*/")?;
+			rest = &comment[end + 2..];
+			Some(&comment[..end])
+		} else {
+			None
+		}
+	})
+}
+
+#[cfg(test)]
+mod test {
+	use super::Pragmas;
+	use crate::file_handlers::{Capabilities, Language};
+
+	#[test]
+	fn scans_only_leading_comments() {
+		let pragmas = Pragmas::scan("let a;\n// rome-disable format");
+		assert_eq!(Pragmas::default(), pragmas);
+
+		let pragmas = Pragmas::scan("\u{feff}/** docs */\n\n// rome-disable lint, format\n");
+		assert!(pragmas.disable_format);
+		assert!(pragmas.disable_lint);
+	}
+
+	#[test]
+	fn ignores_unknown_pragmas() {
+		let pragmas =
+			Pragmas::scan("// rome-disabled format\n// rome-language cobol\n/* rome-disable");
+		assert_eq!(Pragmas::default(), pragmas);
+	}
+
+	#[test]
+	fn language_and_capabilities() {
+		let pragmas = Pragmas::scan("// rome-disable\n// rome-language json");
+		assert_eq!(Some(Language::Json), pragmas.language);
+
+		let capabilities = pragmas.restrict(Capabilities {
+			format: true,
+			lint: true,
+		});
+		assert!(!capabilities.format);
+		assert!(!capabilities.lint);
+	}
+}
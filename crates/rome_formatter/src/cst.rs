@@ -1,8 +1,8 @@
 use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::{
 	ArgList, ArrayPattern, AssignPattern, CallExpr, ForInStmt, ForStmt, ForStmtInit, ForStmtTest,
-	ForStmtUpdate, JsArrayExpression, JsArrowFunctionExpression, JsBlockStatement,
-	JsBooleanLiteralExpression, JsCaseClause, JsCatchClause, JsClassDeclaration,
+	ForStmtUpdate, JsAccessorPropertyClassMember, JsArrayExpression, JsArrowFunctionExpression,
+	JsBlockStatement, JsBooleanLiteralExpression, JsCaseClause, JsCatchClause, JsClassDeclaration,
 	JsConstructorParameterList, JsContinueStatement, JsDebuggerStatement, JsDefaultClause,
 	JsDoWhileStatement, JsEmptyStatement, JsExpressionStatement, JsFinallyClause,
 	JsFunctionDeclaration, JsGetterClassMember, JsIfStatement, JsLabeledStatement,
@@ -11,7 +11,7 @@ use rslint_parser::ast::{
 	JsReturnStatement, JsRoot, JsSequenceExpression, JsSetterClassMember,
 	JsShorthandPropertyObjectMember, JsStringLiteralExpression, JsSwitchStatement, JsTryStatement,
 	JsVariableDeclarationStatement, JsVariableDeclarator, JsWhileStatement, JsWithStatement, Name,
-	SinglePattern,
+	SinglePattern, TsDecorator,
 };
 use rslint_parser::{AstNode, SyntaxKind, SyntaxNode};
 
@@ -170,6 +170,9 @@ impl ToFormatElement for SyntaxNode {
 			SyntaxKind::JS_CLASS_DECLARATION => JsClassDeclaration::cast(self.clone())
 				.unwrap()
 				.to_format_element(formatter),
+			SyntaxKind::TS_DECORATOR => TsDecorator::cast(self.clone())
+				.unwrap()
+				.to_format_element(formatter),
 			SyntaxKind::JS_CONSTRUCTOR_PARAMETER_LIST => {
 				JsConstructorParameterList::cast(self.clone())
 					.unwrap()
@@ -184,6 +187,11 @@ impl ToFormatElement for SyntaxNode {
 			SyntaxKind::JS_PROPERTY_CLASS_MEMBER => JsPropertyClassMember::cast(self.clone())
 				.unwrap()
 				.to_format_element(formatter),
+			SyntaxKind::JS_ACCESSOR_PROPERTY_CLASS_MEMBER => {
+				JsAccessorPropertyClassMember::cast(self.clone())
+					.unwrap()
+					.to_format_element(formatter)
+			}
 
 			_ => todo!(
 				"Implement formatting for the {:?} syntax kind.",
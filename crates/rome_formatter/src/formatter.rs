@@ -4,7 +4,7 @@ use crate::{
 	ToFormatElement,
 };
 use rome_rowan::SyntaxElement;
-use rslint_parser::{AstNode, SyntaxNode, SyntaxToken};
+use rslint_parser::{AstNode, CancellationToken, SyntaxNode, SyntaxToken};
 
 /// Handles the formatting of a CST and stores the options how the CST should be formatted (user preferences).
 /// The formatter is passed to the [ToFormatElement] implementation of every node in the CST so that they
@@ -12,12 +12,43 @@ use rslint_parser::{AstNode, SyntaxNode, SyntaxToken};
 #[derive(Debug, Default)]
 pub struct Formatter {
 	options: FormatOptions,
+	cancellation: Option<CancellationToken>,
 }
 
 impl Formatter {
 	/// Creates a new context that uses the given formatter options
 	pub fn new(options: FormatOptions) -> Self {
-		Self { options }
+		Self {
+			options,
+			cancellation: None,
+		}
+	}
+
+	/// Stops the formatting at the next statement once `cancellation` is cancelled,
+	/// [Formatter::format_root] then returns [FormatError::Cancelled]
+	///
+	/// ```
+	/// use rome_formatter::{FormatError, FormatOptions, Formatter};
+	/// use rslint_parser::{parse_text, CancellationToken};
+	///
+	/// let cancellation = CancellationToken::new();
+	/// let root = parse_text("let a = 1;", 0).syntax();
+	/// let formatter = Formatter::new(FormatOptions::default()).with_cancellation(cancellation.clone());
+	///
+	/// cancellation.cancel();
+	/// assert_eq!(Err(FormatError::Cancelled), formatter.format_root(&root));
+	/// ```
+	pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+		self.cancellation = Some(cancellation);
+		self
+	}
+
+	/// Returns `true` if the formatting has been cancelled and should stop as soon as possible
+	pub fn is_cancelled(&self) -> bool {
+		match &self.cancellation {
+			Some(cancellation) => cancellation.is_cancelled(),
+			None => false,
+		}
 	}
 
 	/// Returns the [FormatOptions] specifying how to format the current CST
@@ -29,6 +60,9 @@ impl Formatter {
 	/// Formats a CST
 	pub fn format_root(self, root: &SyntaxNode) -> FormatResult<Formatted> {
 		let element = self.format_syntax_node(root)?;
+		if self.is_cancelled() {
+			return Err(FormatError::Cancelled);
+		}
 
 		let printer = Printer::new(self.options);
 		Ok(printer.print(&element))
@@ -69,9 +69,10 @@ pub use format_element::{
 pub use printer::Printer;
 pub use printer::PrinterOptions;
 use rome_core::file_handlers::Language;
+use rome_core::pragma::Pragmas;
 use rome_core::App;
 use rome_path::RomePath;
-use rslint_parser::parse_text;
+use rslint_parser::{parse_cancellable, CancellationToken, Cancelled, Syntax};
 
 use std::io::Read;
 use std::str::FromStr;
@@ -95,6 +96,9 @@ pub enum FormatError {
 
 	/// When the ability to format the current file has been turned off on purpose
 	CapabilityDisabled,
+
+	/// When the formatting was cancelled because its result is no longer needed
+	Cancelled,
 }
 
 impl From<SyntaxError> for FormatError {
@@ -105,6 +109,12 @@ impl From<SyntaxError> for FormatError {
 	}
 }
 
+impl From<Cancelled> for FormatError {
+	fn from(_: Cancelled) -> Self {
+		FormatError::Cancelled
+	}
+}
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum IndentStyle {
 	/// Tab
@@ -179,6 +189,16 @@ impl Formatted {
 // TODO: implement me + handle errors
 /// Main function
 pub fn format(rome_path: &mut RomePath, options: FormatOptions) -> FormatResult<Formatted> {
+	format_cancellable(rome_path, options, &CancellationToken::new())
+}
+
+/// Same as [format] but stops and returns [FormatError::Cancelled] once `cancellation` is cancelled,
+/// for example because the file changed again before the formatting completed
+pub fn format_cancellable(
+	rome_path: &mut RomePath,
+	options: FormatOptions,
+	cancellation: &CancellationToken,
+) -> FormatResult<Formatted> {
 	// we assume that file exists
 	let mut file = rome_path.open();
 	let mut buffer = String::new();
@@ -187,11 +207,20 @@ pub fn format(rome_path: &mut RomePath, options: FormatOptions) -> FormatResult<
 		.expect("cannot read the file to format");
 
 	if let Some(handler) = rome_path.get_handler() {
-		if handler.capabilities().format {
-			let result = match handler.language() {
+		let pragmas = Pragmas::scan(&buffer);
+		if pragmas.restrict(handler.capabilities()).format {
+			let result = match pragmas.language.unwrap_or_else(|| handler.language()) {
 				Language::Js => {
-					let parsed_result = parse_text(buffer.as_str(), 0);
-					Formatter::new(options).format_root(&parsed_result.syntax())
+					let syntax = rome_path
+						.extension()
+						.and_then(|extension| extension.to_str())
+						.and_then(Syntax::from_extension)
+						.unwrap_or_default();
+					let parsed_result =
+						parse_cancellable(buffer.as_str(), 0, syntax, cancellation)?;
+					Formatter::new(options)
+						.with_cancellation(cancellation.clone())
+						.format_root(&parsed_result.syntax())
 				}
 				Language::Json => {
 					let element = tokenize_json(buffer.as_str());
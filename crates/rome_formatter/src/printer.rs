@@ -1,5 +1,6 @@
 use crate::format_element::{ConditionalGroupContent, Group, GroupPrintMode, LineMode};
 use crate::{FormatElement, FormatOptions, Formatted, IndentStyle};
+use rslint_parser::SyntaxKind;
 
 /// Options that affect how the [Printer] prints the format tokens
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -135,6 +136,9 @@ impl Printer {
 				if self.state.pending_spaces > 0 {
 					self.print_str(" ".repeat(self.state.pending_spaces as usize).as_str());
 					self.state.pending_spaces = 0;
+				} else if needs_separating_space(&self.state.buffer, token) {
+					// Printing the tokens next to each other would change the meaning of the program
+					self.print_str(" ");
 				}
 
 				self.print_str(token);
@@ -302,6 +306,51 @@ impl Printer {
 	}
 }
 
+/// Character sequences that aren't tokens but start a comment when printed next to each other
+const COMMENT_STARTS: [&str; 2] = ["//", "/*"];
+
+/// Returns `true` if printing `token` directly after the `printed` text lexes differently
+/// than printing the two separated by a space, e.g. `a` followed by `in` becomes `ain`
+/// and `+` followed by `+` becomes `++`.
+fn needs_separating_space(printed: &str, token: &str) -> bool {
+	let (last, first) = match (printed.chars().next_back(), token.chars().next()) {
+		(Some(last), Some(first)) => (last, first),
+		_ => return false,
+	};
+
+	if is_word_char(last) && is_word_char(first) {
+		return true;
+	}
+
+	// `1 .toString()` must not become the number `1.`
+	if first == '.' && ends_with_integer(printed) {
+		return true;
+	}
+
+	SyntaxKind::PUNCTUATORS
+		.iter()
+		.chain(COMMENT_STARTS.iter())
+		// Consecutive `>` close nested type arguments, e.g. `A<B<C>>`, and are never joined by the parser
+		.filter(|punctuator| !punctuator.bytes().all(|byte| byte == b'>'))
+		.any(|punctuator| {
+			(1..punctuator.len()).any(|split| {
+				let (head, tail) = punctuator.split_at(split);
+				printed.ends_with(head) && token.starts_with(tail)
+			})
+		})
+}
+
+fn is_word_char(c: char) -> bool {
+	c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Returns `true` if `printed` ends with a decimal integer literal, e.g. `1` or `1_000` but not `a1`, `_1` or `1.5`
+fn ends_with_integer(printed: &str) -> bool {
+	let rest = printed.trim_end_matches(|c: char| c.is_ascii_digit() || c == '_');
+	printed[rest.len()..].starts_with(|c: char| c.is_ascii_digit())
+		&& !rest.ends_with(|c: char| is_word_char(c) || c == '.')
+}
+
 /// Printer state that is global to all elements.
 /// Stores the result of the print operation (buffer and mappings) and at what
 /// position the printer currently is.
@@ -580,6 +629,55 @@ two lines`,
 		assert_eq!("[\n\t'a',\n\t\'b',\n\t\'c',\n\t'd',\n]", result.code());
 	}
 
+	#[test]
+	fn it_separates_tokens_that_would_join() {
+		let element = format_elements![
+			token("a"),
+			token("in"),
+			token("b"),
+			token("+"),
+			token("+"),
+			token("c"),
+			token("/"),
+			token("/d/"),
+			token(";"),
+			token("1"),
+			token(".toString"),
+			token("!"),
+			token("="),
+			token("x1"),
+			token(".y"),
+			token(";"),
+			token("1_000"),
+			token(".toString"),
+			token(";"),
+			token("_1"),
+			token(".y"),
+		];
+
+		assert_eq!(
+			"a in b+ +c/ /d/;1 .toString! =x1.y;1_000 .toString;_1.y",
+			print_element(element).code()
+		);
+	}
+
+	#[test]
+	fn it_keeps_closing_type_arguments_together() {
+		let element = format_elements![
+			token("A"),
+			token("<"),
+			token("B"),
+			token("<"),
+			token("C"),
+			token(">"),
+			token(">"),
+			token("("),
+			token(")"),
+		];
+
+		assert_eq!("A<B<C>>()", print_element(element).code());
+	}
+
 	fn create_array_element(items: Vec<FormatElement>) -> FormatElement {
 		let separator = format_elements![token(","), soft_line_break_or_space(),];
 
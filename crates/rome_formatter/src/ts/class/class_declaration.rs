@@ -1,3 +1,4 @@
+use super::decorator::format_decorators;
 use crate::{
 	block_indent, empty_element, format_elements, group_elements, hard_line_break, join_elements,
 	space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
@@ -13,6 +14,7 @@ impl ToFormatElement for JsClassDeclaration {
 		};
 
 		Ok(format_elements![
+			format_decorators(formatter, self.decorators(), hard_line_break())?,
 			formatter.format_token(&self.class_token()?)?,
 			space_token(),
 			formatter.format_node(self.id()?)?,
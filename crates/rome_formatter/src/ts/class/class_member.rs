@@ -11,11 +11,17 @@ impl ToFormatElement for JsAnyClassMember {
 			JsAnyClassMember::JsPropertyClassMember(class_prop) => {
 				class_prop.to_format_element(formatter)
 			}
+			JsAnyClassMember::JsAccessorPropertyClassMember(accessor_prop) => {
+				accessor_prop.to_format_element(formatter)
+			}
 			JsAnyClassMember::JsConstructorClassMember(constructor) => {
 				constructor.to_format_element(formatter)
 			}
 			JsAnyClassMember::JsGetterClassMember(getter) => getter.to_format_element(formatter),
 			JsAnyClassMember::JsSetterClassMember(setter) => setter.to_format_element(formatter),
+			JsAnyClassMember::JsStaticInitializationBlockClassMember(block) => {
+				block.to_format_element(formatter)
+			}
 			JsAnyClassMember::JsUnknownMember(_) => todo!(),
 			JsAnyClassMember::TsIndexSignature(_) => todo!(),
 		}
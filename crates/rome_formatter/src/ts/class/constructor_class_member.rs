@@ -38,6 +38,9 @@ impl ToFormatElement for JsAnyConstructorParameter {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		match self {
 			JsAnyConstructorParameter::TsConstructorParam(_) => todo!(),
+			JsAnyConstructorParameter::TsDecoratedParameter(parameter) => {
+				parameter.to_format_element(formatter)
+			}
 			JsAnyConstructorParameter::Pattern(pattern) => pattern.to_format_element(formatter),
 		}
 	}
@@ -0,0 +1,34 @@
+use crate::{
+	concat_elements, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{AstNodeList, TsDecoratedParameter, TsDecorator};
+
+impl ToFormatElement for TsDecorator {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.at_token()?)?,
+			formatter.format_node(self.expression()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsDecoratedParameter {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			format_decorators(formatter, self.decorators(), space_token())?,
+			formatter.format_node(self.parameter()?)?
+		])
+	}
+}
+
+/// Formats the decorators of a class, a class member, or a parameter, each decorator is followed by `separator`
+pub(crate) fn format_decorators(
+	formatter: &Formatter,
+	decorators: AstNodeList<TsDecorator>,
+	separator: FormatElement,
+) -> FormatResult<FormatElement> {
+	Ok(concat_elements(formatter.format_nodes(decorators)?.map(
+		|decorator| format_elements![decorator, separator.clone()],
+	)))
+}
@@ -1,3 +1,4 @@
+use super::decorator::format_decorators;
 use crate::{
 	format_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
@@ -6,6 +7,7 @@ use rslint_parser::ast::JsGetterClassMember;
 impl ToFormatElement for JsGetterClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		Ok(format_elements![
+			format_decorators(formatter, self.decorators(), space_token())?,
 			formatter.format_token(&self.get_token()?)?,
 			space_token(),
 			formatter.format_node(self.name()?)?,
@@ -1,3 +1,4 @@
+use super::decorator::format_decorators;
 use crate::{
 	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
 	ToFormatElement,
@@ -15,6 +16,7 @@ impl ToFormatElement for JsMethodClassMember {
 		let params = formatter.format_node(self.parameter_list()?)?;
 		let body = formatter.format_node(self.body()?)?;
 		Ok(format_elements![
+			format_decorators(formatter, self.decorators(), space_token())?,
 			private_token,
 			name,
 			params,
@@ -1,10 +1,13 @@
+mod accessor_property_class_member;
 mod class_declaration;
 mod class_member;
 mod class_member_name;
 mod constructor_class_member;
+mod decorator;
 mod empty_class_member;
 mod extends_clause;
 mod getter_class_member;
 mod method_class_member;
 mod property_class_member;
 mod setter_class_member;
+mod static_initialization_block_class_member;
@@ -1,3 +1,4 @@
+use super::decorator::format_decorators;
 use crate::{
 	empty_element, format_elements, space_token, token, FormatElement, FormatResult, Formatter,
 	ToFormatElement,
@@ -19,6 +20,7 @@ impl ToFormatElement for JsPropertyClassMember {
 		};
 
 		Ok(format_elements![
+			format_decorators(formatter, self.decorators(), space_token())?,
 			static_token,
 			formatter.format_node(self.name()?)?,
 			init,
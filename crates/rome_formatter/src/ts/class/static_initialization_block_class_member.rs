@@ -0,0 +1,18 @@
+use crate::ts::statements::format_statements;
+use crate::{
+	block_indent, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::JsStaticInitializationBlockClassMember;
+
+impl ToFormatElement for JsStaticInitializationBlockClassMember {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.static_token()?)?,
+			space_token(),
+			formatter.format_token(&self.l_curly_token()?)?,
+			block_indent(format_statements(self.statements(), formatter)),
+			formatter.format_token(&self.r_curly_token()?)?
+		])
+	}
+}
@@ -41,6 +41,7 @@ impl ToFormatElement for JsAnyExpression {
 			JsAnyExpression::TsNonNull(_) => todo!(),
 			JsAnyExpression::TsAssertion(_) => todo!(),
 			JsAnyExpression::TsConstAssertion(_) => todo!(),
+			JsAnyExpression::TsInstantiationExpr(expr) => expr.to_format_element(formatter),
 			JsAnyExpression::JsPreUpdateExpression(_) => todo!(),
 			JsAnyExpression::JsPostUpdateExpression(_) => todo!(),
 			JsAnyExpression::JsUnknownExpression(_) => todo!(),
@@ -0,0 +1,14 @@
+use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::{ast::TsInstantiationExpr, AstNode};
+
+impl ToFormatElement for TsInstantiationExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		// The formatter doesn't support types yet, keep the type arguments as they are
+		let type_args = formatter.format_raw(self.type_args()?.syntax());
+
+		Ok(format_elements![
+			formatter.format_node(self.expr()?)?,
+			type_args.trim_start().trim_end()
+		])
+	}
+}
@@ -3,20 +3,14 @@ use rslint_parser::ast::{
 	JsAnyLiteralExpression, JsBigIntLiteralExpression, JsBooleanLiteralExpression,
 	JsNullLiteralExpression, JsNumberLiteralExpression, JsStringLiteralExpression,
 };
+use rslint_parser::util::quotes::{convert_string_literal, QuoteStyle};
 
 impl ToFormatElement for JsStringLiteralExpression {
 	fn to_format_element(&self, _: &Formatter) -> FormatResult<FormatElement> {
 		let value_token = self.value_token()?;
 		let quoted = value_token.text_trimmed();
 
-		// uses single quotes
-		if quoted.starts_with('\'') {
-			let s = &quoted[1..quoted.len() - 1];
-			let s = format!("\"{}\"", s);
-			Ok(token(s))
-		} else {
-			Ok(token(quoted))
-		}
+		Ok(token(convert_string_literal(quoted, QuoteStyle::Double)))
 	}
 }
 
@@ -2,6 +2,7 @@ mod array_expr;
 mod arrow_expr;
 mod call_expression;
 mod expression;
+mod instantiation_expression;
 mod literal_expression;
 mod object_expression;
 mod reference_identifier_expression;
@@ -2,7 +2,7 @@ use crate::{
 	format_elements, group_elements, join_elements, soft_indent, soft_line_break_or_space, token,
 	FormatElement, FormatResult, Formatter, ToFormatElement,
 };
-use rslint_parser::ast::JsParameterList;
+use rslint_parser::ast::{JsAnyParameter, JsParameterList, JsRestParameter};
 
 impl ToFormatElement for JsParameterList {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -18,3 +18,24 @@ impl ToFormatElement for JsParameterList {
 		]))
 	}
 }
+
+impl ToFormatElement for JsAnyParameter {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			JsAnyParameter::Pattern(pattern) => pattern.to_format_element(formatter),
+			JsAnyParameter::JsRestParameter(parameter) => parameter.to_format_element(formatter),
+			JsAnyParameter::TsDecoratedParameter(parameter) => {
+				parameter.to_format_element(formatter)
+			}
+		}
+	}
+}
+
+impl ToFormatElement for JsRestParameter {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.dotdotdot_token()?)?,
+			formatter.format_node(self.binding()?)?
+		])
+	}
+}
@@ -1,6 +1,7 @@
 use crate::ts::statements::format_statements;
 use crate::{
-	format_elements, hard_line_break, FormatElement, FormatResult, Formatter, ToFormatElement,
+	format_elements, hard_line_break, token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
 };
 use rslint_parser::ast::JsRoot;
 
@@ -15,6 +16,12 @@ impl ToFormatElement for JsRoot {
 
 		elements.push(format_statements(self.statements(), formatter));
 
+		// Magic comments must stay on the last lines of the file for browsers and bundlers to pick them up
+		for comment in self.magic_comments() {
+			elements.push(hard_line_break());
+			elements.push(token(comment.text()));
+		}
+
 		Ok(format_elements![
 			concat_elements(elements),
 			hard_line_break()
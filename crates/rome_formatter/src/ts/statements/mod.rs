@@ -28,10 +28,14 @@ pub fn format_statements(
 ) -> FormatElement {
 	join_elements(
 		hard_line_break(),
-		stmts.iter().map(|stmt| {
-			formatter
-				.format_node(stmt.clone())
-				.unwrap_or_else(|_| formatter.format_raw(stmt.syntax()).trim_start().trim_end())
-		}),
+		// Skip the remaining statements if the formatting has been cancelled
+		stmts
+			.iter()
+			.take_while(|_| !formatter.is_cancelled())
+			.map(|stmt| {
+				formatter
+					.format_node(stmt.clone())
+					.unwrap_or_else(|_| formatter.format_raw(stmt.syntax()).trim_start().trim_end())
+			}),
 	)
 }
@@ -35,5 +35,14 @@ pub fn run(spec_input_file: &str, expected_file: &str) {
 	let result = format_file(file_path, FormatOptions::default(), &app);
 	let expected_output = fs::read_to_string(expected_file).unwrap();
 
-	assert_eq!(&expected_output, result.code());
+	assert_eq!(
+		normalize_line_endings(&expected_output),
+		normalize_line_endings(result.code())
+	);
+}
+
+/// Git may check out the specs with `\r\n` line endings on Windows, compare the outputs
+/// independently of the line endings so that the specs pass on every platform.
+fn normalize_line_endings(text: &str) -> String {
+	text.replace("\r\n", "\n")
 }
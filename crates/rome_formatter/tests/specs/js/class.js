@@ -25,4 +25,9 @@ class Foo extends Boar {
 	new_prop = 5
 
 	double_semicolon = [5,3,4];;
+
+	static {   init()
+	}
+
+	static {}
 }
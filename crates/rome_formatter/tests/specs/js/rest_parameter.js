@@ -0,0 +1,4 @@
+function foo(a,   ...rest) {}
+function bar(  ...   args  ) {
+	return args;
+}
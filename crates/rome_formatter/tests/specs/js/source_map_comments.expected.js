@@ -0,0 +1,6 @@
+let a = 1;
+function foo() {
+	return a;
+}
+//# sourceURL=app.js
+//# sourceMappingURL=app.js.map
@@ -0,0 +1,5 @@
+let a = 1;
+//# sourceURL=app.js
+function foo() { return a; }
+
+//# sourceMappingURL=app.js.map
@@ -0,0 +1,2 @@
+let a = f<number>;
+let b = g  <string, Array<number>>;
@@ -3,8 +3,13 @@
 //! give additional information around the the file that holds:
 //! - the [FileHandlers] for the specific file
 //! - shortcuts to open/write to the file
-use rome_core::{file_handlers::ExtensionHandler, App};
-use std::{fs::File, io::Write, ops::Deref, path::PathBuf};
+use rome_core::{file_handlers::ExtensionHandler, pragma::Pragmas, App};
+use std::{
+	fs::{self, File},
+	io::Write,
+	ops::Deref,
+	path::PathBuf,
+};
 
 pub struct RomePath<'handler> {
 	file: PathBuf,
@@ -54,6 +59,10 @@ impl<'handler> RomePath<'handler> {
 	/// ```
 	pub fn deduce_handler(mut self, app: &'handler App) -> Self {
 		if self.extension().is_none() {
+			// Files without extension can choose their language with a `rome-language` pragma
+			if let Some(language) = self.read_pragmas().language {
+				self.handler = app.get_handler_for_language(language);
+			}
 			return self;
 		}
 		let extension = self.extension().unwrap().to_str().unwrap();
@@ -74,6 +83,13 @@ impl<'handler> RomePath<'handler> {
 		File::open(&self.file).expect("cannot open the file to format")
 	}
 
+	/// Reads the pragmas at the top of the file, see [Pragmas]
+	pub fn read_pragmas(&self) -> Pragmas {
+		fs::read_to_string(&self.file)
+			.map(|content| Pragmas::scan(&content))
+			.unwrap_or_default()
+	}
+
 	/// Accepts a file opened in read mode and saves into it
 	pub fn save(&mut self, content: &str) -> Result<(), std::io::Error> {
 		let mut file_to_write = File::create(&self.file).unwrap();
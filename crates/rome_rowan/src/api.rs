@@ -1,10 +1,15 @@
-use std::{fmt, iter, marker::PhantomData, ops::Range};
-
-use crate::cursor::SyntaxSlot;
+use std::{borrow::Cow, fmt, io, iter, marker::PhantomData, ops::Range};
+
+use crate::fingerprint::{self, Fingerprint};
+use crate::green::{GreenElement, GreenTokenTrivia};
+use crate::green_view::{GreenNodeView, GreenTokenView};
+use crate::memory_stats::MemoryStats;
+use crate::node_map::SyntaxNodeKey;
+use crate::validate::{self, InvariantViolation};
 use crate::{
 	cursor::{self},
-	Direction, GreenNode, NodeOrToken, SyntaxKind, SyntaxText, TextRange, TextSize, TokenAtOffset,
-	WalkEvent,
+	Direction, GreenNode, GreenNodeData, GreenTokenData, NodeOrToken, SyntaxKind, SyntaxText,
+	TextRange, TextSize, TokenAtOffset, WalkEvent,
 };
 
 pub trait Language: Sized + Clone + Copy + fmt::Debug + Eq + Ord + std::hash::Hash {
@@ -13,6 +18,33 @@ pub trait Language: Sized + Clone + Copy + fmt::Debug + Eq + Ord + std::hash::Ha
 	fn kind_from_raw(raw: SyntaxKind) -> Self::Kind;
 	fn kind_to_raw(kind: Self::Kind) -> SyntaxKind;
 	fn list_kind() -> Self::Kind;
+
+	/// Returns the name of the slot `index` of nodes of the kind `kind`, e.g. `"test"` for the
+	/// condition of an if statement. Returns [None] by default, for languages without named slots.
+	fn slot_name(kind: Self::Kind, index: usize) -> Option<&'static str> {
+		let _ = (kind, index);
+		None
+	}
+
+	/// Returns `true` for the kinds of tokens that are trivia, like whitespace or comments, for
+	/// languages that represent trivia as tokens. Returns `false` by default.
+	fn is_trivia(kind: Self::Kind) -> bool {
+		let _ = kind;
+		false
+	}
+
+	/// Returns `true` for the kinds of keyword tokens, e.g. to highlight them in generic tools.
+	/// Returns `false` by default.
+	fn is_keyword(kind: Self::Kind) -> bool {
+		let _ = kind;
+		false
+	}
+
+	/// Returns `true` for the kinds of punctuation tokens. Returns `false` by default.
+	fn is_punct(kind: Self::Kind) -> bool {
+		let _ = kind;
+		false
+	}
 }
 
 #[derive(Debug, Default, Hash, Copy, Eq, Ord, PartialEq, PartialOrd, Clone)]
@@ -38,6 +70,11 @@ impl Language for RawLanguage {
 pub enum TriviaPiece {
 	Whitespace(usize),
 	Comments(usize),
+	/// A run of line breaks only (`\n`, `\r\n`, ...). The value is the length of the text, not the number of lines.
+	Newline(usize),
+	/// A token that the parser skipped during error recovery and attached to the next token
+	/// to keep the tree lossless without introducing an error node.
+	Skipped(usize),
 }
 
 impl TriviaPiece {
@@ -46,12 +83,63 @@ impl TriviaPiece {
 		match self {
 			TriviaPiece::Whitespace(n) => (*n as u32).into(),
 			TriviaPiece::Comments(n) => (*n as u32).into(),
+			TriviaPiece::Newline(n) => (*n as u32).into(),
+			TriviaPiece::Skipped(n) => (*n as u32).into(),
 		}
 	}
 }
 
 pub struct SyntaxTriviaPieceWhitespace<L: Language>(SyntaxTriviaPiece<L>);
 pub struct SyntaxTriviaPieceComments<L: Language>(SyntaxTriviaPiece<L>);
+pub struct SyntaxTriviaPieceNewline<L: Language>(SyntaxTriviaPiece<L>);
+pub struct SyntaxTriviaPieceSkipped<L: Language>(SyntaxTriviaPiece<L>);
+
+impl<L: Language> SyntaxTriviaPieceComments<L> {
+	pub fn text(&self) -> &str {
+		self.0.text()
+	}
+
+	pub fn text_range(&self) -> TextRange {
+		self.0.text_range()
+	}
+
+	/// Returns the trivia piece of this comment
+	pub fn as_piece(&self) -> &SyntaxTriviaPiece<L> {
+		&self.0
+	}
+}
+
+impl<L: Language> SyntaxTriviaPieceNewline<L> {
+	pub fn text(&self) -> &str {
+		self.0.text()
+	}
+
+	pub fn text_range(&self) -> TextRange {
+		self.0.text_range()
+	}
+
+	/// Returns the number of line breaks of this piece, `\r\n` counts as a single line break
+	pub fn lines(&self) -> usize {
+		count_lines(self.text())
+	}
+}
+
+/// Returns the number of line breaks in `text`. `\r\n` counts as a single line break.
+fn count_lines(text: &str) -> usize {
+	let mut lines = 0;
+	let mut chars = text.chars().peekable();
+	while let Some(chr) = chars.next() {
+		match chr {
+			'\r' => {
+				chars.next_if_eq(&'\n');
+				lines += 1;
+			}
+			'\n' | '\u{2028}' | '\u{2029}' => lines += 1,
+			_ => {}
+		}
+	}
+	lines
+}
 
 /// [SyntaxTriviaPiece] gives access to the most granular information about the trivia
 /// that was specified by the lexer at the token creation time.
@@ -99,7 +187,7 @@ impl<L: Language> SyntaxTriviaPiece<L> {
 	/// ```
 	pub fn text(&self) -> &str {
 		let txt = self.raw.text();
-		let start = self.offset - self.raw.offset();
+		let start = self.offset - self.raw.text_range().start();
 		let end = start + self.text_len();
 
 		&txt[start.into()..end.into()]
@@ -202,6 +290,81 @@ impl<L: Language> SyntaxTriviaPiece<L> {
 			_ => None,
 		}
 	}
+
+	/// Cast this trivia piece to [SyntaxTriviaPieceNewline].
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// use std::iter::Iterator;
+	/// let mut node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "\n\t let",
+	///         vec![TriviaPiece::Newline(1), TriviaPiece::Whitespace(2)],
+	///         vec![],
+	///     );
+	/// });
+	/// let pieces: Vec<_> = node.first_leading_trivia().unwrap().pieces().collect();
+	/// assert!(pieces[0].as_newline().is_some());
+	/// assert!(pieces[1].as_newline().is_none());
+	/// ```
+	pub fn as_newline(&self) -> Option<SyntaxTriviaPieceNewline<L>> {
+		match &self.trivia {
+			TriviaPiece::Newline(_) => Some(SyntaxTriviaPieceNewline(self.clone())),
+			_ => None,
+		}
+	}
+
+	/// Cast this trivia piece to [SyntaxTriviaPieceSkipped].
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// use std::iter::Iterator;
+	/// let mut node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "@ let",
+	///         vec![TriviaPiece::Skipped(1), TriviaPiece::Whitespace(1)],
+	///         vec![],
+	///     );
+	/// });
+	/// let pieces: Vec<_> = node.first_leading_trivia().unwrap().pieces().collect();
+	/// assert!(pieces[0].as_skipped().is_some());
+	/// assert!(pieces[1].as_skipped().is_none());
+	/// ```
+	pub fn as_skipped(&self) -> Option<SyntaxTriviaPieceSkipped<L>> {
+		match &self.trivia {
+			TriviaPiece::Skipped(_) => Some(SyntaxTriviaPieceSkipped(self.clone())),
+			_ => None,
+		}
+	}
+
+	/// Returns the number of line breaks inside this trivia piece. `\r\n` counts as one line break.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// use std::iter::Iterator;
+	/// let mut node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "\r\n\n\t let",
+	///         vec![TriviaPiece::Newline(3), TriviaPiece::Whitespace(2)],
+	///         vec![],
+	///     );
+	/// });
+	/// let pieces: Vec<_> = node.first_leading_trivia().unwrap().pieces().collect();
+	/// assert_eq!(2, pieces[0].lines());
+	/// assert_eq!(0, pieces[1].lines());
+	/// ```
+	pub fn lines(&self) -> usize {
+		match &self.trivia {
+			TriviaPiece::Newline(_) | TriviaPiece::Whitespace(_) => count_lines(self.text()),
+			TriviaPiece::Comments(_) | TriviaPiece::Skipped(_) => 0,
+		}
+	}
 }
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -224,6 +387,253 @@ pub struct SyntaxToken<L: Language> {
 
 pub type SyntaxElement<L> = NodeOrToken<SyntaxNode<L>, SyntaxToken<L>>;
 
+/// A reference to a node that doesn't keep the node or its tree alive, created by [SyntaxNode::downgrade].
+///
+/// A [SyntaxNode] keeps all its ancestors and the green tree alive. Caches that store nodes for a
+/// long time can store weak nodes instead, which only keep a small allocation for the root of the tree
+/// alive. A weak node stores the path from the root to the node and can be upgraded as long as any node
+/// of the tree is alive, even if no handle to the node itself is. A weak node of a mutable tree can no
+/// longer be upgraded once a node of the tree gained or lost a child, because the node may be at another
+/// path after the edit.
+///
+/// ```
+/// use rome_rowan::parse_sexpr;
+///
+/// let root = parse_sexpr("(0 (1 (2 'a')) (1 (2 'b')))").unwrap();
+/// let weak = root.last_child().unwrap().downgrade();
+///
+/// // The child is re-created from the root, which keeps the tree alive
+/// assert_eq!(root.last_child(), weak.upgrade());
+///
+/// drop(root);
+/// assert!(weak.upgrade().is_none());
+/// ```
+#[derive(Clone)]
+pub struct WeakSyntaxNode<L: Language> {
+	raw: cursor::WeakSyntaxNode,
+	_p: PhantomData<L>,
+}
+
+impl<L: Language> WeakSyntaxNode<L> {
+	/// Returns the node if its tree is still alive and, for a mutable tree, hasn't changed its shape since
+	/// the node was downgraded
+	pub fn upgrade(&self) -> Option<SyntaxNode<L>> {
+		self.raw.upgrade().map(SyntaxNode::from)
+	}
+
+	/// Returns `true` if the node is still alive and [WeakSyntaxNode::upgrade] returns it
+	pub fn is_alive(&self) -> bool {
+		self.raw.is_alive()
+	}
+}
+
+impl<L: Language> fmt::Debug for WeakSyntaxNode<L> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.upgrade() {
+			Some(node) => f.debug_tuple("WeakSyntaxNode").field(&node).finish(),
+			None => f.write_str("WeakSyntaxNode(<dropped>)"),
+		}
+	}
+}
+
+/// An error recorded on a node when building the tree, see [TreeBuilder::error](crate::TreeBuilder::error).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NodeError {
+	range: TextRange,
+	message: String,
+}
+
+impl NodeError {
+	/// The range of the error in the tree
+	pub fn range(&self) -> TextRange {
+		self.range
+	}
+
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+}
+
+/// What [SyntaxNode::text_with_trivia_filter] writes for a trivia piece.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriviaFilter {
+	/// Write the text of the piece
+	Keep,
+	/// Don't write the piece
+	Remove,
+	/// Write the given text instead of the piece, e.g. a single space instead of a whitespace piece
+	Replace(&'static str),
+}
+
+/// The text of a node without its comments, see [SyntaxNode::text_without_comments].
+#[derive(Clone, Copy, Debug)]
+pub struct TextWithoutComments<'a, L: Language> {
+	node: &'a SyntaxNode<L>,
+}
+
+impl<L: Language> TextWithoutComments<'_, L> {
+	/// Writes the text into `writer` without collecting it into a [String]
+	pub fn write_to<W>(&self, writer: &mut W) -> io::Result<()>
+	where
+		W: io::Write + ?Sized,
+	{
+		let mut adapter = IoWriter {
+			writer,
+			error: None,
+		};
+
+		match self.for_each_chunk(|chunk| fmt::Write::write_str(&mut adapter, chunk)) {
+			Ok(()) => Ok(()),
+			Err(fmt::Error) => Err(adapter.error.unwrap_or_else(|| io::ErrorKind::Other.into())),
+		}
+	}
+
+	/// Calls `f` with the chunks of the text in order
+	fn for_each_chunk<F>(&self, mut f: F) -> fmt::Result
+	where
+		F: FnMut(&str) -> fmt::Result,
+	{
+		// Whether the last written text was a whitespace or newline piece
+		let mut after_whitespace = false;
+		let write_trivia = |f: &mut F, trivia: SyntaxTrivia<L>, after_whitespace: &mut bool| {
+			for piece in trivia.pieces() {
+				match piece.trivia {
+					TriviaPiece::Comments(_) => {}
+					TriviaPiece::Whitespace(_) | TriviaPiece::Newline(_) => {
+						if !*after_whitespace {
+							f(piece.text())?;
+						}
+						*after_whitespace = true;
+					}
+					TriviaPiece::Skipped(_) => {
+						f(piece.text())?;
+						*after_whitespace = false;
+					}
+				}
+			}
+			Ok(())
+		};
+
+		for token in self.node.descendants_tokens() {
+			write_trivia(&mut f, token.leading_trivia(), &mut after_whitespace)?;
+			let text = token.text_trimmed();
+			if !text.is_empty() {
+				f(text)?;
+				after_whitespace = false;
+			}
+			write_trivia(&mut f, token.trailing_trivia(), &mut after_whitespace)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<L: Language> fmt::Display for TextWithoutComments<'_, L> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.for_each_chunk(|chunk| f.write_str(chunk))
+	}
+}
+
+impl<L: Language> PartialEq<str> for TextWithoutComments<'_, L> {
+	fn eq(&self, mut rhs: &str) -> bool {
+		self.for_each_chunk(|chunk| {
+			rhs = rhs.strip_prefix(chunk).ok_or(fmt::Error)?;
+			Ok(())
+		})
+		.is_ok() && rhs.is_empty()
+	}
+}
+
+impl<L: Language> PartialEq<&'_ str> for TextWithoutComments<'_, L> {
+	fn eq(&self, rhs: &&str) -> bool {
+		self == *rhs
+	}
+}
+
+impl<L: Language> PartialEq<TextWithoutComments<'_, L>> for &'_ str {
+	fn eq(&self, rhs: &TextWithoutComments<'_, L>) -> bool {
+		rhs == self
+	}
+}
+
+impl<L: Language> PartialEq for TextWithoutComments<'_, L> {
+	fn eq(&self, other: &Self) -> bool {
+		*self == *other.to_string()
+	}
+}
+
+impl<L: Language> Eq for TextWithoutComments<'_, L> {}
+
+/// Writes formatted text into an [io::Write], keeping the io error that `fmt::Write` can't return
+struct IoWriter<'a, W: io::Write + ?Sized> {
+	writer: &'a mut W,
+	error: Option<io::Error>,
+}
+
+impl<W: io::Write + ?Sized> fmt::Write for IoWriter<'_, W> {
+	fn write_str(&mut self, text: &str) -> fmt::Result {
+		self.writer.write_all(text.as_bytes()).map_err(|error| {
+			self.error = Some(error);
+			fmt::Error
+		})
+	}
+}
+
+/// The part of a token an offset points into, see [SyntaxToken::offset_position].
+#[derive(Clone, Debug)]
+pub enum TokenOffsetPosition<L: Language> {
+	/// The offset is inside of a piece of the leading trivia
+	LeadingTrivia(SyntaxTriviaPiece<L>),
+	/// The offset is inside of the token text, excluding its trivia
+	Trimmed,
+	/// The offset is inside of a piece of the trailing trivia
+	TrailingTrivia(SyntaxTriviaPiece<L>),
+}
+
+/// The element covering a range, see [SyntaxNode::covering_range_detail].
+#[derive(Clone, Debug)]
+pub enum CoveringElement<L: Language> {
+	Node(SyntaxNode<L>),
+	Token(SyntaxToken<L>),
+	/// The range is inside of a single trivia piece of the token
+	Trivia(SyntaxToken<L>, SyntaxTriviaPiece<L>),
+}
+
+impl<L: Language> CoveringElement<L> {
+	/// Returns the covering node or token, the owning token if the range is inside of trivia
+	pub fn element(&self) -> SyntaxElement<L> {
+		match self {
+			CoveringElement::Node(node) => NodeOrToken::Node(node.clone()),
+			CoveringElement::Token(token) | CoveringElement::Trivia(token, _) => {
+				NodeOrToken::Token(token.clone())
+			}
+		}
+	}
+
+	pub fn trivia_piece(&self) -> Option<&SyntaxTriviaPiece<L>> {
+		match self {
+			CoveringElement::Trivia(_, piece) => Some(piece),
+			_ => None,
+		}
+	}
+}
+
+impl<L: Language> TokenOffsetPosition<L> {
+	/// Returns the trivia piece covering the offset, if the offset isn't inside of the token text
+	pub fn trivia_piece(&self) -> Option<&SyntaxTriviaPiece<L>> {
+		match self {
+			TokenOffsetPosition::LeadingTrivia(piece)
+			| TokenOffsetPosition::TrailingTrivia(piece) => Some(piece),
+			TokenOffsetPosition::Trimmed => None,
+		}
+	}
+
+	/// Returns `true` if the offset is inside of a comment
+	pub fn is_comment(&self) -> bool {
+		matches!(self.trivia_piece(), Some(piece) if piece.as_comments().is_some())
+	}
+}
+
 impl<L: Language> fmt::Debug for SyntaxNode<L> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		if f.alternate() {
@@ -235,16 +645,18 @@ impl<L: Language> fmt::Debug for SyntaxNode<L> {
 							write!(f, "  ")?;
 						}
 						match element {
-							SyntaxSlot::Node(node) => {
+							cursor::SyntaxSlot::Node(node) => {
 								writeln!(f, "{}: {:?}", node.index(), SyntaxNode::<L>::from(node))?
 							}
-							SyntaxSlot::Token(token) => writeln!(
+							cursor::SyntaxSlot::Token(token) => writeln!(
 								f,
 								"{}: {:?}",
 								token.index(),
 								SyntaxToken::<L>::from(token)
 							)?,
-							SyntaxSlot::Empty { index, .. } => writeln!(f, "{}: (empty)", index)?,
+							cursor::SyntaxSlot::Empty { index, .. } => {
+								writeln!(f, "{}: (empty)", index)?
+							}
 						}
 						level += 1;
 					}
@@ -295,6 +707,23 @@ fn print_debug_trivia_piece<L: Language>(
 			print_debug_str(piece.text(), f)?;
 			write!(f, ")")
 		}
+		TriviaPiece::Newline(_) => {
+			write!(f, "Newline(")?;
+			print_debug_str(piece.text(), f)?;
+			write!(f, ")")
+		}
+		TriviaPiece::Skipped(_) => {
+			write!(f, "Skipped(")?;
+			print_debug_str(piece.text(), f)?;
+			write!(f, ")")
+		}
+	}
+}
+
+impl<L: Language> fmt::Debug for SyntaxTriviaPiece<L> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		print_debug_trivia_piece(self.clone(), f)?;
+		write!(f, "@{:?}", self.text_range())
 	}
 }
 
@@ -366,8 +795,14 @@ impl<L: Language> Iterator for SyntaxTriviaPiecesIterator<L> {
 			_p: PhantomData,
 		})
 	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
 }
 
+impl<L: Language> ExactSizeIterator for SyntaxTriviaPiecesIterator<L> {}
+
 impl<L: Language> SyntaxTrivia<L> {
 	/// Returns all [SyntaxTriviaPiece] of this trivia.
 	///
@@ -396,6 +831,83 @@ impl<L: Language> SyntaxTrivia<L> {
 		}
 	}
 
+	/// Returns the number of pieces of this trivia.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// let token = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "\n/**/ let",
+	///         vec![TriviaPiece::Newline(1), TriviaPiece::Comments(4), TriviaPiece::Whitespace(1)],
+	///         vec![],
+	///     );
+	/// }).first_token().unwrap();
+	///
+	/// let leading = token.leading_trivia();
+	/// assert_eq!(3, leading.len());
+	/// assert_eq!("/**/", leading.get(1).unwrap().text());
+	/// assert_eq!(" ", leading.last().unwrap().text());
+	/// assert_eq!(None, leading.get(3).map(|piece| piece.text().to_string()));
+	/// assert!(token.trailing_trivia().is_empty());
+	/// ```
+	pub fn len(&self) -> usize {
+		self.raw.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the first piece of this trivia, if any
+	pub fn first(&self) -> Option<SyntaxTriviaPiece<L>> {
+		self.pieces().next()
+	}
+
+	/// Returns the last piece of this trivia, if any
+	pub fn last(&self) -> Option<SyntaxTriviaPiece<L>> {
+		let index = self.len().checked_sub(1)?;
+		let trivia = self.raw.get_piece(index)?;
+		Some(SyntaxTriviaPiece {
+			raw: self.raw.clone(),
+			offset: self.text_range().end() - trivia.text_len(),
+			trivia,
+			_p: PhantomData,
+		})
+	}
+
+	/// Returns the `index`-th piece of this trivia, or [None] if the trivia has fewer pieces
+	pub fn get(&self, index: usize) -> Option<SyntaxTriviaPiece<L>> {
+		self.pieces().nth(index)
+	}
+
+	/// Returns the comment pieces of this trivia.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// let token = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "/* a */ // b\nlet",
+	///         vec![
+	///             TriviaPiece::Comments(7),
+	///             TriviaPiece::Whitespace(1),
+	///             TriviaPiece::Comments(4),
+	///             TriviaPiece::Newline(1),
+	///         ],
+	///         vec![],
+	///     );
+	/// }).first_token().unwrap();
+	///
+	/// let comments: Vec<_> = token.leading_trivia().comments().map(|comment| comment.text().to_string()).collect();
+	/// assert_eq!(vec!["/* a */", "// b"], comments);
+	/// ```
+	pub fn comments(&self) -> impl Iterator<Item = SyntaxTriviaPieceComments<L>> {
+		self.pieces().filter_map(|piece| piece.as_comments())
+	}
+
 	pub fn text(&self) -> &str {
 		self.raw.text()
 	}
@@ -403,6 +915,31 @@ impl<L: Language> SyntaxTrivia<L> {
 	pub fn text_range(&self) -> TextRange {
 		self.raw.text_range()
 	}
+
+	/// Returns `true` if this trivia contains at least one line break, either as a
+	/// [TriviaPiece::Newline] piece or inside a whitespace piece.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// let mut token = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "\n\t let ",
+	///         vec![TriviaPiece::Newline(1), TriviaPiece::Whitespace(2)],
+	///         vec![TriviaPiece::Whitespace(1)],
+	///     );
+	/// }).first_token().unwrap();
+	/// assert!(token.leading_trivia().has_newline());
+	/// assert!(!token.trailing_trivia().has_newline());
+	/// ```
+	pub fn has_newline(&self) -> bool {
+		self.pieces().any(|piece| match piece.trivia {
+			TriviaPiece::Newline(len) => len > 0,
+			TriviaPiece::Whitespace(_) => piece.lines() > 0,
+			TriviaPiece::Comments(_) | TriviaPiece::Skipped(_) => false,
+		})
+	}
 }
 
 impl<L: Language> SyntaxNode<L> {
@@ -410,6 +947,67 @@ impl<L: Language> SyntaxNode<L> {
 		SyntaxNode::from(cursor::SyntaxNode::new_root(green))
 	}
 
+	pub(crate) fn green(&self) -> Cow<'_, GreenNodeData> {
+		self.raw.green()
+	}
+
+	/// Returns the errors recorded on this node when building the tree, excluding the errors of
+	/// its descendants, see [TreeBuilder::error](crate::TreeBuilder::error).
+	///
+	/// The errors are part of the node: they move with the node when the tree is edited and are
+	/// removed together with it. Changing a child of this node or one of its descendants removes
+	/// the errors of this node that end after the start of the changed child.
+	pub fn errors(&self) -> impl Iterator<Item = NodeError> {
+		let start = self.text_range().start();
+		let green = self.green().into_owned();
+		(0..green.errors().len()).map(move |index| {
+			let error = &green.errors()[index];
+			NodeError {
+				range: error.rel_range + start,
+				message: error.message.to_string(),
+			}
+		})
+	}
+
+	/// Returns a read-only view of the green node of this node, see [GreenNodeView]
+	pub fn green_view(&self) -> GreenNodeView {
+		GreenNodeView::new_raw(self.green().into_owned())
+	}
+
+	/// Returns statistics about the memory used by the subtree of this node.
+	pub fn memory_stats(&self) -> MemoryStats {
+		MemoryStats::of(&self.raw)
+	}
+
+	/// Returns a cheap identity of this node that can be used as key of a map, see [crate::NodeMap].
+	pub fn key(&self) -> SyntaxNodeKey {
+		SyntaxNodeKey::from(&self.raw)
+	}
+
+	/// Returns `true` if both nodes have the same green node, like the subtrees two trees share
+	/// when one is built with [crate::TreeBuilder::with_reuse]. Unlike [SyntaxNode::key], it doesn't
+	/// tell apart the nodes of different trees.
+	pub fn shares_green(&self, other: &SyntaxNode<L>) -> bool {
+		self.raw.key().0 == other.raw.key().0
+	}
+
+	/// Checks the internal invariants of the subtree of this node: the offsets of the slots, the
+	/// lengths of the nodes and of the trivia of the tokens, and the slot indices, parents and offsets
+	/// of the children. Returns the invariants that don't hold, an empty list for a valid tree.
+	///
+	/// A violation is always a bug in the code that created or edited the tree. Tests of code
+	/// that edits trees can use this method to catch corrupted trees early.
+	///
+	/// ```
+	/// use rome_rowan::parse_sexpr;
+	///
+	/// let root = parse_sexpr("(0 (1 'a') _ (2 ' ' 'b' ''))").unwrap();
+	/// assert!(root.validate().is_empty());
+	/// ```
+	pub fn validate(&self) -> Vec<InvariantViolation<L>> {
+		validate::validate(self)
+	}
+
 	/// Returns the element stored in the slot with the given index. Returns [None] if the slot is empty.
 	///
 	/// ## Panics
@@ -418,6 +1016,52 @@ impl<L: Language> SyntaxNode<L> {
 		self.raw.element_in_slot(slot).map(SyntaxElement::from)
 	}
 
+	/// Returns the element stored in the slot named `name`, see [Language::slot_name]. Returns [None]
+	/// if the slot is empty or if the nodes of this kind have no slot with this name.
+	///
+	/// ```
+	/// use rome_rowan::{Language, SyntaxKind, TreeBuilder};
+	///
+	/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+	/// struct IfLanguage;
+	///
+	/// impl Language for IfLanguage {
+	///     type Kind = SyntaxKind;
+	///
+	///     fn kind_from_raw(raw: SyntaxKind) -> SyntaxKind {
+	///         raw
+	///     }
+	///     fn kind_to_raw(kind: SyntaxKind) -> SyntaxKind {
+	///         kind
+	///     }
+	///     fn list_kind() -> SyntaxKind {
+	///         SyntaxKind(0)
+	///     }
+	///
+	///     fn slot_name(kind: SyntaxKind, index: usize) -> Option<&'static str> {
+	///         match (kind, index) {
+	///             (SyntaxKind(1), 0) => Some("condition"),
+	///             (SyntaxKind(1), 1) => Some("consequent"),
+	///             _ => None,
+	///         }
+	///     }
+	/// }
+	///
+	/// let node = TreeBuilder::<IfLanguage>::wrap_with_node(SyntaxKind(1), |builder| {
+	///     builder.token(SyntaxKind(2), "a");
+	///     builder.missing();
+	/// });
+	///
+	/// assert_eq!("a", node.element_by_slot_name("condition").unwrap().to_string());
+	/// assert!(node.element_by_slot_name("consequent").is_none());
+	/// assert!(node.element_by_slot_name("alternate").is_none());
+	/// ```
+	pub fn element_by_slot_name(&self, name: &str) -> Option<SyntaxElement<L>> {
+		let slot = (0..self.green().slots().len())
+			.find(|index| L::slot_name(self.kind(), *index) == Some(name))?;
+		self.element_in_slot(slot as u32)
+	}
+
 	pub fn kind(&self) -> L::Kind {
 		L::kind_from_raw(self.raw.kind())
 	}
@@ -476,6 +1120,163 @@ impl<L: Language> SyntaxNode<L> {
 		self.raw.text_trimmed()
 	}
 
+	/// Writes the text of all descendants tokens combined into `writer`. `filter` decides for every
+	/// trivia piece whether its text is written, skipped or replaced, for example to print a node
+	/// without its comments, without allocating the text of the node.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::{RawLanguage, TriviaFilter};
+	/// let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "/* a */ let \n\t",
+	///         vec![TriviaPiece::Comments(7), TriviaPiece::Whitespace(1)],
+	///         vec![TriviaPiece::Whitespace(3)],
+	///     );
+	///     builder.token(SyntaxKind(1), "a");
+	/// });
+	///
+	/// let mut text = String::new();
+	/// node.text_with_trivia_filter(&mut text, |piece| {
+	///     if piece.as_comments().is_some() {
+	///         TriviaFilter::Remove
+	///     } else {
+	///         TriviaFilter::Replace(" ")
+	///     }
+	/// })
+	/// .unwrap();
+	/// assert_eq!(" let a", text);
+	/// ```
+	pub fn text_with_trivia_filter<W, F>(&self, writer: &mut W, mut filter: F) -> fmt::Result
+	where
+		W: fmt::Write + ?Sized,
+		F: FnMut(&SyntaxTriviaPiece<L>) -> TriviaFilter,
+	{
+		let mut write_trivia = |writer: &mut W, trivia: SyntaxTrivia<L>| {
+			for piece in trivia.pieces() {
+				match filter(&piece) {
+					TriviaFilter::Keep => writer.write_str(piece.text())?,
+					TriviaFilter::Remove => {}
+					TriviaFilter::Replace(text) => writer.write_str(text)?,
+				}
+			}
+			Ok(())
+		};
+
+		for token in self.descendants_tokens() {
+			write_trivia(writer, token.leading_trivia())?;
+			writer.write_str(token.text_trimmed())?;
+			write_trivia(writer, token.trailing_trivia())?;
+		}
+
+		Ok(())
+	}
+
+	/// Writes the text of this node, including the trivia of its tokens, into `writer` by streaming
+	/// the text of all descendants tokens, without collecting the text of the node into a [String].
+	///
+	/// ```
+	/// use rome_rowan::parse_sexpr;
+	///
+	/// let node = parse_sexpr("(0 (1 'let') (1 ' ' 'a' ''))").unwrap();
+	/// let mut bytes = Vec::new();
+	/// node.write_to(&mut bytes).unwrap();
+	///
+	/// assert_eq!(b"let a", bytes.as_slice());
+	/// ```
+	pub fn write_to<W>(&self, writer: &mut W) -> io::Result<()>
+	where
+		W: io::Write + ?Sized,
+	{
+		for token in self.descendants_tokens() {
+			for part in token.green().text_parts().iter() {
+				writer.write_all(part.as_bytes())?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Writes the text of this node into `writer` like [SyntaxNode::write_to], using `filter` to decide
+	/// for every trivia piece whether its text is written, skipped or replaced.
+	/// See [SyntaxNode::text_with_trivia_filter].
+	pub fn write_to_with_trivia_filter<W, F>(&self, writer: &mut W, filter: F) -> io::Result<()>
+	where
+		W: io::Write + ?Sized,
+		F: FnMut(&SyntaxTriviaPiece<L>) -> TriviaFilter,
+	{
+		let mut adapter = IoWriter {
+			writer,
+			error: None,
+		};
+
+		match self.text_with_trivia_filter(&mut adapter, filter) {
+			Ok(()) => Ok(()),
+			Err(fmt::Error) => Err(adapter.error.unwrap_or_else(|| io::ErrorKind::Other.into())),
+		}
+	}
+
+	/// Returns a view of the text of this node without the comments in its trivia, for example to check
+	/// if two nodes only differ in their comments. See [SyntaxNode::text_with_trivia_filter] to remove other trivia.
+	///
+	/// The text isn't collected into a [String], the view writes it when it's displayed or compared.
+	/// Runs of whitespace and newline pieces that are adjacent once the comments are removed, even across
+	/// tokens, are collapsed into their first piece, so `let /* a */ b` becomes `let b`. Removing a comment
+	/// can join the texts of the surrounding tokens: the text of `a/**/b` is `ab`.
+	///
+	/// ```
+	/// use rome_rowan::{api::RawLanguage, SyntaxKind, TreeBuilder, TriviaPiece};
+	///
+	/// let with_comment = |comment: &str| {
+	///     TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+	///         builder.token_with_trivia(
+	///             SyntaxKind(1),
+	///             &format!("let {} ", comment),
+	///             vec![],
+	///             vec![
+	///                 TriviaPiece::Whitespace(1),
+	///                 TriviaPiece::Comments(comment.len()),
+	///                 TriviaPiece::Whitespace(1),
+	///             ],
+	///         );
+	///         builder.token(SyntaxKind(2), "a");
+	///     })
+	/// };
+	/// let first = with_comment("/* the name */");
+	/// let second = with_comment("/* a */");
+	///
+	/// assert_ne!(first.text(), second.text());
+	/// assert_eq!("let a", first.text_without_comments());
+	/// assert_eq!(first.text_without_comments(), second.text_without_comments());
+	/// assert_eq!("let a", first.text_without_comments().to_string());
+	/// ```
+	pub fn text_without_comments(&self) -> TextWithoutComments<L> {
+		TextWithoutComments { node: self }
+	}
+
+	/// Returns a hash of the kind, the structure and the text of this subtree, including its trivia.
+	/// Equal subtrees have the same fingerprint, no matter at which offset or in which tree they are.
+	///
+	/// ```
+	/// use rome_rowan::parse_sexpr;
+	///
+	/// let before = parse_sexpr("(0 (1 (2 'a')) (1 (2 'b')))").unwrap();
+	/// let after = parse_sexpr("(0 (1 (2 'c')) (1 (2 'b')))").unwrap();
+	///
+	/// assert_ne!(before.first_child().unwrap().fingerprint(), after.first_child().unwrap().fingerprint());
+	/// assert_eq!(before.last_child().unwrap().fingerprint(), after.last_child().unwrap().fingerprint());
+	/// ```
+	pub fn fingerprint(&self) -> Fingerprint {
+		fingerprint::node_fingerprint(&self.green(), true)
+	}
+
+	/// Same as [SyntaxNode::fingerprint] but ignores the trivia, so that only changing whitespace
+	/// or comments doesn't change the fingerprint.
+	pub fn fingerprint_without_trivia(&self) -> Fingerprint {
+		fingerprint::node_fingerprint(&self.green(), false)
+	}
+
 	/// Returns the range corresponding for the text of all descendants tokens combined, including all trivia.
 	///
 	/// ```
@@ -608,6 +1409,11 @@ impl<L: Language> SyntaxNode<L> {
 		self.raw.parent().map(Self::from)
 	}
 
+	/// The index of the slot of this node in its parent
+	pub fn index(&self) -> usize {
+		self.raw.index()
+	}
+
 	pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode<L>> {
 		self.raw.ancestors().map(SyntaxNode::from)
 	}
@@ -619,6 +1425,27 @@ impl<L: Language> SyntaxNode<L> {
 		}
 	}
 
+	/// Returns an iterator over all slots of this node, including the empty slots.
+	///
+	/// ```
+	/// use rome_rowan::{parse_sexpr, SyntaxSlot};
+	///
+	/// let root = parse_sexpr("(0 (1 'a') _ (2))").unwrap();
+	/// let slots: Vec<_> = root.slots().collect();
+	///
+	/// assert_eq!(3, slots.len());
+	/// assert!(matches!(&slots[0], SyntaxSlot::Token(token) if token.text() == "a"));
+	/// assert!(matches!(slots[1], SyntaxSlot::Empty { index: 1, .. }));
+	/// assert!(matches!(&slots[2], SyntaxSlot::Node(node) if node.index() == 2));
+	/// ```
+	pub fn slots(&self) -> SyntaxSlots<L> {
+		SyntaxSlots {
+			parent: self.clone(),
+			next: 0,
+			end: self.green().slots().len() as u32,
+		}
+	}
+
 	pub fn children_with_tokens(&self) -> SyntaxElementChildren<L> {
 		SyntaxElementChildren {
 			raw: self.raw.children_with_tokens(),
@@ -629,6 +1456,24 @@ impl<L: Language> SyntaxNode<L> {
 	pub fn first_child(&self) -> Option<SyntaxNode<L>> {
 		self.raw.first_child().map(Self::from)
 	}
+	/// Returns the `n`-th child node of this node, counting from zero, without creating the
+	/// preceding children. Tokens and empty slots aren't counted, use [SyntaxNode::element_in_slot]
+	/// to get the child in a slot.
+	///
+	/// ```
+	/// use rome_rowan::parse_sexpr;
+	///
+	/// let root = parse_sexpr("(0 (1 (3 'a')) (2 '(') _ (1 (3 'b')) (1 (3 'c')))").unwrap();
+	/// let b = root.nth_child(1).unwrap();
+	///
+	/// assert_eq!("b", b.text());
+	/// assert_eq!(3, b.index());
+	/// assert!(root.nth_child(3).is_none());
+	/// ```
+	pub fn nth_child(&self, n: usize) -> Option<SyntaxNode<L>> {
+		self.raw.nth_child(n).map(Self::from)
+	}
+
 	pub fn last_child(&self) -> Option<SyntaxNode<L>> {
 		self.raw.last_child().map(Self::from)
 	}
@@ -663,6 +1508,35 @@ impl<L: Language> SyntaxNode<L> {
 		self.raw.last_token().map(SyntaxToken::from)
 	}
 
+	/// Returns the leftmost token in the subtree of this node that isn't [missing](SyntaxToken::is_missing).
+	///
+	/// ```
+	/// use rome_rowan::{api::RawLanguage, SyntaxKind, TreeBuilder};
+	///
+	/// let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+	///     builder.token(SyntaxKind(1), "");
+	///     builder.token(SyntaxKind(1), "a");
+	///     builder.token(SyntaxKind(1), "b");
+	///     builder.token(SyntaxKind(1), "");
+	/// });
+	///
+	/// assert_eq!("", node.first_token().unwrap().text());
+	/// assert_eq!("a", node.first_non_empty_token().unwrap().text());
+	/// assert_eq!("b", node.last_non_empty_token().unwrap().text());
+	/// ```
+	pub fn first_non_empty_token(&self) -> Option<SyntaxToken<L>> {
+		self.descendants_tokens().find(|token| !token.is_missing())
+	}
+
+	/// Returns the rightmost token in the subtree of this node that isn't [missing](SyntaxToken::is_missing).
+	pub fn last_non_empty_token(&self) -> Option<SyntaxToken<L>> {
+		let start = self.text_range().start();
+		// A token that isn't missing and precedes this node ends before the start of the node
+		iter::successors(self.last_token(), SyntaxToken::prev_token)
+			.take_while(|token| token.text_range().start() >= start)
+			.find(|token| !token.is_missing())
+	}
+
 	pub fn siblings(&self, direction: Direction) -> impl Iterator<Item = SyntaxNode<L>> {
 		self.raw.siblings(direction).map(SyntaxNode::from)
 	}
@@ -713,6 +1587,40 @@ impl<L: Language> SyntaxNode<L> {
 		self.raw.token_at_offset(offset).map(SyntaxToken::from)
 	}
 
+	/// Same as [SyntaxNode::token_at_offset] but also returns which part of each token the
+	/// offset points into, telling apart an offset inside of a comment from one on the token text.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::{RawLanguage, TokenOffsetPosition};
+	/// let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "/**/ let ",
+	///         vec![TriviaPiece::Comments(4), TriviaPiece::Whitespace(1)],
+	///         vec![TriviaPiece::Whitespace(1)],
+	///     );
+	///     builder.token(SyntaxKind(2), "a");
+	/// });
+	/// let (token, position) = node.token_at_offset_with_trivia(2.into()).right_biased().unwrap();
+	/// assert_eq!("let", token.text_trimmed());
+	/// assert!(position.is_comment());
+	///
+	/// let (_, position) = node.token_at_offset_with_trivia(6.into()).right_biased().unwrap();
+	/// assert!(matches!(position, TokenOffsetPosition::Trimmed));
+	/// ```
+	pub fn token_at_offset_with_trivia(
+		&self,
+		offset: TextSize,
+	) -> TokenAtOffset<(SyntaxToken<L>, TokenOffsetPosition<L>)> {
+		self.token_at_offset(offset).map(|token| {
+			let position = token
+				.offset_position(offset)
+				.expect("token_at_offset to return tokens covering the offset");
+			(token, position)
+		})
+	}
+
 	/// Return the deepest node or token in the current subtree that fully
 	/// contains the range. If the range is empty and is contained in two leaf
 	/// nodes, either one can be returned. Precondition: range must be contained
@@ -721,6 +1629,92 @@ impl<L: Language> SyntaxNode<L> {
 		NodeOrToken::from(self.raw.covering_element(range))
 	}
 
+	/// Returns the ranges an editor selects when repeatedly expanding the selection `range`: the
+	/// trimmed ranges of the covering token and of its ancestors up to this node, in increasing
+	/// order. Every range strictly contains the previous one, ranges that don't grow the selection
+	/// are skipped.
+	///
+	/// ```
+	/// use rome_rowan::{parse_sexpr, TextRange};
+	///
+	/// // let a =
+	/// let root = parse_sexpr("(0 (1 (2 'let') (3 (2 ' ' 'a' ' '))) (2 '='))").unwrap();
+	///
+	/// let ranges: Vec<_> = root.expand_selection(TextRange::empty(4.into())).collect();
+	/// assert_eq!(
+	///     vec![
+	///         TextRange::new(4.into(), 5.into()),
+	///         TextRange::new(0.into(), 5.into()),
+	///         TextRange::new(0.into(), 7.into()),
+	///     ],
+	///     ranges
+	/// );
+	/// ```
+	///
+	/// ## Panics
+	/// If `range` isn't contained in the range of this node
+	pub fn expand_selection(&self, range: TextRange) -> impl Iterator<Item = TextRange> {
+		let root = self.clone();
+		let covering = self.covering_element(range);
+
+		let mut selection = range;
+		iter::successors(Some(covering), move |element| match element {
+			NodeOrToken::Node(node) if *node == root => None,
+			_ => element.parent().map(NodeOrToken::Node),
+		})
+		.filter_map(move |element| {
+			let expanded = element.text_trimmed_range();
+			if expanded != selection && expanded.contains_range(selection) {
+				selection = expanded;
+				Some(expanded)
+			} else {
+				None
+			}
+		})
+	}
+
+	/// Same as [SyntaxNode::covering_element] but narrows the result down to a trivia piece
+	/// of the covering token if the range falls entirely inside of that piece.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::{CoveringElement, RawLanguage};
+	/// let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "/* a */ let",
+	///         vec![TriviaPiece::Comments(7), TriviaPiece::Whitespace(1)],
+	///         vec![],
+	///     );
+	/// });
+	/// let covering = node.covering_range_detail(TextRange::new(3.into(), 4.into()));
+	/// assert_eq!("/* a */", covering.trivia_piece().unwrap().text());
+	///
+	/// let covering = node.covering_range_detail(TextRange::new(8.into(), 11.into()));
+	/// assert!(matches!(covering, CoveringElement::Token(_)));
+	/// ```
+	pub fn covering_range_detail(&self, range: TextRange) -> CoveringElement<L> {
+		let token = match self.covering_element(range) {
+			NodeOrToken::Node(node) => return CoveringElement::Node(node),
+			NodeOrToken::Token(token) => token,
+		};
+
+		if token.text_trimmed_range().contains_range(range) {
+			return CoveringElement::Token(token);
+		}
+
+		let piece = token
+			.leading_trivia()
+			.pieces()
+			.chain(token.trailing_trivia().pieces())
+			.find(|piece| piece.text_range().contains_range(range));
+
+		match piece {
+			Some(piece) => CoveringElement::Trivia(token, piece),
+			None => CoveringElement::Token(token),
+		}
+	}
+
 	/// Finds a [`SyntaxElement`] which intersects with a given `range`. If
 	/// there are several intersecting elements, any one can be returned.
 	///
@@ -744,6 +1738,14 @@ impl<L: Language> SyntaxNode<L> {
 		SyntaxNode::from(self.raw.clone_for_update())
 	}
 
+	/// Returns a weak reference to this node that doesn't keep the tree alive, see [WeakSyntaxNode]
+	pub fn downgrade(&self) -> WeakSyntaxNode<L> {
+		WeakSyntaxNode {
+			raw: self.raw.downgrade(),
+			_p: PhantomData,
+		}
+	}
+
 	pub fn detach(&self) {
 		self.raw.detach()
 	}
@@ -756,6 +1758,52 @@ impl<L: Language> SyntaxNode<L> {
 		self.raw.splice_children(to_delete, to_insert)
 	}
 
+	/// Inserts `element` into the slot `slot` of this node and returns the inserted element.
+	/// The following slots are shifted by one.
+	///
+	/// `element` can be part of any tree:
+	/// * An element of a mutable tree, this or another one, is moved and stays the same element.
+	/// * An element of an immutable tree, for example a node of another parse, is copied and its tree
+	///   stays unchanged.
+	///
+	/// The trivia of `element` moves with it, and the offsets of `element` and its following siblings
+	/// reflect its new position.
+	///
+	/// ```
+	/// use rome_rowan::{api::RawLanguage, SyntaxKind, TextRange, TextSize, TreeBuilder};
+	///
+	/// let build = |text| {
+	///     TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+	///         builder.token(SyntaxKind(1), text);
+	///     })
+	/// };
+	///
+	/// let root = build("a").clone_for_update();
+	/// let other = build("b");
+	///
+	/// let b = root.insert_child_at(0, other.first_token().unwrap());
+	/// assert_eq!("ba", root.text());
+	/// assert_eq!(TextRange::new(0.into(), 1.into()), b.text_range());
+	/// assert_eq!(TextSize::from(1), root.last_token().unwrap().text_range().start());
+	/// // The other tree is left unchanged
+	/// assert_eq!("b", other.text());
+	/// ```
+	///
+	/// ## Panics
+	/// * If this node isn't mutable, see [SyntaxNode::clone_for_update]
+	/// * If `slot` is greater than the number of slots of this node
+	/// * If `element` is this node or one of its ancestors
+	pub fn insert_child_at(
+		&self,
+		slot: usize,
+		element: impl Into<SyntaxElement<L>>,
+	) -> SyntaxElement<L> {
+		self.raw
+			.insert_child_at(slot, cursor::SyntaxElement::from(element.into()));
+		self.element_in_slot(slot as u32)
+			.expect("the slot of the inserted element is empty")
+	}
+
 	pub fn into_list(self) -> Option<SyntaxList<L>> {
 		if self.kind() == L::list_kind() {
 			Some(SyntaxList::new(self))
@@ -766,6 +1814,15 @@ impl<L: Language> SyntaxNode<L> {
 }
 
 impl<L: Language> SyntaxToken<L> {
+	pub(crate) fn green(&self) -> &GreenTokenData {
+		self.raw.green()
+	}
+
+	/// Returns a read-only view of the green token of this token, see [GreenTokenView]
+	pub fn green_view(&self) -> GreenTokenView {
+		GreenTokenView::new_raw(self.green().to_owned())
+	}
+
 	pub fn kind(&self) -> L::Kind {
 		L::kind_from_raw(self.raw.kind())
 	}
@@ -774,8 +1831,52 @@ impl<L: Language> SyntaxToken<L> {
 		self.raw.text_range()
 	}
 
-	pub fn text_trimmed_range(&self) -> TextRange {
-		self.raw.text_trimmed_range()
+	pub fn text_trimmed_range(&self) -> TextRange {
+		self.raw.text_trimmed_range()
+	}
+
+	/// Returns which part of this token `offset` points into or `None` if the offset is outside of
+	/// the token. An offset at the end of the token text is considered to be on the token text,
+	/// an offset at the end of a trailing trivia piece is considered to be inside of that piece.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::{RawLanguage, TokenOffsetPosition};
+	/// let token = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "\tlet /**/",
+	///         vec![TriviaPiece::Whitespace(1)],
+	///         vec![TriviaPiece::Whitespace(1), TriviaPiece::Comments(4)],
+	///     );
+	/// }).first_token().unwrap();
+	/// assert!(matches!(token.offset_position(0.into()), Some(TokenOffsetPosition::LeadingTrivia(_))));
+	/// assert!(matches!(token.offset_position(4.into()), Some(TokenOffsetPosition::Trimmed)));
+	/// assert!(token.offset_position(8.into()).unwrap().is_comment());
+	/// assert!(token.offset_position(9.into()).unwrap().is_comment());
+	/// assert!(token.offset_position(10.into()).is_none());
+	/// ```
+	pub fn offset_position(&self, offset: TextSize) -> Option<TokenOffsetPosition<L>> {
+		let trimmed_range = self.text_trimmed_range();
+
+		if !self.text_range().contains_inclusive(offset) {
+			None
+		} else if offset < trimmed_range.start() {
+			self.leading_trivia()
+				.pieces()
+				.find(|piece| piece.text_range().contains(offset))
+				.map(TokenOffsetPosition::LeadingTrivia)
+		} else if offset <= trimmed_range.end() {
+			Some(TokenOffsetPosition::Trimmed)
+		} else {
+			self.trailing_trivia()
+				.pieces()
+				.find(|piece| {
+					let range = piece.text_range();
+					range.start() < offset && offset <= range.end()
+				})
+				.map(TokenOffsetPosition::TrailingTrivia)
+		}
 	}
 
 	pub fn index(&self) -> usize {
@@ -797,7 +1898,10 @@ impl<L: Language> SyntaxToken<L> {
 	/// }).first_token().unwrap();
 	/// assert_eq!("\n\t let \t\t", token.text());
 	/// ```
-	pub fn text(&self) -> &str {
+	///
+	/// The text of a token is only borrowed if the token has no trivia because the text of tokens
+	/// without their trivia is shared between tokens, see [NodeCache](crate::NodeCache).
+	pub fn text(&self) -> Cow<str> {
 		self.raw.text()
 	}
 
@@ -820,6 +1924,22 @@ impl<L: Language> SyntaxToken<L> {
 		self.raw.text_trimmed()
 	}
 
+	/// Returns a hash of the kind and the text of this token, including its trivia, see [SyntaxNode::fingerprint]
+	pub fn fingerprint(&self) -> Fingerprint {
+		fingerprint::token_fingerprint(self.green(), true)
+	}
+
+	/// Returns a hash of the kind and the text of this token, excluding its trivia
+	pub fn fingerprint_without_trivia(&self) -> Fingerprint {
+		fingerprint::token_fingerprint(self.green(), false)
+	}
+
+	/// Returns `true` if the text of this token, excluding trivia, is empty. Such tokens don't
+	/// come from the source text, e.g. the synthetic tokens inserted by error recovery.
+	pub fn is_missing(&self) -> bool {
+		self.text_trimmed().is_empty()
+	}
+
 	pub fn parent(&self) -> Option<SyntaxNode<L>> {
 		self.raw.parent().map(SyntaxNode::from)
 	}
@@ -853,6 +1973,39 @@ impl<L: Language> SyntaxToken<L> {
 		self.raw.prev_token().map(SyntaxToken::from)
 	}
 
+	/// Returns the tokens from this token up to and including `other` in document order.
+	///
+	/// The iterator stops at the first token that starts after `other`, so it doesn't run to the
+	/// end of the tree if `other` comes before this token.
+	///
+	/// ```
+	/// use rome_rowan::parse_sexpr;
+	///
+	/// let root = parse_sexpr("(0 (1 'a') (2 (1 '' 'b' ' /* c */')) (1 'd'))").unwrap();
+	/// let a = root.first_token().unwrap();
+	/// let d = root.last_token().unwrap();
+	///
+	/// let texts: Vec<_> = a.tokens_until(&d).map(|token| token.text().to_string()).collect();
+	/// assert_eq!(vec!["a", "b /* c */", "d"], texts);
+	///
+	/// assert!(a
+	///     .tokens_until(&d)
+	///     .any(|token| token.trailing_trivia().text().contains("/*")));
+	/// assert_eq!(0, d.tokens_until(&a).count());
+	/// ```
+	pub fn tokens_until(&self, other: &SyntaxToken<L>) -> impl Iterator<Item = SyntaxToken<L>> {
+		let end = other.clone();
+		let end_start = end.text_range().start();
+		iter::successors(Some(self.clone()), move |token| {
+			if token == &end {
+				None
+			} else {
+				token.next_token()
+			}
+		})
+		.take_while(move |token| token.text_range().start() <= end_start)
+	}
+
 	pub fn detach(&self) {
 		self.raw.detach()
 	}
@@ -902,6 +2055,129 @@ impl<L: Language> SyntaxToken<L> {
 			_p: PhantomData,
 		}
 	}
+
+	/// Returns a new token, inside of a new tree, where the text of this token, excluding its
+	/// trivia, is replaced by `text`. The text ranges of the ancestors and of all the following
+	/// elements are adjusted accordingly. The original tree is left unchanged.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(SyntaxKind(1), "let ", vec![], vec![TriviaPiece::Whitespace(1)]);
+	///     builder.token_with_trivia(SyntaxKind(2), "a ", vec![], vec![TriviaPiece::Whitespace(1)]);
+	///     builder.token(SyntaxKind(3), ";");
+	/// });
+	/// let renamed = node.children_with_tokens().nth(1).unwrap().into_token().unwrap().with_text("abc");
+	/// assert_eq!("abc", renamed.text_trimmed());
+	/// assert_eq!("abc ", renamed.text());
+	///
+	/// let new_root = renamed.parent().unwrap();
+	/// assert_eq!("let abc ;", new_root.text());
+	/// assert_eq!(TextRange::new(8.into(), 9.into()), new_root.last_token().unwrap().text_range());
+	/// assert_eq!("let a ;", node.text());
+	/// ```
+	pub fn with_text(&self, text: &str) -> SyntaxToken<L> {
+		SyntaxToken::from(self.raw.with_text(text))
+	}
+
+	/// Replaces the text of this token, excluding its trivia, in place. The text ranges of the
+	/// following tokens and the lengths of the ancestors change accordingly, like when calling
+	/// [SyntaxNode::splice_children].
+	///
+	/// ## Panics
+	/// If the tree isn't mutable, see [SyntaxNode::clone_for_update]
+	///
+	/// ```
+	/// use rome_rowan::{parse_sexpr, SyntaxKind, TextRange};
+	///
+	/// let root = parse_sexpr("(0 (1 (2 'let') (2 ' ' 'a' ' ')) (2 ';'))").unwrap().clone_for_update();
+	/// let name = root.first_child().unwrap().last_token().unwrap();
+	/// let semicolon = root.last_token().unwrap();
+	///
+	/// name.set_text("abc");
+	/// assert_eq!("let abc ;", root.text());
+	/// assert_eq!(" abc ", name.text());
+	/// assert_eq!(TextRange::new(8.into(), 9.into()), semicolon.text_range());
+	///
+	/// semicolon.set_kind(SyntaxKind(3));
+	/// assert_eq!(SyntaxKind(3), root.last_token().unwrap().kind());
+	/// ```
+	pub fn set_text(&self, text: &str) {
+		self.raw.set_text(text)
+	}
+
+	/// Replaces the kind of this token in place, see [SyntaxToken::set_text]
+	///
+	/// ## Panics
+	/// If the tree isn't mutable, see [SyntaxNode::clone_for_update]
+	pub fn set_kind(&self, kind: L::Kind) {
+		self.raw.set_kind(L::kind_to_raw(kind))
+	}
+
+	/// Returns a new token, inside of a new tree, where the leading trivia is replaced by `text`
+	/// split into the given `pieces`. The original tree is left unchanged.
+	///
+	/// Panics if the length of the pieces doesn't match the length of `text`.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// let token = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "\n\t let \t\t",
+	///         vec![TriviaPiece::Whitespace(3)],
+	///         vec![TriviaPiece::Whitespace(3)],
+	///     );
+	/// }).first_token().unwrap();
+	/// let new_token = token.with_leading_trivia(
+	///     "/**/ ",
+	///     vec![TriviaPiece::Comments(4), TriviaPiece::Whitespace(1)],
+	/// );
+	/// assert_eq!("/**/ let \t\t", new_token.text());
+	/// assert_eq!("/**/ let \t\t", new_token.parent().unwrap().text());
+	/// assert_eq!("\n\t let \t\t", token.parent().unwrap().text());
+	/// ```
+	pub fn with_leading_trivia(&self, text: &str, pieces: Vec<TriviaPiece>) -> SyntaxToken<L> {
+		let trivia = GreenTokenTrivia::from(pieces);
+		assert_eq!(
+			trivia.text_len(),
+			TextSize::of(text),
+			"The length of the trivia pieces must match the length of the text"
+		);
+		SyntaxToken::from(self.raw.with_leading_trivia(text, trivia))
+	}
+
+	/// Returns a new token, inside of a new tree, where the trailing trivia is replaced by `text`
+	/// split into the given `pieces`. The original tree is left unchanged.
+	///
+	/// Panics if the length of the pieces doesn't match the length of `text`.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// let token = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0),|builder| {
+	///     builder.token_with_trivia(
+	///         SyntaxKind(1),
+	///         "\n\t let \t\t",
+	///         vec![TriviaPiece::Whitespace(3)],
+	///         vec![TriviaPiece::Whitespace(3)],
+	///     );
+	/// }).first_token().unwrap();
+	/// let new_token = token.with_trailing_trivia("", vec![]);
+	/// assert_eq!("\n\t let", new_token.text());
+	/// assert_eq!("\n\t let", new_token.parent().unwrap().text());
+	/// ```
+	pub fn with_trailing_trivia(&self, text: &str, pieces: Vec<TriviaPiece>) -> SyntaxToken<L> {
+		let trivia = GreenTokenTrivia::from(pieces);
+		assert_eq!(
+			trivia.text_len(),
+			TextSize::of(text),
+			"The length of the trivia pieces must match the length of the text"
+		);
+		SyntaxToken::from(self.raw.with_trailing_trivia(text, trivia))
+	}
 }
 
 impl<L: Language> SyntaxElement<L> {
@@ -947,6 +2223,21 @@ impl<L: Language> SyntaxElement<L> {
 		}
 	}
 
+	/// The index of the slot of this element in its parent
+	pub fn index(&self) -> usize {
+		match self {
+			NodeOrToken::Node(it) => it.index(),
+			NodeOrToken::Token(it) => it.index(),
+		}
+	}
+
+	pub(crate) fn green(&self) -> GreenElement {
+		match self {
+			NodeOrToken::Node(it) => it.green().into(),
+			NodeOrToken::Token(it) => it.raw.green().to_owned().into(),
+		}
+	}
+
 	pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode<L>> {
 		let first = match self {
 			NodeOrToken::Node(it) => Some(it.clone()),
@@ -990,6 +2281,72 @@ impl<L: Language> Iterator for SyntaxNodeChildren<L> {
 	}
 }
 
+/// A slot of a node, which either contains an element or is empty if the child isn't present
+/// in the source.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SyntaxSlot<L: Language> {
+	Node(SyntaxNode<L>),
+	Token(SyntaxToken<L>),
+	Empty { parent: SyntaxNode<L>, index: u32 },
+}
+
+impl<L: Language> SyntaxSlot<L> {
+	/// Returns the index of this slot in its parent
+	pub fn index(&self) -> usize {
+		match self {
+			SyntaxSlot::Node(node) => node.index(),
+			SyntaxSlot::Token(token) => token.index(),
+			SyntaxSlot::Empty { index, .. } => *index as usize,
+		}
+	}
+
+	/// Returns the element in this slot or [None] if the slot is empty
+	pub fn into_element(self) -> Option<SyntaxElement<L>> {
+		match self {
+			SyntaxSlot::Node(node) => Some(NodeOrToken::Node(node)),
+			SyntaxSlot::Token(token) => Some(NodeOrToken::Token(token)),
+			SyntaxSlot::Empty { .. } => None,
+		}
+	}
+}
+
+/// Iterator over the slots of a node, see [SyntaxNode::slots]
+#[derive(Debug, Clone)]
+pub struct SyntaxSlots<L: Language> {
+	parent: SyntaxNode<L>,
+	next: u32,
+	end: u32,
+}
+
+impl<L: Language> Iterator for SyntaxSlots<L> {
+	type Item = SyntaxSlot<L>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.next == self.end {
+			return None;
+		}
+
+		let index = self.next;
+		self.next += 1;
+		Some(match self.parent.element_in_slot(index) {
+			Some(NodeOrToken::Node(node)) => SyntaxSlot::Node(node),
+			Some(NodeOrToken::Token(token)) => SyntaxSlot::Token(token),
+			None => SyntaxSlot::Empty {
+				parent: self.parent.clone(),
+				index,
+			},
+		})
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = (self.end - self.next) as usize;
+		(len, Some(len))
+	}
+}
+
+impl<L: Language> ExactSizeIterator for SyntaxSlots<L> {}
+impl<L: Language> iter::FusedIterator for SyntaxSlots<L> {}
+
 #[derive(Debug, Clone)]
 pub struct SyntaxElementChildren<L: Language> {
 	raw: cursor::SyntaxElementChildren,
@@ -1169,11 +2526,201 @@ impl<L: Language> IntoIterator for SyntaxList<L> {
 
 #[cfg(test)]
 mod tests {
-	use text_size::TextRange;
+	use text_size::{TextRange, TextSize};
 
-	use crate::api::{RawLanguage, TriviaPiece};
+	use crate::api::{
+		CoveringElement, RawLanguage, TokenOffsetPosition, TriviaFilter, TriviaPiece,
+	};
 	use crate::{Direction, Language, SyntaxKind, SyntaxList, TreeBuilder};
 
+	#[test]
+	fn expand_selection_skips_ranges_that_dont_contain_the_selection() {
+		let root = crate::parse_sexpr("(0 (1 (2 'let') (3 (2 ' ' 'a' ' '))) (2 '='))").unwrap();
+		let range = |start: u32, end: u32| TextRange::new(start.into(), end.into());
+
+		// The whitespace before `a` is only part of the trimmed range of the statement
+		let ranges: Vec<_> = root.expand_selection(range(3, 4)).collect();
+		assert_eq!(vec![range(0, 5), range(0, 7)], ranges);
+
+		assert_eq!(None, root.expand_selection(range(0, 7)).next());
+
+		// Stops at the node it's called on
+		let statement = root.first_child().unwrap();
+		let ranges: Vec<_> = statement.expand_selection(range(1, 2)).collect();
+		assert_eq!(vec![range(0, 3), range(0, 5)], ranges);
+	}
+
+	#[test]
+	fn write_to_streams_the_filtered_text() {
+		let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token_with_trivia(
+				SyntaxKind(1),
+				"/**/let ",
+				vec![TriviaPiece::Comments(4)],
+				vec![TriviaPiece::Whitespace(1)],
+			);
+			builder.token(SyntaxKind(1), "a");
+		});
+
+		let mut bytes = Vec::new();
+		node.write_to_with_trivia_filter(&mut bytes, |piece| {
+			if piece.as_comments().is_some() {
+				TriviaFilter::Remove
+			} else {
+				TriviaFilter::Keep
+			}
+		})
+		.unwrap();
+		assert_eq!(b"let a", bytes.as_slice());
+
+		// The io error of the writer is returned
+		let mut full = [0u8; 3];
+		let error = node
+			.write_to_with_trivia_filter(&mut &mut full[..], |_| TriviaFilter::Keep)
+			.unwrap_err();
+		assert_eq!(std::io::ErrorKind::WriteZero, error.kind());
+		let error = node.write_to(&mut &mut full[..]).unwrap_err();
+		assert_eq!(std::io::ErrorKind::WriteZero, error.kind());
+	}
+
+	#[test]
+	fn text_without_comments_collapses_whitespace_across_tokens() {
+		let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token_with_trivia(
+				SyntaxKind(1),
+				"let /* a */",
+				vec![],
+				vec![TriviaPiece::Whitespace(1), TriviaPiece::Comments(7)],
+			);
+			builder.token_with_trivia(
+				SyntaxKind(1),
+				"\n// b\n\tb",
+				vec![
+					TriviaPiece::Newline(1),
+					TriviaPiece::Comments(4),
+					TriviaPiece::Newline(1),
+					TriviaPiece::Whitespace(1),
+				],
+				vec![],
+			);
+			builder.token_with_trivia(
+				SyntaxKind(1),
+				"/**/;",
+				vec![TriviaPiece::Comments(4)],
+				vec![],
+			);
+		});
+
+		let text = node.text_without_comments();
+		assert_eq!("let b;", text.to_string());
+		assert_eq!(text, "let b;");
+		assert_ne!(text, "let b");
+
+		let mut bytes = Vec::new();
+		text.write_to(&mut bytes).unwrap();
+		assert_eq!(b"let b;", bytes.as_slice());
+	}
+
+	#[test]
+	fn weak_nodes_dont_keep_the_tree_alive() {
+		let root = crate::parse_sexpr("(0 (1 (2 'a')) (1 (2 'b')))").unwrap();
+		let token = root.last_token().unwrap();
+		let weak_root = root.downgrade();
+		let weak_child = token.parent().unwrap().downgrade();
+		let cloned = weak_child.clone();
+
+		// The token keeps its ancestors alive
+		drop(root);
+		assert_eq!("b", weak_child.upgrade().unwrap().text());
+		assert!(weak_root.is_alive());
+
+		drop(token);
+		assert!(!weak_root.is_alive());
+		assert!(weak_child.upgrade().is_none());
+		assert!(cloned.upgrade().is_none());
+
+		// Editing a mutable tree invalidates the weak nodes created before the edit
+		let root = build_lists().clone_for_update();
+		let weak = root.first_child().unwrap().downgrade();
+		let weak_last = root.last_child().unwrap().downgrade();
+		assert_eq!("ab", weak.upgrade().unwrap().text());
+		root.first_child().unwrap().detach();
+		assert!(weak.upgrade().is_none());
+		assert!(weak_last.upgrade().is_none());
+		assert_eq!(
+			"c",
+			root.first_child()
+				.unwrap()
+				.downgrade()
+				.upgrade()
+				.unwrap()
+				.text()
+		);
+
+		// Setting the text of a token keeps the shape of the tree
+		let weak = root.first_child().unwrap().downgrade();
+		root.first_token().unwrap().set_text("d");
+		assert_eq!("d", weak.upgrade().unwrap().text());
+
+		// A child copied from an immutable tree moves the following siblings to other slots
+		let weak = root.first_child().unwrap().downgrade();
+		root.insert_child_at(0, build_lists().first_child().unwrap());
+		assert!(weak.upgrade().is_none());
+		assert_eq!("abd", root.text());
+	}
+
+	#[test]
+	fn set_text_updates_the_mutable_tree() {
+		let root = build_lists().clone_for_update();
+		let a = root.first_token().unwrap();
+		let text = a.text();
+
+		a.set_text("xyz");
+		a.set_text("uv");
+		assert_eq!("a", text);
+		assert_eq!("uvbc", root.text());
+		assert_eq!(TextSize::from(4), root.text_range().len());
+		assert_eq!(
+			TextRange::new(3.into(), 4.into()),
+			root.last_child().unwrap().text_range()
+		);
+		assert_eq!("uvb", root.first_child().unwrap().text());
+
+		// Detached tokens own their green token
+		let c = root.last_token().unwrap();
+		c.detach();
+		c.set_kind(SyntaxKind(5));
+		c.set_text("d");
+		assert_eq!(SyntaxKind(5), c.kind());
+		assert_eq!("d", c.text());
+		assert_eq!("uvb", root.text());
+	}
+
+	#[test]
+	#[should_panic(expected = "immutable tree")]
+	fn set_text_rejects_immutable_trees() {
+		build_lists().first_token().unwrap().set_text("b");
+	}
+
+	#[test]
+	fn non_empty_tokens_stay_in_the_subtree() {
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token(SyntaxKind(1), "a");
+			builder.start_node(SyntaxKind(2));
+			builder.token_with_trivia(SyntaxKind(1), " ", vec![TriviaPiece::Whitespace(1)], vec![]);
+			builder.token(SyntaxKind(1), "");
+			builder.finish_node();
+			builder.token(SyntaxKind(1), "");
+		});
+		let node = root.first_child().unwrap();
+
+		assert!(node.first_token().unwrap().is_missing());
+		assert_eq!(None, node.first_non_empty_token());
+		assert_eq!(None, node.last_non_empty_token());
+		assert_eq!("a", root.last_non_empty_token().unwrap().text());
+		assert!(!root.first_token().unwrap().is_missing());
+	}
+
 	#[test]
 	fn empty_list() {
 		let list = SyntaxList::<RawLanguage>::default();
@@ -1526,5 +3073,243 @@ mod tests {
 		assert_eq!(TextSize::from(4), pieces[1].text_len());
 		assert_eq!(TextRange::new(3.into(), 7.into()), pieces[1].text_range());
 		assert!(pieces[1].as_comments().is_some());
+
+		let pieces: Vec<_> = node.last_trailing_trivia().unwrap().pieces().collect();
+		assert_eq!(" \t\t", pieces[0].text());
+		assert_eq!(TextRange::new(10.into(), 13.into()), pieces[0].text_range());
+	}
+
+	#[test]
+	pub fn token_at_offset_with_trivia() {
+		use crate::*;
+		let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token_with_trivia(
+				SyntaxKind(1),
+				"a // b",
+				vec![],
+				vec![TriviaPiece::Whitespace(1), TriviaPiece::Comments(4)],
+			);
+			builder.token_with_trivia(
+				SyntaxKind(2),
+				"\n/* c */b",
+				vec![TriviaPiece::Newline(1), TriviaPiece::Comments(7)],
+				vec![],
+			);
+		});
+
+		let positions: Vec<_> = node
+			.token_at_offset_with_trivia(6.into())
+			.map(|(token, position)| (token.kind(), position.trivia_piece().cloned()))
+			.collect();
+		assert_eq!(2, positions.len());
+		assert_eq!(SyntaxKind(1), positions[0].0);
+		assert_eq!("// b", positions[0].1.as_ref().unwrap().text());
+		assert_eq!(SyntaxKind(2), positions[1].0);
+		assert!(positions[1].1.as_ref().unwrap().as_newline().is_some());
+
+		let (token, position) = node
+			.token_at_offset_with_trivia(10.into())
+			.right_biased()
+			.unwrap();
+		assert_eq!(SyntaxKind(2), token.kind());
+		assert!(position.is_comment());
+
+		let (_, position) = node
+			.token_at_offset_with_trivia(14.into())
+			.right_biased()
+			.unwrap();
+		assert!(matches!(position, TokenOffsetPosition::Trimmed));
+	}
+
+	#[test]
+	pub fn covering_range_detail() {
+		use crate::*;
+		let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token_with_trivia(
+				SyntaxKind(1),
+				"a // b",
+				vec![],
+				vec![TriviaPiece::Whitespace(1), TriviaPiece::Comments(4)],
+			);
+			builder.token_with_trivia(SyntaxKind(2), "\nb", vec![TriviaPiece::Newline(1)], vec![]);
+		});
+
+		let covering = node.covering_range_detail(TextRange::new(3.into(), 6.into()));
+		assert!(
+			matches!(&covering, CoveringElement::Trivia(token, _) if token.kind() == SyntaxKind(1))
+		);
+		assert_eq!("// b", covering.trivia_piece().unwrap().text());
+
+		// Spans the comment and the token text
+		let covering = node.covering_range_detail(TextRange::new(0.into(), 4.into()));
+		assert!(matches!(covering, CoveringElement::Token(_)));
+
+		// Spans both tokens
+		let covering = node.covering_range_detail(TextRange::new(3.into(), 8.into()));
+		assert!(matches!(covering, CoveringElement::Node(_)));
+		assert_eq!(
+			None,
+			covering
+				.trivia_piece()
+				.map(|piece| piece.text().to_string())
+		);
+	}
+
+	#[test]
+	pub fn syntax_trivia_newlines() {
+		use crate::*;
+		let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token_with_trivia(
+				SyntaxKind(1),
+				"\r\n\n/**/ \u{2028}let \t",
+				vec![
+					TriviaPiece::Newline(3),
+					TriviaPiece::Comments(4),
+					TriviaPiece::Whitespace(4),
+				],
+				vec![TriviaPiece::Whitespace(2)],
+			);
+		});
+		let token = node.first_token().unwrap();
+		let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+
+		assert_eq!("\r\n\n", pieces[0].text());
+		assert!(pieces[0].as_newline().is_some());
+		assert_eq!(2, pieces[0].lines());
+		assert_eq!(0, pieces[1].lines());
+		assert_eq!(1, pieces[2].lines());
+
+		assert!(token.leading_trivia().has_newline());
+		assert!(!token.trailing_trivia().has_newline());
+	}
+
+	#[test]
+	pub fn syntax_trivia_skipped() {
+		use crate::*;
+		let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token_with_trivia(
+				SyntaxKind(1),
+				"@# let",
+				vec![TriviaPiece::Skipped(2), TriviaPiece::Whitespace(1)],
+				vec![],
+			);
+		});
+		let token = node.first_token().unwrap();
+		let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+
+		assert_eq!("@#", pieces[0].text());
+		assert!(pieces[0].as_skipped().is_some());
+		assert!(pieces[0].as_comments().is_none());
+		assert_eq!("let", token.text_trimmed());
+		assert_eq!("@# let", node.text());
+		assert_eq!(
+			format!("{:?}", token),
+			"SyntaxKind(1)@0..6 \"let\" [Skipped(\"@#\"), Whitespace(\" \")] []"
+		);
+	}
+
+	#[test]
+	pub fn token_with_trivia_rebuilds_ancestors() {
+		use crate::*;
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token_with_trivia(
+				SyntaxKind(1),
+				"let ",
+				vec![],
+				vec![TriviaPiece::Whitespace(1)],
+			);
+			builder.start_node(SyntaxKind(2));
+			builder.token_with_trivia(
+				SyntaxKind(3),
+				"/* a */ a",
+				vec![TriviaPiece::Comments(7), TriviaPiece::Whitespace(1)],
+				vec![],
+			);
+			builder.finish_node();
+		});
+
+		let let_token = root.first_token().unwrap();
+		let a_token = root.last_token().unwrap();
+
+		let new_a = a_token.with_leading_trivia("", vec![]);
+		let new_let = let_token.with_trailing_trivia(
+			" /* a */ ",
+			vec![
+				TriviaPiece::Whitespace(1),
+				TriviaPiece::Comments(7),
+				TriviaPiece::Whitespace(1),
+			],
+		);
+
+		assert_eq!("let a", new_a.ancestors().last().unwrap().text());
+		assert_eq!(TextRange::new(4.into(), 5.into()), new_a.text_range());
+		assert_eq!(SyntaxKind(2), new_a.parent().unwrap().kind());
+		assert_eq!(
+			"let /* a */ /* a */ a",
+			new_let.ancestors().last().unwrap().text()
+		);
+		assert!(new_let
+			.trailing_trivia()
+			.pieces()
+			.nth(1)
+			.unwrap()
+			.as_comments()
+			.is_some());
+
+		// The original tree is left unchanged
+		assert_eq!("let /* a */ a", root.text());
+	}
+
+	fn build_lists() -> crate::SyntaxNode<RawLanguage> {
+		TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "a");
+			builder.token(SyntaxKind(2), "b");
+			builder.finish_node();
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "c");
+			builder.finish_node();
+		})
+	}
+
+	#[test]
+	fn insert_child_at_moves_elements_between_mutable_trees() {
+		let root = build_lists().clone_for_update();
+		let other = build_lists().clone_for_update();
+		let first = root.first_child().unwrap();
+		let other_last = other.last_child().unwrap();
+
+		let moved = first.insert_child_at(1, other_last.clone());
+		assert_eq!(Some(other_last.clone()), moved.as_node().cloned());
+		assert_eq!("acbc", root.text());
+		assert_eq!("ab", other.text());
+		assert_eq!(TextRange::new(1.into(), 2.into()), other_last.text_range());
+		assert_eq!(Some(first), other_last.parent());
+
+		// Moving within the same tree
+		let c = root.last_token().unwrap();
+		root.first_child().unwrap().insert_child_at(0, c.clone());
+		assert_eq!("cacb", root.text());
+		assert_eq!(TextSize::from(0), c.text_range().start());
+	}
+
+	#[test]
+	fn insert_child_at_copies_elements_of_immutable_trees() {
+		let root = build_lists().clone_for_update();
+		let other = build_lists();
+
+		let inserted = root.insert_child_at(2, other.first_child().unwrap());
+		assert_eq!("abcab", root.text());
+		assert_eq!(TextRange::new(3.into(), 5.into()), inserted.text_range());
+		assert_eq!(Some(root), inserted.parent());
+		assert_eq!("abc", other.text());
+	}
+
+	#[test]
+	#[should_panic(expected = "can't insert a node into its own subtree")]
+	fn insert_child_at_rejects_ancestors() {
+		let root = build_lists().clone_for_update();
+		let first = root.first_child().unwrap();
+		first.insert_child_at(0, root);
 	}
 }
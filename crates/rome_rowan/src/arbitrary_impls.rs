@@ -0,0 +1,137 @@
+//! Random trees for property based tests, see the [Arbitrary] implementation of [SyntaxNode].
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{api::RawLanguage, SyntaxKind, SyntaxNode, TreeBuilder, TriviaPiece};
+
+/// The deepest level at which a generated node still has child nodes
+const MAX_DEPTH: usize = 6;
+const MAX_SLOTS: usize = 5;
+const MAX_TRIVIA_PIECES: usize = 3;
+/// Kinds are drawn from a small range so that the same kinds repeat across a tree
+const MAX_KIND: u16 = 15;
+
+/// Generates structurally valid trees: nodes with up to five slots that are either empty,
+/// a node, or a token with leading and trailing trivia. The text of every trivia piece matches its kind.
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use rome_rowan::{api::RawLanguage, SyntaxNode};
+///
+/// let bytes = [7u8; 64];
+/// let tree = SyntaxNode::<RawLanguage>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+/// assert_eq!(tree.text().len(), tree.text_range().len());
+/// ```
+impl<'a> Arbitrary<'a> for SyntaxNode<RawLanguage> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let mut builder = TreeBuilder::new();
+		arbitrary_node(u, &mut builder, 0)?;
+		Ok(builder.finish())
+	}
+}
+
+fn arbitrary_node(
+	u: &mut Unstructured<'_>,
+	builder: &mut TreeBuilder<'_, RawLanguage>,
+	depth: usize,
+) -> Result<()> {
+	builder.start_node(arbitrary_kind(u)?);
+	for _ in 0..u.int_in_range(0..=MAX_SLOTS)? {
+		match u.int_in_range(0u8..=2)? {
+			0 => builder.missing(),
+			1 if depth < MAX_DEPTH => arbitrary_node(u, builder, depth + 1)?,
+			_ => arbitrary_token(u, builder)?,
+		}
+	}
+	builder.finish_node();
+	Ok(())
+}
+
+fn arbitrary_token(
+	u: &mut Unstructured<'_>,
+	builder: &mut TreeBuilder<'_, RawLanguage>,
+) -> Result<()> {
+	let kind = arbitrary_kind(u)?;
+	let (leading_text, leading) = arbitrary_trivia(u)?;
+	let text = arbitrary_word(u)?;
+	let (trailing_text, trailing) = arbitrary_trivia(u)?;
+
+	builder.token_with_trivia(
+		kind,
+		&[leading_text, text, trailing_text].concat(),
+		leading,
+		trailing,
+	);
+	Ok(())
+}
+
+fn arbitrary_kind(u: &mut Unstructured<'_>) -> Result<SyntaxKind> {
+	Ok(SyntaxKind(u.int_in_range(0..=MAX_KIND)?))
+}
+
+/// Returns the text of the trivia and its pieces
+fn arbitrary_trivia(u: &mut Unstructured<'_>) -> Result<(String, Vec<TriviaPiece>)> {
+	let mut text = String::new();
+	let mut pieces = Vec::new();
+	for _ in 0..u.int_in_range(0..=MAX_TRIVIA_PIECES)? {
+		let (piece_text, piece): (String, fn(usize) -> TriviaPiece) =
+			match u.int_in_range(0u8..=3)? {
+				0 => (" ".repeat(u.int_in_range(1..=3)?), TriviaPiece::Whitespace),
+				1 => ("\n".repeat(u.int_in_range(1..=2)?), TriviaPiece::Newline),
+				2 => (format!("/*{}*/", arbitrary_word(u)?), TriviaPiece::Comments),
+				_ => (arbitrary_word(u)?, TriviaPiece::Skipped),
+			};
+		pieces.push(piece(piece_text.len()));
+		text.push_str(&piece_text);
+	}
+	Ok((text, pieces))
+}
+
+/// Returns a non empty word of ASCII letters
+fn arbitrary_word(u: &mut Unstructured<'_>) -> Result<String> {
+	let len = u.int_in_range(1..=8)?;
+	(0..len)
+		.map(|_| Ok(char::from(b'a' + u.int_in_range(0..=25)?)))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use arbitrary::{Arbitrary, Unstructured};
+
+	use crate::{api::RawLanguage, SyntaxNode};
+
+	#[test]
+	fn generates_consistent_trees() {
+		let mut seed = 0x2545_f491_u32;
+		for _ in 0..100 {
+			// Xorshift, to generate different trees without depending on a random number generator
+			let bytes: Vec<u8> = (0..512)
+				.map(|_| {
+					seed ^= seed << 13;
+					seed ^= seed >> 17;
+					seed ^= seed << 5;
+					seed as u8
+				})
+				.collect();
+
+			let tree =
+				SyntaxNode::<RawLanguage>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+			let tokens_text: String = tree
+				.descendants_tokens()
+				.map(|token| token.text().to_string())
+				.collect();
+			assert_eq!(tree.text().to_string(), tokens_text);
+
+			for token in tree.descendants_tokens() {
+				let trivia_len: usize = token
+					.leading_trivia()
+					.pieces()
+					.chain(token.trailing_trivia().pieces())
+					.map(|piece| piece.text().len())
+					.sum();
+				assert_eq!(token.text().len(), trivia_len + token.text_trimmed().len());
+			}
+		}
+	}
+}
@@ -0,0 +1,476 @@
+//! Green trees allocated in a bump arena.
+//!
+//! Building a tree with [TreeBuilder](crate::TreeBuilder) allocates every green node and token
+//! separately. [ArenaTreeBuilder] instead puts all the green data of a tree inside of a single
+//! [GreenArena] that is freed at once when the arena is dropped or reset, which makes building
+//! and throwing away the trees of large files considerably cheaper.
+//!
+//! Arena trees don't support the red tree API, use [ArenaNode::to_syntax] to get a [SyntaxNode].
+
+use std::{fmt, marker::PhantomData};
+
+use bumpalo::Bump;
+
+use crate::{
+	api::TriviaPiece, GreenBuilder, Language, NodeOrToken, SyntaxKind, SyntaxNode, TextRange,
+	TextSize, TreeBuilder,
+};
+
+/// The memory in which the green data of [ArenaTreeBuilder] trees is allocated
+#[derive(Debug, Default)]
+pub struct GreenArena {
+	bump: Bump,
+}
+
+impl GreenArena {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates an arena that can hold `bytes` of green data before allocating again
+	pub fn with_capacity(bytes: usize) -> Self {
+		GreenArena {
+			bump: Bump::with_capacity(bytes),
+		}
+	}
+
+	/// The number of bytes allocated by the arena
+	pub fn allocated_bytes(&self) -> usize {
+		self.bump.allocated_bytes()
+	}
+
+	/// Frees all the trees of the arena at once, keeping the largest chunk of memory for reuse
+	pub fn reset(&mut self) {
+		self.bump.reset()
+	}
+}
+
+pub type ArenaElement<'arena> = NodeOrToken<&'arena ArenaNode<'arena>, &'arena ArenaToken<'arena>>;
+
+/// An error recorded on an [ArenaNode], see [TreeBuilder::error](crate::TreeBuilder::error)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaNodeError<'arena> {
+	/// The range of the error relative to the start of the node
+	pub rel_range: TextRange,
+	pub message: &'arena str,
+}
+
+/// A green node allocated in a [GreenArena]
+#[derive(Clone, Copy)]
+pub struct ArenaNode<'arena> {
+	kind: SyntaxKind,
+	text_len: TextSize,
+	slots: &'arena [Option<ArenaElement<'arena>>],
+	errors: &'arena [ArenaNodeError<'arena>],
+}
+
+impl<'arena> ArenaNode<'arena> {
+	pub fn kind(&self) -> SyntaxKind {
+		self.kind
+	}
+
+	pub fn text_len(&self) -> TextSize {
+		self.text_len
+	}
+
+	/// The slots of the node, `None` for a missing child
+	pub fn slots(&self) -> &'arena [Option<ArenaElement<'arena>>] {
+		self.slots
+	}
+
+	/// The children of the node, skipping the missing ones
+	pub fn children(&self) -> impl Iterator<Item = ArenaElement<'arena>> + 'arena {
+		self.slots.iter().flatten().copied()
+	}
+
+	/// The errors recorded on the node, but not on its descendants
+	pub fn errors(&self) -> &'arena [ArenaNodeError<'arena>] {
+		self.errors
+	}
+
+	/// Copies the tree out of the arena into a regular syntax tree
+	pub fn to_syntax<L: Language>(&self) -> SyntaxNode<L> {
+		let mut builder = TreeBuilder::<L>::new();
+		self.build(&mut builder, TextSize::from(0));
+		builder.finish()
+	}
+
+	fn build<L: Language>(&self, builder: &mut TreeBuilder<L>, offset: TextSize) {
+		builder.start_node(L::kind_from_raw(self.kind));
+		let mut child_offset = offset;
+		for slot in self.slots {
+			match slot {
+				None => builder.missing(),
+				Some(NodeOrToken::Node(node)) => {
+					node.build(builder, child_offset);
+					child_offset += node.text_len;
+				}
+				Some(NodeOrToken::Token(token)) => {
+					builder.token_with_trivia(
+						L::kind_from_raw(token.kind),
+						token.text,
+						token.leading.to_vec(),
+						token.trailing.to_vec(),
+					);
+					child_offset += token.text_len();
+				}
+			}
+		}
+		for error in self.errors {
+			builder.error(error.rel_range + offset, error.message);
+		}
+		builder.finish_node();
+	}
+}
+
+impl fmt::Debug for ArenaNode<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ArenaNode")
+			.field("kind", &self.kind)
+			.field("text_len", &self.text_len)
+			.field("n_slots", &self.slots.len())
+			.finish()
+	}
+}
+
+impl fmt::Display for ArenaNode<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for child in self.children() {
+			match child {
+				NodeOrToken::Node(node) => fmt::Display::fmt(node, f)?,
+				NodeOrToken::Token(token) => fmt::Display::fmt(token, f)?,
+			}
+		}
+		Ok(())
+	}
+}
+
+/// A green token allocated in a [GreenArena]
+#[derive(Debug, Clone, Copy)]
+pub struct ArenaToken<'arena> {
+	kind: SyntaxKind,
+	text: &'arena str,
+	leading: &'arena [TriviaPiece],
+	trailing: &'arena [TriviaPiece],
+}
+
+impl<'arena> ArenaToken<'arena> {
+	pub fn kind(&self) -> SyntaxKind {
+		self.kind
+	}
+
+	/// The text of the token, including its trivia
+	pub fn text(&self) -> &'arena str {
+		self.text
+	}
+
+	pub fn text_len(&self) -> TextSize {
+		TextSize::of(self.text)
+	}
+
+	pub fn leading_trivia(&self) -> &'arena [TriviaPiece] {
+		self.leading
+	}
+
+	pub fn trailing_trivia(&self) -> &'arena [TriviaPiece] {
+		self.trailing
+	}
+}
+
+impl fmt::Display for ArenaToken<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(self.text, f)
+	}
+}
+
+/// A checkpoint for maybe wrapping a node, see [TreeBuilder::checkpoint](crate::TreeBuilder::checkpoint).
+#[derive(Clone, Copy, Debug)]
+pub struct ArenaCheckpoint(usize);
+
+/// A builder for a syntax tree whose green data lives in a [GreenArena].
+/// It has the same API as [TreeBuilder](crate::TreeBuilder) and implements [GreenBuilder],
+/// so that the tree sink of a parser can build its trees in an arena.
+///
+/// ```
+/// use rome_rowan::{api::RawLanguage, arena::{ArenaTreeBuilder, GreenArena}, SyntaxKind};
+///
+/// let arena = GreenArena::new();
+/// let mut builder = ArenaTreeBuilder::<RawLanguage>::new(&arena);
+/// builder.start_node(SyntaxKind(0));
+/// builder.token(SyntaxKind(1), "let");
+/// builder.missing();
+/// builder.finish_node();
+/// let root = builder.finish();
+///
+/// assert_eq!("let", root.to_string());
+/// assert_eq!(2, root.slots().len());
+/// assert_eq!("let", root.to_syntax::<RawLanguage>().text());
+/// ```
+#[derive(Debug)]
+pub struct ArenaTreeBuilder<'arena, L: Language> {
+	arena: &'arena Bump,
+	parents: Vec<(L::Kind, usize)>,
+	children: Vec<Option<ArenaElement<'arena>>>,
+	/// The errors of the started nodes with the number of parents at the time the error was recorded
+	errors: Vec<(usize, TextRange, String)>,
+	/// The length of all the tokens added to the builder
+	text_len: TextSize,
+	_p: PhantomData<L>,
+}
+
+impl<'arena, L: Language> ArenaTreeBuilder<'arena, L> {
+	pub fn new(arena: &'arena GreenArena) -> Self {
+		ArenaTreeBuilder {
+			arena: &arena.bump,
+			parents: Vec::new(),
+			children: Vec::new(),
+			errors: Vec::new(),
+			text_len: TextSize::from(0),
+			_p: PhantomData,
+		}
+	}
+
+	/// Adds new token to the current branch.
+	#[inline]
+	pub fn token(&mut self, kind: L::Kind, text: &str) {
+		self.token_with_trivia(kind, text, Vec::new(), Vec::new())
+	}
+
+	/// Adds new token to the current branch.
+	#[inline]
+	pub fn token_with_trivia(
+		&mut self,
+		kind: L::Kind,
+		text: &str,
+		leading: Vec<TriviaPiece>,
+		trailing: Vec<TriviaPiece>,
+	) {
+		let token = self.arena.alloc(ArenaToken {
+			kind: L::kind_to_raw(kind),
+			text: self.arena.alloc_str(text),
+			leading: self.arena.alloc_slice_copy(&leading),
+			trailing: self.arena.alloc_slice_copy(&trailing),
+		});
+		self.text_len += token.text_len();
+		self.children.push(Some(NodeOrToken::Token(token)));
+	}
+
+	/// Inserts a placeholder for a missing child, see [TreeBuilder::missing](crate::TreeBuilder::missing).
+	#[inline]
+	pub fn missing(&mut self) {
+		self.children.push(None);
+	}
+
+	/// Records an error on the current node, see [TreeBuilder::error](crate::TreeBuilder::error).
+	///
+	/// ## Panics
+	/// If no node has been started
+	pub fn error(&mut self, range: TextRange, message: impl Into<String>) {
+		assert!(
+			!self.parents.is_empty(),
+			"errors can only be recorded inside of a node"
+		);
+		self.errors
+			.push((self.parents.len(), range, message.into()));
+	}
+
+	/// Returns the range of the current node so far, see [TreeBuilder::current_node_range](crate::TreeBuilder::current_node_range).
+	pub fn current_node_range(&self) -> Option<TextRange> {
+		let (_, first_child) = self.parents.last()?;
+		Some(self.range_of_children(*first_child))
+	}
+
+	/// The range of the children starting at `first_child`, which end at the end of the text added so far
+	fn range_of_children(&self, first_child: usize) -> TextRange {
+		let len = self.children[first_child..]
+			.iter()
+			.flatten()
+			.map(element_len)
+			.sum();
+		TextRange::at(self.text_len - len, len)
+	}
+
+	/// Start new node and make it current.
+	#[inline]
+	pub fn start_node(&mut self, kind: L::Kind) {
+		let len = self.children.len();
+		self.parents.push((kind, len));
+	}
+
+	/// Finish current branch and restore previous
+	/// branch as current.
+	#[inline]
+	pub fn finish_node(&mut self) {
+		let depth = self.parents.len();
+		let (kind, first_child) = self.parents.pop().unwrap();
+
+		// The errors of the children have already been taken by the children
+		let first_error = self
+			.errors
+			.iter()
+			.rposition(|(error_depth, _, _)| *error_depth < depth)
+			.map_or(0, |index| index + 1);
+		let node_range = self.range_of_children(first_child);
+		let arena = self.arena;
+		let errors = arena.alloc_slice_fill_iter(self.errors.drain(first_error..).map(
+			|(_, range, message)| {
+				assert!(
+					node_range.contains_range(range),
+					"the range {:?} of the error {:?} is outside of the node {:?}",
+					range,
+					message,
+					node_range
+				);
+				ArenaNodeError {
+					rel_range: range - node_range.start(),
+					message: arena.alloc_str(&message),
+				}
+			},
+		));
+
+		let slots = arena.alloc_slice_copy(&self.children[first_child..]);
+		self.children.truncate(first_child);
+		let node = arena.alloc(ArenaNode {
+			kind: L::kind_to_raw(kind),
+			text_len: node_range.len(),
+			slots,
+			errors,
+		});
+		self.children.push(Some(NodeOrToken::Node(node)));
+	}
+
+	/// Prepare for maybe wrapping the next node, see [TreeBuilder::checkpoint](crate::TreeBuilder::checkpoint).
+	#[inline]
+	pub fn checkpoint(&self) -> ArenaCheckpoint {
+		ArenaCheckpoint(self.children.len())
+	}
+
+	/// Wrap the previous branch marked by `checkpoint` in a new branch and
+	/// make it current.
+	#[inline]
+	pub fn start_node_at(&mut self, checkpoint: ArenaCheckpoint, kind: L::Kind) {
+		let ArenaCheckpoint(checkpoint) = checkpoint;
+		assert!(
+			checkpoint <= self.children.len(),
+			"checkpoint no longer valid, was finish_node called early?"
+		);
+
+		if let Some(&(_, first_child)) = self.parents.last() {
+			assert!(
+				checkpoint >= first_child,
+				"checkpoint no longer valid, was an unmatched start_node_at called?"
+			);
+		}
+
+		self.parents.push((kind, checkpoint));
+	}
+
+	/// Complete tree building. Make sure that
+	/// `start_node_at` and `finish_node` calls
+	/// are paired!
+	#[must_use]
+	pub fn finish(mut self) -> &'arena ArenaNode<'arena> {
+		assert_eq!(self.children.len(), 1);
+		match self.children.pop().unwrap() {
+			Some(NodeOrToken::Node(node)) => node,
+			_ => panic!(),
+		}
+	}
+}
+
+impl<L: Language> GreenBuilder<L> for ArenaTreeBuilder<'_, L> {
+	fn start_node(&mut self, kind: L::Kind) {
+		ArenaTreeBuilder::start_node(self, kind)
+	}
+
+	fn finish_node(&mut self) {
+		ArenaTreeBuilder::finish_node(self)
+	}
+
+	fn token_with_trivia(
+		&mut self,
+		kind: L::Kind,
+		text: &str,
+		leading: Vec<TriviaPiece>,
+		trailing: Vec<TriviaPiece>,
+	) {
+		ArenaTreeBuilder::token_with_trivia(self, kind, text, leading, trailing)
+	}
+
+	fn missing(&mut self) {
+		ArenaTreeBuilder::missing(self)
+	}
+
+	fn error(&mut self, range: TextRange, message: String) {
+		ArenaTreeBuilder::error(self, range, message)
+	}
+
+	fn current_node_range(&self) -> Option<TextRange> {
+		ArenaTreeBuilder::current_node_range(self)
+	}
+}
+
+fn element_len(element: &ArenaElement<'_>) -> TextSize {
+	match element {
+		NodeOrToken::Node(node) => node.text_len(),
+		NodeOrToken::Token(token) => token.text_len(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ArenaTreeBuilder, GreenArena};
+	use crate::{api::RawLanguage, NodeOrToken, SyntaxKind, TextRange, TextSize, TriviaPiece};
+
+	#[test]
+	fn builds_the_same_tree_as_tree_builder() {
+		let mut arena = GreenArena::new();
+		let mut builder = ArenaTreeBuilder::<RawLanguage>::new(&arena);
+
+		builder.start_node(SyntaxKind(0));
+		let checkpoint = builder.checkpoint();
+		builder.token_with_trivia(
+			SyntaxKind(1),
+			"\n1 ",
+			vec![TriviaPiece::Newline(1)],
+			vec![TriviaPiece::Whitespace(1)],
+		);
+		builder.start_node_at(checkpoint, SyntaxKind(2));
+		builder.token(SyntaxKind(3), "+");
+		builder.missing();
+		assert_eq!(
+			Some(TextRange::new(0.into(), 4.into())),
+			builder.current_node_range()
+		);
+		builder.error(TextRange::new(3.into(), 4.into()), "expected an expression");
+		builder.finish_node();
+		builder.finish_node();
+		let root = builder.finish();
+
+		assert_eq!(TextSize::from(4), root.text_len());
+		assert_eq!("\n1 +", root.to_string());
+
+		let binary = match root.slots()[0] {
+			Some(NodeOrToken::Node(node)) => node,
+			_ => panic!("expected a node"),
+		};
+		assert_eq!(SyntaxKind(2), binary.kind());
+		assert_eq!(3, binary.slots().len());
+		assert_eq!(2, binary.children().count());
+		assert_eq!(1, binary.errors().len());
+		assert!(root.errors().is_empty());
+
+		let syntax = root.to_syntax::<RawLanguage>();
+		let token = syntax.first_token().unwrap();
+		assert_eq!("1", token.text_trimmed());
+		assert_eq!("\n", token.leading_trivia().text());
+		let binary = syntax.first_child().unwrap();
+		assert_eq!(2, binary.children_with_tokens().count());
+		let errors: Vec<_> = binary.errors().collect();
+		assert_eq!("expected an expression", errors[0].message());
+		assert_eq!(TextRange::new(3.into(), 4.into()), errors[0].range());
+
+		assert!(arena.allocated_bytes() > 0);
+		arena.reset();
+	}
+}
@@ -95,9 +95,9 @@ use std::{
 
 use countme::Count;
 
-use crate::green::{Slot, Slots};
+use crate::green::{GreenTokenTrivia, Slot, Slots};
 use crate::{
-	green::{Child, Children},
+	green::{Child, Children, GreenElement},
 	TriviaPiece,
 };
 use crate::{
@@ -108,12 +108,16 @@ use crate::{
 	WalkEvent,
 };
 
+#[allow(clippy::box_vec)]
 enum Green {
 	Node {
 		ptr: Cell<ptr::NonNull<GreenNodeData>>,
 	},
 	Token {
-		ptr: ptr::NonNull<GreenTokenData>,
+		ptr: Cell<ptr::NonNull<GreenTokenData>>,
+		/// The green tokens replaced by [SyntaxToken::set_text] and [SyntaxToken::set_kind]. They're
+		/// kept alive until the token is dropped because [SyntaxToken::text] borrows from them.
+		replaced: Cell<Option<Box<Vec<GreenToken>>>>,
 	},
 }
 
@@ -123,6 +127,12 @@ struct NodeData {
 	_c: Count<_SyntaxElement>,
 
 	rc: Cell<u32>,
+	/// The number of [WeakSyntaxNode]s pointing to this node. A node whose `rc` is zero has
+	/// released its parent and green node but its memory is only freed once `weak` is zero too.
+	weak: Cell<u32>,
+	/// Bumped on the root of a mutable tree whenever a node of the tree gains or loses a child, so that
+	/// [WeakSyntaxNode]s created before the edit no longer resolve their path.
+	version: Cell<u32>,
 	parent: Cell<Option<ptr::NonNull<NodeData>>>,
 	slot: Cell<u32>,
 	green: Green,
@@ -153,6 +163,9 @@ unsafe impl sll::Elem for NodeData {
 
 pub(crate) type SyntaxElement = NodeOrToken<SyntaxNode, SyntaxToken>;
 
+/// The number of bytes allocated for a node or token of the red tree
+pub(crate) const RED_ELEMENT_SIZE: usize = mem::size_of::<NodeData>();
+
 #[derive(PartialEq, Eq, Clone, Hash)]
 pub(crate) struct SyntaxTrivia {
 	offset: TextSize,
@@ -208,13 +221,14 @@ unsafe fn free(mut data: ptr::NonNull<NodeData>) {
 	loop {
 		debug_assert_eq!(data.as_ref().rc.get(), 0);
 		debug_assert!(data.as_ref().first.get().is_null());
-		let node = Box::from_raw(data.as_ptr());
+		let node = data.as_ref();
 		match node.parent.take() {
 			Some(parent) => {
 				debug_assert!(parent.as_ref().rc.get() > 0);
 				if node.mutable {
-					sll::unlink(&parent.as_ref().first, &*node)
+					sll::unlink(&parent.as_ref().first, node)
 				}
+				dealloc_unless_weak(data);
 				if parent.as_ref().dec_rc() {
 					data = parent;
 				} else {
@@ -226,16 +240,24 @@ unsafe fn free(mut data: ptr::NonNull<NodeData>) {
 					Green::Node { ptr } => {
 						let _ = GreenNode::from_raw(ptr.get());
 					}
-					Green::Token { ptr } => {
-						let _ = GreenToken::from_raw(*ptr);
+					Green::Token { ptr, .. } => {
+						let _ = GreenToken::from_raw(ptr.get());
 					}
 				}
+				dealloc_unless_weak(data);
 				break;
 			}
 		}
 	}
 }
 
+/// Frees the memory of a released node unless a [WeakSyntaxNode] still points to it
+unsafe fn dealloc_unless_weak(data: ptr::NonNull<NodeData>) {
+	if data.as_ref().weak.get() == 0 {
+		drop(Box::from_raw(data.as_ptr()));
+	}
+}
+
 impl NodeData {
 	#[inline]
 	fn new(
@@ -249,6 +271,8 @@ impl NodeData {
 		let res = NodeData {
 			_c: Count::new(),
 			rc: Cell::new(1),
+			weak: Cell::new(0),
+			version: Cell::new(0),
 			parent: Cell::new(parent.as_ref().map(|it| it.ptr)),
 			slot: Cell::new(slot),
 			green,
@@ -307,6 +331,24 @@ impl NodeData {
 		self.rc.set(rc)
 	}
 
+	/// Invalidates the [WeakSyntaxNode]s of the tree of this node
+	fn bump_version(&self) {
+		let mut root = self;
+		while let Some(parent) = root.parent() {
+			root = parent;
+		}
+		root.version.set(root.version.get().wrapping_add(1));
+	}
+
+	#[inline]
+	fn inc_weak(&self) {
+		let weak = match self.weak.get().checked_add(1) {
+			Some(it) => it,
+			None => std::process::abort(),
+		};
+		self.weak.set(weak)
+	}
+
 	#[inline]
 	fn dec_rc(&self) -> bool {
 		let rc = self.rc.get() - 1;
@@ -318,7 +360,7 @@ impl NodeData {
 	fn key(&self) -> (ptr::NonNull<()>, TextSize) {
 		let ptr = match &self.green {
 			Green::Node { ptr } => ptr.get().cast(),
-			Green::Token { ptr } => ptr.cast(),
+			Green::Token { ptr, .. } => ptr.get().cast(),
 		};
 		(ptr, self.offset())
 	}
@@ -342,7 +384,7 @@ impl NodeData {
 	fn green(&self) -> GreenElementRef<'_> {
 		match &self.green {
 			Green::Node { ptr } => GreenElementRef::Node(unsafe { &*ptr.get().as_ptr() }),
-			Green::Token { ptr } => GreenElementRef::Token(unsafe { &*ptr.as_ref() }),
+			Green::Token { ptr, .. } => GreenElementRef::Token(unsafe { ptr.get().as_ref() }),
 		}
 	}
 
@@ -469,6 +511,7 @@ impl NodeData {
 		unsafe {
 			sll::adjust(self, self.slot() + 1, Delta::Sub(1));
 			let parent = parent_ptr.as_ref();
+			parent.bump_version();
 			sll::unlink(&parent.first, self);
 
 			// Add strong ref to green
@@ -498,6 +541,9 @@ impl NodeData {
 		assert!(self.mutable && child.mutable && child.parent().is_none());
 		assert!(self.rc.get() > 0 && child.rc.get() > 0);
 
+		self.bump_version();
+		child.bump_version();
+
 		unsafe {
 			child.slot.set(index as u32);
 			child.parent.set(Some(self.into()));
@@ -519,7 +565,7 @@ impl NodeData {
 					// Child is root, so it owns the green node. Steal it!
 					let child_green = match &child.green {
 						Green::Node { ptr } => GreenNode::from_raw(ptr.get()).into(),
-						Green::Token { ptr } => GreenToken::from_raw(*ptr).into(),
+						Green::Token { ptr, .. } => GreenToken::from_raw(ptr.get()).into(),
 					};
 
 					let green = green.insert_slot(index, Some(child_green));
@@ -529,6 +575,63 @@ impl NodeData {
 			}
 		}
 	}
+	/// Inserts `child` into the slot `index` of the green node without creating a [NodeData] for it
+	fn insert_green_child(&self, index: usize, child: GreenElement) {
+		assert!(self.mutable);
+		assert!(self.rc.get() > 0);
+		self.bump_version();
+
+		unsafe {
+			if !self.first.get().is_null() {
+				sll::adjust(&*self.first.get(), index as u32, Delta::Add(1));
+			}
+
+			match self.green() {
+				NodeOrToken::Node(green) => {
+					let green = green.insert_slot(index, Some(child));
+					self.respine(green);
+				}
+				NodeOrToken::Token(_) => unreachable!(),
+			}
+		}
+	}
+	/// Replaces the green token of this token, updating the green nodes of its ancestors
+	fn replace_green_token(&self, new_green: GreenToken) {
+		assert!(self.mutable);
+		assert!(self.rc.get() > 0);
+		let (ptr, replaced) = match &self.green {
+			Green::Token { ptr, replaced } => (ptr, replaced),
+			Green::Node { .. } => unreachable!(),
+		};
+		let new_ptr = ptr::NonNull::from(&*new_green);
+
+		unsafe {
+			let old_green = match self.parent() {
+				Some(parent) => {
+					let old_green = ptr.get().as_ref().to_owned();
+					match parent.green() {
+						NodeOrToken::Node(green) => {
+							let green =
+								green.replace_child(self.slot() as usize, Some(new_green.into()));
+							parent.respine(green)
+						}
+						NodeOrToken::Token(_) => unreachable!(),
+					}
+					old_green
+				}
+				// A token without a parent owns its green token
+				None => {
+					GreenToken::into_raw(new_green);
+					GreenToken::from_raw(ptr.get())
+				}
+			};
+
+			ptr.set(new_ptr);
+			let mut list = replaced.take().unwrap_or_default();
+			list.push(old_green);
+			replaced.set(Some(list));
+		}
+	}
 	unsafe fn respine(&self, mut new_green: GreenNode) {
 		let mut node = self;
 		loop {
@@ -573,6 +676,11 @@ impl Iterator for SyntaxTriviaPiecesIterator {
 
 		Some(piece)
 	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.raw.len() - self.next_index.min(self.raw.len());
+		(len, Some(len))
+	}
 }
 
 impl DoubleEndedIterator for SyntaxTriviaPiecesIterator {
@@ -601,6 +709,16 @@ impl SyntaxTrivia {
 		}
 	}
 
+	/// The number of pieces of this trivia
+	pub(crate) fn len(&self) -> usize {
+		let green_token = self.token.green();
+		if self.is_leading {
+			green_token.leading_trivia().len()
+		} else {
+			green_token.trailing_trivia().len()
+		}
+	}
+
 	/// Gets index-th trivia piece when the token associated with this trivia was created.
 	/// See [SyntaxTriviaPiece].
 	pub(crate) fn get_piece(&self, index: usize) -> Option<TriviaPiece> {
@@ -619,24 +737,25 @@ impl SyntaxTrivia {
 		SyntaxTriviaPiecesIterator {
 			raw: self.clone(),
 			next_index: 0,
-			next_offset: self.offset,
+			next_offset: self.text_range().start(),
 		}
 	}
-
-	#[inline]
-	pub(crate) fn offset(&self) -> TextSize {
-		self.offset
-	}
 }
 
 impl SyntaxNode {
 	pub(crate) fn new_root(green: GreenNode) -> SyntaxNode {
+		SyntaxNode::new_root_at(green, 0.into())
+	}
+
+	/// Creates a root that starts at `offset`, for example a copy of a node of another tree
+	/// that keeps the text ranges of the original node.
+	pub(crate) fn new_root_at(green: GreenNode, offset: TextSize) -> SyntaxNode {
 		let green = GreenNode::into_raw(green);
 		let green = Green::Node {
 			ptr: Cell::new(green),
 		};
 		SyntaxNode {
-			ptr: NodeData::new(None, 0, 0.into(), green, false),
+			ptr: NodeData::new(None, 0, offset, green, false),
 		}
 	}
 
@@ -685,6 +804,39 @@ impl SyntaxNode {
 		unsafe { self.ptr.as_ref() }
 	}
 
+	pub fn downgrade(&self) -> WeakSyntaxNode {
+		let mut path = Vec::new();
+		let mut root = self.clone();
+		while let Some(parent) = root.parent() {
+			path.push(root.data().slot());
+			root = parent;
+		}
+		path.reverse();
+
+		root.data().inc_weak();
+		WeakSyntaxNode {
+			root: root.ptr,
+			version: root.data().version.get(),
+			path,
+		}
+	}
+
+	/// Returns a cheap identity of this node: the address of its green node and its offset.
+	#[inline]
+	pub(crate) fn key(&self) -> (ptr::NonNull<()>, TextSize) {
+		self.data().key()
+	}
+
+	/// Returns the address of the data of the root of this node's tree, which tells apart trees
+	/// that share their green nodes.
+	pub(crate) fn root_key(&self) -> ptr::NonNull<()> {
+		let mut data = self.data();
+		while let Some(parent) = data.parent() {
+			data = parent;
+		}
+		ptr::NonNull::from(data).cast()
+	}
+
 	#[inline]
 	pub fn kind(&self) -> SyntaxKind {
 		self.data().kind()
@@ -827,6 +979,21 @@ impl SyntaxNode {
 		})
 	}
 
+	pub fn nth_child(&self, n: usize) -> Option<SyntaxNode> {
+		let child = self
+			.green_ref()
+			.children()
+			.filter(|child| child.element().into_node().is_some())
+			.nth(n)?;
+		let green = child.element().into_node()?;
+		Some(SyntaxNode::new_child(
+			green,
+			self.clone(),
+			child.slot(),
+			self.offset() + child.rel_offset(),
+		))
+	}
+
 	pub fn last_child(&self) -> Option<SyntaxNode> {
 		self.green_ref().children().rev().find_map(|child| {
 			child.element().into_node().map(|green| {
@@ -1028,6 +1195,34 @@ impl SyntaxNode {
 		self.data().detach()
 	}
 
+	/// Inserts `child` into the slot `index`, shifting the following slots.
+	///
+	/// A `child` of a mutable tree, including this tree, is moved. A `child` of an immutable
+	/// tree is copied and the tree it belongs to stays unchanged.
+	pub fn insert_child_at(&self, index: usize, child: SyntaxElement) {
+		assert!(self.data().mutable, "immutable tree: {}", self);
+		if let NodeOrToken::Node(node) = &child {
+			assert!(
+				!self.ancestors().any(|ancestor| &ancestor == node),
+				"can't insert a node into its own subtree"
+			);
+		}
+
+		let mutable = match &child {
+			NodeOrToken::Node(it) => it.data().mutable,
+			NodeOrToken::Token(it) => it.data().mutable,
+		};
+		if mutable {
+			self.attach_child(index, child);
+		} else {
+			let green = match &child {
+				NodeOrToken::Node(it) => it.green().into(),
+				NodeOrToken::Token(it) => it.green().to_owned().into(),
+			};
+			self.data().insert_green_child(index, green);
+		}
+	}
+
 	fn attach_child(&self, index: usize, child: SyntaxElement) {
 		assert!(self.data().mutable, "immutable tree: {}", self);
 		child.detach();
@@ -1047,14 +1242,17 @@ impl SyntaxToken {
 		offset: TextSize,
 	) -> SyntaxToken {
 		let mutable = parent.data().mutable;
-		let green = Green::Token { ptr: green.into() };
+		let green = Green::Token {
+			ptr: Cell::new(green.into()),
+			replaced: Cell::new(None),
+		};
 		SyntaxToken {
 			ptr: NodeData::new(Some(parent), index, offset, green, mutable),
 		}
 	}
 
 	#[inline]
-	fn green(&self) -> &GreenTokenData {
+	pub(crate) fn green(&self) -> &GreenTokenData {
 		match self.data().green().as_token() {
 			Some(token) => token,
 			None => {
@@ -1097,7 +1295,7 @@ impl SyntaxToken {
 	}
 
 	#[inline]
-	pub fn text(&self) -> &str {
+	pub fn text(&self) -> Cow<str> {
 		self.green().text()
 	}
 
@@ -1162,6 +1360,128 @@ impl SyntaxToken {
 		self.data().detach()
 	}
 
+	/// Returns a new token with `text` as its text, excluding trivia, in a new tree. The rest of
+	/// the tree is structurally shared with the tree of this token.
+	pub fn with_text(&self, text: &str) -> SyntaxToken {
+		let green = self.green();
+		let text = format!(
+			"{}{}{}",
+			green.text_leading_trivia(),
+			text,
+			green.text_trailing_trivia()
+		);
+		let token = GreenToken::with_trivia(
+			green.kind(),
+			&text,
+			green.leading_trivia().clone(),
+			green.trailing_trivia().clone(),
+		);
+		self.replace_with(token)
+	}
+
+	/// Returns a new token with the given leading trivia in a new tree. The rest of the tree is
+	/// structurally shared with the tree of this token.
+	pub fn with_leading_trivia(&self, text: &str, trivia: GreenTokenTrivia) -> SyntaxToken {
+		let green = self.green();
+		let token = GreenToken::with_shared_text(
+			green.kind(),
+			green.text_trimmed_shared().clone(),
+			text,
+			green.text_trailing_trivia(),
+			trivia,
+			green.trailing_trivia().clone(),
+		);
+		self.replace_with(token)
+	}
+
+	/// Returns a new token with the given trailing trivia in a new tree. The rest of the tree is
+	/// structurally shared with the tree of this token.
+	pub fn with_trailing_trivia(&self, text: &str, trivia: GreenTokenTrivia) -> SyntaxToken {
+		let green = self.green();
+		let token = GreenToken::with_shared_text(
+			green.kind(),
+			green.text_trimmed_shared().clone(),
+			green.text_leading_trivia(),
+			text,
+			green.leading_trivia().clone(),
+			trivia,
+		);
+		self.replace_with(token)
+	}
+
+	/// Replaces the text of this token, excluding its trivia, in its mutable tree
+	pub fn set_text(&self, text: &str) {
+		assert!(self.data().mutable, "immutable tree: {}", self);
+		let green = self.green();
+		let text = format!(
+			"{}{}{}",
+			green.text_leading_trivia(),
+			text,
+			green.text_trailing_trivia()
+		);
+		let token = GreenToken::with_trivia(
+			green.kind(),
+			&text,
+			green.leading_trivia().clone(),
+			green.trailing_trivia().clone(),
+		);
+		self.data().replace_green_token(token)
+	}
+
+	/// Replaces the kind of this token in its mutable tree
+	pub fn set_kind(&self, kind: SyntaxKind) {
+		assert!(self.data().mutable, "immutable tree: {}", self);
+		let green = self.green();
+		let token = GreenToken::with_shared_text(
+			kind,
+			green.text_trimmed_shared().clone(),
+			green.text_leading_trivia(),
+			green.text_trailing_trivia(),
+			green.leading_trivia().clone(),
+			green.trailing_trivia().clone(),
+		);
+		self.data().replace_green_token(token)
+	}
+
+	/// Replaces the green token of this token and rebuilds all its ancestors.
+	/// Returns the token inside of the new (immutable) root.
+	fn replace_with(&self, replacement: GreenToken) -> SyntaxToken {
+		let mut path = vec![self.index() as u32];
+		let mut green = GreenElement::from(replacement);
+		let mut slot = self.index();
+
+		let mut parent = self.parent();
+		while let Some(node) = parent {
+			let new_green = node.green_ref().replace_child(slot, Some(green));
+			slot = node.index();
+			parent = node.parent();
+
+			if parent.is_some() {
+				path.push(slot as u32);
+			}
+			green = new_green.into();
+		}
+
+		let root = match green {
+			NodeOrToken::Node(node) => SyntaxNode::new_root(node),
+			NodeOrToken::Token(_) => unreachable!("A token must have a parent node"),
+		};
+
+		let mut current = root;
+		while let Some(slot) = path.pop() {
+			match current.element_in_slot(slot) {
+				Some(NodeOrToken::Node(node)) => current = node,
+				Some(NodeOrToken::Token(token)) => {
+					debug_assert!(path.is_empty());
+					return token;
+				}
+				None => unreachable!("The replaced slot can't be empty"),
+			}
+		}
+
+		unreachable!("The path must end with the replaced token")
+	}
+
 	#[inline]
 	pub fn leading_trivia(&self) -> SyntaxTrivia {
 		SyntaxTrivia {
@@ -1297,6 +1617,68 @@ impl fmt::Display for SyntaxTrivia {
 	}
 }
 
+/// A reference to a node that doesn't keep the node or its tree alive.
+///
+/// The nodes of a tree are created on demand and released as soon as nothing references them, so a weak
+/// reference to the node itself would die with the last handle to the node even though the tree is still
+/// alive. Instead, it references the root of the tree and the slots of the path from the root to the node,
+/// and re-creates the node from the root when upgrading. Editing a mutable tree can move the node to
+/// another path, so upgrading fails once the tree has changed since the node was downgraded.
+pub(crate) struct WeakSyntaxNode {
+	root: ptr::NonNull<NodeData>,
+	/// The version of the root when the node was downgraded
+	version: u32,
+	path: Vec<u32>,
+}
+
+impl WeakSyntaxNode {
+	/// Returns the node if its tree is still alive and hasn't been edited since the node was downgraded
+	pub fn upgrade(&self) -> Option<SyntaxNode> {
+		let data = unsafe { self.root.as_ref() };
+		if data.rc.get() == 0 || data.version.get() != self.version {
+			return None;
+		}
+
+		data.inc_rc();
+		let mut node = SyntaxNode { ptr: self.root };
+		for &slot in &self.path {
+			if slot as usize >= node.green_ref().slots().len() {
+				return None;
+			}
+			node = node.element_in_slot(slot)?.into_node()?;
+		}
+		Some(node)
+	}
+
+	pub fn is_alive(&self) -> bool {
+		self.upgrade().is_some()
+	}
+}
+
+impl Clone for WeakSyntaxNode {
+	#[inline]
+	fn clone(&self) -> Self {
+		unsafe { self.root.as_ref() }.inc_weak();
+		WeakSyntaxNode {
+			root: self.root,
+			version: self.version,
+			path: self.path.clone(),
+		}
+	}
+}
+
+impl Drop for WeakSyntaxNode {
+	#[inline]
+	fn drop(&mut self) {
+		let data = unsafe { self.root.as_ref() };
+		let weak = data.weak.get() - 1;
+		data.weak.set(weak);
+		if weak == 0 && data.rc.get() == 0 {
+			unsafe { drop(Box::from_raw(self.root.as_ptr())) }
+		}
+	}
+}
+
 // Identity semantics for hash & eq
 impl PartialEq for SyntaxNode {
 	#[inline]
@@ -1353,7 +1735,7 @@ impl Hash for SyntaxToken {
 
 impl fmt::Display for SyntaxToken {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		fmt::Display::fmt(self.text(), f)
+		fmt::Display::fmt(self.green(), f)
 	}
 }
 
@@ -0,0 +1,237 @@
+//! Batched edits of immutable trees, see [EditBuilder].
+
+use std::{cmp::Reverse, ops::Range};
+
+use crate::{
+	green::GreenElement, GreenNode, Language, NodeMap, NodeSet, SyntaxElement, SyntaxNode,
+	SyntaxNodePtr,
+};
+
+#[derive(Debug)]
+struct SlotEdit<L: Language> {
+	parent: SyntaxNodePtr<L>,
+	slots: Range<usize>,
+	replacement: Vec<Option<GreenElement>>,
+}
+
+/// Collects edits of a tree and applies them all at once, returning a new root.
+///
+/// Applying several [SyntaxNode::splice_children] one after another is error prone because each
+/// splice shifts the slot indices of the following siblings. The edits of an [EditBuilder] are
+/// instead addressed by the slots of the *original* tree and are applied in reverse slot order,
+/// so that no edit invalidates the position of another one.
+///
+/// ```
+/// use rome_rowan::{api::RawLanguage, EditBuilder, SyntaxKind, TreeBuilder};
+///
+/// let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+///     builder.token(SyntaxKind(1), "a");
+///     builder.token(SyntaxKind(1), "b");
+///     builder.token(SyntaxKind(1), "c");
+/// });
+/// let c = root.last_token().unwrap();
+///
+/// let mut edits = EditBuilder::new();
+/// edits.remove(root.first_token().unwrap());
+/// // The slot of `c` is still 2 even though `a` is removed by the previous edit
+/// edits.splice_slots(&root, 2..2, vec![Some(c.into())]);
+///
+/// assert_eq!("bcc", edits.finish(&root).text());
+/// // The original tree is unchanged
+/// assert_eq!("abc", root.text());
+/// ```
+#[derive(Debug)]
+pub struct EditBuilder<L: Language> {
+	edits: Vec<SlotEdit<L>>,
+}
+
+impl<L: Language> Default for EditBuilder<L> {
+	fn default() -> Self {
+		EditBuilder { edits: Vec::new() }
+	}
+}
+
+impl<L: Language> EditBuilder<L> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns `true` if no edit has been recorded
+	pub fn is_empty(&self) -> bool {
+		self.edits.is_empty()
+	}
+
+	/// Replaces the slots `slots` of `parent` with `replacement`. A [None] in `replacement`
+	/// inserts an empty slot.
+	pub fn splice_slots<I>(&mut self, parent: &SyntaxNode<L>, slots: Range<usize>, replacement: I)
+	where
+		I: IntoIterator<Item = Option<SyntaxElement<L>>>,
+	{
+		self.edits.push(SlotEdit {
+			parent: SyntaxNodePtr::new(parent),
+			slots,
+			replacement: replacement
+				.into_iter()
+				.map(|element| element.map(|element| element.green()))
+				.collect(),
+		});
+	}
+
+	/// Replaces `element` with `replacement`. Replacing an element with [None] keeps its slot but leaves it empty.
+	///
+	/// ## Panics
+	/// If `element` has no parent
+	pub fn replace(
+		&mut self,
+		element: impl Into<SyntaxElement<L>>,
+		replacement: Option<SyntaxElement<L>>,
+	) {
+		let element = element.into();
+		let parent = element
+			.parent()
+			.expect("can't replace an element without a parent");
+		let index = element.index();
+		self.splice_slots(&parent, index..index + 1, Some(replacement));
+	}
+
+	/// Removes `element` and its slot from its parent.
+	///
+	/// ## Panics
+	/// If `element` has no parent
+	pub fn remove(&mut self, element: impl Into<SyntaxElement<L>>) {
+		let element = element.into();
+		let parent = element
+			.parent()
+			.expect("can't remove an element without a parent");
+		let index = element.index();
+		self.splice_slots(&parent, index..index + 1, None);
+	}
+
+	/// Applies all edits to the tree of `root` and returns the root of the edited tree.
+	///
+	/// ## Panics
+	/// * If the parent of an edit can't be found in the tree of `root`
+	/// * If two edits of the same parent overlap
+	pub fn finish(self, root: &SyntaxNode<L>) -> SyntaxNode<L> {
+		let mut edits: NodeMap<L, Vec<SlotEdit<L>>> = NodeMap::new();
+		// The edited nodes and all their ancestors, each of which needs to be rebuilt
+		let mut to_rebuild = Vec::new();
+		let mut seen = NodeSet::new();
+
+		for edit in self.edits {
+			let parent = edit
+				.parent
+				.to_node(root)
+				.expect("the parent of an edit isn't part of the tree");
+
+			for ancestor in parent.ancestors() {
+				if !seen.insert(&ancestor) {
+					break;
+				}
+				to_rebuild.push((ancestor.ancestors().count(), ancestor));
+			}
+
+			edits.get_or_insert_with(&parent, Vec::new).push(edit);
+		}
+
+		// Rebuild the deepest nodes first so that the new children of a node are known when rebuilding the node
+		to_rebuild.sort_by_key(|(depth, _)| Reverse(*depth));
+
+		let mut rebuilt: NodeMap<L, GreenNode> = NodeMap::new();
+		for (_, node) in to_rebuild {
+			let mut green = node.green().into_owned();
+
+			for child in node.children() {
+				if let Some(new_child) = rebuilt.remove(&child) {
+					green = green.replace_child(child.index(), Some(new_child.into()));
+				}
+			}
+
+			if let Some(mut node_edits) = edits.remove(&node) {
+				node_edits.sort_by_key(|edit| Reverse(edit.slots.start));
+
+				for pair in node_edits.windows(2) {
+					assert!(
+						pair[1].slots.end <= pair[0].slots.start,
+						"overlapping edits of the slots {:?} and {:?}",
+						pair[1].slots,
+						pair[0].slots
+					);
+				}
+
+				for edit in node_edits {
+					green = green.splice_slots(edit.slots, edit.replacement);
+				}
+			}
+
+			rebuilt.insert(&node, green);
+		}
+
+		let green = match rebuilt.remove(root) {
+			Some(green) => green,
+			None => root.green().into_owned(),
+		};
+		SyntaxNode::new_root(green)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::EditBuilder;
+	use crate::{api::RawLanguage, SyntaxKind, SyntaxNode, TreeBuilder};
+
+	fn build() -> SyntaxNode<RawLanguage> {
+		TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "a");
+			builder.token(SyntaxKind(2), "b");
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "c");
+			builder.finish_node();
+			builder.finish_node();
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "d");
+			builder.finish_node();
+		})
+	}
+
+	#[test]
+	fn applies_edits_at_different_depths() {
+		let root = build();
+		let first = root.first_child().unwrap();
+		let nested = first.first_child().unwrap();
+		let d = root.last_token().unwrap();
+
+		let mut edits = EditBuilder::new();
+		edits.replace(first.first_token().unwrap(), Some(d.clone().into()));
+		edits.remove(nested.first_token().unwrap());
+		edits.splice_slots(&first, 1..1, vec![Some(d.into())]);
+		edits.replace(root.last_child().unwrap(), None);
+		assert!(!edits.is_empty());
+
+		let new_root = edits.finish(&root);
+		assert_eq!("ddb", new_root.text());
+		assert_eq!(1, new_root.children().count());
+		assert!(new_root.element_in_slot(1).is_none());
+		assert_eq!("abcd", root.text());
+	}
+
+	#[test]
+	fn returns_an_equal_tree_without_edits() {
+		let root = build();
+		let new_root = EditBuilder::new().finish(&root);
+		assert_eq!(format!("{:#?}", root), format!("{:#?}", new_root));
+	}
+
+	#[test]
+	#[should_panic(expected = "overlapping edits")]
+	fn rejects_overlapping_edits() {
+		let root = build();
+		let first = root.first_child().unwrap();
+
+		let mut edits = EditBuilder::new();
+		edits.splice_slots(&first, 0..2, None);
+		edits.splice_slots(&first, 1..3, None);
+		edits.finish(&root);
+	}
+}
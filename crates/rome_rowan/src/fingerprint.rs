@@ -0,0 +1,208 @@
+//! Structural hashes of subtrees, see [Fingerprint].
+
+use crate::{
+	green::{GreenElementRef, GreenTokenTrivia},
+	GreenNodeData, GreenTokenData, NodeOrToken, SyntaxKind, TriviaPiece,
+};
+
+/// A 64-bit hash of the kind, the text and the structure of a subtree: the kinds of all its nodes
+/// and tokens, the text of all its tokens and its empty slots.
+///
+/// Two subtrees with the same fingerprint are equal with a high probability, no matter in which
+/// tree or at which offset they are. Caching layers can use fingerprints to detect the subtrees
+/// that are unchanged after a re-parse and reuse the results of analyzing them.
+///
+/// A fingerprint either includes the trivia, see [SyntaxNode::fingerprint](crate::SyntaxNode::fingerprint),
+/// or ignores it, see [SyntaxNode::fingerprint_without_trivia](crate::SyntaxNode::fingerprint_without_trivia).
+/// Fingerprints are computed with a fixed hash function and don't change between runs of the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+	pub fn as_u64(self) -> u64 {
+		self.0
+	}
+}
+
+impl From<Fingerprint> for u64 {
+	fn from(fingerprint: Fingerprint) -> Self {
+		fingerprint.0
+	}
+}
+
+// Tags that separate the hashed parts, so that a node can't have the fingerprint of a token
+const NODE_TAG: u8 = 0;
+const TOKEN_TAG: u8 = 1;
+const EMPTY_SLOT_TAG: u8 = 2;
+
+/// The 64-bit FNV-1a hash, which is simple and doesn't depend on the platform or on the
+/// version of a hashing crate. Use it for hashes that are persisted, e.g. in a cache on disk.
+///
+/// ```
+/// use rome_rowan::StableHasher;
+///
+/// let mut hasher = StableHasher::new();
+/// hasher.write(b"");
+/// assert_eq!(0xcbf2_9ce4_8422_2325, hasher.finish());
+/// ```
+#[derive(Debug, Clone)]
+pub struct StableHasher(u64);
+
+impl StableHasher {
+	pub fn new() -> Self {
+		StableHasher(0xcbf2_9ce4_8422_2325)
+	}
+
+	pub fn write(&mut self, bytes: &[u8]) {
+		for byte in bytes {
+			self.0 ^= u64::from(*byte);
+			self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+		}
+	}
+
+	pub fn write_u64(&mut self, value: u64) {
+		self.write(&value.to_le_bytes());
+	}
+
+	pub fn finish(&self) -> u64 {
+		self.0
+	}
+}
+
+impl Default for StableHasher {
+	fn default() -> Self {
+		StableHasher::new()
+	}
+}
+
+pub(crate) fn node_fingerprint(node: &GreenNodeData, with_trivia: bool) -> Fingerprint {
+	combine(
+		node.kind(),
+		node.slots().map(|slot| {
+			slot.as_ref()
+				.map(|element| element_fingerprint(element, with_trivia))
+		}),
+	)
+}
+
+pub(crate) fn token_fingerprint(token: &GreenTokenData, with_trivia: bool) -> Fingerprint {
+	let mut hasher = StableHasher::new();
+	hasher.write(&[TOKEN_TAG]);
+	hasher.write(&token.kind().0.to_le_bytes());
+
+	if with_trivia {
+		for part in token.text_parts().iter() {
+			hasher.write(part.as_bytes());
+		}
+		write_trivia(&mut hasher, token.leading_trivia());
+		write_trivia(&mut hasher, token.trailing_trivia());
+	} else {
+		hasher.write(token.text_trimmed().as_bytes());
+	}
+
+	Fingerprint(hasher.finish())
+}
+
+pub(crate) fn element_fingerprint(element: GreenElementRef, with_trivia: bool) -> Fingerprint {
+	match element {
+		NodeOrToken::Node(node) => node_fingerprint(node, with_trivia),
+		NodeOrToken::Token(token) => token_fingerprint(token, with_trivia),
+	}
+}
+
+/// Returns the fingerprint of a node from the fingerprints of its slots, [None] for an empty slot
+pub(crate) fn combine(
+	kind: SyntaxKind,
+	slots: impl Iterator<Item = Option<Fingerprint>>,
+) -> Fingerprint {
+	let mut hasher = StableHasher::new();
+	hasher.write(&[NODE_TAG]);
+	hasher.write(&kind.0.to_le_bytes());
+	for slot in slots {
+		match slot {
+			Some(fingerprint) => hasher.write_u64(fingerprint.0),
+			None => hasher.write(&[EMPTY_SLOT_TAG]),
+		}
+	}
+	Fingerprint(hasher.finish())
+}
+
+fn write_trivia(hasher: &mut StableHasher, trivia: &GreenTokenTrivia) {
+	let mut index = 0;
+	while let Some(piece) = trivia.get_piece(index) {
+		index += 1;
+		let (tag, len) = match piece {
+			TriviaPiece::Whitespace(len) => (0, len),
+			TriviaPiece::Comments(len) => (1, len),
+			TriviaPiece::Newline(len) => (2, len),
+			TriviaPiece::Skipped(len) => (3, len),
+		};
+		hasher.write(&[tag]);
+		hasher.write_u64(len as u64);
+	}
+	// Separates the leading from the trailing trivia
+	hasher.write(&[u8::MAX]);
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{api::RawLanguage, parse_sexpr, SyntaxKind, TreeBuilder, TriviaPiece};
+
+	#[test]
+	fn equal_subtrees_have_equal_fingerprints() {
+		let root =
+			parse_sexpr("(0 (1 (2 'a') _ (2 ' ' 'b' '')) (1 (2 'a') _ (2 ' ' 'b' '')))").unwrap();
+		let first = root.first_child().unwrap();
+		let last = root.last_child().unwrap();
+
+		assert_ne!(first.text_range(), last.text_range());
+		assert_eq!(first.fingerprint(), last.fingerprint());
+		assert_ne!(root.fingerprint(), first.fingerprint());
+
+		let different = [
+			"(0 (1 (2 'a') _ (2 '  ' 'b' '')))",
+			"(0 (1 (2 'a') (2 ' ' 'b' '')))",
+			"(0 (1 (3 'a') _ (2 ' ' 'b' '')))",
+			"(0 (3 (2 'a') _ (2 ' ' 'b' '')))",
+			"(0 (1 (2 'a') _ (2 ' ' 'c' '')))",
+		];
+		for text in different {
+			let other = parse_sexpr(text).unwrap().first_child().unwrap();
+			assert_ne!(first.fingerprint(), other.fingerprint(), "{}", text);
+		}
+	}
+
+	#[test]
+	fn trivia_is_optional() {
+		let build = |leading: &str| {
+			TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+				builder.token_with_trivia(
+					SyntaxKind(1),
+					&format!("{}a", leading),
+					vec![TriviaPiece::Whitespace(leading.len())],
+					vec![],
+				);
+			})
+		};
+
+		let first = build(" ");
+		let second = build("  ");
+		assert_ne!(first.fingerprint(), second.fingerprint());
+		assert_eq!(
+			first.fingerprint_without_trivia(),
+			second.fingerprint_without_trivia()
+		);
+
+		let comment = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token_with_trivia(SyntaxKind(1), " a", vec![TriviaPiece::Comments(1)], vec![]);
+		});
+		assert_ne!(first.fingerprint(), comment.fingerprint());
+
+		let token = first.first_token().unwrap();
+		assert_eq!(
+			token.fingerprint_without_trivia(),
+			second.first_token().unwrap().fingerprint_without_trivia()
+		);
+		assert_ne!(token.fingerprint(), first.fingerprint());
+	}
+}
@@ -0,0 +1,84 @@
+//! Folding ranges for editors, see [SyntaxNode::folding_ranges].
+
+use crate::{Language, SyntaxNode, TextRange, TextSize};
+
+impl<L: Language> SyntaxNode<L> {
+	/// Returns the ranges an editor can fold: the trimmed ranges of this node and its descendants
+	/// for which `is_foldable` returns `true` and that span multiple lines, in preorder.
+	///
+	/// The ranges exclude the leading and trailing trivia of the nodes, so that folding a block
+	/// doesn't hide the comment before it or the line break after it.
+	///
+	/// ```
+	/// use rome_rowan::{parse_sexpr, SyntaxKind, TextRange};
+	///
+	/// const BLOCK: SyntaxKind = SyntaxKind(1);
+	///
+	/// // {a}\n{\nb\n}
+	/// let root = parse_sexpr(
+	///     "(0 (1 (2 '{') (2 'a') (2 '}')) (1 (2 '\n' '{' '') (2 '\n' 'b' '') (2 '\n' '}' '')))",
+	/// )
+	/// .unwrap();
+	///
+	/// let ranges = root.folding_ranges(|kind| kind == BLOCK);
+	/// assert_eq!(vec![TextRange::new(4.into(), 9.into())], ranges);
+	/// ```
+	pub fn folding_ranges<F>(&self, is_foldable: F) -> Vec<TextRange>
+	where
+		F: Fn(L::Kind) -> bool,
+	{
+		let line_breaks = line_breaks(self);
+
+		self.descendants()
+			.filter(|node| is_foldable(node.kind()))
+			.map(|node| node.text_trimmed_range())
+			.filter(|range| {
+				let first_after_start =
+					line_breaks.partition_point(|offset| *offset < range.start());
+				matches!(line_breaks.get(first_after_start), Some(offset) if *offset < range.end())
+			})
+			.collect()
+	}
+}
+
+/// Returns the offsets of all line breaks in the text of `node`, in increasing order
+fn line_breaks<L: Language>(node: &SyntaxNode<L>) -> Vec<TextSize> {
+	let mut offsets = Vec::new();
+	for token in node.descendants_tokens() {
+		let mut start = token.text_range().start();
+		for part in token.green().text_parts().iter() {
+			for (index, chr) in part.char_indices() {
+				if matches!(chr, '\n' | '\r' | '\u{2028}' | '\u{2029}') {
+					offsets.push(start + TextSize::from(index as u32));
+				}
+			}
+			start += TextSize::of(*part);
+		}
+	}
+	offsets
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{parse_sexpr, SyntaxKind, TextRange};
+
+	#[test]
+	fn nested_multiline_nodes() {
+		// {\n{ a }\n{\r\nb}\n}
+		let root = parse_sexpr(
+			"(0 (1
+				(2 '{')
+				(1 (2 '\n' '{' '') (2 ' ' 'a' ' ') (2 '}'))
+				(1 (2 '\n' '{' '') (2 '\r\n' 'b' '') (2 '}'))
+				(2 '\n' '}' '')
+			))",
+		)
+		.unwrap();
+
+		let ranges = root.folding_ranges(|kind| kind == SyntaxKind(1));
+		let range = |start: u32, end: u32| TextRange::new(start.into(), end.into());
+		assert_eq!(vec![range(0, 15), range(8, 13)], ranges);
+
+		assert!(root.folding_ranges(|kind| kind == SyntaxKind(3)).is_empty());
+	}
+}
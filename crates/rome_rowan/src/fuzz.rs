@@ -0,0 +1,224 @@
+//! Fuzz targets for the cursor and green layers that don't need any fuzzing dependency.
+//!
+//! Every target builds a random tree from the input bytes, runs an operation on it, and panics
+//! if the result is inconsistent. A `cargo fuzz` target only needs to forward its input:
+//!
+//! ```ignore
+//! #![no_main]
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| rome_rowan::fuzz::splice(data));
+//! ```
+
+use crate::{
+	api::RawLanguage, parse_sexpr, to_sexpr, InvariantViolation, NodeOrToken, SyntaxElement,
+	SyntaxKind, SyntaxNode, TextRange, TextSize, TreeBuilder, TriviaPiece,
+};
+
+const MAX_DEPTH: usize = 5;
+const MAX_SLOTS: u8 = 5;
+
+/// Builds a tree and replaces a random range of the children of one of its nodes without empty
+/// slots with children of a second tree, then checks the text and the invariants of the edited tree.
+pub fn splice(data: &[u8]) {
+	let mut input = Input(data);
+	let root = input.tree().clone_for_update();
+	let other = input.tree().clone_for_update();
+
+	// `splice_children` deletes by child index but inserts by slot index, which only agree
+	// if none of the slots before the splice is empty
+	let nodes: Vec<_> = root
+		.descendants()
+		.filter(|node| node.children_with_tokens().count() == node.green().slots().len())
+		.collect();
+	if nodes.is_empty() {
+		return;
+	}
+	let parent = &nodes[input.index(nodes.len())];
+	let children: Vec<_> = parent.children_with_tokens().collect();
+	let start = input.index(children.len() + 1);
+	let end = start + input.index(children.len() - start + 1);
+
+	let to_insert: Vec<_> = other
+		.children_with_tokens()
+		.filter(|_| input.byte() % 2 == 0)
+		.collect();
+
+	let mut expected = String::new();
+	for child in &children[..start] {
+		expected.push_str(&element_text(child));
+	}
+	for child in &to_insert {
+		expected.push_str(&element_text(child));
+	}
+	for child in &children[end..] {
+		expected.push_str(&element_text(child));
+	}
+
+	parent.splice_children(start..end, to_insert);
+
+	assert_eq!(expected, parent.text().to_string());
+	assert_eq!(
+		Vec::<InvariantViolation<RawLanguage>>::new(),
+		root.validate()
+	);
+	assert!(other.validate().is_empty());
+}
+
+/// Builds a tree, writes it in the s-expression notation and checks that parsing the notation
+/// results in the same tree.
+pub fn sexpr_roundtrip(data: &[u8]) {
+	let tree = Input(data).tree();
+	let notation = to_sexpr(&tree);
+	let parsed = parse_sexpr(&notation).unwrap();
+
+	assert_eq!(tree.text(), parsed.text());
+	assert_eq!(notation, to_sexpr(&parsed));
+	assert!(parsed.validate().is_empty());
+}
+
+/// Builds a tree and checks that slicing its text and looking up elements in a random range
+/// are consistent with the text of the tree.
+pub fn text_slicing(data: &[u8]) {
+	let mut input = Input(data);
+	let tree = input.tree();
+	let text = tree.text().to_string();
+
+	// All generated texts are ASCII, so every offset is a char boundary
+	let start = input.index(text.len() + 1);
+	let end = start + input.index(text.len() - start + 1);
+	let range = TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32));
+
+	assert_eq!(&text[start..end], tree.text().slice(range).to_string());
+	assert_eq!(
+		text[start..].chars().next(),
+		tree.text().char_at(range.start())
+	);
+
+	let covering = tree.covering_element(range);
+	assert!(covering.text_range().contains_range(range));
+
+	for token in tree.token_at_offset(range.start()) {
+		let token_range = token.text_range();
+		assert!(token_range.contains_inclusive(range.start()));
+		assert_eq!(
+			&text[usize::from(token_range.start())..usize::from(token_range.end())],
+			token.text()
+		);
+	}
+}
+
+fn element_text(element: &SyntaxElement<RawLanguage>) -> String {
+	match element {
+		NodeOrToken::Node(node) => node.text().to_string(),
+		NodeOrToken::Token(token) => token.text().to_string(),
+	}
+}
+
+/// Reads the fuzz input, returning zeros once all bytes are consumed
+struct Input<'a>(&'a [u8]);
+
+impl Input<'_> {
+	fn byte(&mut self) -> u8 {
+		match self.0.split_first() {
+			Some((byte, rest)) => {
+				self.0 = rest;
+				*byte
+			}
+			None => 0,
+		}
+	}
+
+	/// Returns an index smaller than `len`, which must be greater than zero
+	fn index(&mut self, len: usize) -> usize {
+		usize::from(self.byte()) % len
+	}
+
+	fn tree(&mut self) -> SyntaxNode<RawLanguage> {
+		let mut builder = TreeBuilder::new();
+		self.node(&mut builder, 0);
+		builder.finish()
+	}
+
+	fn node(&mut self, builder: &mut TreeBuilder<'_, RawLanguage>, depth: usize) {
+		builder.start_node(self.kind());
+		for _ in 0..self.byte() % (MAX_SLOTS + 1) {
+			match self.byte() % 3 {
+				0 => builder.missing(),
+				1 if depth < MAX_DEPTH => self.node(builder, depth + 1),
+				_ => self.token(builder),
+			}
+		}
+		builder.finish_node();
+	}
+
+	fn token(&mut self, builder: &mut TreeBuilder<'_, RawLanguage>) {
+		let kind = self.kind();
+		let (leading_text, leading) = self.trivia();
+		let text = self.word();
+		let (trailing_text, trailing) = self.trivia();
+		builder.token_with_trivia(
+			kind,
+			&[leading_text, text, trailing_text].concat(),
+			leading,
+			trailing,
+		);
+	}
+
+	fn kind(&mut self) -> SyntaxKind {
+		SyntaxKind(u16::from(self.byte() % 16))
+	}
+
+	fn trivia(&mut self) -> (String, Vec<TriviaPiece>) {
+		let mut text = String::new();
+		let mut pieces = Vec::new();
+		for _ in 0..self.byte() % 3 {
+			let piece_text = match self.byte() % 3 {
+				0 => {
+					pieces.push(TriviaPiece::Whitespace(1));
+					" ".to_string()
+				}
+				1 => {
+					pieces.push(TriviaPiece::Newline(1));
+					"\n".to_string()
+				}
+				_ => {
+					let comment = format!("/*{}*/", self.word());
+					pieces.push(TriviaPiece::Comments(comment.len()));
+					comment
+				}
+			};
+			text.push_str(&piece_text);
+		}
+		(text, pieces)
+	}
+
+	/// Returns a word of up to five ASCII letters, which may be empty
+	fn word(&mut self) -> String {
+		(0..self.byte() % 6)
+			.map(|_| char::from(b'a' + self.byte() % 26))
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{sexpr_roundtrip, splice, text_slicing};
+
+	#[test]
+	fn targets_accept_any_input() {
+		let mut seed = 0x9e37_79b9_u32;
+		for len in 0..200 {
+			let data: Vec<u8> = (0..len * 4)
+				.map(|_| {
+					seed ^= seed << 13;
+					seed ^= seed >> 17;
+					seed ^= seed << 5;
+					seed as u8
+				})
+				.collect();
+
+			splice(&data);
+			sexpr_roundtrip(&data);
+			text_slicing(&data);
+		}
+	}
+}
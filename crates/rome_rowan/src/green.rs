@@ -5,14 +5,14 @@ mod token;
 
 pub(crate) use self::{
 	element::{GreenElement, GreenElementRef},
-	node::{Child, Children, GreenNode, GreenNodeData, Slot, Slots},
-	token::{GreenToken, GreenTokenData},
+	node::{Child, Children, GreenNode, GreenNodeData, GreenNodeError, Slot, Slots},
+	token::{GreenToken, GreenTokenData, GreenTokenTrivia},
 };
 
-pub use self::node_cache::NodeCache;
+pub use self::node_cache::{register_common_tokens, NodeCache};
 
 /// SyntaxKind is a type tag for each token or node.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SyntaxKind(pub u16);
 
 #[cfg(test)]
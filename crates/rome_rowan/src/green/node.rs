@@ -12,7 +12,7 @@ use std::{
 use countme::Count;
 
 use crate::{
-	arc::{Arc, HeaderSlice, ThinArc},
+	arc::{Arc, ArcInner, HeaderSlice, ThinArc},
 	green::{GreenElement, GreenElementRef, SyntaxKind},
 	utility_types::static_assert,
 	GreenToken, NodeOrToken, TextRange, TextSize,
@@ -22,9 +22,20 @@ use crate::{
 pub(super) struct GreenNodeHead {
 	kind: SyntaxKind,
 	text_len: TextSize,
+	/// A boxed slice rather than a [Vec] because the errors never change and most nodes have none,
+	/// an empty boxed slice doesn't allocate
+	errors: Box<[GreenNodeError]>,
 	_c: Count<GreenNode>,
 }
 
+/// An error recorded on a node when building the tree, see [TreeBuilder::error](crate::TreeBuilder::error)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct GreenNodeError {
+	/// The range of the error relative to the start of the node
+	pub(crate) rel_range: TextRange,
+	pub(crate) message: Box<str>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) enum Slot {
 	Node {
@@ -158,6 +169,36 @@ impl GreenNodeData {
 		self.header().text_len
 	}
 
+	/// Returns the errors recorded on this node when building the tree
+	#[inline]
+	pub(crate) fn errors(&self) -> &[GreenNodeError] {
+		&self.header().errors
+	}
+
+	/// Returns the number of bytes allocated for this node, excluding its children.
+	pub(crate) fn heap_size(&self) -> usize {
+		let errors_size = self
+			.errors()
+			.iter()
+			.map(|error| mem::size_of::<GreenNodeError>() + error.message.len())
+			.sum::<usize>();
+		mem::size_of::<ArcInner<ReprThin>>() + mem::size_of_val(self.slice()) + errors_size
+	}
+
+	/// Returns the errors that are before the slot at `index` and are therefore unaffected by
+	/// changing the slots starting at `index`
+	fn errors_before_slot(&self, index: usize) -> Vec<GreenNodeError> {
+		let end = self
+			.slice()
+			.get(index)
+			.map_or_else(|| self.text_len(), Slot::rel_offset);
+		self.errors()
+			.iter()
+			.filter(|error| error.rel_range.end() <= end)
+			.cloned()
+			.collect()
+	}
+
 	/// Children of this node.
 	#[inline]
 	pub fn children(&self) -> Children<'_> {
@@ -203,7 +244,7 @@ impl GreenNodeData {
 				child.as_ref().map(|elem| elem.to_owned())
 			}
 		});
-		GreenNode::new(self.kind(), slots)
+		GreenNode::with_errors(self.kind(), slots, self.errors_before_slot(index))
 	}
 
 	#[must_use]
@@ -232,8 +273,13 @@ impl GreenNodeData {
 			})
 			.collect();
 
+		let first_changed = match range.start_bound() {
+			ops::Bound::Included(index) => *index,
+			ops::Bound::Excluded(index) => index + 1,
+			ops::Bound::Unbounded => 0,
+		};
 		slots.splice(range, replace_with);
-		GreenNode::new(self.kind(), slots)
+		GreenNode::with_errors(self.kind(), slots, self.errors_before_slot(first_changed))
 	}
 }
 
@@ -254,6 +300,19 @@ impl GreenNode {
 	/// Creates new Node.
 	#[inline]
 	pub fn new<I>(kind: SyntaxKind, slots: I) -> GreenNode
+	where
+		I: IntoIterator<Item = Option<GreenElement>>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		GreenNode::with_errors(kind, slots, Vec::new())
+	}
+
+	/// Creates a new node with the given errors
+	pub(crate) fn with_errors<I>(
+		kind: SyntaxKind,
+		slots: I,
+		errors: Vec<GreenNodeError>,
+	) -> GreenNode
 	where
 		I: IntoIterator<Item = Option<GreenElement>>,
 		I::IntoIter: ExactSizeIterator,
@@ -277,6 +336,7 @@ impl GreenNode {
 			GreenNodeHead {
 				kind,
 				text_len: 0.into(),
+				errors: errors.into_boxed_slice(),
 				_c: Count::new(),
 			},
 			slots,
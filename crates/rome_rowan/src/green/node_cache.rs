@@ -1,6 +1,8 @@
 use hashbrown::hash_map::RawEntryMut;
+use once_cell::sync::OnceCell;
 use rustc_hash::FxHasher;
 use std::hash::{BuildHasherDefault, Hash, Hasher};
+use std::sync::Arc;
 
 use crate::api::TriviaPiece;
 use crate::green::Slot;
@@ -10,13 +12,79 @@ use crate::{
 };
 
 use super::element::GreenElement;
-use super::token::GreenTokenTrivia;
+use super::token::{split_token_text, GreenTokenTrivia};
 
 type HashMap<K, V> = hashbrown::HashMap<K, V, BuildHasherDefault<FxHasher>>;
+type HashSet<T> = hashbrown::HashSet<T, BuildHasherDefault<FxHasher>>;
 
 #[derive(Debug)]
 struct NoHash<T>(T);
 
+/// Tokens without trivia that are shared by all caches, see [register_common_tokens]
+static COMMON_TOKENS: OnceCell<HashMap<NoHash<GreenToken>, ()>> = OnceCell::new();
+
+/// Registers tokens without trivia, like punctuation and keywords, that all trees of the process share.
+///
+/// A [NodeCache] only deduplicates the tokens of the trees built with it, so every new cache allocates
+/// its own `;` and `(` tokens again. Building a token without trivia whose kind and text are registered
+/// instead returns the registered token, without allocating.
+///
+/// Only the first registration takes effect, later calls return `false` and leave the registered tokens
+/// unchanged. Languages can therefore register their tokens lazily before every parse.
+///
+/// ```
+/// use rome_rowan::{api::RawLanguage, register_common_tokens, SyntaxKind, TreeBuilder};
+///
+/// assert!(register_common_tokens(vec![(SyntaxKind(1), ";"), (SyntaxKind(2), "(")]));
+/// // Later registrations are ignored
+/// assert!(!register_common_tokens(vec![(SyntaxKind(3), ",")]));
+///
+/// let mut builder = TreeBuilder::<RawLanguage>::new();
+/// builder.start_node(SyntaxKind(0));
+/// builder.token(SyntaxKind(1), ";");
+/// builder.finish_node();
+/// assert_eq!(";", builder.finish().text());
+/// ```
+pub fn register_common_tokens<I, T>(tokens: I) -> bool
+where
+	I: IntoIterator<Item = (SyntaxKind, T)>,
+	T: AsRef<str>,
+{
+	let mut registered = false;
+	COMMON_TOKENS.get_or_init(|| {
+		registered = true;
+		let mut common = HashMap::default();
+		for (kind, text) in tokens {
+			let text = text.as_ref();
+			let hash = token_hash_of(kind, ["", text, ""]);
+			let entry = common
+				.raw_entry_mut()
+				.from_hash(hash, |token: &NoHash<GreenToken>| {
+					token.0.kind() == kind && token.0.text_parts() == ["", text, ""]
+				});
+			if let RawEntryMut::Vacant(entry) = entry {
+				let token = GreenToken::with_trivia(
+					kind,
+					text,
+					GreenTokenTrivia::from(Vec::new()),
+					GreenTokenTrivia::from(Vec::new()),
+				);
+				entry.insert_with_hasher(hash, NoHash(token), (), |t| token_hash(&t.0));
+			}
+		}
+		common
+	});
+	registered
+}
+
+/// Returns the registered token with the given kind and text, see [register_common_tokens]
+fn common_token(hash: u64, kind: SyntaxKind, text: &str) -> Option<GreenToken> {
+	let (token, _) = COMMON_TOKENS.get()?.raw_entry().from_hash(hash, |token| {
+		token.0.kind() == kind && token.0.text_parts() == ["", text, ""]
+	})?;
+	Some(token.0.clone())
+}
+
 /// Interner for GreenTokens and GreenNodes
 // XXX: the impl is a bit tricky. As usual when writing interners, we want to
 // store all values in one HashSet.
@@ -36,21 +104,36 @@ struct NoHash<T>(T);
 //
 // To fix that, we additionally wrap the data in `NoHash` wrapper, to make sure
 // we don't accidentally use the wrong hash!
+//
+// The text of tokens without their trivia is interned separately, so that `console `
+// and `console\n` share the `console` text.
 #[derive(Default, Debug)]
 pub struct NodeCache {
 	nodes: HashMap<NoHash<GreenNode>, ()>,
 	tokens: HashMap<NoHash<GreenToken>, ()>,
+	texts: HashSet<Arc<str>>,
 }
 
-fn token_hash_of(kind: SyntaxKind, text: &str) -> u64 {
+/// Hashes a token from its kind and the text of its leading trivia, its trimmed text and its trailing trivia
+fn token_hash_of(kind: SyntaxKind, text_parts: [&str; 3]) -> u64 {
 	let mut h = FxHasher::default();
 	kind.hash(&mut h);
-	text.hash(&mut h);
+	text_parts.hash(&mut h);
 	h.finish()
 }
 
 fn token_hash(token: &GreenTokenData) -> u64 {
-	token_hash_of(token.kind(), token.text())
+	token_hash_of(token.kind(), token.text_parts())
+}
+
+/// Returns the shared copy of `text`
+fn intern_text(texts: &mut HashSet<Arc<str>>, text: &str) -> Arc<str> {
+	if let Some(text) = texts.get(text) {
+		return text.clone();
+	}
+	let text: Arc<str> = Arc::from(text);
+	texts.insert(text.clone());
+	text
 }
 
 fn node_hash(node: &GreenNodeData) -> u64 {
@@ -82,10 +165,47 @@ impl NodeCache {
 	/// Hash for an empty slot
 	const EMPTY_SLOT_HASH: u64 = 1;
 
+	/// Creates an empty cache.
+	///
+	/// Identifiers like `require` or `console` repeat across the files of a project. Building the
+	/// trees of several files with the same cache, see [TreeBuilder::with_cache](crate::TreeBuilder::with_cache),
+	/// stores every distinct token and small node only once, no matter in how many trees it appears.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The number of distinct tokens stored in the cache
+	pub fn token_count(&self) -> usize {
+		self.tokens.len()
+	}
+
+	/// The number of distinct nodes stored in the cache
+	pub fn node_count(&self) -> usize {
+		self.nodes.len()
+	}
+
+	/// The number of distinct token texts, excluding their trivia, stored in the cache
+	pub fn text_count(&self) -> usize {
+		self.texts.len()
+	}
+
+	/// Removes all tokens, nodes and texts from the cache, e.g. to bound the memory used by a long lived cache.
+	/// Trees built with the cache are not affected.
+	pub fn clear(&mut self) {
+		self.nodes.clear();
+		self.tokens.clear();
+		self.texts.clear();
+	}
+
 	pub(crate) const fn empty() -> (u64, Option<GreenElement>) {
 		(Self::EMPTY_SLOT_HASH, None)
 	}
 
+	/// Returns the slot of a node that wasn't built with the cache
+	pub(crate) fn uncached(node: GreenNode) -> (u64, Option<GreenElement>) {
+		(Self::UNCACHED_NODE_HASH, Some(node.into()))
+	}
+
 	pub(crate) fn node(
 		&mut self,
 		kind: SyntaxKind,
@@ -164,19 +284,42 @@ impl NodeCache {
 		leading: Vec<TriviaPiece>,
 		trailing: Vec<TriviaPiece>,
 	) -> (u64, GreenToken) {
-		let hash = token_hash_of(kind, text);
-
 		let leading = GreenTokenTrivia::from(leading);
 		let trailing = GreenTokenTrivia::from(trailing);
+		let text_parts = split_token_text(text, &leading, &trailing);
+		let hash = token_hash_of(kind, text_parts);
+
+		if let (GreenTokenTrivia::None, GreenTokenTrivia::None) = (&leading, &trailing) {
+			if let Some(token) = common_token(hash, kind, text) {
+				return (hash, token);
+			}
+		}
 
 		let entry = self.tokens.raw_entry_mut().from_hash(hash, |token| {
-			token.0.kind() == kind && token.0.text() == text
+			token.0.kind() == kind
+				&& token.0.text_parts() == text_parts
+				&& token.0.leading_trivia() == &leading
+				&& token.0.trailing_trivia() == &trailing
 		});
 
 		let token = match entry {
 			RawEntryMut::Occupied(entry) => entry.key().0.clone(),
 			RawEntryMut::Vacant(entry) => {
-				let token = GreenToken::with_trivia(kind, text, leading, trailing);
+				let [leading_text, trimmed, trailing_text] = text_parts;
+				// Punctuation and keywords with trivia share the text of the registered token
+				let text = match common_token(token_hash_of(kind, ["", trimmed, ""]), kind, trimmed)
+				{
+					Some(common) => common.text_trimmed_shared().clone(),
+					None => intern_text(&mut self.texts, trimmed),
+				};
+				let token = GreenToken::with_shared_text(
+					kind,
+					text,
+					leading_text,
+					trailing_text,
+					leading,
+					trailing,
+				);
 				entry.insert_with_hasher(hash, NoHash(token.clone()), (), |t| token_hash(&t.0));
 				token
 			}
@@ -216,3 +359,86 @@ fn green_token_hash() {
 	);
 	assert_ne!(token_hash(&t1), token_hash(&t4));
 }
+
+#[test]
+fn cache_shared_between_trees() {
+	use crate::{api::RawLanguage, TreeBuilder};
+
+	let mut cache = NodeCache::new();
+	for _ in 0..2 {
+		let mut builder = TreeBuilder::<RawLanguage>::with_cache(&mut cache);
+		builder.start_node(SyntaxKind(0));
+		builder.token(SyntaxKind(1), "console");
+		builder.token(SyntaxKind(2), ".");
+		builder.token(SyntaxKind(1), "console");
+		builder.finish_node();
+		assert_eq!("console.console", builder.finish().text());
+	}
+
+	assert_eq!(2, cache.token_count());
+	assert_eq!(1, cache.node_count());
+
+	cache.clear();
+	assert_eq!(0, cache.token_count());
+}
+
+#[test]
+fn token_texts_shared_without_trivia() {
+	let mut cache = NodeCache::new();
+	let (_, space) = cache.token_with_trivia(
+		SyntaxKind(1),
+		"console ",
+		Vec::new(),
+		vec![TriviaPiece::Whitespace(1)],
+	);
+	let (_, newline) = cache.token_with_trivia(
+		SyntaxKind(1),
+		"\nconsole",
+		vec![TriviaPiece::Newline(1)],
+		Vec::new(),
+	);
+	let (_, other_kind) = cache.token(SyntaxKind(2), "console");
+
+	assert_eq!("console ", space.text());
+	assert_eq!("\nconsole", newline.text());
+	assert_eq!(3, cache.token_count());
+	assert_eq!(1, cache.text_count());
+	assert!(std::ptr::eq(space.text_trimmed(), newline.text_trimmed()));
+	assert!(std::ptr::eq(
+		space.text_trimmed(),
+		other_kind.text_trimmed()
+	));
+
+	// The same text with different trivia is a different token
+	let (_, comment) = cache.token_with_trivia(
+		SyntaxKind(1),
+		"console ",
+		Vec::new(),
+		vec![TriviaPiece::Comments(1)],
+	);
+	assert!(!std::ptr::eq(&*space, &*comment));
+}
+
+#[test]
+fn common_tokens_shared_between_caches() {
+	register_common_tokens(vec![(SyntaxKind(100), ";"), (SyntaxKind(101), "(")]);
+
+	let (_, first) = NodeCache::new().token(SyntaxKind(100), ";");
+	let (_, second) = NodeCache::new().token(SyntaxKind(100), ";");
+	assert!(std::ptr::eq(&*first, &*second));
+
+	let mut cache = NodeCache::new();
+	cache.token(SyntaxKind(100), ";");
+	assert_eq!(0, cache.token_count());
+
+	// A token with trivia or another kind isn't shared
+	let (_, with_trivia) = cache.token_with_trivia(
+		SyntaxKind(100),
+		" ;",
+		vec![TriviaPiece::Whitespace(1)],
+		Vec::new(),
+	);
+	assert_eq!(" ;", with_trivia.text());
+	cache.token(SyntaxKind(102), ";");
+	assert_eq!(2, cache.token_count());
+}
@@ -1,17 +1,18 @@
 use std::{
-	borrow::Borrow,
+	borrow::{Borrow, Cow},
 	fmt,
 	mem::{self, ManuallyDrop},
 	ops, ptr,
+	sync::Arc as TextArc,
 };
 
 use countme::Count;
 
 use crate::{
 	api::TriviaPiece,
-	arc::{Arc, HeaderSlice, ThinArc},
+	arc::{Arc, ArcInner, HeaderSlice, ThinArc},
 	green::SyntaxKind,
-	TextSize,
+	TextRange, TextSize,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -40,6 +41,15 @@ impl GreenTokenTrivia {
 		}
 	}
 
+	/// The number of pieces
+	pub(crate) fn len(&self) -> usize {
+		match self {
+			GreenTokenTrivia::None => 0,
+			GreenTokenTrivia::Whitespace(_) | GreenTokenTrivia::Comments(_) => 1,
+			GreenTokenTrivia::Many(v) => v.len(),
+		}
+	}
+
 	pub(crate) fn get_piece(&self, index: usize) -> Option<TriviaPiece> {
 		match self {
 			GreenTokenTrivia::Whitespace(l) if index == 0 => Some(TriviaPiece::Whitespace(*l)),
@@ -64,11 +74,15 @@ impl From<Vec<TriviaPiece>> for GreenTokenTrivia {
 #[derive(PartialEq, Eq, Hash)]
 struct GreenTokenHead {
 	kind: SyntaxKind,
+	/// The text of the token without its trivia, shared by all tokens with the same text that
+	/// are built with the same [NodeCache](crate::NodeCache)
+	text: TextArc<str>,
 	leading: GreenTokenTrivia,
 	trailing: GreenTokenTrivia,
 	_c: Count<GreenToken>,
 }
 
+/// The slice holds the text of the leading trivia followed by the text of the trailing trivia
 type Repr = HeaderSlice<GreenTokenHead, [u8]>;
 type ReprThin = HeaderSlice<GreenTokenHead, [u8; 0]>;
 #[repr(transparent)]
@@ -78,7 +92,7 @@ pub(crate) struct GreenTokenData {
 
 impl PartialEq for GreenTokenData {
 	fn eq(&self, other: &Self) -> bool {
-		self.kind() == other.kind() && self.text() == other.text()
+		self.kind() == other.kind() && self.text_bytes().eq(other.text_bytes())
 	}
 }
 
@@ -136,7 +150,9 @@ impl fmt::Display for GreenToken {
 
 impl fmt::Display for GreenTokenData {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{}", self.text())
+		self.text_parts()
+			.iter()
+			.try_for_each(|part| f.write_str(part))
 	}
 }
 
@@ -148,51 +164,122 @@ impl GreenTokenData {
 	}
 
 	/// Whole text of this Token, including all trivia.
+	///
+	/// The text is only borrowed if the token has no trivia, use [GreenTokenData::text_parts]
+	/// to avoid copying the text of tokens with trivia.
+	#[inline]
+	pub fn text(&self) -> Cow<str> {
+		match self.text_parts() {
+			["", text, ""] => Cow::Borrowed(text),
+			parts => Cow::Owned(parts.concat()),
+		}
+	}
+
+	/// The text of the leading trivia, the trimmed text and the text of the trailing trivia of this Token.
 	#[inline]
-	pub fn text(&self) -> &str {
+	pub fn text_parts(&self) -> [&str; 3] {
+		let trivia = self.trivia_text();
+		let leading_len: usize = self.data.header.leading.text_len().into();
+		match (trivia.get(..leading_len), trivia.get(leading_len..)) {
+			(Some(leading), Some(trailing)) => [leading, self.text_trimmed(), trailing],
+			// The trivia didn't fit in the text, see [split_token_text]
+			_ => ["", self.text_trimmed(), ""],
+		}
+	}
+
+	/// Returns `false` if the trivia of the token is longer than its text or doesn't end at char boundaries
+	pub(crate) fn has_valid_trivia(&self) -> bool {
+		let (leading_len, trailing_len, _) = self.leading_trailing_total_len();
+		TextSize::of(self.trivia_text()) == leading_len + trailing_len
+	}
+
+	/// The non-empty pieces of the text in `range`, relative to the start of this Token, that
+	/// are stored contiguously
+	pub(crate) fn text_chunks(&self, range: TextRange) -> impl Iterator<Item = &str> {
+		let mut start = TextSize::from(0);
+		IntoIterator::into_iter(self.text_parts()).filter_map(move |part| {
+			let part_range = TextRange::at(start, TextSize::of(part));
+			start = part_range.end();
+			let range = range
+				.intersect(part_range)
+				.filter(|range| !range.is_empty())?;
+			Some(&part[range - part_range.start()])
+		})
+	}
+
+	/// The text in `range`, relative to the start of this Token, if it can be borrowed
+	pub(crate) fn text_in(&self, range: TextRange) -> Option<&str> {
+		let mut chunks = self.text_chunks(range);
+		let text = chunks.next()?;
+		match chunks.next() {
+			None => Some(text),
+			Some(_) => None,
+		}
+	}
+
+	/// The bytes of the whole text of this Token
+	fn text_bytes(&self) -> impl Iterator<Item = u8> + '_ {
+		let [leading, text, trailing] = self.text_parts();
+		leading.bytes().chain(text.bytes()).chain(trailing.bytes())
+	}
+
+	/// The text of the leading trivia followed by the text of the trailing trivia
+	#[inline]
+	fn trivia_text(&self) -> &str {
 		unsafe { std::str::from_utf8_unchecked(self.data.slice()) }
 	}
 
 	pub(crate) fn leading_trailing_total_len(&self) -> (TextSize, TextSize, TextSize) {
 		let leading_len = self.data.header.leading.text_len();
 		let trailing_len = self.data.header.trailing.text_len();
-		let total_len = self.data.slice().len() as u32;
-		(leading_len, trailing_len, total_len.into())
+		(leading_len, trailing_len, self.text_len())
 	}
 
 	/// Text of this Token, excluding all trivia.
 	#[inline]
 	pub fn text_trimmed(&self) -> &str {
-		let (leading_len, trailing_len, total_len) = self.leading_trailing_total_len();
+		&self.data.header.text
+	}
 
-		let start: usize = leading_len.into();
-		let end: usize = (total_len - trailing_len).into();
-		let text = unsafe { std::str::from_utf8_unchecked(self.data.slice()) };
-		&text[start..end]
+	/// The shared text of this Token, excluding all trivia.
+	#[inline]
+	pub(crate) fn text_trimmed_shared(&self) -> &TextArc<str> {
+		&self.data.header.text
 	}
 
 	#[inline]
 	pub fn text_leading_trivia(&self) -> &str {
-		let leading_len = self.leading_trivia().text_len();
-
-		let end: usize = leading_len.into();
-		let text = unsafe { std::str::from_utf8_unchecked(self.data.slice()) };
-		&text[0..end]
+		let [leading, _, _] = self.text_parts();
+		leading
 	}
 
 	#[inline]
 	pub fn text_trailing_trivia(&self) -> &str {
-		let (_, trailing_len, total_len) = self.leading_trailing_total_len();
-
-		let start: usize = (total_len - trailing_len).into();
-		let text = unsafe { std::str::from_utf8_unchecked(self.data.slice()) };
-		&text[start..]
+		let [_, _, trailing] = self.text_parts();
+		trailing
 	}
 
 	/// Returns the length of the text covered by this token.
 	#[inline]
 	pub fn text_len(&self) -> TextSize {
-		TextSize::of(self.text())
+		TextSize::of(self.trivia_text()) + TextSize::of(self.text_trimmed())
+	}
+
+	/// Returns the number of bytes allocated for this token, including its trivia.
+	pub(crate) fn heap_size(&self) -> usize {
+		let trivia_size = |trivia: &GreenTokenTrivia| match trivia {
+			GreenTokenTrivia::Many(pieces) => {
+				mem::size_of::<Vec<TriviaPiece>>()
+					+ pieces.capacity() * mem::size_of::<TriviaPiece>()
+			}
+			_ => 0,
+		};
+
+		// The trimmed text is shared between the tokens with the same text, so it's not included
+		mem::size_of::<ArcInner<ReprThin>>()
+			+ self.trivia_text().len()
+			+ trivia_size(self.leading_trivia())
+			+ trivia_size(self.trailing_trivia())
 	}
 
 	#[inline]
@@ -219,14 +306,38 @@ impl GreenToken {
 		text: &str,
 		leading: GreenTokenTrivia,
 		trailing: GreenTokenTrivia,
+	) -> GreenToken {
+		let [leading_text, trimmed, trailing_text] = split_token_text(text, &leading, &trailing);
+		Self::with_shared_text(
+			kind,
+			TextArc::from(trimmed),
+			leading_text,
+			trailing_text,
+			leading,
+			trailing,
+		)
+	}
+
+	/// Creates a token whose trimmed text is `text`, the text of its trivia is `leading_text` and `trailing_text`
+	#[inline]
+	pub(crate) fn with_shared_text(
+		kind: SyntaxKind,
+		text: TextArc<str>,
+		leading_text: &str,
+		trailing_text: &str,
+		leading: GreenTokenTrivia,
+		trailing: GreenTokenTrivia,
 	) -> GreenToken {
 		let head = GreenTokenHead {
 			kind,
+			text,
 			leading,
 			trailing,
 			_c: Count::new(),
 		};
-		let ptr = ThinArc::from_header_and_iter(head, text.bytes());
+		let mut bytes = leading_text.bytes().chain(trailing_text.bytes());
+		let len = leading_text.len() + trailing_text.len();
+		let ptr = ThinArc::from_header_and_iter(head, (0..len).map(|_| bytes.next().unwrap()));
 		GreenToken { ptr }
 	}
 
@@ -245,6 +356,25 @@ impl GreenToken {
 	}
 }
 
+/// Splits the text of a token into the text of its leading trivia, its trimmed text and the text of its trailing trivia.
+///
+/// The trivia of a token is only validated by [SyntaxNode::validate](crate::SyntaxNode::validate), so
+/// the whole text is the trimmed text if the trivia is longer than the text or doesn't end at char boundaries.
+pub(crate) fn split_token_text<'a>(
+	text: &'a str,
+	leading: &GreenTokenTrivia,
+	trailing: &GreenTokenTrivia,
+) -> [&'a str; 3] {
+	let start: usize = leading.text_len().into();
+	let end = text.len().checked_sub(trailing.text_len().into());
+	match end {
+		Some(end) if start <= end && text.is_char_boundary(start) && text.is_char_boundary(end) => {
+			[&text[..start], &text[start..end], &text[end..]]
+		}
+		_ => ["", text, ""],
+	}
+}
+
 impl ops::Deref for GreenToken {
 	type Target = GreenTokenData;
 
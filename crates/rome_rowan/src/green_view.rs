@@ -0,0 +1,284 @@
+//! A public, read-only view of the green tree, see [GreenNodeView].
+
+use std::{borrow::Cow, fmt};
+
+use crate::{
+	green::{GreenNode, GreenToken, GreenTokenTrivia},
+	Language, NodeOrToken, SyntaxKind, SyntaxNode, TextSize, TriviaPiece,
+};
+
+/// A node or a token of the green tree
+pub type GreenElementView = NodeOrToken<GreenNodeView, GreenTokenView>;
+
+/// The green node of a [SyntaxNode], returned by [SyntaxNode::green_view].
+///
+/// Green nodes are the immutable and position independent part of the tree: they store the kind,
+/// the slots and the length of a node but neither its offset nor its parent. A green node can be
+/// shared by many trees and sent to other threads. This view gives tools like serializers or
+/// custom tree builders access to the green tree without depending on its internal layout.
+///
+/// ```
+/// use rome_rowan::{parse_sexpr, SyntaxKind, TextSize};
+///
+/// let root = parse_sexpr("(0 (1 'a') _ (2 ' ' 'b' ''))").unwrap();
+/// let green = root.green_view();
+///
+/// let slots: Vec<_> = green
+///     .slots()
+///     .map(|slot| (slot.rel_offset(), slot.element().map(|element| element.kind())))
+///     .collect();
+///
+/// assert_eq!(
+///     vec![
+///         (TextSize::from(0), Some(SyntaxKind(1))),
+///         (TextSize::from(1), None),
+///         (TextSize::from(1), Some(SyntaxKind(2))),
+///     ],
+///     slots
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GreenNodeView {
+	node: GreenNode,
+}
+
+impl GreenNodeView {
+	pub(crate) fn new_raw(node: GreenNode) -> Self {
+		GreenNodeView { node }
+	}
+
+	/// Creates a green node from its slots, [None] for an empty slot.
+	///
+	/// ```
+	/// use rome_rowan::{api::RawLanguage, GreenNodeView, GreenTokenView, SyntaxKind, TriviaPiece};
+	///
+	/// let token = GreenTokenView::new(SyntaxKind(2), " a", vec![TriviaPiece::Whitespace(1)], vec![]);
+	/// let green = GreenNodeView::new(SyntaxKind(1), vec![None, Some(token.into())]);
+	///
+	/// let node = green.into_root::<RawLanguage>();
+	/// assert_eq!("a", node.text_trimmed());
+	/// assert_eq!(2, node.slots().len());
+	/// ```
+	pub fn new<I>(kind: SyntaxKind, slots: I) -> Self
+	where
+		I: IntoIterator<Item = Option<GreenElementView>>,
+		I::IntoIter: ExactSizeIterator,
+	{
+		let slots = slots.into_iter().map(|slot| {
+			slot.map(|element| match element {
+				NodeOrToken::Node(node) => NodeOrToken::Node(node.node),
+				NodeOrToken::Token(token) => NodeOrToken::Token(token.token),
+			})
+		});
+
+		GreenNodeView::new_raw(GreenNode::new(kind, slots))
+	}
+
+	pub fn kind(&self) -> SyntaxKind {
+		self.node.kind()
+	}
+
+	/// The length of the text of this node, including the trivia of its tokens
+	pub fn text_len(&self) -> TextSize {
+		self.node.text_len()
+	}
+
+	/// Returns the slots of this node, including the empty slots
+	pub fn slots(&self) -> impl ExactSizeIterator<Item = GreenSlotView> + '_ {
+		self.node.slots().map(|slot| GreenSlotView {
+			rel_offset: slot.rel_offset(),
+			element: slot.as_ref().map(|element| match element.to_owned() {
+				NodeOrToken::Node(node) => NodeOrToken::Node(GreenNodeView::new_raw(node)),
+				NodeOrToken::Token(token) => NodeOrToken::Token(GreenTokenView { token }),
+			}),
+		})
+	}
+
+	/// Returns the root of a new tree for this green node
+	pub fn into_root<L: Language>(self) -> SyntaxNode<L> {
+		SyntaxNode::new_root(self.node)
+	}
+}
+
+impl fmt::Debug for GreenNodeView {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.node, f)
+	}
+}
+
+/// A slot of a [GreenNodeView]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GreenSlotView {
+	rel_offset: TextSize,
+	element: Option<GreenElementView>,
+}
+
+impl GreenSlotView {
+	/// The offset of the slot relative to the start of its parent
+	pub fn rel_offset(&self) -> TextSize {
+		self.rel_offset
+	}
+
+	/// The node or token in this slot, or [None] if the slot is empty
+	pub fn element(&self) -> Option<&GreenElementView> {
+		self.element.as_ref()
+	}
+
+	pub fn into_element(self) -> Option<GreenElementView> {
+		self.element
+	}
+}
+
+/// The green token of a [SyntaxToken](crate::SyntaxToken), returned by
+/// [SyntaxToken::green_view](crate::SyntaxToken::green_view). See [GreenNodeView].
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GreenTokenView {
+	token: GreenToken,
+}
+
+impl GreenTokenView {
+	pub(crate) fn new_raw(token: GreenToken) -> Self {
+		GreenTokenView { token }
+	}
+
+	/// Creates a green token. `text` includes the text of the leading and trailing trivia.
+	///
+	/// ## Panics
+	/// If the trivia is longer than `text`
+	pub fn new(
+		kind: SyntaxKind,
+		text: &str,
+		leading: Vec<TriviaPiece>,
+		trailing: Vec<TriviaPiece>,
+	) -> Self {
+		let leading = GreenTokenTrivia::from(leading);
+		let trailing = GreenTokenTrivia::from(trailing);
+		assert!(
+			leading.text_len() + trailing.text_len() <= TextSize::of(text),
+			"the trivia of the token is longer than its text {:?}",
+			text
+		);
+
+		GreenTokenView::new_raw(GreenToken::with_trivia(kind, text, leading, trailing))
+	}
+
+	pub fn kind(&self) -> SyntaxKind {
+		self.token.kind()
+	}
+
+	/// The text of this token, including its trivia
+	pub fn text(&self) -> Cow<str> {
+		self.token.text()
+	}
+
+	/// The text of this token, excluding its trivia
+	pub fn text_trimmed(&self) -> &str {
+		self.token.text_trimmed()
+	}
+
+	pub fn text_len(&self) -> TextSize {
+		self.token.text_len()
+	}
+
+	pub fn leading_trivia(&self) -> impl ExactSizeIterator<Item = TriviaPiece> + '_ {
+		trivia_pieces(self.token.leading_trivia())
+	}
+
+	pub fn trailing_trivia(&self) -> impl ExactSizeIterator<Item = TriviaPiece> + '_ {
+		trivia_pieces(self.token.trailing_trivia())
+	}
+}
+
+impl fmt::Debug for GreenTokenView {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.token, f)
+	}
+}
+
+impl From<GreenNodeView> for GreenElementView {
+	fn from(node: GreenNodeView) -> Self {
+		NodeOrToken::Node(node)
+	}
+}
+
+impl From<GreenTokenView> for GreenElementView {
+	fn from(token: GreenTokenView) -> Self {
+		NodeOrToken::Token(token)
+	}
+}
+
+impl GreenElementView {
+	pub fn kind(&self) -> SyntaxKind {
+		match self {
+			NodeOrToken::Node(node) => node.kind(),
+			NodeOrToken::Token(token) => token.kind(),
+		}
+	}
+
+	pub fn text_len(&self) -> TextSize {
+		match self {
+			NodeOrToken::Node(node) => node.text_len(),
+			NodeOrToken::Token(token) => token.text_len(),
+		}
+	}
+}
+
+fn trivia_pieces(trivia: &GreenTokenTrivia) -> impl ExactSizeIterator<Item = TriviaPiece> + '_ {
+	(0..trivia.len()).map(move |index| trivia.get_piece(index).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		api::RawLanguage, parse_sexpr, GreenNodeView, GreenTokenView, NodeOrToken, SyntaxKind,
+		TriviaPiece,
+	};
+
+	/// Rebuilds a green node from its view, like a deserializer would
+	fn rebuild(node: &GreenNodeView) -> GreenNodeView {
+		let slots: Vec<_> = node
+			.slots()
+			.map(|slot| {
+				slot.into_element().map(|element| match element {
+					NodeOrToken::Node(node) => rebuild(&node).into(),
+					NodeOrToken::Token(token) => GreenTokenView::new(
+						token.kind(),
+						&token.text(),
+						token.leading_trivia().collect(),
+						token.trailing_trivia().collect(),
+					)
+					.into(),
+				})
+			})
+			.collect();
+		GreenNodeView::new(node.kind(), slots)
+	}
+
+	#[test]
+	fn rebuilt_tree_equals_the_original() {
+		let root =
+			parse_sexpr("(0 (1 (2 'let') (3 (2 ' ' 'a' ' '))) _ (1 (2 '=') (2 '' '1' '\n')))")
+				.unwrap();
+		let green = root.green_view();
+		let rebuilt = rebuild(&green);
+
+		assert_eq!(green, rebuilt);
+		assert_eq!(
+			root.to_string(),
+			rebuilt.into_root::<RawLanguage>().to_string()
+		);
+
+		let token = root.last_token().unwrap().green_view();
+		assert_eq!("1", token.text_trimmed());
+		assert_eq!(
+			vec![TriviaPiece::Whitespace(1)],
+			token.trailing_trivia().collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "longer than its text")]
+	fn rejects_trivia_longer_than_the_text() {
+		GreenTokenView::new(SyntaxKind(1), "a", vec![TriviaPiece::Whitespace(2)], vec![]);
+	}
+}
@@ -0,0 +1,49 @@
+//! A process wide registry for the [Debug](fmt::Debug) output of [SyntaxKind], see [register_kind_formatter].
+
+use std::{
+	fmt, mem, ptr,
+	sync::atomic::{AtomicPtr, Ordering},
+};
+
+use crate::SyntaxKind;
+
+/// Writes the human readable name of a raw kind
+pub type KindFormatter = fn(SyntaxKind, &mut fmt::Formatter<'_>) -> fmt::Result;
+
+static FORMATTER: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers the formatter used by the [Debug](fmt::Debug) implementation of [SyntaxKind].
+///
+/// The raw kinds of a [crate::api::RawLanguage] tree don't know the language that created them and
+/// print as `SyntaxKind(42)`. A language crate can register a formatter that prints the symbolic
+/// names of its kinds instead. The registry is shared by the whole process and the last registered
+/// formatter wins.
+///
+/// ```
+/// use rome_rowan::{register_kind_formatter, SyntaxKind};
+///
+/// assert_eq!("SyntaxKind(1)", format!("{:?}", SyntaxKind(1)));
+///
+/// register_kind_formatter(|kind, f| match kind.0 {
+///     1 => write!(f, "IDENT"),
+///     _ => write!(f, "SyntaxKind({})", kind.0),
+/// });
+/// assert_eq!("IDENT", format!("{:?}", SyntaxKind(1)));
+/// assert_eq!("SyntaxKind(2)", format!("{:?}", SyntaxKind(2)));
+/// ```
+pub fn register_kind_formatter(formatter: KindFormatter) {
+	FORMATTER.store(formatter as *mut (), Ordering::Release);
+}
+
+impl fmt::Debug for SyntaxKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let formatter = FORMATTER.load(Ordering::Acquire);
+		if formatter.is_null() {
+			f.debug_tuple("SyntaxKind").field(&self.0).finish()
+		} else {
+			// SAFETY: Only `register_kind_formatter` stores a non null pointer, which is a `KindFormatter`
+			let formatter = unsafe { mem::transmute::<*mut (), KindFormatter>(formatter) };
+			formatter(*self, f)
+		}
+	}
+}
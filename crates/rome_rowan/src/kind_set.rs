@@ -0,0 +1,176 @@
+//! Sets of syntax kinds, see [SyntaxKindSet].
+
+use std::fmt;
+use std::iter::FromIterator;
+
+use crate::{Language, SyntaxKind, SyntaxNode, SyntaxToken};
+
+const WORDS: usize = 16;
+
+/// A set of raw syntax kinds, stored as a bitset so that testing if a kind is in the set is a
+/// single lookup, no matter how many kinds the set contains.
+///
+/// Sets can be created in constants, which replaces long `matches!(kind, A | B | C)` chains
+/// that are repeated across analyses.
+///
+/// ```
+/// use rome_rowan::{parse_sexpr, SyntaxKind, SyntaxKindSet};
+///
+/// const STATEMENTS: SyntaxKindSet = SyntaxKindSet::from_raw(&[SyntaxKind(1), SyntaxKind(2)]);
+///
+/// let root = parse_sexpr("(0 (1 (3 (2 (4 'a')))) (5 'b'))").unwrap();
+/// let token = root.first_token().unwrap();
+///
+/// assert_eq!(2, root.descendants_of_kind(&STATEMENTS).count());
+/// assert_eq!(
+///     vec![SyntaxKind(2), SyntaxKind(1)],
+///     token.ancestors_of_kind(&STATEMENTS).map(|node| node.kind()).collect::<Vec<_>>()
+/// );
+/// assert!(!root.kind_in(&STATEMENTS));
+/// ```
+///
+/// The set supports the raw kinds up to `1023`, creating a set with a larger kind panics.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SyntaxKindSet {
+	bits: [u64; WORDS],
+}
+
+impl SyntaxKindSet {
+	/// The set without any kinds
+	pub const EMPTY: SyntaxKindSet = SyntaxKindSet { bits: [0; WORDS] };
+
+	/// Creates a set containing the given raw kinds
+	pub const fn from_raw(kinds: &[SyntaxKind]) -> Self {
+		let mut set = SyntaxKindSet::EMPTY;
+		let mut index = 0;
+		while index < kinds.len() {
+			set = set.with(kinds[index]);
+			index += 1;
+		}
+		set
+	}
+
+	/// Creates a set containing the kinds of a language
+	pub fn from_kinds<L: Language>(kinds: impl IntoIterator<Item = L::Kind>) -> Self {
+		kinds.into_iter().map(L::kind_to_raw).collect()
+	}
+
+	/// Returns a copy of this set that also contains `kind`
+	#[must_use]
+	pub const fn with(mut self, kind: SyntaxKind) -> Self {
+		let kind = kind.0 as usize;
+		self.bits[kind / 64] |= 1 << (kind % 64);
+		self
+	}
+
+	/// Returns the set containing the kinds of both sets
+	#[must_use]
+	pub const fn union(mut self, other: SyntaxKindSet) -> Self {
+		let mut index = 0;
+		while index < WORDS {
+			self.bits[index] |= other.bits[index];
+			index += 1;
+		}
+		self
+	}
+
+	#[inline]
+	pub fn contains(&self, kind: SyntaxKind) -> bool {
+		let kind = kind.0 as usize;
+		match self.bits.get(kind / 64) {
+			Some(word) => word & (1 << (kind % 64)) != 0,
+			None => false,
+		}
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.bits.iter().all(|word| *word == 0)
+	}
+
+	/// Returns the kinds of this set in increasing order
+	pub fn iter(&self) -> impl Iterator<Item = SyntaxKind> + '_ {
+		(0..WORDS * 64)
+			.filter(move |kind| self.bits[kind / 64] & (1 << (kind % 64)) != 0)
+			.map(|kind| SyntaxKind(kind as u16))
+	}
+}
+
+impl FromIterator<SyntaxKind> for SyntaxKindSet {
+	fn from_iter<I: IntoIterator<Item = SyntaxKind>>(kinds: I) -> Self {
+		kinds
+			.into_iter()
+			.fold(SyntaxKindSet::EMPTY, SyntaxKindSet::with)
+	}
+}
+
+impl fmt::Debug for SyntaxKindSet {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<L: Language> SyntaxNode<L> {
+	/// Returns `true` if the kind of this node is in `set`
+	pub fn kind_in(&self, set: &SyntaxKindSet) -> bool {
+		set.contains(L::kind_to_raw(self.kind()))
+	}
+
+	/// Returns the ancestors of this node, including itself, whose kind is in `set`
+	pub fn ancestors_of_kind(&self, set: &SyntaxKindSet) -> impl Iterator<Item = SyntaxNode<L>> {
+		let set = *set;
+		self.ancestors().filter(move |node| node.kind_in(&set))
+	}
+
+	/// Returns the descendants of this node, including itself, whose kind is in `set`, in preorder
+	pub fn descendants_of_kind(&self, set: &SyntaxKindSet) -> impl Iterator<Item = SyntaxNode<L>> {
+		let set = *set;
+		self.descendants().filter(move |node| node.kind_in(&set))
+	}
+}
+
+impl<L: Language> SyntaxToken<L> {
+	/// Returns `true` if the kind of this token is in `set`
+	pub fn kind_in(&self, set: &SyntaxKindSet) -> bool {
+		set.contains(L::kind_to_raw(self.kind()))
+	}
+
+	/// Returns the ancestors of this token whose kind is in `set`
+	pub fn ancestors_of_kind(&self, set: &SyntaxKindSet) -> impl Iterator<Item = SyntaxNode<L>> {
+		let set = *set;
+		self.ancestors().filter(move |node| node.kind_in(&set))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SyntaxKindSet;
+	use crate::SyntaxKind;
+
+	#[test]
+	fn contains_the_added_kinds() {
+		let set = SyntaxKindSet::from_raw(&[SyntaxKind(0), SyntaxKind(63), SyntaxKind(64)]);
+		let other = SyntaxKindSet::EMPTY.with(SyntaxKind(1023));
+		let union = set.union(other);
+
+		assert!(set.contains(SyntaxKind(63)));
+		assert!(!set.contains(SyntaxKind(1)));
+		assert!(!set.contains(SyntaxKind(u16::MAX)));
+		assert!(SyntaxKindSet::EMPTY.is_empty());
+		assert_eq!(
+			vec![
+				SyntaxKind(0),
+				SyntaxKind(63),
+				SyntaxKind(64),
+				SyntaxKind(1023)
+			],
+			union.iter().collect::<Vec<_>>()
+		);
+		assert_eq!(union, union.iter().collect());
+	}
+
+	#[test]
+	#[should_panic]
+	fn rejects_kinds_outside_of_the_set() {
+		let _ = SyntaxKindSet::EMPTY.with(SyntaxKind(1024));
+	}
+}
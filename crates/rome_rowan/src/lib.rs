@@ -12,16 +12,39 @@
 pub mod cursor;
 #[allow(unsafe_code)]
 mod green;
+#[allow(unsafe_code)]
+mod kind_names;
 
 pub mod api;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(feature = "arena")]
+pub mod arena;
+mod edit;
+mod fingerprint;
+mod folding;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+mod green_view;
+mod kind_set;
+mod memory_stats;
+mod node_map;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod ptr;
+mod range_index;
 mod syntax_text;
+mod transaction;
 mod utility_types;
+mod validate;
+mod visitor;
 
 #[allow(unsafe_code)]
 mod arc;
 mod cow_mut;
 #[cfg(feature = "serde1")]
 mod serde_impls;
+mod sexpr;
 #[allow(unsafe_code)]
 mod sll;
 mod tree_builder;
@@ -30,13 +53,27 @@ pub use text_size::{TextLen, TextRange, TextSize};
 
 pub use crate::{
 	api::{
-		Language, SyntaxElement, SyntaxElementChildren, SyntaxList, SyntaxNode, SyntaxNodeChildren,
-		SyntaxToken, TriviaPiece,
+		Language, NodeError, SyntaxElement, SyntaxElementChildren, SyntaxList, SyntaxNode,
+		SyntaxNodeChildren, SyntaxSlot, SyntaxSlots, SyntaxToken, TextWithoutComments,
+		TriviaFilter, TriviaPiece, WeakSyntaxNode,
 	},
-	green::SyntaxKind,
+	edit::EditBuilder,
+	fingerprint::{Fingerprint, StableHasher},
+	green::{register_common_tokens, NodeCache, SyntaxKind},
+	green_view::{GreenElementView, GreenNodeView, GreenSlotView, GreenTokenView},
+	kind_names::{register_kind_formatter, KindFormatter},
+	kind_set::SyntaxKindSet,
+	memory_stats::MemoryStats,
+	node_map::{NodeMap, NodeSet, SyntaxNodeKey},
+	ptr::SyntaxNodePtr,
+	range_index::RangeIndex,
+	sexpr::{parse_sexpr, to_sexpr, SexprError},
 	syntax_text::SyntaxText,
-	tree_builder::{Checkpoint, TreeBuilder},
+	transaction::Transaction,
+	tree_builder::{Checkpoint, GreenBuilder, Subtree, TextLenOverflow, TreeBuilder, TreeEvent},
 	utility_types::{Direction, NodeOrToken, TokenAtOffset, WalkEvent},
+	validate::InvariantViolation,
+	visitor::{SyntaxVisitor, VisitControl},
 };
 
 pub(crate) use crate::green::{GreenNode, GreenNodeData, GreenToken, GreenTokenData};
@@ -0,0 +1,142 @@
+//! Statistics about the memory used by a syntax tree.
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+	cursor::{self, RED_ELEMENT_SIZE},
+	green::Slot,
+	GreenNodeData, GreenTokenData,
+};
+
+/// Memory used by the green nodes and tokens of a subtree, see [crate::SyntaxNode::memory_stats].
+///
+/// Identical nodes and tokens are shared between all the places where they appear in a tree.
+/// The `total_*` fields count every occurrence, as if nothing was shared, whereas the
+/// `unique_*` fields count every shared node or token once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+	/// Number of nodes in the tree
+	pub total_nodes: usize,
+	/// Number of tokens in the tree
+	pub total_tokens: usize,
+	/// Number of distinct green nodes
+	pub unique_nodes: usize,
+	/// Number of distinct green tokens
+	pub unique_tokens: usize,
+	/// Bytes the green tree would use without sharing identical nodes and tokens
+	pub total_bytes: usize,
+	/// Bytes used by the green tree
+	pub unique_bytes: usize,
+	/// Bytes used by the red tree if every node and token is alive at the same time.
+	///
+	/// Red nodes aren't cached: they're created while traversing the tree and freed once they're
+	/// dropped, this is an upper bound of what a traversal holding on to all nodes uses.
+	pub red_bytes: usize,
+}
+
+/// Totals of a green node, including all its descendants
+#[derive(Clone, Copy, Default)]
+struct Subtree {
+	nodes: usize,
+	tokens: usize,
+	bytes: usize,
+}
+
+impl MemoryStats {
+	pub(crate) fn of(node: &cursor::SyntaxNode) -> MemoryStats {
+		let green = node.green();
+		let mut stats = MemoryStats::default();
+
+		let mut subtrees: FxHashMap<*const GreenNodeData, Subtree> = FxHashMap::default();
+		let mut tokens: FxHashSet<*const GreenTokenData> = FxHashSet::default();
+
+		// Post order traversal using an explicit stack, syntax trees can be very deep
+		let mut stack: Vec<(&GreenNodeData, bool)> = vec![(&green, false)];
+		while let Some((node, children_visited)) = stack.pop() {
+			if subtrees.contains_key(&(node as *const _)) {
+				continue;
+			}
+
+			if !children_visited {
+				stack.push((node, true));
+				for slot in node.slots() {
+					if let Slot::Node { node: child, .. } = slot {
+						stack.push((child, false));
+					}
+				}
+				continue;
+			}
+
+			let node_size = node.heap_size();
+			let mut subtree = Subtree {
+				nodes: 1,
+				tokens: 0,
+				bytes: node_size,
+			};
+			stats.unique_nodes += 1;
+			stats.unique_bytes += node_size;
+
+			for slot in node.slots() {
+				match slot {
+					Slot::Node { node: child, .. } => {
+						let child = subtrees[&(&**child as *const _)];
+						subtree.nodes += child.nodes;
+						subtree.tokens += child.tokens;
+						subtree.bytes += child.bytes;
+					}
+					Slot::Token { token, .. } => {
+						let token_size = token.heap_size();
+						subtree.tokens += 1;
+						subtree.bytes += token_size;
+
+						if tokens.insert(&**token as *const _) {
+							stats.unique_tokens += 1;
+							stats.unique_bytes += token_size;
+						}
+					}
+					Slot::Empty { .. } => {}
+				}
+			}
+
+			subtrees.insert(node as *const _, subtree);
+		}
+
+		let root = subtrees[&(&*green as *const _)];
+		stats.total_nodes = root.nodes;
+		stats.total_tokens = root.tokens;
+		stats.total_bytes = root.bytes;
+		stats.red_bytes = (root.nodes + root.tokens) * RED_ELEMENT_SIZE;
+		stats
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::api::RawLanguage;
+	use crate::{SyntaxKind, TreeBuilder};
+
+	#[test]
+	fn counts_shared_nodes_once() {
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			for _ in 0..3 {
+				builder.start_node(SyntaxKind(1));
+				builder.token(SyntaxKind(2), "a");
+				builder.token(SyntaxKind(3), "+");
+				builder.token(SyntaxKind(2), "a");
+				builder.finish_node();
+			}
+		});
+
+		let stats = root.memory_stats();
+		assert_eq!(4, stats.total_nodes);
+		assert_eq!(9, stats.total_tokens);
+		assert_eq!(2, stats.unique_nodes);
+		assert_eq!(2, stats.unique_tokens);
+		assert!(stats.unique_bytes < stats.total_bytes);
+		assert!(stats.red_bytes > 0);
+
+		let child_stats = root.first_child().unwrap().memory_stats();
+		assert_eq!(1, child_stats.total_nodes);
+		assert_eq!(3, child_stats.total_tokens);
+		assert_eq!(2, child_stats.unique_tokens);
+	}
+}
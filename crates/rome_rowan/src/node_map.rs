@@ -0,0 +1,307 @@
+//! Side tables to attach data to syntax nodes.
+//!
+//! The collections are keyed by the identity of a node (the address of its green node, its offset, its
+//! position in its parent, and its tree), which is cheap to compute and doesn't require keeping the nodes alive.
+use std::{collections::hash_map::Entry, fmt, iter::FromIterator, marker::PhantomData};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{cursor, Language, SyntaxNode, TextSize};
+
+/// Identity of a node inside of a tree.
+///
+/// Two nodes have the same key if they point to the same green node at the same offset, and are in the
+/// same slot of parents with the same green node. The slot tells apart empty nodes that share their green
+/// node and offset, like the empty directive and statement lists of `function f() {}`.
+///
+/// The key also contains the address of the root of the tree, because the trees of two parses
+/// share their green nodes when they're built with the same `NodeCache`. Keys don't keep the tree
+/// alive, a tree dropped and created again can reuse the address of its root, so keys should only
+/// be compared while their trees are alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SyntaxNodeKey {
+	green: usize,
+	offset: TextSize,
+	parent: usize,
+	slot: usize,
+	root: usize,
+}
+
+impl From<&cursor::SyntaxNode> for SyntaxNodeKey {
+	fn from(node: &cursor::SyntaxNode) -> Self {
+		let (green, offset) = node.key();
+		let parent = node
+			.parent()
+			.map_or(0, |parent| parent.key().0.as_ptr() as usize);
+		SyntaxNodeKey {
+			green: green.as_ptr() as usize,
+			offset,
+			parent,
+			slot: node.index(),
+			root: node.root_key().as_ptr() as usize,
+		}
+	}
+}
+
+/// A map from the nodes of a tree to values of type `V`.
+///
+/// ```
+/// use rome_rowan::*;
+/// use rome_rowan::api::RawLanguage;
+/// let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+///     builder.start_node(SyntaxKind(1));
+///     builder.token(SyntaxKind(2), "a");
+///     builder.finish_node();
+/// });
+/// let child = root.first_child().unwrap();
+///
+/// let mut depths = NodeMap::new();
+/// depths.insert(&root, 0);
+/// depths.insert(&child, 1);
+///
+/// assert_eq!(Some(&1), depths.get(&root.first_child().unwrap()));
+/// ```
+pub struct NodeMap<L: Language, V> {
+	map: FxHashMap<SyntaxNodeKey, V>,
+	_p: PhantomData<L>,
+}
+
+impl<L: Language, V> NodeMap<L, V> {
+	pub fn new() -> Self {
+		NodeMap {
+			map: FxHashMap::default(),
+			_p: PhantomData,
+		}
+	}
+
+	/// Associates `value` with `node`, returns the previous value of the node if any.
+	pub fn insert(&mut self, node: &SyntaxNode<L>, value: V) -> Option<V> {
+		self.map.insert(node.key(), value)
+	}
+
+	pub fn get(&self, node: &SyntaxNode<L>) -> Option<&V> {
+		self.map.get(&node.key())
+	}
+
+	pub fn get_mut(&mut self, node: &SyntaxNode<L>) -> Option<&mut V> {
+		self.map.get_mut(&node.key())
+	}
+
+	/// Returns the value of `node`, inserting the value returned by `f` if the node has none.
+	pub fn get_or_insert_with<F>(&mut self, node: &SyntaxNode<L>, f: F) -> &mut V
+	where
+		F: FnOnce() -> V,
+	{
+		match self.map.entry(node.key()) {
+			Entry::Occupied(entry) => entry.into_mut(),
+			Entry::Vacant(entry) => entry.insert(f()),
+		}
+	}
+
+	pub fn remove(&mut self, node: &SyntaxNode<L>) -> Option<V> {
+		self.map.remove(&node.key())
+	}
+
+	pub fn contains_node(&self, node: &SyntaxNode<L>) -> bool {
+		self.map.contains_key(&node.key())
+	}
+
+	pub fn len(&self) -> usize {
+		self.map.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.map.is_empty()
+	}
+
+	pub fn clear(&mut self) {
+		self.map.clear()
+	}
+
+	pub fn values(&self) -> impl Iterator<Item = &V> {
+		self.map.values()
+	}
+}
+
+impl<L: Language, V> Default for NodeMap<L, V> {
+	fn default() -> Self {
+		NodeMap::new()
+	}
+}
+
+impl<L: Language, V: Clone> Clone for NodeMap<L, V> {
+	fn clone(&self) -> Self {
+		NodeMap {
+			map: self.map.clone(),
+			_p: PhantomData,
+		}
+	}
+}
+
+impl<L: Language, V: fmt::Debug> fmt::Debug for NodeMap<L, V> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map().entries(self.map.iter()).finish()
+	}
+}
+
+/// A set of nodes of a tree.
+///
+/// ```
+/// use rome_rowan::*;
+/// use rome_rowan::api::RawLanguage;
+/// let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+///     builder.start_node(SyntaxKind(1));
+///     builder.token(SyntaxKind(2), "a");
+///     builder.finish_node();
+/// });
+///
+/// let visited: NodeSet<_> = root.descendants().filter(|node| node.kind() == SyntaxKind(1)).collect();
+/// assert!(visited.contains(&root.first_child().unwrap()));
+/// assert!(!visited.contains(&root));
+/// ```
+pub struct NodeSet<L: Language> {
+	set: FxHashSet<SyntaxNodeKey>,
+	_p: PhantomData<L>,
+}
+
+impl<L: Language> NodeSet<L> {
+	pub fn new() -> Self {
+		NodeSet {
+			set: FxHashSet::default(),
+			_p: PhantomData,
+		}
+	}
+
+	/// Adds `node` to the set, returns `false` if the set already contained the node.
+	pub fn insert(&mut self, node: &SyntaxNode<L>) -> bool {
+		self.set.insert(node.key())
+	}
+
+	pub fn contains(&self, node: &SyntaxNode<L>) -> bool {
+		self.set.contains(&node.key())
+	}
+
+	/// Removes `node` from the set, returns `false` if the set didn't contain the node.
+	pub fn remove(&mut self, node: &SyntaxNode<L>) -> bool {
+		self.set.remove(&node.key())
+	}
+
+	pub fn len(&self) -> usize {
+		self.set.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.set.is_empty()
+	}
+
+	pub fn clear(&mut self) {
+		self.set.clear()
+	}
+}
+
+impl<L: Language> Default for NodeSet<L> {
+	fn default() -> Self {
+		NodeSet::new()
+	}
+}
+
+impl<L: Language> Clone for NodeSet<L> {
+	fn clone(&self) -> Self {
+		NodeSet {
+			set: self.set.clone(),
+			_p: PhantomData,
+		}
+	}
+}
+
+impl<L: Language> fmt::Debug for NodeSet<L> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_set().entries(self.set.iter()).finish()
+	}
+}
+
+impl<L: Language> FromIterator<SyntaxNode<L>> for NodeSet<L> {
+	fn from_iter<T: IntoIterator<Item = SyntaxNode<L>>>(iter: T) -> Self {
+		let mut set = NodeSet::new();
+		set.extend(iter);
+		set
+	}
+}
+
+impl<L: Language> Extend<SyntaxNode<L>> for NodeSet<L> {
+	fn extend<T: IntoIterator<Item = SyntaxNode<L>>>(&mut self, iter: T) {
+		self.set.extend(iter.into_iter().map(|node| node.key()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::api::RawLanguage;
+	use crate::{NodeMap, NodeSet, SyntaxKind, TreeBuilder};
+
+	#[test]
+	fn identical_subtrees_have_different_keys() {
+		// Both `(a)` nodes share the same green node but are at different offsets
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			for _ in 0..2 {
+				builder.start_node(SyntaxKind(1));
+				builder.token(SyntaxKind(2), "a");
+				builder.finish_node();
+			}
+		});
+		let first = root.first_child().unwrap();
+		let last = root.last_child().unwrap();
+
+		let mut map = NodeMap::new();
+		map.insert(&first, "first");
+		map.insert(&last, "last");
+
+		assert_eq!(2, map.len());
+		assert_eq!(Some(&"first"), map.get(&first));
+		assert_eq!(Some("last"), map.remove(&last));
+		assert!(!map.contains_node(&last));
+
+		*map.get_or_insert_with(&root, || "root") = "new root";
+		assert_eq!(Some(&"new root"), map.get(&root));
+
+		let mut set = NodeSet::new();
+		assert!(set.insert(&first));
+		assert!(!set.insert(&root.first_child().unwrap()));
+		assert!(!set.contains(&last));
+	}
+
+	#[test]
+	fn empty_siblings_have_different_keys() {
+		// Both empty nodes share the same green node and offset
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			for _ in 0..2 {
+				builder.start_node(SyntaxKind(1));
+				builder.finish_node();
+			}
+		});
+		let first = root.first_child().unwrap();
+		let last = root.last_child().unwrap();
+		assert_ne!(first.key(), last.key());
+
+		let set: NodeSet<_> = root.children().collect();
+		assert_eq!(2, set.len());
+		assert!(set.contains(&first));
+		assert!(set.contains(&last));
+	}
+
+	#[test]
+	fn trees_sharing_green_nodes_have_different_keys() {
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "a");
+			builder.finish_node();
+		});
+		let other = root.clone_subtree();
+		assert_ne!(root.key(), other.key());
+
+		let mut map = NodeMap::new();
+		map.insert(&root.first_child().unwrap(), "root");
+		assert_eq!(None, map.get(&other.first_child().unwrap()));
+		assert_eq!(Some(&"root"), map.get(&root.first_child().unwrap()));
+	}
+}
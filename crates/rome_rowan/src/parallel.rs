@@ -0,0 +1,136 @@
+//! Parallel traversals of a tree, see [SyntaxNode::par_descendants].
+//!
+//! The nodes of a tree aren't `Send` but its green tree is. Each worker creates its own copy of
+//! the node from the green tree and traverses the subtree of one child of the copy.
+
+use rayon::prelude::*;
+
+use crate::{cursor, Language, NodeOrToken, SyntaxElement, SyntaxNode};
+
+impl<L: Language> SyntaxNode<L> {
+	/// Calls `map` on this node and all its descendants and returns the results in preorder,
+	/// the order of [SyntaxNode::descendants].
+	///
+	/// The subtrees of the children of this node are traversed in parallel, which pays off for
+	/// analyses that are independent for each node, like counting nodes or collecting symbols.
+	/// The descendants passed to `map` belong to a copy of this node that keeps the text ranges,
+	/// but their ancestors end at this node and editing them doesn't change this tree.
+	///
+	/// ```
+	/// use rome_rowan::{parse_sexpr, SyntaxKind};
+	///
+	/// let root = parse_sexpr("(0 (1 (2 'a')) (1 (2 'b') (3)))").unwrap();
+	/// let kinds = root.par_descendants(|node| node.kind());
+	///
+	/// assert_eq!(
+	///     root.descendants().map(|node| node.kind()).collect::<Vec<_>>(),
+	///     kinds
+	/// );
+	/// assert_eq!(vec![SyntaxKind(0), SyntaxKind(1), SyntaxKind(1), SyntaxKind(3)], kinds);
+	/// ```
+	pub fn par_descendants<F, R>(&self, map: F) -> Vec<R>
+	where
+		F: Fn(SyntaxNode<L>) -> R + Sync,
+		R: Send,
+	{
+		par_map_children(self, map(self.clone()), |child| match child {
+			NodeOrToken::Node(node) => node.descendants().map(&map).collect(),
+			NodeOrToken::Token(_) => Vec::new(),
+		})
+	}
+
+	/// Calls `map` on this node and all its descendant nodes and tokens and returns the results
+	/// in preorder, the order of [SyntaxNode::descendants_with_tokens]. See [SyntaxNode::par_descendants]
+	/// for how the tree is traversed.
+	///
+	/// ```
+	/// use rome_rowan::parse_sexpr;
+	///
+	/// let root = parse_sexpr("(0 (1 (2 'a')) (2 'b') (1 (2 'c')))").unwrap();
+	/// let tokens = root.par_descendants_with_tokens(|element| match element.as_token() {
+	///     Some(token) => token.text().to_string(),
+	///     None => String::new(),
+	/// });
+	///
+	/// assert_eq!("abc", tokens.concat());
+	/// ```
+	pub fn par_descendants_with_tokens<F, R>(&self, map: F) -> Vec<R>
+	where
+		F: Fn(SyntaxElement<L>) -> R + Sync,
+		R: Send,
+	{
+		par_map_children(self, map(self.clone().into()), |child| match child {
+			NodeOrToken::Node(node) => node.descendants_with_tokens().map(&map).collect(),
+			NodeOrToken::Token(token) => vec![map(token.into())],
+		})
+	}
+}
+
+/// Calls `map_child` for each child of `node` in parallel and returns `first` followed by the
+/// results of all children in the order of the children
+fn par_map_children<L, F, R>(node: &SyntaxNode<L>, first: R, map_child: F) -> Vec<R>
+where
+	L: Language,
+	F: Fn(SyntaxElement<L>) -> Vec<R> + Sync,
+	R: Send,
+{
+	let green = node.green().into_owned();
+	let offset = node.text_range().start();
+
+	let children: Vec<Vec<R>> = (0..green.slots().len() as u32)
+		.into_par_iter()
+		.map(|slot| {
+			let copy =
+				SyntaxNode::<L>::from(cursor::SyntaxNode::new_root_at(green.clone(), offset));
+			match copy.element_in_slot(slot) {
+				Some(child) => map_child(child),
+				None => Vec::new(),
+			}
+		})
+		.collect();
+
+	let mut results = Vec::with_capacity(1 + children.iter().map(Vec::len).sum::<usize>());
+	results.push(first);
+	results.extend(children.into_iter().flatten());
+	results
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{parse_sexpr, SyntaxKind};
+
+	#[test]
+	fn matches_the_sequential_traversal() {
+		let root = parse_sexpr(
+			"(0
+				(1 (2 'let') (3 (2 ' ' 'a' ' ')))
+				_
+				(1 (2 '') (4 (5 (2 '' '=' ' ')) (2 '1')) (2 ';'))
+				(2 '\n' 'b' '')
+			)",
+		)
+		.unwrap();
+		let nested = root.last_child().unwrap();
+
+		for node in [root, nested] {
+			assert_eq!(
+				node.descendants()
+					.map(|node| (node.kind(), node.text_range()))
+					.collect::<Vec<_>>(),
+				node.par_descendants(|node| (node.kind(), node.text_range()))
+			);
+			assert_eq!(
+				node.descendants_with_tokens()
+					.map(|element| (element.kind(), element.text_range()))
+					.collect::<Vec<_>>(),
+				node.par_descendants_with_tokens(|element| (element.kind(), element.text_range()))
+			);
+		}
+
+		let leaf = parse_sexpr("(0)").unwrap();
+		assert_eq!(
+			vec![SyntaxKind(0)],
+			leaf.par_descendants(|node| node.kind())
+		);
+	}
+}
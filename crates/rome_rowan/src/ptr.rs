@@ -0,0 +1,124 @@
+//! Tree independent references to nodes, see [SyntaxNodePtr].
+
+use std::{fmt, marker::PhantomData};
+
+use crate::{Language, SyntaxKind, SyntaxNode, TextRange};
+
+/// A pointer to a node that doesn't keep the tree alive and that can be resolved against
+/// any tree with the same text, for example a tree that has been re-parsed from the same source.
+///
+/// The node is identified by the slot indices of the path from the root to the node. The kind and
+/// range alone aren't unique, e.g. two empty lists of the same kind at the same offset. Resolving the
+/// pointer follows the path from the root and verifies that the node found has the kind and range of the pointer.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SyntaxNodePtr<L: Language> {
+	kind: SyntaxKind,
+	range: TextRange,
+	/// The slot indices from the root down to the node
+	path: Vec<u32>,
+	_p: PhantomData<L>,
+}
+
+impl<L: Language> SyntaxNodePtr<L> {
+	pub fn new(node: &SyntaxNode<L>) -> Self {
+		let mut path: Vec<_> = node
+			.ancestors()
+			.filter(|ancestor| ancestor.parent().is_some())
+			.map(|ancestor| ancestor.index() as u32)
+			.collect();
+		path.reverse();
+
+		SyntaxNodePtr {
+			kind: L::kind_to_raw(node.kind()),
+			range: node.text_range(),
+			path,
+			_p: PhantomData,
+		}
+	}
+
+	pub fn kind(&self) -> L::Kind {
+		L::kind_from_raw(self.kind)
+	}
+
+	pub fn text_range(&self) -> TextRange {
+		self.range
+	}
+
+	/// Returns the node in the tree of `root` this pointer points to or [None] if the tree
+	/// has no node at the path of the pointer or if that node has a different kind or range.
+	pub fn to_node(&self, root: &SyntaxNode<L>) -> Option<SyntaxNode<L>> {
+		let mut node = root.clone();
+		for &slot in &self.path {
+			if slot as usize >= node.slots().len() {
+				return None;
+			}
+			node = node.element_in_slot(slot)?.into_node()?;
+		}
+
+		if node.text_range() == self.range && L::kind_to_raw(node.kind()) == self.kind {
+			Some(node)
+		} else {
+			None
+		}
+	}
+}
+
+impl<L: Language> fmt::Debug for SyntaxNodePtr<L> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:?}@{:?}", self.kind(), self.range)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SyntaxNodePtr;
+	use crate::{api::RawLanguage, SyntaxKind, TreeBuilder};
+
+	#[test]
+	fn resolves_against_an_equal_tree() {
+		let build = || {
+			TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+				builder.start_node(SyntaxKind(1));
+				builder.start_node(SyntaxKind(2));
+				builder.token(SyntaxKind(3), "a");
+				builder.finish_node();
+				builder.finish_node();
+				builder.start_node(SyntaxKind(1));
+				builder.token(SyntaxKind(3), "b");
+				builder.finish_node();
+			})
+		};
+
+		let first = build();
+		let inner = first.first_child().unwrap().first_child().unwrap();
+		let ptr = SyntaxNodePtr::new(&inner);
+
+		let second = build();
+		let resolved = ptr.to_node(&second).unwrap();
+		assert_eq!(SyntaxKind(2), resolved.kind());
+		assert_eq!(inner.text_range(), resolved.text_range());
+
+		let last = SyntaxNodePtr::new(&second.last_child().unwrap());
+		assert_eq!("b", last.to_node(&first).unwrap().text());
+	}
+
+	#[test]
+	fn distinguishes_empty_siblings_of_the_same_kind() {
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.start_node(SyntaxKind(1));
+			builder.finish_node();
+			builder.start_node(SyntaxKind(1));
+			builder.finish_node();
+			builder.token(SyntaxKind(2), "a");
+		});
+
+		let second = root.children().nth(1).unwrap();
+		let resolved = SyntaxNodePtr::new(&second).to_node(&root).unwrap();
+		assert_eq!(1, resolved.index());
+
+		let other = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token(SyntaxKind(2), "a");
+		});
+		assert_eq!(None, SyntaxNodePtr::new(&second).to_node(&other));
+	}
+}
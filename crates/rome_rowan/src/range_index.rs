@@ -0,0 +1,183 @@
+//! An index for repeated range queries on the same tree, see [RangeIndex].
+
+use crate::{Language, NodeOrToken, SyntaxElement, SyntaxNode, TextRange, WalkEvent};
+
+/// Answers [SyntaxNode::covering_element] queries in `O(log(N))`, where `N` is the number of
+/// nodes and tokens of the tree, instead of descending from the root for every query.
+///
+/// The index stores all elements of the tree in preorder, together with the index of the
+/// ancestors at a distance of 1, 2, 4, ... of every element. A query first searches the last
+/// element that starts before the range and then jumps up to the deepest of its ancestors that
+/// contains the range. Building the index takes `O(N log(D))` time and memory, where `D` is the
+/// depth of the tree, which only pays off for tools that run many queries on the same tree, like
+/// semantic highlighting.
+///
+/// The index doesn't observe changes of the tree and must be rebuilt after each mutation.
+///
+/// ```
+/// use rome_rowan::{parse_sexpr, RangeIndex, TextRange};
+///
+/// let root = parse_sexpr("(0 (1 (2 'let') (2 ' ' 'a' '')) (1 (2 '=')))").unwrap();
+/// let index = RangeIndex::new(&root);
+///
+/// let range = TextRange::new(4.into(), 5.into());
+/// assert_eq!(root.covering_element(range), index.covering_element(range));
+/// assert_eq!("let a", index.covering_node(TextRange::new(2.into(), 5.into())).text());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RangeIndex<L: Language> {
+	/// All elements of the tree in preorder
+	elements: Vec<SyntaxElement<L>>,
+	ranges: Vec<TextRange>,
+	/// `ancestors[k][i]` is the index of the ancestor of the element `i` at a distance of `2^k`,
+	/// or the index of the root if the element has no such ancestor
+	ancestors: Vec<Vec<u32>>,
+}
+
+impl<L: Language> RangeIndex<L> {
+	pub fn new(root: &SyntaxNode<L>) -> Self {
+		let mut elements = Vec::new();
+		let mut ranges = Vec::new();
+		let mut parents = Vec::new();
+		let mut depth = 0;
+		let mut max_depth = 0;
+		let mut open: Vec<u32> = Vec::new();
+
+		for event in root.preorder_with_tokens() {
+			match event {
+				WalkEvent::Enter(element) => {
+					let index = elements.len() as u32;
+					parents.push(open.last().copied().unwrap_or(0));
+					ranges.push(element.text_range());
+					if element.as_node().is_some() {
+						open.push(index);
+						depth += 1;
+						max_depth = max_depth.max(depth);
+					}
+					elements.push(element);
+				}
+				WalkEvent::Leave(NodeOrToken::Node(_)) => {
+					open.pop();
+					depth -= 1;
+				}
+				WalkEvent::Leave(NodeOrToken::Token(_)) => {}
+			}
+		}
+
+		let mut ancestors = vec![parents];
+		let mut distance = 1;
+		while distance < max_depth {
+			let previous = ancestors.last().unwrap();
+			let next = previous
+				.iter()
+				.map(|ancestor| previous[*ancestor as usize])
+				.collect();
+			ancestors.push(next);
+			distance *= 2;
+		}
+
+		RangeIndex {
+			elements,
+			ranges,
+			ancestors,
+		}
+	}
+
+	/// Returns the deepest node or token of the tree that contains `range`, the same element as
+	/// [SyntaxNode::covering_element] on the root. If the range is empty and is contained in two
+	/// leaf elements, either one can be returned.
+	///
+	/// ## Panics
+	/// If `range` isn't contained in the range of the root
+	pub fn covering_element(&self, range: TextRange) -> SyntaxElement<L> {
+		assert!(
+			self.ranges[0].contains_range(range),
+			"Bad range: root range {:?}, range {:?}",
+			self.ranges[0],
+			range
+		);
+
+		// The covering element is an ancestor of the last element that starts before the range,
+		// or the element itself
+		let last_before = self
+			.ranges
+			.partition_point(|element_range| element_range.start() <= range.start())
+			- 1;
+
+		let mut index = last_before;
+		if !self.ranges[index].contains_range(range) {
+			// Jump to the highest ancestor that doesn't contain the range, its parent is the covering element
+			for level in self.ancestors.iter().rev() {
+				let ancestor = level[index] as usize;
+				if !self.ranges[ancestor].contains_range(range) {
+					index = ancestor;
+				}
+			}
+			index = self.ancestors[0][index] as usize;
+		}
+
+		self.elements[index].clone()
+	}
+
+	/// Returns the deepest node of the tree that contains `range`, which is the parent of the
+	/// covering element if the covering element is a token.
+	///
+	/// ## Panics
+	/// If `range` isn't contained in the range of the root
+	pub fn covering_node(&self, range: TextRange) -> SyntaxNode<L> {
+		match self.covering_element(range) {
+			NodeOrToken::Node(node) => node,
+			NodeOrToken::Token(token) => token.parent().expect("tokens always have a parent"),
+		}
+	}
+
+	/// Returns the number of nodes and tokens in the index
+	pub fn len(&self) -> usize {
+		self.elements.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.elements.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::RangeIndex;
+	use crate::{parse_sexpr, TextRange, TextSize};
+
+	#[test]
+	fn matches_covering_element_of_the_tree() {
+		let root = parse_sexpr(
+			"(0
+				(1 (2 'let') (3 (2 ' ' 'a' ' ')))
+				_
+				(1 (2 '') (4 (5 (2 '' '=' ' ')) (2 '1')) (2 ';'))
+				(2 '')
+			)",
+		)
+		.unwrap();
+		let index = RangeIndex::new(&root);
+		assert_eq!(root.descendants_with_tokens().count(), index.len());
+
+		let len = u32::from(root.text_range().len());
+		for start in 0..=len {
+			for end in start..=len {
+				let range = TextRange::new(TextSize::from(start), TextSize::from(end));
+				let element = index.covering_element(range);
+				assert!(element.text_range().contains_range(range));
+
+				if !range.is_empty() {
+					assert_eq!(root.covering_element(range), element, "{:?}", range);
+				}
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Bad range")]
+	fn rejects_ranges_outside_of_the_root() {
+		let root = parse_sexpr("(0 (1 'a'))").unwrap();
+		RangeIndex::new(&root).covering_element(TextRange::new(0.into(), 2.into()));
+	}
+}
@@ -0,0 +1,319 @@
+//! A compact notation for [RawLanguage] trees, see [parse_sexpr] and [to_sexpr].
+
+use std::{error::Error, fmt, iter::Peekable, str::CharIndices};
+
+use crate::{api::RawLanguage, NodeOrToken, SyntaxKind, SyntaxNode, TreeBuilder, TriviaPiece};
+
+/// Builds a [RawLanguage] tree from an s-expression, which is shorter and easier to read in tests
+/// than the equivalent [TreeBuilder] calls.
+///
+/// * `(kind child...)` is a node, e.g. `(0 (1) (2))`
+/// * `(kind 'text')` is a token
+/// * `(kind 'leading' 'text' 'trailing')` is a token with whitespace trivia
+/// * `_` is an empty slot
+///
+/// Kinds are the numbers of the [SyntaxKind]s. Texts are in single quotes, `\'` and `\\` escape
+/// a quote and a backslash.
+///
+/// ```
+/// use rome_rowan::{parse_sexpr, SyntaxKind};
+///
+/// let root = parse_sexpr("(0 (1 (2 'let') (3 ' ' 'a' '')) _)").unwrap();
+///
+/// assert_eq!("let a", root.text());
+/// assert_eq!(SyntaxKind(0), root.kind());
+/// assert!(root.element_in_slot(1).is_none());
+///
+/// let a = root.last_token().unwrap();
+/// assert_eq!(SyntaxKind(3), a.kind());
+/// assert_eq!(" ", a.leading_trivia().text());
+/// ```
+pub fn parse_sexpr(text: &str) -> Result<SyntaxNode<RawLanguage>, SexprError> {
+	let mut parser = SexprParser {
+		chars: text.char_indices().peekable(),
+		builder: TreeBuilder::new(),
+		len: text.len(),
+		depth: 0,
+	};
+
+	parser.skip_whitespace();
+	if parser.peek() != Some('(') {
+		return Err(parser.error("expected the root node"));
+	}
+	parser.element()?;
+	parser.skip_whitespace();
+	if parser.peek().is_some() {
+		return Err(parser.error("expected the end of the input after the root node"));
+	}
+
+	Ok(parser.builder.finish())
+}
+
+/// Writes `node` in the notation of [parse_sexpr]. The trivia of a token is written as a single text, parsing
+/// the notation again turns it into a single whitespace trivia piece.
+///
+/// ```
+/// use rome_rowan::{parse_sexpr, to_sexpr};
+///
+/// let notation = "(0 (1 'it\\'s') _ (2 ' ' 'a' ''))";
+/// assert_eq!(notation, to_sexpr(&parse_sexpr(notation).unwrap()));
+/// ```
+pub fn to_sexpr(node: &SyntaxNode<RawLanguage>) -> String {
+	let mut notation = String::new();
+	write_node(node, &mut notation);
+	notation
+}
+
+fn write_node(node: &SyntaxNode<RawLanguage>, notation: &mut String) {
+	notation.push_str(&format!("({}", node.kind().0));
+	for slot in 0..node.green().slots().len() {
+		notation.push(' ');
+		match node.element_in_slot(slot as u32) {
+			None => notation.push('_'),
+			Some(NodeOrToken::Node(child)) => write_node(&child, notation),
+			Some(NodeOrToken::Token(token)) => {
+				notation.push_str(&format!("({} ", token.kind().0));
+				let trimmed = token.text_trimmed();
+				if trimmed.len() == token.text().len() {
+					write_text(trimmed, notation);
+				} else {
+					write_text(token.leading_trivia().text(), notation);
+					notation.push(' ');
+					write_text(trimmed, notation);
+					notation.push(' ');
+					write_text(token.trailing_trivia().text(), notation);
+				}
+				notation.push(')');
+			}
+		}
+	}
+	notation.push(')');
+}
+
+fn write_text(text: &str, notation: &mut String) {
+	notation.push('\'');
+	for c in text.chars() {
+		if matches!(c, '\'' | '\\') {
+			notation.push('\\');
+		}
+		notation.push(c);
+	}
+	notation.push('\'');
+}
+
+/// Error returned by [parse_sexpr] for an invalid notation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SexprError {
+	/// The byte offset of the error in the notation
+	pub offset: usize,
+	pub message: &'static str,
+}
+
+impl fmt::Display for SexprError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} at offset {}", self.message, self.offset)
+	}
+}
+
+impl Error for SexprError {}
+
+struct SexprParser<'a> {
+	chars: Peekable<CharIndices<'a>>,
+	builder: TreeBuilder<'static, RawLanguage>,
+	len: usize,
+	/// The number of open nodes
+	depth: usize,
+}
+
+impl SexprParser<'_> {
+	fn peek(&mut self) -> Option<char> {
+		self.chars.peek().map(|(_, c)| *c)
+	}
+
+	fn offset(&mut self) -> usize {
+		match self.chars.peek() {
+			Some((offset, _)) => *offset,
+			None => self.len,
+		}
+	}
+
+	fn error(&mut self, message: &'static str) -> SexprError {
+		SexprError {
+			offset: self.offset(),
+			message,
+		}
+	}
+
+	fn skip_whitespace(&mut self) {
+		while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+			self.chars.next();
+		}
+	}
+
+	/// Parses a node, a token or an empty slot
+	fn element(&mut self) -> Result<(), SexprError> {
+		match self.peek() {
+			Some('_') => {
+				self.chars.next();
+				self.builder.missing();
+				Ok(())
+			}
+			Some('(') => {
+				self.chars.next();
+				self.skip_whitespace();
+				let kind = self.kind()?;
+				self.skip_whitespace();
+
+				if self.peek() == Some('\'') {
+					self.token(kind)?;
+				} else {
+					self.builder.start_node(kind);
+					self.depth += 1;
+					while !matches!(self.peek(), Some(')') | None) {
+						self.element()?;
+						self.skip_whitespace();
+					}
+					self.depth -= 1;
+					self.builder.finish_node();
+				}
+
+				match self.chars.next() {
+					Some((_, ')')) => Ok(()),
+					_ => Err(self.error("expected `)`")),
+				}
+			}
+			_ => Err(self.error("expected `(` or `_`")),
+		}
+	}
+
+	fn kind(&mut self) -> Result<SyntaxKind, SexprError> {
+		let mut kind: Option<u16> = None;
+		while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+			kind = kind
+				.unwrap_or(0)
+				.checked_mul(10)
+				.and_then(|kind| kind.checked_add(digit as u16));
+			if kind.is_none() {
+				return Err(self.error("the kind doesn't fit into a u16"));
+			}
+			self.chars.next();
+		}
+
+		kind.map(SyntaxKind)
+			.ok_or_else(|| self.error("expected the number of a kind"))
+	}
+
+	/// Parses the texts of a token and adds the token
+	fn token(&mut self, kind: SyntaxKind) -> Result<(), SexprError> {
+		if self.depth == 0 {
+			return Err(self.error("expected a node as root"));
+		}
+
+		let mut texts = Vec::new();
+		while self.peek() == Some('\'') {
+			texts.push(self.string()?);
+			self.skip_whitespace();
+		}
+
+		match texts.as_slice() {
+			[text] => self.builder.token(kind, text),
+			[leading, text, trailing] => self.builder.token_with_trivia(
+				kind,
+				&[leading.as_str(), text.as_str(), trailing.as_str()].concat(),
+				whitespace(leading),
+				whitespace(trailing),
+			),
+			_ => return Err(self.error("expected one text or three texts for a token")),
+		}
+		Ok(())
+	}
+
+	fn string(&mut self) -> Result<String, SexprError> {
+		self.chars.next();
+		let mut text = String::new();
+		loop {
+			match self.chars.next() {
+				Some((_, '\'')) => return Ok(text),
+				Some((_, '\\')) => match self.chars.next() {
+					Some((_, c @ ('\'' | '\\'))) => text.push(c),
+					_ => return Err(self.error("expected `'` or `\\` after `\\`")),
+				},
+				Some((_, c)) => text.push(c),
+				None => return Err(self.error("unterminated text")),
+			}
+		}
+	}
+}
+
+fn whitespace(text: &str) -> Vec<TriviaPiece> {
+	if text.is_empty() {
+		Vec::new()
+	} else {
+		vec![TriviaPiece::Whitespace(text.len())]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{parse_sexpr, SexprError};
+	use crate::{api::RawLanguage, SyntaxKind, TreeBuilder, TriviaPiece};
+
+	#[test]
+	fn builds_the_same_tree_as_the_builder() {
+		let expected = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "it's");
+			builder.missing();
+			builder.finish_node();
+			builder.token_with_trivia(
+				SyntaxKind(3),
+				" \\ ",
+				vec![TriviaPiece::Whitespace(1)],
+				vec![TriviaPiece::Whitespace(1)],
+			);
+			builder.start_node(SyntaxKind(4));
+			builder.finish_node();
+		});
+
+		let actual = parse_sexpr(
+			r"
+			(0
+				(1 (2 'it\'s') _)
+				(3 ' ' '\\' ' ')
+				(4)
+			)",
+		)
+		.unwrap();
+
+		assert_eq!(format!("{:#?}", expected), format!("{:#?}", actual));
+	}
+
+	#[test]
+	fn reports_errors() {
+		assert_eq!(
+			Err(SexprError {
+				offset: 10,
+				message: "expected `)`"
+			}),
+			parse_sexpr("(0 (1 'a')").map(|_| ())
+		);
+		assert_eq!(
+			Err(SexprError {
+				offset: 1,
+				message: "expected the number of a kind"
+			}),
+			parse_sexpr("(a)").map(|_| ())
+		);
+		assert_eq!(
+			Err(SexprError {
+				offset: 13,
+				message: "expected one text or three texts for a token"
+			}),
+			parse_sexpr("(0 (1 'a' 'b'))").map(|_| ())
+		);
+		assert!(parse_sexpr("_").is_err());
+		assert!(parse_sexpr("(0 'a')").is_err());
+		assert!(parse_sexpr("(0) (1)").is_err());
+		assert!(parse_sexpr("(70000)").is_err());
+	}
+}
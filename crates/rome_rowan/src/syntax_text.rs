@@ -1,8 +1,8 @@
-use std::fmt;
+use std::{borrow::Cow, fmt};
 
 use crate::{
 	cursor::{SyntaxNode, SyntaxToken},
-	TextRange, TextSize,
+	GreenNodeData, NodeOrToken, TextRange, TextSize,
 };
 
 #[derive(Clone)]
@@ -61,6 +61,123 @@ impl SyntaxText {
 		found(res)
 	}
 
+	/// Returns the offset of the first occurrence of `pattern`, matches may span several tokens.
+	pub fn find(&self, pattern: &str) -> Option<TextSize> {
+		if pattern.is_empty() {
+			return Some(0.into());
+		}
+
+		// The end of the previous chunks, which is too short to contain `pattern` but may start a match
+		let mut carry = String::new();
+		let mut chunk_start: TextSize = 0.into();
+		let res = self.try_for_each_chunk(|chunk| {
+			if !carry.is_empty() {
+				let prefix_len = (pattern.len() - 1).min(chunk.len());
+				let prefix = &chunk[..ceil_char_boundary(chunk, prefix_len)];
+				let mut boundary = String::with_capacity(carry.len() + prefix.len());
+				boundary.push_str(&carry);
+				boundary.push_str(prefix);
+				if let Some(pos) = boundary.find(pattern).filter(|pos| *pos < carry.len()) {
+					return Err(
+						chunk_start - TextSize::of(carry.as_str()) + TextSize::from(pos as u32)
+					);
+				}
+			}
+
+			if let Some(pos) = chunk.find(pattern) {
+				return Err(chunk_start + TextSize::from(pos as u32));
+			}
+
+			carry.push_str(
+				&chunk[ceil_char_boundary(chunk, chunk.len().saturating_sub(pattern.len()))..],
+			);
+			let excess = ceil_char_boundary(&carry, carry.len().saturating_sub(pattern.len() - 1));
+			carry.drain(..excess);
+			chunk_start += TextSize::of(chunk);
+			Ok(())
+		});
+		found(res)
+	}
+
+	pub fn contains(&self, pattern: &str) -> bool {
+		self.find(pattern).is_some()
+	}
+
+	pub fn starts_with(&self, prefix: &str) -> bool {
+		self.matches_at(0.into(), prefix)
+	}
+
+	pub fn ends_with(&self, suffix: &str) -> bool {
+		let suffix_len = TextSize::of(suffix);
+		suffix_len <= self.len() && self.matches_at(self.len() - suffix_len, suffix)
+	}
+
+	/// Returns an iterator over the characters of the text.
+	pub fn chars(&self) -> impl Iterator<Item = char> {
+		SyntaxTextChars {
+			tokens: self.tokens_with_ranges(),
+			current: None,
+		}
+	}
+
+	/// Returns `true` if the text at `offset` starts with `pattern`, comparing bytes so that
+	/// `offset` doesn't need to be at a char boundary.
+	fn matches_at(&self, offset: TextSize, mut pattern: &str) -> bool {
+		let mut chunk_start: TextSize = 0.into();
+		let res = self.try_for_each_chunk(|chunk| {
+			let chunk_end = chunk_start + TextSize::of(chunk);
+			if pattern.is_empty() {
+				return Err(true);
+			}
+
+			if offset < chunk_end {
+				let skip = u32::from(offset.max(chunk_start) - chunk_start) as usize;
+				let bytes = &chunk.as_bytes()[skip..];
+				let len = bytes.len().min(pattern.len());
+				if bytes[..len] != pattern.as_bytes()[..len] {
+					return Err(false);
+				}
+				pattern = &pattern[len..];
+			}
+
+			chunk_start = chunk_end;
+			Ok(())
+		});
+		found(res).unwrap_or_else(|| pattern.is_empty())
+	}
+
+	/// Returns the text as a `&str` if it is contained in a single token of an immutable tree
+	/// and only allocates a `String` if it spans multiple tokens.
+	///
+	/// ```
+	/// use rome_rowan::*;
+	/// use rome_rowan::api::RawLanguage;
+	/// use std::borrow::Cow;
+	/// let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+	///     builder.token(SyntaxKind(1), "let");
+	///     builder.token(SyntaxKind(2), " a");
+	/// });
+	///
+	/// assert!(matches!(node.text().to_cow(), Cow::Owned(text) if text == "let a"));
+	/// let keyword = node.text().slice(TextSize::from(0)..TextSize::from(3));
+	/// assert!(matches!(keyword.to_cow(), Cow::Borrowed("let")));
+	/// ```
+	pub fn to_cow(&self) -> Cow<'_, str> {
+		if self.is_empty() {
+			return Cow::Borrowed("");
+		}
+
+		// The green tree of a mutable tree can be replaced at any time, it can't be borrowed
+		if let Cow::Borrowed(green) = self.node.green() {
+			let range = self.range - self.node.text_range().start();
+			if let Some(text) = single_token_text(green, range) {
+				return Cow::Borrowed(text);
+			}
+		}
+
+		Cow::Owned(self.to_string())
+	}
+
 	pub fn slice<R: private::SyntaxTextRange>(&self, range: R) -> SyntaxText {
 		let start = range.start().unwrap_or_default();
 		let end = range.end().unwrap_or_else(|| self.len());
@@ -93,7 +210,7 @@ impl SyntaxText {
 	{
 		self.tokens_with_ranges()
 			.try_fold(init, move |acc, (token, range)| {
-				f(acc, &token.text()[range])
+				token.green().text_chunks(range).try_fold(acc, &mut f)
 			})
 	}
 
@@ -128,6 +245,48 @@ impl SyntaxText {
 	}
 }
 
+struct SyntaxTextChars<I> {
+	tokens: I,
+	current: Option<(SyntaxToken, TextRange)>,
+}
+
+impl<I: Iterator<Item = (SyntaxToken, TextRange)>> Iterator for SyntaxTextChars<I> {
+	type Item = char;
+
+	fn next(&mut self) -> Option<char> {
+		loop {
+			match &mut self.current {
+				Some((token, range)) if !range.is_empty() => {
+					let c = token.green().text_chunks(*range).next()?.chars().next()?;
+					*range = TextRange::new(range.start() + TextSize::of(c), range.end());
+					return Some(c);
+				}
+				_ => self.current = Some(self.tokens.next()?),
+			}
+		}
+	}
+}
+
+/// Returns the text of the non-empty `range` relative to `node` if it is inside of a single token
+fn single_token_text(mut node: &GreenNodeData, mut range: TextRange) -> Option<&str> {
+	loop {
+		let (_, rel_offset, slot) = node.slot_at_range(range)?;
+		range -= rel_offset;
+		match slot.as_ref()? {
+			NodeOrToken::Node(child) => node = child,
+			NodeOrToken::Token(token) => return token.text_in(range),
+		}
+	}
+}
+
+/// Returns the smallest char boundary of `text` at or after `index`
+fn ceil_char_boundary(text: &str, mut index: usize) -> usize {
+	while !text.is_char_boundary(index) {
+		index += 1;
+	}
+	index
+}
+
 fn found<T>(res: Result<(), T>) -> Option<T> {
 	match res {
 		Ok(()) => None,
@@ -207,12 +366,12 @@ fn zip_texts<I: Iterator<Item = (SyntaxToken, TextRange)>>(xs: &mut I, ys: &mut
 		while y.1.is_empty() {
 			y = ys.next()?;
 		}
-		let x_text = &x.0.text()[x.1];
-		let y_text = &y.0.text()[y.1];
+		let x_text = x.0.green().text_chunks(x.1).next()?;
+		let y_text = y.0.green().text_chunks(y.1).next()?;
 		if !(x_text.starts_with(y_text) || y_text.starts_with(x_text)) {
 			return Some(());
 		}
-		let advance = std::cmp::min(x.1.len(), y.1.len());
+		let advance = TextSize::of(x_text).min(TextSize::of(y_text));
 		x.1 = TextRange::new(x.1.start() + advance, x.1.end());
 		y.1 = TextRange::new(y.1.start() + advance, y.1.end());
 	}
@@ -279,7 +438,8 @@ mod private {
 #[cfg(test)]
 mod tests {
 	use crate::api::RawLanguage;
-	use crate::{SyntaxKind, SyntaxNode, TreeBuilder};
+	use crate::{SyntaxKind, SyntaxNode, SyntaxText, TextSize, TreeBuilder};
+	use std::borrow::Cow;
 
 	fn build_tree(chunks: &[&str]) -> SyntaxNode<RawLanguage> {
 		let mut builder = TreeBuilder::<'_, RawLanguage>::new();
@@ -323,4 +483,88 @@ mod tests {
 		check(&["{", "abc", "}"], &["{", "123", "}", "{"]);
 		check(&["{", "abc", "}ab"], &["{", "abc", "}", "ab"]);
 	}
+
+	#[test]
+	fn test_text_search() {
+		fn check(chunks: &[&str], pattern: &str) {
+			let text = build_tree(chunks).text();
+			let string = text.to_string();
+			let expected = string.find(pattern).map(|pos| TextSize::from(pos as u32));
+			assert_eq!(
+				expected,
+				text.find(pattern),
+				"find `{}` in {:?}",
+				pattern,
+				chunks
+			);
+			assert_eq!(string.contains(pattern), text.contains(pattern));
+			assert_eq!(string.starts_with(pattern), text.starts_with(pattern));
+			assert_eq!(string.ends_with(pattern), text.ends_with(pattern));
+		}
+
+		check(&[""], "");
+		check(&["abc"], "");
+		check(&["abc"], "b");
+		check(&["abc"], "abcd");
+		check(&["ab", "c"], "bc");
+		check(&["a", "b", "c", "d"], "bcd");
+		check(&["a", "b", "c", "d"], "abcd");
+		check(&["aab", "aab"], "baa");
+		check(&["ab", "ab", "abc"], "abc");
+		check(&["ab", "ab", "abc"], "bab");
+		check(&["hello", " ", "world"], "o w");
+		check(&["hello", " ", "world"], "world!");
+		check(&["h\u{e9}l", "l\u{f6}"], "\u{e9}ll\u{f6}");
+		check(&["h\u{e9}l", "l\u{f6}"], "\u{f6}");
+	}
+
+	#[test]
+	fn test_to_cow() {
+		fn is_borrowed(text: &SyntaxText) -> bool {
+			matches!(text.to_cow(), Cow::Borrowed(_))
+		}
+
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token(SyntaxKind(1), "a");
+			builder.start_node(SyntaxKind(2));
+			builder.start_node(SyntaxKind(3));
+			builder.token(SyntaxKind(1), "bcd");
+			builder.finish_node();
+			builder.token(SyntaxKind(1), "ef");
+			builder.finish_node();
+		});
+		let text = root.text();
+
+		assert_eq!("abcdef", text.to_cow());
+		assert!(!is_borrowed(&text));
+
+		let inner = text.slice(TextSize::from(2)..TextSize::from(4));
+		assert_eq!("cd", inner.to_cow());
+		assert!(is_borrowed(&inner));
+
+		let spanning = text.slice(TextSize::from(3)..TextSize::from(5));
+		assert_eq!("de", spanning.to_cow());
+		assert!(!is_borrowed(&spanning));
+
+		let nested = root.last_child().unwrap().first_child().unwrap().text();
+		assert_eq!("bcd", nested.to_cow());
+		assert!(is_borrowed(&nested));
+
+		let mutable = root.clone_for_update();
+		let mutable = mutable.first_child().unwrap().first_child().unwrap().text();
+		assert_eq!("bcd", mutable.to_cow());
+		assert!(!is_borrowed(&mutable));
+	}
+
+	#[test]
+	fn test_text_chars() {
+		let text = build_tree(&["h\u{e9}", "", "llo", " w\u{f6}rld"]).text();
+		assert_eq!("h\u{e9}llo w\u{f6}rld", text.chars().collect::<String>());
+		assert_eq!(
+			"llo w",
+			text.slice(TextSize::from(3)..TextSize::from(8))
+				.chars()
+				.collect::<String>()
+		);
+	}
 }
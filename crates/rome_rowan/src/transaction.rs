@@ -0,0 +1,194 @@
+//! Undoable edits of mutable trees, see [Transaction].
+
+use std::ops::Range;
+
+use crate::{Language, SyntaxElement, SyntaxNode};
+
+/// A single step that reverts an edit
+#[derive(Debug)]
+enum Undo<L: Language> {
+	/// Insert the detached `element` back into the slot `index` of `parent`
+	Attach {
+		parent: SyntaxNode<L>,
+		index: usize,
+		element: SyntaxElement<L>,
+	},
+	/// Detach the inserted `element`
+	Detach(SyntaxElement<L>),
+}
+
+/// Edits a mutable tree, see [SyntaxNode::clone_for_update], and records how to revert the edits.
+///
+/// This allows to attempt a refactoring on a tree and to abandon it with [Transaction::rollback]
+/// without cloning the whole tree up front. Dropping the transaction keeps the edits.
+///
+/// ```
+/// use rome_rowan::{api::RawLanguage, SyntaxKind, Transaction, TreeBuilder};
+///
+/// let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+///     builder.token(SyntaxKind(1), "a");
+///     builder.token(SyntaxKind(1), "b");
+///     builder.token(SyntaxKind(1), "c");
+/// })
+/// .clone_for_update();
+///
+/// let mut transaction = Transaction::new();
+/// let c = root.last_token().unwrap();
+/// transaction.splice_children(&root, 0..1, vec![c.into()]);
+/// assert_eq!("cb", root.text());
+///
+/// transaction.rollback();
+/// assert_eq!("abc", root.text());
+/// ```
+#[derive(Debug)]
+pub struct Transaction<L: Language> {
+	undo: Vec<Undo<L>>,
+}
+
+impl<L: Language> Default for Transaction<L> {
+	fn default() -> Self {
+		Transaction { undo: Vec::new() }
+	}
+}
+
+impl<L: Language> Transaction<L> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns `true` if the transaction hasn't recorded any edit
+	pub fn is_empty(&self) -> bool {
+		self.undo.is_empty()
+	}
+
+	/// Detaches `element` from its parent, see [SyntaxNode::detach]
+	pub fn detach(&mut self, element: impl Into<SyntaxElement<L>>) {
+		let element = element.into();
+		if let Some(parent) = element.parent() {
+			let index = element.index();
+			element.detach();
+			self.undo.push(Undo::Attach {
+				parent,
+				index,
+				element,
+			});
+		}
+	}
+
+	/// Replaces the children of `parent` in `to_delete` with `to_insert`, see [SyntaxNode::splice_children].
+	/// Elements of `to_insert` that are attached to a tree are moved.
+	pub fn splice_children(
+		&mut self,
+		parent: &SyntaxNode<L>,
+		to_delete: Range<usize>,
+		to_insert: Vec<SyntaxElement<L>>,
+	) {
+		let deleted: Vec<_> = parent
+			.children_with_tokens()
+			.enumerate()
+			.filter(|(index, _)| to_delete.contains(index))
+			.map(|(_, child)| child)
+			.collect();
+		for child in deleted {
+			self.detach(child);
+		}
+
+		// Moving a child of `parent` that is before the deleted range shifts the range to the left
+		let moved_before = to_insert
+			.iter()
+			.filter(|child| child.parent().as_ref() == Some(parent))
+			.filter(|child| child.index() < to_delete.start)
+			.count();
+
+		for (index, child) in (to_delete.start - moved_before..).zip(to_insert) {
+			self.detach(child.clone());
+			parent.splice_children(index..index, vec![child.clone()]);
+			self.undo.push(Undo::Detach(child));
+		}
+	}
+
+	/// Reverts all the edits of the transaction, in reverse order
+	pub fn rollback(mut self) {
+		while let Some(undo) = self.undo.pop() {
+			match undo {
+				Undo::Attach {
+					parent,
+					index,
+					element,
+				} => parent.splice_children(index..index, vec![element]),
+				Undo::Detach(element) => element.detach(),
+			}
+		}
+	}
+
+	/// Keeps the edits of the transaction and forgets how to revert them
+	pub fn commit(self) {}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Transaction;
+	use crate::{api::RawLanguage, SyntaxKind, SyntaxNode, TreeBuilder};
+
+	fn build() -> SyntaxNode<RawLanguage> {
+		TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "a");
+			builder.token(SyntaxKind(2), "b");
+			builder.finish_node();
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "c");
+			builder.finish_node();
+		})
+	}
+
+	#[test]
+	fn rollback_restores_the_tree() {
+		let original = build();
+		let root = original.clone_for_update();
+		let first = root.first_child().unwrap();
+		let second = root.last_child().unwrap();
+
+		let mut transaction = Transaction::new();
+		transaction.detach(first.first_token().unwrap());
+		// Move the `c` token into the first node and delete the now empty second node
+		transaction.splice_children(&first, 1..1, vec![second.first_token().unwrap().into()]);
+		transaction.detach(second);
+		transaction.detach(root.clone());
+		assert!(!transaction.is_empty());
+		assert_eq!("bc", root.text());
+
+		transaction.rollback();
+		assert_eq!("abc", root.text());
+		assert_eq!(format!("{:#?}", original), format!("{:#?}", root));
+	}
+
+	#[test]
+	fn moves_children_before_the_deleted_range() {
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			for text in &["a", "b", "c", "d"] {
+				builder.token(SyntaxKind(1), text);
+			}
+		})
+		.clone_for_update();
+		let a = root.first_token().unwrap();
+		let b = a.next_token().unwrap();
+
+		let mut transaction = Transaction::new();
+		transaction.splice_children(&root, 2..3, vec![b.into(), a.into()]);
+		assert_eq!("bad", root.text());
+
+		transaction.rollback();
+		assert_eq!("abcd", root.text());
+	}
+
+	#[test]
+	fn commit_keeps_the_edits() {
+		let root = build().clone_for_update();
+		let mut transaction = Transaction::new();
+		transaction.splice_children(&root, 0..1, Vec::new());
+		transaction.commit();
+
+		assert_eq!("c", root.text());
+	}
+}
@@ -1,20 +1,234 @@
+use std::{borrow::Cow, error::Error, fmt, marker::PhantomData, ptr};
+
+use rustc_hash::FxHashMap;
+
 use crate::{
 	api::TriviaPiece,
 	cow_mut::CowMut,
-	green::{GreenElement, NodeCache},
-	GreenNode, Language, NodeOrToken, SyntaxNode,
+	fingerprint::{self, Fingerprint},
+	green::{GreenElement, GreenNodeError, NodeCache},
+	GreenNode, GreenNodeData, Language, NodeOrToken, SyntaxKind, SyntaxNode, TextRange, TextSize,
 };
 
 /// A checkpoint for maybe wrapping a node. See `GreenNodeBuilder::checkpoint` for details.
 #[derive(Clone, Copy, Debug)]
 pub struct Checkpoint(usize);
 
+/// A node built by a nested [TreeBuilder], see [TreeBuilder::nested].
+#[derive(Debug, Clone)]
+pub struct Subtree<L: Language> {
+	hash: u64,
+	node: GreenNode,
+	_p: PhantomData<L>,
+}
+
+impl<L: Language> Subtree<L> {
+	pub fn kind(&self) -> L::Kind {
+		L::kind_from_raw(self.node.kind())
+	}
+
+	/// Turns the subtree into the root of its own tree
+	pub fn into_node(self) -> SyntaxNode<L> {
+		SyntaxNode::new_root(self.node)
+	}
+}
+
+/// A step of building a tree, see [TreeBuilder::from_events].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeEvent<'a, K> {
+	/// Starts a node, see [TreeBuilder::start_node]
+	StartNode(K),
+	/// Adds a token to the current node, see [TreeBuilder::token_with_trivia]
+	Token {
+		kind: K,
+		/// The text of the token, including the text of its trivia
+		text: Cow<'a, str>,
+		leading: Vec<TriviaPiece>,
+		trailing: Vec<TriviaPiece>,
+	},
+	/// Adds an empty slot to the current node, see [TreeBuilder::missing]
+	Missing,
+	/// Finishes the current node, see [TreeBuilder::finish_node]
+	FinishNode,
+}
+
+impl<'a, K> TreeEvent<'a, K> {
+	/// Creates a [TreeEvent::Token] event for a token without trivia
+	pub fn token(kind: K, text: impl Into<Cow<'a, str>>) -> Self {
+		TreeEvent::Token {
+			kind,
+			text: text.into(),
+			leading: Vec::new(),
+			trailing: Vec::new(),
+		}
+	}
+}
+
+/// Error returned by [TreeBuilder::try_finish] if the text of the tree is too long
+/// for its offsets to be represented by [TextSize](crate::TextSize).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextLenOverflow {
+	/// The length of the text added to the builder
+	pub text_len: u64,
+}
+
+impl fmt::Display for TextLenOverflow {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"the text of the tree is {} bytes long but at most {} bytes are supported",
+			self.text_len,
+			u32::MAX
+		)
+	}
+}
+
+impl Error for TextLenOverflow {}
+
+/// The operations a tree sink needs to build a tree, implemented by [TreeBuilder] and, with the `arena`
+/// feature, by [ArenaTreeBuilder](crate::arena::ArenaTreeBuilder). See the methods of [TreeBuilder] for details.
+pub trait GreenBuilder<L: Language> {
+	/// Start new node and make it current.
+	fn start_node(&mut self, kind: L::Kind);
+
+	/// Finish current branch and restore previous branch as current.
+	fn finish_node(&mut self);
+
+	/// Adds new token to the current branch.
+	fn token_with_trivia(
+		&mut self,
+		kind: L::Kind,
+		text: &str,
+		leading: Vec<TriviaPiece>,
+		trailing: Vec<TriviaPiece>,
+	);
+
+	/// Inserts a placeholder for a missing child.
+	fn missing(&mut self);
+
+	/// Records an error on the current node.
+	fn error(&mut self, range: TextRange, message: String);
+
+	/// Returns the range of the current node so far.
+	fn current_node_range(&self) -> Option<TextRange>;
+}
+
+impl<L: Language> GreenBuilder<L> for TreeBuilder<'_, L> {
+	fn start_node(&mut self, kind: L::Kind) {
+		TreeBuilder::start_node(self, kind)
+	}
+
+	fn finish_node(&mut self) {
+		TreeBuilder::finish_node(self)
+	}
+
+	fn token_with_trivia(
+		&mut self,
+		kind: L::Kind,
+		text: &str,
+		leading: Vec<TriviaPiece>,
+		trailing: Vec<TriviaPiece>,
+	) {
+		TreeBuilder::token_with_trivia(self, kind, text, leading, trailing)
+	}
+
+	fn missing(&mut self) {
+		TreeBuilder::missing(self)
+	}
+
+	fn error(&mut self, range: TextRange, message: String) {
+		TreeBuilder::error(self, range, message)
+	}
+
+	fn current_node_range(&self) -> Option<TextRange> {
+		TreeBuilder::current_node_range(self)
+	}
+}
+
+/// The nodes of a previous tree that a builder can reuse, see [TreeBuilder::with_reuse].
+#[derive(Debug, Default)]
+struct Reuse {
+	/// The nodes of the previous tree by their fingerprint
+	nodes: FxHashMap<Fingerprint, GreenNode>,
+	/// The fingerprints of the children of the builder, [None] for empty slots
+	fingerprints: Vec<Option<Fingerprint>>,
+}
+
+impl Reuse {
+	fn new(root: &GreenNodeData) -> Self {
+		let mut reuse = Reuse::default();
+		reuse.add(root);
+		reuse
+	}
+
+	fn add(&mut self, node: &GreenNodeData) -> Fingerprint {
+		let slots = node.slots().map(|slot| match slot.as_ref()? {
+			NodeOrToken::Node(child) => {
+				let fingerprint = self.add(child);
+				self.nodes
+					.entry(fingerprint)
+					.or_insert_with(|| child.to_owned());
+				Some(fingerprint)
+			}
+			NodeOrToken::Token(token) => Some(fingerprint::token_fingerprint(token, true)),
+		});
+
+		fingerprint::combine(node.kind(), slots)
+	}
+
+	/// Returns the node of the previous tree with the given fingerprint if it has the same
+	/// kind and children as the node that is about to be built
+	fn find(
+		&self,
+		fingerprint: Fingerprint,
+		kind: SyntaxKind,
+		children: &[(u64, Option<GreenElement>)],
+	) -> Option<GreenNode> {
+		let node = self.nodes.get(&fingerprint)?;
+		if node.kind() != kind || node.slots().len() != children.len() || !node.errors().is_empty()
+		{
+			return None;
+		}
+
+		let same_children = node.slots().zip(children).all(|(slot, (_, child))| {
+			match (slot.as_ref(), child.as_ref().map(|child| child.as_deref())) {
+				(None, None) => true,
+				// The children have been reused too if they are identical, unless the previous
+				// tree contains the same subtree more than once
+				(Some(NodeOrToken::Node(old)), Some(NodeOrToken::Node(new))) => {
+					ptr::eq(old, new) || old == new
+				}
+				(Some(NodeOrToken::Token(old)), Some(NodeOrToken::Token(new))) => {
+					old == new
+						&& old.leading_trivia() == new.leading_trivia()
+						&& old.trailing_trivia() == new.trailing_trivia()
+				}
+				_ => false,
+			}
+		});
+
+		if same_children {
+			Some(node.clone())
+		} else {
+			None
+		}
+	}
+}
+
 /// A builder for a syntax tree.
 #[derive(Debug)]
 pub struct TreeBuilder<'cache, L: Language> {
 	cache: CowMut<'cache, NodeCache>,
 	parents: Vec<(L::Kind, usize)>,
 	children: Vec<(u64, Option<GreenElement>)>,
+	/// The errors of the started nodes with the number of parents at the time the error was recorded
+	errors: Vec<(usize, TextRange, String)>,
+	reuse: Option<Reuse>,
+	/// The length of all the tokens added to the builder, which may exceed [TextSize](crate::TextSize)
+	text_len: u64,
+	/// The length of the children that have been replaced by empty slots because they didn't fit into `max_text_len`
+	dropped_len: u64,
+	max_text_len: u64,
 }
 
 impl<L: Language> Default for TreeBuilder<'_, L> {
@@ -23,6 +237,11 @@ impl<L: Language> Default for TreeBuilder<'_, L> {
 			cache: CowMut::default(),
 			parents: Vec::default(),
 			children: Vec::default(),
+			errors: Vec::default(),
+			reuse: None,
+			text_len: 0,
+			dropped_len: 0,
+			max_text_len: u64::from(u32::MAX),
 		}
 	}
 }
@@ -38,8 +257,149 @@ impl<L: Language> TreeBuilder<'_, L> {
 	pub fn with_cache(cache: &mut NodeCache) -> TreeBuilder<'_, L> {
 		TreeBuilder {
 			cache: CowMut::Borrowed(cache),
-			parents: Vec::new(),
-			children: Vec::new(),
+			..TreeBuilder::default()
+		}
+	}
+
+	/// Makes the builder reuse the nodes of `old_root` instead of allocating new ones. Call it
+	/// before adding anything to the builder, it can be combined with [TreeBuilder::with_cache].
+	///
+	/// When a node is finished, the builder looks up a node of the previous tree with the same
+	/// [fingerprint](SyntaxNode::fingerprint) and uses it if it has the same kind and children.
+	/// Re-parsing a file after a small change then shares all unchanged subtrees with the previous
+	/// tree, even without an incremental parser. Subtrees built by [TreeBuilder::nested] builders
+	/// are not reused.
+	///
+	/// ```
+	/// use rome_rowan::{api::RawLanguage, SyntaxKind, TreeBuilder};
+	///
+	/// fn build(builder: &mut TreeBuilder<RawLanguage>, name: &str) {
+	///     builder.start_node(SyntaxKind(0));
+	///     builder.start_node(SyntaxKind(1));
+	///     builder.start_node(SyntaxKind(2));
+	///     builder.token(SyntaxKind(3), "a");
+	///     builder.finish_node();
+	///     builder.finish_node();
+	///     builder.start_node(SyntaxKind(1));
+	///     builder.token(SyntaxKind(3), name);
+	///     builder.finish_node();
+	///     builder.finish_node();
+	/// }
+	///
+	/// let mut builder = TreeBuilder::new();
+	/// build(&mut builder, "b");
+	/// let old_root = builder.finish();
+	///
+	/// let mut builder = TreeBuilder::new().with_reuse(&old_root);
+	/// build(&mut builder, "c");
+	/// let new_root = builder.finish();
+	///
+	/// assert_eq!("ac", new_root.text());
+	/// let reused = |root: &rome_rowan::SyntaxNode<RawLanguage>| root.first_child().unwrap().first_child().unwrap();
+	/// assert!(reused(&old_root).shares_green(&reused(&new_root)));
+	/// assert!(!old_root.last_child().unwrap().shares_green(&new_root.last_child().unwrap()));
+	/// ```
+	pub fn with_reuse(mut self, old_root: &SyntaxNode<L>) -> Self {
+		let green = old_root.green();
+		let mut reuse = Reuse::new(&green);
+		let root_fingerprint = fingerprint::node_fingerprint(&green, true);
+		reuse
+			.nodes
+			.entry(root_fingerprint)
+			.or_insert_with(|| green.into_owned());
+
+		self.reuse = Some(reuse);
+		self
+	}
+
+	/// Builds a tree from a sequence of events, for example the output of a parser that doesn't
+	/// use a [TreeBuilder] itself. The events are consumed one at a time, so the tokens don't need
+	/// to be held in memory at once.
+	///
+	/// ```
+	/// use rome_rowan::{api::RawLanguage, SyntaxKind, TreeBuilder, TreeEvent, TriviaPiece};
+	///
+	/// let events = vec![
+	///     TreeEvent::StartNode(SyntaxKind(0)),
+	///     TreeEvent::token(SyntaxKind(1), "let"),
+	///     TreeEvent::Token {
+	///         kind: SyntaxKind(2),
+	///         text: " a".into(),
+	///         leading: vec![TriviaPiece::Whitespace(1)],
+	///         trailing: vec![],
+	///     },
+	///     TreeEvent::Missing,
+	///     TreeEvent::FinishNode,
+	/// ];
+	///
+	/// let root = TreeBuilder::<RawLanguage>::from_events(events);
+	/// assert_eq!("let a", root.text());
+	/// assert_eq!(3, root.slots().len());
+	/// ```
+	///
+	/// ## Panics
+	/// If the events don't describe a single root node, for example because a started node isn't
+	/// finished, or if the text of the tree is longer than [TextSize] can represent
+	pub fn from_events<'a, I>(events: I) -> SyntaxNode<L>
+	where
+		I: IntoIterator<Item = TreeEvent<'a, L::Kind>>,
+	{
+		let mut builder = TreeBuilder::<L>::new();
+		builder.events(events);
+		assert!(
+			builder.parents.is_empty(),
+			"the events don't finish all started nodes"
+		);
+		builder.finish()
+	}
+
+	/// Builds a root node of `kind` whose children are the given tokens, for example the tokens
+	/// produced by an external lexer.
+	///
+	/// ```
+	/// use rome_rowan::{api::RawLanguage, SyntaxKind, TreeBuilder};
+	///
+	/// let tokens = "a b".split_inclusive(' ').map(|text| (SyntaxKind(1), text));
+	/// let root = TreeBuilder::<RawLanguage>::from_tokens(SyntaxKind(0), tokens);
+	/// assert_eq!("a b", root.text());
+	/// assert_eq!(2, root.children_with_tokens().count());
+	/// ```
+	pub fn from_tokens<I, T>(kind: L::Kind, tokens: I) -> SyntaxNode<L>
+	where
+		I: IntoIterator<Item = (L::Kind, T)>,
+		T: AsRef<str>,
+	{
+		let mut builder = TreeBuilder::<L>::new();
+		builder.start_node(kind);
+		for (kind, text) in tokens {
+			builder.token(kind, text.as_ref());
+		}
+		builder.finish_node();
+		builder.finish()
+	}
+
+	/// Applies a single event to this builder, see [TreeBuilder::from_events]
+	pub fn event(&mut self, event: TreeEvent<'_, L::Kind>) {
+		match event {
+			TreeEvent::StartNode(kind) => self.start_node(kind),
+			TreeEvent::Token {
+				kind,
+				text,
+				leading,
+				trailing,
+			} => self.token_with_trivia(kind, &text, leading, trailing),
+			TreeEvent::Missing => self.missing(),
+			TreeEvent::FinishNode => self.finish_node(),
+		}
+	}
+
+	/// Applies all events to this builder, see [TreeBuilder::from_events]
+	pub fn events<'a, I>(&mut self, events: I)
+	where
+		I: IntoIterator<Item = TreeEvent<'a, L::Kind>>,
+	{
+		for event in events {
+			self.event(event);
 		}
 	}
 
@@ -59,11 +419,115 @@ impl<L: Language> TreeBuilder<'_, L> {
 		builder.finish()
 	}
 
+	/// Same as [TreeBuilder::wrap_with_node] but returns the value returned by `build` alongside the node,
+	/// for example the diagnostics collected while building the tree.
+	///
+	/// ```
+	/// # use rome_rowan::{TreeBuilder, SyntaxKind};
+	/// # use rome_rowan::api::RawLanguage;
+	/// let (node, count) = TreeBuilder::<RawLanguage>::wrap_with_node_and(SyntaxKind(0), |builder| {
+	///     builder.token(SyntaxKind(1), "a");
+	///     builder.token(SyntaxKind(1), "b");
+	///     2
+	/// });
+	/// assert_eq!("ab", node.text());
+	/// assert_eq!(2, count);
+	/// ```
+	pub fn wrap_with_node_and<F, T>(kind: L::Kind, build: F) -> (SyntaxNode<L>, T)
+	where
+		F: FnOnce(&mut Self) -> T,
+	{
+		let mut builder = TreeBuilder::<L>::new();
+		builder.start_node(kind);
+		let data = build(&mut builder);
+		builder.finish_node();
+		(builder.finish(), data)
+	}
+
+	/// Same as [TreeBuilder::wrap_with_node_and] but stops building the tree as soon as `build` returns an error.
+	///
+	/// ```
+	/// # use rome_rowan::{TreeBuilder, SyntaxKind};
+	/// # use rome_rowan::api::RawLanguage;
+	/// let result = TreeBuilder::<RawLanguage>::try_wrap_with_node(SyntaxKind(0), |builder| {
+	///     for c in &["1", "x"] {
+	///         if c.parse::<u32>().is_err() {
+	///             return Err(format!("expected a number but found `{}`", c));
+	///         }
+	///         builder.token(SyntaxKind(1), c);
+	///     }
+	///     Ok(())
+	/// });
+	/// assert_eq!(Err(String::from("expected a number but found `x`")), result.map(|(node, _)| node.to_string()));
+	/// ```
+	pub fn try_wrap_with_node<F, T, E>(kind: L::Kind, build: F) -> Result<(SyntaxNode<L>, T), E>
+	where
+		F: FnOnce(&mut Self) -> Result<T, E>,
+	{
+		let mut builder = TreeBuilder::<L>::new();
+		builder.start_node(kind);
+		let data = build(&mut builder)?;
+		builder.finish_node();
+		Ok((builder.finish(), data))
+	}
+
+	/// Creates a builder sharing the cache of this builder. The nested builder builds a single node
+	/// that can be appended to this builder at any later point with [TreeBuilder::subtree], which
+	/// allows to build the children of a node out of order.
+	///
+	/// ```
+	/// # use rome_rowan::{TreeBuilder, SyntaxKind};
+	/// # use rome_rowan::api::RawLanguage;
+	/// let mut builder = TreeBuilder::<RawLanguage>::new();
+	/// // The value is parsed before the key but must come after it in the tree
+	/// let value = builder.nested().wrap_subtree(SyntaxKind(2), |builder| {
+	///     builder.token(SyntaxKind(3), "1");
+	/// });
+	///
+	/// builder.start_node(SyntaxKind(0));
+	/// builder.token(SyntaxKind(1), "a:");
+	/// builder.subtree(value);
+	/// builder.finish_node();
+	/// assert_eq!("a:1", builder.finish().text());
+	/// ```
+	pub fn nested(&mut self) -> TreeBuilder<'_, L> {
+		TreeBuilder {
+			cache: CowMut::Borrowed(&mut *self.cache),
+			parents: Vec::new(),
+			children: Vec::new(),
+			errors: Vec::new(),
+			reuse: None,
+			text_len: 0,
+			dropped_len: 0,
+			max_text_len: self.max_text_len,
+		}
+	}
+
+	/// Builds a node of `kind` with the children added by `build` and returns it as a [Subtree].
+	/// Intended to be called on the builder returned by [TreeBuilder::nested].
+	pub fn wrap_subtree<F>(mut self, kind: L::Kind, build: F) -> Subtree<L>
+	where
+		F: FnOnce(&mut Self),
+	{
+		self.start_node(kind);
+		build(&mut self);
+		self.finish_node();
+		self.finish_subtree()
+	}
+
+	/// Appends a node built by a nested builder to the current branch.
+	#[inline]
+	pub fn subtree(&mut self, subtree: Subtree<L>) {
+		self.text_len += u64::from(u32::from(subtree.node.text_len()));
+		self.push_child(subtree.hash, Some(subtree.node.into()));
+	}
+
 	/// Adds new token to the current branch.
 	#[inline]
 	pub fn token(&mut self, kind: L::Kind, text: &str) {
+		self.text_len += text.len() as u64;
 		let (hash, token) = self.cache.token(L::kind_to_raw(kind), text);
-		self.children.push((hash, Some(token.into())));
+		self.push_child(hash, Some(token.into()));
 	}
 
 	/// Adds new token to the current branch.
@@ -75,10 +539,48 @@ impl<L: Language> TreeBuilder<'_, L> {
 		leading: Vec<TriviaPiece>,
 		trailing: Vec<TriviaPiece>,
 	) {
+		self.text_len += text.len() as u64;
 		let (hash, token) =
 			self.cache
 				.token_with_trivia(L::kind_to_raw(kind), text, leading, trailing);
-		self.children.push((hash, Some(token.into())));
+		self.push_child(hash, Some(token.into()));
+	}
+
+	/// Appends a child to the current branch and records its fingerprint if the builder reuses nodes
+	fn push_child(&mut self, hash: u64, element: Option<GreenElement>) {
+		if let Some(reuse) = &mut self.reuse {
+			let fingerprint = element
+				.as_ref()
+				.map(|element| fingerprint::element_fingerprint(element.as_deref(), true));
+			reuse.fingerprints.push(fingerprint);
+		}
+		self.children.push((hash, element));
+	}
+
+	/// The length of the text of the children of the builder, excluding the children that have been dropped
+	fn kept_text_len(&self) -> u64 {
+		self.text_len - self.dropped_len
+	}
+
+	/// Replaces the last children with empty slots until the length of the tree fits into `max_text_len`.
+	/// The error is reported when finishing the tree. Children are only added to a node when it's finished,
+	/// so it's enough to check the length once per node.
+	#[cold]
+	fn drop_overflowing_children(&mut self) {
+		for index in (0..self.children.len()).rev() {
+			if self.kept_text_len() <= self.max_text_len {
+				break;
+			}
+			if let Some(child) = self.children[index].1.take() {
+				self.dropped_len += u64::from(u32::from(child.text_len()));
+				self.children[index] = NodeCache::empty();
+				if let Some(reuse) = &mut self.reuse {
+					reuse.fingerprints[index] = None;
+				}
+			}
+		}
+		// The text of the tree ends at the first dropped child, shorter children added later don't fit either
+		self.max_text_len = self.kept_text_len();
 	}
 
 	/// Inserts a placeholder for a child that is missing in a parent node either because
@@ -86,7 +588,65 @@ impl<L: Language> TreeBuilder<'_, L> {
 	/// because of a syntax error.
 	#[inline]
 	pub fn missing(&mut self) {
-		self.children.push(NodeCache::empty());
+		let (hash, element) = NodeCache::empty();
+		self.push_child(hash, element);
+	}
+
+	/// Records an error on the current node, for example a syntax error found while parsing it.
+	/// The error becomes part of the node and can be retrieved with [SyntaxNode::errors], so that
+	/// a tree and its errors can't get out of sync. `range` is relative to the start of the text
+	/// added to this builder and must be inside of the current node.
+	///
+	/// Nodes with errors are never shared with other nodes or trees through the cache.
+	///
+	/// ```
+	/// use rome_rowan::{api::RawLanguage, SyntaxKind, TextRange, TreeBuilder};
+	///
+	/// let mut builder = TreeBuilder::<RawLanguage>::new();
+	/// builder.start_node(SyntaxKind(0));
+	/// builder.token(SyntaxKind(1), "let");
+	/// builder.start_node(SyntaxKind(2));
+	/// builder.token(SyntaxKind(1), " =");
+	/// builder.error(TextRange::new(4.into(), 5.into()), "expected an identifier");
+	/// builder.finish_node();
+	/// builder.finish_node();
+	///
+	/// let root = builder.finish();
+	/// let errors: Vec<_> = root.descendants().flat_map(|node| node.errors()).collect();
+	/// assert_eq!(1, errors.len());
+	/// assert_eq!("expected an identifier", errors[0].message());
+	/// assert_eq!(TextRange::new(4.into(), 5.into()), errors[0].range());
+	/// ```
+	///
+	/// ## Panics
+	/// If no node has been started
+	pub fn error(&mut self, range: TextRange, message: impl Into<String>) {
+		assert!(
+			!self.parents.is_empty(),
+			"errors can only be recorded inside of a node"
+		);
+		self.errors
+			.push((self.parents.len(), range, message.into()));
+	}
+
+	/// Returns the range of the current node so far, from the start of its first child to the end of the
+	/// text added to the builder, or [None] if no node has been started. Like the ranges passed to
+	/// [TreeBuilder::error], the range is relative to the start of the text added to this builder.
+	pub fn current_node_range(&self) -> Option<TextRange> {
+		let (_, first_child) = self.parents.last()?;
+		Some(self.range_of_children(*first_child))
+	}
+
+	/// The range of the children starting at `first_child`, which end at the end of the text added so far
+	fn range_of_children(&self, first_child: usize) -> TextRange {
+		let len: u64 = self.children[first_child..]
+			.iter()
+			.filter_map(|(_, child)| child.as_ref())
+			.map(|child| u64::from(u32::from(child.text_len())))
+			.sum();
+		// The children that don't fit are only dropped when finishing the node
+		let saturate = |len: u64| TextSize::from(len.min(u64::from(u32::MAX)) as u32);
+		TextRange::at(saturate(self.kept_text_len() - len), saturate(len))
 	}
 
 	/// Start new node and make it current.
@@ -100,11 +660,62 @@ impl<L: Language> TreeBuilder<'_, L> {
 	/// branch as current.
 	#[inline]
 	pub fn finish_node(&mut self) {
+		if self.kept_text_len() > self.max_text_len {
+			self.drop_overflowing_children();
+		}
+
+		let depth = self.parents.len();
 		let (kind, first_child) = self.parents.pop().unwrap();
-		let (hash, node) = self
-			.cache
-			.node(L::kind_to_raw(kind), &mut self.children, first_child);
-		self.children.push((hash, Some(node.into())));
+		let kind = L::kind_to_raw(kind);
+
+		// The errors of the children have already been taken by the children
+		let first_error = self
+			.errors
+			.iter()
+			.rposition(|(error_depth, _, _)| *error_depth < depth)
+			.map_or(0, |index| index + 1);
+
+		if let Some(reuse) = &mut self.reuse {
+			let fingerprint = fingerprint::combine(kind, reuse.fingerprints.drain(first_child..));
+			reuse.fingerprints.push(Some(fingerprint));
+
+			if first_error == self.errors.len() {
+				if let Some(node) = reuse.find(fingerprint, kind, &self.children[first_child..]) {
+					self.children.truncate(first_child);
+					self.children.push(NodeCache::uncached(node));
+					return;
+				}
+			}
+		}
+
+		if first_error == self.errors.len() {
+			let (hash, node) = self.cache.node(kind, &mut self.children, first_child);
+			self.children.push((hash, Some(node.into())));
+			return;
+		}
+
+		let node_range = self.range_of_children(first_child);
+		let errors = self
+			.errors
+			.drain(first_error..)
+			.map(|(_, range, message)| {
+				assert!(
+					node_range.contains_range(range),
+					"the range {:?} of the error {:?} is outside of the node {:?}",
+					range,
+					message,
+					node_range
+				);
+				GreenNodeError {
+					rel_range: range - node_range.start(),
+					message: message.into_boxed_str(),
+				}
+			})
+			.collect();
+
+		let slots = self.children.drain(first_child..).map(|(_, child)| child);
+		let node = GreenNode::with_errors(kind, slots, errors);
+		self.children.push(NodeCache::uncached(node));
 	}
 
 	/// Prepare for maybe wrapping the next node.
@@ -161,10 +772,77 @@ impl<L: Language> TreeBuilder<'_, L> {
 	/// Complete tree building. Make sure that
 	/// `start_node_at` and `finish_node` calls
 	/// are paired!
+	///
+	/// # Panics
+	/// Panics if the text of the tree is longer than [TextSize](crate::TextSize) can represent, use
+	/// [TreeBuilder::try_finish] or [TreeBuilder::finish_truncated] to handle that case.
 	#[inline]
 	#[must_use]
 	pub fn finish(self) -> SyntaxNode<L> {
-		SyntaxNode::new_root(self.finish_green())
+		match self.try_finish() {
+			Ok(node) => node,
+			Err(error) => panic!("{}", error),
+		}
+	}
+
+	/// Same as [TreeBuilder::finish] but returns an error if the text of the tree is longer
+	/// than [TextSize](crate::TextSize) can represent, for example for a large concatenated bundle.
+	pub fn try_finish(self) -> Result<SyntaxNode<L>, TextLenOverflow> {
+		match self.finish_truncated() {
+			(node, None) => Ok(node),
+			(_, Some(error)) => Err(error),
+		}
+	}
+
+	/// Same as [TreeBuilder::try_finish] but also returns the tree if its text is too long. The children
+	/// that don't fit into the length, starting with the first one that overflows it, are replaced by
+	/// empty slots.
+	///
+	/// ```
+	/// use rome_rowan::{api::RawLanguage, SyntaxKind, TreeBuilder};
+	///
+	/// let mut builder = TreeBuilder::<RawLanguage>::new().with_max_text_len(4.into());
+	/// builder.start_node(SyntaxKind(0));
+	/// builder.token(SyntaxKind(1), "let");
+	/// builder.token(SyntaxKind(1), " a");
+	/// builder.finish_node();
+	///
+	/// let (root, error) = builder.finish_truncated();
+	/// assert_eq!("let", root.text());
+	/// assert_eq!(2, root.slots().count());
+	/// assert_eq!(5, error.unwrap().text_len);
+	/// ```
+	pub fn finish_truncated(self) -> (SyntaxNode<L>, Option<TextLenOverflow>) {
+		let error = if self.text_len > self.max_text_len {
+			Some(TextLenOverflow {
+				text_len: self.text_len,
+			})
+		} else {
+			None
+		};
+		(SyntaxNode::new_root(self.finish_green()), error)
+	}
+
+	/// Limits the length of the text of the tree, which is at most [u32::MAX] by default.
+	/// The children of the tree that don't fit are dropped and reported by [TreeBuilder::try_finish],
+	/// for example to parse only the start of files that are too large to be worth parsing completely.
+	pub fn with_max_text_len(mut self, max_text_len: TextSize) -> Self {
+		self.max_text_len = u32::from(max_text_len).into();
+		self
+	}
+
+	/// Completes the single node built by this builder, see [TreeBuilder::nested].
+	#[must_use]
+	pub fn finish_subtree(mut self) -> Subtree<L> {
+		assert_eq!(self.children.len(), 1);
+		match self.children.pop().unwrap() {
+			(hash, Some(NodeOrToken::Node(node))) => Subtree {
+				hash,
+				node,
+				_p: PhantomData,
+			},
+			_ => panic!(),
+		}
 	}
 
 	// For tests
@@ -182,7 +860,10 @@ impl<L: Language> TreeBuilder<'_, L> {
 mod tests {
 	use crate::api::RawLanguage;
 	use crate::green::GreenElementRef;
-	use crate::{GreenNodeData, GreenTokenData, NodeOrToken, SyntaxKind, TreeBuilder};
+	use crate::{
+		GreenNodeData, GreenTokenData, NodeOrToken, SyntaxKind, TextRange, TextSize, TreeBuilder,
+		TreeEvent, TriviaPiece,
+	};
 
 	// Builds a "Condition" like structure where the closing ) is missing
 	fn build_condition_with_missing_closing_parenthesis(builder: &mut TreeBuilder<RawLanguage>) {
@@ -249,6 +930,43 @@ mod tests {
 		assert_ne!(first_condition.element(), last_condition.element());
 	}
 
+	#[test]
+	fn nested_builders_share_the_cache() {
+		let mut builder: TreeBuilder<RawLanguage> = TreeBuilder::new();
+
+		let first = builder
+			.nested()
+			.wrap_subtree(SyntaxKind(2), |builder| builder.token(SyntaxKind(3), "a"));
+		let second = builder
+			.nested()
+			.wrap_subtree(SyntaxKind(2), |builder| builder.token(SyntaxKind(3), "a"));
+		assert_eq!(SyntaxKind(2), first.kind());
+
+		builder.start_node(SyntaxKind(1));
+		builder.subtree(second);
+		builder.token(SyntaxKind(4), ",");
+		builder.subtree(first);
+		builder.finish_node();
+
+		let root = builder.finish_green();
+		let first = root.children().next().unwrap();
+		let last = root.children().next_back().unwrap();
+
+		assert_same_elements(first.element(), last.element());
+	}
+
+	#[test]
+	fn try_wrap_with_node_returns_data() {
+		let result = TreeBuilder::<RawLanguage>::try_wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token(SyntaxKind(1), "a");
+			Ok::<_, ()>(1)
+		});
+
+		let (node, data) = result.unwrap();
+		assert_eq!("a", node.text());
+		assert_eq!(1, data);
+	}
+
 	fn assert_same_elements(left: GreenElementRef<'_>, right: GreenElementRef<'_>) {
 		fn element_id(element: GreenElementRef<'_>) -> *const () {
 			match element {
@@ -259,4 +977,239 @@ mod tests {
 
 		assert_eq!(element_id(left), element_id(right),);
 	}
+
+	#[test]
+	fn reuses_unchanged_subtrees_of_the_previous_tree() {
+		fn build(builder: &mut TreeBuilder<RawLanguage>, trailing: &str) {
+			builder.start_node(SyntaxKind(1));
+			build_condition_with_missing_closing_parenthesis(builder);
+			builder.start_node(SyntaxKind(6));
+			builder.token_with_trivia(
+				SyntaxKind(7),
+				&format!("b{}", trailing),
+				vec![],
+				vec![TriviaPiece::Whitespace(trailing.len())],
+			);
+			builder.finish_node();
+			builder.finish_node();
+		}
+
+		let mut builder = TreeBuilder::<RawLanguage>::new();
+		build(&mut builder, " ");
+		let old_root = builder.finish();
+
+		let mut builder = TreeBuilder::new().with_reuse(&old_root);
+		build(&mut builder, " ");
+		let same = builder.finish_green();
+		assert_same_elements(
+			NodeOrToken::Node(&old_root.green()),
+			NodeOrToken::Node(&same),
+		);
+
+		// Only changes the trivia of the last token
+		let mut builder = TreeBuilder::new().with_reuse(&old_root);
+		build(&mut builder, "  ");
+		let changed = builder.finish_green();
+		let old_green = old_root.green();
+		assert_eq!(
+			old_root.text().len() + TextSize::from(1),
+			changed.text_len()
+		);
+
+		let old_children: Vec<_> = old_green.children().map(|child| child.element()).collect();
+		let new_children: Vec<_> = changed.children().map(|child| child.element()).collect();
+		assert_same_elements(old_children[0], new_children[0]);
+		assert_ne!(old_children[1], new_children[1]);
+	}
+
+	#[test]
+	fn errors_are_recorded_on_the_current_node() {
+		let mut builder = TreeBuilder::<RawLanguage>::new();
+		builder.start_node(SyntaxKind(0));
+		builder.token(SyntaxKind(1), "a");
+		let checkpoint = builder.checkpoint();
+		builder.start_node(SyntaxKind(2));
+		builder.token(SyntaxKind(1), "b");
+		builder.error(range(1, 2), "inner");
+		builder.finish_node();
+		builder.error(range(0, 1), "outer");
+		builder.start_node_at(checkpoint, SyntaxKind(3));
+		builder.token(SyntaxKind(1), "c");
+		builder.error(range(1, 3), "wrapper");
+		builder.finish_node();
+		builder.finish_node();
+
+		let root = builder.finish();
+		let errors: Vec<_> = root
+			.descendants()
+			.flat_map(|node| node.errors())
+			.map(|error| (error.message().to_string(), error.range()))
+			.collect();
+		assert_eq!(
+			vec![
+				(String::from("outer"), range(0, 1)),
+				(String::from("wrapper"), range(1, 3)),
+				(String::from("inner"), range(1, 2)),
+			],
+			errors
+		);
+	}
+
+	#[test]
+	fn current_node_range_covers_the_children_added_so_far() {
+		let mut builder = TreeBuilder::<RawLanguage>::new();
+		assert_eq!(None, builder.current_node_range());
+		builder.start_node(SyntaxKind(0));
+		builder.token(SyntaxKind(1), "ab");
+		builder.start_node(SyntaxKind(2));
+		assert_eq!(Some(range(2, 2)), builder.current_node_range());
+		builder.token(SyntaxKind(1), "c");
+		assert_eq!(Some(range(2, 3)), builder.current_node_range());
+		builder.finish_node();
+		assert_eq!(Some(range(0, 3)), builder.current_node_range());
+	}
+
+	#[test]
+	fn nodes_with_errors_are_not_shared() {
+		let mut builder = TreeBuilder::<RawLanguage>::new();
+		builder.start_node(SyntaxKind(0));
+		for error in &[false, true] {
+			builder.start_node(SyntaxKind(1));
+			builder.token(SyntaxKind(2), "a");
+			if *error {
+				builder.error(range(1, 2), "error");
+			}
+			builder.finish_node();
+		}
+		builder.finish_node();
+
+		let root = builder.finish();
+		let first = root.first_child().unwrap();
+		let last = root.last_child().unwrap();
+		assert_eq!(0, first.errors().count());
+		assert_eq!(1, last.errors().count());
+		assert_ne!(first.green_view(), last.green_view());
+	}
+
+	#[test]
+	fn edits_remove_the_errors_after_the_changed_child() {
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token(SyntaxKind(1), "a");
+			builder.token(SyntaxKind(1), "b");
+			builder.token(SyntaxKind(1), "c");
+			builder.error(range(0, 1), "before");
+			builder.error(range(1, 3), "after");
+		})
+		.clone_for_update();
+
+		root.last_token().unwrap().detach();
+		let errors: Vec<_> = root
+			.errors()
+			.map(|error| error.message().to_string())
+			.collect();
+		assert_eq!(vec![String::from("before")], errors);
+	}
+
+	#[test]
+	#[should_panic(expected = "outside of the node")]
+	fn rejects_errors_outside_of_the_node() {
+		let mut builder = TreeBuilder::<RawLanguage>::new();
+		builder.start_node(SyntaxKind(0));
+		builder.token(SyntaxKind(1), "a");
+		builder.start_node(SyntaxKind(2));
+		builder.token(SyntaxKind(1), "b");
+		builder.error(range(0, 2), "error");
+		builder.finish_node();
+	}
+
+	#[test]
+	fn events_build_the_same_tree_as_calls() {
+		let mut builder = TreeBuilder::<RawLanguage>::new();
+		builder.start_node(SyntaxKind(0));
+		build_condition_with_missing_closing_parenthesis(&mut builder);
+		builder.token_with_trivia(
+			SyntaxKind(6),
+			"; ",
+			vec![],
+			vec![TriviaPiece::Whitespace(1)],
+		);
+		builder.finish_node();
+		let expected = builder.finish();
+
+		let events = vec![
+			TreeEvent::StartNode(SyntaxKind(0)),
+			TreeEvent::StartNode(SyntaxKind(2)),
+			TreeEvent::token(SyntaxKind(3), "("),
+			TreeEvent::StartNode(SyntaxKind(4)),
+			TreeEvent::token(SyntaxKind(5), String::from("a")),
+			TreeEvent::FinishNode,
+			TreeEvent::Missing,
+			TreeEvent::FinishNode,
+			TreeEvent::Token {
+				kind: SyntaxKind(6),
+				text: "; ".into(),
+				leading: vec![],
+				trailing: vec![TriviaPiece::Whitespace(1)],
+			},
+			TreeEvent::FinishNode,
+		];
+		let root = TreeBuilder::<RawLanguage>::from_events(events);
+
+		assert_eq!(expected.green_view(), root.green_view());
+	}
+
+	#[test]
+	#[should_panic(expected = "don't finish all started nodes")]
+	fn from_events_rejects_unfinished_nodes() {
+		TreeBuilder::<RawLanguage>::from_events(vec![
+			TreeEvent::StartNode(SyntaxKind(0)),
+			TreeEvent::StartNode(SyntaxKind(1)),
+			TreeEvent::token(SyntaxKind(2), "a"),
+			TreeEvent::FinishNode,
+		]);
+	}
+
+	fn range(start: u32, end: u32) -> TextRange {
+		TextRange::new(TextSize::from(start), TextSize::from(end))
+	}
+
+	#[test]
+	fn try_finish_reports_text_len_overflow() {
+		let mut builder = TreeBuilder::<RawLanguage>::new().with_max_text_len(4.into());
+		builder.start_node(SyntaxKind(0));
+		builder.token(SyntaxKind(1), "let");
+		builder.token(SyntaxKind(1), " a");
+		builder.token(SyntaxKind(1), ";");
+		builder.finish_node();
+
+		let error = builder.try_finish().unwrap_err();
+		assert_eq!(6, error.text_len);
+
+		let mut builder = TreeBuilder::<RawLanguage>::new().with_max_text_len(4.into());
+		builder.start_node(SyntaxKind(0));
+		builder.token(SyntaxKind(1), "let");
+		builder.finish_node();
+		assert_eq!("let", builder.try_finish().unwrap().text());
+	}
+
+	#[test]
+	fn finish_truncated_drops_the_children_that_overflow() {
+		let mut builder = TreeBuilder::<RawLanguage>::new().with_max_text_len(4.into());
+		builder.start_node(SyntaxKind(0));
+		builder.token(SyntaxKind(1), "let");
+		builder.start_node(SyntaxKind(2));
+		builder.token(SyntaxKind(1), " a");
+		builder.token(SyntaxKind(1), "b");
+		builder.finish_node();
+		builder.token(SyntaxKind(1), ";");
+		builder.finish_node();
+
+		let (root, error) = builder.finish_truncated();
+		assert_eq!(7, error.unwrap().text_len);
+		assert_eq!("let", root.text());
+		let child = root.first_child().unwrap();
+		assert_eq!(2, child.slots().count());
+		assert_eq!(0, child.children_with_tokens().count());
+		assert_eq!(3, root.slots().count());
+	}
 }
@@ -65,7 +65,7 @@ pub enum Direction {
 }
 
 /// `WalkEvent` describes tree walking process.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum WalkEvent<T> {
 	/// Fired before traversing the node.
 	Enter(T),
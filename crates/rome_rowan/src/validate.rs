@@ -0,0 +1,170 @@
+//! Checks of the internal invariants of a tree, see [SyntaxNode::validate].
+
+use crate::{Language, NodeOrToken, SyntaxNode, SyntaxToken, TextSize};
+
+/// An internal invariant of a tree that doesn't hold, returned by [SyntaxNode::validate].
+/// Any violation is a bug in the code that created or edited the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation<L: Language> {
+	/// The slot `slot` of `node` doesn't start where the previous slot ends
+	SlotOffset {
+		node: SyntaxNode<L>,
+		slot: usize,
+		expected: TextSize,
+		actual: TextSize,
+	},
+	/// The length of `node` isn't the sum of the lengths of its slots
+	NodeLength {
+		node: SyntaxNode<L>,
+		expected: TextSize,
+		actual: TextSize,
+	},
+	/// The leading and trailing trivia of `token` are longer than its text or
+	/// don't end on a character boundary
+	TriviaLength {
+		token: SyntaxToken<L>,
+		leading: TextSize,
+		trailing: TextSize,
+	},
+	/// The child in the slot `slot` of `node` reports a different slot index
+	SlotIndex {
+		node: SyntaxNode<L>,
+		slot: usize,
+		actual: usize,
+	},
+	/// The child in the slot `slot` of `node` reports a different parent
+	Parent { node: SyntaxNode<L>, slot: usize },
+	/// The child in the slot `slot` of `node` reports a different offset than the offset of the slot
+	ChildOffset {
+		node: SyntaxNode<L>,
+		slot: usize,
+		expected: TextSize,
+		actual: TextSize,
+	},
+}
+
+pub(crate) fn validate<L: Language>(root: &SyntaxNode<L>) -> Vec<InvariantViolation<L>> {
+	let mut violations = Vec::new();
+	for node in root.descendants() {
+		validate_node(&node, &mut violations);
+	}
+	violations
+}
+
+fn validate_node<L: Language>(node: &SyntaxNode<L>, violations: &mut Vec<InvariantViolation<L>>) {
+	let green = node.green();
+	let mut offset = TextSize::from(0);
+
+	for (slot, green_slot) in green.slots().enumerate() {
+		if green_slot.rel_offset() != offset {
+			violations.push(InvariantViolation::SlotOffset {
+				node: node.clone(),
+				slot,
+				expected: offset,
+				actual: green_slot.rel_offset(),
+			});
+		}
+
+		if let Some(child) = node.element_in_slot(slot as u32) {
+			if child.index() != slot {
+				violations.push(InvariantViolation::SlotIndex {
+					node: node.clone(),
+					slot,
+					actual: child.index(),
+				});
+			}
+			if child.parent().as_ref() != Some(node) {
+				violations.push(InvariantViolation::Parent {
+					node: node.clone(),
+					slot,
+				});
+			}
+
+			let expected_start = node.text_range().start() + green_slot.rel_offset();
+			if child.text_range().start() != expected_start {
+				violations.push(InvariantViolation::ChildOffset {
+					node: node.clone(),
+					slot,
+					expected: expected_start,
+					actual: child.text_range().start(),
+				});
+			}
+
+			if let NodeOrToken::Token(token) = &child {
+				validate_token(token, violations);
+			}
+		}
+
+		if let Some(element) = green_slot.as_ref() {
+			offset += element.text_len();
+		}
+	}
+
+	if green.text_len() != offset {
+		violations.push(InvariantViolation::NodeLength {
+			node: node.clone(),
+			expected: offset,
+			actual: green.text_len(),
+		});
+	}
+}
+
+fn validate_token<L: Language>(
+	token: &SyntaxToken<L>,
+	violations: &mut Vec<InvariantViolation<L>>,
+) {
+	let (leading, trailing, _) = token.green().leading_trailing_total_len();
+	if !token.green().has_valid_trivia() {
+		violations.push(InvariantViolation::TriviaLength {
+			token: token.clone(),
+			leading,
+			trailing,
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::InvariantViolation;
+	use crate::{
+		api::RawLanguage, parse_sexpr, EditBuilder, SyntaxKind, TextSize, Transaction, TreeBuilder,
+		TriviaPiece,
+	};
+
+	#[test]
+	fn edited_trees_are_valid() {
+		let root = parse_sexpr("(0 (1 (2 'a') _ (2 ' ' 'b' ' ')) (1 (3 'c')))").unwrap();
+		assert_eq!(Vec::<InvariantViolation<_>>::new(), root.validate());
+
+		let mut edits = EditBuilder::new();
+		edits.remove(root.first_token().unwrap());
+		edits.replace(root.last_child().unwrap(), None);
+		assert!(edits.finish(&root).validate().is_empty());
+
+		let mutable = root.clone_for_update();
+		let first = mutable.first_child().unwrap();
+		let mut transaction = Transaction::new();
+		transaction.detach(first.first_token().unwrap());
+		first.insert_child_at(1, mutable.last_token().unwrap());
+		assert!(mutable.validate().is_empty());
+
+		transaction.rollback();
+		assert!(mutable.validate().is_empty());
+	}
+
+	#[test]
+	fn reports_trivia_longer_than_the_token() {
+		let root = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			builder.token_with_trivia(SyntaxKind(1), "a", vec![TriviaPiece::Whitespace(2)], vec![]);
+		});
+
+		assert_eq!(
+			vec![InvariantViolation::TriviaLength {
+				token: root.first_token().unwrap(),
+				leading: TextSize::from(2),
+				trailing: TextSize::from(0),
+			}],
+			root.validate()
+		);
+	}
+}
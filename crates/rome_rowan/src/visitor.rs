@@ -0,0 +1,156 @@
+//! Callback based traversal of a syntax tree, see [SyntaxNode::walk] and [SyntaxVisitor].
+
+use crate::{Language, SyntaxNode, WalkEvent};
+
+/// Tells the traversal how to continue after a node has been entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisitControl {
+	/// Visit the children of the node
+	Continue,
+	/// Don't visit the children of the node, the node is still left
+	SkipChildren,
+	/// Stop the traversal, no more events are emitted
+	Stop,
+}
+
+/// A visitor of the nodes of a syntax tree, see [SyntaxNode::accept].
+pub trait SyntaxVisitor<L: Language> {
+	/// Returns `true` if [SyntaxVisitor::enter] and [SyntaxVisitor::leave] should be called for
+	/// nodes of `kind`. The children of the other nodes are still visited.
+	fn visits(&self, _kind: L::Kind) -> bool {
+		true
+	}
+
+	/// Called before the children of `node` are visited
+	fn enter(&mut self, _node: &SyntaxNode<L>) -> VisitControl {
+		VisitControl::Continue
+	}
+
+	/// Called after the children of `node` have been visited, or skipped
+	fn leave(&mut self, _node: &SyntaxNode<L>) -> VisitControl {
+		VisitControl::Continue
+	}
+}
+
+impl<L: Language> SyntaxNode<L> {
+	/// Traverses the subtree rooted at this node (including this node) in preorder and calls
+	/// `visit` when entering and when leaving every node. The return value of `visit` decides
+	/// whether the children of an entered node are visited, it is ignored when a node is left
+	/// unless it is [VisitControl::Stop].
+	///
+	/// ```
+	/// use rome_rowan::{*, api::RawLanguage};
+	/// let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+	///     builder.start_node(SyntaxKind(1));
+	///     builder.start_node(SyntaxKind(2));
+	///     builder.token(SyntaxKind(3), "a");
+	///     builder.finish_node();
+	///     builder.finish_node();
+	/// });
+	///
+	/// let mut events = Vec::new();
+	/// node.walk(|event| {
+	///     let control = match &event {
+	///         WalkEvent::Enter(node) if node.kind() == SyntaxKind(1) => VisitControl::SkipChildren,
+	///         _ => VisitControl::Continue,
+	///     };
+	///     events.push(event.map(|node| node.kind().0));
+	///     control
+	/// });
+	///
+	/// assert_eq!(
+	///     vec![WalkEvent::Enter(0), WalkEvent::Enter(1), WalkEvent::Leave(1), WalkEvent::Leave(0)],
+	///     events
+	/// );
+	/// ```
+	pub fn walk<F>(&self, mut visit: F)
+	where
+		F: FnMut(WalkEvent<&SyntaxNode<L>>) -> VisitControl,
+	{
+		let mut preorder = self.preorder();
+		while let Some(event) = preorder.next() {
+			let control = match &event {
+				WalkEvent::Enter(node) => visit(WalkEvent::Enter(node)),
+				WalkEvent::Leave(node) => visit(WalkEvent::Leave(node)),
+			};
+
+			match (control, event) {
+				(VisitControl::Stop, _) => return,
+				(VisitControl::SkipChildren, WalkEvent::Enter(_)) => preorder.skip_subtree(),
+				_ => {}
+			}
+		}
+	}
+
+	/// Traverses the subtree rooted at this node with `visitor`, see [SyntaxNode::walk].
+	pub fn accept<V>(&self, visitor: &mut V)
+	where
+		V: SyntaxVisitor<L> + ?Sized,
+	{
+		self.walk(|event| match event {
+			WalkEvent::Enter(node) if visitor.visits(node.kind()) => visitor.enter(node),
+			WalkEvent::Leave(node) if visitor.visits(node.kind()) => visitor.leave(node),
+			_ => VisitControl::Continue,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{SyntaxVisitor, VisitControl};
+	use crate::{api::RawLanguage, SyntaxKind, SyntaxNode, TreeBuilder};
+
+	/// Collects the text of the nodes of kind 2 and stops at the first node of kind 3
+	#[derive(Default)]
+	struct Collector {
+		texts: Vec<String>,
+		left: usize,
+	}
+
+	impl SyntaxVisitor<RawLanguage> for Collector {
+		fn visits(&self, kind: SyntaxKind) -> bool {
+			kind != SyntaxKind(0)
+		}
+
+		fn enter(&mut self, node: &SyntaxNode<RawLanguage>) -> VisitControl {
+			match node.kind() {
+				SyntaxKind(2) => {
+					self.texts.push(node.text().to_string());
+					VisitControl::SkipChildren
+				}
+				SyntaxKind(3) => VisitControl::Stop,
+				_ => VisitControl::Continue,
+			}
+		}
+
+		fn leave(&mut self, _node: &SyntaxNode<RawLanguage>) -> VisitControl {
+			self.left += 1;
+			VisitControl::Continue
+		}
+	}
+
+	#[test]
+	fn visitor_skips_and_stops() {
+		let node = TreeBuilder::<RawLanguage>::wrap_with_node(SyntaxKind(0), |builder| {
+			for text in &["a", "b"] {
+				builder.start_node(SyntaxKind(2));
+				builder.start_node(SyntaxKind(2));
+				builder.token(SyntaxKind(4), text);
+				builder.finish_node();
+				builder.finish_node();
+			}
+			builder.start_node(SyntaxKind(3));
+			builder.finish_node();
+			builder.start_node(SyntaxKind(2));
+			builder.token(SyntaxKind(4), "c");
+			builder.finish_node();
+		});
+
+		let mut collector = Collector::default();
+		node.accept(&mut collector);
+
+		assert_eq!(vec!["a", "b"], collector.texts);
+		// The two skipped nodes of kind 2 are still left, the root isn't visited
+		assert_eq!(2, collector.left);
+	}
+}
@@ -9,6 +9,7 @@ use std::ops::Range;
 /// These are ordered in the following way:
 #[derive(Copy, Clone, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Severity {
 	/// An unexpected bug.
 	Bug,
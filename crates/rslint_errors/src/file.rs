@@ -1,4 +1,5 @@
-use rome_rowan::{Language, SyntaxElement, SyntaxNode, SyntaxToken, TextRange};
+use crate::line_index::LineIndex;
+use rome_rowan::{Language, SyntaxElement, SyntaxNode, SyntaxToken, TextRange, TextSize};
 use std::{collections::HashMap, ops::Range};
 
 /// A value which can be used as the range inside of a diagnostic.
@@ -167,14 +168,14 @@ pub trait Files {
 pub struct SimpleFile {
 	name: String,
 	source: String,
-	line_starts: Vec<usize>,
+	lines: LineIndex,
 }
 
 impl SimpleFile {
 	/// Create a new file with the name and source.
 	pub fn new(name: String, source: String) -> Self {
 		Self {
-			line_starts: line_starts(&source).collect(),
+			lines: LineIndex::new(&source),
 			name,
 			source,
 		}
@@ -185,14 +186,9 @@ impl SimpleFile {
 		SimpleFile::new(String::new(), String::new())
 	}
 
-	fn line_start(&self, line_index: usize) -> Option<usize> {
-		use std::cmp::Ordering;
-
-		match line_index.cmp(&self.line_starts.len()) {
-			Ordering::Less => self.line_starts.get(line_index).cloned(),
-			Ordering::Equal => Some(self.source.len()),
-			Ordering::Greater => None,
-		}
+	/// The index of the lines of the file
+	pub fn lines(&self) -> &LineIndex {
+		&self.lines
 	}
 }
 
@@ -206,18 +202,13 @@ impl Files for SimpleFile {
 	}
 
 	fn line_index(&self, _file_id: FileId, byte_index: usize) -> Option<usize> {
-		Some(
-			self.line_starts
-				.binary_search(&byte_index)
-				.unwrap_or_else(|next_line| next_line - 1),
-		)
+		let line = self.lines.line_col(TextSize::from(byte_index as u32)).line;
+		Some(line as usize)
 	}
 
 	fn line_range(&self, _: FileId, line_index: usize) -> Option<Range<usize>> {
-		let line_start = self.line_start(line_index)?;
-		let next_line_start = self.line_start(line_index + 1)?;
-
-		Some(line_start..next_line_start)
+		let range = self.lines.line_range(line_index as u32)?;
+		Some(range.into())
 	}
 }
 
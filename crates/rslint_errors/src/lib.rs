@@ -1,8 +1,11 @@
 #![deny(rust_2018_idioms)]
 
 pub mod file;
+pub mod line_index;
 #[cfg(feature = "lsp")]
 pub mod lsp;
+pub mod metadata;
+pub mod source_map;
 
 mod codespan;
 mod diagnostic;
@@ -10,7 +13,7 @@ mod emit;
 mod formatters;
 mod suggestion;
 
-pub use diagnostic::{Diagnostic, SubDiagnostic};
+pub use diagnostic::{Diagnostic, Footer, SubDiagnostic};
 pub use emit::Emitter;
 pub use file::Span;
 pub use formatters::*;
@@ -0,0 +1,325 @@
+//! Conversion between byte offsets and line/column positions of a file.
+
+use rome_rowan::{TextRange, TextSize};
+use std::collections::HashMap;
+
+/// A zero based line and column, the column is counted in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LineCol {
+	pub line: u32,
+	pub col: u32,
+}
+
+/// A zero based line and column, the column is counted in UTF-16 code units
+/// like the positions of the language server protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LineColUtf16 {
+	pub line: u32,
+	pub col: u32,
+}
+
+/// The unit in which the columns of a [WideLineCol] are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PositionEncoding {
+	/// Bytes of UTF-8, the same columns as [LineCol]
+	Utf8,
+	/// UTF-16 code units, the default of the language server protocol
+	Utf16,
+	/// Unicode scalar values, which is what `str::chars` counts
+	Utf32,
+}
+
+/// A zero based line and column, the column is counted in the units of a [PositionEncoding].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WideLineCol {
+	pub line: u32,
+	pub col: u32,
+}
+
+/// A character that takes more than one byte in UTF-8
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WideChar {
+	/// The column of the character in bytes
+	start: TextSize,
+	len_utf8: u32,
+	len_utf16: u32,
+}
+
+impl WideChar {
+	fn len(&self, encoding: PositionEncoding) -> u32 {
+		match encoding {
+			PositionEncoding::Utf8 => self.len_utf8,
+			PositionEncoding::Utf16 => self.len_utf16,
+			PositionEncoding::Utf32 => 1,
+		}
+	}
+}
+
+/// Index of the lines of a file, built once and used to convert offsets to line/column positions.
+///
+/// `\n`, `\r\n`, `\r`, `\u{2028}` and `\u{2029}` terminate a line.
+///
+/// ```
+/// use rslint_errors::line_index::{LineCol, LineColUtf16, LineIndex};
+///
+/// let index = LineIndex::new("let a;\r\nlet 😀 = a;");
+/// assert_eq!(LineCol { line: 1, col: 4 }, index.line_col(12.into()));
+/// assert_eq!(Some(12.into()), index.offset(LineCol { line: 1, col: 4 }));
+///
+/// // The emoji is 4 bytes long but only 2 UTF-16 code units
+/// let end_of_emoji = index.line_col(16.into());
+/// assert_eq!(LineColUtf16 { line: 1, col: 6 }, index.to_utf16(end_of_emoji));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+	/// The offset of the first character of every line
+	line_starts: Vec<TextSize>,
+	/// The wide characters of the lines that have any
+	wide_chars: HashMap<u32, Vec<WideChar>>,
+	len: TextSize,
+}
+
+impl LineIndex {
+	pub fn new(text: &str) -> LineIndex {
+		let mut line_starts = vec![TextSize::from(0)];
+		let mut wide_chars = HashMap::new();
+		let mut line_wide_chars = Vec::new();
+		let mut line_start = 0;
+
+		let mut chars = text.char_indices().peekable();
+		while let Some((offset, c)) = chars.next() {
+			let terminator_len = match c {
+				'\r' if matches!(chars.peek(), Some((_, '\n'))) => {
+					chars.next();
+					2
+				}
+				'\n' | '\r' | '\u{2028}' | '\u{2029}' => c.len_utf8(),
+				_ => {
+					if c.len_utf8() > 1 {
+						line_wide_chars.push(WideChar {
+							start: TextSize::from((offset - line_start) as u32),
+							len_utf8: c.len_utf8() as u32,
+							len_utf16: c.len_utf16() as u32,
+						});
+					}
+					continue;
+				}
+			};
+
+			if !line_wide_chars.is_empty() {
+				let line = (line_starts.len() - 1) as u32;
+				wide_chars.insert(line, std::mem::take(&mut line_wide_chars));
+			}
+			line_start = offset + terminator_len;
+			line_starts.push(TextSize::from(line_start as u32));
+		}
+
+		if !line_wide_chars.is_empty() {
+			wide_chars.insert((line_starts.len() - 1) as u32, line_wide_chars);
+		}
+
+		LineIndex {
+			line_starts,
+			wide_chars,
+			len: TextSize::of(text),
+		}
+	}
+
+	/// The number of lines, a file always has at least one line
+	pub fn line_count(&self) -> usize {
+		self.line_starts.len()
+	}
+
+	/// The line and column of `offset`, offsets past the end of the text are clamped to the end.
+	pub fn line_col(&self, offset: TextSize) -> LineCol {
+		let offset = offset.min(self.len);
+		let line = self
+			.line_starts
+			.partition_point(|start| *start <= offset)
+			.saturating_sub(1);
+		LineCol {
+			line: line as u32,
+			col: (offset - self.line_starts[line]).into(),
+		}
+	}
+
+	/// The offset of a line and column, `None` if the position is outside of the text.
+	pub fn offset(&self, line_col: LineCol) -> Option<TextSize> {
+		let range = self.line_range(line_col.line)?;
+		let offset = range.start() + TextSize::from(line_col.col);
+		if offset <= range.end() {
+			Some(offset)
+		} else {
+			None
+		}
+	}
+
+	/// The range of a line including its line terminator
+	pub fn line_range(&self, line: u32) -> Option<TextRange> {
+		let start = *self.line_starts.get(line as usize)?;
+		let end = self
+			.line_starts
+			.get(line as usize + 1)
+			.copied()
+			.unwrap_or(self.len);
+		Some(TextRange::new(start, end))
+	}
+
+	pub fn to_utf16(&self, line_col: LineCol) -> LineColUtf16 {
+		let WideLineCol { line, col } = self.to_wide(PositionEncoding::Utf16, line_col);
+		LineColUtf16 { line, col }
+	}
+
+	pub fn to_utf8(&self, line_col: LineColUtf16) -> LineCol {
+		self.from_wide(
+			PositionEncoding::Utf16,
+			WideLineCol {
+				line: line_col.line,
+				col: line_col.col,
+			},
+		)
+	}
+
+	/// Converts the byte column of `line_col` to a column in the units of `encoding`
+	pub fn to_wide(&self, encoding: PositionEncoding, line_col: LineCol) -> WideLineCol {
+		let mut col = line_col.col;
+		for wide_char in self.line_wide_chars(line_col.line) {
+			if u32::from(wide_char.start) >= line_col.col {
+				break;
+			}
+			col -= wide_char.len_utf8 - wide_char.len(encoding);
+		}
+		WideLineCol {
+			line: line_col.line,
+			col,
+		}
+	}
+
+	/// Converts a column in the units of `encoding` to a byte column
+	pub fn from_wide(&self, encoding: PositionEncoding, line_col: WideLineCol) -> LineCol {
+		let mut col = line_col.col;
+		for wide_char in self.line_wide_chars(line_col.line) {
+			if u32::from(wide_char.start) >= col {
+				break;
+			}
+			col += wide_char.len_utf8 - wide_char.len(encoding);
+		}
+		LineCol {
+			line: line_col.line,
+			col,
+		}
+	}
+
+	/// Returns the start and end position of `range` with the columns in the units of `encoding`,
+	/// e.g. of the range of a token or node of a syntax tree of the text.
+	///
+	/// ```
+	/// use rslint_errors::line_index::{LineIndex, PositionEncoding, WideLineCol};
+	/// use rome_rowan::{TextRange, TextSize};
+	///
+	/// let text = "let 𝕏 = 'é';";
+	/// let index = LineIndex::new(text);
+	/// let string = TextRange::at(TextSize::from(11), TextSize::of("'é'"));
+	///
+	/// let (start, end) = index.range_to_wide(PositionEncoding::Utf16, string);
+	/// assert_eq!((WideLineCol { line: 0, col: 9 }, WideLineCol { line: 0, col: 12 }), (start, end));
+	///
+	/// let (start, end) = index.range_to_wide(PositionEncoding::Utf32, string);
+	/// assert_eq!((WideLineCol { line: 0, col: 8 }, WideLineCol { line: 0, col: 11 }), (start, end));
+	/// ```
+	pub fn range_to_wide(
+		&self,
+		encoding: PositionEncoding,
+		range: TextRange,
+	) -> (WideLineCol, WideLineCol) {
+		(
+			self.to_wide(encoding, self.line_col(range.start())),
+			self.to_wide(encoding, self.line_col(range.end())),
+		)
+	}
+
+	fn line_wide_chars(&self, line: u32) -> impl Iterator<Item = &WideChar> {
+		self.wide_chars.get(&line).into_iter().flatten()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn line_terminators() {
+		let index = LineIndex::new("a\nb\r\nc\rd\u{2028}e\u{2029}");
+		assert_eq!(6, index.line_count());
+
+		let starts: Vec<u32> = (0..6)
+			.map(|line| index.line_range(line).unwrap().start().into())
+			.collect();
+		assert_eq!(vec![0, 2, 5, 7, 11, 15], starts);
+		assert_eq!(None, index.line_range(6));
+
+		assert_eq!(LineCol { line: 1, col: 1 }, index.line_col(3.into()));
+		assert_eq!(LineCol { line: 1, col: 2 }, index.line_col(4.into()));
+		assert_eq!(LineCol { line: 5, col: 0 }, index.line_col(100.into()));
+	}
+
+	#[test]
+	fn offsets_roundtrip() {
+		let text = "const é = 'ü';\n\nfoo(𝕏, é)";
+		let index = LineIndex::new(text);
+
+		for (offset, _) in text.char_indices() {
+			let offset = TextSize::from(offset as u32);
+			let line_col = index.line_col(offset);
+			assert_eq!(Some(offset), index.offset(line_col));
+			assert_eq!(line_col, index.to_utf8(index.to_utf16(line_col)));
+		}
+
+		assert_eq!(None, index.offset(LineCol { line: 1, col: 2 }));
+		assert_eq!(None, index.offset(LineCol { line: 3, col: 0 }));
+	}
+
+	#[test]
+	fn wide_columns() {
+		let text = "é𝕏a\n\taé";
+		let index = LineIndex::new(text);
+
+		for encoding in [
+			PositionEncoding::Utf8,
+			PositionEncoding::Utf16,
+			PositionEncoding::Utf32,
+		] {
+			for (offset, _) in text.char_indices() {
+				let line_col = index.line_col(TextSize::from(offset as u32));
+				let wide = index.to_wide(encoding, line_col);
+				assert_eq!(line_col, index.from_wide(encoding, wide));
+
+				let line_start = usize::from(index.line_range(line_col.line).unwrap().start());
+				let prefix = &text[line_start..offset];
+				let expected = match encoding {
+					PositionEncoding::Utf8 => prefix.len(),
+					PositionEncoding::Utf16 => prefix.encode_utf16().count(),
+					PositionEncoding::Utf32 => prefix.chars().count(),
+				};
+				assert_eq!(expected as u32, wide.col);
+			}
+		}
+	}
+
+	#[test]
+	fn utf16_columns() {
+		let index = LineIndex::new("é𝕏a\naé");
+		assert_eq!(
+			LineColUtf16 { line: 0, col: 3 },
+			index.to_utf16(LineCol { line: 0, col: 6 })
+		);
+		assert_eq!(
+			LineCol { line: 0, col: 6 },
+			index.to_utf8(LineColUtf16 { line: 0, col: 3 })
+		);
+		assert_eq!(
+			LineColUtf16 { line: 1, col: 2 },
+			index.to_utf16(LineCol { line: 1, col: 3 })
+		);
+	}
+}
@@ -1,6 +1,7 @@
 //! functions to convert diagnostics to LSP (language server protocol) Diagnostics.
 
 use crate::file::Files;
+use crate::line_index::{LineCol, LineColUtf16, LineIndex};
 use crate::*;
 
 use lsp_types::{
@@ -86,6 +87,7 @@ pub fn severity_to_lsp_severity(severity: Severity) -> DiagnosticSeverity {
 // This code below is taken from codespan-lsp but adapted to use rslint_errors Files
 
 use lsp_types::{Position as LspPosition, Range as LspRange};
+use rome_rowan::{TextRange, TextSize};
 use std::{error, fmt, ops::Range};
 
 #[derive(Debug, PartialEq)]
@@ -323,3 +325,30 @@ where
 	Ok(position_to_byte_index(files, file_id, &range.start)?
 		..position_to_byte_index(files, file_id, &range.end)?)
 }
+
+/// Converts an offset to an LSP position using the line index of the document
+pub fn offset_to_position(index: &LineIndex, offset: TextSize) -> LspPosition {
+	let position = index.to_utf16(index.line_col(offset));
+	LspPosition {
+		line: position.line as u64,
+		character: position.col as u64,
+	}
+}
+
+/// Converts a text range to an LSP range using the line index of the document
+pub fn text_range_to_range(index: &LineIndex, range: TextRange) -> LspRange {
+	LspRange {
+		start: offset_to_position(index, range.start()),
+		end: offset_to_position(index, range.end()),
+	}
+}
+
+/// Converts an LSP position to an offset using the line index of the document,
+/// returns `None` if the position is outside of the document.
+pub fn position_to_offset(index: &LineIndex, position: &LspPosition) -> Option<TextSize> {
+	let line_col: LineCol = index.to_utf8(LineColUtf16 {
+		line: position.line as u32,
+		col: position.character as u32,
+	});
+	index.offset(line_col)
+}
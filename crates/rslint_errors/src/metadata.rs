@@ -0,0 +1,62 @@
+//! Structured information about every diagnostic Rome can emit, for tools that present the
+//! diagnostics or their settings without running Rome, e.g. the website or editor extensions.
+//!
+//! Every new diagnostic code or rule must be registered in `DIAGNOSTICS`.
+
+use crate::Severity;
+
+/// What produces a diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum DiagnosticCategory {
+	/// The lexer or the parser, for source code that isn't valid
+	Syntax,
+	/// A lint rule
+	Lint,
+}
+
+/// The description of a diagnostic code
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DiagnosticMetadata {
+	/// The code of the diagnostics, see [Diagnostic::code](crate::Diagnostic::code)
+	pub code: &'static str,
+	pub category: DiagnosticCategory,
+	/// The name of the rule that emits the diagnostics, if any
+	pub rule: Option<&'static str>,
+	/// The severity of the diagnostics if not configured otherwise
+	pub default_severity: Severity,
+	/// Whether the diagnostics come with a suggestion that can be applied automatically
+	pub has_fix: bool,
+	/// The page documenting the diagnostics
+	pub docs_url: Option<&'static str>,
+	pub description: &'static str,
+}
+
+/// All the diagnostic codes, ordered by category and code
+static DIAGNOSTICS: &[DiagnosticMetadata] = &[DiagnosticMetadata {
+	code: "SyntaxError",
+	category: DiagnosticCategory::Syntax,
+	rule: None,
+	default_severity: Severity::Error,
+	has_fix: false,
+	docs_url: None,
+	description: "The source code isn't valid JavaScript or TypeScript.",
+}];
+
+/// Returns the metadata of every diagnostic code Rome can emit
+///
+/// ```
+/// use rslint_errors::metadata::{metadata, DiagnosticCategory};
+///
+/// let syntax_error = metadata().iter().find(|it| it.code == "SyntaxError").unwrap();
+/// assert_eq!(DiagnosticCategory::Syntax, syntax_error.category);
+/// ```
+pub fn metadata() -> &'static [DiagnosticMetadata] {
+	DIAGNOSTICS
+}
+
+/// Returns the metadata of the diagnostic code `code`
+pub fn metadata_for(code: &str) -> Option<&'static DiagnosticMetadata> {
+	DIAGNOSTICS.iter().find(|metadata| metadata.code == code)
+}
@@ -0,0 +1,572 @@
+//! Consumption of [source maps (v3)](https://sourcemaps.info/spec.html) to report the diagnostics
+//! of generated files against the positions in their original sources.
+
+use crate::{
+	file::{FileId, FileSpan, Files, SimpleFiles},
+	line_index::{LineColUtf16, LineIndex},
+	Diagnostic, SubDiagnostic,
+};
+use rome_rowan::TextSize;
+use std::{convert::TryFrom, fmt};
+
+/// A position in one of the original sources of a source map.
+/// Lines are zero based, columns are zero based and counted in UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OriginalLocation {
+	/// The index of the source in [`SourceMap::sources`]
+	pub source: u32,
+	pub line: u32,
+	pub column: u32,
+	/// The index of the name in [`SourceMap::names`]
+	pub name: Option<u32>,
+}
+
+/// A segment of the `mappings` of a source map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mapping {
+	pub generated_line: u32,
+	pub generated_column: u32,
+	/// `None` if the generated code doesn't come from any of the sources
+	pub original: Option<OriginalLocation>,
+}
+
+/// A parsed source map
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+	pub file: Option<String>,
+	/// The sources with the `sourceRoot` prepended
+	pub sources: Vec<String>,
+	pub sources_content: Vec<Option<String>>,
+	pub names: Vec<String>,
+	/// Sorted by generated line and column
+	mappings: Vec<Mapping>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceMapError {
+	UnsupportedVersion(Option<u64>),
+	/// Index maps (`sections`) aren't supported
+	IndexMap,
+	MissingField(&'static str),
+	InvalidMappings(String),
+}
+
+impl fmt::Display for SourceMapError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			SourceMapError::UnsupportedVersion(Some(version)) => {
+				write!(f, "unsupported source map version {}", version)
+			}
+			SourceMapError::UnsupportedVersion(None) => write!(f, "missing source map version"),
+			SourceMapError::IndexMap => write!(f, "index source maps are not supported"),
+			SourceMapError::MissingField(field) => {
+				write!(f, "the source map is missing the `{}` field", field)
+			}
+			SourceMapError::InvalidMappings(err) => {
+				write!(f, "invalid source map mappings: {}", err)
+			}
+		}
+	}
+}
+
+impl std::error::Error for SourceMapError {}
+
+/// The fields of a source map as they're stored in its JSON representation.
+///
+/// With the `serde` feature, it can be deserialized from the JSON of the source map and then
+/// be converted to a [SourceMap] with `SourceMap::try_from`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Deserialize),
+	serde(default, rename_all = "camelCase")
+)]
+pub struct RawSourceMap {
+	pub version: Option<u64>,
+	pub file: Option<String>,
+	pub source_root: Option<String>,
+	pub sources: Option<Vec<Option<String>>>,
+	pub sources_content: Option<Vec<Option<String>>>,
+	pub names: Vec<String>,
+	pub mappings: Option<String>,
+	/// Whether the map has `sections`, which makes it an index map
+	#[cfg_attr(feature = "serde", serde(deserialize_with = "is_present"))]
+	pub sections: bool,
+}
+
+#[cfg(feature = "serde")]
+fn is_present<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+	<serde::de::IgnoredAny as serde::Deserialize>::deserialize(deserializer).map(|_| true)
+}
+
+impl TryFrom<RawSourceMap> for SourceMap {
+	type Error = SourceMapError;
+
+	/// Validates the fields of a source map and decodes its mappings
+	///
+	/// ```
+	/// use rslint_errors::source_map::{RawSourceMap, SourceMap};
+	/// use std::convert::TryFrom;
+	///
+	/// let map = SourceMap::try_from(RawSourceMap {
+	///     version: Some(3),
+	///     sources: Some(vec![Some("a.ts".to_string())]),
+	///     mappings: Some("AAAA;AACA,IAAI".to_string()),
+	///     ..RawSourceMap::default()
+	/// }).unwrap();
+	///
+	/// let location = map.lookup(1, 6).unwrap();
+	/// assert_eq!((0, 1, 4), (location.source, location.line, location.column));
+	/// ```
+	fn try_from(raw: RawSourceMap) -> Result<SourceMap, SourceMapError> {
+		if raw.sections {
+			return Err(SourceMapError::IndexMap);
+		}
+
+		match raw.version {
+			Some(3) => {}
+			version => return Err(SourceMapError::UnsupportedVersion(version)),
+		}
+
+		let source_root = raw.source_root.filter(|root| !root.is_empty());
+		let sources: Vec<String> = raw
+			.sources
+			.ok_or(SourceMapError::MissingField("sources"))?
+			.into_iter()
+			.map(|source| {
+				let source = source.unwrap_or_default();
+				match &source_root {
+					Some(root) if root.ends_with('/') => format!("{}{}", root, source),
+					Some(root) => format!("{}/{}", root, source),
+					None => source,
+				}
+			})
+			.collect();
+
+		let mut sources_content = raw.sources_content.unwrap_or_default();
+		sources_content.resize(sources.len(), None);
+
+		let mappings = raw
+			.mappings
+			.ok_or(SourceMapError::MissingField("mappings"))?;
+
+		Ok(SourceMap {
+			file: raw.file,
+			mappings: decode_mappings(&mappings, sources.len())?,
+			sources,
+			sources_content,
+			names: raw.names,
+		})
+	}
+}
+
+impl SourceMap {
+	pub fn mappings(&self) -> &[Mapping] {
+		&self.mappings
+	}
+
+	/// Returns the original location of the closest mapping at or before the zero based `line`
+	/// and UTF-16 `column` of the generated file.
+	pub fn lookup(&self, line: u32, column: u32) -> Option<OriginalLocation> {
+		let idx = self.mappings.partition_point(|mapping| {
+			(mapping.generated_line, mapping.generated_column) <= (line, column)
+		});
+		let mapping = self.mappings[..idx].last()?;
+
+		if mapping.generated_line == line {
+			mapping.original
+		} else {
+			None
+		}
+	}
+}
+
+fn decode_mappings(text: &str, sources: usize) -> Result<Vec<Mapping>, SourceMapError> {
+	let mut mappings = Vec::new();
+	// All fields but the generated column are relative to the previous segment, even across lines
+	let (mut source, mut original_line, mut original_column, mut name) = (0i64, 0i64, 0i64, 0i64);
+
+	for (generated_line, line) in text.split(';').enumerate() {
+		let mut generated_column = 0i64;
+
+		for segment in line.split(',').filter(|segment| !segment.is_empty()) {
+			let fields = decode_vlq(segment)?;
+			generated_column += fields[0];
+
+			let original = match fields.len() {
+				1 => None,
+				4 | 5 => {
+					source += fields[1];
+					original_line += fields[2];
+					original_column += fields[3];
+					let name = fields.get(4).map(|delta| {
+						name += delta;
+						name
+					});
+
+					if source < 0 || source as usize >= sources {
+						return Err(SourceMapError::InvalidMappings(format!(
+							"source index {} is out of bounds",
+							source
+						)));
+					}
+
+					Some(OriginalLocation {
+						source: source as u32,
+						line: to_u32(original_line)?,
+						column: to_u32(original_column)?,
+						name: name.map(to_u32).transpose()?,
+					})
+				}
+				len => {
+					return Err(SourceMapError::InvalidMappings(format!(
+						"segment `{}` has {} fields",
+						segment, len
+					)))
+				}
+			};
+
+			mappings.push(Mapping {
+				generated_line: generated_line as u32,
+				generated_column: to_u32(generated_column)?,
+				original,
+			});
+		}
+	}
+
+	mappings.sort_by_key(|mapping| (mapping.generated_line, mapping.generated_column));
+	Ok(mappings)
+}
+
+fn to_u32(value: i64) -> Result<u32, SourceMapError> {
+	u32::try_from(value)
+		.map_err(|_| SourceMapError::InvalidMappings(format!("invalid position {}", value)))
+}
+
+/// Decodes the base 64 VLQ encoded fields of a segment
+fn decode_vlq(segment: &str) -> Result<Vec<i64>, SourceMapError> {
+	let mut fields = Vec::with_capacity(5);
+	let mut value = 0i64;
+	let mut shift = 0;
+
+	for c in segment.bytes() {
+		let digit = match c {
+			b'A'..=b'Z' => c - b'A',
+			b'a'..=b'z' => c - b'a' + 26,
+			b'0'..=b'9' => c - b'0' + 52,
+			b'+' => 62,
+			b'/' => 63,
+			_ => {
+				return Err(SourceMapError::InvalidMappings(format!(
+					"invalid base64 character `{}`",
+					c as char
+				)))
+			}
+		} as i64;
+
+		if shift > 32 {
+			return Err(SourceMapError::InvalidMappings(format!(
+				"value of segment `{}` overflows",
+				segment
+			)));
+		}
+
+		value += (digit & 0b11111) << shift;
+		if digit & 0b100000 == 0 {
+			let magnitude = value >> 1;
+			fields.push(if value & 1 == 1 {
+				-magnitude
+			} else {
+				magnitude
+			});
+			value = 0;
+			shift = 0;
+		} else {
+			shift += 5;
+		}
+	}
+
+	if shift != 0 {
+		return Err(SourceMapError::InvalidMappings(format!(
+			"segment `{}` ends in the middle of a value",
+			segment
+		)));
+	}
+
+	Ok(fields)
+}
+
+/// Rewrites the spans of the diagnostics of a generated file to point into its original sources.
+pub struct SourceMapRemapper {
+	map: SourceMap,
+	generated: FileId,
+	generated_lines: Option<LineIndex>,
+	/// The file ids of the sources of the map, `None` if the content of the source is unknown
+	sources: Vec<Option<(FileId, LineIndex)>>,
+}
+
+impl SourceMapRemapper {
+	/// Creates a remapper for the diagnostics of the file `generated`, adding the original sources to `files`.
+	///
+	/// The content of the sources is taken from `sourcesContent`, `load` is called with the path
+	/// of the sources without content.
+	pub fn new(
+		map: SourceMap,
+		generated: FileId,
+		files: &mut SimpleFiles,
+		mut load: impl FnMut(&str) -> Option<String>,
+	) -> SourceMapRemapper {
+		let generated_lines = files.get(generated).map(|file| file.lines().clone());
+		let sources = map
+			.sources
+			.iter()
+			.zip(&map.sources_content)
+			.map(|(name, content)| {
+				let content = content.clone().or_else(|| load(name))?;
+				let lines = LineIndex::new(&content);
+				Some((files.add(name.clone(), content), lines))
+			})
+			.collect();
+
+		SourceMapRemapper {
+			map,
+			generated,
+			generated_lines,
+			sources,
+		}
+	}
+
+	/// Returns the span in the original sources of a span of the generated file,
+	/// or `None` if it can't be mapped.
+	pub fn remap_span(&self, files: &dyn Files, span: &FileSpan) -> Option<FileSpan> {
+		if span.file != self.generated {
+			return None;
+		}
+
+		let start = self.original_position(span.range.start)?;
+
+		// Segments usually only map the start of tokens, use the generated length if
+		// the end doesn't map to a later position of the same source.
+		let end = self
+			.original_position(span.range.end)
+			.filter(|end| end.0 == start.0 && end.1 > start.1)
+			.map(|end| end.1)
+			.unwrap_or_else(|| {
+				let source = files.source(start.0).unwrap_or_default();
+				let line_end = source[start.1..]
+					.find(is_line_terminator)
+					.map_or(source.len(), |len| start.1 + len);
+				(start.1 + span.range.len()).min(line_end)
+			});
+
+		Some(FileSpan {
+			file: start.0,
+			range: start.1..end,
+		})
+	}
+
+	/// Moves the labels of `diagnostic` to the original sources. Labels that can't be mapped are kept as is,
+	/// the suggestions of remapped diagnostics are removed because they are edits of the generated file.
+	pub fn remap(&self, files: &dyn Files, mut diagnostic: Diagnostic) -> Diagnostic {
+		let mut remapped = false;
+		let mut remap_label = |label: &mut SubDiagnostic| {
+			if let Some(span) = self.remap_span(files, &label.span) {
+				label.span = span;
+				remapped = true;
+			}
+		};
+
+		if let Some(primary) = diagnostic.primary.as_mut() {
+			remap_label(primary);
+		}
+		diagnostic.children.iter_mut().for_each(remap_label);
+
+		if remapped {
+			if let Some(primary) = &diagnostic.primary {
+				diagnostic.file_id = primary.span.file;
+			}
+			diagnostic
+				.suggestions
+				.retain(|suggestion| suggestion.span.file != self.generated);
+		}
+
+		diagnostic
+	}
+
+	/// The file and byte offset in the original source of a byte offset in the generated source
+	fn original_position(&self, offset: usize) -> Option<(FileId, usize)> {
+		let generated_lines = self.generated_lines.as_ref()?;
+		let position =
+			generated_lines.to_utf16(generated_lines.line_col(TextSize::from(offset as u32)));
+		let location = self.map.lookup(position.line, position.col)?;
+
+		let (file, lines) = self.sources.get(location.source as usize)?.as_ref()?;
+		let line_col = lines.to_utf8(LineColUtf16 {
+			line: location.line,
+			col: location.column,
+		});
+		// Clamp columns past the end of the line
+		let offset = lines
+			.offset(line_col)
+			.or_else(|| Some(lines.line_range(line_col.line)?.end()))?;
+		Some((*file, usize::from(offset)))
+	}
+}
+
+fn is_line_terminator(c: char) -> bool {
+	matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn raw_map(sources: &[&str], mappings: &str) -> RawSourceMap {
+		RawSourceMap {
+			version: Some(3),
+			sources: Some(
+				sources
+					.iter()
+					.map(|source| Some(source.to_string()))
+					.collect(),
+			),
+			mappings: Some(mappings.to_string()),
+			..RawSourceMap::default()
+		}
+	}
+
+	#[test]
+	fn decodes_vlq() {
+		assert_eq!(vec![0, 0, 0, 0], decode_vlq("AAAA").unwrap());
+		assert_eq!(vec![1, -1, 15, 16, 1000], decode_vlq("CDegBw+B").unwrap());
+		assert!(decode_vlq("g").is_err());
+		assert!(decode_vlq("A!").is_err());
+	}
+
+	#[test]
+	fn relative_fields_carry_across_lines() {
+		let map = SourceMap::try_from(RawSourceMap {
+			names: vec!["foo".to_string()],
+			..raw_map(&["a.js", "b.js"], "AAAAA,EACC;ACDD,A")
+		})
+		.unwrap();
+
+		assert_eq!(
+			map.mappings(),
+			&[
+				Mapping {
+					generated_line: 0,
+					generated_column: 0,
+					original: Some(OriginalLocation {
+						source: 0,
+						line: 0,
+						column: 0,
+						name: Some(0)
+					}),
+				},
+				Mapping {
+					generated_line: 0,
+					generated_column: 2,
+					original: Some(OriginalLocation {
+						source: 0,
+						line: 1,
+						column: 1,
+						name: None
+					}),
+				},
+				Mapping {
+					generated_line: 1,
+					generated_column: 0,
+					original: Some(OriginalLocation {
+						source: 1,
+						line: 0,
+						column: 0,
+						name: None
+					}),
+				},
+				Mapping {
+					generated_line: 1,
+					generated_column: 0,
+					original: None,
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn rejects_invalid_maps() {
+		assert_eq!(
+			Err(SourceMapError::UnsupportedVersion(Some(2))),
+			SourceMap::try_from(RawSourceMap {
+				version: Some(2),
+				..raw_map(&[], "")
+			})
+		);
+		assert_eq!(
+			Err(SourceMapError::IndexMap),
+			SourceMap::try_from(RawSourceMap {
+				version: Some(3),
+				sections: true,
+				..RawSourceMap::default()
+			})
+		);
+		assert_eq!(
+			Err(SourceMapError::MissingField("mappings")),
+			SourceMap::try_from(RawSourceMap {
+				mappings: None,
+				..raw_map(&[], "")
+			})
+		);
+		assert!(matches!(
+			SourceMap::try_from(raw_map(&[], "AAAA")),
+			Err(SourceMapError::InvalidMappings(_))
+		));
+	}
+
+	#[test]
+	fn remaps_diagnostics_to_original_source() {
+		// a.ts:
+		// let a: number = 1;
+		// let bé: string = a;
+		//
+		// generated:
+		// "use strict";
+		// let a = 1;
+		// let bé = a;
+		let map = SourceMap::try_from(RawSourceMap {
+			source_root: Some("src".to_string()),
+			sources_content: Some(vec![Some(
+				"let a: number = 1;\nlet bé: string = a;\n".to_string(),
+			)]),
+			..raw_map(&["a.ts"], ";AAAA,IAAI,IAAY;AAChB,IAAI,KAAa")
+		})
+		.unwrap();
+		assert_eq!(vec!["src/a.ts"], map.sources);
+
+		let mut files = SimpleFiles::new();
+		let generated = files.add(
+			"a.js".to_string(),
+			"\"use strict\";\r\nlet a = 1;\r\nlet b\u{e9} = a;\r\n".to_string(),
+		);
+		let remapper = SourceMapRemapper::new(map, generated, &mut files, |_| None);
+
+		// `a` of `= a`
+		let diagnostic = Diagnostic::error(generated, "", "undeclared").primary(37usize..38, "");
+		let diagnostic = remapper.remap(&files, diagnostic);
+		let primary = diagnostic.primary.unwrap();
+		assert_eq!(1, diagnostic.file_id);
+		assert_eq!(1, primary.span.file);
+		assert_eq!("a", &files.source(1).unwrap()[primary.span.range.clone()]);
+		assert_eq!(37..38, primary.span.range);
+
+		// `"use strict"` isn't mapped
+		let diagnostic = Diagnostic::error(generated, "", "").primary(0usize..12, "");
+		assert_eq!(
+			Some(generated),
+			remapper
+				.remap(&files, diagnostic)
+				.primary
+				.map(|primary| primary.span.file)
+		);
+	}
+}
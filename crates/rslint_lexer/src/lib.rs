@@ -1,6 +1,6 @@
 //! An extremely fast, lookup table based, ECMAScript lexer which yields SyntaxKind tokens used by the rslint_parse parser.
 //! For the purposes of error recovery, tokens may have an error attached to them, which is reflected in the Iterator Item.
-//! The lexer will also yield `COMMENT` and `WHITESPACE` tokens.
+//! The lexer will also yield `COMMENT`, `WHITESPACE` and `NEWLINE` tokens.
 //!
 //! The lexer operates on raw bytes to take full advantage of lookup table optimizations, these bytes **must** be valid utf8,
 //! therefore making a lexer from a `&[u8]` is unsafe since you must make sure the bytes are valid utf8.
@@ -82,6 +82,24 @@ fn is_id_continue(c: char) -> bool {
 	c == '$' || c == '\u{200d}' || c == '\u{200c}' || ID_Continue(c)
 }
 
+/// The state of a [`Lexer`] between two tokens, the context it needs to lex the tokens that follow
+/// such as whether a `/` starts a regex or whether it's in a template literal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LexerCheckpoint {
+	state: LexerState,
+	starts_file: bool,
+}
+
+impl Default for LexerCheckpoint {
+	/// The state of a lexer at the start of a file
+	fn default() -> Self {
+		Self {
+			state: LexerState::new(),
+			starts_file: true,
+		}
+	}
+}
+
 /// An extremely fast, lookup table based, lossless ECMAScript lexer
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Lexer<'src> {
@@ -90,6 +108,8 @@ pub struct Lexer<'src> {
 	state: LexerState,
 	pub file_id: usize,
 	returned_eof: bool,
+	/// Whether the bytes are at the start of the file, a shebang can only be at the start of the file
+	starts_file: bool,
 }
 
 impl<'src> Lexer<'src> {
@@ -104,6 +124,7 @@ impl<'src> Lexer<'src> {
 			file_id,
 			state: LexerState::new(),
 			returned_eof: false,
+			starts_file: true,
 		}
 	}
 
@@ -115,6 +136,27 @@ impl<'src> Lexer<'src> {
 			file_id,
 			state: LexerState::new(),
 			returned_eof: false,
+			starts_file: true,
+		}
+	}
+
+	/// Make a new lexer which continues lexing where the lexer of `checkpoint` stopped, `string` is the text
+	/// that follows the last token returned by that lexer.
+	///
+	/// The offsets of the diagnostics of the lexer are relative to the start of `string`.
+	pub fn from_checkpoint(string: &'src str, file_id: usize, checkpoint: LexerCheckpoint) -> Self {
+		Self {
+			state: checkpoint.state,
+			starts_file: checkpoint.starts_file,
+			..Self::from_str(string, file_id)
+		}
+	}
+
+	/// The state of the lexer after the last token it returned, see [`Lexer::from_checkpoint`].
+	pub fn checkpoint(&self) -> LexerCheckpoint {
+		LexerCheckpoint {
+			state: self.state.clone(),
+			starts_file: self.starts_file && self.cur == 0,
 		}
 	}
 
@@ -155,10 +197,15 @@ impl<'src> Lexer<'src> {
 		}
 	}
 
-	// Consume all whitespace starting from the current byte
-	fn consume_whitespace(&mut self) {
+	// Lex the line breaks starting from the current byte as a `NEWLINE` token, or the other whitespace as a `WHITESPACE` token
+	fn resolve_whitespace(&mut self) -> LexerReturn {
+		let start = self.cur;
 		self.consume_newlines();
+		if self.cur > start {
+			return tok!(NEWLINE, self.cur - start);
+		}
 		self.consume_whitespace_until_newline();
+		tok!(WHITESPACE, self.cur - start)
 	}
 
 	// Get the unicode char which starts at the current byte and advance the lexer's cursor
@@ -225,9 +272,13 @@ impl<'src> Lexer<'src> {
 		if self.bytes.get(self.cur) != Some(&b'}') {
 			// We should not yield diagnostics on a unicode char boundary. That wont make codespan panic
 			// but it may cause a panic for other crates which just consume the diagnostics
-			let invalid = self.get_unicode_char();
+			let invalid_len = if self.cur < self.bytes.len() {
+				self.get_unicode_char().len_utf8()
+			} else {
+				0
+			};
 			let err = Diagnostic::error(self.file_id, "", "expected hex digits for a unicode code point escape, but encountered an invalid character")
-                .primary(self.cur .. invalid.len_utf8(), "");
+                .primary(self.cur..self.cur + invalid_len, "");
 
 			self.cur -= 1;
 			return Err(err);
@@ -600,12 +651,13 @@ impl<'src> Lexer<'src> {
 
 	#[inline]
 	fn special_number_start<F: Fn(char) -> bool>(&mut self, func: F) -> bool {
-		if self
-			.bytes
-			.get(self.cur + 2)
-			.map(|b| func(*b as char))
-			.unwrap_or(false)
-		{
+		// A separator directly after the prefix is an error, but it's still part of the number
+		let first_digit = match self.bytes.get(self.cur + 2) {
+			Some(b'_') => self.bytes.get(self.cur + 3),
+			byte => byte,
+		};
+
+		if first_digit.map(|b| func(*b as char)).unwrap_or(false) {
 			self.cur += 1;
 			true
 		} else {
@@ -626,7 +678,7 @@ impl<'src> Lexer<'src> {
 		match self.bytes.get(self.cur + 1) {
 			Some(b'x') | Some(b'X') => {
 				if self.special_number_start(|c| c.is_ascii_hexdigit()) {
-					let diag = self.read_hexnumber();
+					let diag = self.separator_after_prefix().or(self.read_hexnumber());
 					self.maybe_bigint();
 					diag
 				} else {
@@ -636,7 +688,7 @@ impl<'src> Lexer<'src> {
 			}
 			Some(b'b') | Some(b'B') => {
 				if self.special_number_start(|c| c == '0' || c == '1') {
-					let diag = self.read_bindigits();
+					let diag = self.separator_after_prefix().or(self.read_bindigits());
 					self.maybe_bigint();
 					diag
 				} else {
@@ -646,7 +698,7 @@ impl<'src> Lexer<'src> {
 			}
 			Some(b'o') | Some(b'O') => {
 				if self.special_number_start(|c| ('0'..='7').contains(&c)) {
-					let diag = self.read_octaldigits();
+					let diag = self.separator_after_prefix().or(self.read_octaldigits());
 					self.maybe_bigint();
 					diag
 				} else {
@@ -673,13 +725,17 @@ impl<'src> Lexer<'src> {
 							None
 						}
 					}
-					Some(b'0'..=b'9') => self.read_exponent(),
+					Some(b'0'..=b'9') => {
+						self.next();
+						self.read_exponent()
+					}
 					_ => {
 						self.next();
 						None
 					}
 				}
 			}
+			Some(b'_') => self.separator_after_prefix().or(self.read_number()),
 			// FIXME: many engines actually allow things like `09`, but by the spec, this is not allowed
 			// maybe we should not allow it if we want to go fully by the spec
 			_ => self.read_number(),
@@ -702,40 +758,53 @@ impl<'src> Lexer<'src> {
 	fn handle_numeric_separator(&mut self, radix: u8) -> Option<Diagnostic> {
 		debug_assert_eq!(self.bytes[self.cur], b'_');
 
-		let err_diag = Diagnostic::error(
-			self.file_id,
-			"",
-			"numeric separators are only allowed between two digits",
-		)
-		.primary(self.cur..self.cur + 1, "");
-
+		let prev = self.bytes[self.cur - 1];
 		let peeked = self.bytes.get(self.cur + 1).copied();
 
-		if peeked.is_none() || !char::from(peeked.unwrap()).is_digit(radix as u32) {
-			return Some(err_diag);
-		}
-
-		let forbidden = |c: Option<u8>| {
-			if c.is_none() {
-				return true;
+		let message = match (prev, peeked) {
+			// The second underscore reports the error, the first one is skipped over
+			(_, Some(b'_')) => return None,
+			(b'_', _) => "only one underscore is allowed as numeric separator",
+			(b'.', _) | (_, Some(b'.')) => {
+				"numeric separators are not allowed next to a decimal point"
 			}
-			let c = c.unwrap();
-
-			if radix == 16 {
-				matches!(c, b'.' | b'X' | b'_' | b'x')
-			} else {
-				matches!(c, b'.' | b'B' | b'E' | b'O' | b'_' | b'b' | b'e' | b'o')
+			(b'e' | b'E', _) | (_, Some(b'e' | b'E')) if radix == 10 => {
+				"numeric separators are not allowed next to an exponent"
 			}
+			(_, Some(c)) if char::from(c).is_digit(radix as u32) => {
+				self.next_bounded();
+				return None;
+			}
+			_ => "numeric separators are not allowed at the end of numeric literals",
 		};
 
-		let prev = self.bytes.get(self.cur - 1).copied();
+		Some(
+			Diagnostic::error(self.file_id, "", message)
+				.primary(self.cur..self.cur + 1, "remove this separator"),
+		)
+	}
 
-		if forbidden(prev) || forbidden(peeked) {
-			return Some(err_diag);
+	/// Reports a separator directly after a `0`, `0x`, `0b`, or `0o` prefix such as `0x_FF`.
+	/// `self.cur` must point at the last character of the prefix.
+	#[inline]
+	fn separator_after_prefix(&self) -> Option<Diagnostic> {
+		if self.bytes.get(self.cur + 1) != Some(&b'_') {
+			return None;
 		}
 
-		self.next_bounded();
-		None
+		let message = if self.bytes[self.cur] == b'0' {
+			"numeric separators are not allowed after a leading `0`".to_string()
+		} else {
+			format!(
+				"numeric separators are not allowed directly after a `0{}` prefix",
+				char::from(self.bytes[self.cur])
+			)
+		};
+
+		Some(
+			Diagnostic::error(self.file_id, "", message)
+				.primary(self.cur + 1..self.cur + 2, "remove this separator"),
+		)
 	}
 
 	// Read a number which does not start with 0, since that can be more things and is handled
@@ -748,7 +817,7 @@ impl<'src> Lexer<'src> {
 				Some(b'_') => diag = diag.or(self.handle_numeric_separator(10)),
 				Some(b'0'..=b'9') => {},
 				Some(b'.') => {
-					return self.read_float();
+					return diag.or(self.read_float());
 				},
 				// TODO: merge this, and read_float's implementation into one so we dont duplicate exponent code
 				Some(b'e') | Some(b'E') => {
@@ -757,12 +826,12 @@ impl<'src> Lexer<'src> {
 						Some(b'-') | Some(b'+') => {
 							if let Some(b'0'..=b'9') = self.bytes.get(self.cur + 2) {
 								self.next();
-								return self.read_exponent();
+								return diag.or(self.read_exponent());
 							} else {
 								return diag;
 							}
 						},
-						Some(b'0'..=b'9') => return self.read_exponent(),
+						Some(b'0'..=b'9') => return diag.or(self.read_exponent()),
 						_ => return diag,
 					}
 				},
@@ -781,7 +850,7 @@ impl<'src> Lexer<'src> {
 
 		unwind_loop! {
 			match self.next_bounded() {
-				Some(b'_') => diag = diag.or(self.handle_numeric_separator(16)),
+				Some(b'_') => diag = diag.or(self.handle_numeric_separator(10)),
 				// LLVM has a hard time optimizing inclusive patterns, perhaps we should check if it makes llvm sad,
 				// and optimize this into a lookup table
 				Some(b'0'..=b'9') => {},
@@ -800,6 +869,11 @@ impl<'src> Lexer<'src> {
 						_ => return diag,
 					}
 				},
+				// Not a valid BigInt, but the parser reports a more helpful error for it
+				Some(b'n') => {
+					self.next();
+					return diag;
+				}
 				_ => return diag,
 			}
 		}
@@ -816,6 +890,10 @@ impl<'src> Lexer<'src> {
 			match self.next() {
 				Some(b'_') => diag = diag.or(self.handle_numeric_separator(10)),
 				Some(b'0'..=b'9') => {},
+				Some(b'n') => {
+					self.next();
+					return diag;
+				}
 				_ => return diag,
 			}
 		}
@@ -870,7 +948,7 @@ impl<'src> Lexer<'src> {
 	fn read_shebang(&mut self) -> LexerReturn {
 		let start = self.cur;
 		self.next();
-		if start != 0 {
+		if start != 0 || !self.starts_file {
 			return (Token::new(T![#], 1), None);
 		}
 
@@ -890,7 +968,7 @@ impl<'src> Lexer<'src> {
 				"",
 				"expected `!` following a `#`, but found none",
 			)
-			.primary(0usize..1usize, "");
+			.primary(start..start + 1, "");
 
 			(Token::new(SyntaxKind::ERROR_TOKEN, 1), Some(err))
 		}
@@ -1249,10 +1327,7 @@ impl<'src> Lexer<'src> {
 		let dispatched = Self::lookup(byte);
 
 		match dispatched {
-			WHS => {
-				self.consume_whitespace();
-				tok!(WHITESPACE, self.cur - start)
-			}
+			WHS => self.resolve_whitespace(),
 			EXL => self.resolve_bang(),
 			HAS => self.read_shebang(),
 			PRC => self.bin_or_assign(T![%], T![%=]),
@@ -1360,8 +1435,7 @@ impl<'src> Lexer<'src> {
 				if is_linebreak(chr)
 					|| (UNICODE_WHITESPACE_STARTS.contains(&byte) && UNICODE_SPACES.contains(&chr))
 				{
-					self.consume_whitespace();
-					tok!(WHITESPACE, self.cur - start)
+					self.resolve_whitespace()
 				} else {
 					self.cur += chr.len_utf8() - 1;
 					if is_id_start(chr) {
@@ -1399,7 +1473,6 @@ impl<'src> Lexer<'src> {
 
 	fn lex_template(&mut self) -> LexerReturn {
 		let start = self.cur;
-		let mut diagnostic = None;
 
 		while let Some(b) = self.bytes.get(self.cur) {
 			match *b as char {
@@ -1410,23 +1483,23 @@ impl<'src> Lexer<'src> {
 				'`' => {
 					return (
 						Token::new(SyntaxKind::TEMPLATE_CHUNK, self.cur - start),
-						diagnostic,
+						None,
 					);
 				}
 				'\\' => {
-					if let Some(err) = self.validate_escape_sequence() {
-						diagnostic = Some(err);
-					}
+					// Escapes aren't validated here because tagged templates allow malformed escapes,
+					// the parser reports them for untagged templates
+					self.next_bounded();
 					self.next_bounded();
 				}
 				'$' if self.bytes.get(self.cur + 1) == Some(&b'{') && self.cur == start => {
 					self.advance(2);
-					return (Token::new(SyntaxKind::DOLLARCURLY, 2), diagnostic);
+					return (Token::new(SyntaxKind::DOLLARCURLY, 2), None);
 				}
 				'$' if self.bytes.get(self.cur + 1) == Some(&b'{') => {
 					return (
 						Token::new(SyntaxKind::TEMPLATE_CHUNK, self.cur - start),
-						diagnostic,
+						None,
 					)
 				}
 				_ => {
@@ -1471,6 +1544,7 @@ impl Iterator for Lexer<'_> {
 		if ![
 			SyntaxKind::COMMENT,
 			SyntaxKind::WHITESPACE,
+			SyntaxKind::NEWLINE,
 			SyntaxKind::TEMPLATE_CHUNK,
 		]
 		.contains(&token.0.kind)
@@ -162,7 +162,8 @@ fn all_whitespace() {
 	assert_lex! {
 		"
 		",
-		WHITESPACE:3
+		NEWLINE:1,
+		WHITESPACE:2
 	}
 }
 
@@ -723,6 +724,88 @@ fn number_basic_err() {
 	}
 }
 
+#[test]
+fn numeric_separators() {
+	assert_lex! {
+		"1_000_000 0b1010_0001 0xFF_FF 0o7_7 1_0.0_1e1_0 1_0n",
+		JS_NUMBER_LITERAL:9,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:11,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:7,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:5,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:11,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:4
+	}
+
+	for valid in ["1_000", "0b1010_0001", "0xFF_FF", ".0_1", "1e1_0", "0xE_F"].iter() {
+		let diagnostic = Lexer::from_str(valid, 0).find_map(|(_, diagnostic)| diagnostic);
+		assert!(diagnostic.is_none(), "unexpected error for `{}`", valid);
+	}
+}
+
+#[test]
+fn numeric_separators_err() {
+	assert_lex! {
+		"1__0 0x_FF 1_ 0_1 1_.5",
+		JS_NUMBER_LITERAL:4,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:5,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:2,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:3,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:4
+	}
+
+	let cases = [
+		(
+			"1__0",
+			"only one underscore is allowed as numeric separator",
+		),
+		(
+			"1_",
+			"numeric separators are not allowed at the end of numeric literals",
+		),
+		(
+			"1_.5",
+			"numeric separators are not allowed next to a decimal point",
+		),
+		(
+			"1._5",
+			"numeric separators are not allowed next to a decimal point",
+		),
+		(
+			"1_e5",
+			"numeric separators are not allowed next to an exponent",
+		),
+		(
+			"0_1",
+			"numeric separators are not allowed after a leading `0`",
+		),
+		(
+			"0x_FF",
+			"numeric separators are not allowed directly after a `0x` prefix",
+		),
+		(
+			"0b_1",
+			"numeric separators are not allowed directly after a `0b` prefix",
+		),
+	];
+
+	for (source, title) in cases.iter() {
+		let diagnostic = Lexer::from_str(source, 0).find_map(|(_, diagnostic)| diagnostic);
+		assert_eq!(
+			diagnostic.map(|diagnostic| diagnostic.title),
+			Some(title.to_string())
+		);
+	}
+}
+
 #[test]
 fn number_complex() {
 	assert_lex! {
@@ -741,6 +824,13 @@ fn number_complex() {
 		".0e34",
 		JS_NUMBER_LITERAL:5
 	}
+
+	assert_lex! {
+		"0e5 0E-5",
+		JS_NUMBER_LITERAL:3,
+		WHITESPACE:1,
+		JS_NUMBER_LITERAL:4
+	}
 }
 
 #[test]
@@ -809,13 +899,13 @@ fn shebang() {
 	assert_lex! {
 		"#!/bin/node\n",
 		JS_SHEBANG:11,
-		WHITESPACE:1
+		NEWLINE:1
 	}
 
 	assert_lex! {
 		"#!/usr/bin/env deno\u{2028}",
 		JS_SHEBANG:19,
-		WHITESPACE:3
+		NEWLINE:3
 	}
 
 	assert_lex! {
@@ -839,7 +929,8 @@ fn single_line_comments() {
 		"//abc
 	",
 		COMMENT:5,
-		WHITESPACE:2
+		NEWLINE:1,
+		WHITESPACE:1
 	}
 
 	assert_lex! {
@@ -952,7 +1043,7 @@ fn fuzz_fail_5() {
 	assert_lex! {
 		"//\u{2028}",
 		COMMENT:2,
-		WHITESPACE:3
+		NEWLINE:3
 	}
 }
 
@@ -1053,34 +1144,39 @@ fn object_expr_getter() {
 fn newline_space_must_be_two_tokens() {
 	assert_lex! {
 		"\n ",
-		WHITESPACE:2
+		NEWLINE:1
+		WHITESPACE:1
 	}
 	assert_lex! {
 		" \n",
 		WHITESPACE:1
-		WHITESPACE:1
+		NEWLINE:1
 	}
 	assert_lex! {
 		" \n ",
 		WHITESPACE:1
-		WHITESPACE:2
+		NEWLINE:1
+		WHITESPACE:1
 	}
 
 	assert_lex! {
 		" a\n b \n ",
 		WHITESPACE:1
 		IDENT:1
-		WHITESPACE:2
+		NEWLINE:1
+		WHITESPACE:1
 		IDENT:1
 		WHITESPACE:1
-		WHITESPACE:2
+		NEWLINE:1
+		WHITESPACE:1
 	}
 	assert_lex! {
 		"a //COMMENT \n /*COMMENT*/ b /*COM\nMENT*/",
 		IDENT:1
 		WHITESPACE:1
 		COMMENT:10
-		WHITESPACE:2
+		NEWLINE:1
+		WHITESPACE:1
 		COMMENT:11
 		WHITESPACE:1
 		IDENT:1
@@ -1092,7 +1188,8 @@ fn newline_space_must_be_two_tokens() {
 		IDENT:1
 		WHITESPACE:1
 		COMMENT:10
-		WHITESPACE:2
+		NEWLINE:1
+		WHITESPACE:1
 		COMMENT:11
 		WHITESPACE:1
 		IDENT:1
@@ -1103,35 +1200,40 @@ fn newline_space_must_be_two_tokens() {
 	//Now with CR
 	assert_lex! {
 		"\r\n ",
-		WHITESPACE:3
+		NEWLINE:2
+		WHITESPACE:1
 	}
 
 	assert_lex! {
 		" \r\n",
 		WHITESPACE:1
-		WHITESPACE:2
+		NEWLINE:2
 	}
 	assert_lex! {
 		" \r\n ",
 		WHITESPACE:1
-		WHITESPACE:3
+		NEWLINE:2
+		WHITESPACE:1
 	}
 
 	assert_lex! {
 		" a\r\n b \r\n ",
 		WHITESPACE:1
 		IDENT:1
-		WHITESPACE:3
+		NEWLINE:2
+		WHITESPACE:1
 		IDENT:1
 		WHITESPACE:1
-		WHITESPACE:3
+		NEWLINE:2
+		WHITESPACE:1
 	}
 	assert_lex! {
 		"a //COMMENT \r\n /*COMMENT*/ b /*COM\r\nMENT*/",
 		IDENT:1
 		WHITESPACE:1
 		COMMENT:10
-		WHITESPACE:3
+		NEWLINE:2
+		WHITESPACE:1
 		COMMENT:11
 		WHITESPACE:1
 		IDENT:1
@@ -1143,7 +1245,8 @@ fn newline_space_must_be_two_tokens() {
 		IDENT:1
 		WHITESPACE:1
 		COMMENT:10
-		WHITESPACE:3
+		NEWLINE:2
+		WHITESPACE:1
 		COMMENT:11
 		WHITESPACE:1
 		IDENT:1
@@ -1151,3 +1254,31 @@ fn newline_space_must_be_two_tokens() {
 		COMMENT:13
 	}
 }
+
+#[test]
+fn from_checkpoint() {
+	use rslint_syntax::SyntaxKind::*;
+
+	// The regex and the end of the template are only lexed correctly with the state after `+`
+	let mut lexer = Lexer::from_str("a = `${b + ", 0);
+	assert_eq!(
+		10,
+		lexer
+			.by_ref()
+			.take_while(|(token, _)| token.kind != EOF)
+			.count()
+	);
+
+	let kinds = Lexer::from_checkpoint("/c/}`", 0, lexer.checkpoint())
+		.map(|(token, _)| token.kind)
+		.collect::<Vec<_>>();
+	assert_eq!(vec![JS_REGEX_LITERAL, R_CURLY, BACKTICK, EOF], kinds);
+}
+
+#[test]
+fn unterminated_codepoint_escape() {
+	assert_lex! {
+		"'\\u{a",
+		ERROR_TOKEN:5
+	}
+}
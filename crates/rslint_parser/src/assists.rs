@@ -0,0 +1,1326 @@
+//! Code actions that rewrite a piece of code to an equivalent piece of code.
+//!
+//! An assist returns an [Indel] that replaces the range of the node it applies to, or [None]
+//! if it doesn't apply. Every assist reparses the code it generates and only returns an edit if
+//! it parses without errors.
+//!
+//! [node_assists] returns all the assists applicable to a node, which is what an editor
+//! integration offers to the user as code actions.
+
+use crate::ast::{
+	JsAnyArrowFunctionBody, JsAnyArrowFunctionParameters, JsAnyExpression, JsAnyLiteralExpression,
+	JsAnyStatement, JsArrowFunctionExpression, JsBinaryExpression, JsFunctionBody,
+	JsFunctionDeclaration, JsFunctionExpression, JsRoot, JsVariableDeclaration,
+	JsVariableDeclarator, Pattern, Template,
+};
+use crate::semantic::{Binding, Reference, ScopeKind, Scopes};
+use crate::util::quotes::{convert_string_literal, QuoteStyle};
+use crate::{parse_expr, parse_statement, AstNode, Syntax, SyntaxKind::*, SyntaxNode, T};
+use rslint_text_edit::Indel;
+
+/// An applicable code action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assist {
+	/// The title of the action shown to the user
+	pub label: &'static str,
+	/// The edit to apply to the source text
+	pub edit: Indel,
+}
+
+/// Returns all the assists that can be applied to `node`
+pub fn node_assists(node: &SyntaxNode) -> Vec<Assist> {
+	let mut assists = vec![];
+	let mut push = |label, edit: Option<Indel>| {
+		if let Some(edit) = edit {
+			assists.push(Assist { label, edit });
+		}
+	};
+
+	if let Some(expr) = JsBinaryExpression::cast(node.clone()) {
+		push("Convert to template literal", concat_to_template(&expr));
+	}
+	if let Some(template) = Template::cast(node.clone()) {
+		push(
+			"Convert to string concatenation",
+			template_to_concat(&template),
+		);
+	}
+	if let Some(arrow) = JsArrowFunctionExpression::cast(node.clone()) {
+		push(
+			"Add braces to arrow function body",
+			add_arrow_body_braces(&arrow),
+		);
+		push(
+			"Remove braces from arrow function body",
+			remove_arrow_body_braces(&arrow),
+		);
+		push(
+			"Convert to function expression",
+			arrow_to_function_expression(&arrow),
+		);
+		push(
+			"Convert to function declaration",
+			arrow_to_function_declaration(&arrow),
+		);
+	}
+	if let Some(function) = JsFunctionExpression::cast(node.clone()) {
+		push("Convert to arrow function", function_to_arrow(&function));
+	}
+	if let Some(function) = JsFunctionDeclaration::cast(node.clone()) {
+		push(
+			"Convert to arrow function",
+			function_declaration_to_arrow(&function),
+		);
+	}
+
+	assists
+}
+
+/// Converts a chain of string concatenations to a template literal.
+///
+/// `expr` can be any `+` expression of the chain, the whole chain gets converted.
+/// The assist only applies if the result of the chain is guaranteed to be a string, meaning
+/// that one of the first two operands is a string literal or template.
+///
+/// ```
+/// use rslint_parser::{assists::concat_to_template, parse_expr, ast::JsBinaryExpression, AstNode};
+///
+/// let expr = JsBinaryExpression::cast(parse_expr("'Hello ' + name + \"!\"", 0).syntax()).unwrap();
+/// assert_eq!(concat_to_template(&expr).unwrap().insert, "`Hello ${name}!`");
+/// ```
+pub fn concat_to_template(expr: &JsBinaryExpression) -> Option<Indel> {
+	let root = concat_root(expr);
+	let operands = concat_operands(&root)?;
+
+	if !operands.iter().take(2).any(is_string_like) {
+		return None;
+	}
+
+	let mut text = String::from("`");
+	for operand in &operands {
+		match operand {
+			JsAnyExpression::JsAnyLiteralExpression(
+				JsAnyLiteralExpression::JsStringLiteralExpression(string),
+			) => {
+				let raw = string.value_token().ok()?;
+				let converted = convert_string_literal(raw.text_trimmed(), QuoteStyle::Backtick);
+				text.push_str(&converted[1..converted.len() - 1]);
+			}
+			JsAnyExpression::Template(template) if !is_tagged(template) => {
+				text.push_str(&template_content(template)?);
+			}
+			expr => {
+				text.push_str("${");
+				text.push_str(&strip_parentheses(expr).syntax().text_trimmed().to_string());
+				text.push('}');
+			}
+		}
+	}
+	text.push('`');
+
+	verified_replace(root.syntax(), text)
+}
+
+/// Converts an untagged template literal to a chain of string concatenations.
+///
+/// ```
+/// use rslint_parser::{assists::template_to_concat, parse_expr, ast::Template, AstNode};
+///
+/// let template = Template::cast(parse_expr("`Hello ${name}!`", 0).syntax()).unwrap();
+/// assert_eq!(template_to_concat(&template).unwrap().insert, "\"Hello \" + name + \"!\"");
+/// ```
+pub fn template_to_concat(template: &Template) -> Option<Indel> {
+	if is_tagged(template) {
+		return None;
+	}
+
+	let mut operands = vec![];
+	let mut strings = 0;
+	for element in template_elements(template)? {
+		match element {
+			rome_rowan::NodeOrToken::Token(chunk) => {
+				if operands.len() < 2 {
+					strings += 1;
+				}
+				operands.push(template_chunk_to_string(chunk.text_trimmed()));
+			}
+			rome_rowan::NodeOrToken::Node(element) => {
+				operands.push(template_element_to_operand(&element)?);
+			}
+		}
+	}
+
+	// Make sure that the first `+` is a string concatenation: `${a}${b}` must not become `a + b`
+	if strings == 0 {
+		operands.insert(0, String::from("\"\""));
+	}
+
+	verified_replace(template.syntax(), operands.join(" + "))
+}
+
+/// Returns the outermost `+` expression of the chain `expr` is part of
+fn concat_root(expr: &JsBinaryExpression) -> JsBinaryExpression {
+	let mut root = expr.clone();
+	while let Some(parent) = root.syntax().parent().and_then(JsBinaryExpression::cast) {
+		// `a + (b + c)` isn't a chain, the parentheses are a separate expression
+		let is_left = matches!(parent.left(), Ok(left) if left.syntax() == root.syntax());
+		if !is_plus(&parent) || !is_left {
+			break;
+		}
+		root = parent;
+	}
+	root
+}
+
+/// Flattens a left associative chain of `+` expressions: `a + b + c` returns `[a, b, c]`
+fn concat_operands(root: &JsBinaryExpression) -> Option<Vec<JsAnyExpression>> {
+	if !is_plus(root) {
+		return None;
+	}
+
+	let mut operands = vec![root.right()?];
+	let mut left = root.left().ok()?;
+	loop {
+		match left {
+			JsAnyExpression::JsBinaryExpression(binary) if is_plus(&binary) => {
+				operands.push(binary.right()?);
+				left = binary.left().ok()?;
+			}
+			left => {
+				operands.push(left);
+				break;
+			}
+		}
+	}
+	operands.reverse();
+	Some(operands)
+}
+
+fn is_plus(expr: &JsBinaryExpression) -> bool {
+	matches!(expr.operator(), Ok(operator) if operator.kind() == T![+])
+}
+
+fn is_string_like(expr: &JsAnyExpression) -> bool {
+	match expr {
+		JsAnyExpression::JsAnyLiteralExpression(
+			JsAnyLiteralExpression::JsStringLiteralExpression(_),
+		) => true,
+		JsAnyExpression::Template(template) => !is_tagged(template),
+		_ => false,
+	}
+}
+
+fn is_tagged(template: &Template) -> bool {
+	!matches!(template.syntax().first_token(), Some(token) if token.kind() == BACKTICK)
+}
+
+/// Returns the chunks and `${}` elements of a template
+fn template_elements(
+	template: &Template,
+) -> Option<impl Iterator<Item = rome_rowan::NodeOrToken<SyntaxNode, crate::SyntaxToken>>> {
+	let list = template
+		.syntax()
+		.children()
+		.find(|child| child.kind() == LIST)?;
+	Some(
+		list.children_with_tokens()
+			.filter(|element| matches!(element.kind(), TEMPLATE_CHUNK | TEMPLATE_ELEMENT)),
+	)
+}
+
+/// The source text of a template, without its backticks
+fn template_content(template: &Template) -> Option<String> {
+	let text = template.syntax().text_trimmed().to_string();
+	let inner = text.strip_prefix('`')?.strip_suffix('`')?;
+	Some(inner.to_string())
+}
+
+fn strip_parentheses(expr: &JsAnyExpression) -> JsAnyExpression {
+	let mut expr = expr.clone();
+	while let JsAnyExpression::JsParenthesizedExpression(parenthesized) = &expr {
+		match parenthesized.expression() {
+			Ok(inner) => expr = inner,
+			Err(_) => break,
+		}
+	}
+	expr
+}
+
+/// Whether an expression must be wrapped in parentheses to be an operand of `+`
+fn needs_parentheses(expr: &JsAnyExpression) -> bool {
+	!matches!(
+		expr,
+		JsAnyExpression::JsAnyLiteralExpression(_)
+			| JsAnyExpression::JsArrayExpression(_)
+			| JsAnyExpression::JsComputedMemberExpression(_)
+			| JsAnyExpression::JsImportCallExpression(_)
+			| JsAnyExpression::JsObjectExpression(_)
+			| JsAnyExpression::JsParenthesizedExpression(_)
+			| JsAnyExpression::JsReferenceIdentifierExpression(_)
+			| JsAnyExpression::JsStaticMemberExpression(_)
+			| JsAnyExpression::JsThisExpression(_)
+			| JsAnyExpression::Template(_)
+			| JsAnyExpression::CallExpr(_)
+			| JsAnyExpression::NewExpr(_)
+			| JsAnyExpression::NewTarget(_)
+			| JsAnyExpression::ImportMeta(_)
+	)
+}
+
+/// Converts the raw text of a template chunk to a double quoted string literal with the same value
+fn template_chunk_to_string(raw: &str) -> String {
+	let mut result = String::with_capacity(raw.len() + 2);
+	result.push('"');
+
+	let mut chars = raw.chars().peekable();
+	while let Some(chr) = chars.next() {
+		match chr {
+			'\\' => match chars.next() {
+				// Only needed inside of templates
+				Some(escaped @ '`') | Some(escaped @ '$') => result.push(escaped),
+				// A line continuation doesn't add anything to the value
+				Some('\r') => {
+					chars.next_if_eq(&'\n');
+				}
+				Some('\n') | Some('\u{2028}') | Some('\u{2029}') => {}
+				Some(escaped) => {
+					result.push('\\');
+					result.push(escaped);
+				}
+				None => result.push('\\'),
+			},
+			'"' => result.push_str("\\\""),
+			// Templates normalize `\r\n` and `\r` line breaks to `\n`
+			'\r' => {
+				chars.next_if_eq(&'\n');
+				result.push_str("\\n");
+			}
+			'\n' => result.push_str("\\n"),
+			'\u{2028}' => result.push_str("\\u2028"),
+			'\u{2029}' => result.push_str("\\u2029"),
+			chr => result.push(chr),
+		}
+	}
+
+	result.push('"');
+	result
+}
+
+/// Converts a `${}` element of a template to an operand of `+`, keeping the comments inside of it
+fn template_element_to_operand(element: &SyntaxNode) -> Option<String> {
+	let expr = element.children().find_map(JsAnyExpression::cast)?;
+	let comments: Vec<_> = element
+		.descendants_tokens()
+		.filter(|token| token.kind() != T!['}'])
+		.flat_map(|token| {
+			token
+				.leading_trivia()
+				.pieces()
+				.chain(token.trailing_trivia().pieces())
+		})
+		.filter_map(|piece| piece.as_comments())
+		.collect();
+
+	if comments.is_empty() {
+		let expr = strip_parentheses(&expr);
+		let text = expr.syntax().text_trimmed().to_string();
+		return Some(if needs_parentheses(&expr) {
+			format!("({})", text)
+		} else {
+			text
+		});
+	}
+
+	// The text between `${` and `}`, the parentheses are kept as they may contain comments
+	let start = element.first_token()?.text_trimmed_range().end();
+	let end = element.last_token()?.text_trimmed_range().start();
+	let offset = element.text_range().start();
+	let text = element
+		.text()
+		.slice(rome_rowan::TextRange::new(start - offset, end - offset))
+		.to_string();
+	let mut text = text.trim().to_string();
+
+	// A line comment at the end would comment out the rest of the concatenation
+	let last = comments.last()?;
+	if last.text().starts_with("//") && text.ends_with(last.text()) {
+		text.push('\n');
+	}
+
+	Some(if needs_parentheses(&expr) {
+		format!("({})", text)
+	} else {
+		text
+	})
+}
+
+/// Turns an arrow function with an expression body into one with a block body.
+///
+/// ```
+/// use rslint_parser::{assists::add_arrow_body_braces, parse_expr, ast::JsArrowFunctionExpression, AstNode};
+///
+/// let arrow = JsArrowFunctionExpression::cast(parse_expr("a => a + 1", 0).syntax()).unwrap();
+/// assert_eq!(add_arrow_body_braces(&arrow).unwrap().insert, "a => { return a + 1; }");
+/// ```
+pub fn add_arrow_body_braces(arrow: &JsArrowFunctionExpression) -> Option<Indel> {
+	let expr = match arrow.body()? {
+		JsAnyArrowFunctionBody::JsAnyExpression(expr) => expr,
+		JsAnyArrowFunctionBody::JsFunctionBody(_) => return None,
+	};
+
+	let text = format!(
+		"{}{{ return {}; }}",
+		arrow_head(arrow, expr.syntax()),
+		strip_parentheses(&expr).syntax().text_trimmed()
+	);
+	verified_replace(arrow.syntax(), text)
+}
+
+/// Turns an arrow function with a block body that only returns an expression into one
+/// with an expression body.
+///
+/// The comments of the body are moved before or after the returned expression.
+///
+/// ```
+/// use rslint_parser::{assists::remove_arrow_body_braces, parse_expr, ast::JsArrowFunctionExpression, AstNode};
+///
+/// let arrow = JsArrowFunctionExpression::cast(parse_expr("(a) => { return { a }; }", 0).syntax()).unwrap();
+/// assert_eq!(remove_arrow_body_braces(&arrow).unwrap().insert, "(a) => ({ a })");
+///
+/// let arrow = JsArrowFunctionExpression::cast(parse_expr("() => { return /* a */ a; }", 0).syntax()).unwrap();
+/// assert_eq!(remove_arrow_body_braces(&arrow).unwrap().insert, "() => /* a */ a");
+/// ```
+pub fn remove_arrow_body_braces(arrow: &JsArrowFunctionExpression) -> Option<Indel> {
+	let body = match arrow.body()? {
+		JsAnyArrowFunctionBody::JsFunctionBody(body) => body,
+		JsAnyArrowFunctionBody::JsAnyExpression(_) => return None,
+	};
+	let expr = single_return(&body)?;
+
+	let body_range = body.syntax().text_trimmed_range();
+	let expr_range = expr.syntax().text_trimmed_range();
+	let mut before = String::new();
+	let mut after = String::new();
+	for comment in body
+		.syntax()
+		.descendants_tokens()
+		.flat_map(|token| {
+			token
+				.leading_trivia()
+				.pieces()
+				.chain(token.trailing_trivia().pieces())
+		})
+		.filter_map(|piece| piece.as_comments())
+	{
+		let range = comment.text_range();
+		let is_line = comment.text().starts_with("//");
+		if !body_range.contains_range(range) || expr_range.contains_range(range) {
+			continue;
+		}
+		if range.end() <= expr_range.start() {
+			before.push_str(comment.text());
+			before.push(if is_line { '\n' } else { ' ' });
+		} else {
+			after.push(' ');
+			after.push_str(comment.text());
+			// A line comment would otherwise comment out the code after the arrow function
+			if is_line {
+				after.push('\n');
+			}
+		}
+	}
+
+	let expr_text = expr.syntax().text_trimmed().to_string();
+	let expr_text = match expr {
+		// `() => { a }` is a block, and `() => a, b` a sequence containing an arrow
+		JsAnyExpression::JsObjectExpression(_) | JsAnyExpression::JsSequenceExpression(_) => {
+			format!("({})", expr_text)
+		}
+		_ => expr_text,
+	};
+
+	let text = format!(
+		"{}{}{}{}",
+		arrow_head(arrow, body.syntax()),
+		before,
+		expr_text,
+		after
+	);
+	verified_replace(arrow.syntax(), text)
+}
+
+/// Converts a function expression to an arrow function.
+///
+/// The assist doesn't apply to generators and to functions that use their own `this`, `arguments`,
+/// `super` or `new.target`, nor to named functions that refer to themselves.
+///
+/// ```
+/// use rslint_parser::{assists::function_to_arrow, parse_text, ast::JsFunctionExpression, AstNode};
+///
+/// let parse = parse_text("f(async function(a) { await a; })", 0);
+/// let function = parse.syntax().descendants().find_map(JsFunctionExpression::cast).unwrap();
+/// assert_eq!(function_to_arrow(&function).unwrap().insert, "async (a) => { await a; }");
+///
+/// let parse = parse_text("f(function() { return this; })", 0);
+/// let function = parse.syntax().descendants().find_map(JsFunctionExpression::cast).unwrap();
+/// assert!(function_to_arrow(&function).is_none());
+/// ```
+pub fn function_to_arrow(function: &JsFunctionExpression) -> Option<Indel> {
+	if function.star_token().is_some() {
+		return None;
+	}
+
+	let scopes = file_scopes(function.syntax())?;
+	if uses_own_function_bindings(&scopes, function.syntax()) {
+		return None;
+	}
+	if let Some(id) = function.id() {
+		let binding = scopes.binding_of(id.syntax())?;
+		if scopes.references_of(binding).next().is_some() {
+			return None;
+		}
+	}
+
+	let mut text = String::new();
+	if function.async_token().is_some() {
+		text.push_str("async ");
+	}
+	if let Some(type_parameters) = function.type_parameters() {
+		text.push_str(&type_parameters.syntax().text_trimmed().to_string());
+	}
+	text.push_str(
+		&function
+			.parameters()
+			.ok()?
+			.syntax()
+			.text_trimmed()
+			.to_string(),
+	);
+	if let Some(return_type) = function.return_type() {
+		text.push_str(&return_type.syntax().text_trimmed().to_string());
+	}
+	text.push_str(" => ");
+	text.push_str(&function.body().ok()?.syntax().text_trimmed().to_string());
+
+	// Arrow functions have a lower precedence than function expressions: `a || function() {}`
+	if !is_arrow_position(function.syntax()) {
+		text = format!("({})", text);
+	}
+
+	verified_replace(function.syntax(), text)
+}
+
+/// Converts a function declaration to a `const` variable initialized with an arrow function:
+/// `function f() {}` becomes `const f = () => {};`.
+///
+/// The variable can't be used before its declaration, unlike the hoisted function, so the assist
+/// doesn't apply if a reference to the function may run before the declaration, or if the function is
+/// reassigned or declared more than once. It doesn't apply to declarations inside of blocks and exports
+/// either, nor to generators and to functions that use their own `this`, `arguments`, `super` or `new.target`.
+///
+/// ```
+/// use rslint_parser::{assists::function_declaration_to_arrow, parse_text, ast::JsFunctionDeclaration, AstNode};
+///
+/// let parse = parse_text("function double(a) { return a * 2; }\ndouble(2);", 0);
+/// let function = parse.syntax().descendants().find_map(JsFunctionDeclaration::cast).unwrap();
+/// assert_eq!(
+///     function_declaration_to_arrow(&function).unwrap().insert,
+///     "const double = (a) => { return a * 2; };"
+/// );
+///
+/// let parse = parse_text("double(2);\nfunction double(a) { return a * 2; }", 0);
+/// let function = parse.syntax().descendants().find_map(JsFunctionDeclaration::cast).unwrap();
+/// assert!(function_declaration_to_arrow(&function).is_none());
+/// ```
+pub fn function_declaration_to_arrow(function: &JsFunctionDeclaration) -> Option<Indel> {
+	if function.star_token().is_some() || function.declare_token().is_some() {
+		return None;
+	}
+	if !is_statement_list_item(function.syntax()) {
+		return None;
+	}
+
+	let id = function.id().ok()?;
+	let parameters = function.parameter_list().ok()?;
+	let body = function.body().ok()?;
+	let mut kept = vec![
+		id.syntax().clone(),
+		parameters.syntax().clone(),
+		body.syntax().clone(),
+	];
+	kept.extend(function.type_parameters().map(|node| node.syntax().clone()));
+	kept.extend(function.return_type().map(|node| node.syntax().clone()));
+	if has_comments_outside(function.syntax(), &kept) {
+		return None;
+	}
+
+	let scopes = file_scopes(function.syntax())?;
+	if uses_own_function_bindings(&scopes, function.syntax())
+		|| !can_move_declaration(&scopes, id.syntax(), function.syntax(), false)
+	{
+		return None;
+	}
+
+	let mut text = format!("const {} = ", id.syntax().text_trimmed());
+	if function.async_token().is_some() {
+		text.push_str("async ");
+	}
+	if let Some(type_parameters) = function.type_parameters() {
+		text.push_str(&type_parameters.syntax().text_trimmed().to_string());
+	}
+	text.push_str(&parameters.syntax().text_trimmed().to_string());
+	if let Some(return_type) = function.return_type() {
+		text.push_str(&return_type.syntax().text_trimmed().to_string());
+	}
+	text.push_str(" => ");
+	text.push_str(&body.syntax().text_trimmed().to_string());
+	text.push(';');
+
+	verified_statement_replace(function.syntax(), text)
+}
+
+/// Converts an arrow function assigned to a variable to a function expression:
+/// `const a = () => {}` becomes `const a = function() {}`.
+///
+/// The assist doesn't apply if the arrow function uses the `this`, `arguments`, `super` or `new.target`
+/// of its parent.
+///
+/// ```
+/// use rslint_parser::{assists::arrow_to_function_expression, parse_text, ast::JsArrowFunctionExpression, AstNode};
+///
+/// let parse = parse_text("const double = a => a * 2;", 0);
+/// let arrow = parse.syntax().descendants().find_map(JsArrowFunctionExpression::cast).unwrap();
+/// assert_eq!(
+///     arrow_to_function_expression(&arrow).unwrap().insert,
+///     "function(a) { return a * 2; }"
+/// );
+/// ```
+pub fn arrow_to_function_expression(arrow: &JsArrowFunctionExpression) -> Option<Indel> {
+	// A function expression at the start of a statement would be parsed as a declaration
+	arrow
+		.syntax()
+		.parent()
+		.filter(|parent| parent.kind() == JS_EQUAL_VALUE_CLAUSE)?
+		.parent()
+		.filter(|parent| parent.kind() == JS_VARIABLE_DECLARATOR)?;
+
+	let scopes = file_scopes(arrow.syntax())?;
+	if uses_outer_function_bindings(&scopes, arrow) {
+		return None;
+	}
+
+	verified_replace(arrow.syntax(), arrow_to_function_text(arrow, None)?)
+}
+
+/// Converts a variable declaration that assigns an arrow function to a function declaration:
+/// `const f = () => {};` becomes `function f() {}`.
+///
+/// The declaration is hoisted, so the assist doesn't apply if a reference to the variable may run
+/// before the declaration, e.g. the `typeof` in `typeof f; const f = () => {};`, or if the variable
+/// is declared more than once. It only applies to declarations with a single variable outside of blocks
+/// and exports, and to arrow functions that don't use the `this`, `arguments`, `super` or `new.target`
+/// of their parent.
+///
+/// ```
+/// use rslint_parser::{assists::arrow_to_function_declaration, parse_text, ast::JsArrowFunctionExpression, AstNode};
+///
+/// let parse = parse_text("const double = a => a * 2;", 0);
+/// let arrow = parse.syntax().descendants().find_map(JsArrowFunctionExpression::cast).unwrap();
+/// assert_eq!(
+///     arrow_to_function_declaration(&arrow).unwrap().insert,
+///     "function double(a) { return a * 2; }"
+/// );
+/// ```
+pub fn arrow_to_function_declaration(arrow: &JsArrowFunctionExpression) -> Option<Indel> {
+	let declarator = arrow
+		.syntax()
+		.parent()
+		.filter(|parent| parent.kind() == JS_EQUAL_VALUE_CLAUSE)?
+		.parent()
+		.and_then(JsVariableDeclarator::cast)?;
+	let declaration = declarator
+		.syntax()
+		.ancestors()
+		.find_map(JsVariableDeclaration::cast)?;
+	let statement = declaration
+		.syntax()
+		.parent()
+		.filter(|parent| parent.kind() == JS_VARIABLE_DECLARATION_STATEMENT)?;
+	if declaration.declarators().len() != 1 || !is_statement_list_item(&statement) {
+		return None;
+	}
+
+	// A type annotation of the variable can't be kept
+	let name = match declarator.id().ok()? {
+		Pattern::SinglePattern(pattern)
+			if pattern.ty().is_none() && pattern.excl_token().is_none() =>
+		{
+			pattern.name().ok()?
+		}
+		_ => return None,
+	};
+	if declarator.ty().is_some()
+		|| has_comments_outside(&statement, &[name.syntax().clone(), arrow.syntax().clone()])
+	{
+		return None;
+	}
+
+	let scopes = file_scopes(arrow.syntax())?;
+	if uses_outer_function_bindings(&scopes, arrow)
+		|| !can_move_declaration(&scopes, name.syntax(), &statement, true)
+	{
+		return None;
+	}
+
+	let text = arrow_to_function_text(arrow, Some(&name.syntax().text_trimmed().to_string()))?;
+	verified_statement_replace(&statement, text)
+}
+
+/// The text of the function expression, or declaration if it has a `name`, equivalent to `arrow`
+fn arrow_to_function_text(arrow: &JsArrowFunctionExpression, name: Option<&str>) -> Option<String> {
+	let mut text = String::new();
+	if arrow.async_token().is_some() {
+		text.push_str("async ");
+	}
+	text.push_str("function");
+	if let Some(name) = name {
+		text.push(' ');
+		text.push_str(name);
+	}
+	if let Some(type_parameters) = arrow.type_parameters() {
+		text.push_str(&type_parameters.syntax().text_trimmed().to_string());
+	}
+	match arrow.parameter_list()? {
+		JsAnyArrowFunctionParameters::JsParameterList(parameters) => {
+			text.push_str(&parameters.syntax().text_trimmed().to_string())
+		}
+		JsAnyArrowFunctionParameters::JsIdentifierBinding(binding) => {
+			text.push_str(&format!("({})", binding.syntax().text_trimmed()))
+		}
+	}
+	if let Some(return_type) = arrow.return_type() {
+		text.push_str(&return_type.syntax().text_trimmed().to_string());
+	}
+	text.push(' ');
+	match arrow.body()? {
+		JsAnyArrowFunctionBody::JsFunctionBody(body) => {
+			text.push_str(&body.syntax().text_trimmed().to_string())
+		}
+		JsAnyArrowFunctionBody::JsAnyExpression(expr) => {
+			text.push_str(&format!(
+				"{{ return {}; }}",
+				strip_parentheses(&expr).syntax().text_trimmed()
+			));
+		}
+	}
+	Some(text)
+}
+
+/// The text of an arrow function up to, and including, the whitespace before its body
+fn arrow_head(arrow: &JsArrowFunctionExpression, body: &SyntaxNode) -> String {
+	let range = arrow.syntax().text_trimmed_range();
+	let body_start = body.text_trimmed_range().start();
+	let text = arrow.syntax().text();
+	text.slice(rome_rowan::TextRange::new(
+		range.start() - arrow.syntax().text_range().start(),
+		body_start - arrow.syntax().text_range().start(),
+	))
+	.to_string()
+}
+
+/// Returns the argument of the return statement if it's the only statement of `body`
+fn single_return(body: &JsFunctionBody) -> Option<JsAnyExpression> {
+	if body.directives().iter().next().is_some() {
+		return None;
+	}
+
+	let mut statements = body.statements().iter();
+	match (statements.next()?, statements.next()) {
+		(JsAnyStatement::JsReturnStatement(statement), None) => statement.argument(),
+		_ => None,
+	}
+}
+
+/// Analyzes the scopes of the file `node` is in, or returns [None] if `node` isn't part of a file
+fn file_scopes(node: &SyntaxNode) -> Option<Scopes> {
+	let root = JsRoot::cast(node.ancestors().last()?)?;
+	Some(Scopes::new(&root))
+}
+
+/// Returns the `this`, `super`, and `new.target` expressions inside of `node`, and the references
+/// to `arguments` that don't refer to a variable
+fn function_binding_uses<'a>(
+	scopes: &'a Scopes,
+	node: &SyntaxNode,
+) -> impl Iterator<Item = SyntaxNode> + 'a {
+	node.descendants()
+		.filter(move |descendant| match descendant.kind() {
+			JS_THIS_EXPRESSION | JS_SUPER_EXPRESSION | NEW_TARGET => true,
+			JS_REFERENCE_IDENTIFIER_EXPRESSION => {
+				scopes.reference_of(descendant).map_or(false, |reference| {
+					reference.name() == "arguments" && reference.is_unresolved()
+				})
+			}
+			_ => false,
+		})
+}
+
+/// Returns the node whose `this`, `arguments`, `super` and `new.target` `node` uses: the closest
+/// function scope that isn't an arrow function, or the root of the file
+fn function_bindings_owner(scopes: &Scopes, node: &SyntaxNode) -> SyntaxNode {
+	scopes
+		.ancestors(scopes.scope_of(node).id())
+		.find(|scope| match scope.kind() {
+			ScopeKind::Global => true,
+			ScopeKind::Function => scope.node().kind() != JS_ARROW_FUNCTION_EXPRESSION,
+			_ => false,
+		})
+		.unwrap_or_else(|| scopes.global_scope())
+		.node()
+		.clone()
+}
+
+/// Whether the function `function` uses its own `this`, `arguments`, `super` or `new.target`
+fn uses_own_function_bindings(scopes: &Scopes, function: &SyntaxNode) -> bool {
+	function_binding_uses(scopes, function)
+		.any(|node| &function_bindings_owner(scopes, &node) == function)
+}
+
+/// Whether `arrow` uses the `this`, `arguments`, `super` or `new.target` of its parent
+fn uses_outer_function_bindings(scopes: &Scopes, arrow: &JsArrowFunctionExpression) -> bool {
+	function_binding_uses(scopes, arrow.syntax()).any(|node| {
+		let owner = function_bindings_owner(scopes, &node);
+		arrow.syntax().ancestors().any(|ancestor| ancestor == owner)
+	})
+}
+
+/// Whether the declaration `statement` of the binding `name` can be turned from a variable declaration
+/// into a function declaration, or the other way around if `to_function` is `false`.
+///
+/// A function declaration is hoisted while a variable can't be used before its declaration has run,
+/// so every reference must run after the declaration. A variable declared with `const` can't be
+/// reassigned and a function declaration can be declared more than once.
+fn can_move_declaration(
+	scopes: &Scopes,
+	name: &SyntaxNode,
+	statement: &SyntaxNode,
+	to_function: bool,
+) -> bool {
+	let binding = match scopes.binding_of(name) {
+		Some(binding) => binding,
+		None => return false,
+	};
+	let declarations = scopes
+		.scope(binding.scope())
+		.bindings()
+		.iter()
+		.filter(|id| scopes.binding(**id).name() == binding.name())
+		.count();
+	if declarations != 1 {
+		return false;
+	}
+
+	scopes.references_of(binding).all(|reference| {
+		(to_function || !reference.kind().is_write())
+			&& !may_run_before(scopes, binding, reference, statement)
+	})
+}
+
+/// Whether `reference` may run before `statement`, the declaration of `binding`, has run: it comes
+/// before the statement, or it is inside of a function declaration that is hoisted above the statement.
+/// The references inside of the statement can only run once it has run.
+fn may_run_before(
+	scopes: &Scopes,
+	binding: &Binding,
+	reference: &Reference,
+	statement: &SyntaxNode,
+) -> bool {
+	let range = statement.text_trimmed_range();
+	if range.contains_range(reference.range()) {
+		return false;
+	}
+	if reference.range().start() < range.start() {
+		return true;
+	}
+
+	let declaration_scope = scopes.scope(binding.scope()).node();
+	reference
+		.node()
+		.ancestors()
+		.take_while(|ancestor| ancestor != declaration_scope)
+		.filter(|ancestor| ancestor.kind() == JS_FUNCTION_DECLARATION)
+		.filter_map(|function| function.parent())
+		.any(|parent| scopes.scope_of(&parent).range().start() < range.end())
+}
+
+/// Whether `node` is a statement of a file or a function body, and not of a block or an export
+fn is_statement_list_item(node: &SyntaxNode) -> bool {
+	node.parent()
+		.filter(|parent| parent.kind() == LIST)
+		.and_then(|list| list.parent())
+		.map_or(false, |parent| {
+			matches!(parent.kind(), JS_ROOT | JS_FUNCTION_BODY)
+		})
+}
+
+/// Whether `node` has comments that aren't inside of one of the `kept` nodes, which would be lost
+/// when `node` is rewritten from the text of the `kept` nodes
+fn has_comments_outside(node: &SyntaxNode, kept: &[SyntaxNode]) -> bool {
+	let range = node.text_trimmed_range();
+	node.descendants_tokens()
+		.flat_map(|token| {
+			token
+				.leading_trivia()
+				.pieces()
+				.chain(token.trailing_trivia().pieces())
+		})
+		.filter_map(|piece| piece.as_comments())
+		.any(|comment| {
+			let comment_range = comment.text_range();
+			range.contains_range(comment_range)
+				&& !kept
+					.iter()
+					.any(|node| node.text_trimmed_range().contains_range(comment_range))
+		})
+}
+
+/// Whether an arrow function can replace the expression `node` without being parenthesized
+fn is_arrow_position(node: &SyntaxNode) -> bool {
+	let parent = match node.parent() {
+		Some(parent) => parent,
+		None => return true,
+	};
+
+	match parent.kind() {
+		JS_EQUAL_VALUE_CLAUSE | JS_PARENTHESIZED_EXPRESSION | JS_RETURN_STATEMENT => true,
+		// The right side of an assignment
+		ASSIGN_EXPR => parent.last_child().as_ref() == Some(node),
+		LIST => matches!(
+			parent.parent().map(|list_parent| list_parent.kind()),
+			Some(ARG_LIST) | Some(JS_ARRAY_EXPRESSION)
+		),
+		_ => false,
+	}
+}
+
+/// Returns an edit replacing `node` with `text` if `text` is a valid expression
+fn verified_replace(node: &SyntaxNode, text: String) -> Option<Indel> {
+	let parse = parse_expr(&text, 0);
+	if !parse.errors().is_empty() || parse.syntax().text() != text.as_str() {
+		return None;
+	}
+
+	Some(Indel::replace(node.text_trimmed_range(), text))
+}
+
+/// Returns an edit replacing `node` with `text` if `text` is a valid statement
+fn verified_statement_replace(node: &SyntaxNode, text: String) -> Option<Indel> {
+	let parse = parse_statement(&text, 0, Syntax::default());
+	if !parse.errors().is_empty() || parse.syntax().text() != text.as_str() {
+		return None;
+	}
+
+	Some(Indel::replace(node.text_trimmed_range(), text))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		add_arrow_body_braces, arrow_to_function_declaration, arrow_to_function_expression,
+		concat_to_template, function_declaration_to_arrow, function_to_arrow, node_assists,
+		remove_arrow_body_braces, template_to_concat,
+	};
+	use crate::ast::{
+		JsArrowFunctionExpression, JsBinaryExpression, JsFunctionDeclaration, JsFunctionExpression,
+		Template,
+	};
+	use crate::{parse_text, AstNode};
+	use rslint_text_edit::Indel;
+
+	fn apply<N: AstNode>(code: &str, assist: fn(&N) -> Option<Indel>) -> Option<String> {
+		let parse = parse_text(code, 0);
+		let node = parse.syntax().descendants().find_map(N::cast).unwrap();
+		let indel = assist(&node)?;
+		let mut text = code.to_string();
+		indel.apply(&mut text);
+		Some(text)
+	}
+
+	fn concat(code: &str) -> Option<String> {
+		let parse = parse_text(code, 0);
+		let expr = parse
+			.syntax()
+			.descendants()
+			.find_map(JsBinaryExpression::cast)
+			.unwrap();
+		let indel = concat_to_template(&expr)?;
+		let mut text = code.to_string();
+		indel.apply(&mut text);
+		Some(text)
+	}
+
+	fn template(code: &str) -> Option<String> {
+		let parse = parse_text(code, 0);
+		let template = parse
+			.syntax()
+			.descendants()
+			.find_map(Template::cast)
+			.unwrap();
+		let indel = template_to_concat(&template)?;
+		let mut text = code.to_string();
+		indel.apply(&mut text);
+		Some(text)
+	}
+
+	#[test]
+	fn concat_chain_to_template() {
+		assert_eq!(
+			concat("let a = 'a' + b + \"c\" + (d + 1);").as_deref(),
+			Some("let a = `a${b}c${d + 1}`;")
+		);
+		assert_eq!(
+			concat("x = \"it's `${a}`\" + b").as_deref(),
+			Some("x = `it's \\`\\${a}\\`${b}`")
+		);
+		assert_eq!(
+			concat("x = `a${b}` + c").as_deref(),
+			Some("x = `a${b}${c}`")
+		);
+		// Legacy octal escapes are syntax errors in templates
+		assert_eq!(
+			concat("x = '\\101' + a + '\\0\\8'").as_deref(),
+			Some("x = `\\x41${a}\\x008`")
+		);
+	}
+
+	#[test]
+	fn concat_not_a_string() {
+		// `1 + 2 + "a"` is "3a", and not "12a"
+		assert_eq!(concat("1 + 2 + 'a'"), None);
+		assert_eq!(concat("a - 'b'"), None);
+		assert_eq!(concat("a + b"), None);
+	}
+
+	#[test]
+	fn template_to_concat_chain() {
+		assert_eq!(
+			template("let a = `a ${b} \"c\" ${d ? 1 : 2}`;").as_deref(),
+			Some("let a = \"a \" + b + \" \\\"c\\\" \" + (d ? 1 : 2);")
+		);
+		assert_eq!(
+			template("x = `${a}${b}`").as_deref(),
+			Some("x = \"\" + a + b")
+		);
+		assert_eq!(
+			template("x = `\\`\\${a}\n`").as_deref(),
+			Some("x = \"`${a}\\n\"")
+		);
+		assert_eq!(template("x = tag`a${b}`"), None);
+	}
+
+	#[test]
+	fn template_to_concat_line_breaks() {
+		assert_eq!(
+			template("x = `a\r\nb\rc\\\r\nd`").as_deref(),
+			Some("x = \"a\\nb\\ncd\"")
+		);
+	}
+
+	#[test]
+	fn template_to_concat_keeps_comments() {
+		assert_eq!(
+			template("x = `a${/* b */ b}${c /* c */ + 1}`").as_deref(),
+			Some("x = \"a\" + /* b */ b + (c /* c */ + 1)")
+		);
+		assert_eq!(
+			template("x = `${a // a\n}b`").as_deref(),
+			Some("x = a // a\n + \"b\"")
+		);
+	}
+
+	#[test]
+	fn arrow_body_braces() {
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"f(async (a, b) => ({ a }));",
+				add_arrow_body_braces
+			)
+			.as_deref(),
+			Some("f(async (a, b) => { return { a }; });")
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"f(() => { return a, b; });",
+				remove_arrow_body_braces
+			)
+			.as_deref(),
+			Some("f(() => (a, b));")
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"f(() => { a(); return b; });",
+				remove_arrow_body_braces
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"f(() => { /* b */ return b; });",
+				remove_arrow_body_braces
+			)
+			.as_deref(),
+			Some("f(() => /* b */ b);")
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"f(() => {\n\t// b\n\treturn /* c */ b; // d\n}, e);",
+				remove_arrow_body_braces
+			)
+			.as_deref(),
+			Some("f(() => // b\n/* c */ b // d\n, e);")
+		);
+	}
+
+	#[test]
+	fn function_expression_to_arrow() {
+		assert_eq!(
+			apply::<JsFunctionExpression>(
+				"a || function named(b) { return () => b; }",
+				function_to_arrow
+			)
+			.as_deref(),
+			Some("a || ((b) => { return () => b; })")
+		);
+		assert_eq!(
+			apply::<JsFunctionExpression>(
+				"f(function() { return function() { return this; }; })",
+				function_to_arrow
+			)
+			.as_deref(),
+			Some("f(() => { return function() { return this; }; })")
+		);
+		assert_eq!(
+			apply::<JsFunctionExpression>(
+				"f(function() { return () => arguments; })",
+				function_to_arrow
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsFunctionExpression>("f(function a() { return a; })", function_to_arrow),
+			None
+		);
+		assert_eq!(
+			apply::<JsFunctionExpression>("f(function*() {})", function_to_arrow),
+			None
+		);
+		// `arguments` refers to the parameter
+		assert_eq!(
+			apply::<JsFunctionExpression>(
+				"f(function(arguments) { return arguments; })",
+				function_to_arrow
+			)
+			.as_deref(),
+			Some("f((arguments) => { return arguments; })")
+		);
+		assert_eq!(
+			apply::<JsFunctionExpression>(
+				"f(function() { return { a() { return this; } }; })",
+				function_to_arrow
+			)
+			.as_deref(),
+			Some("f(() => { return { a() { return this; } }; })")
+		);
+	}
+
+	#[test]
+	fn function_declaration_to_const_arrow() {
+		assert_eq!(
+			apply::<JsFunctionDeclaration>(
+				"async function f(a) { await a; }\nf(1);",
+				function_declaration_to_arrow
+			)
+			.as_deref(),
+			Some("const f = async (a) => { await a; };\nf(1);")
+		);
+		assert_eq!(
+			apply::<JsFunctionDeclaration>(
+				"function f(a) { return a && f(a - 1); }\nconst g = () => f(1);",
+				function_declaration_to_arrow
+			)
+			.as_deref(),
+			Some("const f = (a) => { return a && f(a - 1); };\nconst g = () => f(1);")
+		);
+		// The function is hoisted
+		assert_eq!(
+			apply::<JsFunctionDeclaration>("f();\nfunction f() {}", function_declaration_to_arrow),
+			None
+		);
+		assert_eq!(
+			apply::<JsFunctionDeclaration>(
+				"g();\nfunction f() {}\nfunction g() { f(); }",
+				function_declaration_to_arrow
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsFunctionDeclaration>(
+				"function f() {}\nf = 1;",
+				function_declaration_to_arrow
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsFunctionDeclaration>(
+				"function f() {}\nfunction f() {}",
+				function_declaration_to_arrow
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsFunctionDeclaration>(
+				"function f() { return arguments; }",
+				function_declaration_to_arrow
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsFunctionDeclaration>(
+				"if (a) { function f() {} }",
+				function_declaration_to_arrow
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsFunctionDeclaration>(
+				"function /* f */ f() {}",
+				function_declaration_to_arrow
+			),
+			None
+		);
+	}
+
+	#[test]
+	fn arrow_to_function() {
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"let f = async () => { await g(); }",
+				arrow_to_function_expression
+			)
+			.as_deref(),
+			Some("let f = async function() { await g(); }")
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"const f = () => 1, g = 2;",
+				arrow_to_function_expression
+			)
+			.as_deref(),
+			Some("const f = function() { return 1; }, g = 2;")
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"const f = () => this.a;",
+				arrow_to_function_expression
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>("f(() => 1);", arrow_to_function_expression),
+			None
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"function g() { const f = () => arguments; }",
+				arrow_to_function_expression
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"const f = () => function() { return this; };",
+				arrow_to_function_expression
+			)
+			.as_deref(),
+			Some("const f = function() { return function() { return this; }; };")
+		);
+	}
+
+	#[test]
+	fn arrow_to_declaration() {
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"let f = async a => a; // f\nf(1);",
+				arrow_to_function_declaration
+			)
+			.as_deref(),
+			Some("async function f(a) { return a; } // f\nf(1);")
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"function g() {\n\tconst f = () => {};\n\tf = 1;\n}",
+				arrow_to_function_declaration
+			)
+			.as_deref(),
+			Some("function g() {\n\tfunction f() {}\n\tf = 1;\n}")
+		);
+		// The variable can't be used before its declaration
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"typeof f;\nconst f = () => {};",
+				arrow_to_function_declaration
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"const f = () => {}, g = 1;",
+				arrow_to_function_declaration
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"if (a) { const f = () => {}; }",
+				arrow_to_function_declaration
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"function g() { const f = () => this; }",
+				arrow_to_function_declaration
+			),
+			None
+		);
+		assert_eq!(
+			apply::<JsArrowFunctionExpression>(
+				"var f = () => {};\nvar f;",
+				arrow_to_function_declaration
+			),
+			None
+		);
+	}
+
+	#[test]
+	fn assists_of_node() {
+		let parse = parse_text("const f = () => 1;", 0);
+		let arrow = parse
+			.syntax()
+			.descendants()
+			.find(|node| JsArrowFunctionExpression::can_cast(node.kind()))
+			.unwrap();
+		let labels: Vec<_> = node_assists(&arrow)
+			.iter()
+			.map(|assist| assist.label)
+			.collect();
+		assert_eq!(
+			labels,
+			vec![
+				"Add braces to arrow function body",
+				"Convert to function expression",
+				"Convert to function declaration"
+			]
+		);
+	}
+}
@@ -7,6 +7,7 @@
 #[macro_use]
 mod expr_ext;
 mod generated;
+mod root_ext;
 mod stmt_ext;
 mod ts_ext;
 
@@ -17,6 +18,7 @@ use std::marker::PhantomData;
 pub use self::{
 	expr_ext::*,
 	generated::{nodes::*, tokens::*},
+	root_ext::*,
 	stmt_ext::*,
 	ts_ext::*,
 };
@@ -57,7 +59,7 @@ pub trait AstToken {
 
 	fn syntax(&self) -> &SyntaxToken;
 
-	fn text(&self) -> &str {
+	fn text(&self) -> std::borrow::Cow<str> {
 		self.syntax().text()
 	}
 }
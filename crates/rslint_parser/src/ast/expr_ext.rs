@@ -1,6 +1,6 @@
 //! Extensions for things which are not easily generated in ast expr nodes
 
-use crate::{ast::*, numbers::*, util::*, TextRange, T};
+use crate::{ast::*, numbers::*, strings::*, util::*, TextRange, T};
 use rome_rowan::{SyntaxText, TextSize};
 use SyntaxKind::*;
 
@@ -229,6 +229,26 @@ impl JsSequenceExpression {
 	}
 }
 
+impl JsImportCallExpression {
+	/// The options object passed as the second argument, e.g. `{ with: { type: "json" } }`
+	///
+	/// ```
+	/// use rslint_parser::{ast::JsImportCallExpression, parse_module, AstNode};
+	///
+	/// let parse = parse_module("import(\"a.json\", { with: { type: \"json\" } });", 0);
+	/// let call = parse
+	///     .syntax()
+	///     .descendants()
+	///     .find_map(JsImportCallExpression::cast)
+	///     .unwrap();
+	/// assert_eq!("\"a.json\"", call.argument().unwrap().text());
+	/// assert_eq!("{ with: { type: \"json\" } }", call.options().unwrap().text());
+	/// ```
+	pub fn options(&self) -> Option<JsAnyExpression> {
+		support::children(self.syntax()).nth(1)
+	}
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum JsUnaryOperation {
 	/// `delete`
@@ -358,18 +378,49 @@ impl JsObjectExpression {
 }
 
 impl JsNumberLiteralExpression {
+	/// The value of the number the same way JS' `Number()` evaluates it, taking prefixes,
+	/// legacy octal literals, exponents and numeric separators into account.
+	pub fn as_f64(&self) -> Option<f64> {
+		parse_js_number(self.value_token().ok()?.text_trimmed())
+	}
+
+	/// Alias for [`JsNumberLiteralExpression::as_f64`]
 	pub fn as_number(&self) -> Option<f64> {
-		parse_js_number(self.value_token().unwrap().text())
+		self.as_f64()
 	}
 }
 
 impl JsBigIntLiteralExpression {
 	pub fn as_number(&self) -> Option<BigInt> {
-		parse_js_big_int(self.value_token().ok()?.text())
+		parse_js_big_int(&self.value_token().ok()?.text())
+	}
+
+	/// Get the text of the number without the `n` suffix, e.g. `0xFF` for `0xFFn`
+	pub fn number_text(&self) -> SyntaxText {
+		let text = self.syntax().text_trimmed();
+		let len = text.len();
+
+		if len > TextSize::from(0) && text.char_at(len - TextSize::from(1)) == Some('n') {
+			text.slice(..len - TextSize::from(1))
+		} else {
+			text
+		}
 	}
 }
 
 impl JsStringLiteralExpression {
+	/// Compute the value of the string by decoding its escape sequences, e.g. `a\nb` for `"a\\nb"`.
+	/// Malformed escape sequences are returned with their range inside the file.
+	pub fn value(&self) -> Result<String, Vec<EscapeError>> {
+		let offset = self.syntax().text_trimmed_range().start();
+		parse_js_string(&self.syntax().text_trimmed().to_string()).map_err(|errors| {
+			errors
+				.into_iter()
+				.map(|error| error.with_offset(offset))
+				.collect()
+		})
+	}
+
 	/// Get the inner text of a string not including the quotes
 	pub fn inner_string_text(&self) -> SyntaxText {
 		let start = self.syntax().text_range().start() + TextSize::from(1);
@@ -430,10 +481,17 @@ impl Template {
 	/// `foo ${bar} foo` breaks down into:
 	/// `QUASIS ELEMENT{EXPR} QUASIS`
 	pub fn quasis(&self) -> impl Iterator<Item = SyntaxToken> {
-		self.syntax()
-			.children_with_tokens()
-			.filter_map(NodeOrToken::into_token)
-			.filter(|t| t.kind() == TEMPLATE_CHUNK)
+		template_chunk_tokens(self.syntax())
+	}
+
+	/// The string chunks of the template with access to their cooked and raw values.
+	pub fn chunks(&self) -> impl Iterator<Item = TemplateChunk> {
+		self.quasis().map(TemplateChunk)
+	}
+
+	/// The tag of a tagged template, e.g. `tag` in `` tag`foo` ``
+	pub fn tag(&self) -> Option<JsAnyExpression> {
+		self.syntax().first_child().and_then(JsAnyExpression::cast)
 	}
 
 	pub fn template_range(&self) -> Option<TextRange> {
@@ -449,6 +507,53 @@ impl Template {
 	}
 }
 
+/// The `TEMPLATE_CHUNK` tokens of a template or a template literal type
+pub(crate) fn template_chunk_tokens(template: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+	template
+		.children()
+		.filter(|child| child.kind() == LIST)
+		.flat_map(|list| list.children_with_tokens())
+		.filter_map(NodeOrToken::into_token)
+		.filter(|t| t.kind() == TEMPLATE_CHUNK)
+}
+
+/// A string chunk of a template literal, e.g. `foo ` and ` bar` in `` `foo ${a} bar` ``.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateChunk(pub(crate) SyntaxToken);
+
+impl TemplateChunk {
+	pub fn token(&self) -> &SyntaxToken {
+		&self.0
+	}
+
+	/// The source text of the chunk with `\r\n` and `\r` normalized to `\n`, escapes are not decoded.
+	/// This is the value of `strings.raw` inside of a template tag function.
+	pub fn raw(&self) -> String {
+		self.0
+			.text_trimmed()
+			.replace("\r\n", "\n")
+			.replace('\r', "\n")
+	}
+
+	/// The value of the chunk with its escape sequences decoded.
+	///
+	/// Returns `None` if the chunk contains a malformed escape. This is an error in untagged templates,
+	/// but tagged templates allow it and pass `undefined` as the cooked value to the tag function.
+	pub fn cooked(&self) -> Option<String> {
+		self.cooked_value().ok()
+	}
+
+	/// The value of the chunk with its escape sequences decoded, or the malformed escapes with
+	/// absolute ranges inside the file.
+	pub fn cooked_value(&self) -> Result<String, Vec<EscapeError>> {
+		unescape(
+			self.0.text_trimmed(),
+			self.0.text_trimmed_range().start(),
+			true,
+		)
+	}
+}
+
 impl CallExpr {
 	pub fn opt_chain_token(&self) -> Option<SyntaxToken> {
 		self.syntax()
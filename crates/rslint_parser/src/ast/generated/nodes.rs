@@ -379,6 +379,7 @@ pub struct JsFunctionDeclaration {
 	pub(crate) syntax: SyntaxNode,
 }
 impl JsFunctionDeclaration {
+	pub fn declare_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![declare]) }
 	pub fn async_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![async]) }
 	pub fn function_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![function])
@@ -397,6 +398,13 @@ pub struct JsClassDeclaration {
 	pub(crate) syntax: SyntaxNode,
 }
 impl JsClassDeclaration {
+	pub fn decorators(&self) -> AstNodeList<TsDecorator> {
+		support::node_list(&self.syntax, 0usize)
+	}
+	pub fn declare_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![declare]) }
+	pub fn abstract_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![abstract])
+	}
 	pub fn class_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![class])
 	}
@@ -407,7 +415,7 @@ impl JsClassDeclaration {
 		support::required_token(&self.syntax, T!['{'])
 	}
 	pub fn members(&self) -> AstNodeList<JsAnyClassMember> {
-		support::node_list(&self.syntax, 0usize)
+		support::node_list(&self.syntax, 1usize)
 	}
 	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['}'])
@@ -519,10 +527,7 @@ impl ImportDecl {
 	pub fn source_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![js_string_literal])
 	}
-	pub fn asserted_object(&self) -> SyntaxResult<JsObjectExpression> {
-		support::required_node(&self.syntax)
-	}
-	pub fn assert_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![assert]) }
+	pub fn assertion(&self) -> Option<JsImportAssertion> { support::node(&self.syntax) }
 	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -544,6 +549,7 @@ impl ExportNamed {
 	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['}'])
 	}
+	pub fn assertion(&self) -> Option<JsImportAssertion> { support::node(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExportDefaultDecl {
@@ -582,13 +588,14 @@ impl ExportWildcard {
 		support::required_token(&self.syntax, T ! [*])
 	}
 	pub fn as_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![as]) }
-	pub fn ident(&self) -> Option<Ident> { support::node(&self.syntax) }
+	pub fn alias(&self) -> Option<Name> { support::node(&self.syntax) }
 	pub fn from_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![from])
 	}
 	pub fn source_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![js_string_literal])
 	}
+	pub fn assertion(&self) -> Option<JsImportAssertion> { support::node(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExportDecl {
@@ -847,6 +854,9 @@ pub struct JsClassExpression {
 	pub(crate) syntax: SyntaxNode,
 }
 impl JsClassExpression {
+	pub fn decorators(&self) -> AstNodeList<TsDecorator> {
+		support::node_list(&self.syntax, 0usize)
+	}
 	pub fn class_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![class])
 	}
@@ -856,7 +866,7 @@ impl JsClassExpression {
 		support::required_token(&self.syntax, T!['{'])
 	}
 	pub fn members(&self) -> AstNodeList<JsAnyClassMember> {
-		support::node_list(&self.syntax, 0usize)
+		support::node_list(&self.syntax, 1usize)
 	}
 	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['}'])
@@ -921,6 +931,7 @@ impl JsImportCallExpression {
 		support::required_token(&self.syntax, T!['('])
 	}
 	pub fn argument(&self) -> SyntaxResult<JsAnyExpression> { support::required_node(&self.syntax) }
+	pub fn comma_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [,]) }
 	pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![')'])
 	}
@@ -1195,6 +1206,14 @@ impl TsConstAssertion {
 	}
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TsInstantiationExpr {
+	pub(crate) syntax: SyntaxNode,
+}
+impl TsInstantiationExpr {
+	pub fn expr(&self) -> SyntaxResult<JsAnyExpression> { support::required_node(&self.syntax) }
+	pub fn type_args(&self) -> SyntaxResult<TsTypeArgs> { support::required_node(&self.syntax) }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TsTypeArgs {
 	pub(crate) syntax: SyntaxNode,
 }
@@ -1248,7 +1267,7 @@ impl JsParameterList {
 	pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['('])
 	}
-	pub fn parameters(&self) -> AstSeparatedList<Pattern> {
+	pub fn parameters(&self) -> AstSeparatedList<JsAnyParameter> {
 		support::separated_list(&self.syntax, 0usize)
 	}
 	pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
@@ -1452,6 +1471,18 @@ impl Name {
 	}
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TsDecorator {
+	pub(crate) syntax: SyntaxNode,
+}
+impl TsDecorator {
+	pub fn at_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T ! [@])
+	}
+	pub fn expression(&self) -> SyntaxResult<JsAnyExpression> {
+		support::required_node(&self.syntax)
+	}
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TsImplementsClause {
 	pub(crate) syntax: SyntaxNode,
 }
@@ -1512,12 +1543,48 @@ pub struct JsPropertyClassMember {
 	pub(crate) syntax: SyntaxNode,
 }
 impl JsPropertyClassMember {
+	pub fn decorators(&self) -> AstNodeList<TsDecorator> {
+		support::node_list(&self.syntax, 0usize)
+	}
 	pub fn declare_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![declare]) }
 	pub fn access_modifier(&self) -> Option<TsAccessibility> { support::node(&self.syntax) }
 	pub fn abstract_token(&self) -> Option<SyntaxToken> {
 		support::token(&self.syntax, T![abstract])
 	}
 	pub fn static_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![static]) }
+	pub fn override_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![override])
+	}
+	pub fn name(&self) -> SyntaxResult<JsAnyClassMemberName> {
+		support::required_node(&self.syntax)
+	}
+	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [?])
+	}
+	pub fn excl_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![!]) }
+	pub fn ty(&self) -> Option<TsTypeAnnotation> { support::node(&self.syntax) }
+	pub fn value(&self) -> Option<JsEqualValueClause> { support::node(&self.syntax) }
+	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsAccessorPropertyClassMember {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsAccessorPropertyClassMember {
+	pub fn decorators(&self) -> AstNodeList<TsDecorator> {
+		support::node_list(&self.syntax, 0usize)
+	}
+	pub fn access_modifier(&self) -> Option<TsAccessibility> { support::node(&self.syntax) }
+	pub fn abstract_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![abstract])
+	}
+	pub fn static_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![static]) }
+	pub fn override_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![override])
+	}
+	pub fn accessor_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![accessor])
+	}
 	pub fn name(&self) -> SyntaxResult<JsAnyClassMemberName> {
 		support::required_node(&self.syntax)
 	}
@@ -1534,11 +1601,17 @@ pub struct JsMethodClassMember {
 	pub(crate) syntax: SyntaxNode,
 }
 impl JsMethodClassMember {
+	pub fn decorators(&self) -> AstNodeList<TsDecorator> {
+		support::node_list(&self.syntax, 0usize)
+	}
 	pub fn access_modifier(&self) -> Option<TsAccessibility> { support::node(&self.syntax) }
 	pub fn static_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![static]) }
 	pub fn abstract_token(&self) -> Option<SyntaxToken> {
 		support::token(&self.syntax, T![abstract])
 	}
+	pub fn override_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![override])
+	}
 	pub fn async_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![async]) }
 	pub fn star_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [*]) }
 	pub fn name(&self) -> SyntaxResult<JsAnyClassMemberName> {
@@ -1556,11 +1629,17 @@ pub struct JsGetterClassMember {
 	pub(crate) syntax: SyntaxNode,
 }
 impl JsGetterClassMember {
+	pub fn decorators(&self) -> AstNodeList<TsDecorator> {
+		support::node_list(&self.syntax, 0usize)
+	}
 	pub fn access_modifier(&self) -> Option<TsAccessibility> { support::node(&self.syntax) }
 	pub fn abstract_token(&self) -> Option<SyntaxToken> {
 		support::token(&self.syntax, T![abstract])
 	}
 	pub fn static_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![static]) }
+	pub fn override_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![override])
+	}
 	pub fn get_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![get])
 	}
@@ -1581,11 +1660,17 @@ pub struct JsSetterClassMember {
 	pub(crate) syntax: SyntaxNode,
 }
 impl JsSetterClassMember {
+	pub fn decorators(&self) -> AstNodeList<TsDecorator> {
+		support::node_list(&self.syntax, 0usize)
+	}
 	pub fn access_modifier(&self) -> Option<TsAccessibility> { support::node(&self.syntax) }
 	pub fn abstract_token(&self) -> Option<SyntaxToken> {
 		support::token(&self.syntax, T![abstract])
 	}
 	pub fn static_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![static]) }
+	pub fn override_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![override])
+	}
 	pub fn set_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![set])
 	}
@@ -1611,6 +1696,24 @@ impl JsEmptyClassMember {
 	}
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsStaticInitializationBlockClassMember {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsStaticInitializationBlockClassMember {
+	pub fn static_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![static])
+	}
+	pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T!['{'])
+	}
+	pub fn statements(&self) -> AstNodeList<JsAnyStatement> {
+		support::node_list(&self.syntax, 0usize)
+	}
+	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T!['}'])
+	}
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TsIndexSignature {
 	pub(crate) syntax: SyntaxNode,
 }
@@ -1668,6 +1771,18 @@ impl TsConstructorParam {
 	pub fn pat(&self) -> SyntaxResult<Pattern> { support::required_node(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TsDecoratedParameter {
+	pub(crate) syntax: SyntaxNode,
+}
+impl TsDecoratedParameter {
+	pub fn decorators(&self) -> AstNodeList<TsDecorator> {
+		support::node_list(&self.syntax, 0usize)
+	}
+	pub fn parameter(&self) -> SyntaxResult<JsAnyConstructorParameter> {
+		support::required_node(&self.syntax)
+	}
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct JsEqualValueClause {
 	pub(crate) syntax: SyntaxNode,
 }
@@ -1804,9 +1919,44 @@ pub struct JsVariableDeclarator {
 }
 impl JsVariableDeclarator {
 	pub fn id(&self) -> SyntaxResult<Pattern> { support::required_node(&self.syntax) }
+	pub fn excl_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![!]) }
+	pub fn ty(&self) -> Option<TsTypeAnnotation> { support::node(&self.syntax) }
 	pub fn init(&self) -> Option<JsEqualValueClause> { support::node(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsImportAssertion {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsImportAssertion {
+	pub fn kind_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::find_required_token(&self.syntax, &[T![with], T![assert]])
+	}
+	pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T!['{'])
+	}
+	pub fn assertions(&self) -> AstSeparatedList<JsImportAssertionEntry> {
+		support::separated_list(&self.syntax, 0usize)
+	}
+	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T!['}'])
+	}
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsImportAssertionEntry {
+	pub(crate) syntax: SyntaxNode,
+}
+impl JsImportAssertionEntry {
+	pub fn key(&self) -> SyntaxResult<SyntaxToken> {
+		support::find_required_token(&self.syntax, &[T![ident], T![js_string_literal]])
+	}
+	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T ! [:])
+	}
+	pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![js_string_literal])
+	}
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WildcardImport {
 	pub(crate) syntax: SyntaxNode,
 }
@@ -1846,6 +1996,7 @@ pub struct Specifier {
 	pub(crate) syntax: SyntaxNode,
 }
 impl Specifier {
+	pub fn type_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![type]) }
 	pub fn name(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2025,7 +2176,9 @@ impl TsTuple {
 	pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['['])
 	}
-	pub fn elements(&self) -> SyntaxResult<TsTupleElement> { support::required_node(&self.syntax) }
+	pub fn elements(&self) -> AstSeparatedList<TsAnyTupleTypeElement> {
+		support::separated_list(&self.syntax, 0usize)
+	}
 	pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![']'])
 	}
@@ -2056,8 +2209,11 @@ pub struct TsTemplate {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTemplate {
-	pub fn elements(&self) -> SyntaxResult<TsTemplateElement> {
-		support::required_node(&self.syntax)
+	pub fn backtick_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T!['`'])
+	}
+	pub fn elements(&self) -> AstNodeList<TsTemplateElement> {
+		support::node_list(&self.syntax, 0usize)
 	}
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2069,20 +2225,20 @@ impl TsMappedType {
 		support::required_token(&self.syntax, T!['{'])
 	}
 	pub fn readonly_modifier(&self) -> Option<TsMappedTypeReadonly> { support::node(&self.syntax) }
+	pub fn param(&self) -> SyntaxResult<TsMappedTypeParam> { support::required_node(&self.syntax) }
 	pub fn minus_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [-]) }
 	pub fn plus_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [+]) }
 	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
 		support::token(&self.syntax, T ! [?])
 	}
-	pub fn param(&self) -> SyntaxResult<TsMappedTypeParam> { support::required_node(&self.syntax) }
 	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [:])
 	}
 	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['}'])
 	}
-	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TsImport {
@@ -2165,6 +2321,9 @@ pub struct TsConstructorType {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsConstructorType {
+	pub fn abstract_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![abstract])
+	}
 	pub fn new_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![new])
 	}
@@ -2212,19 +2371,39 @@ impl TsInfer {
 	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TsTupleElement {
+pub struct TsNamedTupleTypeElement {
 	pub(crate) syntax: SyntaxNode,
 }
-impl TsTupleElement {
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+impl TsNamedTupleTypeElement {
+	pub fn dotdotdot_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [...]) }
+	pub fn name(&self) -> SyntaxResult<Name> { support::required_node(&self.syntax) }
+	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [?])
+	}
 	pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [:])
 	}
+	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TsRestTupleTypeElement {
+	pub(crate) syntax: SyntaxNode,
+}
+impl TsRestTupleTypeElement {
+	pub fn dotdotdot_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T ! [...])
+	}
+	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TsOptionalTupleTypeElement {
+	pub(crate) syntax: SyntaxNode,
+}
+impl TsOptionalTupleTypeElement {
+	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 	pub fn question_mark_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [?])
 	}
-	pub fn dotdotdot_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [...]) }
-	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TsEnumMember {
@@ -2263,10 +2442,29 @@ pub struct TsMappedTypeParam {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsMappedTypeParam {
-	pub fn l_brack_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['[']) }
-	pub fn name(&self) -> Option<TsTypeName> { support::node(&self.syntax) }
-	pub fn r_brack_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![']']) }
-	pub fn ident(&self) -> Option<Ident> { support::node(&self.syntax) }
+	pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T!['['])
+	}
+	pub fn name_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![ident])
+	}
+	pub fn in_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![in])
+	}
+	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
+	pub fn as_clause(&self) -> Option<TsMappedTypeAsClause> { support::node(&self.syntax) }
+	pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![']'])
+	}
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TsMappedTypeAsClause {
+	pub(crate) syntax: SyntaxNode,
+}
+impl TsMappedTypeAsClause {
+	pub fn as_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![as])
+	}
 	pub fn ty(&self) -> SyntaxResult<TsType> { support::required_node(&self.syntax) }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2304,6 +2502,7 @@ pub struct TsTypeParam {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsTypeParam {
+	pub fn const_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![const]) }
 	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
 	pub fn constraint(&self) -> SyntaxResult<TsConstraint> { support::required_node(&self.syntax) }
 	pub fn default(&self) -> SyntaxResult<TsDefault> { support::required_node(&self.syntax) }
@@ -2347,6 +2546,9 @@ pub struct TsConstructSignatureDecl {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsConstructSignatureDecl {
+	pub fn abstract_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![abstract])
+	}
 	pub fn new_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![new])
 	}
@@ -2479,6 +2681,7 @@ pub enum JsAnyExpression {
 	TsNonNull(TsNonNull),
 	TsAssertion(TsAssertion),
 	TsConstAssertion(TsConstAssertion),
+	TsInstantiationExpr(TsInstantiationExpr),
 	JsUnknownExpression(JsUnknownExpression),
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2565,10 +2768,12 @@ pub enum PropName {
 pub enum JsAnyClassMember {
 	JsConstructorClassMember(JsConstructorClassMember),
 	JsPropertyClassMember(JsPropertyClassMember),
+	JsAccessorPropertyClassMember(JsAccessorPropertyClassMember),
 	JsMethodClassMember(JsMethodClassMember),
 	JsGetterClassMember(JsGetterClassMember),
 	JsSetterClassMember(JsSetterClassMember),
 	JsEmptyClassMember(JsEmptyClassMember),
+	JsStaticInitializationBlockClassMember(JsStaticInitializationBlockClassMember),
 	TsIndexSignature(TsIndexSignature),
 	JsUnknownMember(JsUnknownMember),
 }
@@ -2581,6 +2786,7 @@ pub enum JsAnyClassMemberName {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum JsAnyConstructorParameter {
 	TsConstructorParam(TsConstructorParam),
+	TsDecoratedParameter(TsDecoratedParameter),
 	Pattern(Pattern),
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2652,6 +2858,7 @@ pub enum JsAnyExportDeclaration {
 pub enum JsAnyParameter {
 	Pattern(Pattern),
 	JsRestParameter(JsRestParameter),
+	TsDecoratedParameter(TsDecoratedParameter),
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TsModuleRef {
@@ -2664,6 +2871,13 @@ pub enum TsEntityName {
 	TsQualifiedPath(TsQualifiedPath),
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TsAnyTupleTypeElement {
+	TsNamedTupleTypeElement(TsNamedTupleTypeElement),
+	TsRestTupleTypeElement(TsRestTupleTypeElement),
+	TsOptionalTupleTypeElement(TsOptionalTupleTypeElement),
+	TsType(TsType),
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TsThisOrMore {
 	TsThis(TsThis),
 	TsTypeName(TsTypeName),
@@ -3616,6 +3830,17 @@ impl AstNode for TsConstAssertion {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for TsInstantiationExpr {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_INSTANTIATION_EXPR }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for TsTypeArgs {
 	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TYPE_ARGS }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -3847,6 +4072,17 @@ impl AstNode for Name {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for TsDecorator {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_DECORATOR }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for TsImplementsClause {
 	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_IMPLEMENTS_CLAUSE }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -3913,6 +4149,17 @@ impl AstNode for JsPropertyClassMember {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for JsAccessorPropertyClassMember {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JS_ACCESSOR_PROPERTY_CLASS_MEMBER }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for JsMethodClassMember {
 	fn can_cast(kind: SyntaxKind) -> bool { kind == JS_METHOD_CLASS_MEMBER }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -3957,6 +4204,17 @@ impl AstNode for JsEmptyClassMember {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for JsStaticInitializationBlockClassMember {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for TsIndexSignature {
 	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_INDEX_SIGNATURE }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -4001,6 +4259,17 @@ impl AstNode for TsConstructorParam {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for TsDecoratedParameter {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_DECORATED_PARAMETER }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for JsEqualValueClause {
 	fn can_cast(kind: SyntaxKind) -> bool { kind == JS_EQUAL_VALUE_CLAUSE }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -4144,6 +4413,28 @@ impl AstNode for JsVariableDeclarator {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for JsImportAssertion {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JS_IMPORT_ASSERTION }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for JsImportAssertionEntry {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == JS_IMPORT_ASSERTION_ENTRY }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for WildcardImport {
 	fn can_cast(kind: SyntaxKind) -> bool { kind == WILDCARD_IMPORT }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -4573,8 +4864,8 @@ impl AstNode for TsInfer {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl AstNode for TsTupleElement {
-	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TUPLE_ELEMENT }
+impl AstNode for TsNamedTupleTypeElement {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_NAMED_TUPLE_TYPE_ELEMENT }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
 		if Self::can_cast(syntax.kind()) {
 			Some(Self { syntax })
@@ -4584,8 +4875,8 @@ impl AstNode for TsTupleElement {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl AstNode for TsEnumMember {
-	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_ENUM_MEMBER }
+impl AstNode for TsRestTupleTypeElement {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_REST_TUPLE_TYPE_ELEMENT }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
 		if Self::can_cast(syntax.kind()) {
 			Some(Self { syntax })
@@ -4595,8 +4886,8 @@ impl AstNode for TsEnumMember {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl AstNode for TsTemplateElement {
-	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TEMPLATE_ELEMENT }
+impl AstNode for TsOptionalTupleTypeElement {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_OPTIONAL_TUPLE_TYPE_ELEMENT }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
 		if Self::can_cast(syntax.kind()) {
 			Some(Self { syntax })
@@ -4606,8 +4897,8 @@ impl AstNode for TsTemplateElement {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl AstNode for TsMappedTypeReadonly {
-	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_MAPPED_TYPE_READONLY }
+impl AstNode for TsEnumMember {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_ENUM_MEMBER }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
 		if Self::can_cast(syntax.kind()) {
 			Some(Self { syntax })
@@ -4617,8 +4908,8 @@ impl AstNode for TsMappedTypeReadonly {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl AstNode for TsMappedTypeParam {
-	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_MAPPED_TYPE_PARAM }
+impl AstNode for TsTemplateElement {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TEMPLATE_ELEMENT }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
 		if Self::can_cast(syntax.kind()) {
 			Some(Self { syntax })
@@ -4628,9 +4919,42 @@ impl AstNode for TsMappedTypeParam {
 	}
 	fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
-impl AstNode for TsTypeName {
-	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TYPE_NAME }
-	fn cast(syntax: SyntaxNode) -> Option<Self> {
+impl AstNode for TsMappedTypeReadonly {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_MAPPED_TYPE_READONLY }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for TsMappedTypeParam {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_MAPPED_TYPE_PARAM }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for TsMappedTypeAsClause {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_MAPPED_TYPE_AS_CLAUSE }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl AstNode for TsTypeName {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TYPE_NAME }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
 		if Self::can_cast(syntax.kind()) {
 			Some(Self { syntax })
 		} else {
@@ -5142,11 +5466,21 @@ impl From<TsAssertion> for JsAnyExpression {
 impl From<TsConstAssertion> for JsAnyExpression {
 	fn from(node: TsConstAssertion) -> JsAnyExpression { JsAnyExpression::TsConstAssertion(node) }
 }
+impl From<TsInstantiationExpr> for JsAnyExpression {
+	fn from(node: TsInstantiationExpr) -> JsAnyExpression {
+		JsAnyExpression::TsInstantiationExpr(node)
+	}
+}
 impl From<JsUnknownExpression> for JsAnyExpression {
 	fn from(node: JsUnknownExpression) -> JsAnyExpression {
 		JsAnyExpression::JsUnknownExpression(node)
 	}
 }
+impl From<JsAnyLiteralExpression> for JsAnyExpression {
+	fn from(node: JsAnyLiteralExpression) -> JsAnyExpression {
+		JsAnyExpression::JsAnyLiteralExpression(node)
+	}
+}
 impl AstNode for JsAnyExpression {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
@@ -5180,6 +5514,7 @@ impl AstNode for JsAnyExpression {
 			| TS_NON_NULL
 			| TS_ASSERTION
 			| TS_CONST_ASSERTION
+			| TS_INSTANTIATION_EXPR
 			| JS_UNKNOWN_EXPRESSION => true,
 			k if JsAnyLiteralExpression::can_cast(k) => true,
 			_ => false,
@@ -5247,6 +5582,9 @@ impl AstNode for JsAnyExpression {
 			TS_NON_NULL => JsAnyExpression::TsNonNull(TsNonNull { syntax }),
 			TS_ASSERTION => JsAnyExpression::TsAssertion(TsAssertion { syntax }),
 			TS_CONST_ASSERTION => JsAnyExpression::TsConstAssertion(TsConstAssertion { syntax }),
+			TS_INSTANTIATION_EXPR => {
+				JsAnyExpression::TsInstantiationExpr(TsInstantiationExpr { syntax })
+			}
 			JS_UNKNOWN_EXPRESSION => {
 				JsAnyExpression::JsUnknownExpression(JsUnknownExpression { syntax })
 			}
@@ -5293,6 +5631,7 @@ impl AstNode for JsAnyExpression {
 			JsAnyExpression::TsNonNull(it) => &it.syntax,
 			JsAnyExpression::TsAssertion(it) => &it.syntax,
 			JsAnyExpression::TsConstAssertion(it) => &it.syntax,
+			JsAnyExpression::TsInstantiationExpr(it) => &it.syntax,
 			JsAnyExpression::JsUnknownExpression(it) => &it.syntax,
 			JsAnyExpression::JsAnyLiteralExpression(it) => it.syntax(),
 		}
@@ -5301,6 +5640,9 @@ impl AstNode for JsAnyExpression {
 impl From<JsVariableDeclaration> for ForHead {
 	fn from(node: JsVariableDeclaration) -> ForHead { ForHead::JsVariableDeclaration(node) }
 }
+impl From<JsAnyExpression> for ForHead {
+	fn from(node: JsAnyExpression) -> ForHead { ForHead::JsAnyExpression(node) }
+}
 impl AstNode for ForHead {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
@@ -5534,6 +5876,11 @@ impl From<JsFunctionBody> for JsAnyArrowFunctionBody {
 		JsAnyArrowFunctionBody::JsFunctionBody(node)
 	}
 }
+impl From<JsAnyExpression> for JsAnyArrowFunctionBody {
+	fn from(node: JsAnyExpression) -> JsAnyArrowFunctionBody {
+		JsAnyArrowFunctionBody::JsAnyExpression(node)
+	}
+}
 impl AstNode for JsAnyArrowFunctionBody {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
@@ -5567,6 +5914,9 @@ impl From<SpreadElement> for JsAnyArrayElement {
 impl From<JsArrayHole> for JsAnyArrayElement {
 	fn from(node: JsArrayHole) -> JsAnyArrayElement { JsAnyArrayElement::JsArrayHole(node) }
 }
+impl From<JsAnyExpression> for JsAnyArrayElement {
+	fn from(node: JsAnyExpression) -> JsAnyArrayElement { JsAnyArrayElement::JsAnyExpression(node) }
+}
 impl AstNode for JsAnyArrayElement {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
@@ -5596,6 +5946,12 @@ impl AstNode for JsAnyArrayElement {
 		}
 	}
 }
+impl From<Pattern> for PatternOrExpr {
+	fn from(node: Pattern) -> PatternOrExpr { PatternOrExpr::Pattern(node) }
+}
+impl From<JsAnyExpression> for PatternOrExpr {
+	fn from(node: JsAnyExpression) -> PatternOrExpr { PatternOrExpr::JsAnyExpression(node) }
+}
 impl AstNode for PatternOrExpr {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
@@ -5848,6 +6204,11 @@ impl From<JsPropertyClassMember> for JsAnyClassMember {
 		JsAnyClassMember::JsPropertyClassMember(node)
 	}
 }
+impl From<JsAccessorPropertyClassMember> for JsAnyClassMember {
+	fn from(node: JsAccessorPropertyClassMember) -> JsAnyClassMember {
+		JsAnyClassMember::JsAccessorPropertyClassMember(node)
+	}
+}
 impl From<JsMethodClassMember> for JsAnyClassMember {
 	fn from(node: JsMethodClassMember) -> JsAnyClassMember {
 		JsAnyClassMember::JsMethodClassMember(node)
@@ -5868,6 +6229,11 @@ impl From<JsEmptyClassMember> for JsAnyClassMember {
 		JsAnyClassMember::JsEmptyClassMember(node)
 	}
 }
+impl From<JsStaticInitializationBlockClassMember> for JsAnyClassMember {
+	fn from(node: JsStaticInitializationBlockClassMember) -> JsAnyClassMember {
+		JsAnyClassMember::JsStaticInitializationBlockClassMember(node)
+	}
+}
 impl From<TsIndexSignature> for JsAnyClassMember {
 	fn from(node: TsIndexSignature) -> JsAnyClassMember { JsAnyClassMember::TsIndexSignature(node) }
 }
@@ -5880,10 +6246,12 @@ impl AstNode for JsAnyClassMember {
 			kind,
 			JS_CONSTRUCTOR_CLASS_MEMBER
 				| JS_PROPERTY_CLASS_MEMBER
+				| JS_ACCESSOR_PROPERTY_CLASS_MEMBER
 				| JS_METHOD_CLASS_MEMBER
 				| JS_GETTER_CLASS_MEMBER
 				| JS_SETTER_CLASS_MEMBER
 				| JS_EMPTY_CLASS_MEMBER
+				| JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
 				| TS_INDEX_SIGNATURE
 				| JS_UNKNOWN_MEMBER
 		)
@@ -5896,6 +6264,11 @@ impl AstNode for JsAnyClassMember {
 			JS_PROPERTY_CLASS_MEMBER => {
 				JsAnyClassMember::JsPropertyClassMember(JsPropertyClassMember { syntax })
 			}
+			JS_ACCESSOR_PROPERTY_CLASS_MEMBER => {
+				JsAnyClassMember::JsAccessorPropertyClassMember(JsAccessorPropertyClassMember {
+					syntax,
+				})
+			}
 			JS_METHOD_CLASS_MEMBER => {
 				JsAnyClassMember::JsMethodClassMember(JsMethodClassMember { syntax })
 			}
@@ -5908,6 +6281,11 @@ impl AstNode for JsAnyClassMember {
 			JS_EMPTY_CLASS_MEMBER => {
 				JsAnyClassMember::JsEmptyClassMember(JsEmptyClassMember { syntax })
 			}
+			JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER => {
+				JsAnyClassMember::JsStaticInitializationBlockClassMember(
+					JsStaticInitializationBlockClassMember { syntax },
+				)
+			}
 			TS_INDEX_SIGNATURE => JsAnyClassMember::TsIndexSignature(TsIndexSignature { syntax }),
 			JS_UNKNOWN_MEMBER => JsAnyClassMember::JsUnknownMember(JsUnknownMember { syntax }),
 			_ => return None,
@@ -5918,10 +6296,12 @@ impl AstNode for JsAnyClassMember {
 		match self {
 			JsAnyClassMember::JsConstructorClassMember(it) => &it.syntax,
 			JsAnyClassMember::JsPropertyClassMember(it) => &it.syntax,
+			JsAnyClassMember::JsAccessorPropertyClassMember(it) => &it.syntax,
 			JsAnyClassMember::JsMethodClassMember(it) => &it.syntax,
 			JsAnyClassMember::JsGetterClassMember(it) => &it.syntax,
 			JsAnyClassMember::JsSetterClassMember(it) => &it.syntax,
 			JsAnyClassMember::JsEmptyClassMember(it) => &it.syntax,
+			JsAnyClassMember::JsStaticInitializationBlockClassMember(it) => &it.syntax,
 			JsAnyClassMember::TsIndexSignature(it) => &it.syntax,
 			JsAnyClassMember::JsUnknownMember(it) => &it.syntax,
 		}
@@ -5977,10 +6357,18 @@ impl From<TsConstructorParam> for JsAnyConstructorParameter {
 		JsAnyConstructorParameter::TsConstructorParam(node)
 	}
 }
+impl From<TsDecoratedParameter> for JsAnyConstructorParameter {
+	fn from(node: TsDecoratedParameter) -> JsAnyConstructorParameter {
+		JsAnyConstructorParameter::TsDecoratedParameter(node)
+	}
+}
+impl From<Pattern> for JsAnyConstructorParameter {
+	fn from(node: Pattern) -> JsAnyConstructorParameter { JsAnyConstructorParameter::Pattern(node) }
+}
 impl AstNode for JsAnyConstructorParameter {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
-			TS_CONSTRUCTOR_PARAM => true,
+			TS_CONSTRUCTOR_PARAM | TS_DECORATED_PARAMETER => true,
 			k if Pattern::can_cast(k) => true,
 			_ => false,
 		}
@@ -5990,6 +6378,9 @@ impl AstNode for JsAnyConstructorParameter {
 			TS_CONSTRUCTOR_PARAM => {
 				JsAnyConstructorParameter::TsConstructorParam(TsConstructorParam { syntax })
 			}
+			TS_DECORATED_PARAMETER => {
+				JsAnyConstructorParameter::TsDecoratedParameter(TsDecoratedParameter { syntax })
+			}
 			_ => {
 				if let Some(pattern) = Pattern::cast(syntax) {
 					return Some(JsAnyConstructorParameter::Pattern(pattern));
@@ -6002,6 +6393,7 @@ impl AstNode for JsAnyConstructorParameter {
 	fn syntax(&self) -> &SyntaxNode {
 		match self {
 			JsAnyConstructorParameter::TsConstructorParam(it) => &it.syntax,
+			JsAnyConstructorParameter::TsDecoratedParameter(it) => &it.syntax,
 			JsAnyConstructorParameter::Pattern(it) => it.syntax(),
 		}
 	}
@@ -6404,10 +6796,18 @@ impl AstNode for JsAnyExportDeclaration {
 impl From<JsRestParameter> for JsAnyParameter {
 	fn from(node: JsRestParameter) -> JsAnyParameter { JsAnyParameter::JsRestParameter(node) }
 }
+impl From<TsDecoratedParameter> for JsAnyParameter {
+	fn from(node: TsDecoratedParameter) -> JsAnyParameter {
+		JsAnyParameter::TsDecoratedParameter(node)
+	}
+}
+impl From<Pattern> for JsAnyParameter {
+	fn from(node: Pattern) -> JsAnyParameter { JsAnyParameter::Pattern(node) }
+}
 impl AstNode for JsAnyParameter {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
-			JS_REST_PARAMETER => true,
+			JS_REST_PARAMETER | TS_DECORATED_PARAMETER => true,
 			k if Pattern::can_cast(k) => true,
 			_ => false,
 		}
@@ -6415,6 +6815,9 @@ impl AstNode for JsAnyParameter {
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
 		let res = match syntax.kind() {
 			JS_REST_PARAMETER => JsAnyParameter::JsRestParameter(JsRestParameter { syntax }),
+			TS_DECORATED_PARAMETER => {
+				JsAnyParameter::TsDecoratedParameter(TsDecoratedParameter { syntax })
+			}
 			_ => {
 				if let Some(pattern) = Pattern::cast(syntax) {
 					return Some(JsAnyParameter::Pattern(pattern));
@@ -6427,6 +6830,7 @@ impl AstNode for JsAnyParameter {
 	fn syntax(&self) -> &SyntaxNode {
 		match self {
 			JsAnyParameter::JsRestParameter(it) => &it.syntax,
+			JsAnyParameter::TsDecoratedParameter(it) => &it.syntax,
 			JsAnyParameter::Pattern(it) => it.syntax(),
 		}
 	}
@@ -6434,6 +6838,9 @@ impl AstNode for JsAnyParameter {
 impl From<TsExternalModuleRef> for TsModuleRef {
 	fn from(node: TsExternalModuleRef) -> TsModuleRef { TsModuleRef::TsExternalModuleRef(node) }
 }
+impl From<TsEntityName> for TsModuleRef {
+	fn from(node: TsEntityName) -> TsModuleRef { TsModuleRef::TsEntityName(node) }
+}
 impl AstNode for TsModuleRef {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		match kind {
@@ -6486,6 +6893,65 @@ impl AstNode for TsEntityName {
 		}
 	}
 }
+impl From<TsNamedTupleTypeElement> for TsAnyTupleTypeElement {
+	fn from(node: TsNamedTupleTypeElement) -> TsAnyTupleTypeElement {
+		TsAnyTupleTypeElement::TsNamedTupleTypeElement(node)
+	}
+}
+impl From<TsRestTupleTypeElement> for TsAnyTupleTypeElement {
+	fn from(node: TsRestTupleTypeElement) -> TsAnyTupleTypeElement {
+		TsAnyTupleTypeElement::TsRestTupleTypeElement(node)
+	}
+}
+impl From<TsOptionalTupleTypeElement> for TsAnyTupleTypeElement {
+	fn from(node: TsOptionalTupleTypeElement) -> TsAnyTupleTypeElement {
+		TsAnyTupleTypeElement::TsOptionalTupleTypeElement(node)
+	}
+}
+impl From<TsType> for TsAnyTupleTypeElement {
+	fn from(node: TsType) -> TsAnyTupleTypeElement { TsAnyTupleTypeElement::TsType(node) }
+}
+impl AstNode for TsAnyTupleTypeElement {
+	fn can_cast(kind: SyntaxKind) -> bool {
+		match kind {
+			TS_NAMED_TUPLE_TYPE_ELEMENT
+			| TS_REST_TUPLE_TYPE_ELEMENT
+			| TS_OPTIONAL_TUPLE_TYPE_ELEMENT => true,
+			k if TsType::can_cast(k) => true,
+			_ => false,
+		}
+	}
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		let res = match syntax.kind() {
+			TS_NAMED_TUPLE_TYPE_ELEMENT => {
+				TsAnyTupleTypeElement::TsNamedTupleTypeElement(TsNamedTupleTypeElement { syntax })
+			}
+			TS_REST_TUPLE_TYPE_ELEMENT => {
+				TsAnyTupleTypeElement::TsRestTupleTypeElement(TsRestTupleTypeElement { syntax })
+			}
+			TS_OPTIONAL_TUPLE_TYPE_ELEMENT => {
+				TsAnyTupleTypeElement::TsOptionalTupleTypeElement(TsOptionalTupleTypeElement {
+					syntax,
+				})
+			}
+			_ => {
+				if let Some(ts_type) = TsType::cast(syntax) {
+					return Some(TsAnyTupleTypeElement::TsType(ts_type));
+				}
+				return None;
+			}
+		};
+		Some(res)
+	}
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			TsAnyTupleTypeElement::TsNamedTupleTypeElement(it) => &it.syntax,
+			TsAnyTupleTypeElement::TsRestTupleTypeElement(it) => &it.syntax,
+			TsAnyTupleTypeElement::TsOptionalTupleTypeElement(it) => &it.syntax,
+			TsAnyTupleTypeElement::TsType(it) => it.syntax(),
+		}
+	}
+}
 impl From<TsThis> for TsThisOrMore {
 	fn from(node: TsThis) -> TsThisOrMore { TsThisOrMore::TsThis(node) }
 }
@@ -6712,6 +7178,11 @@ impl std::fmt::Display for TsEntityName {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for TsAnyTupleTypeElement {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsThisOrMore {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -7152,6 +7623,11 @@ impl std::fmt::Display for TsConstAssertion {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for TsInstantiationExpr {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsTypeArgs {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -7257,6 +7733,11 @@ impl std::fmt::Display for Name {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for TsDecorator {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsImplementsClause {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -7287,6 +7768,11 @@ impl std::fmt::Display for JsPropertyClassMember {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for JsAccessorPropertyClassMember {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for JsMethodClassMember {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -7307,6 +7793,11 @@ impl std::fmt::Display for JsEmptyClassMember {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for JsStaticInitializationBlockClassMember {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsIndexSignature {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -7327,6 +7818,11 @@ impl std::fmt::Display for TsConstructorParam {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for TsDecoratedParameter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for JsEqualValueClause {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -7392,6 +7888,16 @@ impl std::fmt::Display for JsVariableDeclarator {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for JsImportAssertion {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for JsImportAssertionEntry {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for WildcardImport {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -7587,7 +8093,17 @@ impl std::fmt::Display for TsInfer {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
-impl std::fmt::Display for TsTupleElement {
+impl std::fmt::Display for TsNamedTupleTypeElement {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for TsRestTupleTypeElement {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
+impl std::fmt::Display for TsOptionalTupleTypeElement {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
@@ -7612,6 +8128,11 @@ impl std::fmt::Display for TsMappedTypeParam {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for TsMappedTypeAsClause {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsTypeName {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -7667,3 +8188,1229 @@ impl std::fmt::Display for TsQualifiedPath {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl From<JsAnyStatement> for SyntaxNode {
+	fn from(node: JsAnyStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyExpression> for SyntaxNode {
+	fn from(node: JsAnyExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ForHead> for SyntaxNode {
+	fn from(node: ForHead) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnySwitchClause> for SyntaxNode {
+	fn from(node: JsAnySwitchClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<Pattern> for SyntaxNode {
+	fn from(node: Pattern) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyLiteralExpression> for SyntaxNode {
+	fn from(node: JsAnyLiteralExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyArrowFunctionParameters> for SyntaxNode {
+	fn from(node: JsAnyArrowFunctionParameters) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyArrowFunctionBody> for SyntaxNode {
+	fn from(node: JsAnyArrowFunctionBody) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyArrayElement> for SyntaxNode {
+	fn from(node: JsAnyArrayElement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<PatternOrExpr> for SyntaxNode {
+	fn from(node: PatternOrExpr) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyReferenceMember> for SyntaxNode {
+	fn from(node: JsAnyReferenceMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyObjectMemberName> for SyntaxNode {
+	fn from(node: JsAnyObjectMemberName) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyObjectMember> for SyntaxNode {
+	fn from(node: JsAnyObjectMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<PropName> for SyntaxNode {
+	fn from(node: PropName) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyClassMember> for SyntaxNode {
+	fn from(node: JsAnyClassMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyClassMemberName> for SyntaxNode {
+	fn from(node: JsAnyClassMemberName) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyConstructorParameter> for SyntaxNode {
+	fn from(node: JsAnyConstructorParameter) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ObjectPatternProp> for SyntaxNode {
+	fn from(node: ObjectPatternProp) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsType> for SyntaxNode {
+	fn from(node: TsType) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ImportClause> for SyntaxNode {
+	fn from(node: ImportClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<DefaultDecl> for SyntaxNode {
+	fn from(node: DefaultDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyExportDeclaration> for SyntaxNode {
+	fn from(node: JsAnyExportDeclaration) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAnyParameter> for SyntaxNode {
+	fn from(node: JsAnyParameter) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsModuleRef> for SyntaxNode {
+	fn from(node: TsModuleRef) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsEntityName> for SyntaxNode {
+	fn from(node: TsEntityName) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsAnyTupleTypeElement> for SyntaxNode {
+	fn from(node: TsAnyTupleTypeElement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsThisOrMore> for SyntaxNode {
+	fn from(node: TsThisOrMore) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTypeElement> for SyntaxNode {
+	fn from(node: TsTypeElement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsNamespaceBody> for SyntaxNode {
+	fn from(node: TsNamespaceBody) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsUnknownStatement> for SyntaxNode {
+	fn from(node: JsUnknownStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsUnknownExpression> for SyntaxNode {
+	fn from(node: JsUnknownExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsUnknownPattern> for SyntaxNode {
+	fn from(node: JsUnknownPattern) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsUnknownMember> for SyntaxNode {
+	fn from(node: JsUnknownMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsUnknownBinding> for SyntaxNode {
+	fn from(node: JsUnknownBinding) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsUnknownAssignmentTarget> for SyntaxNode {
+	fn from(node: JsUnknownAssignmentTarget) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<Ident> for SyntaxNode {
+	fn from(node: Ident) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsRoot> for SyntaxNode {
+	fn from(node: JsRoot) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsDirective> for SyntaxNode {
+	fn from(node: JsDirective) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsBlockStatement> for SyntaxNode {
+	fn from(node: JsBlockStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsEmptyStatement> for SyntaxNode {
+	fn from(node: JsEmptyStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsExpressionStatement> for SyntaxNode {
+	fn from(node: JsExpressionStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsIfStatement> for SyntaxNode {
+	fn from(node: JsIfStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsDoWhileStatement> for SyntaxNode {
+	fn from(node: JsDoWhileStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsWhileStatement> for SyntaxNode {
+	fn from(node: JsWhileStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ForStmt> for SyntaxNode {
+	fn from(node: ForStmt) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ForInStmt> for SyntaxNode {
+	fn from(node: ForInStmt) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ForOfStmt> for SyntaxNode {
+	fn from(node: ForOfStmt) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsContinueStatement> for SyntaxNode {
+	fn from(node: JsContinueStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsBreakStatement> for SyntaxNode {
+	fn from(node: JsBreakStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsReturnStatement> for SyntaxNode {
+	fn from(node: JsReturnStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsWithStatement> for SyntaxNode {
+	fn from(node: JsWithStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsLabeledStatement> for SyntaxNode {
+	fn from(node: JsLabeledStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsSwitchStatement> for SyntaxNode {
+	fn from(node: JsSwitchStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsThrowStatement> for SyntaxNode {
+	fn from(node: JsThrowStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsTryStatement> for SyntaxNode {
+	fn from(node: JsTryStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsTryFinallyStatement> for SyntaxNode {
+	fn from(node: JsTryFinallyStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsDebuggerStatement> for SyntaxNode {
+	fn from(node: JsDebuggerStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsFunctionDeclaration> for SyntaxNode {
+	fn from(node: JsFunctionDeclaration) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsClassDeclaration> for SyntaxNode {
+	fn from(node: JsClassDeclaration) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsVariableDeclarationStatement> for SyntaxNode {
+	fn from(node: JsVariableDeclarationStatement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsEnum> for SyntaxNode {
+	fn from(node: TsEnum) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTypeAliasDecl> for SyntaxNode {
+	fn from(node: TsTypeAliasDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsNamespaceDecl> for SyntaxNode {
+	fn from(node: TsNamespaceDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsModuleDecl> for SyntaxNode {
+	fn from(node: TsModuleDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsInterfaceDecl> for SyntaxNode {
+	fn from(node: TsInterfaceDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ImportDecl> for SyntaxNode {
+	fn from(node: ImportDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ExportNamed> for SyntaxNode {
+	fn from(node: ExportNamed) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ExportDefaultDecl> for SyntaxNode {
+	fn from(node: ExportDefaultDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ExportDefaultExpr> for SyntaxNode {
+	fn from(node: ExportDefaultExpr) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ExportWildcard> for SyntaxNode {
+	fn from(node: ExportWildcard) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ExportDecl> for SyntaxNode {
+	fn from(node: ExportDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsImportEqualsDecl> for SyntaxNode {
+	fn from(node: TsImportEqualsDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsExportAssignment> for SyntaxNode {
+	fn from(node: TsExportAssignment) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsNamespaceExportDecl> for SyntaxNode {
+	fn from(node: TsNamespaceExportDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsElseClause> for SyntaxNode {
+	fn from(node: JsElseClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ForStmtInit> for SyntaxNode {
+	fn from(node: ForStmtInit) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ForStmtTest> for SyntaxNode {
+	fn from(node: ForStmtTest) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ForStmtUpdate> for SyntaxNode {
+	fn from(node: ForStmtUpdate) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsVariableDeclaration> for SyntaxNode {
+	fn from(node: JsVariableDeclaration) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsCaseClause> for SyntaxNode {
+	fn from(node: JsCaseClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsDefaultClause> for SyntaxNode {
+	fn from(node: JsDefaultClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsCatchClause> for SyntaxNode {
+	fn from(node: JsCatchClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsFinallyClause> for SyntaxNode {
+	fn from(node: JsFinallyClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsCatchDeclaration> for SyntaxNode {
+	fn from(node: JsCatchDeclaration) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsArrayExpression> for SyntaxNode {
+	fn from(node: JsArrayExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsArrowFunctionExpression> for SyntaxNode {
+	fn from(node: JsArrowFunctionExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAwaitExpression> for SyntaxNode {
+	fn from(node: JsAwaitExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsBinaryExpression> for SyntaxNode {
+	fn from(node: JsBinaryExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsClassExpression> for SyntaxNode {
+	fn from(node: JsClassExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsConditionalExpression> for SyntaxNode {
+	fn from(node: JsConditionalExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsComputedMemberExpression> for SyntaxNode {
+	fn from(node: JsComputedMemberExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsFunctionExpression> for SyntaxNode {
+	fn from(node: JsFunctionExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsImportCallExpression> for SyntaxNode {
+	fn from(node: JsImportCallExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsLogicalExpression> for SyntaxNode {
+	fn from(node: JsLogicalExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsObjectExpression> for SyntaxNode {
+	fn from(node: JsObjectExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsParenthesizedExpression> for SyntaxNode {
+	fn from(node: JsParenthesizedExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsReferenceIdentifierExpression> for SyntaxNode {
+	fn from(node: JsReferenceIdentifierExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsSequenceExpression> for SyntaxNode {
+	fn from(node: JsSequenceExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsStaticMemberExpression> for SyntaxNode {
+	fn from(node: JsStaticMemberExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsSuperExpression> for SyntaxNode {
+	fn from(node: JsSuperExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsThisExpression> for SyntaxNode {
+	fn from(node: JsThisExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsUnaryExpression> for SyntaxNode {
+	fn from(node: JsUnaryExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsPreUpdateExpression> for SyntaxNode {
+	fn from(node: JsPreUpdateExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsPostUpdateExpression> for SyntaxNode {
+	fn from(node: JsPostUpdateExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsYieldExpression> for SyntaxNode {
+	fn from(node: JsYieldExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<Template> for SyntaxNode {
+	fn from(node: Template) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<NewExpr> for SyntaxNode {
+	fn from(node: NewExpr) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<CallExpr> for SyntaxNode {
+	fn from(node: CallExpr) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<AssignExpr> for SyntaxNode {
+	fn from(node: AssignExpr) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<NewTarget> for SyntaxNode {
+	fn from(node: NewTarget) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ImportMeta> for SyntaxNode {
+	fn from(node: ImportMeta) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsNonNull> for SyntaxNode {
+	fn from(node: TsNonNull) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsAssertion> for SyntaxNode {
+	fn from(node: TsAssertion) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsConstAssertion> for SyntaxNode {
+	fn from(node: TsConstAssertion) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsInstantiationExpr> for SyntaxNode {
+	fn from(node: TsInstantiationExpr) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTypeArgs> for SyntaxNode {
+	fn from(node: TsTypeArgs) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ArgList> for SyntaxNode {
+	fn from(node: ArgList) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsIdentifierBinding> for SyntaxNode {
+	fn from(node: JsIdentifierBinding) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTypeParams> for SyntaxNode {
+	fn from(node: TsTypeParams) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsParameterList> for SyntaxNode {
+	fn from(node: JsParameterList) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTypeAnnotation> for SyntaxNode {
+	fn from(node: TsTypeAnnotation) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsFunctionBody> for SyntaxNode {
+	fn from(node: JsFunctionBody) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<SpreadElement> for SyntaxNode {
+	fn from(node: SpreadElement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsArrayHole> for SyntaxNode {
+	fn from(node: JsArrayHole) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsLiteralMemberName> for SyntaxNode {
+	fn from(node: JsLiteralMemberName) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsComputedMemberName> for SyntaxNode {
+	fn from(node: JsComputedMemberName) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsPropertyObjectMember> for SyntaxNode {
+	fn from(node: JsPropertyObjectMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsMethodObjectMember> for SyntaxNode {
+	fn from(node: JsMethodObjectMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsGetterObjectMember> for SyntaxNode {
+	fn from(node: JsGetterObjectMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsSetterObjectMember> for SyntaxNode {
+	fn from(node: JsSetterObjectMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<InitializedProp> for SyntaxNode {
+	fn from(node: InitializedProp) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsShorthandPropertyObjectMember> for SyntaxNode {
+	fn from(node: JsShorthandPropertyObjectMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsSpread> for SyntaxNode {
+	fn from(node: JsSpread) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsStringLiteralExpression> for SyntaxNode {
+	fn from(node: JsStringLiteralExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsNumberLiteralExpression> for SyntaxNode {
+	fn from(node: JsNumberLiteralExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<Name> for SyntaxNode {
+	fn from(node: Name) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsDecorator> for SyntaxNode {
+	fn from(node: TsDecorator) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsImplementsClause> for SyntaxNode {
+	fn from(node: TsImplementsClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsExtendsClause> for SyntaxNode {
+	fn from(node: JsExtendsClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsExprWithTypeArgs> for SyntaxNode {
+	fn from(node: TsExprWithTypeArgs) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsPrivateClassMemberName> for SyntaxNode {
+	fn from(node: JsPrivateClassMemberName) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsConstructorClassMember> for SyntaxNode {
+	fn from(node: JsConstructorClassMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsPropertyClassMember> for SyntaxNode {
+	fn from(node: JsPropertyClassMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsAccessorPropertyClassMember> for SyntaxNode {
+	fn from(node: JsAccessorPropertyClassMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsMethodClassMember> for SyntaxNode {
+	fn from(node: JsMethodClassMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsGetterClassMember> for SyntaxNode {
+	fn from(node: JsGetterClassMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsSetterClassMember> for SyntaxNode {
+	fn from(node: JsSetterClassMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsEmptyClassMember> for SyntaxNode {
+	fn from(node: JsEmptyClassMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsStaticInitializationBlockClassMember> for SyntaxNode {
+	fn from(node: JsStaticInitializationBlockClassMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsIndexSignature> for SyntaxNode {
+	fn from(node: TsIndexSignature) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsAccessibility> for SyntaxNode {
+	fn from(node: TsAccessibility) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsConstructorParameterList> for SyntaxNode {
+	fn from(node: JsConstructorParameterList) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsConstructorParam> for SyntaxNode {
+	fn from(node: TsConstructorParam) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsDecoratedParameter> for SyntaxNode {
+	fn from(node: TsDecoratedParameter) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsEqualValueClause> for SyntaxNode {
+	fn from(node: JsEqualValueClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsBigIntLiteralExpression> for SyntaxNode {
+	fn from(node: JsBigIntLiteralExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsBooleanLiteralExpression> for SyntaxNode {
+	fn from(node: JsBooleanLiteralExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsNullLiteralExpression> for SyntaxNode {
+	fn from(node: JsNullLiteralExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsRegexLiteralExpression> for SyntaxNode {
+	fn from(node: JsRegexLiteralExpression) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<SinglePattern> for SyntaxNode {
+	fn from(node: SinglePattern) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<RestPattern> for SyntaxNode {
+	fn from(node: RestPattern) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<AssignPattern> for SyntaxNode {
+	fn from(node: AssignPattern) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ObjectPattern> for SyntaxNode {
+	fn from(node: ObjectPattern) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ArrayPattern> for SyntaxNode {
+	fn from(node: ArrayPattern) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ExprPattern> for SyntaxNode {
+	fn from(node: ExprPattern) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<KeyValuePattern> for SyntaxNode {
+	fn from(node: KeyValuePattern) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsVariableDeclarator> for SyntaxNode {
+	fn from(node: JsVariableDeclarator) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsImportAssertion> for SyntaxNode {
+	fn from(node: JsImportAssertion) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsImportAssertionEntry> for SyntaxNode {
+	fn from(node: JsImportAssertionEntry) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<WildcardImport> for SyntaxNode {
+	fn from(node: WildcardImport) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<NamedImports> for SyntaxNode {
+	fn from(node: NamedImports) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<ImportStringSpecifier> for SyntaxNode {
+	fn from(node: ImportStringSpecifier) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<Specifier> for SyntaxNode {
+	fn from(node: Specifier) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsReferenceIdentifierMember> for SyntaxNode {
+	fn from(node: JsReferenceIdentifierMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsReferencePrivateMember> for SyntaxNode {
+	fn from(node: JsReferencePrivateMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<JsRestParameter> for SyntaxNode {
+	fn from(node: JsRestParameter) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsExternalModuleRef> for SyntaxNode {
+	fn from(node: TsExternalModuleRef) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsAny> for SyntaxNode {
+	fn from(node: TsAny) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsUnknown> for SyntaxNode {
+	fn from(node: TsUnknown) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsNumber> for SyntaxNode {
+	fn from(node: TsNumber) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsObject> for SyntaxNode {
+	fn from(node: TsObject) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsBoolean> for SyntaxNode {
+	fn from(node: TsBoolean) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsBigint> for SyntaxNode {
+	fn from(node: TsBigint) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsString> for SyntaxNode {
+	fn from(node: TsString) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsSymbol> for SyntaxNode {
+	fn from(node: TsSymbol) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsVoid> for SyntaxNode {
+	fn from(node: TsVoid) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsUndefined> for SyntaxNode {
+	fn from(node: TsUndefined) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsNull> for SyntaxNode {
+	fn from(node: TsNull) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsNever> for SyntaxNode {
+	fn from(node: TsNever) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsThis> for SyntaxNode {
+	fn from(node: TsThis) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsLiteral> for SyntaxNode {
+	fn from(node: TsLiteral) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsPredicate> for SyntaxNode {
+	fn from(node: TsPredicate) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTuple> for SyntaxNode {
+	fn from(node: TsTuple) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsParen> for SyntaxNode {
+	fn from(node: TsParen) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTypeRef> for SyntaxNode {
+	fn from(node: TsTypeRef) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTemplate> for SyntaxNode {
+	fn from(node: TsTemplate) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsMappedType> for SyntaxNode {
+	fn from(node: TsMappedType) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsImport> for SyntaxNode {
+	fn from(node: TsImport) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsArray> for SyntaxNode {
+	fn from(node: TsArray) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsIndexedArray> for SyntaxNode {
+	fn from(node: TsIndexedArray) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTypeOperator> for SyntaxNode {
+	fn from(node: TsTypeOperator) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsIntersection> for SyntaxNode {
+	fn from(node: TsIntersection) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsUnion> for SyntaxNode {
+	fn from(node: TsUnion) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsFnType> for SyntaxNode {
+	fn from(node: TsFnType) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsConstructorType> for SyntaxNode {
+	fn from(node: TsConstructorType) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsConditionalType> for SyntaxNode {
+	fn from(node: TsConditionalType) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsObjectType> for SyntaxNode {
+	fn from(node: TsObjectType) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsInfer> for SyntaxNode {
+	fn from(node: TsInfer) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsNamedTupleTypeElement> for SyntaxNode {
+	fn from(node: TsNamedTupleTypeElement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsRestTupleTypeElement> for SyntaxNode {
+	fn from(node: TsRestTupleTypeElement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsOptionalTupleTypeElement> for SyntaxNode {
+	fn from(node: TsOptionalTupleTypeElement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsEnumMember> for SyntaxNode {
+	fn from(node: TsEnumMember) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTemplateElement> for SyntaxNode {
+	fn from(node: TsTemplateElement) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsMappedTypeReadonly> for SyntaxNode {
+	fn from(node: TsMappedTypeReadonly) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsMappedTypeParam> for SyntaxNode {
+	fn from(node: TsMappedTypeParam) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsMappedTypeAsClause> for SyntaxNode {
+	fn from(node: TsMappedTypeAsClause) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTypeName> for SyntaxNode {
+	fn from(node: TsTypeName) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsExtends> for SyntaxNode {
+	fn from(node: TsExtends) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsModuleBlock> for SyntaxNode {
+	fn from(node: TsModuleBlock) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsTypeParam> for SyntaxNode {
+	fn from(node: TsTypeParam) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsConstraint> for SyntaxNode {
+	fn from(node: TsConstraint) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsDefault> for SyntaxNode {
+	fn from(node: TsDefault) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsCallSignatureDecl> for SyntaxNode {
+	fn from(node: TsCallSignatureDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsConstructSignatureDecl> for SyntaxNode {
+	fn from(node: TsConstructSignatureDecl) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsPropertySignature> for SyntaxNode {
+	fn from(node: TsPropertySignature) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsMethodSignature> for SyntaxNode {
+	fn from(node: TsMethodSignature) -> SyntaxNode { node.syntax().clone() }
+}
+impl From<TsQualifiedPath> for SyntaxNode {
+	fn from(node: TsQualifiedPath) -> SyntaxNode { node.syntax().clone() }
+}
+#[doc = r" The name of the slot `index` of the nodes of the kind `kind`, see [rome_rowan::Language::slot_name]"]
+pub(crate) fn slot_name(kind: SyntaxKind, index: usize) -> Option<&'static str> {
+	let names: &[&str] = match kind {
+		IDENT => &["ident_token"],
+		JS_ROOT => &["interpreter_token", "directives", "statements"],
+		JS_DIRECTIVE => &["value_token", "semicolon_token"],
+		JS_BLOCK_STATEMENT => &["l_curly_token", "statements", "r_curly_token"],
+		JS_EMPTY_STATEMENT => &["semicolon_token"],
+		JS_EXPRESSION_STATEMENT => &["expression", "semicolon_token"],
+		JS_IF_STATEMENT => &[
+			"if_token",
+			"l_paren_token",
+			"test",
+			"r_paren_token",
+			"consequent",
+			"else_clause",
+		],
+		JS_DO_WHILE_STATEMENT => &[
+			"do_token",
+			"body",
+			"while_token",
+			"l_paren_token",
+			"test",
+			"r_paren_token",
+			"semicolon_token",
+		],
+		JS_WHILE_STATEMENT => &[
+			"while_token",
+			"l_paren_token",
+			"test",
+			"r_paren_token",
+			"body",
+		],
+		FOR_STMT => &[
+			"for_token",
+			"l_paren_token",
+			"init",
+			"test",
+			"update",
+			"r_paren_token",
+			"cons",
+		],
+		FOR_IN_STMT => &[
+			"for_token",
+			"l_paren_token",
+			"left",
+			"in_token",
+			"right",
+			"r_paren_token",
+			"cons",
+		],
+		FOR_OF_STMT => &[
+			"for_token",
+			"l_paren_token",
+			"left",
+			"of_token",
+			"right",
+			"r_paren_token",
+			"cons",
+		],
+		JS_CONTINUE_STATEMENT => &["continue_token", "label_token", "semicolon_token"],
+		JS_BREAK_STATEMENT => &["break_token", "label_token", "semicolon_token"],
+		JS_RETURN_STATEMENT => &["return_token", "argument", "semicolon_token"],
+		JS_WITH_STATEMENT => &[
+			"with_token",
+			"l_paren_token",
+			"object",
+			"r_paren_token",
+			"body",
+		],
+		JS_LABELED_STATEMENT => &["label_token", "colon_token", "body"],
+		JS_SWITCH_STATEMENT => &[
+			"switch_token",
+			"l_paren_token",
+			"discriminant",
+			"r_paren_token",
+			"l_curly_token",
+			"cases",
+			"r_curly_token",
+		],
+		JS_THROW_STATEMENT => &["throw_token", "argument", "semicolon_token"],
+		JS_TRY_STATEMENT => &["try_token", "body", "catch_clause"],
+		JS_TRY_FINALLY_STATEMENT => &["try_token", "body", "catch_clause", "finally_clause"],
+		JS_DEBUGGER_STATEMENT => &["debugger_token", "semicolon_token"],
+		JS_FUNCTION_DECLARATION => &[
+			"declare_token",
+			"async_token",
+			"function_token",
+			"star_token",
+			"id",
+			"type_parameters",
+			"parameter_list",
+			"return_type",
+			"body",
+		],
+		JS_CLASS_DECLARATION => &[
+			"decorators",
+			"declare_token",
+			"abstract_token",
+			"class_token",
+			"id",
+			"implements_clause",
+			"extends_clause",
+			"l_curly_token",
+			"members",
+			"r_curly_token",
+		],
+		JS_VARIABLE_DECLARATION_STATEMENT => &["declaration", "semicolon_token"],
+		TS_ENUM => &[
+			"const_token",
+			"enum_token",
+			"ident",
+			"l_curly_token",
+			"members",
+			"r_curly_token",
+		],
+		TS_TYPE_ALIAS_DECL => &["type_token", "type_params", "eq_token", "ty"],
+		TS_NAMESPACE_DECL => &["declare_token", "ident", "dot_token", "body"],
+		TS_MODULE_DECL => &[
+			"declare_token",
+			"global_token",
+			"module_token",
+			"dot_token",
+			"ident",
+			"body",
+		],
+		TS_INTERFACE_DECL => &[
+			"declare_token",
+			"interface_token",
+			"type_params",
+			"extends_token",
+			"extends",
+			"l_curly_token",
+			"members",
+			"r_curly_token",
+		],
+		IMPORT_DECL => &[
+			"import_token",
+			"imports",
+			"type_token",
+			"from_token",
+			"source_token",
+			"assertion",
+			"semicolon_token",
+		],
+		EXPORT_NAMED => &[
+			"export_token",
+			"type_token",
+			"from_token",
+			"l_curly_token",
+			"specifiers",
+			"r_curly_token",
+			"assertion",
+		],
+		EXPORT_DEFAULT_DECL => &["export_token", "default_token", "type_token", "decl"],
+		EXPORT_DEFAULT_EXPR => &["export_token", "type_token", "default_token", "expr"],
+		EXPORT_WILDCARD => &[
+			"export_token",
+			"type_token",
+			"star_token",
+			"as_token",
+			"alias",
+			"from_token",
+			"source_token",
+			"assertion",
+		],
+		EXPORT_DECL => &["export_token", "type_token", "decl"],
+		TS_IMPORT_EQUALS_DECL => &[
+			"import_token",
+			"export_token",
+			"ident",
+			"eq_token",
+			"module",
+			"semicolon_token",
+		],
+		TS_EXPORT_ASSIGNMENT => &["export_token", "eq_token", "expr", "semicolon_token"],
+		TS_NAMESPACE_EXPORT_DECL => &[
+			"export_token",
+			"as_token",
+			"namespace_token",
+			"ident",
+			"semicolon_token",
+		],
+		JS_ELSE_CLAUSE => &["else_token", "alternate"],
+		FOR_STMT_INIT => &["inner", "semicolon_token"],
+		FOR_STMT_TEST => &["expr", "semicolon_token"],
+		FOR_STMT_UPDATE => &["expr"],
+		JS_VARIABLE_DECLARATION => &["kind_token", "declarators"],
+		JS_CASE_CLAUSE => &["case_token", "test", "colon_token", "consequent"],
+		JS_DEFAULT_CLAUSE => &["default_token", "colon_token", "consequent"],
+		JS_CATCH_CLAUSE => &["catch_token", "declaration", "body"],
+		JS_FINALLY_CLAUSE => &["finally_token", "body"],
+		JS_CATCH_DECLARATION => &["l_paren_token", "binding", "r_paren_token"],
+		JS_ARRAY_EXPRESSION => &["l_brack_token", "elements", "r_brack_token"],
+		JS_ARROW_FUNCTION_EXPRESSION => &[
+			"async_token",
+			"type_parameters",
+			"parameter_list",
+			"fat_arrow_token",
+			"return_type",
+		],
+		JS_AWAIT_EXPRESSION => &["await_token", "argument"],
+		JS_BINARY_EXPRESSION => &["left", "operator"],
+		JS_CLASS_EXPRESSION => &[
+			"decorators",
+			"class_token",
+			"id",
+			"extends_clause",
+			"l_curly_token",
+			"members",
+			"r_curly_token",
+		],
+		JS_CONDITIONAL_EXPRESSION => &["test", "question_mark_token", "colon_token"],
+		JS_COMPUTED_MEMBER_EXPRESSION => &[
+			"object",
+			"optional_chain_token_token",
+			"l_brack_token",
+			"r_brack_token",
+		],
+		JS_FUNCTION_EXPRESSION => &[
+			"async_token",
+			"function_token",
+			"star_token",
+			"id",
+			"type_parameters",
+			"parameters",
+			"return_type",
+			"body",
+		],
+		JS_IMPORT_CALL_EXPRESSION => &[
+			"import_token",
+			"l_paren_token",
+			"argument",
+			"comma_token",
+			"r_paren_token",
+		],
+		JS_LOGICAL_EXPRESSION => &["left", "operator"],
+		JS_OBJECT_EXPRESSION => &["l_curly_token", "members", "r_curly_token"],
+		JS_PARENTHESIZED_EXPRESSION => &["l_paren_token", "expression", "r_paren_token"],
+		JS_REFERENCE_IDENTIFIER_EXPRESSION => &["name_token"],
+		JS_SEQUENCE_EXPRESSION => &["left", "comma_token"],
+		JS_STATIC_MEMBER_EXPRESSION => &["object", "operator", "member"],
+		JS_SUPER_EXPRESSION => &["super_token"],
+		JS_THIS_EXPRESSION => &["this_token"],
+		JS_UNARY_EXPRESSION => &["operator", "argument"],
+		JS_PRE_UPDATE_EXPRESSION => &["operator", "operand"],
+		JS_POST_UPDATE_EXPRESSION => &["operand", "operator"],
+		JS_YIELD_EXPRESSION => &["yield_token", "star_token", "argument"],
+		TEMPLATE => &["backtick_token"],
+		NEW_EXPR => &["new_token", "type_args", "object", "arguments"],
+		CALL_EXPR => &["type_args", "callee", "arguments"],
+		ASSIGN_EXPR => &["operator"],
+		NEW_TARGET => &["new_token", "dot_token", "target_token"],
+		IMPORT_META => &["import_token", "dot_token"],
+		TS_NON_NULL => &["expr", "excl_token"],
+		TS_ASSERTION => &["expr", "ident", "l_angle_token", "ty", "r_angle_token"],
+		TS_CONST_ASSERTION => &[
+			"expr",
+			"ident",
+			"l_angle_token",
+			"const_token",
+			"r_angle_token",
+		],
+		TS_INSTANTIATION_EXPR => &["expr", "type_args"],
+		TS_TYPE_ARGS => &["l_angle_token", "args", "r_angle_token"],
+		ARG_LIST => &["l_paren_token", "args", "r_paren_token"],
+		JS_IDENTIFIER_BINDING => &["name_token"],
+		TS_TYPE_PARAMS => &["l_angle_token", "params", "r_angle_token"],
+		JS_PARAMETER_LIST => &["l_paren_token", "parameters", "r_paren_token"],
+		TS_TYPE_ANNOTATION => &["colon_token", "ty"],
+		JS_FUNCTION_BODY => &["l_curly_token", "directives", "statements", "r_curly_token"],
+		SPREAD_ELEMENT => &["dotdotdot_token", "element"],
+		JS_LITERAL_MEMBER_NAME => &["value"],
+		JS_COMPUTED_MEMBER_NAME => &["l_brack_token", "expression", "r_brack_token"],
+		JS_PROPERTY_OBJECT_MEMBER => &["name", "colon_token"],
+		JS_METHOD_OBJECT_MEMBER => &[
+			"async_token",
+			"star_token",
+			"name",
+			"type_params",
+			"parameter_list",
+			"return_type",
+			"body",
+		],
+		JS_GETTER_OBJECT_MEMBER => &[
+			"get_token",
+			"name",
+			"l_paren_token",
+			"r_paren_token",
+			"return_type",
+			"body",
+		],
+		JS_SETTER_OBJECT_MEMBER => &[
+			"set_token",
+			"name",
+			"l_paren_token",
+			"parameter",
+			"r_paren_token",
+			"body",
+		],
+		INITIALIZED_PROP => &["key", "eq_token", "value"],
+		JS_SHORTHAND_PROPERTY_OBJECT_MEMBER => &["name"],
+		JS_SPREAD => &["dotdotdot_token", "argument"],
+		JS_STRING_LITERAL_EXPRESSION => &["value_token"],
+		JS_NUMBER_LITERAL_EXPRESSION => &["value_token"],
+		NAME => &["ident_token"],
+		TS_DECORATOR => &["at_token", "expression"],
+		TS_IMPLEMENTS_CLAUSE => &["implements_token", "interfaces"],
+		JS_EXTENDS_CLAUSE => &["extends_token", "super_class"],
+		TS_EXPR_WITH_TYPE_ARGS => &["item", "type_params"],
+		JS_PRIVATE_CLASS_MEMBER_NAME => &["hash_token", "id_token"],
+		JS_CONSTRUCTOR_CLASS_MEMBER => &["access_modifier", "name", "parameter_list", "body"],
+		JS_PROPERTY_CLASS_MEMBER => &[
+			"decorators",
+			"declare_token",
+			"access_modifier",
+			"abstract_token",
+			"static_token",
+			"override_token",
+			"name",
+			"question_mark_token",
+			"excl_token",
+			"ty",
+			"value",
+			"semicolon_token",
+		],
+		JS_ACCESSOR_PROPERTY_CLASS_MEMBER => &[
+			"decorators",
+			"access_modifier",
+			"abstract_token",
+			"static_token",
+			"override_token",
+			"accessor_token",
+			"name",
+			"question_mark_token",
+			"excl_token",
+			"ty",
+			"value",
+			"semicolon_token",
+		],
+		JS_METHOD_CLASS_MEMBER => &[
+			"decorators",
+			"access_modifier",
+			"static_token",
+			"abstract_token",
+			"override_token",
+			"async_token",
+			"star_token",
+			"name",
+			"type_parameters",
+			"parameter_list",
+			"return_type",
+			"body",
+		],
+		JS_GETTER_CLASS_MEMBER => &[
+			"decorators",
+			"access_modifier",
+			"abstract_token",
+			"static_token",
+			"override_token",
+			"get_token",
+			"name",
+			"l_paren_token",
+			"r_paren_token",
+			"return_type",
+			"body",
+		],
+		JS_SETTER_CLASS_MEMBER => &[
+			"decorators",
+			"access_modifier",
+			"abstract_token",
+			"static_token",
+			"override_token",
+			"set_token",
+			"name",
+			"l_paren_token",
+			"parameter",
+			"r_paren_token",
+			"body",
+		],
+		JS_EMPTY_CLASS_MEMBER => &["semicolon_token"],
+		JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER => &[
+			"static_token",
+			"l_curly_token",
+			"statements",
+			"r_curly_token",
+		],
+		TS_INDEX_SIGNATURE => &[
+			"readonly_token",
+			"l_brack_token",
+			"pat",
+			"colon_token",
+			"ty",
+			"r_brack_token",
+		],
+		TS_ACCESSIBILITY => &["private_token", "readonly_token"],
+		JS_CONSTRUCTOR_PARAMETER_LIST => &["l_paren_token", "parameters", "r_paren_token"],
+		TS_CONSTRUCTOR_PARAM => &["readonly_token", "pat"],
+		TS_DECORATED_PARAMETER => &["decorators", "parameter"],
+		JS_EQUAL_VALUE_CLAUSE => &["eq_token", "expression"],
+		JS_BIG_INT_LITERAL_EXPRESSION => &["value_token"],
+		JS_BOOLEAN_LITERAL_EXPRESSION => &["value_token"],
+		JS_NULL_LITERAL_EXPRESSION => &["value_token"],
+		JS_REGEX_LITERAL_EXPRESSION => &["value_token"],
+		SINGLE_PATTERN => &["name", "question_mark_token", "excl_token", "ty"],
+		REST_PATTERN => &["dotdotdot_token", "pat"],
+		ASSIGN_PATTERN => &["key", "ty", "eq_token", "value"],
+		OBJECT_PATTERN => &["l_curly_token", "elements", "r_curly_token"],
+		ARRAY_PATTERN => &[
+			"l_brack_token",
+			"elements",
+			"r_brack_token",
+			"excl_token",
+			"ty",
+		],
+		EXPR_PATTERN => &["expr"],
+		KEY_VALUE_PATTERN => &["key", "colon_token"],
+		JS_VARIABLE_DECLARATOR => &["id", "excl_token", "ty", "init"],
+		JS_IMPORT_ASSERTION => &["kind_token", "l_curly_token", "assertions", "r_curly_token"],
+		JS_IMPORT_ASSERTION_ENTRY => &["key", "colon_token", "value_token"],
+		WILDCARD_IMPORT => &["star_token", "as_token", "ident"],
+		NAMED_IMPORTS => &["l_curly_token", "specifiers", "r_curly_token"],
+		IMPORT_STRING_SPECIFIER => &["source_token"],
+		SPECIFIER => &["type_token", "name"],
+		JS_REFERENCE_IDENTIFIER_MEMBER => &["name_token"],
+		JS_REFERENCE_PRIVATE_MEMBER => &["hash_token", "name_token"],
+		JS_REST_PARAMETER => &["dotdotdot_token", "binding"],
+		TS_EXTERNAL_MODULE_REF => &[
+			"require_token",
+			"l_paren_token",
+			"module_token",
+			"r_paren_token",
+		],
+		TS_ANY => &["any_token"],
+		TS_UNKNOWN => &["unknown_token"],
+		TS_NUMBER => &["ident"],
+		TS_OBJECT => &["ident"],
+		TS_BOOLEAN => &["ident"],
+		TS_BIGINT => &["ident"],
+		TS_STRING => &["ident"],
+		TS_SYMBOL => &["ident"],
+		TS_VOID => &["void_token"],
+		TS_UNDEFINED => &["undefined_token"],
+		TS_NULL => &["null_token"],
+		TS_NEVER => &["never_token"],
+		TS_THIS => &["this_token"],
+		TS_LITERAL => &["ident"],
+		TS_PREDICATE => &["lhs", "rhs"],
+		TS_TUPLE => &["l_brack_token", "elements", "r_brack_token"],
+		TS_PAREN => &["l_paren_token", "ty", "r_paren_token"],
+		TS_TYPE_REF => &["name", "type_args"],
+		TS_TEMPLATE => &["backtick_token", "elements"],
+		TS_MAPPED_TYPE => &[
+			"l_curly_token",
+			"readonly_modifier",
+			"param",
+			"minus_token",
+			"plus_token",
+			"question_mark_token",
+			"colon_token",
+			"ty",
+			"semicolon_token",
+			"r_curly_token",
+		],
+		TS_IMPORT => &[
+			"import_token",
+			"type_args",
+			"dot_token",
+			"l_paren_token",
+			"qualifier",
+			"r_paren_token",
+		],
+		TS_ARRAY => &["l_brack_token", "ty", "r_brack_token"],
+		TS_INDEXED_ARRAY => &["l_brack_token", "ty", "r_brack_token"],
+		TS_TYPE_OPERATOR => &["ty"],
+		TS_INTERSECTION => &["types"],
+		TS_UNION => &["types"],
+		TS_FN_TYPE => &["params", "fat_arrow_token", "return_type"],
+		TS_CONSTRUCTOR_TYPE => &[
+			"abstract_token",
+			"new_token",
+			"params",
+			"colon_token",
+			"return_type",
+		],
+		TS_CONDITIONAL_TYPE => &["ty", "question_mark_token", "colon_token", "extends"],
+		TS_OBJECT_TYPE => &["l_curly_token", "members", "r_curly_token"],
+		TS_INFER => &["infer_token", "ident"],
+		TS_NAMED_TUPLE_TYPE_ELEMENT => &[
+			"dotdotdot_token",
+			"name",
+			"question_mark_token",
+			"colon_token",
+			"ty",
+		],
+		TS_REST_TUPLE_TYPE_ELEMENT => &["dotdotdot_token", "ty"],
+		TS_OPTIONAL_TUPLE_TYPE_ELEMENT => &["ty", "question_mark_token"],
+		TS_ENUM_MEMBER => &["ident", "eq_token", "value"],
+		TS_TEMPLATE_ELEMENT => &["ty", "r_curly_token"],
+		TS_MAPPED_TYPE_READONLY => &["minus_token", "plus_token", "readonly_token"],
+		TS_MAPPED_TYPE_PARAM => &[
+			"l_brack_token",
+			"name_token",
+			"in_token",
+			"ty",
+			"as_clause",
+			"r_brack_token",
+		],
+		TS_MAPPED_TYPE_AS_CLAUSE => &["as_token", "ty"],
+		TS_TYPE_NAME => &["ident"],
+		TS_EXTENDS => &["extends_token", "ty"],
+		TS_MODULE_BLOCK => &["l_curly_token", "items", "r_curly_token"],
+		TS_TYPE_PARAM => &["const_token", "ident", "constraint", "default"],
+		TS_CONSTRAINT => &["extends_token", "ty"],
+		TS_DEFAULT => &["eq_token", "ty"],
+		TS_CALL_SIGNATURE_DECL => &["type_params", "parameters", "colon_token", "return_type"],
+		TS_CONSTRUCT_SIGNATURE_DECL => &[
+			"abstract_token",
+			"new_token",
+			"type_params",
+			"parameters",
+			"colon_token",
+			"return_type",
+		],
+		TS_PROPERTY_SIGNATURE => &[
+			"readonly_token",
+			"prop",
+			"question_mark_token",
+			"colon_token",
+			"ty",
+		],
+		TS_METHOD_SIGNATURE => &[
+			"readonly_token",
+			"key",
+			"type_params",
+			"parameters",
+			"question_mark_token",
+			"colon_token",
+			"return_type",
+		],
+		TS_QUALIFIED_PATH => &["lhs", "dot_token", "rhs"],
+		_ => return None,
+	};
+	names.get(index).copied()
+}
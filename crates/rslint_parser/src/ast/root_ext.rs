@@ -0,0 +1,331 @@
+//! Extended AST node definitions for the root of a file
+
+use crate::{ast::*, TextSize, T};
+
+/// The kind of a [`MagicComment`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MagicCommentKind {
+	/// `//# sourceMappingURL=<url>`, the location of the source map of the file
+	SourceMappingUrl,
+	/// `//# sourceURL=<url>`, the name tools should show for the file
+	SourceUrl,
+}
+
+impl MagicCommentKind {
+	/// The name of the directive, e.g. `sourceMappingURL`
+	pub fn name(&self) -> &'static str {
+		match self {
+			MagicCommentKind::SourceMappingUrl => "sourceMappingURL",
+			MagicCommentKind::SourceUrl => "sourceURL",
+		}
+	}
+}
+
+/// A line comment read by browsers and bundlers such as `//# sourceMappingURL=index.js.map`.
+/// The legacy `//@` prefix is recognized as well.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MagicComment {
+	pub kind: MagicCommentKind,
+	pub url: String,
+	/// The range of the whole comment
+	pub range: TextRange,
+	/// The range of the url inside of the comment
+	pub url_range: TextRange,
+}
+
+impl MagicComment {
+	/// Parses the text of a comment starting at `offset`, returns `None` if it isn't a magic comment
+	pub fn parse(text: &str, offset: TextSize) -> Option<MagicComment> {
+		let body = text
+			.strip_prefix("//#")
+			.or_else(|| text.strip_prefix("//@"))?;
+		let trimmed = body.trim_start_matches(&[' ', '\t'][..]);
+		// `//#sourceMappingURL` is accepted, `//# sourceMappingURL` is the canonical form
+		let (kind, rest) = [
+			MagicCommentKind::SourceMappingUrl,
+			MagicCommentKind::SourceUrl,
+		]
+		.iter()
+		.find_map(|kind| Some((*kind, trimmed.strip_prefix(kind.name())?)))?;
+		let value = rest.trim_start().strip_prefix('=')?;
+		let url = value.trim();
+		if url.is_empty() || url.contains(|c: char| c.is_whitespace() || c == '"' || c == '\'') {
+			return None;
+		}
+
+		let url_start = text.len() - value.trim_start().len();
+		Some(MagicComment {
+			kind,
+			url: url.to_string(),
+			range: TextRange::at(offset, TextSize::of(text)),
+			url_range: TextRange::at(offset + TextSize::from(url_start as u32), TextSize::of(url)),
+		})
+	}
+
+	/// The source text of the comment, e.g. `//# sourceMappingURL=index.js.map`
+	pub fn text(&self) -> String {
+		format!("//# {}={}", self.kind.name(), self.url)
+	}
+}
+
+/// The kind of a [`TripleSlashDirective`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TripleSlashDirectiveKind {
+	/// `/// <reference path="./a.d.ts" />`, a dependency on another file
+	Path,
+	/// `/// <reference types="node" />`, a dependency on a package's types
+	Types,
+	/// `/// <reference lib="es2015" />`, a dependency on a built-in lib file
+	Lib,
+}
+
+impl TripleSlashDirectiveKind {
+	/// The name of the attribute, e.g. `path`
+	pub fn name(&self) -> &'static str {
+		match self {
+			TripleSlashDirectiveKind::Path => "path",
+			TripleSlashDirectiveKind::Types => "types",
+			TripleSlashDirectiveKind::Lib => "lib",
+		}
+	}
+}
+
+/// A TypeScript triple-slash reference directive such as `/// <reference path="./a.d.ts" />`
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TripleSlashDirective {
+	pub kind: TripleSlashDirectiveKind,
+	pub value: String,
+	/// The range of the whole comment
+	pub range: TextRange,
+	/// The range of the value inside of the quotes
+	pub value_range: TextRange,
+}
+
+impl TripleSlashDirective {
+	/// Parses the text of a comment starting at `offset`, returns `None` if it isn't a reference directive
+	pub fn parse(text: &str, offset: TextSize) -> Option<TripleSlashDirective> {
+		let is_space = |c: char| c == ' ' || c == '\t';
+		let body = text.strip_prefix("///")?.trim_start_matches(is_space);
+		let attributes = body.strip_prefix("<reference")?;
+		if !attributes.starts_with(is_space) {
+			return None;
+		}
+
+		let attributes = attributes.trim_start_matches(is_space);
+		let (kind, rest) = [
+			TripleSlashDirectiveKind::Path,
+			TripleSlashDirectiveKind::Types,
+			TripleSlashDirectiveKind::Lib,
+		]
+		.iter()
+		.find_map(|kind| Some((*kind, attributes.strip_prefix(kind.name())?)))?;
+		let quoted = rest
+			.trim_start_matches(is_space)
+			.strip_prefix('=')?
+			.trim_start_matches(is_space);
+		let quote = quoted.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+		let value = &quoted[1..quoted[1..].find(quote)? + 1];
+
+		let value_start = text.len() - quoted.len() + 1;
+		Some(TripleSlashDirective {
+			kind,
+			value: value.to_string(),
+			range: TextRange::at(offset, TextSize::of(text)),
+			value_range: TextRange::at(
+				offset + TextSize::from(value_start as u32),
+				TextSize::of(value),
+			),
+		})
+	}
+}
+
+impl JsRoot {
+	/// The triple-slash reference directives of the file in source order. Like TypeScript, only the
+	/// comments before the first statement of the file are considered.
+	///
+	/// ```
+	/// use rslint_parser::{ast::TripleSlashDirectiveKind, parse_module};
+	///
+	/// let text = "/// <reference types=\"node\" />\nimport a from \"a\";\n/// <reference lib=\"dom\" />";
+	/// let directives = parse_module(text, 0).tree().triple_slash_directives();
+	/// assert_eq!(1, directives.len());
+	/// assert_eq!(TripleSlashDirectiveKind::Types, directives[0].kind);
+	/// assert_eq!("node", directives[0].value);
+	/// ```
+	pub fn triple_slash_directives(&self) -> Vec<TripleSlashDirective> {
+		let mut pieces = vec![];
+		for token in self.syntax().descendants_tokens() {
+			pieces.extend(token.leading_trivia().pieces());
+			if token.kind() != T![js_shebang] {
+				break;
+			}
+			pieces.extend(token.trailing_trivia().pieces());
+		}
+
+		pieces
+			.into_iter()
+			.filter(|piece| piece.as_comments().is_some())
+			.filter_map(|piece| {
+				TripleSlashDirective::parse(piece.text(), piece.text_range().start())
+			})
+			.collect()
+	}
+
+	/// Whether the directive prologue of the file contains a `"use strict"` directive
+	pub fn has_use_strict(&self) -> bool {
+		self.directives()
+			.iter()
+			.any(|directive| directive.is_use_strict())
+	}
+
+	/// All the magic comments of the file in source order
+	///
+	/// ```
+	/// use rslint_parser::{ast::MagicCommentKind, parse_text};
+	///
+	/// let root = parse_text("let a = 1;\n//# sourceMappingURL=a.js.map\n", 0).tree();
+	/// let comments = root.magic_comments();
+	/// assert_eq!(1, comments.len());
+	/// assert_eq!(MagicCommentKind::SourceMappingUrl, comments[0].kind);
+	/// assert_eq!("a.js.map", comments[0].url);
+	/// ```
+	pub fn magic_comments(&self) -> Vec<MagicComment> {
+		self.syntax()
+			.descendants_tokens()
+			.flat_map(|token| {
+				let leading = token.leading_trivia().pieces();
+				let trailing = token.trailing_trivia().pieces();
+				leading.chain(trailing)
+			})
+			.filter(|piece| piece.as_comments().is_some())
+			.filter_map(|piece| MagicComment::parse(piece.text(), piece.text_range().start()))
+			.collect()
+	}
+
+	/// The `//# sourceMappingURL` comment of the file. The last comment wins if there are several.
+	pub fn source_mapping_url(&self) -> Option<MagicComment> {
+		self.last_magic_comment(MagicCommentKind::SourceMappingUrl)
+	}
+
+	/// The `//# sourceURL` comment of the file. The last comment wins if there are several.
+	pub fn source_url(&self) -> Option<MagicComment> {
+		self.last_magic_comment(MagicCommentKind::SourceUrl)
+	}
+
+	fn last_magic_comment(&self, kind: MagicCommentKind) -> Option<MagicComment> {
+		self.magic_comments()
+			.into_iter()
+			.rev()
+			.find(|comment| comment.kind == kind)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MagicComment, MagicCommentKind, TripleSlashDirective, TripleSlashDirectiveKind};
+	use crate::{parse_text, TextRange, TextSize};
+
+	#[test]
+	fn parse_magic_comments() {
+		let comment = MagicComment::parse("//# sourceMappingURL=a.js.map", 10.into()).unwrap();
+		assert_eq!(MagicCommentKind::SourceMappingUrl, comment.kind);
+		assert_eq!("a.js.map", comment.url);
+		assert_eq!(TextRange::at(10.into(), TextSize::from(29)), comment.range);
+		assert_eq!(
+			TextRange::at(31.into(), TextSize::from(8)),
+			comment.url_range
+		);
+
+		let comment = MagicComment::parse("//@ sourceURL = foo.js ", 0.into()).unwrap();
+		assert_eq!(MagicCommentKind::SourceUrl, comment.kind);
+		assert_eq!("foo.js", comment.url);
+		assert_eq!(
+			TextRange::at(16.into(), TextSize::from(6)),
+			comment.url_range
+		);
+
+		assert_eq!(
+			None,
+			MagicComment::parse("// sourceMappingURL=a.js.map", 0.into())
+		);
+		assert_eq!(None, MagicComment::parse("//# sourceMappingURL=", 0.into()));
+		assert_eq!(
+			None,
+			MagicComment::parse("//# sourceMappingURL=a b", 0.into())
+		);
+		assert_eq!(
+			None,
+			MagicComment::parse("/*# sourceMappingURL=a */", 0.into())
+		);
+	}
+
+	#[test]
+	fn last_magic_comment_wins() {
+		let root = parse_text(
+			"//# sourceURL=a.js\nfoo();\n//# sourceMappingURL=old.map\n//# sourceMappingURL=new.map",
+			0,
+		)
+		.tree();
+
+		assert_eq!(3, root.magic_comments().len());
+		assert_eq!("a.js", root.source_url().unwrap().url);
+		assert_eq!("new.map", root.source_mapping_url().unwrap().url);
+	}
+
+	#[test]
+	fn parse_triple_slash_directives() {
+		let directive =
+			TripleSlashDirective::parse("/// <reference path='./a.d.ts' />", 5.into()).unwrap();
+		assert_eq!(TripleSlashDirectiveKind::Path, directive.kind);
+		assert_eq!("./a.d.ts", directive.value);
+		assert_eq!(TextRange::at(5.into(), TextSize::from(33)), directive.range);
+		assert_eq!(
+			TextRange::at(26.into(), TextSize::from(8)),
+			directive.value_range
+		);
+
+		let directive =
+			TripleSlashDirective::parse("///<reference lib = \"es2015\"/>", 0.into()).unwrap();
+		assert_eq!(TripleSlashDirectiveKind::Lib, directive.kind);
+		assert_eq!("es2015", directive.value);
+
+		assert_eq!(
+			None,
+			TripleSlashDirective::parse("// <reference path=\"a\" />", 0.into())
+		);
+		assert_eq!(
+			None,
+			TripleSlashDirective::parse("/// <referencepath=\"a\" />", 0.into())
+		);
+		assert_eq!(
+			None,
+			TripleSlashDirective::parse("/// <reference no-default-lib=\"true\" />", 0.into())
+		);
+		assert_eq!(
+			None,
+			TripleSlashDirective::parse("/// <reference path=\"a />", 0.into())
+		);
+	}
+
+	#[test]
+	fn leading_triple_slash_directives() {
+		let root = parse_text(
+			"#!/usr/bin/env node\n/// <reference types=\"node\" />\n/* license */\n/// <reference path=\"./b.d.ts\" />\nfoo();\n/// <reference lib=\"dom\" />",
+			0,
+		)
+		.tree();
+
+		let directives = root
+			.triple_slash_directives()
+			.into_iter()
+			.map(|directive| (directive.kind, directive.value))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			vec![
+				(TripleSlashDirectiveKind::Types, "node".to_string()),
+				(TripleSlashDirectiveKind::Path, "./b.d.ts".to_string())
+			],
+			directives
+		);
+	}
+}
@@ -42,12 +42,87 @@ impl Specifier {
 		self.syntax()
 			.children_with_tokens()
 			.filter_map(|x| x.into_token())
-			.nth(1)
+			.find(|token| token.kind() == T![as])
 	}
 
 	pub fn alias(&self) -> Option<Name> {
 		self.syntax().children().nth(1).and_then(|x| x.try_to())
 	}
+
+	/// Whether the specifier only refers to types, either through its own `type` modifier (`{ type A }`)
+	/// or because its whole declaration is type-only (`import type { A }`)
+	///
+	/// ```
+	/// use rslint_parser::{ast::Specifier, parse, AstNode, Syntax};
+	///
+	/// let text = "import type { A } from \"a\";\nimport { type B, C } from \"b\";";
+	/// let parse = parse(text, 0, Syntax::default().typescript());
+	/// let type_only = parse
+	///     .syntax()
+	///     .descendants()
+	///     .filter_map(Specifier::cast)
+	///     .map(|specifier| specifier.is_type_only());
+	/// assert_eq!(vec![true, true, false], type_only.collect::<Vec<_>>());
+	/// ```
+	pub fn is_type_only(&self) -> bool {
+		self.type_token().is_some()
+			|| self
+				.syntax()
+				.ancestors()
+				.find_map(|node| match node.kind() {
+					IMPORT_DECL => ImportDecl::cast(node).map(|decl| decl.is_type_only()),
+					EXPORT_NAMED => ExportNamed::cast(node).map(|export| export.is_type_only()),
+					_ => None,
+				})
+				.unwrap_or(false)
+	}
+}
+
+impl ImportDecl {
+	/// Whether this is an `import type` declaration
+	pub fn is_type_only(&self) -> bool {
+		self.type_token().is_some()
+	}
+}
+
+impl ExportNamed {
+	/// Whether this is an `export type { ... }` declaration
+	pub fn is_type_only(&self) -> bool {
+		self.type_token().is_some()
+	}
+}
+
+impl ExportWildcard {
+	/// Whether this is an `export type * from "..."` declaration
+	pub fn is_type_only(&self) -> bool {
+		self.type_token().is_some()
+	}
+}
+
+impl JsClassDeclaration {
+	/// The decorators before the `export` or `export default` of an exported class, e.g. `@sealed` of
+	/// `@sealed export class A {}`, which precede the [decorators](JsClassDeclaration::decorators) after `export`
+	///
+	/// ```
+	/// use rslint_parser::{ast::JsClassDeclaration, parse, AstNode, Syntax};
+	///
+	/// let syntax = Syntax::default().module().decorators();
+	/// let parse = parse("@sealed export class A {}\nexport @log class B {}", 0, syntax);
+	/// let decorators = parse
+	///     .syntax()
+	///     .descendants()
+	///     .filter_map(JsClassDeclaration::cast)
+	///     .map(|class| (class.export_decorators().len(), class.decorators().len()));
+	/// assert_eq!(vec![(1, 0), (0, 1)], decorators.collect::<Vec<_>>());
+	/// ```
+	pub fn export_decorators(&self) -> AstNodeList<TsDecorator> {
+		match self.syntax().parent() {
+			Some(parent) if matches!(parent.kind(), EXPORT_DECL | EXPORT_DEFAULT_DECL) => {
+				super::support::node_list(&parent, 0)
+			}
+			_ => AstNodeList::default(),
+		}
+	}
 }
 
 impl WildcardImport {
@@ -80,6 +155,79 @@ mod tests {
 
 		assert!(var_decl.is_some());
 	}
+
+	#[test]
+	fn export_wildcard_alias() {
+		let parsed = parse_module("export * as ns from \"mod\";\nexport * from \"mod\";", 0);
+		assert!(parsed.errors().is_empty());
+
+		let aliases = parsed
+			.tree()
+			.statements()
+			.iter()
+			.filter_map(|stmt| ast::ExportWildcard::cast(stmt.syntax().clone()))
+			.map(|export| export.alias().map(|alias| alias.text()))
+			.collect::<Vec<_>>();
+
+		assert_eq!(vec![Some("ns".to_string()), None], aliases);
+	}
+
+	#[test]
+	fn directive_prologue() {
+		let parsed = parse_text(
+			"function a() { 'use asm'; \"use strict\"; 'use\\x20strict' }",
+			0,
+		);
+		let body = parsed
+			.syntax()
+			.descendants()
+			.find_map(ast::JsFunctionBody::cast)
+			.unwrap();
+
+		let directives = body
+			.directives()
+			.iter()
+			.map(|directive| {
+				(
+					directive.inner_string_text().unwrap(),
+					directive.is_use_strict(),
+				)
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(
+			vec![
+				("use asm".to_string(), false),
+				("use strict".to_string(), true),
+				("use\\x20strict".to_string(), false)
+			],
+			directives
+		);
+		assert!(body.has_use_strict());
+	}
+}
+
+impl JsDirective {
+	/// The text of the directive without the quotes, e.g. `use strict`. Escape sequences are not decoded.
+	pub fn inner_string_text(&self) -> Option<String> {
+		let value = self.value_token().ok()?;
+		let text = value.text_trimmed();
+		Some(text.get(1..text.len() - 1)?.to_string())
+	}
+
+	/// Whether this is a `"use strict"` directive. Like in the spec, a directive spelled
+	/// with escape sequences or line continuations, e.g. `"use\x20strict"`, is not.
+	pub fn is_use_strict(&self) -> bool {
+		self.inner_string_text().as_deref() == Some("use strict")
+	}
+}
+
+impl JsFunctionBody {
+	/// Whether the directive prologue of the function contains a `"use strict"` directive
+	pub fn has_use_strict(&self) -> bool {
+		self.directives()
+			.iter()
+			.any(|directive| directive.is_use_strict())
+	}
 }
 
 impl TsEnumMember {
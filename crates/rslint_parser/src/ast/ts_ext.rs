@@ -43,20 +43,35 @@ impl TsImport {
 	}
 }
 
-impl TsMappedTypeParam {
-	/// present for alias
-	pub fn as_token(&self) -> Option<SyntaxToken> {
-		self.syntax()
-			.children_with_tokens()
-			.filter_map(|x| x.into_token())
-			.find(|x| x.kind() == IDENT && x.text() == "as")
+impl TsTemplate {
+	/// The string chunks of the template literal type, e.g. `a-` in `` `a-${B}` ``
+	///
+	/// ```
+	/// use rslint_parser::{ast::TsTemplate, parse, AstNode, Syntax};
+	///
+	/// let parse = parse("type A = `a-${B}-c`;", 0, Syntax::default().typescript());
+	/// let template = parse.syntax().descendants().find_map(TsTemplate::cast).unwrap();
+	/// let chunks = template.chunks().map(|chunk| chunk.raw()).collect::<Vec<_>>();
+	/// assert_eq!(vec!["a-", "-c"], chunks);
+	/// ```
+	pub fn chunks(&self) -> impl Iterator<Item = TemplateChunk> {
+		template_chunk_tokens(self.syntax()).map(TemplateChunk)
 	}
+}
 
+impl TsMappedTypeParam {
+	/// The type the keys are remapped to, e.g. `Uppercase<K>` in `[K in keyof T as Uppercase<K>]`
+	///
+	/// ```
+	/// use rslint_parser::{ast::TsMappedTypeParam, parse, AstNode, Syntax};
+	///
+	/// let text = "type A = { [K in keyof T as Uppercase<K>]: T[K] };";
+	/// let parse = parse(text, 0, Syntax::default().typescript());
+	/// let param = parse.syntax().descendants().find_map(TsMappedTypeParam::cast).unwrap();
+	/// assert_eq!("Uppercase<K>", param.alias().unwrap().text());
+	/// ```
 	pub fn alias(&self) -> Option<TsType> {
-		self.syntax()
-			.children()
-			.filter_map(|x| x.try_to::<TsType>())
-			.nth(1)
+		self.as_clause()?.ty().ok()
 	}
 }
 
@@ -0,0 +1,81 @@
+//! Cooperative cancellation of parsing and formatting, e.g. to abandon the requests of an editor
+//! for a document that has changed in the meantime.
+
+use std::{
+	error::Error,
+	fmt,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+};
+
+/// A flag shared between the requester of an operation and the operation that signals that the
+/// result of the operation is no longer needed. The operation checks the flag at regular intervals,
+/// for example the parser checks it before every statement.
+///
+/// ```
+/// use rslint_parser::{parse_text_cancellable, CancellationToken, Cancelled};
+///
+/// let token = CancellationToken::new();
+/// assert!(parse_text_cancellable("let a = 1;", 0, &token).is_ok());
+///
+/// // Cancelling a clone of the token cancels all operations using the token
+/// token.clone().cancel();
+/// assert_eq!(Err(Cancelled), parse_text_cancellable("let a = 1;", 0, &token).map(|_| ()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Requests the operations using this token to stop
+	pub fn cancel(&self) {
+		self.0.store(true, Ordering::Relaxed);
+	}
+
+	pub fn is_cancelled(&self) -> bool {
+		self.0.load(Ordering::Relaxed)
+	}
+
+	/// Returns [Cancelled] if the token has been cancelled
+	pub fn check(&self) -> Result<(), Cancelled> {
+		if self.is_cancelled() {
+			Err(Cancelled)
+		} else {
+			Ok(())
+		}
+	}
+}
+
+/// Wraps a flag the application already shares with its workers, setting the flag to `true` cancels the token
+///
+/// ```
+/// use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+/// use rslint_parser::CancellationToken;
+///
+/// let flag = Arc::new(AtomicBool::new(false));
+/// let token = CancellationToken::from(flag.clone());
+/// flag.store(true, Ordering::Relaxed);
+/// assert!(token.is_cancelled());
+/// ```
+impl From<Arc<AtomicBool>> for CancellationToken {
+	fn from(flag: Arc<AtomicBool>) -> Self {
+		CancellationToken(flag)
+	}
+}
+
+/// Error returned by an operation that stopped because its [CancellationToken] was cancelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "the operation was cancelled")
+	}
+}
+
+impl Error for Cancelled {}
@@ -0,0 +1,270 @@
+//! Attachment of comments to the nodes they belong to.
+//!
+//! Comments are trivia of the tokens in the tree, which is rarely the node a comment is about. A JSDoc
+//! comment is in the leading trivia of the `function` keyword for example, but documents the whole
+//! function declaration. [Comments] assigns every comment to an owning node, using the heuristics of
+//! Babel and Prettier, so that the formatter and documentation tooling agree on where a comment belongs:
+//!
+//! - A comment on its own line is a leading comment of the node after it.
+//! - A comment at the end of a line is a trailing comment of the node before it.
+//! - A comment between two nodes on the same line is a leading comment of the node after it, unless
+//!   a token such as `,` separates it from that node, the comment of `f(a /* a */, b)` trails `a`.
+//! - A comment without a node before or after it, e.g. in `f(/* no arguments */)`, is a dangling
+//!   comment of the node that contains it.
+//!
+//! The node after or before a comment is the outermost node that starts after or ends before the comment,
+//! the leading comment of `/** docs */ export function f() {}` belongs to the export declaration.
+
+use crate::{util::CommentKind, JsLanguage, SyntaxKind, SyntaxNode, TextRange, TextSize};
+use rome_rowan::api::SyntaxTriviaPieceComments;
+use std::collections::HashMap;
+
+/// A comment and its place in the source text
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceComment {
+	pub kind: CommentKind,
+	/// The text of the comment, including the `//` or `/*` and `*/`
+	pub text: String,
+	pub range: TextRange,
+}
+
+impl SourceComment {
+	fn new(piece: &SyntaxTriviaPieceComments<JsLanguage>) -> Self {
+		let text = piece.text();
+		let kind = if text.starts_with("//") {
+			CommentKind::Inline
+		} else if text.starts_with("/**") && text != "/**/" {
+			CommentKind::JsDoc
+		} else {
+			CommentKind::Multiline
+		};
+		Self {
+			kind,
+			text: text.to_string(),
+			range: piece.text_range(),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default)]
+struct NodeComments {
+	leading: Vec<SourceComment>,
+	trailing: Vec<SourceComment>,
+	dangling: Vec<SourceComment>,
+}
+
+/// The comments of a tree by the node they belong to
+///
+/// ```
+/// use rslint_parser::{comments::Comments, parse_text, SyntaxKind};
+///
+/// let parse = parse_text("/** Adds one */\nfunction inc(a) {\n\treturn a + 1; // the sum\n}", 0);
+/// let comments = Comments::new(&parse.syntax());
+///
+/// let function = parse.syntax().descendants().find(|node| node.kind() == SyntaxKind::JS_FUNCTION_DECLARATION).unwrap();
+/// assert_eq!("/** Adds one */", comments.leading(&function)[0].text);
+///
+/// let ret = parse.syntax().descendants().find(|node| node.kind() == SyntaxKind::JS_RETURN_STATEMENT).unwrap();
+/// assert_eq!("// the sum", comments.trailing(&ret)[0].text);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Comments {
+	by_node: HashMap<SyntaxNode, NodeComments>,
+}
+
+impl Comments {
+	/// Attaches the comments of the tree of `root` to their nodes
+	pub fn new(root: &SyntaxNode) -> Self {
+		let text = root.to_string();
+		let mut comments = Comments::default();
+
+		for token in root.descendants_tokens() {
+			let leading = token.leading_trivia().pieces();
+			let trailing = token.trailing_trivia().pieces();
+			for piece in leading.chain(trailing) {
+				if let Some(comment) = piece.as_comments() {
+					let comment = SourceComment::new(&comment);
+					comments.attach(root, &text, comment);
+				}
+			}
+		}
+
+		comments
+	}
+
+	/// The comments before `node` that belong to it, in source order
+	pub fn leading(&self, node: &SyntaxNode) -> &[SourceComment] {
+		self.by_node
+			.get(node)
+			.map_or(&[], |comments| &comments.leading)
+	}
+
+	/// The comments after `node` that belong to it, in source order
+	pub fn trailing(&self, node: &SyntaxNode) -> &[SourceComment] {
+		self.by_node
+			.get(node)
+			.map_or(&[], |comments| &comments.trailing)
+	}
+
+	/// The comments inside of `node` that belong to none of its children, e.g. the comment of `{ /* empty */ }`
+	pub fn dangling(&self, node: &SyntaxNode) -> &[SourceComment] {
+		self.by_node
+			.get(node)
+			.map_or(&[], |comments| &comments.dangling)
+	}
+
+	/// Returns `true` if any comment belongs to `node`
+	pub fn has_comments(&self, node: &SyntaxNode) -> bool {
+		self.by_node.contains_key(node)
+	}
+
+	fn attach(&mut self, root: &SyntaxNode, text: &str, comment: SourceComment) {
+		let mut enclosing = root.clone();
+		let (preceding, following) = loop {
+			// The children are sorted and don't overlap, the first child that doesn't end before the comment
+			// either contains or follows it
+			let mut children = children(&enclosing);
+			let index = children
+				.partition_point(|child| child.text_trimmed_range().end() <= comment.range.start());
+			let preceding = index.checked_sub(1).map(|index| children[index].clone());
+			if index == children.len() {
+				break (preceding, None);
+			}
+
+			let child = children.swap_remove(index);
+			if child.text_trimmed_range().contains_range(comment.range) {
+				enclosing = child;
+			} else {
+				break (preceding, Some(child));
+			}
+		};
+
+		let offset = root.text_range().start();
+		let start = usize::from(comment.range.start() - offset);
+		let end = usize::from(comment.range.end() - offset);
+		let line_start = text[..start]
+			.rfind(rslint_lexer::is_linebreak)
+			.map_or(0, |index| index + 1);
+		let line_end = text[end..]
+			.find(rslint_lexer::is_linebreak)
+			.map_or(text.len(), |index| end + index);
+		let own_line = text[line_start..start].trim().is_empty();
+		let end_of_line = text[end..line_end].trim().is_empty();
+		let followed_by_token = following.as_ref().map_or(false, |following| {
+			let following_start = usize::from(following.text_trimmed_range().start() - offset);
+			!text[end..following_start].trim().is_empty()
+		});
+
+		let comments = match (preceding, following) {
+			(Some(preceding), _) if (end_of_line || followed_by_token) && !own_line => {
+				&mut self.by_node.entry(preceding).or_default().trailing
+			}
+			(_, Some(following)) => &mut self.by_node.entry(following).or_default().leading,
+			(Some(preceding), None) => &mut self.by_node.entry(preceding).or_default().trailing,
+			(None, None) => &mut self.by_node.entry(enclosing).or_default().dangling,
+		};
+		comments.push(comment);
+	}
+}
+
+/// The children of `node` a comment can belong to. The elements of lists are children of the node
+/// that contains the list because the lists are an implementation detail of the tree.
+fn children(node: &SyntaxNode) -> Vec<SyntaxNode> {
+	let mut children = vec![];
+	for child in node.children() {
+		if child.kind() == SyntaxKind::LIST {
+			children.extend(children_of_list(&child));
+		} else if child.text_trimmed_range().len() > TextSize::from(0) {
+			children.push(child);
+		}
+	}
+	children
+}
+
+fn children_of_list(list: &SyntaxNode) -> Vec<SyntaxNode> {
+	list.children()
+		.filter(|child| child.text_trimmed_range().len() > TextSize::from(0))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Comments;
+	use crate::{parse_text, util::CommentKind, SyntaxKind, SyntaxKind::*, SyntaxNode};
+
+	fn node(root: &SyntaxNode, kind: SyntaxKind, nth: usize) -> SyntaxNode {
+		root.descendants()
+			.filter(|node| node.kind() == kind)
+			.nth(nth)
+			.unwrap()
+	}
+
+	fn texts(comments: &[super::SourceComment]) -> Vec<&str> {
+		comments
+			.iter()
+			.map(|comment| comment.text.as_str())
+			.collect()
+	}
+
+	#[test]
+	fn leading_and_trailing_comments() {
+		let code = "// first\n/* second */ let a = 1; // a\n\nb; /* b */\n// end\n";
+		let root = parse_text(code, 0).syntax();
+		let comments = Comments::new(&root);
+
+		let a = node(&root, JS_VARIABLE_DECLARATION_STATEMENT, 0);
+		assert_eq!(
+			vec!["// first", "/* second */"],
+			texts(comments.leading(&a))
+		);
+		assert_eq!(vec!["// a"], texts(comments.trailing(&a)));
+
+		let b = node(&root, JS_EXPRESSION_STATEMENT, 0);
+		assert!(comments.leading(&b).is_empty());
+		// The comment on its own line at the end of the file has no node after it
+		assert_eq!(vec!["/* b */", "// end"], texts(comments.trailing(&b)));
+	}
+
+	#[test]
+	fn comments_inside_of_nodes() {
+		let code = "f(/* none */);\nlet a = /* one */ 1;\nfunction g(a /* a */, b) {}\nif (a) {\n\t// empty\n}";
+		let root = parse_text(code, 0).syntax();
+		let comments = Comments::new(&root);
+
+		let arguments = node(&root, ARG_LIST, 0);
+		assert_eq!(vec!["/* none */"], texts(comments.dangling(&arguments)));
+
+		let one = node(&root, JS_NUMBER_LITERAL_EXPRESSION, 0);
+		assert_eq!(vec!["/* one */"], texts(comments.leading(&one)));
+
+		// The comment is separated from `b` by the comma
+		let a = node(&root, SINGLE_PATTERN, 1);
+		assert_eq!("a", a.text_trimmed().to_string());
+		assert_eq!(vec!["/* a */"], texts(comments.trailing(&a)));
+		assert!(comments.leading(&node(&root, SINGLE_PATTERN, 2)).is_empty());
+
+		let block = node(&root, JS_BLOCK_STATEMENT, 0);
+		assert_eq!(vec!["// empty"], texts(comments.dangling(&block)));
+		assert!(!comments.has_comments(&node(&root, JS_IF_STATEMENT, 0)));
+	}
+
+	#[test]
+	fn comment_kinds() {
+		let root = parse_text("/** doc */ /* block */ // line\na;", 0).syntax();
+		let comments = Comments::new(&root);
+		let statement = node(&root, JS_EXPRESSION_STATEMENT, 0);
+		let kinds: Vec<_> = comments
+			.leading(&statement)
+			.iter()
+			.map(|comment| comment.kind)
+			.collect();
+		assert_eq!(
+			vec![
+				CommentKind::JsDoc,
+				CommentKind::Multiline,
+				CommentKind::Inline
+			],
+			kinds
+		);
+	}
+}
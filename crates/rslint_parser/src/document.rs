@@ -0,0 +1,243 @@
+//! Versioned source files that are kept in sync with the edits of an editor, see [Document].
+
+use std::{error::Error, fmt};
+
+use rslint_errors::line_index::{LineCol, LineIndex};
+use rslint_text_edit::TextEdit;
+
+use crate::{
+	ast::JsRoot, parse::parse_with_cache, reparse::reparse_incrementally, NodeCache, Parse, Syntax,
+	TextRange, TextSize,
+};
+
+/// The text of a file together with its version, its parse result and the data derived from it.
+///
+/// A [Document] is the single place that owns the state of an open file. Every change goes
+/// through [Document::apply_change], which checks the version of the change, updates the text,
+/// reparses it and invalidates the derived data. Only the statements touched by a change are
+/// reparsed if possible, see [reparse](crate::reparse). Otherwise the whole text is reparsed, reusing the
+/// unchanged subtrees of the previous tree and the tokens and nodes kept in a cache.
+///
+/// ```
+/// use rslint_errors::line_index::LineCol;
+/// use rslint_parser::{ChangeError, Document, StaleVersion, Syntax, TextRange, TextSize};
+/// use rslint_text_edit::TextEdit;
+///
+/// let mut document = Document::new(0, "let a = 1;\nlet b = 2;", 1, Syntax::default());
+/// assert_eq!(LineCol { line: 1, col: 4 }, document.line_col(TextSize::from(15)));
+///
+/// let edit = TextEdit::replace(TextRange::new(4.into(), 5.into()), "c".to_string());
+/// document.apply_change(2, &edit).unwrap();
+/// assert_eq!("let c = 1;\nlet b = 2;", document.text());
+/// assert_eq!("let c = 1;\nlet b = 2;", document.parse().syntax().text());
+///
+/// // Changes for an outdated version of the document are rejected
+/// assert_eq!(
+///     Err(ChangeError::StaleVersion(StaleVersion { current: 2, received: 2 })),
+///     document.apply_change(2, &edit)
+/// );
+///
+/// // So are edits outside of the text
+/// let past_the_end = TextEdit::delete(TextRange::new(20.into(), 30.into()));
+/// assert!(document.apply_change(3, &past_the_end).is_err());
+/// assert_eq!(2, document.version());
+/// ```
+#[derive(Debug)]
+pub struct Document {
+	file_id: usize,
+	syntax: Syntax,
+	version: i32,
+	text: String,
+	parse: Parse<JsRoot>,
+	line_index: LineIndex,
+	/// The nodes of the previous trees, cleared once it holds more than [MAX_CACHED_NODES] nodes
+	cache: NodeCache,
+}
+
+/// The number of nodes above which the cache of a [Document] is cleared. The cache keeps every node
+/// of every version of the document alive, without a bound it would grow with every full reparse.
+const MAX_CACHED_NODES: usize = 100_000;
+
+impl Document {
+	pub fn new(file_id: usize, text: impl Into<String>, version: i32, syntax: Syntax) -> Self {
+		let text = text.into();
+		let mut cache = NodeCache::default();
+		let parse = parse_with_cache(&text, file_id, syntax, &mut cache, None);
+		Document {
+			file_id,
+			syntax,
+			version,
+			line_index: LineIndex::new(&text),
+			text,
+			parse,
+			cache,
+		}
+	}
+
+	pub fn file_id(&self) -> usize {
+		self.file_id
+	}
+
+	pub fn syntax(&self) -> Syntax {
+		self.syntax
+	}
+
+	pub fn version(&self) -> i32 {
+		self.version
+	}
+
+	pub fn text(&self) -> &str {
+		&self.text
+	}
+
+	/// The parse result of the current text
+	pub fn parse(&self) -> &Parse<JsRoot> {
+		&self.parse
+	}
+
+	/// The line index of the current text
+	pub fn line_index(&self) -> &LineIndex {
+		&self.line_index
+	}
+
+	/// Returns the zero based line and column of `offset`
+	pub fn line_col(&self, offset: TextSize) -> LineCol {
+		self.line_index.line_col(offset)
+	}
+
+	/// Applies `edit` to the text of the document and reparses it. The offsets of `edit`
+	/// refer to the text of the current version.
+	///
+	/// Returns an error without changing the document if `version` isn't newer than
+	/// the version of the document or if a range of `edit` isn't a valid range of the text.
+	pub fn apply_change(&mut self, version: i32, edit: &TextEdit) -> Result<(), ChangeError> {
+		if version <= self.version {
+			return Err(StaleVersion {
+				current: self.version,
+				received: version,
+			}
+			.into());
+		}
+		self.check_edit(edit)?;
+
+		self.version = version;
+		if edit.is_empty() {
+			return Ok(());
+		}
+
+		edit.apply(&mut self.text);
+		self.parse =
+			match reparse_incrementally(&self.parse, edit, &self.text, self.file_id, self.syntax) {
+				Some(parse) => parse,
+				None => {
+					if self.cache.node_count() > MAX_CACHED_NODES {
+						self.cache.clear();
+					}
+					let previous = self.parse.syntax();
+					parse_with_cache(
+						&self.text,
+						self.file_id,
+						self.syntax,
+						&mut self.cache,
+						Some(&previous),
+					)
+				}
+			};
+		self.line_index = LineIndex::new(&self.text);
+		Ok(())
+	}
+
+	/// Replaces the whole text of the document, e.g. after the file changed on disk
+	pub fn set_text(&mut self, version: i32, text: impl Into<String>) -> Result<(), ChangeError> {
+		let edit = TextEdit::replace(
+			TextRange::up_to(TextSize::of(self.text.as_str())),
+			text.into(),
+		);
+		self.apply_change(version, &edit)
+	}
+
+	fn check_edit(&self, edit: &TextEdit) -> Result<(), ChangeError> {
+		let len = TextSize::of(self.text.as_str());
+		for indel in edit.iter() {
+			let range = indel.delete;
+			if range.end() > len {
+				return Err(ChangeError::OutOfBounds { range, len });
+			}
+			for offset in [range.start(), range.end()] {
+				if !self.text.is_char_boundary(offset.into()) {
+					return Err(ChangeError::InvalidCharBoundary { offset });
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Error returned by [Document::apply_change] for a change that can't be applied to the document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeError {
+	StaleVersion(StaleVersion),
+	/// The range of an edit ends past the end of the text
+	OutOfBounds {
+		range: TextRange,
+		len: TextSize,
+	},
+	/// The range of an edit starts or ends inside of a character
+	InvalidCharBoundary {
+		offset: TextSize,
+	},
+}
+
+impl fmt::Display for ChangeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ChangeError::StaleVersion(error) => error.fmt(f),
+			ChangeError::OutOfBounds { range, len } => write!(
+				f,
+				"the edit range {:?} is out of bounds of the text of length {:?}",
+				range, len
+			),
+			ChangeError::InvalidCharBoundary { offset } => write!(
+				f,
+				"the edit offset {:?} is not on a character boundary",
+				offset
+			),
+		}
+	}
+}
+
+impl Error for ChangeError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			ChangeError::StaleVersion(error) => Some(error),
+			ChangeError::OutOfBounds { .. } | ChangeError::InvalidCharBoundary { .. } => None,
+		}
+	}
+}
+
+impl From<StaleVersion> for ChangeError {
+	fn from(error: StaleVersion) -> Self {
+		ChangeError::StaleVersion(error)
+	}
+}
+
+/// The error of a change that isn't newer than the document, see [ChangeError::StaleVersion]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaleVersion {
+	/// The version of the document
+	pub current: i32,
+	/// The version of the rejected change
+	pub received: i32,
+}
+
+impl fmt::Display for StaleVersion {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"received a change for version {} but the document is already at version {}",
+			self.received, self.current
+		)
+	}
+}
+
+impl Error for StaleVersion {}
@@ -0,0 +1,456 @@
+//! Parsing of JSDoc comments.
+//!
+//! A JSDoc comment is a `/** ... */` comment in front of a declaration. Its text starts with a
+//! free form description which is followed by block tags, e.g. `@param {string} name The name`.
+//! [JsDoc] parses the description and the tags, every part of the comment keeps its range in the source
+//! text so that diagnostics can point into the comment. The types of `@param`, `@returns`, and `@type`
+//! tags are parsed as TypeScript types.
+//!
+//! The comment of a node is found with the comments that [Comments] attached to the node:
+//!
+//! ```
+//! use rslint_parser::{comments::Comments, jsdoc::JsDoc, parse_text, SyntaxKind};
+//!
+//! let parse = parse_text("/**\n * Adds two numbers.\n * @param {number} a The first number\n */\nfunction add(a, b) {}", 0);
+//! let comments = Comments::new(&parse.syntax());
+//! let function = parse.syntax().descendants().find(|node| node.kind() == SyntaxKind::JS_FUNCTION_DECLARATION).unwrap();
+//!
+//! let doc = JsDoc::of(&comments, &function, 0).unwrap();
+//! assert_eq!("Adds two numbers.", doc.description.unwrap().text);
+//! assert_eq!("a", doc.tags[0].name().unwrap().text);
+//! ```
+
+use crate::{
+	ast::TsType,
+	comments::{Comments, SourceComment},
+	parse_type,
+	util::CommentKind,
+	Parse,
+	SyntaxKind::*,
+	SyntaxNode, TextRange, TextSize,
+};
+
+/// A part of a JSDoc comment and its range in the source text
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsDocText {
+	pub text: String,
+	pub range: TextRange,
+}
+
+/// The type of a tag, e.g. `string` of `@type {string}`
+#[derive(Debug, Clone)]
+pub struct JsDocType {
+	/// The text between the braces
+	pub text: JsDocText,
+	/// The type parsed as a TypeScript type. The ranges of the parsed tree are relative to the start of the type text.
+	pub parse: Parse<TsType>,
+}
+
+impl JsDocType {
+	/// The parsed type, `None` if the type isn't a valid TypeScript type, e.g. the JSDoc specific type `{string=}`
+	pub fn ts_type(&self) -> Option<TsType> {
+		if self.parse.errors().is_empty() {
+			self.parse.try_tree()
+		} else {
+			None
+		}
+	}
+}
+
+/// The tags JSDoc gives a meaning
+#[derive(Debug, Clone)]
+pub enum JsDocTagKind {
+	/// `@param {type} name description`, or `@arg` and `@argument`.
+	/// The name of an optional parameter is written in brackets, `@param [name=default]`.
+	Param {
+		ty: Option<JsDocType>,
+		name: Option<JsDocText>,
+		optional: bool,
+	},
+	/// `@returns {type} description` or `@return`
+	Returns { ty: Option<JsDocType> },
+	/// `@type {type}`
+	Type { ty: Option<JsDocType> },
+	/// Any other tag, e.g. `@deprecated` or `@example`
+	Other,
+}
+
+/// A block tag of a JSDoc comment
+#[derive(Debug, Clone)]
+pub struct JsDocTag {
+	/// The name of the tag without the `@`
+	pub tag: JsDocText,
+	pub kind: JsDocTagKind,
+	/// The text after the type and the name of the tag. The ` - ` between the name of a parameter
+	/// and the description isn't part of the description.
+	pub description: Option<JsDocText>,
+	/// The range of the whole tag, from the `@` to the end of its last line
+	pub range: TextRange,
+}
+
+impl JsDocTag {
+	/// The type of a `@param`, `@returns`, or `@type` tag
+	pub fn ty(&self) -> Option<&JsDocType> {
+		match &self.kind {
+			JsDocTagKind::Param { ty, .. }
+			| JsDocTagKind::Returns { ty }
+			| JsDocTagKind::Type { ty } => ty.as_ref(),
+			JsDocTagKind::Other => None,
+		}
+	}
+
+	/// The name of a `@param` tag
+	pub fn name(&self) -> Option<&JsDocText> {
+		match &self.kind {
+			JsDocTagKind::Param { name, .. } => name.as_ref(),
+			_ => None,
+		}
+	}
+}
+
+/// A parsed JSDoc comment
+#[derive(Debug, Clone)]
+pub struct JsDoc {
+	/// The text in front of the first tag
+	pub description: Option<JsDocText>,
+	pub tags: Vec<JsDocTag>,
+	/// The range of the whole comment
+	pub range: TextRange,
+}
+
+impl JsDoc {
+	/// Parses `comment`, returns `None` if the comment isn't a JSDoc comment.
+	/// The types of the tags are parsed with the id `file_id`.
+	pub fn parse(comment: &SourceComment, file_id: usize) -> Option<Self> {
+		if comment.kind != CommentKind::JsDoc {
+			return None;
+		}
+
+		let start = comment.range.start() + TextSize::from(3);
+		let body = &comment.text[3..];
+		let body = body.strip_suffix("*/").unwrap_or(body);
+		let lines = content_lines(body, start);
+
+		let first_tag = lines
+			.iter()
+			.position(|line| line.text.starts_with('@'))
+			.unwrap_or(lines.len());
+		let description = Block::new(&lines[..first_tag]).text(0, usize::MAX);
+
+		let mut tags = vec![];
+		let mut rest = &lines[first_tag..];
+		while !rest.is_empty() {
+			let end = rest[1..]
+				.iter()
+				.position(|line| line.text.starts_with('@'))
+				.map_or(rest.len(), |index| index + 1);
+			tags.push(parse_tag(&Block::new(&rest[..end]), file_id));
+			rest = &rest[end..];
+		}
+
+		Some(Self {
+			description,
+			tags,
+			range: comment.range,
+		})
+	}
+
+	/// Returns the JSDoc comment of `node`, the last JSDoc comment of the leading comments of `node`.
+	/// The comment of a declaration is the comment of the export or variable statement that
+	/// contains it, e.g. the comment of `/** docs */ export function f() {}` documents the function.
+	pub fn of(comments: &Comments, node: &SyntaxNode, file_id: usize) -> Option<Self> {
+		let mut documented =
+			std::iter::once(node.clone()).chain(node.ancestors().skip(1).take_while(|ancestor| {
+				matches!(
+					ancestor.kind(),
+					EXPORT_DECL
+						| EXPORT_DEFAULT_DECL | EXPORT_DEFAULT_EXPR
+						| JS_VARIABLE_DECLARATION_STATEMENT
+						| JS_VARIABLE_DECLARATION
+						| JS_VARIABLE_DECLARATOR | LIST
+				)
+			}));
+
+		let comment = documented.find_map(|node| {
+			comments
+				.leading(&node)
+				.iter()
+				.rev()
+				.find(|comment| comment.kind == CommentKind::JsDoc)
+				.cloned()
+		})?;
+		Self::parse(&comment, file_id)
+	}
+
+	/// Returns the tags named `tag`, e.g. `deprecated` for `@deprecated`
+	pub fn tags_named<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a JsDocTag> {
+		self.tags.iter().filter(move |it| it.tag.text == tag)
+	}
+
+	/// Returns the `@param` tag of the parameter `name`
+	pub fn param(&self, name: &str) -> Option<&JsDocTag> {
+		self.tags
+			.iter()
+			.find(|tag| matches!(tag.name(), Some(text) if text.text == name))
+	}
+}
+
+/// A line of a comment without its indentation and the leading `*`
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+	text: &'a str,
+	start: TextSize,
+}
+
+fn content_lines(body: &str, start: TextSize) -> Vec<Line<'_>> {
+	let mut lines = vec![];
+	let mut offset = 0;
+	for line in body.split('\n') {
+		let line_start = offset;
+		offset += line.len() + 1;
+
+		let indent = line.len() - line.trim_start().len();
+		let content = &line[indent..];
+		let (content, star) = match content.strip_prefix('*') {
+			Some(content) => match content.strip_prefix(' ') {
+				Some(content) => (content, 2),
+				None => (content, 1),
+			},
+			None => (content, 0),
+		};
+		let content_start = line_start + indent + star;
+		lines.push(Line {
+			text: content.trim_end(),
+			start: start + TextSize::from(content_start as u32),
+		});
+	}
+	lines
+}
+
+/// The lines of a description or a tag joined by `\n`, which maps positions in the joined text back into the source
+struct Block {
+	text: String,
+	/// The position of every line in `text` and in the source text
+	lines: Vec<(usize, TextSize)>,
+}
+
+impl Block {
+	fn new(lines: &[Line]) -> Self {
+		let mut text = String::new();
+		let mut starts = vec![];
+		for line in lines {
+			if !starts.is_empty() {
+				text.push('\n');
+			}
+			starts.push((text.len(), line.start));
+			text.push_str(line.text);
+		}
+		Self {
+			text,
+			lines: starts,
+		}
+	}
+
+	fn offset(&self, index: usize) -> TextSize {
+		let (line, start) = self
+			.lines
+			.iter()
+			.take_while(|(line, _)| *line <= index)
+			.last()
+			.copied()
+			.unwrap_or_default();
+		start + TextSize::from((index - line) as u32)
+	}
+
+	/// The trimmed text between `start` and `end`, `None` if it's empty
+	fn text(&self, start: usize, end: usize) -> Option<JsDocText> {
+		let end = end.min(self.text.len());
+		let text = &self.text[start..end];
+		let trimmed = text.trim_start();
+		let start = start + (text.len() - trimmed.len());
+		let trimmed = trimmed.trim_end();
+		if trimmed.is_empty() {
+			return None;
+		}
+		let end = start + trimmed.len();
+		Some(JsDocText {
+			text: trimmed.to_string(),
+			range: TextRange::new(self.offset(start), self.offset(end)),
+		})
+	}
+}
+
+fn parse_tag(block: &Block, file_id: usize) -> JsDocTag {
+	let text = block.text.as_str();
+	let tag_end = text.find(char::is_whitespace).unwrap_or(text.len()).max(1);
+	let tag = JsDocText {
+		text: text[1..tag_end].to_string(),
+		range: TextRange::new(block.offset(1), block.offset(tag_end)),
+	};
+	let mut position = tag_end;
+
+	let mut ty = || -> Option<JsDocType> {
+		let start = position + skip_whitespace(&text[position..]);
+		if !text[start..].starts_with('{') {
+			return None;
+		}
+		let end = start + matching_brace(&text[start..])?;
+		position = end + 1;
+		let text = block.text(start + 1, end)?;
+		let parse = parse_type(&text.text, file_id);
+		Some(JsDocType { text, parse })
+	};
+
+	let kind = match tag.text.as_str() {
+		"param" | "arg" | "argument" => {
+			let ty = ty();
+			let start = position + skip_whitespace(&text[position..]);
+			let (name, optional) = if text[start..].starts_with('[') {
+				let end = text[start..]
+					.find(']')
+					.map_or(text.len(), |end| start + end);
+				position = (end + 1).min(text.len());
+				let name_end = text[start..end]
+					.find('=')
+					.map_or(end, |index| start + index);
+				(block.text(start + 1, name_end), true)
+			} else {
+				let end = text[start..]
+					.find(char::is_whitespace)
+					.map_or(text.len(), |end| start + end);
+				position = end;
+				(block.text(start, end), false)
+			};
+			let rest = &text[position..];
+			if let Some(dash) = rest.trim_start().strip_prefix('-') {
+				position = text.len() - dash.len();
+			}
+			JsDocTagKind::Param { ty, name, optional }
+		}
+		"returns" | "return" => JsDocTagKind::Returns { ty: ty() },
+		"type" => JsDocTagKind::Type { ty: ty() },
+		_ => JsDocTagKind::Other,
+	};
+
+	JsDocTag {
+		description: block.text(position, text.len()),
+		range: TextRange::new(block.offset(0), block.offset(text.len())),
+		tag,
+		kind,
+	}
+}
+
+fn skip_whitespace(text: &str) -> usize {
+	text.len() - text.trim_start().len()
+}
+
+/// The index of the `}` that closes the `{` at the start of `text`
+fn matching_brace(text: &str) -> Option<usize> {
+	let mut depth = 0;
+	for (index, c) in text.char_indices() {
+		match c {
+			'{' => depth += 1,
+			'}' => {
+				depth -= 1;
+				if depth == 0 {
+					return Some(index);
+				}
+			}
+			_ => {}
+		}
+	}
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{JsDoc, JsDocTagKind};
+	use crate::{ast::TsType, comments::Comments, parse_module, SyntaxKind::*};
+
+	const CODE: &str = "/**
+ * Greets a person.
+ * Twice if asked to.
+ *
+ * @param {string} name - The name of the person
+ * @param {{ twice: boolean }} [options={}] How to greet
+ * @returns {Promise<void>} Nothing
+ * @deprecated
+ */
+export function greet(name, options) {}
+
+/** @type {number=} */
+const count = 1;
+";
+
+	fn doc_of(code: &str, kind: crate::SyntaxKind) -> JsDoc {
+		let root = parse_module(code, 0).syntax();
+		let comments = Comments::new(&root);
+		let node = root.descendants().find(|node| node.kind() == kind).unwrap();
+		JsDoc::of(&comments, &node, 0).unwrap()
+	}
+
+	#[test]
+	fn description_and_tags() {
+		let doc = doc_of(CODE, JS_FUNCTION_DECLARATION);
+		let description = doc.description.as_ref().unwrap();
+		assert_eq!("Greets a person.\nTwice if asked to.", description.text);
+		assert_eq!("Greets a person.", &CODE[description.range][..16]);
+
+		let tags: Vec<_> = doc.tags.iter().map(|tag| tag.tag.text.as_str()).collect();
+		assert_eq!(vec!["param", "param", "returns", "deprecated"], tags);
+		assert_eq!(1, doc.tags_named("deprecated").count());
+		assert!(doc.tags[3].description.is_none());
+
+		let name = doc.param("name").unwrap();
+		assert_eq!(
+			"The name of the person",
+			name.description.as_ref().unwrap().text
+		);
+		assert_eq!("string", &CODE[name.ty().unwrap().text.range]);
+		assert_eq!("name", &CODE[name.name().unwrap().range]);
+		assert!(matches!(
+			name.ty().unwrap().ts_type(),
+			Some(TsType::TsString(_))
+		));
+		assert!(CODE[name.range].starts_with("@param {string} name"));
+
+		let options = doc.param("options").unwrap();
+		assert!(matches!(
+			options.kind,
+			JsDocTagKind::Param { optional: true, .. }
+		));
+		assert_eq!("How to greet", options.description.as_ref().unwrap().text);
+		assert!(matches!(
+			options.ty().unwrap().ts_type(),
+			Some(TsType::TsObjectType(_))
+		));
+
+		let returns = &doc.tags[2];
+		assert!(matches!(returns.kind, JsDocTagKind::Returns { .. }));
+		assert_eq!("Promise<void>", returns.ty().unwrap().text.text);
+		assert_eq!(
+			"Nothing",
+			&CODE[returns.description.as_ref().unwrap().range]
+		);
+	}
+
+	#[test]
+	fn jsdoc_types_are_not_typescript_types() {
+		let doc = doc_of(CODE, JS_VARIABLE_DECLARATOR);
+		assert!(doc.description.is_none());
+		let ty = doc.tags[0].ty().unwrap();
+		assert_eq!("number=", ty.text.text);
+		assert!(ty.ts_type().is_none());
+	}
+
+	#[test]
+	fn only_jsdoc_comments() {
+		let root = parse_module("/* not docs */\nfunction f() {}", 0).syntax();
+		let comments = Comments::new(&root);
+		let function = root
+			.descendants()
+			.find(|node| node.kind() == JS_FUNCTION_DECLARATION)
+			.unwrap();
+		assert!(JsDoc::of(&comments, &function, 0).is_none());
+	}
+}
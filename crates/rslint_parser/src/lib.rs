@@ -59,12 +59,16 @@
 mod parser;
 #[macro_use]
 mod token_set;
+mod cancellation;
+mod document;
 mod event;
 mod lossless_tree_sink;
 mod lossy_tree_sink;
 mod numbers;
 mod parse;
+mod reparse;
 mod state;
+mod strings;
 mod syntax_node;
 mod token_source;
 
@@ -73,25 +77,35 @@ mod tests;
 
 #[macro_use]
 pub mod ast;
+pub mod assists;
+pub mod comments;
+pub mod jsdoc;
+pub mod semantic;
 pub mod syntax;
 pub mod util;
 
 pub use crate::{
 	ast::{AstNode, AstNodeList, AstSeparatedList, AstToken, SyntaxError, SyntaxResult},
+	cancellation::{CancellationToken, Cancelled},
+	document::{ChangeError, Document, StaleVersion},
 	event::{process, Event},
 	lossless_tree_sink::LosslessTreeSink,
 	lossy_tree_sink::LossyTreeSink,
 	numbers::BigInt,
 	parse::*,
-	parser::{Checkpoint, CompletedMarker, Marker, ParseRecovery, Parser},
+	parser::{
+		Checkpoint, CompletedMarker, ContextGuard, Marker, ParseRecovery, Parser, ParsingContext,
+	},
+	reparse::reparse,
 	state::{ParserState, StrictMode},
+	strings::{parse_js_string, EscapeError, EscapeErrorKind},
 	syntax_node::*,
 	token_set::TokenSet,
-	token_source::TokenSource,
+	token_source::{SourceText, TokenSource},
 	util::{SyntaxNodeExt, SyntaxTokenExt},
 };
 
-pub use rome_rowan::{SyntaxText, TextRange, TextSize, WalkEvent};
+pub use rome_rowan::{NodeCache, SyntaxText, TextRange, TextSize, WalkEvent};
 
 pub use rslint_syntax::*;
 
@@ -170,7 +184,7 @@ macro_rules! match_ast {
 }
 
 /// A structure describing the syntax features the parser will accept. The
-/// default is an ECMAScript 2021 Script without any proposals.
+/// default is a Script of the latest ECMAScript version without any proposals.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Syntax {
 	pub file_kind: FileKind,
@@ -178,6 +192,23 @@ pub struct Syntax {
 	pub global_return: bool,
 	pub class_fields: bool,
 	pub decorators: bool,
+	/// Accept the legacy TypeScript decorators on the parameters of constructors and methods,
+	/// e.g. `constructor(@inject(TOKEN) private service: Service)`
+	pub parameter_decorators: bool,
+	/// Parse the file as JSX, e.g. a `.tsx` file. In TypeScript files, `<T>` starts a JSX element,
+	/// which disables the `<T>expr` type assertions and requires generic arrow functions to be
+	/// written as `<T,>(x) => x` or `<T extends U>(x) => x`. JSX elements aren't parsed yet.
+	pub jsx: bool,
+	/// Add a note naming the production the parser was attempting to the diagnostics of unknown nodes
+	pub unknown_node_context: bool,
+	/// Validate the patterns of regex literals, e.g. report unbalanced groups or invalid escapes in `u` mode
+	pub validate_regex: bool,
+	/// The ECMAScript version of the code, syntax introduced by a later version is reported.
+	/// TypeScript files accept the syntax of every version.
+	pub ecma_version: EcmaVersion,
+	/// Attach the tokens that error recovery skipped inside of lists as skipped trivia to the next token
+	/// instead of wrapping them in unknown nodes
+	pub skipped_trivia: bool,
 }
 
 impl Syntax {
@@ -212,6 +243,64 @@ impl Syntax {
 		self
 	}
 
+	pub fn parameter_decorators(mut self) -> Self {
+		self.parameter_decorators = true;
+		self
+	}
+
+	pub fn jsx(mut self) -> Self {
+		self.jsx = true;
+		self
+	}
+
+	pub fn unknown_node_context(mut self) -> Self {
+		self.unknown_node_context = true;
+		self
+	}
+
+	pub fn validate_regex(mut self) -> Self {
+		self.validate_regex = true;
+		self
+	}
+
+	/// Reports the syntax introduced by ECMAScript versions later than `version`.
+	///
+	/// The version is ignored in TypeScript files because the TypeScript compiler downlevels the
+	/// syntax of later versions to the version it targets.
+	///
+	/// ```
+	/// use rslint_parser::{parse_expression, EcmaVersion, Syntax};
+	///
+	/// let syntax = Syntax::default().ecma_version(EcmaVersion::Es2018);
+	/// let parse = parse_expression("a?.b", 0, syntax);
+	/// assert_eq!(
+	///     "optional chaining requires ES2020 or later, but the configured version is ES2018",
+	///     parse.errors()[0].title
+	/// );
+	///
+	/// let syntax = Syntax::default().typescript().ecma_version(EcmaVersion::Es2018);
+	/// assert!(parse_expression("a?.b", 0, syntax).errors().is_empty());
+	/// ```
+	pub fn ecma_version(mut self, version: EcmaVersion) -> Self {
+		self.ecma_version = version;
+		self
+	}
+
+	/// Attaches the tokens that error recovery skipped inside of lists to the leading trivia of the next token
+	///
+	/// ```
+	/// use rslint_parser::{parse, Syntax};
+	///
+	/// let parse = parse("a; ) b;", 0, Syntax::default().skipped_trivia());
+	/// assert!(!parse.errors().is_empty());
+	/// // `)` is in the leading trivia of `b`, the statements are `a;` and `b;`
+	/// assert_eq!(2, parse.tree().statements().len());
+	/// ```
+	pub fn skipped_trivia(mut self) -> Self {
+		self.skipped_trivia = true;
+		self
+	}
+
 	pub fn script(mut self) -> Self {
 		self.file_kind = FileKind::Script;
 		self
@@ -224,7 +313,85 @@ impl Syntax {
 
 	pub fn typescript(mut self) -> Self {
 		self.file_kind = FileKind::TypeScript;
-		self.class_fields().decorators().top_level_await()
+		self.class_fields()
+			.decorators()
+			.parameter_decorators()
+			.top_level_await()
+	}
+
+	/// The syntax of `.tsx` files, TypeScript with JSX
+	pub fn tsx(self) -> Self {
+		self.typescript().jsx()
+	}
+
+	/// Returns the syntax of the files with the given extension, or [None] if the extension
+	/// isn't the extension of a JavaScript or TypeScript file.
+	///
+	/// ```
+	/// use rslint_parser::{FileKind, Syntax};
+	///
+	/// assert_eq!(Some(Syntax::default().tsx()), Syntax::from_extension("tsx"));
+	/// assert_eq!(FileKind::Module, Syntax::from_extension("mjs").unwrap().file_kind);
+	/// assert_eq!(None, Syntax::from_extension("json"));
+	/// ```
+	pub fn from_extension(extension: &str) -> Option<Self> {
+		let syntax = match extension {
+			"js" | "cjs" => Syntax::default(),
+			"jsx" => Syntax::default().jsx(),
+			"mjs" => Syntax::default().module(),
+			"ts" | "mts" | "cts" => Syntax::default().typescript(),
+			"tsx" => Syntax::default().tsx(),
+			_ => return None,
+		};
+		Some(syntax)
+	}
+
+	/// Whether files with this syntax are parsed as classic scripts or as ES modules
+	pub fn source_type(&self) -> JsSourceType {
+		match self.file_kind {
+			FileKind::Script => JsSourceType::Script,
+			FileKind::Module | FileKind::TypeScript => JsSourceType::Module,
+		}
+	}
+}
+
+/// An edition of the ECMAScript specification, see [`Syntax::ecma_version`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EcmaVersion {
+	Es5,
+	Es2015,
+	Es2016,
+	Es2017,
+	Es2018,
+	Es2019,
+	Es2020,
+	Es2021,
+	Es2022,
+}
+
+impl EcmaVersion {
+	/// The latest version supported by the parser
+	pub const LATEST: EcmaVersion = EcmaVersion::Es2022;
+
+	/// The name of the version, e.g. `ES2015`
+	pub fn name(&self) -> &'static str {
+		match self {
+			EcmaVersion::Es5 => "ES5",
+			EcmaVersion::Es2015 => "ES2015",
+			EcmaVersion::Es2016 => "ES2016",
+			EcmaVersion::Es2017 => "ES2017",
+			EcmaVersion::Es2018 => "ES2018",
+			EcmaVersion::Es2019 => "ES2019",
+			EcmaVersion::Es2020 => "ES2020",
+			EcmaVersion::Es2021 => "ES2021",
+			EcmaVersion::Es2022 => "ES2022",
+		}
+	}
+}
+
+impl Default for EcmaVersion {
+	fn default() -> Self {
+		EcmaVersion::LATEST
 	}
 }
 
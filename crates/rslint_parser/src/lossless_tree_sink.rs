@@ -1,28 +1,64 @@
 use crate::{
-	ParserError,
+	JsLanguage, ParserError, SourceText, Syntax,
 	SyntaxKind::{self, *},
 	SyntaxNode, SyntaxTreeBuilder, TextRange, TextSize, TreeSink,
 };
-use rome_rowan::TriviaPiece;
+use rome_rowan::{GreenBuilder, NodeCache, TriviaPiece};
 use rslint_lexer::Token;
-use std::mem;
+use std::{cmp::Reverse, mem};
+
+#[cfg(feature = "arena")]
+use rome_rowan::arena::{ArenaNode, ArenaTreeBuilder, GreenArena};
 
 /// Structure for converting events to a syntax tree representation, while preserving whitespace.
 ///
 /// `LosslessTreeSink` also handles attachment of trivia (whitespace) to nodes.
+///
+/// The tree is built with a [SyntaxTreeBuilder] by default, or with any other [GreenBuilder],
+/// like the arena builder that [LosslessTreeSink::in_arena] uses with the `arena` feature.
 #[derive(Debug)]
-pub struct LosslessTreeSink<'a> {
-	text: &'a str,
+pub struct LosslessTreeSink<'a, B = SyntaxTreeBuilder<'a>> {
+	text: SourceText<'a>,
 	tokens: &'a [Token],
 	text_pos: TextSize,
 	token_pos: usize,
 	state: State,
 	errors: Vec<ParserError>,
-	inner: SyntaxTreeBuilder,
+	inner: B,
 	/// Signal that the sink must generate an EOF token when its finishing. See [LosslessTreeSink::finish] for more details.
 	needs_eof: bool,
 	/// Trivia start Offset and its pieces.
 	next_token_leading_trivia: (TextRange, Vec<TriviaPiece>),
+	/// Attach the tokens of unknown nodes in lists to the next token, see [LosslessTreeSink::with_syntax].
+	skipped_trivia: bool,
+	/// The kinds of the nodes that are started but not finished yet
+	parents: Vec<SyntaxKind>,
+	/// The unknown list element that is started but only added to the tree if it turns out that
+	/// it contains more than tokens.
+	pending_skipped: Option<PendingSkipped>,
+	/// The ranges and messages of the errors that aren't recorded on a node yet, sorted by the end of
+	/// their range in descending order. The ranges are relative to the start of the tree.
+	unattached_errors: Vec<(TextRange, String)>,
+	/// The errors that end before the current node but start before it too, they are recorded on an ancestor
+	ended_errors: Vec<(TextRange, String)>,
+	/// The file of the errors reported by the sink itself, see [LosslessTreeSink::with_file_id]
+	file_id: usize,
+}
+
+#[derive(Debug)]
+struct PendingSkipped {
+	kind: SyntaxKind,
+	/// The number of nested unknown nodes that are started but not finished yet
+	depth: usize,
+	events: Vec<PendingEvent>,
+}
+
+#[derive(Debug)]
+enum PendingEvent {
+	Start(SyntaxKind),
+	Token(SyntaxKind),
+	Missing,
+	Finish,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -32,11 +68,12 @@ enum State {
 	PendingFinish,
 }
 
-impl<'a> TreeSink for LosslessTreeSink<'a> {
+impl<'a, B: GreenBuilder<JsLanguage>> TreeSink for LosslessTreeSink<'a, B> {
 	fn consume_multiple_tokens(&mut self, amount: u8, kind: SyntaxKind) {
+		self.start_pending_skipped();
 		match mem::replace(&mut self.state, State::Normal) {
 			State::PendingStart => unreachable!(),
-			State::PendingFinish => self.inner.finish_node(),
+			State::PendingFinish => self.finish_inner_node(),
 			State::Normal => (),
 		}
 
@@ -51,13 +88,18 @@ impl<'a> TreeSink for LosslessTreeSink<'a> {
 		let text = &self.text[range];
 		self.text_pos += len;
 		self.token_pos += amount as usize;
-		self.inner.token(kind, text);
+		self.inner.token_with_trivia(kind, text, vec![], vec![]);
 	}
 
 	fn token(&mut self, kind: SyntaxKind) {
+		if let Some(pending) = &mut self.pending_skipped {
+			pending.events.push(PendingEvent::Token(kind));
+			return;
+		}
+
 		match mem::replace(&mut self.state, State::Normal) {
 			State::PendingStart => unreachable!(),
-			State::PendingFinish => self.inner.finish_node(),
+			State::PendingFinish => self.finish_inner_node(),
 			State::Normal => (),
 		}
 
@@ -66,9 +108,14 @@ impl<'a> TreeSink for LosslessTreeSink<'a> {
 	}
 
 	fn missing(&mut self) {
+		if let Some(pending) = &mut self.pending_skipped {
+			pending.events.push(PendingEvent::Missing);
+			return;
+		}
+
 		match mem::replace(&mut self.state, State::Normal) {
 			State::PendingStart => unreachable!(),
-			State::PendingFinish => self.inner.finish_node(),
+			State::PendingFinish => self.finish_inner_node(),
 			State::Normal => (),
 		}
 
@@ -76,53 +123,149 @@ impl<'a> TreeSink for LosslessTreeSink<'a> {
 	}
 
 	fn start_node(&mut self, kind: SyntaxKind) {
+		let parent = self.parents.last().copied();
+		self.parents.push(kind);
+
+		match &mut self.pending_skipped {
+			Some(pending) if is_recovery_kind(kind) => {
+				pending.depth += 1;
+				pending.events.push(PendingEvent::Start(kind));
+				return;
+			}
+			Some(_) => self.start_pending_skipped(),
+			None => (),
+		}
+
 		match mem::replace(&mut self.state, State::Normal) {
 			State::PendingStart => {
 				self.inner.start_node(kind);
 				self.next_token_leading_trivia = self.get_trivia(false);
 				return;
 			}
-			State::PendingFinish => self.inner.finish_node(),
+			State::PendingFinish => self.finish_inner_node(),
 			State::Normal => (),
 		}
 
-		self.inner.start_node(kind);
+		if self.skipped_trivia && parent == Some(LIST) && is_recovery_kind(kind) {
+			self.pending_skipped = Some(PendingSkipped {
+				kind,
+				depth: 0,
+				events: vec![],
+			});
+		} else {
+			self.inner.start_node(kind);
+		}
 	}
 
 	fn finish_node(&mut self) {
+		self.parents.pop();
+		match &mut self.pending_skipped {
+			Some(pending) if pending.depth > 0 => {
+				pending.depth -= 1;
+				pending.events.push(PendingEvent::Finish);
+				return;
+			}
+			Some(_) => {
+				let pending = self.pending_skipped.take().unwrap();
+				for event in pending.events {
+					if let PendingEvent::Token(_) = event {
+						self.skip_token();
+					}
+				}
+				return;
+			}
+			None => (),
+		}
+
 		match mem::replace(&mut self.state, State::PendingFinish) {
 			State::PendingStart => unreachable!(),
-			State::PendingFinish => self.inner.finish_node(),
+			State::PendingFinish => self.finish_inner_node(),
 			State::Normal => (),
 		}
 	}
 
 	fn errors(&mut self, errors: Vec<ParserError>) {
+		// The tree starts at the current position
+		let start = self.text_pos;
+		self.unattached_errors = errors
+			.iter()
+			.filter_map(|error| {
+				let span = &error.primary.as_ref()?.span.range;
+				if span.start > span.end {
+					return None;
+				}
+				let range = TextRange::new((span.start as u32).into(), (span.end as u32).into());
+				Some((range.checked_sub(start)?, error.title.clone()))
+			})
+			.collect();
+		self.unattached_errors
+			.sort_by_key(|(range, _)| Reverse(range.end()));
 		self.errors = errors;
 	}
 }
 
 impl<'a> LosslessTreeSink<'a> {
-	pub fn new(text: &'a str, tokens: &'a [Token]) -> Self {
+	pub fn new(text: impl Into<SourceText<'a>>, tokens: &'a [Token]) -> Self {
+		JsLanguage::register_common_tokens();
+		Self::with_builder(text, tokens, SyntaxTreeBuilder::default())
+	}
+
+	/// Make a new tree sink for text parsed with `syntax`.
+	///
+	/// With [Syntax::skipped_trivia()], the tokens of unknown elements of lists are attached as [TriviaPiece::Skipped]
+	/// to the leading trivia of the next token instead of adding the unknown nodes to the tree. The elements of the
+	/// lists are then valid nodes even if the parser had to skip tokens to recover from an error.
+	/// Unknown nodes with children other than tokens and unknown nodes are kept because the children may still be of use.
+	pub fn with_syntax(
+		text: impl Into<SourceText<'a>>,
+		tokens: &'a [Token],
+		syntax: Syntax,
+	) -> Self {
 		Self {
-			text,
-			tokens,
-			text_pos: 0.into(),
-			token_pos: 0,
-			state: State::PendingStart,
-			inner: SyntaxTreeBuilder::default(),
-			errors: vec![],
-			needs_eof: true,
-			next_token_leading_trivia: (TextRange::at(0.into(), 0.into()), vec![]),
+			skipped_trivia: syntax.skipped_trivia,
+			..Self::new(text, tokens)
 		}
 	}
 
+	/// Reuse the unchanged nodes of `old_root`, the tree of a previous version of the text, instead of
+	/// allocating new ones, see [TreeBuilder::with_reuse](rome_rowan::TreeBuilder::with_reuse).
+	pub fn with_reuse(mut self, old_root: &SyntaxNode) -> Self {
+		self.inner = mem::take(&mut self.inner).with_reuse(old_root);
+		self
+	}
+
+	/// Make a new tree sink for text parsed with `syntax` that stores the tokens and nodes of the tree in `cache`.
+	/// Sharing the cache between the trees of several files stores the tokens that repeat
+	/// across the files, like `require` or `console`, only once.
+	pub fn with_cache(
+		text: impl Into<SourceText<'a>>,
+		tokens: &'a [Token],
+		syntax: Syntax,
+		cache: &'a mut NodeCache,
+	) -> Self {
+		Self {
+			inner: SyntaxTreeBuilder::with_cache(cache),
+			..Self::with_syntax(text, tokens, syntax)
+		}
+	}
+
+	/// Reports the errors found by the sink itself, like a text that is too long, in `file_id`
+	pub fn with_file_id(mut self, file_id: usize) -> Self {
+		self.file_id = file_id;
+		self
+	}
+
 	/// Make a new tree sink but start the sink at a specific token, this is used for making completed markers
 	/// into AST nodes for rules which need them.
 	///
 	/// # Panics
 	/// Panics if the token start does not line up to a token's start index or is out of bounds
-	pub fn with_offset(text: &'a str, tokens: &'a [Token], token_start: usize) -> Self {
+	pub fn with_offset(
+		text: impl Into<SourceText<'a>>,
+		tokens: &'a [Token],
+		token_start: usize,
+	) -> Self {
+		let text = text.into();
 		let mut len = 0;
 		for (idx, tok) in tokens.iter().enumerate() {
 			if len == token_start {
@@ -136,6 +279,12 @@ impl<'a> LosslessTreeSink<'a> {
 					errors: vec![],
 					needs_eof: true,
 					next_token_leading_trivia: (TextRange::at(0.into(), 0.into()), vec![]),
+					skipped_trivia: false,
+					parents: vec![],
+					pending_skipped: None,
+					unattached_errors: vec![],
+					ended_errors: vec![],
+					file_id: 0,
 				};
 			}
 			len += tok.len;
@@ -147,17 +296,79 @@ impl<'a> LosslessTreeSink<'a> {
 	///
 	/// If tree is finished without a [SyntaxKind::EOF], one will be generated and all pending trivia
 	/// will be appended to its leading trivia.
-	pub fn finish(mut self) -> (SyntaxNode, Vec<ParserError>) {
+	///
+	/// If the text is too long for the offsets of the tree, the tree ends at the first token that
+	/// doesn't fit and an error is reported, see [TreeBuilder::finish_truncated](rome_rowan::TreeBuilder::finish_truncated).
+	pub fn finish(self) -> (SyntaxNode, Vec<ParserError>) {
+		let file_id = self.file_id;
+		let (inner, mut errors) = self.finish_builder();
+		let (root, overflow) = inner.finish_truncated();
+		if let Some(overflow) = overflow {
+			errors.push(ParserError::error(file_id, "", overflow.to_string()));
+		}
+		(root, errors)
+	}
+}
+
+#[cfg(feature = "arena")]
+impl<'a, 'arena> LosslessTreeSink<'a, ArenaTreeBuilder<'arena, JsLanguage>> {
+	/// Make a new tree sink for text parsed with `syntax` that allocates the tree in `arena`,
+	/// see [ArenaTreeBuilder].
+	pub fn in_arena(
+		text: impl Into<SourceText<'a>>,
+		tokens: &'a [Token],
+		syntax: Syntax,
+		arena: &'arena GreenArena,
+	) -> Self {
+		Self {
+			skipped_trivia: syntax.skipped_trivia,
+			..Self::with_builder(text, tokens, ArenaTreeBuilder::new(arena))
+		}
+	}
+
+	/// Finishes the tree and return its root node with possible parser errors, see [LosslessTreeSink::finish].
+	pub fn finish(self) -> (&'arena ArenaNode<'arena>, Vec<ParserError>) {
+		let (inner, errors) = self.finish_builder();
+		(inner.finish(), errors)
+	}
+}
+
+impl<'a, B: GreenBuilder<JsLanguage>> LosslessTreeSink<'a, B> {
+	fn with_builder(text: impl Into<SourceText<'a>>, tokens: &'a [Token], inner: B) -> Self {
+		Self {
+			text: text.into(),
+			tokens,
+			text_pos: 0.into(),
+			token_pos: 0,
+			state: State::PendingStart,
+			inner,
+			errors: vec![],
+			needs_eof: true,
+			next_token_leading_trivia: (TextRange::at(0.into(), 0.into()), vec![]),
+			skipped_trivia: false,
+			parents: vec![],
+			pending_skipped: None,
+			unattached_errors: vec![],
+			ended_errors: vec![],
+			file_id: 0,
+		}
+	}
+
+	/// Finishes the tree and returns the builder to finish with the parser errors.
+	///
+	/// If tree is finished without a [SyntaxKind::EOF], one will be generated and all pending trivia
+	/// will be appended to its leading trivia.
+	fn finish_builder(mut self) -> (B, Vec<ParserError>) {
 		if self.needs_eof {
 			self.do_token(SyntaxKind::EOF, 0.into());
 		}
 
 		match mem::replace(&mut self.state, State::Normal) {
-			State::PendingFinish => self.inner.finish_node(),
+			State::PendingFinish => self.finish_inner_node(),
 			State::PendingStart | State::Normal => unreachable!(),
 		}
 
-		(self.inner.finish(), self.errors)
+		(self.inner, self.errors)
 	}
 
 	fn do_token(&mut self, kind: SyntaxKind, len: TextSize) {
@@ -182,9 +393,66 @@ impl<'a> LosslessTreeSink<'a> {
 			std::mem::replace(&mut self.next_token_leading_trivia, next_token_leading);
 
 		let range = leading_range.cover(token_range).cover(trailing_range);
-		let text = &self.text[range];
+		let text = self.text.slice(range);
+
+		self.inner.token_with_trivia(kind, &text, leading, trailing);
+	}
+
+	/// Finishes the current node of the tree and records the errors that are inside of the node
+	/// but not inside of any of its children on it.
+	fn finish_inner_node(&mut self) {
+		if self.unattached_errors.is_empty() && self.ended_errors.is_empty() {
+			self.inner.finish_node();
+			return;
+		}
+
+		let node_range = self.inner.current_node_range().unwrap();
+		while let Some((range, _)) = self.unattached_errors.last() {
+			if range.end() > node_range.end() {
+				break;
+			}
+			let error = self.unattached_errors.pop().unwrap();
+			self.ended_errors.push(error);
+		}
+
+		let (inside, outside) = mem::take(&mut self.ended_errors)
+			.into_iter()
+			.partition(|(range, _)| node_range.contains_range(*range));
+		self.ended_errors = outside;
+		for (range, message) in inside {
+			self.inner.error(range, message);
+		}
+
+		self.inner.finish_node();
+	}
 
-		self.inner.token_with_trivia(kind, text, leading, trailing);
+	/// Starts the unknown list element that turned out to contain more than tokens
+	/// and adds the children it has got so far.
+	fn start_pending_skipped(&mut self) {
+		if let Some(pending) = self.pending_skipped.take() {
+			self.inner.start_node(pending.kind);
+			for event in pending.events {
+				match event {
+					PendingEvent::Start(kind) => self.inner.start_node(kind),
+					PendingEvent::Token(kind) => self.token(kind),
+					PendingEvent::Missing => self.inner.missing(),
+					PendingEvent::Finish => self.finish_inner_node(),
+				}
+			}
+		}
+	}
+
+	/// Appends the current token and the trivia following it to the leading trivia of the next token
+	fn skip_token(&mut self) {
+		let len = self.tokens[self.token_pos].len;
+		self.text_pos += TextSize::from(len as u32);
+		self.token_pos += 1;
+
+		let (trivia_range, trivia) = self.get_trivia(false);
+		let (range, pieces) = &mut self.next_token_leading_trivia;
+		pieces.push(TriviaPiece::Skipped(len));
+		pieces.extend(trivia);
+		*range = range.cover(trivia_range);
 	}
 
 	fn get_trivia(&mut self, break_on_newline: bool) -> (TextRange, Vec<TriviaPiece>) {
@@ -198,9 +466,7 @@ impl<'a> LosslessTreeSink<'a> {
 				break;
 			}
 
-			let pos: u32 = self.text_pos.into();
-			let pos = pos as usize;
-			let text = &self.text[pos..(pos + token.len)];
+			let text = &self.text[TextRange::at(self.text_pos, TextSize::from(token.len as u32))];
 			if break_on_newline && text.chars().any(rslint_lexer::is_linebreak) {
 				break;
 			}
@@ -212,6 +478,7 @@ impl<'a> LosslessTreeSink<'a> {
 
 			let current_trivia = match token.kind {
 				WHITESPACE => TriviaPiece::Whitespace(token.len),
+				NEWLINE => TriviaPiece::Newline(token.len),
 				COMMENT => TriviaPiece::Comments(token.len),
 				_ => unreachable!("Not Trivia"),
 			};
@@ -222,3 +489,8 @@ impl<'a> LosslessTreeSink<'a> {
 		(TextRange::at(start_text_pos, length), trivia)
 	}
 }
+
+/// Returns `true` for the kinds of the nodes the parser wraps the tokens in that it skipped to recover from an error
+fn is_recovery_kind(kind: SyntaxKind) -> bool {
+	kind == ERROR || crate::parser::is_unknown_kind(kind)
+}
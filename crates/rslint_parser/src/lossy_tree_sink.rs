@@ -13,7 +13,7 @@ pub struct LossyTreeSink<'a> {
 	text_pos: TextSize,
 	token_pos: usize,
 	state: State,
-	inner: SyntaxTreeBuilder,
+	inner: SyntaxTreeBuilder<'a>,
 	errors: Vec<ParserError>,
 	/// Signal that the sink must generate an EOF token when its finishing. See [LosslessTreeSink::finish] for more details.
 	needs_eof: bool,
@@ -224,7 +224,7 @@ impl<'a> LossyTreeSink<'a> {
 			length += len;
 
 			let current_trivia = match token.kind {
-				SyntaxKind::WHITESPACE => continue,
+				SyntaxKind::WHITESPACE | SyntaxKind::NEWLINE => continue,
 				SyntaxKind::COMMENT => TriviaPiece::Comments(token.len),
 				_ => unreachable!("Not Trivia"),
 			};
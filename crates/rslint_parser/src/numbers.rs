@@ -17,11 +17,9 @@ fn split_into_radix_and_number(num: &str) -> (u32, String) {
 pub fn parse_js_number(num: &str) -> Option<f64> {
 	let (radix, raw) = split_into_radix_and_number(num);
 
-	if radix == 10 && raw.starts_with('0') {
-		// account for legacy octal literals
-		if let Ok(parsed) = parse_radix::<f64, _>(raw.as_bytes(), 8) {
-			return Some(parsed);
-		}
+	// account for legacy octal literals such as `017`, while `019` and `0.5` are decimals
+	if radix == 10 && raw.starts_with('0') && raw.bytes().all(|b| matches!(b, b'0'..=b'7')) {
+		return parse_radix::<f64, _>(raw.as_bytes(), 8).ok();
 	}
 
 	parse_radix::<f64, _>(raw.as_bytes(), radix as u8).ok()
@@ -115,6 +113,45 @@ mod tests {
 		assert_float!("058", 58.0);
 	}
 
+	#[test]
+	fn decimals_and_exponents() {
+		assert_float!("0.5", 0.5);
+		assert_float!(".5", 0.5);
+		assert_float!("5.", 5.0);
+		assert_float!("08.5", 8.5);
+		assert_float!("1e3", 1000.0);
+		assert_float!("1E+3", 1000.0);
+		assert_float!("2.5e-3", 0.0025);
+		assert_float!("0e5", 0.0);
+		assert_float!("0.1", 0.1);
+	}
+
+	#[test]
+	fn numeric_separators() {
+		assert_float!("1_000_000", 1_000_000.0);
+		assert_float!("1_0.0_1e1_0", 10.01e10);
+		assert_float!("0xF_F", 255.0);
+		assert_float!("0b1_0", 2.0);
+		assert_float!("0o7_7", 63.0);
+	}
+
+	#[test]
+	fn large_non_decimal_numbers() {
+		assert_float!("0xFFFFFFFFFFFFFFFFF", 295147905179352830000.0);
+		assert_float!("0x20000000000001", 9007199254740992.0);
+		assert_float!(
+			"0b11111111111111111111111111111111111111111111111111111",
+			9007199254740991.0
+		);
+	}
+
+	#[test]
+	fn as_f64_ignores_trivia() {
+		let parsed = parse_expr("  0x10 /* comment */", 0);
+		let literal = JsNumberLiteralExpression::cast(parsed.syntax()).unwrap();
+		assert_eq!(literal.as_f64(), Some(16.0));
+	}
+
 	#[test]
 	fn base_10_bigint() {
 		assert_bigint!("1010n", 1010);
@@ -143,4 +180,41 @@ mod tests {
 			9007199254740991
 		);
 	}
+
+	#[test]
+	fn bigint_number_text() {
+		let parsed = parse_expr("0xFF_FFn", 0);
+		let literal = JsBigIntLiteralExpression::cast(parsed.syntax()).unwrap();
+		assert_eq!(literal.number_text(), "0xFF_FF");
+	}
+
+	#[test]
+	fn invalid_bigint() {
+		let errors = |text| {
+			parse_expr(text, 0)
+				.errors()
+				.iter()
+				.map(|err| err.title.clone())
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(
+			errors("1.5n"),
+			vec!["BigInt literals cannot have a decimal point"]
+		);
+		assert_eq!(
+			errors("1e10n"),
+			vec!["BigInt literals cannot have an exponent"]
+		);
+		assert_eq!(
+			errors("017n"),
+			vec!["legacy octal literals cannot be BigInts"]
+		);
+		assert_eq!(
+			errors("09n"),
+			vec!["BigInt literals cannot have leading zeros"]
+		);
+		assert!(errors("0n").is_empty());
+		assert!(errors("0xEn").is_empty());
+	}
 }
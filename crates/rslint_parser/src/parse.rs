@@ -1,11 +1,19 @@
 //! Utilities for high level parsing of js code.
 
 use crate::{
-	ast::{JsAnyExpression, JsRoot},
+	ast::{JsAnyExpression, JsAnyStatement, JsFunctionBody, JsRoot, TsType},
+	reparse::map_error_ranges,
 	*,
 };
+#[cfg(feature = "arena")]
+use rome_rowan::arena::{ArenaNode, GreenArena};
 use rslint_errors::Severity;
-use std::marker::PhantomData;
+use rslint_lexer::{Lexer, LexerCheckpoint};
+use std::{
+	borrow::Cow,
+	io::{self, Read},
+	marker::PhantomData,
+};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum JsSourceType {
@@ -70,6 +78,43 @@ impl<T> Parse<T> {
 	pub fn source_type(&self) -> JsSourceType {
 		self.source_type
 	}
+
+	/// Whether `node` is strict mode code: the code of modules and classes, and the code of
+	/// files and functions whose directive prologue contains a `"use strict"` directive.
+	///
+	/// ```
+	/// use rslint_parser::{ast::JsReturnStatement, parse_text, AstNode};
+	///
+	/// let parse = parse_text("function a() { return 1 }\nfunction b() { 'use strict'; return 2 }", 0);
+	/// let strict = parse
+	///     .syntax()
+	///     .descendants()
+	///     .filter_map(JsReturnStatement::cast)
+	///     .map(|stmt| parse.is_strict(stmt.syntax()))
+	///     .collect::<Vec<_>>();
+	/// assert_eq!(vec![false, true], strict);
+	/// ```
+	pub fn is_strict(&self, node: &SyntaxNode) -> bool {
+		is_strict(node, self.source_type)
+	}
+}
+
+/// Whether `node` of a file of `source_type` is strict mode code, see [`Parse::is_strict`]
+pub(crate) fn is_strict(node: &SyntaxNode, source_type: JsSourceType) -> bool {
+	if source_type == JsSourceType::Module {
+		return true;
+	}
+
+	node.ancestors().any(|node| match node.kind() {
+		SyntaxKind::JS_CLASS_DECLARATION | SyntaxKind::JS_CLASS_EXPRESSION => true,
+		SyntaxKind::JS_ROOT => {
+			matches!(JsRoot::cast(node), Some(root) if root.has_use_strict())
+		}
+		_ => matches!(
+			node.children().find_map(JsFunctionBody::cast),
+			Some(body) if body.has_use_strict()
+		),
+	})
 }
 
 impl<T: AstNode> Parse<T> {
@@ -109,22 +154,178 @@ pub fn tokenize(text: &str, file_id: usize) -> (Vec<rslint_lexer::Token>, Vec<Pa
 	(tokens, errors)
 }
 
+/// Same as [`tokenize`] but takes the text in chunks and doesn't copy the chunks into one buffer, see [`parse_chunks`].
+///
+/// No token spans two pieces of the returned text. The lexer needs contiguous text, so a chunk is only lexed until
+/// its last whitespace and lexing continues from there with the start of the next chunk. Only the text between the
+/// last whitespace of a chunk and the first whitespace of the next chunk is copied.
+pub fn tokenize_chunks<'a>(
+	chunks: impl IntoIterator<Item = &'a str>,
+	file_id: usize,
+) -> (SourceText<'a>, Vec<rslint_lexer::Token>, Vec<ParserError>) {
+	let mut lexer = ChunkLexer {
+		file_id,
+		text: SourceText::new(),
+		tokens: vec![],
+		errors: vec![],
+		checkpoint: LexerCheckpoint::default(),
+	};
+	// The text after the last whitespace that is lexed
+	let mut pending = String::new();
+
+	for chunk in chunks {
+		let mut rest = chunk;
+		if !pending.is_empty() {
+			// Lex the pending text with a growing part of the start of the chunk until there's a whitespace in it
+			let mut prefix = 0;
+			loop {
+				prefix = char_boundary(chunk, (prefix * 2).max(MIN_CHUNK_PREFIX));
+				let mut window = pending.clone();
+				window.push_str(&chunk[..prefix]);
+
+				match lexer.lex(&window, pending.len()) {
+					Some(end) => {
+						rest = &chunk[end - pending.len()..];
+						window.truncate(end);
+						lexer.text.push(Cow::Owned(window));
+						pending.clear();
+						break;
+					}
+					None if prefix == chunk.len() => {
+						rest = "";
+						pending = window;
+						break;
+					}
+					None => {}
+				}
+			}
+		}
+
+		if !rest.is_empty() {
+			let end = lexer.lex(rest, 0).unwrap_or(0);
+			if end != 0 {
+				lexer.text.push(Cow::Borrowed(&rest[..end]));
+			}
+			pending.push_str(&rest[end..]);
+		}
+	}
+
+	lexer.lex_end(pending);
+	(lexer.text, lexer.tokens, lexer.errors)
+}
+
+/// The length of the start of a chunk that is first lexed with the text of the previous chunks that isn't lexed yet
+const MIN_CHUNK_PREFIX: usize = 64;
+
+/// The first char boundary of `text` at or after `idx`
+fn char_boundary(text: &str, idx: usize) -> usize {
+	let mut idx = idx.min(text.len());
+	while !text.is_char_boundary(idx) {
+		idx += 1;
+	}
+	idx
+}
+
+struct ChunkLexer<'a> {
+	file_id: usize,
+	text: SourceText<'a>,
+	tokens: Vec<rslint_lexer::Token>,
+	errors: Vec<ParserError>,
+	/// The state of the lexer at the end of `text`
+	checkpoint: LexerCheckpoint,
+}
+
+impl ChunkLexer<'_> {
+	/// Lexes `text`, which follows the lexed text, until its last whitespace that ends at or after `min_end`
+	/// and that is followed by another token. The tokens after the whitespace may continue in the next chunk.
+	///
+	/// Returns the end of the whitespace, or `None` without lexing anything if there's no such whitespace.
+	fn lex(&mut self, text: &str, min_end: usize) -> Option<usize> {
+		let mut lexer = Lexer::from_checkpoint(text, self.file_id, self.checkpoint.clone());
+		let mut tokens = vec![];
+		let mut end = 0;
+		let mut last_whitespace = None;
+
+		while let Some((token, error)) = lexer.next() {
+			if token.kind == SyntaxKind::EOF {
+				break;
+			}
+			end += token.len;
+			tokens.push((token, error));
+			let is_whitespace = matches!(token.kind, SyntaxKind::WHITESPACE | SyntaxKind::NEWLINE);
+			if is_whitespace && end >= min_end && end < text.len() {
+				last_whitespace = Some((tokens.len(), end, lexer.checkpoint()));
+			}
+		}
+
+		let (count, end, checkpoint) = last_whitespace?;
+		self.checkpoint = checkpoint;
+		self.push_tokens(tokens.into_iter().take(count));
+		Some(end)
+	}
+
+	/// Lexes the text at the end of the file
+	fn lex_end(&mut self, text: String) {
+		let lexer = Lexer::from_checkpoint(&text, self.file_id, self.checkpoint.clone());
+		self.push_tokens(lexer);
+		self.text.push(Cow::Owned(text));
+	}
+
+	fn push_tokens(&mut self, tokens: impl Iterator<Item = rslint_lexer::LexerReturn>) {
+		let offset = usize::from(self.text.len());
+		for (token, error) in tokens {
+			self.tokens.push(token);
+			if let Some(mut error) = error {
+				map_error_ranges(&mut error, |range| range.start + offset..range.end + offset);
+				self.errors.push(error);
+			}
+		}
+	}
+}
+
 fn parse_common(
 	text: &str,
 	file_id: usize,
 	syntax: Syntax,
+	cancellation: Option<&CancellationToken>,
 ) -> (Vec<Event>, Vec<ParserError>, Vec<rslint_lexer::Token>) {
-	let (tokens, mut errors) = tokenize(text, file_id);
+	let (tokens, errors) = tokenize(text, file_id);
+	let (events, errors) = parse_tokens(text, &tokens, errors, file_id, syntax, cancellation);
+	(events, errors, tokens)
+}
 
-	let tok_source = TokenSource::new(text, &tokens);
+fn parse_tokens<'t>(
+	text: impl Into<SourceText<'t>>,
+	tokens: &'t [rslint_lexer::Token],
+	mut errors: Vec<ParserError>,
+	file_id: usize,
+	syntax: Syntax,
+	cancellation: Option<&CancellationToken>,
+) -> (Vec<Event>, Vec<ParserError>) {
+	let tok_source = TokenSource::new(text, tokens);
 
 	let mut parser = crate::Parser::new(tok_source, file_id, syntax);
+	if let Some(cancellation) = cancellation {
+		parser = parser.with_cancellation(cancellation.clone());
+	}
 	crate::syntax::program::parse(&mut parser);
 
 	let (events, p_errs) = parser.finish();
 	errors.extend(p_errs);
 
-	(events, errors, tokens)
+	(events, errors)
+}
+
+/// Runs the checks that need the finished tree, e.g. of private names that can be used before
+/// they're declared, and adds their errors to `errors`
+pub(crate) fn validate_tree(
+	root: &SyntaxNode,
+	file_id: usize,
+	syntax: Syntax,
+	errors: &mut Vec<ParserError>,
+) {
+	errors.extend(crate::syntax::check_private_names(root, file_id));
+	errors.extend(crate::syntax::check_ecma_version(root, file_id, syntax));
 }
 
 /// Parse text into a [`Parse`](Parse) which can then be turned into an untyped root [`SyntaxNode`](SyntaxNode).
@@ -157,13 +358,230 @@ fn parse_common(
 /// assert_eq!(&util::concat_tokens(&tokens), "foo.bar[2]")
 /// ```
 pub fn parse_text(text: &str, file_id: usize) -> Parse<JsRoot> {
-	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default());
-	let mut tree_sink = LosslessTreeSink::new(text, &tokens);
+	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default(), None);
+	let mut tree_sink = LosslessTreeSink::new(text, &tokens).with_file_id(file_id);
+	crate::process(&mut tree_sink, events, errors);
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(&green, file_id, Syntax::default(), &mut parse_errors);
+	Parse::new_script(green, parse_errors)
+}
+
+/// Same as [`parse_text`] but stores the tokens and nodes of the tree in `cache`.
+///
+/// Parsing several files with the same cache stores the tokens that repeat across the files,
+/// e.g. `require` or `console`, only once. The text of tokens is stored without their trivia,
+/// so `console` is stored once even if it is followed by a space in one file and by a newline in another.
+///
+/// ```
+/// use rslint_parser::{parse_text_with_cache, NodeCache};
+///
+/// let mut cache = NodeCache::new();
+/// let first = parse_text_with_cache("console.log(a)", 0, &mut cache);
+/// let tokens = cache.token_count();
+/// let second = parse_text_with_cache("console.log(a)", 1, &mut cache);
+///
+/// assert_eq!(first.syntax().text(), second.syntax().text());
+/// assert_eq!(tokens, cache.token_count());
+///
+/// let texts = cache.text_count();
+/// parse_text_with_cache("console\n.log(a)\n", 2, &mut cache);
+/// assert_eq!(texts, cache.text_count());
+/// ```
+pub fn parse_text_with_cache(text: &str, file_id: usize, cache: &mut NodeCache) -> Parse<JsRoot> {
+	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default(), None);
+	let mut tree_sink =
+		LosslessTreeSink::with_cache(text, &tokens, Syntax::default(), cache).with_file_id(file_id);
 	crate::process(&mut tree_sink, events, errors);
-	let (green, parse_errors) = tree_sink.finish();
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(&green, file_id, Syntax::default(), &mut parse_errors);
 	Parse::new_script(green, parse_errors)
 }
 
+/// Parses `text` with the given `syntax`, e.g. as a module or as ES2018 code
+///
+/// ```
+/// use rslint_parser::{parse, EcmaVersion, JsSourceType, Syntax};
+///
+/// let syntax = Syntax::default().module().ecma_version(EcmaVersion::Es2016);
+/// let parse = parse("export const a = async () => {};", 0, syntax);
+/// assert_eq!(JsSourceType::Module, parse.source_type());
+/// assert_eq!(
+///     "an async function requires ES2017 or later, but the configured version is ES2016",
+///     parse.errors()[0].title
+/// );
+/// ```
+pub fn parse(text: &str, file_id: usize, syntax: Syntax) -> Parse<JsRoot> {
+	let (events, errors, tokens) = parse_common(text, file_id, syntax, None);
+	let mut tree_sink = LosslessTreeSink::with_syntax(text, &tokens, syntax).with_file_id(file_id);
+	crate::process(&mut tree_sink, events, errors);
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(&green, file_id, syntax, &mut parse_errors);
+	Parse::new(green, parse_errors, syntax.source_type())
+}
+
+/// Parses `text` with the given `syntax` into a tree allocated in `arena`, see [GreenArena].
+///
+/// The checks that run on a syntax tree, like the private names and the ECMAScript version checks,
+/// are skipped, use [ArenaNode::to_syntax] and [parse] if you need them.
+///
+/// ```
+/// use rslint_parser::{parse_in_arena, JsLanguage, Syntax};
+/// use rome_rowan::arena::GreenArena;
+///
+/// let arena = GreenArena::new();
+/// let (root, errors) = parse_in_arena("let a = 1;", 0, Syntax::default(), &arena);
+/// assert!(errors.is_empty());
+/// assert_eq!("let a = 1;", root.to_string());
+/// assert_eq!("let a = 1;", root.to_syntax::<JsLanguage>().text());
+/// ```
+#[cfg(feature = "arena")]
+pub fn parse_in_arena<'arena>(
+	text: &str,
+	file_id: usize,
+	syntax: Syntax,
+	arena: &'arena GreenArena,
+) -> (&'arena ArenaNode<'arena>, Vec<ParserError>) {
+	let (events, errors, tokens) = parse_common(text, file_id, syntax, None);
+	let mut tree_sink = LosslessTreeSink::in_arena(text, &tokens, syntax, arena);
+	crate::process(&mut tree_sink, events, errors);
+	tree_sink.finish()
+}
+/// Parses `text` with the given `syntax` and stores the tokens and nodes of the tree in `cache`.
+/// The unchanged nodes of `previous`, the tree of a previous version of `text`, are reused.
+pub(crate) fn parse_with_cache(
+	text: &str,
+	file_id: usize,
+	syntax: Syntax,
+	cache: &mut NodeCache,
+	previous: Option<&SyntaxNode>,
+) -> Parse<JsRoot> {
+	let (events, errors, tokens) = parse_common(text, file_id, syntax, None);
+	let mut tree_sink =
+		LosslessTreeSink::with_cache(text, &tokens, syntax, cache).with_file_id(file_id);
+	if let Some(previous) = previous {
+		tree_sink = tree_sink.with_reuse(previous);
+	}
+	crate::process(&mut tree_sink, events, errors);
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(&green, file_id, syntax, &mut parse_errors);
+	Parse::new(green, parse_errors, syntax.source_type())
+}
+
+/// Same as [`parse_text`] but stops parsing and returns [`Cancelled`] once `cancellation` is cancelled,
+/// for example because the text changed while it was parsed
+pub fn parse_text_cancellable(
+	text: &str,
+	file_id: usize,
+	cancellation: &CancellationToken,
+) -> Result<Parse<JsRoot>, Cancelled> {
+	let (events, errors, tokens) =
+		parse_common(text, file_id, Syntax::default(), Some(cancellation));
+	cancellation.check()?;
+	let mut tree_sink = LosslessTreeSink::new(text, &tokens).with_file_id(file_id);
+	crate::process(&mut tree_sink, events, errors);
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(&green, file_id, Syntax::default(), &mut parse_errors);
+	Ok(Parse::new_script(green, parse_errors))
+}
+
+/// Same as [`parse`] but takes the text in chunks, e.g. the chunks of the rope of an editor buffer.
+///
+/// The chunks aren't copied into one buffer, the lexer and the parser read the text from the chunks,
+/// see [`tokenize_chunks`].
+///
+/// ```
+/// use rslint_parser::{parse_chunks, Syntax};
+///
+/// let parse = parse_chunks(vec!["let a", " = 1;\nlet", " b = a;"], 0, Syntax::default());
+/// assert!(parse.errors().is_empty());
+/// assert_eq!("let a = 1;\nlet b = a;", parse.syntax().text());
+/// ```
+pub fn parse_chunks<'a>(
+	chunks: impl IntoIterator<Item = &'a str>,
+	file_id: usize,
+	syntax: Syntax,
+) -> Parse<JsRoot> {
+	let (text, tokens, errors) = tokenize_chunks(chunks, file_id);
+	let (events, errors) = parse_tokens(text.clone(), &tokens, errors, file_id, syntax, None);
+	let mut tree_sink = LosslessTreeSink::with_syntax(text, &tokens, syntax).with_file_id(file_id);
+	crate::process(&mut tree_sink, events, errors);
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(&green, file_id, syntax, &mut parse_errors);
+	Parse::new(green, parse_errors, syntax.source_type())
+}
+
+/// The number of bytes [`parse_reader`] reads into one chunk
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Same as [`parse`] but reads the text from `reader`, e.g. a file or a socket.
+/// Returns an error if reading fails or the text isn't valid UTF-8.
+///
+/// The text is read in chunks which are parsed with [`parse_chunks`].
+///
+/// ```
+/// use rslint_parser::{parse_reader, Syntax};
+///
+/// let parse = parse_reader("let a = 1;".as_bytes(), 0, Syntax::default()).unwrap();
+/// assert_eq!("let a = 1;", parse.syntax().text());
+/// ```
+pub fn parse_reader(
+	mut reader: impl Read,
+	file_id: usize,
+	syntax: Syntax,
+) -> io::Result<Parse<JsRoot>> {
+	let invalid_utf8 = || {
+		io::Error::new(
+			io::ErrorKind::InvalidData,
+			"stream did not contain valid UTF-8",
+		)
+	};
+	let mut chunks = vec![];
+	// The bytes of a char that is split between two chunks
+	let mut bytes = vec![];
+
+	loop {
+		let read = reader
+			.by_ref()
+			.take(READ_CHUNK_SIZE as u64)
+			.read_to_end(&mut bytes)?;
+
+		let valid = match std::str::from_utf8(&bytes) {
+			Ok(text) => text.len(),
+			Err(err) if err.error_len().is_none() && read != 0 => err.valid_up_to(),
+			Err(_) => return Err(invalid_utf8()),
+		};
+		let rest = bytes.split_off(valid);
+		chunks.push(String::from_utf8(bytes).map_err(|_| invalid_utf8())?);
+		bytes = rest;
+
+		if read == 0 {
+			break;
+		}
+	}
+
+	Ok(parse_chunks(
+		chunks.iter().map(String::as_str),
+		file_id,
+		syntax,
+	))
+}
+
+/// Same as [`parse`] but stops parsing once `cancellation` is cancelled, see [`parse_text_cancellable`]
+pub fn parse_cancellable(
+	text: &str,
+	file_id: usize,
+	syntax: Syntax,
+	cancellation: &CancellationToken,
+) -> Result<Parse<JsRoot>, Cancelled> {
+	let (events, errors, tokens) = parse_common(text, file_id, syntax, Some(cancellation));
+	cancellation.check()?;
+	let mut tree_sink = LosslessTreeSink::with_syntax(text, &tokens, syntax).with_file_id(file_id);
+	crate::process(&mut tree_sink, events, errors);
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(&green, file_id, syntax, &mut parse_errors);
+	Ok(Parse::new(green, parse_errors, syntax.source_type()))
+}
+
 /// Lossly parse text into a [`Parse`](Parse) which can then be turned into an untyped root [`SyntaxNode`](SyntaxNode).
 /// Or turned into a typed [`Script`](Script) with [`tree`](Parse::tree).
 ///
@@ -200,42 +618,203 @@ pub fn parse_text(text: &str, file_id: usize) -> Parse<JsRoot> {
 /// assert_eq!(&util::concat_tokens(&tokens), "foo.bar[2]")
 /// ```
 pub fn parse_text_lossy(text: &str, file_id: usize) -> Parse<JsRoot> {
-	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default());
+	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default(), None);
 	let mut tree_sink = LossyTreeSink::new(text, &tokens);
 	crate::process(&mut tree_sink, events, errors);
-	let (green, parse_errors) = tree_sink.finish();
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(&green, file_id, Syntax::default(), &mut parse_errors);
 	Parse::new_script(green, parse_errors)
 }
 
 /// Same as [`parse_text_lossy`] but configures the parser to parse an ECMAScript module instead of a Script
 pub fn parse_module_lossy(text: &str, file_id: usize) -> Parse<JsRoot> {
-	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default().module());
+	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default().module(), None);
 	let mut tree_sink = LossyTreeSink::new(text, &tokens);
 	crate::process(&mut tree_sink, events, errors);
-	let (green, parse_errors) = tree_sink.finish();
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(
+		&green,
+		file_id,
+		Syntax::default().module(),
+		&mut parse_errors,
+	);
 	Parse::new_module(green, parse_errors)
 }
 
 /// Same as [`parse_text`] but configures the parser to parse an ECMAScript module instead of a script
 pub fn parse_module(text: &str, file_id: usize) -> Parse<JsRoot> {
-	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default().module());
-	let mut tree_sink = LosslessTreeSink::new(text, &tokens);
+	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default().module(), None);
+	let mut tree_sink = LosslessTreeSink::new(text, &tokens).with_file_id(file_id);
 	crate::process(&mut tree_sink, events, errors);
-	let (green, parse_errors) = tree_sink.finish();
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(
+		&green,
+		file_id,
+		Syntax::default().module(),
+		&mut parse_errors,
+	);
 	Parse::new_module(green, parse_errors)
 }
 
+/// Same as [`parse_module`] but stores the tokens and nodes of the tree in `cache`, see [`parse_text_with_cache`]
+pub fn parse_module_with_cache(text: &str, file_id: usize, cache: &mut NodeCache) -> Parse<JsRoot> {
+	let (events, errors, tokens) = parse_common(text, file_id, Syntax::default().module(), None);
+	let mut tree_sink =
+		LosslessTreeSink::with_cache(text, &tokens, Syntax::default().module(), cache)
+			.with_file_id(file_id);
+	crate::process(&mut tree_sink, events, errors);
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(
+		&green,
+		file_id,
+		Syntax::default().module(),
+		&mut parse_errors,
+	);
+	Parse::new_module(green, parse_errors)
+}
+
+/// Same as [`parse_module`] but stops parsing once `cancellation` is cancelled, see [`parse_text_cancellable`]
+pub fn parse_module_cancellable(
+	text: &str,
+	file_id: usize,
+	cancellation: &CancellationToken,
+) -> Result<Parse<JsRoot>, Cancelled> {
+	let (events, errors, tokens) = parse_common(
+		text,
+		file_id,
+		Syntax::default().module(),
+		Some(cancellation),
+	);
+	cancellation.check()?;
+	let mut tree_sink = LosslessTreeSink::new(text, &tokens).with_file_id(file_id);
+	crate::process(&mut tree_sink, events, errors);
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(
+		&green,
+		file_id,
+		Syntax::default().module(),
+		&mut parse_errors,
+	);
+	Ok(Parse::new_module(green, parse_errors))
+}
+
 /// Losslessly Parse text into an expression [`Parse`](Parse) which can then be turned into an untyped root [`SyntaxNode`](SyntaxNode).
 /// Or turned into a typed [`Expr`](Expr) with [`tree`](Parse::tree).
 pub fn parse_expr(text: &str, file_id: usize) -> Parse<JsAnyExpression> {
+	parse_expression(text, file_id, Syntax::default())
+}
+
+/// Parses `text` as a single standalone expression with the given `syntax`.
+///
+/// The root of the result is the expression itself. If the text is not a single expression, e.g. because
+/// of tokens following the expression, the root is a [`JsUnknownExpression`](crate::ast::JsUnknownExpression)
+/// which contains all the tokens and the result has an error.
+///
+/// ```
+/// use rslint_parser::{ast::JsAnyExpression, parse_expression, Syntax};
+///
+/// let parse = parse_expression("user.name as string", 0, Syntax::default().typescript());
+/// assert!(parse.errors().is_empty());
+/// assert!(matches!(parse.tree(), JsAnyExpression::TsAssertion(_)));
+///
+/// let parse = parse_expression("a b", 0, Syntax::default());
+/// assert!(matches!(parse.tree(), JsAnyExpression::JsUnknownExpression(_)));
+/// assert_eq!(parse.errors().len(), 1);
+/// ```
+pub fn parse_expression(text: &str, file_id: usize, syntax: Syntax) -> Parse<JsAnyExpression> {
+	parse_standalone(
+		text,
+		file_id,
+		syntax,
+		crate::syntax::expr::expr,
+		SyntaxKind::JS_UNKNOWN_EXPRESSION,
+	)
+}
+
+/// Parses `text` as a single standalone statement with the given `syntax`, see [`parse_expression`].
+///
+/// The root of the result is a [`JsUnknownStatement`](crate::ast::JsUnknownStatement) if the text
+/// is not a single statement.
+///
+/// ```
+/// use rslint_parser::{ast::JsAnyStatement, parse_statement, Syntax};
+///
+/// let parse = parse_statement("if (a) { b() }", 0, Syntax::default());
+/// assert!(parse.errors().is_empty());
+/// assert!(matches!(parse.tree(), JsAnyStatement::JsIfStatement(_)));
+/// ```
+pub fn parse_statement(text: &str, file_id: usize, syntax: Syntax) -> Parse<JsAnyStatement> {
+	parse_standalone(
+		text,
+		file_id,
+		syntax,
+		|p| crate::syntax::stmt::stmt(p, None),
+		SyntaxKind::JS_UNKNOWN_STATEMENT,
+	)
+}
+
+/// Parses `text` as a single standalone TypeScript type, see [`parse_expression`].
+///
+/// There is no unknown node for types, the root of the result is an `ERROR` node if the text
+/// is not a single type, use [`try_tree`](Parse::try_tree) or [`ok`](Parse::ok) to get the typed node.
+///
+/// ```
+/// use rslint_parser::{ast::TsType, parse_type};
+///
+/// let parse = parse_type("Array<string> | undefined", 0);
+/// assert!(matches!(parse.ok(), Ok(TsType::TsUnion(_))));
+///
+/// assert!(parse_type("string string", 0).try_tree().is_none());
+/// ```
+pub fn parse_type(text: &str, file_id: usize) -> Parse<TsType> {
+	parse_standalone(
+		text,
+		file_id,
+		Syntax::default().typescript(),
+		crate::syntax::typescript::ts_type,
+		SyntaxKind::ERROR,
+	)
+}
+
+/// Parses `text` with `parse_node` and makes the node the root of the tree. The tokens which
+/// `parse_node` did not consume are reported and wrapped together with the node in an `unknown_kind` node.
+fn parse_standalone<T>(
+	text: &str,
+	file_id: usize,
+	syntax: Syntax,
+	parse_node: impl FnOnce(&mut Parser) -> Option<CompletedMarker>,
+	unknown_kind: SyntaxKind,
+) -> Parse<T> {
 	let (tokens, mut errors) = tokenize(text, file_id);
 	let tok_source = TokenSource::new(text, &tokens);
-	let mut parser = crate::Parser::new(tok_source, file_id, Syntax::default());
-	crate::syntax::expr::expr(&mut parser);
+	let mut parser = crate::Parser::new(tok_source, file_id, syntax);
+	let p = &mut parser;
+
+	let m = p.start();
+	let node = parse_node(p);
+	if node.is_some() && p.at(SyntaxKind::EOF) {
+		m.abandon(p);
+	} else {
+		if !p.at(SyntaxKind::EOF) {
+			let start = p.cur_tok().range.start;
+			let mut end = start;
+			while !p.at(SyntaxKind::EOF) {
+				end = p.cur_tok().range.end;
+				p.bump_any();
+			}
+			let err = p
+				.err_builder("expected the end of the input, but found more tokens")
+				.primary(start..end, "");
+			p.error(err);
+		}
+		m.complete(p, unknown_kind);
+	}
+
 	let (events, p_diags) = parser.finish();
 	errors.extend(p_diags);
-	let mut tree_sink = LosslessTreeSink::new(text, &tokens);
+	let mut tree_sink = LosslessTreeSink::with_syntax(text, &tokens, syntax).with_file_id(file_id);
 	crate::process(&mut tree_sink, events, errors);
-	let (green, parse_errors) = tree_sink.finish();
-	Parse::new_script(green, parse_errors)
+	let (green, mut parse_errors) = tree_sink.finish();
+	validate_tree(&green, file_id, syntax, &mut parse_errors);
+	Parse::new(green, parse_errors, syntax.source_type())
 }
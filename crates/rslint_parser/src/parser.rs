@@ -6,17 +6,20 @@
 pub(crate) mod parse_error;
 mod parse_recovery;
 mod parsed_syntax;
+mod parsing_context;
 pub(crate) mod single_token_parse_recovery;
 
 use drop_bomb::DropBomb;
-use rslint_errors::Diagnostic;
+use rslint_errors::{Diagnostic, Footer, Severity, Span};
 use rslint_syntax::SyntaxKind::EOF;
-use std::borrow::BorrowMut;
+use std::borrow::{BorrowMut, Cow};
 use std::cell::Cell;
 use std::ops::Range;
 
 pub use parse_error::*;
 pub use parsed_syntax::{ConditionalParsedSyntax, InvalidParsedSyntax, ParsedSyntax};
+pub(crate) use parsing_context::is_unknown_kind;
+pub use parsing_context::{ContextGuard, ParsingContext};
 #[allow(deprecated)]
 pub use single_token_parse_recovery::SingleTokenParseRecovery;
 
@@ -97,10 +100,18 @@ pub struct Parser<'t> {
 	pub state: ParserState,
 	pub syntax: Syntax,
 	pub errors: Vec<ParserError>,
+	/// The grammar productions the parser is currently in, innermost last
+	pub(crate) contexts: Vec<ParsingContext>,
+	/// The start event, range and production of the unknown nodes, see [`Syntax::unknown_node_context`]
+	unknown_nodes: Vec<(u32, Range<usize>, ParsingContext)>,
+	cancellation: Option<CancellationToken>,
 }
 
 impl<'t> Parser<'t> {
 	/// Make a new parser
+	///
+	/// The parser accepts the syntax of every ECMAScript version, [`Syntax::ecma_version`] is checked on
+	/// the finished tree by the parse functions like [`parse`](crate::parse), except in TypeScript files.
 	pub fn new(tokens: TokenSource<'t>, file_id: usize, syntax: Syntax) -> Parser<'t> {
 		// TODO(RDambrosio016): Does TypeScript imply Module/Strict?
 		let strict = if syntax.file_kind == FileKind::Module {
@@ -122,6 +133,28 @@ impl<'t> Parser<'t> {
 			state,
 			syntax,
 			errors: vec![],
+			contexts: vec![],
+			unknown_nodes: vec![],
+			cancellation: None,
+		}
+	}
+
+	/// Makes the parser stop early once `cancellation` is cancelled, see [Parser::check_cancelled]
+	pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+		self.cancellation = Some(cancellation);
+		self
+	}
+
+	/// Returns `true` if the parsing has been cancelled. The remaining tokens are then
+	/// dropped so that every production finishes as if the parser reached the end of the file.
+	/// Called at statement boundaries.
+	pub fn check_cancelled(&mut self) -> bool {
+		match &self.cancellation {
+			Some(cancellation) if cancellation.is_cancelled() => {
+				self.tokens.truncate();
+				true
+			}
+			_ => false,
 		}
 	}
 
@@ -129,6 +162,10 @@ impl<'t> Parser<'t> {
 		self.syntax.file_kind == FileKind::TypeScript
 	}
 
+	pub(crate) fn jsx(&self) -> bool {
+		self.syntax.jsx
+	}
+
 	fn overflow_check(&self) {
 		let steps = self.steps.get();
 		assert!(
@@ -147,10 +184,47 @@ impl<'t> Parser<'t> {
 	}
 
 	/// Consume the parser and return the list of events it produced
-	pub fn finish(self) -> (Vec<Event>, Vec<ParserError>) {
+	pub fn finish(mut self) -> (Vec<Event>, Vec<ParserError>) {
+		self.annotate_unknown_nodes();
 		(self.events, self.errors)
 	}
 
+	/// Adds a note naming the production the parser was attempting to every error inside of an unknown node.
+	fn annotate_unknown_nodes(&mut self) {
+		let events = &self.events;
+		// Drop the nodes that were rewound or changed kind since
+		let unknown_nodes: Vec<_> = std::mem::take(&mut self.unknown_nodes)
+			.into_iter()
+			.filter(|(pos, ..)| {
+				matches!(
+					events.get(*pos as usize),
+					Some(Event::Start { kind, .. }) if parsing_context::is_unknown_kind(*kind)
+				)
+			})
+			.collect();
+
+		for error in self.errors.iter_mut() {
+			let start = match &error.primary {
+				Some(primary) => primary.span.range.start,
+				None => continue,
+			};
+
+			let innermost = unknown_nodes
+				.iter()
+				.filter(|(_, range, _)| {
+					range.start <= start && (start < range.end || range.start == range.end)
+				})
+				.min_by_key(|(_, range, _)| range.len());
+
+			if let Some((_, _, context)) = innermost {
+				error.footers.push(Footer {
+					msg: format!("while parsing {}", context.description()),
+					severity: Severity::Note,
+				});
+			}
+		}
+	}
+
 	/// Get the current token kind of the parser
 	pub fn cur(&self) -> SyntaxKind {
 		self.nth(0)
@@ -244,6 +318,22 @@ impl<'t> Parser<'t> {
 		self.errors.push(err.into())
 	}
 
+	/// Marks that the parser is attempting to parse the production `context` until the returned guard is dropped.
+	pub fn with_context<'a>(&'a mut self, context: ParsingContext) -> ContextGuard<'a, 't> {
+		ContextGuard::new(self, context)
+	}
+
+	/// Records the innermost production being parsed for an unknown node, if enabled by [`Syntax::unknown_node_context`].
+	fn record_unknown_node(&mut self, kind: SyntaxKind, start_event: u32, range: Range<usize>) {
+		if !self.syntax.unknown_node_context || !parsing_context::is_unknown_kind(kind) {
+			return;
+		}
+
+		if let Some(context) = self.contexts.last() {
+			self.unknown_nodes.push((start_event, range, *context));
+		}
+	}
+
 	/// Check if the parser's current token is contained in a token set
 	pub fn at_ts(&self, kinds: TokenSet) -> bool {
 		kinds.contains(self.cur())
@@ -351,15 +441,18 @@ impl<'t> Parser<'t> {
 			_ => unreachable!(),
 		};
 
-		let mut sink =
-			LosslessTreeSink::with_offset(self.tokens.source(), self.tokens.raw_tokens, start);
+		let mut sink = LosslessTreeSink::with_offset(
+			self.tokens.source().clone(),
+			self.tokens.raw_tokens,
+			start,
+		);
 		process(&mut sink, events, vec![]);
 		T::cast(sink.finish().0).expect("Marker was parsed to the wrong ast node")
 	}
 
 	/// Get the source code of a range
-	pub fn source(&self, range: TextRange) -> &str {
-		&self.tokens.source()[range]
+	pub fn source(&self, range: TextRange) -> Cow<str> {
+		self.tokens.source().slice(range)
 	}
 
 	/// Rewind the parser back to a previous position in time
@@ -453,8 +546,12 @@ impl<'t> Parser<'t> {
 		}
 	}
 
-	pub fn span_text(&self, span: impl rslint_errors::Span) -> &str {
-		&self.tokens.source()[span.as_range()]
+	pub fn span_text(&self, span: impl rslint_errors::Span) -> Cow<str> {
+		let range = span.as_range();
+		self.source(TextRange::new(
+			TextSize::from(range.start as u32),
+			TextSize::from(range.end as u32),
+		))
 	}
 
 	pub(crate) fn bump_multiple(&mut self, amount: u8, kind: SyntaxKind) {
@@ -547,9 +644,9 @@ impl Marker {
 			_ => unreachable!(),
 		}
 		let finish_pos = p.events.len() as u32;
-		p.push_event(Event::Finish {
-			end: p.tokens.last_tok().map(|t| t.range.end).unwrap_or(0),
-		});
+		let end = p.tokens.last_tok().map(|t| t.range.end).unwrap_or(0);
+		p.push_event(Event::Finish { end });
+		p.record_unknown_node(kind, self.pos, self.start..end.max(self.start));
 		let new = CompletedMarker::new(self.pos, finish_pos, kind);
 		new.old_start(self.old_start)
 	}
@@ -625,6 +722,8 @@ impl CompletedMarker {
 			}
 			_ => unreachable!(),
 		}
+		let range = self.range(p).as_range();
+		p.record_unknown_node(new_kind, self.start_pos, range);
 	}
 
 	// Get the correct offset range in source code of an item inside of a parsed marker
@@ -647,8 +746,8 @@ impl CompletedMarker {
 	}
 
 	/// Get the underlying text of a marker
-	pub fn text<'a>(&self, p: &'a Parser) -> &'a str {
-		&p.tokens.source()[self.range(p)]
+	pub fn text<'a>(&self, p: &'a Parser) -> Cow<'a, str> {
+		p.source(self.range(p))
 	}
 
 	/// This method allows to create a new node which starts
@@ -711,6 +810,15 @@ impl CompletedMarker {
 	pub fn err_if_not_ts(&mut self, p: &mut Parser, err: &str) {
 		p.err_if_not_ts(self, err, SyntaxKind::ERROR);
 	}
+
+	/// Changes the kind of this node to `ERROR` and adds the error `err` if the file is a TSX file
+	pub fn err_if_tsx(&mut self, p: &mut Parser, err: &str) {
+		if p.typescript() && p.jsx() {
+			self.change_kind(p, SyntaxKind::ERROR);
+			let err = p.err_builder(err).primary(self.range(p), "");
+			p.error(err);
+		}
+	}
 }
 
 /// A structure signifying the Parser progress at one point in time
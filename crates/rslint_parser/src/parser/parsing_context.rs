@@ -0,0 +1,77 @@
+//! Tracking of the grammar productions the parser is currently in, used to
+//! explain where an unknown node was produced.
+
+use std::ops::{Deref, DerefMut};
+
+use crate::{Parser, SyntaxKind};
+use rslint_syntax::SyntaxKind::*;
+
+/// A grammar production the parser is attempting to parse.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParsingContext {
+	Statement,
+	Expression,
+	ClassMember,
+	ObjectMember,
+	ImportDeclaration,
+	ExportDeclaration,
+}
+
+impl ParsingContext {
+	/// A description of the production, e.g. `an import declaration`
+	pub fn description(&self) -> &'static str {
+		match self {
+			ParsingContext::Statement => "a statement",
+			ParsingContext::Expression => "an expression",
+			ParsingContext::ClassMember => "a class member",
+			ParsingContext::ObjectMember => "an object member",
+			ParsingContext::ImportDeclaration => "an import declaration",
+			ParsingContext::ExportDeclaration => "an export declaration",
+		}
+	}
+}
+
+/// Returns `true` if `kind` is one of the catch-all unknown node kinds
+pub(crate) fn is_unknown_kind(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		JS_UNKNOWN_STATEMENT
+			| JS_UNKNOWN_EXPRESSION
+			| JS_UNKNOWN_MEMBER
+			| JS_UNKNOWN_PATTERN
+			| JS_UNKNOWN_BINDING
+			| JS_UNKNOWN_ASSIGNMENT_TARGET
+	)
+}
+
+/// Pops the context pushed by [`Parser::with_context`] when dropped.
+pub struct ContextGuard<'p, 't> {
+	inner: &'p mut Parser<'t>,
+}
+
+impl<'p, 't> ContextGuard<'p, 't> {
+	pub(crate) fn new(inner: &'p mut Parser<'t>, context: ParsingContext) -> Self {
+		inner.contexts.push(context);
+		ContextGuard { inner }
+	}
+}
+
+impl<'p, 't> Deref for ContextGuard<'p, 't> {
+	type Target = Parser<'t>;
+
+	fn deref(&self) -> &Parser<'t> {
+		self.inner
+	}
+}
+
+impl<'p, 't> DerefMut for ContextGuard<'p, 't> {
+	fn deref_mut(&mut self) -> &mut Parser<'t> {
+		self.inner
+	}
+}
+
+impl<'p, 't> Drop for ContextGuard<'p, 't> {
+	fn drop(&mut self) {
+		self.inner.contexts.pop();
+	}
+}
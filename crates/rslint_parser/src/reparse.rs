@@ -0,0 +1,365 @@
+//! Incremental reparsing of the top level statements touched by an edit, see [reparse].
+
+use std::ops::Range;
+
+use rslint_errors::SuggestionChange;
+use rslint_text_edit::{Indel, TextEdit};
+
+use crate::{
+	ast::JsRoot, parse::parse, syntax::stmt::statements, tokenize, LosslessTreeSink, Parse, Parser,
+	ParserError, Syntax, SyntaxElement, SyntaxKind, SyntaxNode, TextRange, TextSize, TokenSource,
+	T,
+};
+
+/// Applies `edit` to the text of `old` and parses the new text. `file_id` and `syntax` must be
+/// the ones `old` was parsed with.
+///
+/// Only the top level statements touched by the edit are relexed and reparsed. The nodes of the other
+/// statements are shared with the old tree and their diagnostics are kept. The whole text is parsed
+/// again if the edit could change how the neighbouring statements are parsed, for example because
+/// it opens a block or a template that the following statements would be part of.
+///
+/// ```
+/// use rslint_parser::{parse_text, reparse, Syntax, TextRange};
+/// use rslint_text_edit::TextEdit;
+///
+/// let old = parse_text("let a = 1;\nlet b = 2;\nlet c = 3;\n", 0);
+/// let edit = TextEdit::replace(TextRange::new(15.into(), 16.into()), "bb".to_string());
+/// let new = reparse(&old, &edit, 0, Syntax::default());
+///
+/// assert_eq!("let a = 1;\nlet bb = 2;\nlet c = 3;\n", new.syntax().text());
+/// ```
+pub fn reparse(
+	old: &Parse<JsRoot>,
+	edit: &TextEdit,
+	file_id: usize,
+	syntax: Syntax,
+) -> Parse<JsRoot> {
+	let mut text = old.syntax().to_string();
+	edit.apply(&mut text);
+	reparse_incrementally(old, edit, &text, file_id, syntax)
+		.unwrap_or_else(|| parse(&text, file_id, syntax))
+}
+
+/// Reparses the statements of `old` touched by `edit`, `text` is the text after the edit.
+/// Returns `None` if the whole text must be parsed again.
+pub(crate) fn reparse_incrementally(
+	old: &Parse<JsRoot>,
+	edit: &TextEdit,
+	text: &str,
+	file_id: usize,
+	syntax: Syntax,
+) -> Option<Parse<JsRoot>> {
+	let indel = match edit.iter().collect::<Vec<_>>().as_slice() {
+		[indel] => *indel,
+		_ => return None,
+	};
+
+	let root = old.tree();
+	// The parser state of the statements of a file with a "use strict" directive refers to the directive.
+	// Skipped tokens at the end of the region are trivia of the first token after the region.
+	if root.has_use_strict() || old.source_type() != syntax.source_type() || syntax.skipped_trivia {
+		return None;
+	}
+
+	let old_list = statements_list(&old.syntax())?;
+	let nodes: Vec<_> = old_list.children().collect();
+	let (first, last) = reparsed_statements(&root, &nodes, indel)?;
+
+	let old_range = TextRange::new(
+		nodes[first].text_range().start(),
+		nodes[last].text_range().end(),
+	);
+	let new_range = TextRange::new(
+		old_range.start(),
+		old_range.end() + TextSize::of(indel.insert.as_str()) - indel.delete.len(),
+	);
+	let region = &text[new_range];
+
+	let (tokens, lex_errors) = tokenize(region, file_id);
+	if !lex_errors.is_empty() || !is_balanced(tokens.iter().map(|token| token.kind)) {
+		return None;
+	}
+	// A string at the start of the file could become a directive
+	let first_token = tokens.iter().find(|token| !token.kind.is_trivia());
+	if first == 0
+		&& matches!(first_token, Some(token) if token.kind == SyntaxKind::JS_STRING_LITERAL)
+	{
+		return None;
+	}
+
+	let mut parser = Parser::new(TokenSource::new(region, &tokens), file_id, syntax);
+	let m = parser.start();
+	statements(&mut parser, true, false, None);
+	m.complete(&mut parser, SyntaxKind::JS_ROOT);
+	let (events, errors) = parser.finish();
+	let mut tree_sink = LosslessTreeSink::new(region, &tokens).with_file_id(file_id);
+	crate::process(&mut tree_sink, events, errors);
+	let (new_root, mut region_errors) = tree_sink.finish();
+	crate::parse::validate_tree(&new_root, file_id, syntax, &mut region_errors);
+
+	let new_list = statements_list(&new_root.clone_for_update())?;
+	let new_nodes: Vec<_> = new_list.children().collect();
+	// The trivia at the end of the region ends up in the EOF token if there is no statement to attach it to
+	if new_list.text() != region {
+		return None;
+	}
+	if last + 1 < nodes.len()
+		&& !matches!(new_nodes.last(), Some(node) if ends_with_semicolon(node))
+	{
+		return None;
+	}
+
+	let errors = merge_errors(old.errors(), region_errors, old_range, new_range.end())?;
+
+	let new_tree = old.syntax().clone_for_update();
+	let list = statements_list(&new_tree)?;
+	let to_insert = new_nodes.into_iter().map(SyntaxElement::Node).collect();
+	list.splice_children(first..last + 1, to_insert);
+
+	Some(Parse::new(
+		new_tree.clone_subtree(),
+		errors,
+		syntax.source_type(),
+	))
+}
+
+/// The statements list of a [`JsRoot`] node, which is the last list of the root
+fn statements_list(root: &SyntaxNode) -> Option<SyntaxNode> {
+	root.children()
+		.filter(|child| child.kind() == SyntaxKind::LIST)
+		.last()
+}
+
+/// Returns the indices of the first and last statement which must be reparsed for the edit,
+/// the region is bounded by statements that end with a semicolon so that the lexer and the parser
+/// are in the same state at its start and end as when they parse the whole file.
+fn reparsed_statements(
+	root: &JsRoot,
+	nodes: &[SyntaxNode],
+	indel: &Indel,
+) -> Option<(usize, usize)> {
+	let edit = indel.delete;
+	if nodes.is_empty() || edit.start() < nodes[0].text_range().start() {
+		return None;
+	}
+
+	let mut first = nodes
+		.iter()
+		.position(|node| node.text_range().end() >= edit.start())?;
+	let mut last = nodes
+		.iter()
+		.rposition(|node| node.text_range().start() <= edit.end())?;
+	if edit.end() > nodes[last].text_range().end() {
+		return None;
+	}
+
+	while first > 0 && !ends_with_semicolon(&nodes[first - 1]) {
+		first -= 1;
+	}
+	// The lexer state after a shebang or a directive is different than at the start of the file
+	if first == 0 && (root.interpreter_token().is_some() || !root.directives().is_empty()) {
+		return None;
+	}
+	while last + 1 < nodes.len() && !ends_with_semicolon(&nodes[last]) {
+		last += 1;
+	}
+
+	Some((first, last))
+}
+
+/// Whether the last token of the statement is its own semicolon, e.g. not the one of the body of an `if` statement
+fn ends_with_semicolon(node: &SyntaxNode) -> bool {
+	matches!(node.last_token(), Some(token) if token.kind() == T![;] && token.parent().as_ref() == Some(node))
+}
+
+/// Whether every opened brace, parenthesis, bracket and template substitution is closed
+fn is_balanced(kinds: impl Iterator<Item = SyntaxKind>) -> bool {
+	let mut stack = Vec::new();
+	for kind in kinds {
+		match kind {
+			T!['{'] | SyntaxKind::DOLLARCURLY => stack.push(T!['}']),
+			T!['('] => stack.push(T![')']),
+			T!['['] => stack.push(T![']']),
+			T!['}'] | T![')'] | T![']'] if stack.pop() != Some(kind) => return false,
+			_ => {}
+		}
+	}
+	stack.is_empty()
+}
+
+/// Keeps the errors of `old_errors` outside of `old_range`, moving the errors after it to `new_end`, and
+/// adds the errors of the reparsed region. Returns `None` if an error refers to both the region and the
+/// text around it.
+fn merge_errors(
+	old_errors: &[ParserError],
+	region_errors: Vec<ParserError>,
+	old_range: TextRange,
+	new_end: TextSize,
+) -> Option<Vec<ParserError>> {
+	let start = usize::from(old_range.start());
+	let end = usize::from(old_range.end());
+	let new_end = usize::from(new_end);
+
+	let mut before = vec![];
+	let mut after = vec![];
+	for error in old_errors {
+		let ranges = error_ranges(error);
+		if ranges.iter().all(|range| range.end <= start) {
+			before.push(error.clone());
+		} else if ranges.iter().all(|range| range.start >= end) {
+			let mut error = error.clone();
+			map_error_ranges(&mut error, |range| {
+				range.start - end + new_end..range.end - end + new_end
+			});
+			after.push(error);
+		} else if !ranges
+			.iter()
+			.all(|range| range.start >= start && range.end <= end)
+		{
+			return None;
+		}
+	}
+
+	let region = region_errors.into_iter().map(|mut error| {
+		map_error_ranges(&mut error, |range| range.start + start..range.end + start);
+		error
+	});
+
+	Some(before.into_iter().chain(region).chain(after).collect())
+}
+
+fn error_ranges(error: &ParserError) -> Vec<Range<usize>> {
+	let mut ranges: Vec<_> = error
+		.primary
+		.iter()
+		.chain(error.children.iter())
+		.map(|sub| sub.span.range.clone())
+		.collect();
+	for suggestion in &error.suggestions {
+		ranges.push(suggestion.span.range.clone());
+		ranges.extend(suggestion.labels.iter().cloned());
+	}
+	ranges
+}
+
+pub(crate) fn map_error_ranges(
+	error: &mut ParserError,
+	map: impl Fn(Range<usize>) -> Range<usize>,
+) {
+	for sub in error.primary.iter_mut().chain(error.children.iter_mut()) {
+		sub.span.range = map(sub.span.range.clone());
+	}
+	for suggestion in &mut error.suggestions {
+		suggestion.span.range = map(suggestion.span.range.clone());
+		for label in &mut suggestion.labels {
+			*label = map(label.clone());
+		}
+		if let SuggestionChange::Indels(indels) = &mut suggestion.substitution {
+			for indel in indels {
+				let range = map(indel.delete.start().into()..indel.delete.end().into());
+				indel.delete = TextRange::new(
+					TextSize::from(range.start as u32),
+					TextSize::from(range.end as u32),
+				);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rslint_text_edit::TextEdit;
+
+	use super::reparse_incrementally;
+	use crate::{parse, Syntax, TextRange};
+
+	/// Applies the edit replacing the text between the `$` markers with `insert`,
+	/// asserts that the incremental reparse matches a full parse and returns whether it was incremental
+	fn check(marked: &str, insert: &str, syntax: Syntax) -> bool {
+		let start = marked.find('$').unwrap();
+		let end = marked.rfind('$').unwrap() - 1;
+		let old_text = marked.replace('$', "");
+		let edit = TextEdit::replace(
+			TextRange::new((start as u32).into(), (end as u32).into()),
+			insert.to_string(),
+		);
+		let mut new_text = old_text.clone();
+		edit.apply(&mut new_text);
+
+		let old = parse(&old_text, 0, syntax);
+		let expected = parse(&new_text, 0, syntax);
+		let reparsed = reparse_incrementally(&old, &edit, &new_text, 0, syntax);
+
+		if let Some(reparsed) = &reparsed {
+			assert_eq!(
+				format!("{:#?}", expected.syntax()),
+				format!("{:#?}", reparsed.syntax())
+			);
+			let errors = |errors: &[crate::ParserError]| {
+				errors
+					.iter()
+					.map(|error| {
+						(
+							error.title.clone(),
+							error
+								.primary
+								.as_ref()
+								.map(|primary| primary.span.range.clone()),
+						)
+					})
+					.collect::<Vec<_>>()
+			};
+			assert_eq!(errors(expected.errors()), errors(reparsed.errors()));
+		}
+		reparsed.is_some()
+	}
+
+	#[test]
+	fn reparses_touched_statements() {
+		let script = Syntax::default();
+		assert!(check(
+			"let a = 1;\nlet $b$ = 2;\nlet c = 3;\n",
+			"bb",
+			script
+		));
+		assert!(check("a;\nb$$;\nc;\n", " + 1", script));
+		assert!(check("a;\nfunction b() { $$ }\nc;\n", "return 1", script));
+		assert!(check("a = 1 +;\nb = $x$;\nc = 3 +;\n", "yy", script));
+		assert!(check("a;\nb = $1$;\nc = 3 +;\n", "1 = 2", script));
+		assert!(check(
+			"let a = 1 +;\nlet $b$ = 2;\nlet c = 3;\n",
+			"bb",
+			script
+		));
+		assert!(check(
+			"import a from \"a\";\n$a$;\nexport default 1;",
+			"b",
+			Syntax::default().module()
+		));
+	}
+
+	#[test]
+	fn reparses_everything_if_the_edit_leaks() {
+		let script = Syntax::default();
+		// Opens a block which the next statements become part of
+		assert!(!check("a;\n$b$;\nc;\n", "if (b) {", script));
+		// Removes the semicolon the next statement depends on
+		assert!(!check("a;\nb$;$ // b\n(c);\n", "", script));
+		// Unterminated template and comment
+		assert!(!check("a;\n$b$;\nc;\n", "`", script));
+		assert!(!check("a;\n$b$;\nc;\n", "/*", script));
+		// Could become a directive
+		assert!(!check("$a$;\n", "'use strict'", script));
+		assert!(!check("'use strict';\na;\n$b$;", "c", script));
+
+		// Several edits
+		let mut edit = TextEdit::insert(0.into(), "x".to_string());
+		edit.union(TextEdit::insert(5.into(), "y".to_string()))
+			.unwrap();
+		let old = parse("a;\nb;\nc;\n", 0, script);
+		let mut text = "a;\nb;\nc;\n".to_string();
+		edit.apply(&mut text);
+		assert!(reparse_incrementally(&old, &edit, &text, 0, script).is_none());
+	}
+}
@@ -0,0 +1,33 @@
+//! Semantic analysis of a parsed file.
+//!
+//! [Scopes] walks the tree once and records the scopes of the file, the bindings declared in every scope,
+//! and the references to the bindings. References are resolved after the whole file is walked, which
+//! makes `var` and function declarations visible before their declaration, like they are at runtime.
+//!
+//! [SemanticModel] answers the questions lint rules ask on top of the scopes, e.g. whether a binding
+//! shadows another binding or whether a reference runs before the binding is initialized.
+//!
+//! ```
+//! use rslint_parser::{parse_text, semantic::{DeclarationKind, Scopes}};
+//!
+//! let parse = parse_text("function f(a) { return a + b; }", 0);
+//! let scopes = Scopes::new(&parse.tree());
+//!
+//! let a = scopes.references().find(|reference| reference.name() == "a").unwrap();
+//! let binding = scopes.declaration_of(a).unwrap();
+//! assert_eq!(DeclarationKind::Parameter, binding.kind());
+//! assert_eq!(1, scopes.references_of(binding).count());
+//!
+//! // `b` isn't declared in the file
+//! let b = scopes.references().find(|reference| reference.name() == "b").unwrap();
+//! assert!(scopes.declaration_of(b).is_none());
+//! ```
+
+mod model;
+mod scopes;
+
+pub use model::SemanticModel;
+pub use scopes::{
+	Binding, BindingId, DeclarationKind, Reference, ReferenceId, ReferenceKind, Scope, ScopeId,
+	ScopeKind, Scopes,
+};
@@ -0,0 +1,273 @@
+//! Queries about the bindings and references of a file that take the scoping rules of JavaScript into account.
+
+use super::{Binding, DeclarationKind, Reference, ScopeId, ScopeKind, Scopes};
+use crate::{ast::JsRoot, Parse, SyntaxKind::*};
+
+/// The scopes of a file together with the queries lint rules need, so that rules don't have to
+/// reimplement `var` hoisting, the temporal dead zone of `let` and `const`, or shadowing.
+///
+/// ```
+/// use rslint_parser::{parse_text, semantic::SemanticModel};
+///
+/// let parse = parse_text("let a = 1; function f(a) { return b; } f(a);", 0);
+/// let model = SemanticModel::new(&parse);
+///
+/// let (parameter, shadowed) = model.shadowed_bindings().next().unwrap();
+/// assert_eq!(parameter.name(), shadowed.name());
+///
+/// let b = model.scopes().references().find(|reference| reference.name() == "b").unwrap();
+/// assert!(model.is_global_reference(b));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SemanticModel {
+	scopes: Scopes,
+}
+
+impl SemanticModel {
+	pub fn new(parse: &Parse<JsRoot>) -> Self {
+		Self {
+			scopes: Scopes::with_source_type(&parse.tree(), parse.source_type()),
+		}
+	}
+
+	pub fn scopes(&self) -> &Scopes {
+		&self.scopes
+	}
+
+	/// Returns `true` if `reference` refers to a global variable, e.g. `window` or `console`,
+	/// because no binding of the file declares its name
+	pub fn is_global_reference(&self, reference: &Reference) -> bool {
+		reference.is_unresolved()
+	}
+
+	/// Returns the binding of an enclosing scope `binding` hides, e.g. the `a` of `let a` for the
+	/// parameter of `let a; function f(a) {}`
+	pub fn shadowed_binding(&self, binding: &Binding) -> Option<&Binding> {
+		let parent = self.scopes.scope(binding.scope()).parent()?;
+		self.scopes
+			.ancestors(parent)
+			// A function declared in a block of sloppy mode code is visible in the enclosing function as well
+			.find_map(|scope| scope.get(binding.name()).filter(|id| *id != binding.id()))
+			.map(|id| self.scopes.binding(id))
+	}
+
+	/// Returns every binding that shadows a binding of an enclosing scope, together with the shadowed binding
+	pub fn shadowed_bindings(&self) -> impl Iterator<Item = (&Binding, &Binding)> {
+		self.scopes.bindings().filter_map(move |binding| {
+			self.shadowed_binding(binding)
+				.map(|shadowed| (binding, shadowed))
+		})
+	}
+
+	/// Returns `true` if `reference` runs before its binding is declared, e.g. `a` in `a; var a = 1;`
+	/// reads `undefined` because only the declaration of `a` is hoisted.
+	///
+	/// References inside of a function that is declared before the binding aren't reported because the
+	/// function may be called after the binding is declared, the same goes for class field initializers
+	/// and static initialization blocks, which run once the class is defined. Functions, parameters, and imports are
+	/// initialized before any code runs and are never used before their declaration.
+	pub fn is_used_before_declaration(&self, reference: &Reference) -> bool {
+		let binding = match self.scopes.declaration_of(reference) {
+			Some(binding) => binding,
+			None => return false,
+		};
+
+		let initialized_before_use = matches!(
+			binding.kind(),
+			DeclarationKind::Function | DeclarationKind::Parameter | DeclarationKind::Import
+		);
+		if initialized_before_use {
+			return false;
+		}
+
+		// `let a = a` uses `a` before its declaration is complete
+		let declared_at = binding.declaration().text_trimmed_range().end();
+		reference.range().start() < declared_at
+			&& !self.is_in_nested_function(reference.scope(), binding.scope())
+	}
+
+	/// Returns `true` if `reference` accesses a `let`, `const`, or class binding before it is initialized,
+	/// which throws a `ReferenceError`, e.g. `a` in `a; let a = 1;` or `A` in `class A extends A {}`
+	pub fn is_in_temporal_dead_zone(&self, reference: &Reference) -> bool {
+		let is_lexical = matches!(
+			self.scopes
+				.declaration_of(reference)
+				.map(|binding| binding.kind()),
+			Some(DeclarationKind::Let | DeclarationKind::Const | DeclarationKind::Class)
+		);
+		is_lexical && self.is_used_before_declaration(reference)
+	}
+
+	/// Returns the bindings that are never read. A binding that is only assigned to is unused,
+	/// the bindings exported by the module are used by the importers.
+	pub fn unused_bindings(&self) -> impl Iterator<Item = &Binding> {
+		self.scopes.bindings().filter(move |binding| {
+			let is_read = self
+				.scopes
+				.references_of(binding)
+				.any(|reference| reference.kind().is_read());
+			!is_read && !self.is_exported(binding)
+		})
+	}
+
+	fn is_exported(&self, binding: &Binding) -> bool {
+		binding.scope() == self.scopes.global_scope().id()
+			&& binding.node().ancestors().any(|node| {
+				matches!(
+					node.kind(),
+					EXPORT_DECL | EXPORT_DEFAULT_DECL | EXPORT_DEFAULT_EXPR
+				)
+			})
+	}
+
+	/// Returns `true` if there's a function scope between the scope `inner` and its ancestor `outer`,
+	/// see [ScopeKind::Function]
+	fn is_in_nested_function(&self, inner: ScopeId, outer: ScopeId) -> bool {
+		self.scopes
+			.ancestors(inner)
+			.take_while(|scope| scope.id() != outer)
+			.any(|scope| scope.kind() == ScopeKind::Function)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SemanticModel;
+	use crate::semantic::DeclarationKind;
+	use crate::{parse_module, parse_text};
+
+	fn names<'a>(references: impl Iterator<Item = &'a super::Reference>) -> Vec<String> {
+		references
+			.map(|reference| {
+				format!(
+					"{}@{}",
+					reference.name(),
+					u32::from(reference.range().start())
+				)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn use_before_declaration() {
+		let parse = parse_text(
+			"a; b; f(); function f() { c; } let b = b; var a; const c = 1;",
+			0,
+		);
+		let model = SemanticModel::new(&parse);
+		let before = model
+			.scopes()
+			.references()
+			.filter(|reference| model.is_used_before_declaration(reference));
+		assert_eq!(vec!["a@0", "b@3", "b@39"], names(before));
+
+		let tdz = model
+			.scopes()
+			.references()
+			.filter(|reference| model.is_in_temporal_dead_zone(reference));
+		assert_eq!(vec!["b@3", "b@39"], names(tdz));
+	}
+
+	#[test]
+	fn class_heritage_is_in_temporal_dead_zone() {
+		let parse = parse_text("class A extends A { m() { A; } }", 0);
+		let model = SemanticModel::new(&parse);
+		let tdz = model
+			.scopes()
+			.references()
+			.filter(|reference| model.is_in_temporal_dead_zone(reference));
+		assert_eq!(vec!["A@16"], names(tdz));
+	}
+
+	#[test]
+	fn parameters_do_not_see_the_function_body() {
+		let parse = parse_text("let b; function f(a = b, c = a) { var b; }", 0);
+		let model = SemanticModel::new(&parse);
+		let scopes = model.scopes();
+		let b = scopes
+			.references()
+			.find(|reference| reference.name() == "b")
+			.unwrap();
+		assert_eq!(
+			scopes.global_scope().id(),
+			scopes.declaration_of(b).unwrap().scope()
+		);
+		let a = scopes
+			.references()
+			.find(|reference| reference.name() == "a")
+			.unwrap();
+		assert!(scopes.declaration_of(a).is_some());
+	}
+
+	#[test]
+	fn functions_in_blocks_of_sloppy_code() {
+		let parse = parse_text("{ function f() {} } f();", 0);
+		let model = SemanticModel::new(&parse);
+		assert_eq!(0, model.scopes().unresolved_references().count());
+		assert_eq!(0, model.shadowed_bindings().count());
+
+		let parse = parse_module("{ function f() {} } f();", 0);
+		let model = SemanticModel::new(&parse);
+		assert_eq!(1, model.scopes().unresolved_references().count());
+
+		// The function isn't hoisted past a lexical declaration of the same name
+		let parse = parse_text("{ function f() {} } let f = 1; f;", 0);
+		let model = SemanticModel::new(&parse);
+		let scopes = model.scopes();
+		let f = scopes.references().next().unwrap();
+		let declaration = scopes.declaration_of(f).unwrap();
+		assert_eq!(DeclarationKind::Let, declaration.kind());
+		let unused: Vec<_> = model
+			.unused_bindings()
+			.map(|binding| binding.kind())
+			.collect();
+		assert_eq!(vec![DeclarationKind::Function], unused);
+	}
+
+	#[test]
+	fn class_fields_and_static_blocks_are_deferred() {
+		let parse = parse_text("class A { static x = A; y = A; [A] = 1; static { A; } }", 0);
+		let model = SemanticModel::new(&parse);
+		let tdz = model
+			.scopes()
+			.references()
+			.filter(|reference| model.is_in_temporal_dead_zone(reference));
+		// A computed key is evaluated while the class is defined
+		assert_eq!(vec!["A@32"], names(tdz));
+	}
+
+	#[test]
+	fn shadowed_and_unused_bindings() {
+		let parse = parse_module(
+			"let a = 1, b; b = 2; function f(a) { try {} catch (a) { let c; } } export const d = 1; f(window);",
+			0,
+		);
+		let model = SemanticModel::new(&parse);
+
+		let shadowed: Vec<_> = model
+			.shadowed_bindings()
+			.map(|(binding, shadowed)| {
+				(
+					u32::from(binding.range().start()),
+					u32::from(shadowed.range().start()),
+				)
+			})
+			.collect();
+		// The parameter `a` shadows the variable `a`, the catch parameter `a` shadows the parameter
+		assert_eq!(vec![(32, 4), (51, 32)], shadowed);
+
+		let unused: Vec<_> = model
+			.unused_bindings()
+			.map(|binding| binding.name())
+			.collect();
+		assert_eq!(vec!["a", "b", "a", "a", "c"], unused);
+
+		let globals: Vec<_> = model
+			.scopes()
+			.references()
+			.filter(|reference| model.is_global_reference(reference))
+			.map(|reference| reference.name())
+			.collect();
+		assert_eq!(vec!["window"], globals);
+	}
+}
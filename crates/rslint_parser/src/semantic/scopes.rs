@@ -0,0 +1,949 @@
+//! The scopes of a file and the bindings and references in them.
+
+use crate::{
+	ast::JsRoot, parse::is_strict, AstNode, JsSourceType, SyntaxKind::*, SyntaxNode, SyntaxNodeExt,
+	TextRange, T,
+};
+use std::{collections::HashMap, mem};
+
+/// The index of a [Scope] in [Scopes]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ScopeId(usize);
+
+/// The index of a [Binding] in [Scopes]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BindingId(usize);
+
+/// The index of a [Reference] in [Scopes]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ReferenceId(usize);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ScopeKind {
+	/// The scope of the whole file
+	Global,
+	/// The scope of a function, method, or arrow function, which holds its parameters and `var` declarations.
+	/// The initializer of a class field and a static initialization block have a function scope too,
+	/// because they run when the class is instantiated or after it is defined.
+	Function,
+	/// The scope of a block, a `for` statement, or a `switch` statement
+	Block,
+	/// The scope of a class, which holds the name of a class expression
+	Class,
+	/// The scope of a catch clause, which holds the catch parameter
+	Catch,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DeclarationKind {
+	Var,
+	Let,
+	Const,
+	Function,
+	Class,
+	Parameter,
+	CatchParameter,
+	Import,
+}
+
+impl DeclarationKind {
+	/// Returns `true` if the declaration is scoped to the closest function, e.g. `var` declarations
+	pub fn is_function_scoped(&self) -> bool {
+		matches!(self, DeclarationKind::Var | DeclarationKind::Parameter)
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ReferenceKind {
+	/// The value of the binding is read, e.g. `a` in `a + 1`
+	Read,
+	/// A value is assigned to the binding, e.g. `a` in `a = 1`
+	Write,
+	/// The value is read and assigned, e.g. `a` in `a += 1` or `a++`
+	ReadWrite,
+}
+
+impl ReferenceKind {
+	pub fn is_read(&self) -> bool {
+		matches!(self, ReferenceKind::Read | ReferenceKind::ReadWrite)
+	}
+
+	pub fn is_write(&self) -> bool {
+		matches!(self, ReferenceKind::Write | ReferenceKind::ReadWrite)
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Scope {
+	id: ScopeId,
+	kind: ScopeKind,
+	node: SyntaxNode,
+	parent: Option<ScopeId>,
+	children: Vec<ScopeId>,
+	bindings: Vec<BindingId>,
+	/// The first binding of every name declared in the scope
+	by_name: HashMap<String, BindingId>,
+}
+
+impl Scope {
+	pub fn id(&self) -> ScopeId {
+		self.id
+	}
+
+	pub fn kind(&self) -> ScopeKind {
+		self.kind
+	}
+
+	/// The node that introduces the scope, e.g. a function declaration or a block statement
+	pub fn node(&self) -> &SyntaxNode {
+		&self.node
+	}
+
+	pub fn range(&self) -> TextRange {
+		self.node.text_trimmed_range()
+	}
+
+	pub fn parent(&self) -> Option<ScopeId> {
+		self.parent
+	}
+
+	pub fn children(&self) -> &[ScopeId] {
+		&self.children
+	}
+
+	/// The bindings declared in the scope, in source order
+	pub fn bindings(&self) -> &[BindingId] {
+		&self.bindings
+	}
+
+	/// Returns the first binding named `name` declared in the scope, without looking at the parent scopes
+	pub fn get(&self, name: &str) -> Option<BindingId> {
+		self.by_name.get(name).copied()
+	}
+}
+
+/// A name declared by a declaration, a parameter, or an import
+#[derive(Debug, Clone)]
+pub struct Binding {
+	id: BindingId,
+	name: String,
+	kind: DeclarationKind,
+	node: SyntaxNode,
+	declaration: SyntaxNode,
+	scope: ScopeId,
+	references: Vec<ReferenceId>,
+}
+
+impl Binding {
+	pub fn id(&self) -> BindingId {
+		self.id
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn kind(&self) -> DeclarationKind {
+		self.kind
+	}
+
+	/// The identifier node of the binding, e.g. `a` in `let { a } = b`
+	pub fn node(&self) -> &SyntaxNode {
+		&self.node
+	}
+
+	/// The node that declares the binding, e.g. the variable declarator, the function, the parameter,
+	/// or the import declaration
+	pub fn declaration(&self) -> &SyntaxNode {
+		&self.declaration
+	}
+
+	pub fn range(&self) -> TextRange {
+		self.node.text_trimmed_range()
+	}
+
+	/// The scope the binding is declared in, `var` declarations are declared in the scope of the closest function
+	pub fn scope(&self) -> ScopeId {
+		self.scope
+	}
+}
+
+/// A use of a name in an expression or an assignment target
+#[derive(Debug, Clone)]
+pub struct Reference {
+	id: ReferenceId,
+	name: String,
+	kind: ReferenceKind,
+	node: SyntaxNode,
+	scope: ScopeId,
+	/// The function scope whose parameter list contains the reference. The parameters can't see
+	/// the declarations of the function body.
+	parameters_of: Option<ScopeId>,
+	binding: Option<BindingId>,
+}
+
+impl Reference {
+	pub fn id(&self) -> ReferenceId {
+		self.id
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn kind(&self) -> ReferenceKind {
+		self.kind
+	}
+
+	pub fn node(&self) -> &SyntaxNode {
+		&self.node
+	}
+
+	pub fn range(&self) -> TextRange {
+		self.node.text_trimmed_range()
+	}
+
+	/// The innermost scope that contains the reference
+	pub fn scope(&self) -> ScopeId {
+		self.scope
+	}
+
+	/// Returns `true` if the reference doesn't refer to a binding declared in the file,
+	/// e.g. `console` or an undeclared variable
+	pub fn is_unresolved(&self) -> bool {
+		self.binding.is_none()
+	}
+}
+
+/// The scopes, bindings, and references of a file
+#[derive(Debug, Clone)]
+pub struct Scopes {
+	scopes: Vec<Scope>,
+	bindings: Vec<Binding>,
+	references: Vec<Reference>,
+	scope_by_node: HashMap<SyntaxNode, ScopeId>,
+	binding_by_node: HashMap<SyntaxNode, BindingId>,
+	reference_by_node: HashMap<SyntaxNode, ReferenceId>,
+}
+
+impl Scopes {
+	/// Analyzes the scopes of the file of `root`. The file is analyzed as a module if it contains
+	/// an import or export declaration, see [Scopes::with_source_type].
+	pub fn new(root: &JsRoot) -> Self {
+		let is_module = root
+			.syntax()
+			.children()
+			.filter(|list| list.kind() == LIST)
+			.flat_map(|list| list.children())
+			.any(|item| {
+				matches!(
+					item.kind(),
+					IMPORT_DECL
+						| EXPORT_DECL | EXPORT_DEFAULT_DECL
+						| EXPORT_DEFAULT_EXPR | EXPORT_NAMED
+						| EXPORT_WILDCARD
+				)
+			});
+		let source_type = if is_module {
+			JsSourceType::Module
+		} else {
+			JsSourceType::Script
+		};
+		Self::with_source_type(root, source_type)
+	}
+
+	/// Analyzes the scopes of `root`, a file of `source_type`. Modules are strict mode code,
+	/// functions declared in blocks of strict mode code are only visible in the block.
+	pub fn with_source_type(root: &JsRoot, source_type: JsSourceType) -> Self {
+		let mut builder = ScopesBuilder {
+			source_type,
+			scopes: Scopes {
+				scopes: vec![],
+				bindings: vec![],
+				references: vec![],
+				scope_by_node: HashMap::new(),
+				binding_by_node: HashMap::new(),
+				reference_by_node: HashMap::new(),
+			},
+			stack: vec![],
+			parameters_of: None,
+			block_functions: vec![],
+		};
+		builder.visit(root.syntax());
+		builder.hoist_block_functions();
+		builder.resolve();
+		builder.scopes
+	}
+
+	/// The scope of the whole file
+	pub fn global_scope(&self) -> &Scope {
+		&self.scopes[0]
+	}
+
+	pub fn scope(&self, id: ScopeId) -> &Scope {
+		&self.scopes[id.0]
+	}
+
+	pub fn binding(&self, id: BindingId) -> &Binding {
+		&self.bindings[id.0]
+	}
+
+	pub fn reference(&self, id: ReferenceId) -> &Reference {
+		&self.references[id.0]
+	}
+
+	/// All scopes of the file, the scope of a node comes before the scopes of its descendants
+	pub fn scopes(&self) -> impl Iterator<Item = &Scope> {
+		self.scopes.iter()
+	}
+
+	/// All bindings of the file, in the order they are declared in the source
+	pub fn bindings(&self) -> impl Iterator<Item = &Binding> {
+		self.bindings.iter()
+	}
+
+	/// All references of the file, in source order
+	pub fn references(&self) -> impl Iterator<Item = &Reference> {
+		self.references.iter()
+	}
+
+	/// The references that don't refer to a binding declared in the file
+	pub fn unresolved_references(&self) -> impl Iterator<Item = &Reference> {
+		self.references
+			.iter()
+			.filter(|reference| reference.is_unresolved())
+	}
+
+	/// Returns the innermost scope that contains `node`
+	pub fn scope_of(&self, node: &SyntaxNode) -> &Scope {
+		node.ancestors()
+			.find_map(|ancestor| self.scope_by_node.get(&ancestor))
+			.map_or_else(|| self.global_scope(), |id| self.scope(*id))
+	}
+
+	/// Returns the binding declared by the identifier `node`, e.g. the `NAME` node of `a` in `let a`
+	pub fn binding_of(&self, node: &SyntaxNode) -> Option<&Binding> {
+		self.binding_by_node.get(node).map(|id| self.binding(*id))
+	}
+
+	/// Returns the reference of the identifier `node`
+	pub fn reference_of(&self, node: &SyntaxNode) -> Option<&Reference> {
+		self.reference_by_node
+			.get(node)
+			.map(|id| self.reference(*id))
+	}
+
+	/// Returns the binding `reference` refers to, or [None] if the name isn't declared in the file
+	pub fn declaration_of(&self, reference: &Reference) -> Option<&Binding> {
+		reference.binding.map(|id| self.binding(id))
+	}
+
+	/// Returns the references that refer to `binding`, in source order
+	pub fn references_of<'a>(
+		&'a self,
+		binding: &'a Binding,
+	) -> impl Iterator<Item = &'a Reference> + 'a {
+		binding.references.iter().map(move |id| self.reference(*id))
+	}
+
+	/// Looks `name` up in `scope` and its parent scopes
+	pub fn lookup(&self, scope: ScopeId, name: &str) -> Option<&Binding> {
+		self.ancestors(scope)
+			.find_map(|scope| scope.get(name))
+			.map(|id| self.binding(id))
+	}
+
+	/// Returns `scope` followed by its parent scopes up to the global scope
+	pub fn ancestors(&self, scope: ScopeId) -> impl Iterator<Item = &Scope> {
+		std::iter::successors(Some(self.scope(scope)), move |scope| {
+			scope.parent.map(|parent| self.scope(parent))
+		})
+	}
+
+	/// Looks the name of `reference` up like [Scopes::lookup] but skips the declarations of the body
+	/// of a function if the reference is in the function's parameter list, e.g. `b` in `function f(a = b) { var b; }`
+	fn resolve(&self, reference: &Reference) -> Option<BindingId> {
+		self.ancestors(reference.scope).find_map(|scope| {
+			let binding = scope.get(&reference.name)?;
+			let data = self.binding(binding);
+			let visible = reference.parameters_of != Some(scope.id)
+				|| data.kind == DeclarationKind::Parameter
+				|| &data.declaration == scope.node();
+			Some(binding).filter(|_| visible)
+		})
+	}
+}
+
+struct ScopesBuilder {
+	source_type: JsSourceType,
+	scopes: Scopes,
+	/// The scopes of the nodes that are being visited
+	stack: Vec<ScopeId>,
+	/// The function scope whose parameter list is being visited
+	parameters_of: Option<ScopeId>,
+	/// The functions declared in blocks of sloppy mode code, see [ScopesBuilder::hoist_block_functions]
+	block_functions: Vec<BindingId>,
+}
+
+impl ScopesBuilder {
+	fn visit(&mut self, node: &SyntaxNode) {
+		match node.kind() {
+			JS_FUNCTION_DECLARATION | JS_CLASS_DECLARATION => {
+				let kind = if node.kind() == JS_CLASS_DECLARATION {
+					DeclarationKind::Class
+				} else {
+					DeclarationKind::Function
+				};
+				if let Some(name) = node.child_with_kind(JS_IDENTIFIER_BINDING) {
+					self.declare(&name, kind, node);
+					let in_block =
+						self.scopes.scope(*self.stack.last().unwrap()).kind == ScopeKind::Block;
+					if kind == DeclarationKind::Function
+						&& in_block && !is_strict(&name, self.source_type)
+					{
+						let id = self.scopes.bindings.last().unwrap().id;
+						self.block_functions.push(id);
+					}
+				}
+			}
+			// `export default function f() {}` declares `f` in the module
+			JS_FUNCTION_EXPRESSION
+				if node.parent().map(|parent| parent.kind()) == Some(EXPORT_DEFAULT_EXPR) =>
+			{
+				if let Some(name) = node.child_with_kind(JS_IDENTIFIER_BINDING) {
+					self.declare(&name, DeclarationKind::Function, node);
+				}
+			}
+			JS_VARIABLE_DECLARATION => self.declare_variables(node),
+			IMPORT_DECL => self.declare_imports(node),
+			JS_REFERENCE_IDENTIFIER_EXPRESSION => self.reference(node, reference_kind(node)),
+			ASSIGN_EXPR => {
+				if let Some(target) = node
+					.first_child()
+					.filter(|target| matches!(target.kind(), OBJECT_PATTERN | ARRAY_PATTERN))
+				{
+					for name in pattern_names(&target) {
+						self.reference(&name, ReferenceKind::Write);
+					}
+				}
+			}
+			// `export { a as b }` reads `a` unless the names are exported from another module
+			EXPORT_NAMED if node.token_with_kind(T![from]).is_none() => {
+				for specifier in node.descendants().filter(|n| n.kind() == SPECIFIER) {
+					if let Some(local) = specifier.child_with_kind(NAME) {
+						self.reference(&local, ReferenceKind::Read);
+					}
+				}
+			}
+			_ => {}
+		}
+
+		let scope_kind = scope_kind(node);
+		if let Some(kind) = scope_kind {
+			self.push_scope(node, kind);
+			self.declare_in_scope(node);
+		}
+
+		for child in node.children() {
+			let is_parameters = scope_kind == Some(ScopeKind::Function)
+				&& matches!(
+					child.kind(),
+					JS_PARAMETER_LIST
+						| JS_CONSTRUCTOR_PARAMETER_LIST
+						| SINGLE_PATTERN | OBJECT_PATTERN
+						| ARRAY_PATTERN | ASSIGN_PATTERN
+				);
+			if is_parameters {
+				let parameters_of = self.parameters_of.replace(*self.stack.last().unwrap());
+				self.visit(&child);
+				self.parameters_of = parameters_of;
+			} else {
+				self.visit(&child);
+			}
+		}
+
+		if scope_kind.is_some() {
+			self.stack.pop();
+		}
+	}
+
+	/// Declares the names that belong to the scope `node` introduces, e.g. the parameters of a function
+	fn declare_in_scope(&mut self, node: &SyntaxNode) {
+		match node.kind() {
+			JS_FUNCTION_EXPRESSION | JS_CLASS_EXPRESSION => {
+				if let Some(name) = node.child_with_kind(JS_IDENTIFIER_BINDING) {
+					// The name of `export default function f() {}` is declared in the module scope
+					if node.parent().map(|parent| parent.kind()) != Some(EXPORT_DEFAULT_EXPR) {
+						let kind = if node.kind() == JS_CLASS_EXPRESSION {
+							DeclarationKind::Class
+						} else {
+							DeclarationKind::Function
+						};
+						self.declare(&name, kind, node);
+					}
+				}
+			}
+			JS_CATCH_CLAUSE => {
+				if let Some(declaration) = node.child_with_kind(JS_CATCH_DECLARATION) {
+					for name in pattern_names(&declaration) {
+						self.declare(&name, DeclarationKind::CatchParameter, &declaration);
+					}
+				}
+			}
+			_ => {}
+		}
+
+		if scope_kind(node) != Some(ScopeKind::Function) {
+			return;
+		}
+
+		for child in node.children() {
+			match child.kind() {
+				JS_PARAMETER_LIST | JS_CONSTRUCTOR_PARAMETER_LIST => {
+					let parameters = child.children().filter(|list| list.kind() == LIST);
+					for parameter in parameters.flat_map(|list| list.children()) {
+						for name in pattern_names(&parameter) {
+							self.declare(&name, DeclarationKind::Parameter, &parameter);
+						}
+					}
+				}
+				// `a => a`
+				JS_IDENTIFIER_BINDING if node.kind() == JS_ARROW_FUNCTION_EXPRESSION => {
+					self.declare(&child, DeclarationKind::Parameter, &child);
+				}
+				// The parameter of a setter
+				SINGLE_PATTERN | OBJECT_PATTERN | ARRAY_PATTERN | ASSIGN_PATTERN => {
+					for name in pattern_names(&child) {
+						self.declare(&name, DeclarationKind::Parameter, &child);
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+
+	fn declare_variables(&mut self, declaration: &SyntaxNode) {
+		let kind = match declaration.first_token().map(|token| token.kind()) {
+			Some(T![var]) => DeclarationKind::Var,
+			Some(T![const]) => DeclarationKind::Const,
+			_ => DeclarationKind::Let,
+		};
+
+		let declarators = declaration
+			.children()
+			.filter(|list| list.kind() == LIST)
+			.flat_map(|list| list.children())
+			.filter(|declarator| declarator.kind() == JS_VARIABLE_DECLARATOR);
+		for declarator in declarators {
+			if let Some(pattern) = declarator.first_child() {
+				for name in pattern_names(&pattern) {
+					self.declare(&name, kind, &declarator);
+				}
+			}
+		}
+	}
+
+	fn declare_imports(&mut self, import: &SyntaxNode) {
+		let clauses = import
+			.children()
+			.filter(|list| list.kind() == LIST)
+			.flat_map(|list| list.children());
+		for clause in clauses {
+			match clause.kind() {
+				// `import a from "a"`
+				NAME => self.declare(&clause, DeclarationKind::Import, import),
+				// `import * as a from "a"`
+				WILDCARD_IMPORT => {
+					if let Some(name) = clause.child_with_kind(NAME) {
+						self.declare(&name, DeclarationKind::Import, import);
+					}
+				}
+				// `import { a, b as c } from "a"`
+				NAMED_IMPORTS => {
+					for specifier in clause.descendants().filter(|n| n.kind() == SPECIFIER) {
+						let local = specifier.children().filter(|n| n.kind() == NAME).last();
+						if let Some(local) = local {
+							self.declare(&local, DeclarationKind::Import, import);
+						}
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+
+	fn push_scope(&mut self, node: &SyntaxNode, kind: ScopeKind) {
+		let id = ScopeId(self.scopes.scopes.len());
+		let parent = self.stack.last().copied();
+		if let Some(parent) = parent {
+			self.scopes.scopes[parent.0].children.push(id);
+		}
+		self.scopes.scopes.push(Scope {
+			id,
+			kind,
+			node: node.clone(),
+			parent,
+			children: vec![],
+			bindings: vec![],
+			by_name: HashMap::new(),
+		});
+		self.scopes.scope_by_node.insert(node.clone(), id);
+		self.stack.push(id);
+	}
+
+	fn declare(&mut self, name: &SyntaxNode, kind: DeclarationKind, declaration: &SyntaxNode) {
+		let scope = if kind == DeclarationKind::Var {
+			// `var` declarations are hoisted to the closest function
+			self.stack
+				.iter()
+				.rev()
+				.copied()
+				.find(|id| {
+					matches!(
+						self.scopes.scopes[id.0].kind,
+						ScopeKind::Function | ScopeKind::Global
+					)
+				})
+				.unwrap()
+		} else {
+			*self.stack.last().unwrap()
+		};
+
+		let id = BindingId(self.scopes.bindings.len());
+		let text = name.text_trimmed().to_string();
+		let scope_data = &mut self.scopes.scopes[scope.0];
+		scope_data.bindings.push(id);
+		scope_data.by_name.entry(text.clone()).or_insert(id);
+		self.scopes.binding_by_node.insert(name.clone(), id);
+		self.scopes.bindings.push(Binding {
+			id,
+			name: text,
+			kind,
+			node: name.clone(),
+			declaration: declaration.clone(),
+			scope,
+			references: vec![],
+		});
+	}
+
+	/// Makes the functions declared in blocks of sloppy mode code visible in the enclosing function like a `var`
+	/// declaration, which is how browsers treat these functions (Annex B.3.3). A function isn't hoisted if a
+	/// lexical declaration of the same name is in the way, e.g. `let f` in `{ function f() {} } let f = 1;`,
+	/// so this runs once all declarations of the file are known.
+	fn hoist_block_functions(&mut self) {
+		for id in mem::take(&mut self.block_functions) {
+			let binding = self.scopes.binding(id);
+			let parent = self.scopes.scope(binding.scope).parent.unwrap();
+			let mut function_scope = None;
+			for scope in self.scopes.ancestors(parent) {
+				let is_lexical = |other: &BindingId| {
+					let other = self.scopes.binding(*other);
+					other.name == binding.name
+						&& match other.kind {
+							DeclarationKind::Let
+							| DeclarationKind::Const
+							| DeclarationKind::Class => true,
+							DeclarationKind::Function => scope.kind == ScopeKind::Block,
+							_ => false,
+						}
+				};
+				if scope.bindings.iter().any(is_lexical) {
+					break;
+				}
+				if matches!(scope.kind, ScopeKind::Function | ScopeKind::Global) {
+					function_scope = Some(scope.id);
+					break;
+				}
+			}
+
+			if let Some(function_scope) = function_scope {
+				let name = binding.name.clone();
+				self.scopes.scopes[function_scope.0]
+					.by_name
+					.entry(name)
+					.or_insert(id);
+			}
+		}
+	}
+
+	fn reference(&mut self, node: &SyntaxNode, kind: ReferenceKind) {
+		let id = ReferenceId(self.scopes.references.len());
+		self.scopes.reference_by_node.insert(node.clone(), id);
+		self.scopes.references.push(Reference {
+			id,
+			name: node.text_trimmed().to_string(),
+			kind,
+			node: node.clone(),
+			scope: *self.stack.last().unwrap(),
+			parameters_of: self.parameters_of,
+			binding: None,
+		});
+	}
+
+	/// Resolves the references once all bindings are declared
+	fn resolve(&mut self) {
+		for index in 0..self.scopes.references.len() {
+			let binding = self.scopes.resolve(&self.scopes.references[index]);
+			if let Some(binding) = binding {
+				self.scopes.bindings[binding.0]
+					.references
+					.push(ReferenceId(index));
+			}
+			self.scopes.references[index].binding = binding;
+		}
+	}
+}
+
+fn scope_kind(node: &SyntaxNode) -> Option<ScopeKind> {
+	let kind = match node.kind() {
+		JS_ROOT => ScopeKind::Global,
+		JS_FUNCTION_DECLARATION
+		| JS_FUNCTION_EXPRESSION
+		| JS_ARROW_FUNCTION_EXPRESSION
+		| JS_METHOD_CLASS_MEMBER
+		| JS_GETTER_CLASS_MEMBER
+		| JS_SETTER_CLASS_MEMBER
+		| JS_CONSTRUCTOR_CLASS_MEMBER
+		| JS_METHOD_OBJECT_MEMBER
+		| JS_GETTER_OBJECT_MEMBER
+		| JS_SETTER_OBJECT_MEMBER => ScopeKind::Function,
+		JS_BLOCK_STATEMENT | FOR_STMT | FOR_IN_STMT | FOR_OF_STMT | JS_SWITCH_STATEMENT => {
+			ScopeKind::Block
+		}
+		JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER => ScopeKind::Function,
+		// The initializer of a class field
+		JS_EQUAL_VALUE_CLAUSE
+			if node.parent().map(|parent| parent.kind()) == Some(JS_PROPERTY_CLASS_MEMBER) =>
+		{
+			ScopeKind::Function
+		}
+		JS_CLASS_DECLARATION | JS_CLASS_EXPRESSION => ScopeKind::Class,
+		JS_CATCH_CLAUSE => ScopeKind::Catch,
+		_ => return None,
+	};
+	Some(kind)
+}
+
+/// Returns the identifiers a binding pattern declares, skipping the keys of object patterns and the default values
+fn pattern_names(pattern: &SyntaxNode) -> Vec<SyntaxNode> {
+	let mut names = vec![];
+	let mut stack = vec![pattern.clone()];
+	while let Some(node) = stack.pop() {
+		match node.kind() {
+			NAME | JS_IDENTIFIER_BINDING => names.push(node),
+			// `a = 1`
+			ASSIGN_PATTERN => stack.extend(node.first_child()),
+			// `key: a`
+			KEY_VALUE_PATTERN => stack.extend(node.last_child()),
+			SINGLE_PATTERN
+			| OBJECT_PATTERN
+			| ARRAY_PATTERN
+			| REST_PATTERN
+			| LIST
+			| JS_REST_PARAMETER
+			| JS_CATCH_DECLARATION
+			| JS_CONSTRUCTOR_PARAMETER
+			| TS_CONSTRUCTOR_PARAM
+			| TS_DECORATED_PARAMETER => {
+				// Reversed to pop the children in source order
+				let start = stack.len();
+				stack.extend(node.children());
+				stack[start..].reverse();
+			}
+			_ => {}
+		}
+	}
+	names
+}
+
+/// Returns how the identifier expression `node` uses its binding
+fn reference_kind(node: &SyntaxNode) -> ReferenceKind {
+	let mut current = node.clone();
+	while let Some(parent) = current.parent() {
+		match parent.kind() {
+			ASSIGN_EXPR if parent.first_child().as_ref() == Some(&current) => {
+				let operator = parent
+					.children_with_tokens()
+					.find_map(|element| element.into_token());
+				return match operator.map(|token| token.kind()) {
+					Some(T![=]) => ReferenceKind::Write,
+					_ => ReferenceKind::ReadWrite,
+				};
+			}
+			JS_PRE_UPDATE_EXPRESSION | JS_POST_UPDATE_EXPRESSION => {
+				return ReferenceKind::ReadWrite
+			}
+			// `for (a of b)` or `for ([a, b] of c)`
+			FOR_STMT_INIT => {
+				let is_for_in_or_of = matches!(
+					parent.parent().map(|stmt| stmt.kind()),
+					Some(FOR_IN_STMT | FOR_OF_STMT)
+				);
+				return if is_for_in_or_of {
+					ReferenceKind::Write
+				} else {
+					ReferenceKind::Read
+				};
+			}
+			// The targets of an assignment to an array or object, which the parser only
+			// reparses as a pattern if the assignment isn't the head of a `for` loop
+			JS_PARENTHESIZED_EXPRESSION
+			| JS_ARRAY_EXPRESSION
+			| JS_OBJECT_EXPRESSION
+			| LIST
+			| JS_SHORTHAND_PROPERTY_OBJECT_MEMBER
+			| JS_PROPERTY_OBJECT_MEMBER
+			| SPREAD_ELEMENT
+			| JS_SPREAD => current = parent,
+			_ => return ReferenceKind::Read,
+		}
+	}
+	ReferenceKind::Read
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{DeclarationKind, ReferenceKind, ScopeKind, Scopes};
+	use crate::ast::JsRoot;
+	use crate::{parse_module, parse_text, Parse};
+
+	/// Returns `name@offset -> declaration offset` for every reference of the file
+	fn resolve(parse: &Parse<JsRoot>) -> Vec<String> {
+		let scopes = Scopes::new(&parse.tree());
+		scopes
+			.references()
+			.map(|reference| {
+				let declaration = match scopes.declaration_of(reference) {
+					Some(binding) => u32::from(binding.range().start()).to_string(),
+					None => "unresolved".to_string(),
+				};
+				format!(
+					"{}@{} -> {}",
+					reference.name(),
+					u32::from(reference.range().start()),
+					declaration
+				)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn resolves_hoisted_declarations() {
+		let parse = parse_text("a; f(); function f() { b; var b; } var a; b;", 0);
+		assert_eq!(
+			vec!["a@0 -> 39", "f@3 -> 17", "b@23 -> 30", "b@42 -> unresolved"],
+			resolve(&parse)
+		);
+	}
+
+	#[test]
+	fn resolves_block_scoped_declarations() {
+		let parse = parse_text("let x = 1; { let x = 2; x; } x; for (let i of x) i;", 0);
+		assert_eq!(
+			vec!["x@24 -> 17", "x@29 -> 4", "x@46 -> 4", "i@49 -> 41"],
+			resolve(&parse)
+		);
+	}
+
+	#[test]
+	fn declares_parameters_and_names() {
+		let code = "const f = function g(a, { b, c: [d] }, e = a, ...h) { g; d; h; };
+try {} catch ({ message }) { message; }
+let k = class K { m(n) { K; n; } };
+(p => p)(q);";
+		let parse = parse_text(code, 0);
+		let scopes = Scopes::new(&parse.tree());
+		let bindings: Vec<_> = scopes
+			.bindings()
+			.map(|binding| (binding.name(), binding.kind()))
+			.collect();
+		assert_eq!(
+			vec![
+				("f", DeclarationKind::Const),
+				("g", DeclarationKind::Function),
+				("a", DeclarationKind::Parameter),
+				("b", DeclarationKind::Parameter),
+				("d", DeclarationKind::Parameter),
+				("e", DeclarationKind::Parameter),
+				("h", DeclarationKind::Parameter),
+				("message", DeclarationKind::CatchParameter),
+				("k", DeclarationKind::Let),
+				("K", DeclarationKind::Class),
+				("n", DeclarationKind::Parameter),
+				("p", DeclarationKind::Parameter),
+			],
+			bindings
+		);
+
+		let unresolved: Vec<_> = scopes
+			.unresolved_references()
+			.map(|reference| reference.name())
+			.collect();
+		assert_eq!(vec!["q"], unresolved);
+
+		let kinds: Vec<_> = scopes.scopes().map(|scope| scope.kind()).collect();
+		assert_eq!(
+			vec![
+				ScopeKind::Global,
+				ScopeKind::Function,
+				ScopeKind::Block,
+				ScopeKind::Catch,
+				ScopeKind::Block,
+				ScopeKind::Class,
+				ScopeKind::Function,
+				ScopeKind::Function,
+			],
+			kinds
+		);
+	}
+
+	#[test]
+	fn declares_imports() {
+		let parse = parse_module(
+			"import a, { b as c, d } from 'a'; import * as e from 'e'; a; c; d; e; b; export { a as f };",
+			0,
+		);
+		let scopes = Scopes::new(&parse.tree());
+		let imports: Vec<_> = scopes
+			.bindings()
+			.filter(|binding| binding.kind() == DeclarationKind::Import)
+			.map(|binding| binding.name())
+			.collect();
+		assert_eq!(vec!["a", "c", "d", "e"], imports);
+
+		let a = scopes.bindings().next().unwrap();
+		assert_eq!(2, scopes.references_of(a).count());
+		let unresolved: Vec<_> = scopes
+			.unresolved_references()
+			.map(|reference| reference.name())
+			.collect();
+		assert_eq!(vec!["b"], unresolved);
+	}
+
+	#[test]
+	fn reference_kinds() {
+		let parse = parse_text(
+			"let a; a; a = 1; a += 1; a++; [a] = b; ({ a } = b); for (a of b) {} for ([a] in b) {}",
+			0,
+		);
+		let scopes = Scopes::new(&parse.tree());
+		let a = scopes.bindings().next().unwrap();
+		let kinds: Vec<_> = scopes
+			.references_of(a)
+			.map(|reference| reference.kind())
+			.collect();
+		assert_eq!(
+			vec![
+				ReferenceKind::Read,
+				ReferenceKind::Write,
+				ReferenceKind::ReadWrite,
+				ReferenceKind::ReadWrite,
+				ReferenceKind::Write,
+				ReferenceKind::Write,
+				ReferenceKind::Write,
+				ReferenceKind::Write,
+			],
+			kinds
+		);
+	}
+}
@@ -0,0 +1,344 @@
+//! JS string parsing.
+
+use crate::{TextRange, TextSize};
+use std::fmt;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+/// The reason why an escape sequence is malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscapeErrorKind {
+	/// A `\x` escape that isn't followed by two hex digits
+	InvalidHexEscape,
+	/// A `\u` escape that isn't followed by four hex digits or a braced code point
+	InvalidUnicodeEscape,
+	/// A `\u{...}` escape with a code point greater than `0x10FFFF`
+	CodePointOutOfRange,
+	/// An octal escape such as `\07`, or a `\8` or `\9` escape in a template
+	OctalEscapeInTemplate,
+}
+
+impl fmt::Display for EscapeErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let message = match self {
+			EscapeErrorKind::InvalidHexEscape => "invalid hexadecimal escape sequence",
+			EscapeErrorKind::InvalidUnicodeEscape => "invalid unicode escape sequence",
+			EscapeErrorKind::CodePointOutOfRange => "unicode escapes must be less than `0x10FFFF`",
+			EscapeErrorKind::OctalEscapeInTemplate => {
+				"octal escape sequences are not allowed in templates"
+			}
+		};
+		f.write_str(message)
+	}
+}
+
+/// A malformed escape sequence in a string or template literal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EscapeError {
+	pub kind: EscapeErrorKind,
+	/// The range of the whole escape sequence, including the `\`
+	pub range: TextRange,
+}
+
+impl EscapeError {
+	/// Moves the range of this error by `offset`
+	pub fn with_offset(self, offset: TextSize) -> Self {
+		Self {
+			range: self.range + offset,
+			..self
+		}
+	}
+}
+
+/// Compute the value of a js string literal, including its quotes, by decoding its escape sequences.
+///
+/// Lone surrogates such as `"\uD800"` can't be represented in a Rust string and are replaced by
+/// `U+FFFD REPLACEMENT CHARACTER`. The ranges of the returned errors are relative to the start of `text`.
+pub fn parse_js_string(text: &str) -> Result<String, Vec<EscapeError>> {
+	let (inner, offset) = match text.chars().next() {
+		Some(quote @ '"') | Some(quote @ '\'') => {
+			let inner = &text[1..];
+			(
+				inner.strip_suffix(quote).unwrap_or(inner),
+				TextSize::from(1),
+			)
+		}
+		_ => (text, TextSize::from(0)),
+	};
+
+	unescape(inner, offset, false)
+}
+
+/// Decodes the escape sequences of the contents of a string (`template: false`) or a template chunk
+/// (`template: true`). Templates reject octal escapes and normalize `\r\n` and `\r` line terminators to `\n`.
+pub(crate) fn unescape(
+	text: &str,
+	offset: TextSize,
+	template: bool,
+) -> Result<String, Vec<EscapeError>> {
+	let mut value = String::with_capacity(text.len());
+	let mut errors = Vec::new();
+	let mut chars = text.char_indices().peekable();
+
+	while let Some((start, c)) = chars.next() {
+		if c == '\r' && template {
+			eat_char(&mut chars, '\n');
+			value.push('\n');
+			continue;
+		}
+
+		if c != '\\' {
+			value.push(c);
+			continue;
+		}
+
+		let escaped = match chars.next() {
+			Some((_, escaped)) => escaped,
+			// Unterminated literal, which is already reported by the lexer
+			None => {
+				value.push('\\');
+				break;
+			}
+		};
+
+		let result = match escaped {
+			'n' => Ok('\n'),
+			't' => Ok('\t'),
+			'r' => Ok('\r'),
+			'b' => Ok('\u{8}'),
+			'f' => Ok('\u{c}'),
+			'v' => Ok('\u{b}'),
+			// line continuations
+			'\r' => {
+				eat_char(&mut chars, '\n');
+				continue;
+			}
+			'\n' | '\u{2028}' | '\u{2029}' => continue,
+			'x' => read_hex_digits(&mut chars, 2)
+				.and_then(char::from_u32)
+				.ok_or(EscapeErrorKind::InvalidHexEscape),
+			'u' => read_unicode_escape(&mut chars).map(|code_point| {
+				// Combine surrogate pairs like `\uD83D\uDE00`
+				if (0xD800..=0xDBFF).contains(&code_point) {
+					let mut lookahead = chars.clone();
+					if eat_char(&mut lookahead, '\\') && eat_char(&mut lookahead, 'u') {
+						if let Ok(low @ 0xDC00..=0xDFFF) = read_unicode_escape(&mut lookahead) {
+							chars = lookahead;
+							let combined = 0x10000 + ((code_point - 0xD800) << 10) + (low - 0xDC00);
+							return char::from_u32(combined).unwrap();
+						}
+					}
+				}
+
+				char::from_u32(code_point).unwrap_or('\u{FFFD}')
+			}),
+			'0' if !matches!(chars.peek(), Some((_, '0'..='9'))) => Ok('\0'),
+			'0'..='9' if template => {
+				while matches!(chars.peek(), Some((_, '0'..='9'))) {
+					chars.next();
+				}
+				Err(EscapeErrorKind::OctalEscapeInTemplate)
+			}
+			'0'..='7' => {
+				// `\0` to `\377`, but `\477` is `\47` followed by `7`
+				let max_len = if escaped <= '3' { 3 } else { 2 };
+				let mut code_point = escaped.to_digit(8).unwrap();
+				for _ in 1..max_len {
+					match chars.peek().and_then(|(_, c)| c.to_digit(8)) {
+						Some(digit) => {
+							chars.next();
+							code_point = code_point * 8 + digit;
+						}
+						None => break,
+					}
+				}
+				Ok(char::from_u32(code_point).unwrap())
+			}
+			other => Ok(other),
+		};
+
+		match result {
+			Ok(c) => value.push(c),
+			Err(kind) => {
+				let end = chars.peek().map_or(text.len(), |(idx, _)| *idx);
+				errors.push(EscapeError {
+					kind,
+					range: TextRange::new(
+						offset + TextSize::from(start as u32),
+						offset + TextSize::from(end as u32),
+					),
+				});
+			}
+		}
+	}
+
+	if errors.is_empty() {
+		Ok(value)
+	} else {
+		Err(errors)
+	}
+}
+
+fn eat_char(chars: &mut Peekable<CharIndices>, expected: char) -> bool {
+	if chars.peek().map(|(_, c)| *c) == Some(expected) {
+		chars.next();
+		true
+	} else {
+		false
+	}
+}
+
+/// Reads exactly `count` hex digits, or as many as are present if the escape is malformed
+fn read_hex_digits(chars: &mut Peekable<CharIndices>, count: usize) -> Option<u32> {
+	let mut value = 0;
+	for _ in 0..count {
+		let digit = chars.peek().and_then(|(_, c)| c.to_digit(16))?;
+		chars.next();
+		value = value * 16 + digit;
+	}
+	Some(value)
+}
+
+/// Reads the code point of a `\u` escape after the `u`, either `XXXX` or `{X...}`
+fn read_unicode_escape(chars: &mut Peekable<CharIndices>) -> Result<u32, EscapeErrorKind> {
+	if !eat_char(chars, '{') {
+		return read_hex_digits(chars, 4).ok_or(EscapeErrorKind::InvalidUnicodeEscape);
+	}
+
+	let mut code_point: u32 = 0;
+	let mut digits = 0;
+	let mut out_of_range = false;
+	while let Some(digit) = chars.peek().and_then(|(_, c)| c.to_digit(16)) {
+		chars.next();
+		digits += 1;
+		code_point = code_point.saturating_mul(16).saturating_add(digit);
+		out_of_range |= code_point > 0x10FFFF;
+	}
+
+	let closed = eat_char(chars, '}');
+
+	if digits == 0 || !closed {
+		Err(EscapeErrorKind::InvalidUnicodeEscape)
+	} else if out_of_range {
+		Err(EscapeErrorKind::CodePointOutOfRange)
+	} else {
+		Ok(code_point)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{parse_js_string, EscapeErrorKind};
+	use crate::{
+		ast::{JsStringLiteralExpression, Template},
+		parse_module, AstNode, SyntaxTokenExt, TextRange,
+	};
+
+	macro_rules! assert_string {
+		($literal:literal, $value:expr) => {
+			assert_eq!(parse_js_string($literal), Ok($value.to_string()));
+		};
+	}
+
+	#[test]
+	fn simple_escapes() {
+		assert_string!(r#""abc""#, "abc");
+		assert_string!(r#"'a\nb\tc\\d\'e\"'"#, "a\nb\tc\\d'e\"");
+		assert_string!(r#""\b\f\v\0""#, "\u{8}\u{c}\u{b}\0");
+		assert_string!(r#""\q\$""#, "q$");
+	}
+
+	#[test]
+	fn line_continuations() {
+		assert_string!("\"a\\\nb\\\r\nc\\\rd\\\u{2028}e\"", "abcde");
+	}
+
+	#[test]
+	fn hex_and_unicode_escapes() {
+		assert_string!(r#""\x41B\u{43}\u{1F600}""#, "ABC\u{1F600}");
+		assert_string!(r#""\uD83D\uDE00""#, "\u{1F600}");
+		assert_string!(r#""\u{D83D}\u{DE00}""#, "\u{1F600}");
+		assert_string!(r#""\uD800a""#, "\u{FFFD}a");
+	}
+
+	#[test]
+	fn legacy_octal_escapes() {
+		assert_string!(r#""\101\0101\477\8""#, "A\u{8}1'78");
+	}
+
+	#[test]
+	fn malformed_escapes() {
+		let errors = parse_js_string(r#""a\x4g\u12\u{110000}\u{}""#).unwrap_err();
+		let errors = errors
+			.into_iter()
+			.map(|error| (error.kind, error.range))
+			.collect::<Vec<_>>();
+
+		assert_eq!(
+			vec![
+				(
+					EscapeErrorKind::InvalidHexEscape,
+					TextRange::new(2.into(), 5.into())
+				),
+				(
+					EscapeErrorKind::InvalidUnicodeEscape,
+					TextRange::new(6.into(), 10.into())
+				),
+				(
+					EscapeErrorKind::CodePointOutOfRange,
+					TextRange::new(10.into(), 20.into())
+				),
+				(
+					EscapeErrorKind::InvalidUnicodeEscape,
+					TextRange::new(20.into(), 24.into())
+				),
+			],
+			errors
+		);
+	}
+
+	#[test]
+	fn string_literal_value() {
+		let parsed = parse_module("let a = 'a\\tb';\nlet b = \"\\u{41}\\\nB\";", 0);
+		let literals = parsed
+			.syntax()
+			.descendants()
+			.filter_map(JsStringLiteralExpression::cast)
+			.collect::<Vec<_>>();
+
+		assert_eq!(literals[0].value(), Ok("a\tb".to_string()));
+		assert_eq!(literals[1].value(), Ok("AB".to_string()));
+		assert_eq!(
+			Some(literals[1].value()),
+			literals[1].value_token().unwrap().string_value()
+		);
+	}
+
+	#[test]
+	fn template_chunk_values() {
+		let parsed = parse_module("`a\\tb${c}d\r\ne\\\r\nf`;\ntag`\\x4${c}\\07\\u0041`;", 0);
+		let templates = parsed
+			.syntax()
+			.descendants()
+			.filter_map(Template::cast)
+			.collect::<Vec<_>>();
+
+		assert!(parsed.errors().is_empty());
+		assert!(templates[0].tag().is_none());
+		let chunks = templates[0].chunks().collect::<Vec<_>>();
+		assert_eq!(chunks[0].cooked(), Some("a\tb".to_string()));
+		assert_eq!(chunks[0].raw(), "a\\tb");
+		assert_eq!(chunks[1].cooked(), Some("d\nef".to_string()));
+		assert_eq!(chunks[1].raw(), "d\ne\\\nf");
+
+		assert!(templates[1].tag().is_some());
+		let chunks = templates[1].chunks().collect::<Vec<_>>();
+		assert_eq!(chunks[0].cooked(), None);
+		assert_eq!(chunks[0].raw(), "\\x4");
+		assert_eq!(chunks[1].cooked(), None);
+		assert_eq!(
+			chunks[1].cooked_value().unwrap_err()[0].kind,
+			EscapeErrorKind::OctalEscapeInTemplate
+		);
+	}
+}
@@ -9,6 +9,7 @@
 
 mod class;
 pub mod decl;
+mod ecma_version;
 pub mod expr;
 mod function;
 mod js_parse_error;
@@ -18,3 +19,6 @@ pub mod program;
 pub mod stmt;
 pub mod typescript;
 pub mod util;
+
+pub(crate) use class::check_private_names;
+pub(crate) use ecma_version::check_ecma_version;
@@ -1,21 +1,30 @@
+use crate::ast::{JsPrivateClassMemberName, JsReferencePrivateMember};
 #[allow(deprecated)]
 use crate::parser::single_token_parse_recovery::SingleTokenParseRecovery;
-use crate::parser::ParsedSyntax;
-use crate::syntax::decl::{formal_param_pat, parameter_list, parameters_list};
-use crate::syntax::expr::assign_expr;
+use crate::parser::{ParsedSyntax, ParsingContext};
+use crate::syntax::decl::{formal_param_pat, parameters_list};
+use crate::syntax::expr::{
+	args, assign_expr, paren_expr, reference_identifier_expression, static_member_expression,
+};
 use crate::syntax::function::{function_body, ts_parameter_types, ts_return_type};
 use crate::syntax::js_parse_error;
 use crate::syntax::object::{computed_member_name, literal_member_name};
 use crate::syntax::pat::opt_binding_identifier;
-use crate::syntax::stmt::{block_impl, is_semi, optional_semi};
+use crate::syntax::stmt::{block_impl, is_semi, optional_semi, statements};
 use crate::syntax::typescript::{
-	abstract_readonly_modifiers, maybe_ts_type_annotation, try_parse_index_signature,
-	ts_heritage_clause, ts_modifier, ts_type_params, DISALLOWED_TYPE_NAMES,
+	abstract_readonly_override_modifiers, definite_assignment_errors, maybe_ts_type_annotation,
+	try_parse_index_signature, try_parse_ts, ts_heritage_clause, ts_modifier, ts_type_args,
+	ts_type_params, ClassMemberModifiers, DISALLOWED_TYPE_NAMES,
 };
 use crate::ParsedSyntax::Present;
-use crate::{CompletedMarker, Event, Marker, Parser, ParserState, StrictMode, TokenSet};
+use crate::{
+	AstNode, CompletedMarker, Event, Marker, Parser, ParserError, ParserState, StrictMode,
+	SyntaxNode, TextRange, TokenSet, WalkEvent,
+};
+use rslint_errors::Diagnostic;
 use rslint_syntax::SyntaxKind::*;
 use rslint_syntax::{SyntaxKind, T};
+use std::collections::HashMap;
 use std::ops::Range;
 
 /// Parses a class expression, e.g. let a = class {}
@@ -58,6 +67,112 @@ impl From<ClassKind> for SyntaxKind {
 
 fn class(p: &mut Parser, kind: ClassKind) -> CompletedMarker {
 	let m = p.start();
+	let has_decorators = p.at(T![@]);
+	decorators(p);
+
+	// test_err decorators_before_nested_export
+	// if (a) @sealed export class A {}
+	// let b = @sealed export class {};
+	if has_decorators && p.at(T![export]) {
+		let m = p.start();
+		let start = p.cur_tok().range.start;
+		p.bump_any();
+		p.eat(T![default]);
+		let err = p
+			.err_builder(
+				"decorators before `export` can only be used in the top level of a module, move them after `export`",
+			)
+			.primary(start..p.cur_tok().range.start, "");
+
+		p.error(err);
+		m.complete(p, ERROR);
+	}
+
+	class_after_decorators(p, m, kind, has_decorators)
+}
+
+// test decorators_before_export
+// // DECORATORS
+// @sealed export class A {}
+// @sealed @log() export default class {}
+// export @dec class B {}
+
+// test_err decorators_before_and_after_export
+// // DECORATORS
+// @sealed export @log class A {}
+/// Parses a statement that starts with decorators, which is either a decorated class declaration or the
+/// export of a class with the decorators before `export`, e.g. `@sealed export class A {}`. The decorators
+/// before `export` are the first child of the export declaration, see [JsClassDeclaration::export_decorators].
+///
+/// [JsClassDeclaration::export_decorators]: crate::ast::JsClassDeclaration::export_decorators
+pub(super) fn decorated_class_declaration(p: &mut Parser) -> CompletedMarker {
+	let m = p.start();
+	decorators(p);
+
+	if !p.at(T![export]) {
+		return class_after_decorators(p, m, ClassKind::Declaration, true);
+	}
+
+	let mut guard = p.with_context(ParsingContext::ExportDeclaration);
+	let p = &mut *guard;
+	p.bump_any();
+	let default = p.eat(T![default]);
+
+	let class = p.start();
+	let start = p.cur_tok().range.start;
+	let has_decorators = p.at(T![@]);
+	decorators(p);
+	if has_decorators {
+		let err = p
+			.err_builder(
+				"decorators can be placed before or after `export`, but not in both places",
+			)
+			.primary(start..p.cur_tok().range.start, "");
+
+		p.error(err);
+	}
+
+	class_after_decorators(
+		&mut *p.with_state(ParserState {
+			in_default: default,
+			..p.state.clone()
+		}),
+		class,
+		ClassKind::Declaration,
+		true,
+	);
+
+	let kind = if default {
+		EXPORT_DEFAULT_DECL
+	} else {
+		EXPORT_DECL
+	};
+	m.complete(p, kind)
+}
+
+/// Parses the rest of a class after its decorators, `decorated` is `true` if the class has decorators,
+/// either its own or the ones before its `export`
+fn class_after_decorators(
+	p: &mut Parser,
+	m: Marker,
+	kind: ClassKind,
+	decorated: bool,
+) -> CompletedMarker {
+	if decorated && p.cur_src() == "abstract" && p.nth_at(1, T![class]) {
+		if p.typescript() {
+			p.bump_remap(T![abstract]);
+		} else {
+			let err = p
+				.err_builder("`abstract` modifiers can only be used in TypeScript files")
+				.primary(p.cur_tok().range, "");
+
+			p.error(err);
+			let m = p.start();
+			p.bump_any();
+			m.complete(p, ERROR);
+		}
+	}
+
 	p.expect_required(T![class]);
 
 	// class bodies are implicitly strict
@@ -77,7 +192,7 @@ fn class(p: &mut Parser, kind: ClassKind) -> CompletedMarker {
 		id.change_kind(&mut *guard, JS_IDENTIFIER_BINDING);
 
 		let text = guard.span_text(id.range(&*guard));
-		if guard.typescript() && DISALLOWED_TYPE_NAMES.contains(&text) {
+		if guard.typescript() && DISALLOWED_TYPE_NAMES.contains(&&*text) {
 			let err = guard
 				.err_builder(&format!(
 					"`{}` cannot be used as a class name because it is already reserved as a type",
@@ -96,7 +211,7 @@ fn class(p: &mut Parser, kind: ClassKind) -> CompletedMarker {
 	}
 
 	if guard.at(T![<]) {
-		if let Some(mut complete) = ts_type_params(&mut *guard) {
+		if let Some(mut complete) = ts_type_params(&mut *guard, true) {
 			complete.err_if_not_ts(
 				&mut *guard,
 				"classes can only have type parameters in TypeScript files",
@@ -114,6 +229,177 @@ fn class(p: &mut Parser, kind: ClassKind) -> CompletedMarker {
 	m.complete(&mut *guard, kind.into())
 }
 
+/// The private names declared by a class and the ones it references
+#[derive(Default)]
+struct PrivateNameScope {
+	/// The name, range, kind of member, and whether the member is static of each declaration
+	declarations: Vec<(String, TextRange, SyntaxKind, bool)>,
+	references: Vec<(String, TextRange)>,
+}
+
+// test_err private_name_errors
+// class A {
+//   #a;
+//   #a() {}
+//   get #b() {}
+//   set #b(v) {}
+//   get #b() {}
+//   #constructor() {}
+//   static get #f() {}
+//   set #f(v) {}
+//   m() {
+//     this.#a;
+//     this.#c;
+//     class B {
+//       #d;
+//       n() { this.#a; this.#b; this.#d; this.#e; }
+//     }
+//     this.#d;
+//   }
+// }
+// a.#g;
+/// Reports references to private names that aren't declared by any of the enclosing classes,
+/// duplicate private names, private members named `#constructor`, and getter and setter pairs
+/// of which only one is static.
+///
+/// Private names can be used before they're declared and nested classes can use the private names
+/// of their enclosing classes, which is why the check runs on the finished tree.
+pub(crate) fn check_private_names(root: &SyntaxNode, file_id: usize) -> Vec<ParserError> {
+	let mut scopes: Vec<PrivateNameScope> = vec![];
+	let mut unresolved = vec![];
+	let mut duplicates = vec![];
+	let mut mismatched_static = vec![];
+	let mut constructors = vec![];
+
+	for event in root.preorder() {
+		match event {
+			WalkEvent::Enter(node) => match node.kind() {
+				JS_CLASS_DECLARATION | JS_CLASS_EXPRESSION => {
+					scopes.push(PrivateNameScope::default());
+				}
+				JS_PRIVATE_CLASS_MEMBER_NAME => {
+					let name = match JsPrivateClassMemberName::cast(node.clone())
+						.and_then(|name| name.id_token().ok())
+					{
+						Some(name) => name.text_trimmed().to_string(),
+						None => continue,
+					};
+					let range = node.text_trimmed_range();
+					let member = match node.parent() {
+						Some(member) => member,
+						None => continue,
+					};
+					let member_kind = member.kind();
+					let is_static = member
+						.children_with_tokens()
+						.any(|child| child.kind() == T![static]);
+					let scope = match scopes.last_mut() {
+						Some(scope) => scope,
+						None => continue,
+					};
+
+					let mut is_duplicate = false;
+					for (declared, _, declared_kind, declared_static) in &scope.declarations {
+						if *declared != name {
+							continue;
+						}
+						let is_accessor_pair = matches!(
+							(*declared_kind, member_kind),
+							(JS_GETTER_CLASS_MEMBER, JS_SETTER_CLASS_MEMBER)
+								| (JS_SETTER_CLASS_MEMBER, JS_GETTER_CLASS_MEMBER)
+						);
+						if !is_accessor_pair {
+							is_duplicate = true;
+						} else if *declared_static != is_static {
+							mismatched_static.push((name.clone(), range));
+						}
+					}
+					if name == "constructor" {
+						constructors.push(range);
+					} else if is_duplicate {
+						duplicates.push((name.clone(), range));
+					}
+					scope
+						.declarations
+						.push((name, range, member_kind, is_static));
+				}
+				JS_REFERENCE_PRIVATE_MEMBER => {
+					let name = match JsReferencePrivateMember::cast(node.clone())
+						.and_then(|name| name.name_token().ok())
+					{
+						Some(name) => name.text_trimmed().to_string(),
+						None => continue,
+					};
+					let reference = (name, node.text_trimmed_range());
+					match scopes.last_mut() {
+						Some(scope) => scope.references.push(reference),
+						None => unresolved.push(reference),
+					}
+				}
+				_ => {}
+			},
+			WalkEvent::Leave(node) => {
+				if !matches!(node.kind(), JS_CLASS_DECLARATION | JS_CLASS_EXPRESSION) {
+					continue;
+				}
+				let scope = scopes.pop().unwrap();
+				let declarations = scope.declarations;
+				let references = scope.references.into_iter().filter(|(name, _)| {
+					!declarations
+						.iter()
+						.any(|(declared, _, _, _)| declared == name)
+				});
+
+				match scopes.last_mut() {
+					Some(parent) => parent.references.extend(references),
+					None => unresolved.extend(references),
+				}
+			}
+		}
+	}
+
+	let mut errors = vec![];
+	let err_builder = |message: &str| Diagnostic::error(file_id, "SyntaxError", message);
+
+	for range in constructors {
+		errors.push(
+			err_builder("classes can't have a private member named `#constructor`")
+				.primary(range, ""),
+		);
+	}
+
+	for (name, range) in duplicates {
+		errors.push(
+			err_builder(&format!("duplicate private name `#{}`", name)).primary(
+				range,
+				"a private name can only be declared once, except for a getter and a setter pair",
+			),
+		);
+	}
+
+	for (name, range) in mismatched_static {
+		errors.push(
+			err_builder(&format!(
+				"the getter and setter of `#{}` must either both be static or both be non-static",
+				name
+			))
+			.primary(range, ""),
+		);
+	}
+
+	for (name, range) in unresolved {
+		errors.push(
+			err_builder(&format!(
+				"private name `#{}` must be declared in an enclosing class",
+				name
+			))
+			.primary(range, ""),
+		);
+	}
+
+	errors
+}
+
 fn implements_clause(p: &mut Parser) {
 	if p.cur_src() != "implements" {
 		return;
@@ -205,15 +491,174 @@ fn class_members(p: &mut Parser) -> CompletedMarker {
 	members.complete(p, LIST)
 }
 
+// test_err static_initialization_block_class_member_err
+// class A {
+//   static {
+//     return;
+//   }
+//   static {
+//     break;
+//   }
+// }
+/// Parses a static initialization block like `static { this.a = 1; }`. The statements of the block
+/// run once when the class is evaluated, like the statements of a function without parameters.
+fn static_initialization_block_class_member(p: &mut Parser, m: Marker) -> CompletedMarker {
+	p.bump_remap(T![static]);
+	p.bump(T!['{']);
+
+	statements(
+		&mut *p.with_state(ParserState {
+			in_function: false,
+			in_generator: false,
+			in_async: false,
+			in_constructor: false,
+			break_allowed: false,
+			continue_allowed: false,
+			labels: HashMap::new(),
+			..p.state.clone()
+		}),
+		false,
+		true,
+		None,
+	);
+
+	p.expect_required(T!['}']);
+	m.complete(p, JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER)
+}
+
+/// Parses the decorators of a class, a class member, or a parameter into a list, which is empty
+/// if there are no decorators
+fn decorators(p: &mut Parser) -> CompletedMarker {
+	let list = p.start();
+
+	while p.at(T![@]) {
+		decorator(p);
+	}
+
+	list.complete(p, LIST)
+}
+
+// test decorators
+// // DECORATORS
+// @sealed @(decorators[0]) class A {
+//   @log m() {}
+//   @foo.bar() get a() { return 1; }
+//   @dec set a(v) {}
+//   @observable field = 1;
+// }
+// export @dec class B {}
+// export default @dec class {}
+// let c = @dec class {};
+
+// test_err decorators_unsupported
+// @sealed class A {
+//   @log m() {}
+// }
+// let b = @(decorators[0]) @foo.bar() class {};
+/// Parses a decorator, which is a member expression, optionally called, or a parenthesized expression,
+/// e.g. `@foo`, `@foo.bar<T>(baz)`, or `@(foo[0])`
+fn decorator(p: &mut Parser) -> CompletedMarker {
+	let m = p.start();
+	p.expect_required(T![@]);
+
+	if p.at(T!['(']) {
+		paren_expr(p);
+	} else if let Some(mut expr) = reference_identifier_expression(p) {
+		while p.at(T![.]) {
+			expr = static_member_expression(p, expr, T![.]);
+		}
+
+		if p.at(T![<]) && p.typescript() {
+			try_parse_ts(p, |p| {
+				let m = expr.precede(p);
+				ts_type_args(p)?;
+				if p.at(T!['(']) {
+					args(p);
+					Some(m.complete(p, CALL_EXPR))
+				} else {
+					None
+				}
+			});
+		} else if p.at(T!['(']) {
+			let m = expr.precede(p);
+			args(p);
+			m.complete(p, CALL_EXPR);
+		}
+	}
+
+	let mut decorator = m.complete(p, TS_DECORATOR);
+
+	if !p.syntax.decorators {
+		let err = p
+			.err_builder("decorators are unsupported")
+			.primary(decorator.range(p), "");
+
+		p.error(err);
+		decorator.change_kind(p, ERROR);
+	}
+
+	decorator
+}
+
+/// Parses a parameter with `parse_param`, wrapping it in a decorated parameter if it starts with decorators
+fn decorated_parameter(
+	p: &mut Parser,
+	parse_param: impl Fn(&mut Parser) -> Option<CompletedMarker>,
+) -> Option<CompletedMarker> {
+	if !p.at(T![@]) {
+		return parse_param(p);
+	}
+
+	let m = p.start();
+	decorators(p);
+	parse_param(p);
+	let mut parameter = m.complete(p, TS_DECORATED_PARAMETER);
+
+	if p.syntax.decorators && !p.syntax.parameter_decorators {
+		let err = p
+			.err_builder("parameter decorators are unsupported")
+			.primary(parameter.range(p), "");
+
+		p.error(err);
+		parameter.change_kind(p, ERROR);
+	}
+
+	Some(parameter)
+}
+
 fn class_member(p: &mut Parser) -> CompletedMarker {
+	let mut guard = p.with_context(ParsingContext::ClassMember);
+	let p = &mut *guard;
 	let mut member_marker = p.start();
 
+	// Decorators are only added to the members that can have them, `decorators` is used to turn
+	// them into an error for the other members
+	let mut decorators = if p.at(T![@]) {
+		Some(self::decorators(p))
+	} else {
+		None
+	};
+
 	// test class_empty_element
 	// class foo { ;;;;;;;;;; get foo() {};;;;}
 	if p.eat(T![;]) {
+		invalid_member_decorators(p, &mut decorators, "empty class members");
 		return member_marker.complete(p, JS_EMPTY_CLASS_MEMBER);
 	}
 
+	// test static_initialization_block_class_member
+	// class A {
+	//   static a;
+	//   static {
+	//     this.a = 1;
+	//   }
+	//   static {}
+	// }
+	if p.cur_src() == "static" && p.nth_at(1, T!['{']) {
+		invalid_member_decorators(p, &mut decorators, "static initialization blocks");
+		return static_initialization_block_class_member(p, member_marker);
+	}
+
 	// test static_method
 	// class foo {
 	//  static foo(bar) {}
@@ -221,10 +666,10 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 	//  static async foo() {}
 	//  static async *foo() {}
 	// }
-	let has_access_modifier = matches!(p.cur_src(), "public" | "private" | "protected");
+	let mut has_access_modifier = matches!(p.cur_src(), "public" | "private" | "protected");
 	let mut offset = if has_access_modifier { 1 } else { 0 };
 
-	let declare = p.nth_src(offset) == "declare";
+	let mut declare = p.nth_src(offset) == "declare" && !p.has_linebreak_before_n(offset + 1);
 	if declare {
 		offset += 1;
 	}
@@ -238,92 +683,139 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 		} else if is_property_class_member(p, offset) {
 			literal_member_name(p).ok().unwrap(); // bump declare as identifier
 			return property_class_member_body(p, member_marker);
-		} else {
-			let msg = if p.typescript() {
-				"a `declare` modifier cannot be applied to a class element"
-			} else {
-				"`declare` modifiers can only be used in TypeScript files"
-			};
-			let err = p.err_builder(msg).primary(p.cur_tok().range, "");
-
-			p.error(err);
-			let m = p.start();
-			p.bump_any();
-			m.complete(p, ERROR);
+		} else if !p.typescript() {
+			declare_modifier(p);
+			declare = false;
+			offset -= 1;
 		}
 	};
 
 	if has_access_modifier {
 		if is_method_class_member(p, offset) {
+			// `private declare() {}`, where `declare` is the name of the method
 			if declare {
-				let msg = if p.typescript() {
-					"a `declare` modifier cannot be applied to a class method"
-				} else {
-					"`declare` modifiers can only be used in TypeScript files"
-				};
-
-				let err = p.err_builder(msg).primary(p.cur_tok().range, "");
-
-				p.error(err);
-
-				let m = p.start();
-				p.bump_any();
-				m.complete(p, ERROR);
+				consume_modifiers(p, false, true, false, false);
 			}
 
 			return method_class_member(p, member_marker);
 		} else if is_property_class_member(p, offset) {
+			// `private declare: string`, where `declare` is the name of the property
 			if declare {
-				p.bump_remap(T![declare]);
+				consume_modifiers(p, false, true, false, false);
+				literal_member_name(p).ok().unwrap();
+			} else {
+				p.bump_any();
 			}
-			p.bump_any();
 
 			return property_class_member_body(p, member_marker);
 		}
 	}
 
-	let is_static = p.nth_src(offset) == "static";
+	let mut is_static = p.nth_src(offset) == "static";
 
 	// Let's assume static is an identifier and not the static keyword
 	if is_static {
 		offset += 1;
 
 		if is_method_class_member(p, offset) {
-			consume_modifiers(p, declare, has_access_modifier, is_static, true);
+			let declare_range = consume_modifiers(p, declare, has_access_modifier, is_static, true);
+			declare_method_error(p, declare_range);
 			return method_class_member_body(p, member_marker);
 		} else if is_property_class_member(p, offset) {
-			consume_modifiers(p, declare, has_access_modifier, is_static, true);
+			let declare_range = consume_modifiers(p, declare, has_access_modifier, is_static, true);
 
-			return if declare {
-				property_declaration_class_member_body(p, member_marker, JS_LITERAL_MEMBER_NAME)
-			} else {
-				property_class_member_body(p, member_marker)
+			return match declare_range {
+				Some(declare_range) => property_declaration_class_member_body(
+					p,
+					member_marker,
+					JS_LITERAL_MEMBER_NAME,
+					declare_range,
+				),
+				None => property_class_member_body(p, member_marker),
 			};
 		}
 
 		// Seems that static is a keyword since the parser wasn't able to parse a valid method or property named static
 	}
 
-	consume_modifiers(p, declare, has_access_modifier, is_static, false);
+	let mut declare_range = consume_modifiers(p, declare, has_access_modifier, is_static, false);
+
+	// TypeScript accepts the `declare`, accessibility and `static` modifiers in any order,
+	// e.g. `static declare a` or `declare private b`
+
+	// test ts_declare_members_and_ambient_declarations
+	// // TYPESCRIPT
+	// class A {
+	//   declare a: string;
+	//   static declare b: number;
+	//   declare private c?: string;
+	//   private declare: number;
+	//   declare() {}
+	// }
+	// declare class B {}
+	// declare abstract class C {}
+	// declare function d(): void;
+	// namespace E {
+	//   declare function f(): void;
+	//   export declare class G {}
+	// }
+
+	// test_err ts_declare_class_member_err
+	// // TYPESCRIPT
+	// class A { declare a = 1; }
+	// class B { declare get a() { return 1; } }
+	// abstract class C { declare abstract a: string; }
+	// class D { declare #a: string; }
+
+	// test_err declare_class_member_js
+	// class A {
+	//   declare a;
+	//   static declare b;
+	// }
+	while (declare_range.is_some() || p.cur_src() == "declare")
+		&& !p.has_linebreak_before_n(1)
+		&& !is_property_class_member(p, 1)
+		&& !is_method_class_member(p, 1)
+	{
+		match p.cur_src() {
+			"declare" if declare_range.is_none() => declare_range = Some(declare_modifier(p)),
+			"public" | "private" | "protected" if !has_access_modifier => {
+				has_access_modifier = true;
+				consume_modifiers(p, false, true, false, false);
+			}
+			"static" if !is_static => {
+				is_static = true;
+				p.bump_remap(STATIC_KW);
+			}
+			_ => break,
+		}
+	}
 
 	let maybe_err = p.start();
-	let (abstract_range, readonly_range) = abstract_readonly_modifiers(p);
+	let ClassMemberModifiers {
+		abstract_: abstract_range,
+		readonly: readonly_range,
+		override_: override_range,
+	} = abstract_readonly_override_modifiers(p);
 	let has_modifier = abstract_range
 		.clone()
 		.or_else(|| readonly_range.clone())
+		.or_else(|| override_range.clone())
 		.is_some();
 
 	if has_modifier {
 		let range = abstract_range
 			.clone()
 			.or_else(|| readonly_range.clone())
+			.or_else(|| override_range.clone())
 			.unwrap();
 		if !p.typescript() {
-			let err = p
-				.err_builder(
-					"`abstract` and `readonly` modifiers can only be used in TypeScript files",
-				)
-				.primary(range, "");
+			let msg = if abstract_range.is_none() && readonly_range.is_none() {
+				"`override` modifiers can only be used in TypeScript files"
+			} else {
+				"`abstract` and `readonly` modifiers can only be used in TypeScript files"
+			};
+			let err = p.err_builder(msg).primary(range, "");
 
 			p.error(err);
 			maybe_err.complete(p, ERROR);
@@ -334,6 +826,40 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 		maybe_err.abandon(p);
 	}
 
+	if let Some(override_range) = override_range.clone() {
+		misplaced_modifier_after_override(p, override_range);
+	}
+
+	if let Some(declare_range) = declare_range.clone() {
+		let conflicting = abstract_range
+			.clone()
+			.map(|range| ("abstract", range))
+			.or_else(|| override_range.clone().map(|range| ("override", range)));
+
+		if let Some((modifier, range)) = conflicting {
+			let err = p
+				.err_builder(&format!(
+					"the `declare` modifier cannot be used with the `{}` modifier",
+					modifier
+				))
+				.primary(range, "")
+				.secondary(declare_range, "");
+
+			p.error(err);
+		}
+	}
+
+	if is_accessor_modifier(p) {
+		if let Some(range) = declare_range {
+			let err = p
+				.err_builder("the `declare` modifier cannot be used on `accessor` properties")
+				.primary(range, "");
+
+			p.error(err);
+		}
+		return accessor_property_class_member(p, member_marker, readonly_range);
+	}
+
 	if !is_static && !has_access_modifier {
 		let check = p.checkpoint();
 		if let Some(range) = abstract_range.clone() {
@@ -345,6 +871,7 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 		}
 		member_marker = match try_parse_index_signature(p, member_marker) {
 			Ok(mut sig) => {
+				invalid_member_decorators(p, &mut decorators, "index signatures");
 				sig.err_if_not_ts(
 					p,
 					"class index signatures can only be used in TypeScript files",
@@ -384,6 +911,8 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 			guard.error(err);
 		}
 
+		declare_method_error(&mut guard, declare_range);
+
 		return method_class_member(&mut *guard, member_marker);
 	};
 
@@ -419,6 +948,8 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 			guard.error(err);
 		}
 
+		declare_method_error(&mut guard, declare_range);
+
 		return method_class_member(&mut *guard, member_marker);
 	}
 
@@ -439,7 +970,10 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 			p.error(err);
 		}
 
+		declare_method_error(p, declare_range);
+
 		return if is_constructor {
+			invalid_member_decorators(p, &mut decorators, "constructors");
 			let constructor = constructor_class_member_body(p, member_marker);
 
 			if is_static {
@@ -452,7 +986,10 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 
 			if has_modifier {
 				let err = p.err_builder("constructors cannot have modifiers").primary(
-					abstract_range.or_else(|| readonly_range.clone()).unwrap(),
+					abstract_range
+						.or_else(|| readonly_range.clone())
+						.or(override_range)
+						.unwrap(),
 					"",
 				);
 
@@ -467,10 +1004,14 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 
 	if let Some(member) = member {
 		if is_property_class_member(p, 0) {
-			let property = if declare {
-				property_declaration_class_member_body(p, member_marker, member.kind())
-			} else {
-				property_class_member_body(p, member_marker)
+			let property = match declare_range {
+				Some(declare_range) => property_declaration_class_member_body(
+					p,
+					member_marker,
+					member.kind(),
+					declare_range,
+				),
+				None => property_class_member_body(p, member_marker),
 			};
 
 			if is_constructor {
@@ -487,7 +1028,7 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 		if member.kind() == JS_LITERAL_MEMBER_NAME {
 			let is_at_line_break_or_generator = p.has_linebreak_before_n(0) && p.at(T![*]);
 			let member_name = member.text(p);
-			if matches!(member_name, "get" | "set") && !is_at_line_break_or_generator {
+			if matches!(&*member_name, "get" | "set") && !is_at_line_break_or_generator {
 				let is_getter = member_name == "get";
 
 				// test getter_class_member
@@ -542,6 +1083,8 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 					p.error(err);
 				}
 
+				declare_method_error(p, declare_range);
+
 				// So we've seen a get that now must be followed by a getter/setter name
 				class_member_name(p)
 					.or_missing_with_error(p, js_parse_error::expected_class_member_name);
@@ -568,6 +1111,7 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 		}
 	}
 
+	invalid_member_decorators(p, &mut decorators, "invalid class members");
 	let err = p
 		.err_builder("expected `;`, a property, or a method for a class body, but found none")
 		.primary(p.cur_tok().range, "");
@@ -582,6 +1126,185 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 	member_marker.complete(p, JS_UNKNOWN_MEMBER)
 }
 
+/// Eats the `declare` modifier of a class member, which is only valid in TypeScript files
+fn declare_modifier(p: &mut Parser) -> Range<usize> {
+	let range = p.cur_tok().range;
+
+	if p.typescript() {
+		p.bump_remap(T![declare]);
+	} else {
+		let err = p
+			.err_builder("`declare` modifiers can only be used in TypeScript files")
+			.primary(range.clone(), "");
+
+		p.error(err);
+		let m = p.start();
+		p.bump_any();
+		m.complete(p, ERROR);
+	}
+
+	range
+}
+
+/// Reports a `declare` modifier on a method, getter, setter or constructor
+fn declare_method_error(p: &mut Parser, declare_range: Option<Range<usize>>) {
+	if let Some(range) = declare_range {
+		let err = p
+			.err_builder("a `declare` modifier cannot be applied to a class method")
+			.primary(range, "");
+
+		p.error(err);
+	}
+}
+
+/// Reports an accessibility or `static` modifier that follows an `override` modifier, e.g. `override public a() {}`
+fn misplaced_modifier_after_override(p: &mut Parser, override_range: Range<usize>) {
+	let modifier = p.cur_src();
+	if !matches!(modifier, "public" | "private" | "protected" | "static")
+		|| p.has_linebreak_before_n(1)
+		|| is_property_class_member(p, 1)
+		|| is_method_class_member(p, 1)
+	{
+		return;
+	}
+
+	let err = p
+		.err_builder(&format!(
+			"`{}` modifier must precede `override` modifier",
+			modifier
+		))
+		.primary(p.cur_tok().range, "")
+		.secondary(override_range, "");
+
+	p.error(err);
+	let m = p.start();
+	p.bump_any();
+	m.complete(p, ERROR);
+}
+
+/// Whether the parser is at an `accessor` modifier rather than at a member named `accessor`
+fn is_accessor_modifier(p: &Parser) -> bool {
+	p.cur_src() == "accessor"
+		&& !p.has_linebreak_before_n(1)
+		&& !is_property_class_member(p, 1)
+		&& !is_method_class_member(p, 1)
+}
+
+// test ts_accessor_class_members
+// // TYPESCRIPT
+// class A {
+//   accessor a;
+//   accessor b: number = 1;
+//   static accessor c = 2;
+//   public abstract accessor #d;
+//   @dec accessor ["e"] = 3;
+//   accessor;
+//   accessor = 1;
+//   accessor() {}
+//   accessor
+//   f = 1;
+// }
+
+// test accessor_class_member_with_decorators
+// // DECORATORS
+// class A { accessor a }
+
+// test_err ts_accessor_class_member_err
+// // TYPESCRIPT
+// class A {
+//   readonly accessor a;
+//   accessor get b() {}
+//   accessor c() {}
+// }
+
+// test_err accessor_class_member_unsupported
+// class A {
+//   accessor a = 1;
+// }
+/// Parses an auto-accessor property like `accessor a = 1`, which defines a getter and setter
+/// pair backed by a private field
+fn accessor_property_class_member(
+	p: &mut Parser,
+	member_marker: Marker,
+	readonly_range: Option<Range<usize>>,
+) -> CompletedMarker {
+	let accessor_range = p.cur_tok().range;
+	p.bump_remap(T![accessor]);
+
+	if !p.typescript() && !p.syntax.decorators {
+		let err = p
+			.err_builder(
+				"`accessor` properties can only be used in TypeScript files or with decorators",
+			)
+			.primary(accessor_range.clone(), "");
+
+		p.error(err);
+	}
+
+	if let Some(range) = readonly_range {
+		let err = p
+			.err_builder("`accessor` properties cannot be readonly")
+			.primary(range, "")
+			.secondary(accessor_range.clone(), "");
+
+		p.error(err);
+	}
+
+	// `accessor get a() {}`
+	let is_getter_or_setter = matches!(p.cur_src(), "get" | "set")
+		&& !is_property_class_member(p, 1)
+		&& !is_method_class_member(p, 1);
+	if is_getter_or_setter {
+		let m = p.start();
+		let err = p
+			.err_builder("the `accessor` modifier cannot be used on getters and setters")
+			.primary(p.cur_tok().range, "")
+			.secondary(accessor_range.clone(), "");
+
+		p.error(err);
+		p.bump_any();
+		m.complete(p, ERROR);
+	}
+
+	class_member_name(p).or_missing_with_error(p, js_parse_error::expected_class_member_name);
+
+	if is_method_class_member(p, 0) {
+		let mut method = method_class_member_body(p, member_marker);
+		if !is_getter_or_setter {
+			let err = p
+				.err_builder("the `accessor` modifier can only be used on properties")
+				.primary(accessor_range, "");
+
+			p.error(err);
+		}
+		method.change_kind(p, JS_UNKNOWN_MEMBER);
+		return method;
+	}
+
+	property_member_body(p, member_marker, JS_ACCESSOR_PROPERTY_CLASS_MEMBER, None)
+}
+
+// test_err invalid_member_decorators
+// class A {
+//   @dec;
+//   @dec constructor() {}
+// }
+/// Reports the decorators of a member that can't be decorated and turns them into an error
+fn invalid_member_decorators(
+	p: &mut Parser,
+	decorators: &mut Option<CompletedMarker>,
+	members: &str,
+) {
+	if let Some(decorators) = decorators {
+		let err = p
+			.err_builder(&format!("decorators can't be used on {}", members))
+			.primary(decorators.range(p), "");
+
+		p.error(err);
+		decorators.change_kind(p, ERROR);
+	}
+}
+
 const PROPERTY_START_SET: TokenSet = token_set![T![!], T![:], T![=], T!['}']];
 
 /// Tests if the parser is currently (considering the offset) at the body of a property member.
@@ -598,8 +1321,14 @@ fn property_declaration_class_member_body(
 	p: &mut Parser,
 	member_marker: Marker,
 	member_name_kind: SyntaxKind,
+	declare_range: Range<usize>,
 ) -> CompletedMarker {
-	let property = property_class_member_body(p, member_marker);
+	let property = property_member_body(
+		p,
+		member_marker,
+		JS_PROPERTY_CLASS_MEMBER,
+		Some(declare_range),
+	);
 
 	if member_name_kind == JS_PRIVATE_CLASS_MEMBER_NAME {
 		let err = p
@@ -614,7 +1343,19 @@ fn property_declaration_class_member_body(
 
 /// Parses the body of a property class member (anything after the member name)
 fn property_class_member_body(p: &mut Parser, member_marker: Marker) -> CompletedMarker {
+	property_member_body(p, member_marker, JS_PROPERTY_CLASS_MEMBER, None)
+}
+
+/// Parses the body of a property or an accessor property and completes it with `kind`.
+/// `declare_range` is the range of the `declare` modifier of a `declare` property.
+fn property_member_body(
+	p: &mut Parser,
+	member_marker: Marker,
+	kind: SyntaxKind,
+	declare_range: Option<Range<usize>>,
+) -> CompletedMarker {
 	let optional_range = optional_member_token(p);
+	let mut definite_range = None;
 	if p.at(T![!]) {
 		let range = p.cur_tok().range;
 
@@ -623,7 +1364,7 @@ fn property_class_member_body(p: &mut Parser, member_marker: Marker) -> Complete
 				p.err_builder(
 					"definite assignment assertions can only be used in TypeScript files",
 				)
-				.primary(range, ""),
+				.primary(range.clone(), ""),
 			)
 		} else {
 			optional_range.map(|optional| {
@@ -637,12 +1378,26 @@ fn property_class_member_body(p: &mut Parser, member_marker: Marker) -> Complete
 			p.error(error);
 			p.bump_remap(ERROR);
 		} else {
+			definite_range = Some(range);
 			p.bump_any(); // Bump ! token
 		}
 	}
 
-	maybe_ts_type_annotation(p);
-	optional_equals_value_clause(p);
+	let type_range = maybe_ts_type_annotation(p);
+	let initializer = optional_equals_value_clause(p);
+
+	if let Some(definite_range) = definite_range {
+		definite_assignment_errors(p, definite_range, type_range, initializer);
+	}
+
+	if let (Some(declare_range), Some(initializer)) = (declare_range, initializer) {
+		let err = p
+			.err_builder("initializers are not allowed on `declare` class properties")
+			.primary(initializer.range(p), "")
+			.secondary(declare_range, "");
+
+		p.error(err);
+	}
 
 	if !optional_semi(p) {
 		// Gets the start of the member
@@ -658,7 +1413,7 @@ fn property_class_member_body(p: &mut Parser, member_marker: Marker) -> Complete
 		p.error(err);
 	}
 
-	let complete = member_marker.complete(p, JS_PROPERTY_CLASS_MEMBER);
+	let complete = member_marker.complete(p, kind);
 
 	if !p.syntax.class_fields {
 		let err = p
@@ -721,7 +1476,7 @@ fn method_class_member(p: &mut Parser, m: Marker) -> CompletedMarker {
 fn method_class_member_body(p: &mut Parser, m: Marker) -> CompletedMarker {
 	optional_member_token(p);
 	ts_parameter_types(p);
-	parameter_list(p);
+	method_parameter_list(p);
 	ts_return_type(p);
 	function_body(p).or_missing_with_error(p, js_parse_error::expected_function_body);
 
@@ -738,7 +1493,7 @@ fn constructor_class_member_body(p: &mut Parser, member_marker: Marker) -> Compl
 	}
 
 	if p.at(T![<]) {
-		if let Some(ref mut ty) = ts_type_params(p) {
+		if let Some(ref mut ty) = ts_type_params(p, true) {
 			ty.err_if_not_ts(p, "type parameters can only be used in TypeScript files");
 
 			let err = p
@@ -779,9 +1534,27 @@ fn constructor_class_member_body(p: &mut Parser, member_marker: Marker) -> Compl
 	member_marker.complete(p, JS_CONSTRUCTOR_CLASS_MEMBER)
 }
 
+// test ts_decorated_parameters
+// // TYPESCRIPT
+// class A {
+//   constructor(@inject(TOKEN) private a: A, @optional b) {}
+//   m(@required c) {}
+// }
+
+// test_err decorated_parameters
+// class A {
+//   constructor(@inject a) {}
+//   m(@required b) {}
+// }
+fn method_parameter_list(p: &mut Parser) -> CompletedMarker {
+	let m = p.start();
+	parameters_list(p, |p| decorated_parameter(p, formal_param_pat));
+	m.complete(p, JS_PARAMETER_LIST)
+}
+
 fn constructor_parameter_list(p: &mut Parser) -> CompletedMarker {
 	let m = p.start();
-	parameters_list(p, constructor_parameter);
+	parameters_list(p, |p| decorated_parameter(p, constructor_parameter));
 	m.complete(p, JS_CONSTRUCTOR_PARAMETER_LIST)
 }
 
@@ -859,13 +1632,15 @@ pub(crate) fn private_class_member_name(p: &mut Parser) -> CompletedMarker {
 	m.complete(p, JS_PRIVATE_CLASS_MEMBER_NAME)
 }
 
+/// Eats the accessibility, `declare` and `static` modifiers of a class member and returns the range
+/// of the `declare` modifier
 fn consume_modifiers(
 	p: &mut Parser,
 	declare: bool,
 	accessibility: bool,
 	static_: bool,
 	dont_remap_static: bool,
-) {
+) -> Option<Range<usize>> {
 	if accessibility {
 		let kind = match p.cur_src() {
 			"public" => PUBLIC_KW,
@@ -887,12 +1662,15 @@ fn consume_modifiers(
 			p.bump_remap(kind);
 		}
 	}
-	if declare {
-		p.bump_remap(T![declare]);
-	}
+	let declare_range = if declare {
+		Some(declare_modifier(p))
+	} else {
+		None
+	};
 	if static_ && !dont_remap_static {
 		p.bump_remap(STATIC_KW);
 	} else if static_ && dont_remap_static {
 		p.bump_any();
 	}
+	declare_range
 }
@@ -0,0 +1,241 @@
+//! Reporting of the syntax introduced by ECMAScript versions later than the configured one.
+//!
+//! The version is checked on the finished tree rather than on the tokens because the same token can be
+//! part of syntax from different versions, `...` is an ES2015 array spread in `[...a]` but an ES2018
+//! object spread in `{...a}`.
+
+use crate::{EcmaVersion, FileKind, ParserError, Syntax, SyntaxNode, SyntaxToken, TextRange, T};
+use rslint_errors::Diagnostic;
+use rslint_syntax::SyntaxKind::{self, *};
+
+/// Reports the syntax introduced by ECMAScript versions later than [`Syntax::ecma_version`].
+/// TypeScript files are compiled down to the configured version and aren't checked.
+pub(crate) fn check_ecma_version(
+	root: &SyntaxNode,
+	file_id: usize,
+	syntax: Syntax,
+) -> Vec<ParserError> {
+	let configured = syntax.ecma_version;
+	if configured == EcmaVersion::LATEST || syntax.file_kind == FileKind::TypeScript {
+		return vec![];
+	}
+
+	let mut errors = vec![];
+	for node in root.descendants() {
+		for (required, feature, range) in features(&node) {
+			if required > configured {
+				let err = Diagnostic::error(
+					file_id,
+					"SyntaxError",
+					&format!(
+						"{} requires {} or later, but the configured version is {}",
+						feature,
+						required.name(),
+						configured.name()
+					),
+				)
+				.primary(range, "");
+				errors.push(err);
+			}
+		}
+	}
+	errors
+}
+
+/// The syntax newer than ES5 that `node` introduces, with the version that introduced it, a description
+/// of the syntax, and the range to report. The syntax of the children of `node` isn't included.
+fn features(node: &SyntaxNode) -> Vec<(EcmaVersion, &'static str, TextRange)> {
+	let mut features = vec![];
+	let mut add = |version, feature, token: Option<SyntaxToken>| {
+		let range = token.map_or_else(
+			|| node.text_trimmed_range(),
+			|token| token.text_trimmed_range(),
+		);
+		features.push((version, feature, range));
+	};
+
+	match node.kind() {
+		JS_CLASS_DECLARATION | JS_CLASS_EXPRESSION => {
+			add(EcmaVersion::Es2015, "a class", token(node, T![class]));
+		}
+		JS_PROPERTY_CLASS_MEMBER => add(EcmaVersion::Es2022, "a class field", None),
+		JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER => add(
+			EcmaVersion::Es2022,
+			"a static initialization block",
+			token(node, T![static]),
+		),
+		JS_PRIVATE_CLASS_MEMBER_NAME | JS_REFERENCE_PRIVATE_MEMBER => {
+			add(EcmaVersion::Es2022, "a private class member", None);
+		}
+		JS_ARROW_FUNCTION_EXPRESSION => {
+			add(
+				EcmaVersion::Es2015,
+				"an arrow function",
+				token(node, T![=>]),
+			);
+			if let Some(async_token) = token(node, T![async]) {
+				add(EcmaVersion::Es2017, "an async function", Some(async_token));
+			}
+		}
+		JS_FUNCTION_DECLARATION
+		| JS_FUNCTION_EXPRESSION
+		| JS_METHOD_CLASS_MEMBER
+		| JS_METHOD_OBJECT_MEMBER => match (token(node, T![async]), token(node, T![*])) {
+			(Some(async_token), Some(_)) => {
+				add(EcmaVersion::Es2018, "an async generator", Some(async_token));
+			}
+			(Some(async_token), None) => {
+				add(EcmaVersion::Es2017, "an async function", Some(async_token));
+			}
+			(None, Some(star)) => add(EcmaVersion::Es2015, "a generator", Some(star)),
+			(None, None) => {}
+		},
+		TEMPLATE => add(
+			EcmaVersion::Es2015,
+			"a template literal",
+			token(node, T!['`']),
+		),
+		SPREAD_ELEMENT => add(
+			EcmaVersion::Es2015,
+			"a spread element",
+			token(node, T![...]),
+		),
+		JS_REST_PARAMETER => add(
+			EcmaVersion::Es2015,
+			"a rest parameter",
+			token(node, T![...]),
+		),
+		REST_PATTERN => {
+			let pattern = node.parent().and_then(|list| list.parent());
+			if pattern.map(|pattern| pattern.kind()) == Some(OBJECT_PATTERN) {
+				add(
+					EcmaVersion::Es2018,
+					"an object rest pattern",
+					token(node, T![...]),
+				);
+			} else {
+				add(EcmaVersion::Es2015, "a rest pattern", token(node, T![...]));
+			}
+		}
+		JS_SPREAD => add(
+			EcmaVersion::Es2018,
+			"an object spread",
+			token(node, T![...]),
+		),
+		JS_VARIABLE_DECLARATION => {
+			if let Some(keyword) = token(node, T![let]).or_else(|| token(node, T![const])) {
+				add(EcmaVersion::Es2015, "a lexical declaration", Some(keyword));
+			}
+		}
+		FOR_OF_STMT => {
+			let of = node
+				.children_with_tokens()
+				.filter_map(|element| element.into_token())
+				.find(|token| token.kind() == T![ident] && token.text_trimmed() == "of");
+			add(EcmaVersion::Es2015, "a for...of statement", of);
+			if let Some(await_token) = token(node, T![await]) {
+				add(
+					EcmaVersion::Es2018,
+					"a for await...of statement",
+					Some(await_token),
+				);
+			}
+		}
+		JS_CATCH_CLAUSE => {
+			if !node
+				.children()
+				.any(|child| child.kind() == JS_CATCH_DECLARATION)
+			{
+				add(
+					EcmaVersion::Es2019,
+					"an optional catch binding",
+					token(node, T![catch]),
+				);
+			}
+		}
+		IMPORT_DECL => add(
+			EcmaVersion::Es2015,
+			"an import declaration",
+			token(node, T![import]),
+		),
+		EXPORT_DECL | EXPORT_DEFAULT_DECL | EXPORT_DEFAULT_EXPR | EXPORT_NAMED
+		| EXPORT_WILDCARD => {
+			// `export {}` is an `EXPORT_NAMED` inside of an `EXPORT_DECL`, only the node owning the keyword
+			// is reported
+			if let Some(keyword) = token(node, T![export]) {
+				add(EcmaVersion::Es2015, "an export declaration", Some(keyword));
+			}
+		}
+		JS_IMPORT_CALL_EXPRESSION => add(EcmaVersion::Es2020, "a dynamic import", None),
+		IMPORT_META => add(EcmaVersion::Es2020, "`import.meta`", None),
+		JS_BINARY_EXPRESSION => {
+			if let Some(operator) = token(node, T![**]) {
+				add(
+					EcmaVersion::Es2016,
+					"the exponentiation operator",
+					Some(operator),
+				);
+			}
+		}
+		ASSIGN_EXPR => {
+			if let Some(operator) = token(node, T![**=]) {
+				add(
+					EcmaVersion::Es2016,
+					"the exponentiation operator",
+					Some(operator),
+				);
+			}
+			let logical = token(node, T![&&=])
+				.or_else(|| token(node, T![||=]))
+				.or_else(|| token(node, T![??=]));
+			if let Some(operator) = logical {
+				add(
+					EcmaVersion::Es2021,
+					"a logical assignment operator",
+					Some(operator),
+				);
+			}
+		}
+		JS_LOGICAL_EXPRESSION => {
+			if let Some(operator) = token(node, T![??]) {
+				add(
+					EcmaVersion::Es2020,
+					"the nullish coalescing operator",
+					Some(operator),
+				);
+			}
+		}
+		JS_STATIC_MEMBER_EXPRESSION | JS_COMPUTED_MEMBER_EXPRESSION | CALL_EXPR => {
+			if let Some(operator) = token(node, T![?.]) {
+				add(EcmaVersion::Es2020, "optional chaining", Some(operator));
+			}
+		}
+		JS_BIG_INT_LITERAL_EXPRESSION => {
+			let literal = token(node, T![js_big_int_literal]);
+			if literal.as_ref().map_or(false, has_numeric_separator) {
+				add(EcmaVersion::Es2021, "a numeric separator", literal.clone());
+			}
+			add(EcmaVersion::Es2020, "a BigInt literal", literal);
+		}
+		JS_NUMBER_LITERAL_EXPRESSION => {
+			let literal = token(node, T![js_number_literal]);
+			if literal.as_ref().map_or(false, has_numeric_separator) {
+				add(EcmaVersion::Es2021, "a numeric separator", literal);
+			}
+		}
+		_ => {}
+	}
+
+	features
+}
+
+/// The token of `kind` that is a direct child of `node`
+fn token(node: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(|element| element.into_token())
+		.find(|token| token.kind() == kind)
+}
+
+fn has_numeric_separator(literal: &SyntaxToken) -> bool {
+	literal.text_trimmed().contains('_')
+}
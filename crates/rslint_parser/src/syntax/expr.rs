@@ -15,6 +15,7 @@ use crate::syntax::js_parse_error;
 use crate::syntax::object::object_expr;
 use crate::syntax::stmt::is_semi;
 use crate::{SyntaxKind::*, *};
+use rslint_regex::EcmaVersion;
 
 pub const EXPR_RECOVERY_SET: TokenSet = token_set![VAR_KW, R_PAREN, L_PAREN, L_BRACK, R_BRACK];
 
@@ -59,6 +60,7 @@ pub const STARTS_EXPR: TokenSet = token_set![
 	T![await],
 	T![function],
 	T![class],
+	T![@],
 	T![import],
 	T![super],
 	BACKTICK,
@@ -86,16 +88,33 @@ pub fn literal_expression(p: &mut Parser) -> Option<CompletedMarker> {
 		SyntaxKind::JS_NUMBER_LITERAL => {
 			if p.cur_src().ends_with('n') {
 				let m = p.start();
+				check_big_int_literal(p);
 				p.bump_remap(SyntaxKind::JS_BIG_INT_LITERAL);
 				return Some(m.complete(p, JS_BIG_INT_LITERAL_EXPRESSION));
 			};
 
+			if p.state.strict.is_some() {
+				check_strict_mode_number(p);
+			}
+
 			SyntaxKind::JS_NUMBER_LITERAL_EXPRESSION
 		}
-		SyntaxKind::JS_STRING_LITERAL => SyntaxKind::JS_STRING_LITERAL_EXPRESSION,
+		SyntaxKind::JS_STRING_LITERAL => {
+			if p.state.strict.is_some() {
+				check_strict_mode_string(p, &p.cur_tok());
+			}
+
+			SyntaxKind::JS_STRING_LITERAL_EXPRESSION
+		}
 		SyntaxKind::NULL_KW => SyntaxKind::JS_NULL_LITERAL_EXPRESSION,
 		SyntaxKind::TRUE_KW | SyntaxKind::FALSE_KW => SyntaxKind::JS_BOOLEAN_LITERAL_EXPRESSION,
-		SyntaxKind::JS_REGEX_LITERAL => SyntaxKind::JS_REGEX_LITERAL_EXPRESSION,
+		SyntaxKind::JS_REGEX_LITERAL => {
+			if p.syntax.validate_regex {
+				validate_regex_literal(p);
+			}
+
+			SyntaxKind::JS_REGEX_LITERAL_EXPRESSION
+		}
 		_ => return None,
 	};
 
@@ -104,14 +123,198 @@ pub fn literal_expression(p: &mut Parser) -> Option<CompletedMarker> {
 	Some(m.complete(p, literal_kind))
 }
 
+/// Reports number literals with an `n` suffix that aren't valid BigInts, such as `1.5n`.
+// test_err big_int_literal_err
+// 1.5n;
+// .5n;
+// 1e10n;
+// 017n;
+// 08n;
+fn check_big_int_literal(p: &mut Parser) {
+	let text = p.cur_src();
+	let digits = &text[..text.len() - 1];
+
+	let message = if matches!(
+		digits.get(..2),
+		Some("0x" | "0X" | "0b" | "0B" | "0o" | "0O")
+	) {
+		return;
+	} else if digits.contains('.') {
+		"BigInt literals cannot have a decimal point"
+	} else if digits.contains(&['e', 'E'][..]) {
+		"BigInt literals cannot have an exponent"
+	} else if digits.len() > 1 && digits.starts_with('0') {
+		if digits.bytes().all(|b| matches!(b, b'0'..=b'7' | b'_')) {
+			"legacy octal literals cannot be BigInts"
+		} else {
+			"BigInt literals cannot have leading zeros"
+		}
+	} else {
+		return;
+	};
+
+	let err = p.err_builder(message).primary(p.cur_tok().range, "");
+	p.error(err);
+}
+
+/// Reports legacy octal literals such as `010` and decimals with a leading zero such as `08`,
+/// both of which are early errors in strict mode code.
+// test legacy_octal_sloppy_mode
+// // SCRIPT
+// 010;
+// 08;
+// '\07';
+// '\8';
+
+// test_err strict_mode_legacy_octal
+// "use strict";
+// 010;
+// 08;
+// let a = { 07: 1 };
+// class A { b = 00 }
+fn check_strict_mode_number(p: &mut Parser) {
+	let text = p.cur_src().as_bytes();
+
+	if text.len() < 2 || text[0] != b'0' || !text[1].is_ascii_digit() {
+		return;
+	}
+
+	let message = if text.iter().all(|b| matches!(b, b'0'..=b'7')) {
+		"legacy octal literals are not allowed in strict mode"
+	} else {
+		"decimals with leading zeros are not allowed in strict mode"
+	};
+
+	let err = p.err_builder(message).primary(p.cur_tok().range, "");
+	p.error(err);
+}
+
+/// Reports octal escapes such as `"\07"` and the `"\8"` and `"\9"` escapes in the string literal `token`,
+/// which are early errors in strict mode code.
+// test_err strict_mode_octal_escape
+// "use strict";
+// "\07";
+// "a\1b\377\477";
+// "\8\9";
+// "\0 \\07";
+pub(crate) fn check_strict_mode_string(p: &mut Parser, token: &Token) {
+	let start = token.range.start;
+	let text = p.token_src(token).as_bytes();
+	let mut escapes = Vec::new();
+	let mut idx = 0;
+
+	while idx < text.len() {
+		if text[idx] != b'\\' {
+			idx += 1;
+			continue;
+		}
+
+		let escape_start = idx;
+		idx += 1;
+
+		let escape_end = match text.get(idx) {
+			// `\0` is the null character, unless it's followed by another digit
+			Some(b'0') if !matches!(text.get(idx + 1), Some(b'0'..=b'9')) => None,
+			Some(digit @ b'0'..=b'7') => {
+				// `\0` to `\377`, but `\477` is `\47` followed by `7`
+				let max_len = if *digit <= b'3' { 3 } else { 2 };
+				let mut end = idx + 1;
+				while end < idx + max_len && matches!(text.get(end), Some(b'0'..=b'7')) {
+					end += 1;
+				}
+				Some(end)
+			}
+			Some(b'8' | b'9') => Some(idx + 1),
+			_ => None,
+		};
+
+		match escape_end {
+			Some(end) => {
+				escapes.push(start + escape_start..start + end);
+				idx = end;
+			}
+			// Skip over the escaped character, e.g. the second `\` in `\\`
+			None => idx += 1,
+		}
+	}
+
+	for range in escapes {
+		let err = p
+			.err_builder("octal escape sequences are not allowed in strict mode")
+			.primary(range, "");
+		p.error(err);
+	}
+}
+
+/// Validates the pattern of a regex literal, invalid or duplicate flags are already reported by the lexer.
+// test regex_literal_not_validated
+// let a = /(ab/;
+// let b = /\p{Foo}/u;
+// let c = /a{2,1}/;
+
+// test_err regex_literal_validated
+// // VALIDATE_REGEX
+// let a = /(ab/;
+// let b = /\p{Foo}/u;
+// let c = /a{2,1}/;
+// let d = /(a)[b]\1/gu;
+fn validate_regex_literal(p: &mut Parser) {
+	let range = p.cur_tok().range;
+	let text = p.cur_src();
+
+	let flags_start = match text.rfind('/') {
+		// unterminated regex literal
+		Some(0) | None => return,
+		Some(end) => end + 1,
+	};
+
+	let flags = match rslint_regex::validate_flags(&text[flags_start..], EcmaVersion::ES2021) {
+		Ok(flags) => flags,
+		Err(_) => return,
+	};
+
+	let result = rslint_regex::Parser::new_from_pattern_and_flags(
+		&text[1..flags_start - 1],
+		p.file_id,
+		range.start + 1,
+		EcmaVersion::ES2021,
+		false,
+		flags,
+	)
+	.parse();
+
+	if let Err(error) = result {
+		let err = p
+			.err_builder(&error.message)
+			.primary(error.span.as_range(), "");
+
+		p.error(err);
+	}
+}
+
 /// An assignment expression such as `foo += bar` or `foo = 5`.
 pub fn assign_expr(p: &mut Parser) -> Option<CompletedMarker> {
+	// test tsx_arrow_type_params
+	// // TSX
+	// let a = <T,>(x: T) => x;
+	// let b = <T extends U>(x: T) => x;
+	// let c = <T = U>(x: T) => x;
+
+	// test ts_arrow_type_params
+	// // TYPESCRIPT
+	// let a = <T>(x: T) => x;
+
+	// test_err tsx_arrow_type_params
+	// // TSX
+	// let a = <T>(x: T) => x;
 	if p.at(T![<])
 		&& (token_set![T![ident], T![await], T![yield]].contains(p.nth(1)) || p.nth(1).is_keyword())
 	{
+		let ambiguous_with_jsx = p.typescript() && p.jsx() && !is_tsx_arrow_type_params(p);
+		let mut type_params_range = None;
 		let res = try_parse_ts(p, |p| {
 			let m = p.start();
-			ts_type_params(p)?;
+			type_params_range = Some(ts_type_params(p, true)?.range(p));
 			let res = assign_expr_base(p);
 			if res.map(|x| x.kind()) != Some(JS_ARROW_FUNCTION_EXPRESSION) {
 				m.abandon(p);
@@ -123,12 +326,34 @@ pub fn assign_expr(p: &mut Parser) -> Option<CompletedMarker> {
 		});
 		if let Some(mut res) = res {
 			res.err_if_not_ts(p, "type parameters can only be used in TypeScript files");
+			if let (true, Some(range)) = (ambiguous_with_jsx, type_params_range) {
+				let err = p
+					.err_builder("type parameters of arrow functions in TSX files are ambiguous with JSX elements")
+					.primary(range, "")
+					.footer_help("add a trailing comma or a constraint: `<T,>` or `<T extends unknown>`");
+				p.error(err);
+			}
 			return Some(res);
 		}
 	}
 	assign_expr_base(p)
 }
 
+/// Returns `true` if the type parameters at the current `<` can't start a JSX element, which
+/// TypeScript requires for generic arrow functions in TSX files: `<T,>`, `<T = U>` or `<T extends U>`
+fn is_tsx_arrow_type_params(p: &Parser) -> bool {
+	// `<const T>` can't be a jsx element
+	if p.nth_at(1, T![const]) {
+		return true;
+	}
+
+	match p.nth(2) {
+		T![,] | T![=] => true,
+		_ if p.nth_src(2) == "extends" => !matches!(p.nth(3), T![=] | T![>] | T![/]),
+		_ => false,
+	}
+}
+
 fn assign_expr_base(p: &mut Parser) -> Option<CompletedMarker> {
 	if p.state.in_generator && p.at(T![yield]) {
 		return Some(yield_expr(p));
@@ -548,19 +773,32 @@ pub fn subscripts(p: &mut Parser, mut lhs: CompletedMarker, no_call: bool) -> Co
 				let res = try_parse_ts(p, |p| {
 					let m = lhs.precede(p);
 					// TODO: handle generic async arrow function expressions
-					ts_type_args(p)?;
+					if ts_type_args(p).is_none() {
+						m.abandon(p);
+						return None;
+					}
+
 					if !no_call && p.at(T!['(']) {
 						args(p);
 						Some(m.complete(p, CALL_EXPR))
 					} else if p.at(BACKTICK) {
 						m.abandon(p);
 						Some(template(p, Some(lhs)))
+					} else if !p.at(T!['(']) && can_follow_type_arguments_in_expression(p) {
+						Some(m.complete(p, TS_INSTANTIATION_EXPR))
 					} else {
+						m.abandon(p);
 						None
 					}
 				});
-				if res.is_none() {
-					should_try_parsing_ts = false;
+				match res {
+					Some(res) => {
+						lhs = res;
+						if res.kind() == TS_INSTANTIATION_EXPR {
+							instantiation_member_access_error(p, res);
+						}
+					}
+					None => should_try_parsing_ts = false,
 				}
 			}
 			BACKTICK => lhs = template(p, Some(lhs)),
@@ -570,6 +808,46 @@ pub fn subscripts(p: &mut Parser, mut lhs: CompletedMarker, no_call: bool) -> Co
 	lhs
 }
 
+// test ts_instantiation_expressions
+// // TYPESCRIPT
+// const a = f<number>;
+// const b = f<number>
+// g<string>(b);
+// const c = f<string> as any;
+// const d = f<string> == g;
+// const e = h<string>(1).i;
+// a<b>c;
+// a < b > -c;
+
+// test_err ts_instantiation_expression_member_access
+// // TYPESCRIPT
+// f<string>.a;
+/// Whether the token after the type arguments of `f<T>` makes it an instantiation expression rather than
+/// a comparison like `a < b > c`. Mirrors the rules of tsc: a line break, a binary operator or a token that
+/// can't start an expression ends the instantiation expression, `<`, `>`, `+` and `-` never do.
+fn can_follow_type_arguments_in_expression(p: &Parser) -> bool {
+	if p.at_ts(token_set![T![<], T![>], T![+], T![-]]) {
+		return false;
+	}
+
+	p.has_linebreak_before_n(0)
+		|| get_precedence(p.cur()).is_some()
+		|| p.cur_src() == "as"
+		|| !p.at_ts(STARTS_EXPR)
+}
+
+/// Reports a property access on an instantiation expression such as `f<T>.a`
+fn instantiation_member_access_error(p: &mut Parser, instantiation: CompletedMarker) {
+	if p.at(T![.]) || (p.at(T![?.]) && !p.nth_at(1, T!['(']) && !p.nth_at(1, T!['['])) {
+		let err = p
+			.err_builder("an instantiation expression cannot be followed by a property access")
+			.primary(p.cur_tok().range, "")
+			.secondary(instantiation.range(p), "");
+
+		p.error(err);
+	}
+}
+
 /// A static member expression for accessing a property
 // test dot_expr
 // foo.bar
@@ -917,6 +1195,8 @@ pub fn expr_or_spread(p: &mut Parser) -> Option<CompletedMarker> {
 // test sequence_expr
 // 1, 2, 3, 4, 5
 pub fn expr(p: &mut Parser) -> Option<CompletedMarker> {
+	let mut guard = p.with_context(ParsingContext::Expression);
+	let p = &mut *guard;
 	let first = assign_expr(p)?;
 
 	if p.at(T![,]) {
@@ -955,6 +1235,7 @@ pub fn primary_expr(p: &mut Parser) -> Option<CompletedMarker> {
 			// foo[class {}]
 			class_expression(p)
 		}
+		T![@] => class_expression(p),
 		// test async_ident
 		// let a = async;
 		T![ident] if p.cur_src() == "async" => {
@@ -1084,8 +1365,14 @@ pub fn primary_expr(p: &mut Parser) -> Option<CompletedMarker> {
 
 				// test import_call
 				// import("foo")
+				// import("foo", { with: { type: "json" } })
+				// import("foo", { assert: { type: "json" } },)
 				p.expect_required(T!['(']);
 				assign_expr(p);
+				if p.eat(T![,]) && !p.at(T![')']) {
+					assign_expr(p);
+					p.eat(T![,]);
+				}
 				p.expect_required(T![')']);
 				m.complete(p, JS_IMPORT_CALL_EXPRESSION)
 			}
@@ -1149,13 +1436,19 @@ pub fn reference_identifier_expression(p: &mut Parser) -> Option<CompletedMarker
 // let a = `${foo}`;
 // let a = `foo`;
 pub fn template(p: &mut Parser, tag: Option<CompletedMarker>) -> CompletedMarker {
+	let tagged = tag.is_some();
 	let m = tag.map(|m| m.precede(p)).unwrap_or_else(|| p.start());
 	p.expect_required(BACKTICK);
 	let elements_list = p.start();
 
 	while !p.at(EOF) && !p.at(BACKTICK) {
 		match p.cur() {
-            TEMPLATE_CHUNK => p.bump_any(),
+            TEMPLATE_CHUNK => {
+                if !tagged {
+                    check_template_chunk_escapes(p);
+                }
+                p.bump_any()
+            },
             DOLLARCURLY => {
                 let e = p.start();
                 p.bump_any();
@@ -1177,6 +1470,30 @@ pub fn template(p: &mut Parser, tag: Option<CompletedMarker>) -> CompletedMarker
 	m.complete(p, TEMPLATE)
 }
 
+/// Reports the malformed escapes of the current template chunk. Tagged templates allow them,
+/// their cooked value is `undefined` instead.
+pub(crate) fn check_template_chunk_escapes(p: &mut Parser) {
+	// test_err template_literal_invalid_escape
+	// `\x4`;
+	// `${a}\u{110000}`;
+	// `\07 \8`;
+
+	// test tagged_template_invalid_escape
+	// tag`\x4 \u{110000} \07`;
+	// tag`${a}\unicode`;
+	let range = p.cur_tok().range;
+	let offset = TextSize::from(range.start as u32);
+
+	if let Err(errors) = crate::strings::unescape(p.cur_src(), offset, true) {
+		for error in errors {
+			let err = p
+				.err_builder(&error.kind.to_string())
+				.primary(error.range, "");
+			p.error(err);
+		}
+	}
+}
+
 /// An array literal such as `[foo, bar, ...baz]`.
 // test array_expr
 // [foo, bar];
@@ -1321,6 +1638,15 @@ pub fn unary_expr(p: &mut Parser) -> Option<CompletedMarker> {
 		return Some(m.complete(p, JS_AWAIT_EXPRESSION));
 	}
 
+	// test ts_type_assertion
+	// // TYPESCRIPT
+	// let a = <T>b;
+	// let c = <const>[1];
+
+	// test_err tsx_type_assertion
+	// // TSX
+	// let a = <T>b;
+	// let c = <const>[1];
 	if p.at(T![<]) {
 		let m = p.start();
 		p.bump_any();
@@ -1329,6 +1655,10 @@ pub fn unary_expr(p: &mut Parser) -> Option<CompletedMarker> {
 			unary_expr(p);
 			let mut res = m.complete(p, TS_CONST_ASSERTION);
 			res.err_if_not_ts(p, "const assertions can only be used in TypeScript files");
+			res.err_if_tsx(
+				p,
+				"`<const>` assertions can't be used in TSX files, use `as const` instead",
+			);
 			return Some(res);
 		} else {
 			ts_type(p);
@@ -1336,6 +1666,10 @@ pub fn unary_expr(p: &mut Parser) -> Option<CompletedMarker> {
 			unary_expr(p);
 			let mut res = m.complete(p, TS_ASSERTION);
 			res.err_if_not_ts(p, "type assertions can only be used in TypeScript files");
+			res.err_if_tsx(
+				p,
+				"`<T>` type assertions can't be used in TSX files, use `as T` instead",
+			);
 			return Some(res);
 		}
 	}
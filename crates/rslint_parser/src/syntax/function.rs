@@ -55,7 +55,13 @@ pub(super) fn function_expression(p: &mut Parser) -> CompletedMarker {
 fn function(p: &mut Parser, kind: SyntaxKind) -> ConditionalParsedSyntax {
 	let m = p.start();
 
-	let mut uses_ts_syntax = kind == JS_FUNCTION_DECLARATION && p.eat(T![declare]);
+	let mut uses_ts_syntax = kind == JS_FUNCTION_DECLARATION
+		&& p.cur_src() == "declare"
+		&& p.nth_at(1, T![function])
+		&& {
+			p.bump_remap(T![declare]);
+			true
+		};
 
 	let in_async = p.at(T![ident]) && p.cur_src() == "async";
 	if in_async {
@@ -170,7 +176,7 @@ pub(super) fn function_body_or_declaration(p: &mut Parser) {
 
 fn parse_ts_parameter_types(p: &mut Parser) -> ParsedSyntax {
 	if p.at(T![<]) {
-		Present(ts_type_params(p).unwrap())
+		Present(ts_type_params(p, true).unwrap())
 	} else {
 		Absent
 	}
@@ -178,7 +184,7 @@ fn parse_ts_parameter_types(p: &mut Parser) -> ParsedSyntax {
 
 pub(crate) fn ts_parameter_types(p: &mut Parser) {
 	if p.at(T![<]) {
-		if let Some(ref mut ty) = ts_type_params(p) {
+		if let Some(ref mut ty) = ts_type_params(p, true) {
 			ty.err_if_not_ts(p, "type parameters can only be used in TypeScript files");
 		}
 	}
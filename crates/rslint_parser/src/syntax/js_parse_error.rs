@@ -61,3 +61,7 @@ pub(crate) fn expected_block_statement(p: &Parser, range: Range<usize>) -> Diagn
 pub(crate) fn expected_catch_clause(p: &Parser, range: Range<usize>) -> Diagnostic {
 	expected_node("catch clause", range).to_diagnostic(p)
 }
+
+pub(crate) fn expected_import_assertion_entry(p: &Parser, range: Range<usize>) -> Diagnostic {
+	expected_node("import assertion entry", range).to_diagnostic(p)
+}
@@ -1,7 +1,7 @@
 #[allow(deprecated)]
 use crate::parser::single_token_parse_recovery::SingleTokenParseRecovery;
-use crate::parser::ParsedSyntax;
 use crate::parser::ParsedSyntax::{Absent, Present};
+use crate::parser::{ParsedSyntax, ParsingContext};
 use crate::syntax::decl::{formal_param_pat, parameter_list};
 use crate::syntax::expr::{assign_expr, expr, identifier_name, literal_expression};
 use crate::syntax::function::{function_body, ts_parameter_types, ts_return_type};
@@ -40,6 +40,8 @@ pub(super) fn object_expr(p: &mut Parser) -> CompletedMarker {
 			}
 		}
 
+		let mut guard = p.with_context(ParsingContext::ObjectMember);
+		let p = &mut *guard;
 		let recovered_member = object_member(p).or_recover(
 			p,
 			ParseRecovery::new(JS_UNKNOWN_MEMBER, token_set![T![,], T!['}'], T![;], T![:]])
@@ -58,6 +60,26 @@ pub(super) fn object_expr(p: &mut Parser) -> CompletedMarker {
 	m.complete(p, JS_OBJECT_EXPRESSION)
 }
 
+/// Reports an `override` modifier on an object member and parses the member
+fn override_object_member(p: &mut Parser) -> ParsedSyntax {
+	let m = p.start();
+	let err = p
+		.err_builder("`override` modifiers can only be used on class members")
+		.primary(p.cur_tok().range, "");
+
+	p.error(err);
+	p.bump_remap(ERROR);
+
+	match object_member(p) {
+		Present(member) => {
+			let kind = member.kind();
+			member.undo_completion(p).abandon(p);
+			Present(m.complete(p, kind))
+		}
+		Absent => Present(m.complete(p, JS_UNKNOWN_MEMBER)),
+	}
+}
+
 /// An individual object property such as `"a": b` or `5: 6 + 6`.
 fn object_member(p: &mut Parser) -> ParsedSyntax {
 	match p.cur() {
@@ -96,6 +118,21 @@ fn object_member(p: &mut Parser) -> ParsedSyntax {
 		// }
 		T![ident] if is_parser_at_async_method_member(p) => method_object_member(p),
 
+		// test_err object_expr_override_modifier
+		// let a = {
+		//   override foo() {},
+		//   override bar: 1,
+		//   override: 2,
+		//   override() {}
+		// }
+		T![ident]
+			if p.cur_src() == "override"
+				&& !p.has_linebreak_before_n(1)
+				&& STARTS_MEMBER_NAME.contains(p.nth(1)) =>
+		{
+			override_object_member(p)
+		}
+
 		// test object_expr_spread_prop
 		// let a = {...foo}
 		T![...] => {
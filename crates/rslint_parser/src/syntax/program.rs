@@ -6,11 +6,14 @@ use super::expr::{assign_expr, expr, identifier_name, primary_expr};
 use super::pat::binding_identifier;
 use super::stmt::{semi, statements, variable_declaration_statement};
 use super::typescript::*;
+use crate::parser::ParsedSyntax;
+use crate::parser::ParsedSyntax::{Absent, Present};
 use crate::syntax::class::class_declaration;
 use crate::syntax::function::function_declaration;
-use crate::syntax::object::object_expr;
+use crate::syntax::js_parse_error;
 use crate::syntax::stmt::directives;
 use crate::{SyntaxKind::*, *};
+use std::ops::Range;
 
 #[macro_export]
 macro_rules! at_ident_name {
@@ -36,7 +39,7 @@ pub fn parse(p: &mut Parser) -> CompletedMarker {
 	m.complete(p, SyntaxKind::JS_ROOT)
 }
 
-fn named_list(p: &mut Parser) -> Marker {
+fn named_list(p: &mut Parser, type_only: bool) -> Marker {
 	let m = p.start();
 	p.expect_required(T!['{']);
 	let mut first = true;
@@ -51,15 +54,22 @@ fn named_list(p: &mut Parser) -> Marker {
 			p.expect_required(T![,]);
 		}
 
-		specifier(p);
+		specifier(p, type_only);
 	}
 	specifiers_list.complete(p, LIST);
 	p.expect_required(T!['}']);
 	m
 }
 
-fn specifier(p: &mut Parser) -> CompletedMarker {
+fn specifier(p: &mut Parser, type_only: bool) -> CompletedMarker {
 	let m = p.start();
+	if at_type_modifier(p) {
+		specifier_type_modifier(
+			p,
+			type_only,
+			"the `type` modifier cannot be used on a named import when `import type` is used on its import declaration",
+		);
+	}
 	identifier_name(p);
 	if p.cur_src() == "as" {
 		p.bump_remap(T![as]);
@@ -68,8 +78,15 @@ fn specifier(p: &mut Parser) -> CompletedMarker {
 	m.complete(p, SPECIFIER)
 }
 
-fn named_export_specifier(p: &mut Parser) -> CompletedMarker {
+fn named_export_specifier(p: &mut Parser, type_only: bool) -> CompletedMarker {
 	let m = p.start();
+	if at_type_modifier(p) {
+		specifier_type_modifier(
+			p,
+			type_only,
+			"the `type` modifier cannot be used on a named export when `export type` is used on its export declaration",
+		);
+	}
 	identifier_name(p);
 	if p.cur_src() == "as" {
 		p.bump_remap(T![as]);
@@ -78,12 +95,85 @@ fn named_export_specifier(p: &mut Parser) -> CompletedMarker {
 	m.complete(p, SPECIFIER)
 }
 
+/// Whether the parser is at the `type` modifier of an import or export specifier such as `{ type A }`.
+///
+/// `as` makes this ambiguous: `{ type as }` and `{ type as as b }` are type-only specifiers of `as`, while
+/// `{ type as b }` and `{ type as as }` alias a binding named `type`.
+fn at_type_modifier(p: &Parser) -> bool {
+	if p.cur_src() != "type" || !at_ident_name!(p, 1) {
+		return false;
+	}
+
+	if p.nth_src(1) != "as" {
+		return true;
+	}
+
+	if p.nth_src(2) == "as" {
+		at_ident_name!(p, 3)
+	} else {
+		!at_ident_name!(p, 2)
+	}
+}
+
+fn specifier_type_modifier(p: &mut Parser, declaration_type_only: bool, msg: &str) {
+	if declaration_type_only {
+		let err = p.err_builder(msg).primary(p.cur_tok().range, "");
+
+		p.error(err);
+	}
+
+	type_only_modifier(
+		p,
+		"type-only specifiers can only be used in TypeScript files",
+	);
+}
+
+/// Eats the `type` keyword of a type-only import, export or specifier, or reports an error in JavaScript files.
+// test ts_type_only_import_export
+// // TYPESCRIPT
+// import type { A } from "a";
+// import type B from "b";
+// import { type C, D, type as, type as as E, type as F } from "c";
+// export type { G };
+// export { type H, I };
+// export type * from "j";
+// export * from "k";
+
+// test_err ts_type_only_import_export_err
+// // TYPESCRIPT
+// import type { type A } from "a";
+// export type { type B };
+// import type C, { D } from "c";
+
+// test_err type_only_import_export_js
+// import type { A } from "a";
+// import { type B, C } from "b";
+// export type { D };
+// export { type E };
+// export type * from "f";
+fn type_only_modifier(p: &mut Parser, msg: &str) {
+	debug_assert_eq!(p.cur_src(), "type");
+
+	if !p.typescript() {
+		let err = p.err_builder(msg).primary(p.cur_tok().range, "");
+
+		p.error(err);
+		let m = p.start();
+		p.bump_any();
+		m.complete(p, ERROR);
+	} else {
+		p.bump_remap(T![type]);
+	}
+}
+
 /// An import declaration
 ///
 /// # Panics
 /// Panics if the current syntax kind is not IMPORT_KW
 pub fn import_decl(p: &mut Parser) -> CompletedMarker {
 	assert_eq!(p.cur(), T![import]);
+	let mut guard = p.with_context(ParsingContext::ImportDeclaration);
+	let p = &mut *guard;
 	let m = p.start();
 	let start = p.cur_tok().range.start;
 
@@ -114,12 +204,12 @@ pub fn import_decl(p: &mut Parser) -> CompletedMarker {
 		return complete;
 	}
 
-	let list = p.start();
-
 	if p.at(JS_STRING_LITERAL) {
+		let list = p.start();
 		let inner = p.start();
 		p.bump_any();
 		inner.complete(p, IMPORT_STRING_SPECIFIER);
+		import_assertion(p);
 		semi(p, start..p.cur_tok().range.start);
 
 		list.complete(p, LIST);
@@ -130,21 +220,16 @@ pub fn import_decl(p: &mut Parser) -> CompletedMarker {
 		&& (p.nth_at(1, T!['{']) || p.nth_src(1) != "from" && !p.nth_at(1, T![,]));
 
 	if ty_only {
-		if !p.typescript() {
-			let err = p
-				.err_builder("type imports can only be used in TypeScript files")
-				.primary(p.cur_tok().range, "");
-
-			p.error(err);
-			let m = p.start();
-			p.bump_any();
-			m.complete(p, ERROR);
-		} else {
-			p.bump_remap(T![type]);
-		}
+		type_only_modifier(p, "type imports can only be used in TypeScript files");
 	}
 
+	let list = p.start();
+
+	let clauses_start = p.cur_tok().range.start;
+	let mut has_default = false;
+
 	if p.at_ts(token_set![T![async], T![yield], T![ident]]) {
+		has_default = true;
 		imported_binding(p);
 		if p.cur_src() != "from" {
 			p.expect_required(T![,]);
@@ -166,7 +251,19 @@ pub fn import_decl(p: &mut Parser) -> CompletedMarker {
 		imported_binding(p);
 		m.complete(p, WILDCARD_IMPORT);
 	} else if p.at(T!['{']) {
-		named_list(p).complete(p, NAMED_IMPORTS);
+		named_list(p, ty_only).complete(p, NAMED_IMPORTS);
+	} else {
+		has_default = false;
+	}
+
+	if ty_only && has_default {
+		let err = p
+			.err_builder(
+				"a type-only import can specify a default import or named bindings, but not both",
+			)
+			.primary(clauses_start..p.cur_tok().range.start, "");
+
+		p.error(err);
 	}
 
 	list.complete(p, LIST);
@@ -191,21 +288,123 @@ pub fn import_decl(p: &mut Parser) -> CompletedMarker {
 		p.error(err);
 	}
 
-	if p.cur_src() == "assert" {
+	import_assertion(p);
+
+	semi(p, start..p.cur_tok().range.start);
+	m.complete(p, IMPORT_DECL)
+}
+
+/// An import attributes clause such as `with { type: "json" }`, or the older `assert { type: "json" }` form.
+// test import_assertion
+// import json from "./foo.json" with { type: "json" };
+// import "./foo.json" with { type: "json" };
+// import { a } from "./foo.json" with { type: "json", "other": "value", };
+// import b from "./foo.json" assert { type: "json" };
+// import c from "./foo.json" with {};
+// export * from "./foo.json" with { type: "json" };
+// export * as ns from "./foo.json" with { type: "json" };
+// export { a } from "./foo.json" with { type: "json" };
+// import d from "./foo.json"
+// assert({ type: "json" })
+fn import_assertion(p: &mut Parser) -> Option<CompletedMarker> {
+	// `assert` is only a contextual keyword and mustn't be preceded by a line break,
+	// otherwise `import a from "a"\nassert(b)` would change meaning.
+	let at_assert = p.cur_src() == "assert" && !p.has_linebreak_before_n(0);
+
+	if !p.at(T![with]) && !at_assert {
+		return None;
+	}
+
+	// test_err import_assertion_err
+	// import a from "./foo.json" with { type: "json", type: "css" };
+	// import b from "./foo.json" with { type: json };
+	// import c from "./foo.json" with { 5: "json" };
+	// import d from "./foo.json" assert;
+	let m = p.start();
+	if at_assert {
 		p.bump_remap(T![assert]);
-		if !p.at(T!['{']) {
-			let err = p
-				.err_builder("assert clauses in import declarations require an object expression")
-				.primary(p.cur_tok().range, "");
+	} else {
+		p.bump_any();
+	}
 
-			p.error(err);
+	p.expect_required(T!['{']);
+	let entries = p.start();
+	let mut keys: Vec<(String, Range<usize>)> = Vec::new();
+	let mut first = true;
+
+	while !p.at(EOF) && !p.at(T!['}']) {
+		if first {
+			first = false;
 		} else {
-			object_expr(p);
+			p.expect_required(T![,]);
+
+			if p.at(T!['}']) {
+				break;
+			}
+		}
+
+		let recovered_entry = import_assertion_entry(p, &mut keys).or_recover(
+			p,
+			ParseRecovery::new(ERROR, token_set![T![,], T!['}'], T![;]])
+				.enable_recovery_on_line_break(),
+			js_parse_error::expected_import_assertion_entry,
+		);
+
+		if recovered_entry.is_err() {
+			break;
 		}
 	}
 
-	semi(p, start..p.cur_tok().range.start);
-	m.complete(p, IMPORT_DECL)
+	entries.complete(p, LIST);
+	p.expect_required(T!['}']);
+	Some(m.complete(p, JS_IMPORT_ASSERTION))
+}
+
+/// A single `key: "value"` pair of an import attributes clause.
+fn import_assertion_entry(p: &mut Parser, keys: &mut Vec<(String, Range<usize>)>) -> ParsedSyntax {
+	let key_range = p.cur_tok().range;
+	let key = match p.cur() {
+		JS_STRING_LITERAL => {
+			let src = p.cur_src();
+			src[1..src.len() - 1].to_string()
+		}
+		t if t.is_keyword() || t == T![ident] => p.cur_src().to_string(),
+		_ => return Absent,
+	};
+
+	let m = p.start();
+	if p.at(JS_STRING_LITERAL) {
+		p.bump_any();
+	} else {
+		p.bump_remap(T![ident]);
+	}
+
+	if let Some((_, first_range)) = keys.iter().find(|(existing, _)| *existing == key) {
+		let err = p
+			.err_builder(&format!("duplicate import assertion key `{}`", key))
+			.primary(key_range, "")
+			.secondary(first_range.clone(), "first defined here");
+
+		p.error(err);
+	} else {
+		keys.push((key, key_range));
+	}
+
+	p.expect_required(T![:]);
+
+	if !p.eat(JS_STRING_LITERAL) {
+		let err = p
+			.err_builder("import assertion values must be string literals")
+			.primary(p.cur_tok().range, "");
+
+		p.error(err);
+		// Nothing to do if the parser is already at the end of the entry
+		let _ = ParseRecovery::new(ERROR, token_set![T![,], T!['}'], T![;]])
+			.enable_recovery_on_line_break()
+			.recover(p);
+	}
+
+	Present(m.complete(p, JS_IMPORT_ASSERTION_ENTRY))
 }
 
 fn imported_binding(p: &mut Parser) {
@@ -218,6 +417,8 @@ fn imported_binding(p: &mut Parser) {
 }
 
 pub fn export_decl(p: &mut Parser) -> CompletedMarker {
+	let mut guard = p.with_context(ParsingContext::ExportDeclaration);
+	let p = &mut *guard;
 	let start = p.cur_tok().range.start;
 	let m = p.start();
 	p.expect_required(T![export]);
@@ -337,31 +538,46 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 		}
 	}
 
-	let only_ty = p.typescript()
-		&& (p.cur_src() == "type" && {
-			p.bump_remap(T![type]);
-			true
-		});
+	// The `type` keyword is eaten by the named or wildcard export it belongs to
+	let only_ty =
+		p.cur_src() == "type" && (p.typescript() || p.nth_at(1, T!['{']) || p.nth_at(1, T![*]));
 
-	let mut exports_ns = false;
-	let mut has_star = false;
+	if only_ty && p.nth_at(1, T![*]) {
+		type_only_modifier(p, "type exports can only be used in TypeScript files");
+	}
 
 	if p.eat(T![*]) {
-		has_star = true;
-		if p.cur_src() == "from" {
-			from_clause_and_semi(p, start);
-			return m.complete(p, EXPORT_WILDCARD);
-		}
+		// test export_namespace
+		// export * as ns from "mod";
+		// export * as default from "mod";
 		if p.cur_src() == "as" {
 			p.bump_remap(T![as]);
 			identifier_name(p);
-			exports_ns = true;
 		}
+
+		if p.cur_src() == "from" {
+			from_clause_and_semi(p, start);
+		} else {
+			// test_err export_namespace_missing_from
+			// export * as ns "mod";
+			// export * as ns;
+			// export * "mod";
+			let err = p
+				.err_builder("expected a `from` clause for a wildcard export, but found none")
+				.primary(p.cur_tok().range, "");
+
+			p.error(err);
+			if p.eat(JS_STRING_LITERAL) {
+				import_assertion(p);
+			}
+			semi(p, start..p.cur_tok().range.start);
+		}
+		return m.complete(p, EXPORT_WILDCARD);
 	}
 
 	let mut export_default = false;
 
-	if !only_ty && !exports_ns && p.eat(T![default]) {
+	if !only_ty && p.eat(T![default]) {
 		if p.cur_src() == "abstract" && p.nth_at(1, T![class]) {
 			let inner = p.start();
 			if !p.typescript() {
@@ -392,7 +608,7 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 			return m.complete(p, EXPORT_DEFAULT_DECL);
 		}
 
-		if p.at(T![class]) {
+		if p.at(T![class]) || p.at(T![@]) {
 			class_declaration(&mut *p.with_state(ParserState {
 				in_default: true,
 				..p.state.clone()
@@ -414,7 +630,7 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 		}
 	}
 
-	if !only_ty && p.at(T![class]) {
+	if !only_ty && (p.at(T![class]) || p.at(T![@])) {
 		class_declaration(p);
 	} else if !only_ty
 		// function ...
@@ -437,9 +653,8 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 	} else {
 		let m = p.start();
 
-		if p.cur_src() == "from" && exports_ns {
-			from_clause_and_semi(p, start);
-			return m.complete(p, EXPORT_WILDCARD);
+		if only_ty {
+			type_only_modifier(p, "type exports can only be used in TypeScript files");
 		}
 
 		if !export_default
@@ -455,12 +670,7 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 			return m.complete(p, EXPORT_NAMED);
 		}
 
-		if has_star && !exports_ns {
-			from_clause_and_semi(p, start);
-			return m.complete(p, EXPORT_WILDCARD);
-		}
-
-		if exports_ns || export_default {
+		if export_default {
 			p.expect_required(T![,]);
 		}
 
@@ -475,7 +685,7 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 			} else if p.eat(T![,]) && p.at(T!['}']) {
 				break;
 			}
-			named_export_specifier(p);
+			named_export_specifier(p, only_ty);
 		}
 
 		specifiers.complete(p, LIST);
@@ -485,11 +695,9 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 			from_clause_and_semi(p, start);
 		} else {
 			semi(p, start..p.cur_tok().range.start);
-			if export_default || exports_ns {
+			if export_default {
 				let err = p
-					.err_builder(
-						"`export default` and `export as` declarations must have a `from` clause",
-					)
+					.err_builder("`export default` declarations must have a `from` clause")
 					.primary(start..p.cur_tok().range.start, "");
 
 				p.error(err);
@@ -505,6 +713,7 @@ fn from_clause_and_semi(p: &mut Parser, start: usize) {
 	debug_assert_eq!(p.cur_src(), "from");
 	p.bump_remap(T![from]);
 	p.expect_required(T![js_string_literal]);
+	import_assertion(p);
 	semi(p, start..p.cur_tok().range.start);
 }
 
@@ -2,7 +2,7 @@
 //!
 //! See the [ECMAScript spec](https://www.ecma-international.org/ecma-262/5.1/#sec-12).
 
-use super::expr::{assign_expr, expr, EXPR_RECOVERY_SET, STARTS_EXPR};
+use super::expr::{assign_expr, check_strict_mode_string, expr, EXPR_RECOVERY_SET, STARTS_EXPR};
 use super::pat::*;
 use super::program::{export_decl, import_decl};
 use super::typescript::*;
@@ -10,7 +10,7 @@ use super::util::{check_for_stmt_declaration, check_label_use, check_lhs};
 #[allow(deprecated)]
 use crate::parser::single_token_parse_recovery::SingleTokenParseRecovery;
 use crate::parser::ParsedSyntax;
-use crate::syntax::class::class_declaration;
+use crate::syntax::class::{class_declaration, decorated_class_declaration};
 use crate::syntax::function::function_declaration;
 use crate::syntax::js_parse_error;
 use crate::JsSyntaxFeature::StrictMode;
@@ -93,6 +93,8 @@ pub(super) fn is_semi(p: &Parser, offset: usize) -> bool {
 /// A generic statement such as a block, if, while, with, etc
 #[allow(deprecated)]
 pub fn stmt(p: &mut Parser, recovery_set: impl Into<Option<TokenSet>>) -> Option<CompletedMarker> {
+	let mut guard = p.with_context(ParsingContext::Statement);
+	let p = &mut *guard;
 	let res = match p.cur() {
 		T![;] => empty_stmt(p).ok().unwrap(), // It is only ever Err if there's no ;
 		T!['{'] => block_stmt(p).ok().unwrap(), // It is only ever None if there is no `{`,
@@ -115,7 +117,7 @@ pub fn stmt(p: &mut Parser, recovery_set: impl Into<Option<TokenSet>>) -> Option
 		T![throw] => throw_stmt(p),
 		T![debugger] => debugger_stmt(p),
 		T![function] => function_declaration(p),
-		T![class] => class_declaration(p),
+		T![class] | T![@] => class_declaration(p),
 		T![ident]
 			if p.cur_src() == "async"
 				&& p.nth_at(1, T![function])
@@ -229,7 +231,7 @@ fn expr_stmt(p: &mut Parser) -> Option<CompletedMarker> {
 
 		let text_range = TextRange::new((range.start as u32).into(), (range.end as u32).into());
 		let text = p.source(text_range);
-		if let Some(range) = p.state.labels.get(text) {
+		if let Some(range) = p.state.labels.get(&*text) {
 			let err = p
 				.err_builder("Duplicate statement labels are not allowed")
 				.secondary(
@@ -463,6 +465,7 @@ pub(crate) fn directives(p: &mut Parser) -> Option<ParserState> {
 	let list = p.start();
 
 	let mut old_state: Option<ParserState> = None;
+	let mut directive_tokens = Vec::new();
 
 	fn is_directive(p: &Parser) -> bool {
 		if !p.at(JS_STRING_LITERAL) {
@@ -494,13 +497,25 @@ pub(crate) fn directives(p: &mut Parser) -> Option<ParserState> {
 			}
 
 			let mut new_state = p.state.clone();
-			new_state.strict(p, directive_token.range);
+			new_state.strict(p, directive_token.range.clone());
 			p.state = new_state;
 		}
+
+		directive_tokens.push(directive_token);
 	}
 
 	list.complete(p, LIST);
 
+	// test_err strict_mode_directive_octal_escape
+	// function a() { "\07"; "use strict"; }
+	// function b() { "use strict"; "\08"; }
+	if p.state.strict.is_some() {
+		// A "use strict" directive also applies to the directives before it
+		for token in directive_tokens {
+			check_strict_mode_string(p, &token);
+		}
+	}
+
 	old_state
 }
 
@@ -517,7 +532,7 @@ pub(crate) fn statements(
 	let list_start = p.start();
 
 	while !p.at(EOF) {
-		if stop_on_r_curly && p.at(T!['}']) {
+		if stop_on_r_curly && p.at(T!['}']) || p.check_cancelled() {
 			break;
 		}
 
@@ -553,21 +568,13 @@ pub(crate) fn statements(
 			// }
 			T![export] => {
 				let mut m = export_decl(p);
-				if !p.state.is_module && !p.typescript() {
-					let err = p
-						.err_builder("Illegal use of an export declaration outside of a module")
-						.primary(m.range(p), "not allowed inside scripts");
-
-					p.error(err);
-					m.change_kind(p, ERROR);
-				}
-				if !top_level {
-					let err = p
-						.err_builder("Illegal use of an import declaration not at the top level")
-						.primary(m.range(p), "move this declaration to the top level");
-
-					p.error(err);
-					m.change_kind(p, ERROR);
+				check_export_position(p, &mut m, top_level);
+			}
+			// The decorators of an exported class can be placed before `export`
+			T![@] => {
+				let mut m = decorated_class_declaration(p);
+				if matches!(m.kind(), EXPORT_DECL | EXPORT_DEFAULT_DECL) {
+					check_export_position(p, &mut m, top_level);
 				}
 			}
 			_ => {
@@ -579,6 +586,26 @@ pub(crate) fn statements(
 	list_start.complete(p, LIST);
 }
 
+/// Reports exports in scripts and exports that aren't at the top level
+fn check_export_position(p: &mut Parser, m: &mut CompletedMarker, top_level: bool) {
+	if !p.state.is_module && !p.typescript() {
+		let err = p
+			.err_builder("Illegal use of an export declaration outside of a module")
+			.primary(m.range(p), "not allowed inside scripts");
+
+		p.error(err);
+		m.change_kind(p, ERROR);
+	}
+	if !top_level {
+		let err = p
+			.err_builder("Illegal use of an import declaration not at the top level")
+			.primary(m.range(p), "move this declaration to the top level");
+
+		p.error(err);
+		m.change_kind(p, ERROR);
+	}
+}
+
 /// An expression wrapped in parentheses such as `()`
 pub fn parenthesized_expression(p: &mut Parser) {
 	p.state.allow_object_expr = p.expect_required(T!['(']);
@@ -740,7 +767,7 @@ fn variable_declaration(p: &mut Parser, no_semi: bool) -> CompletedMarker {
 	m.complete(p, JS_VARIABLE_DECLARATION)
 }
 
-// A single declarator, either `ident` or `ident = assign_expr`
+// A single declarator, either `ident`, `ident = assign_expr` or `ident!: Type` in TypeScript
 pub(crate) fn variable_declarator(
 	p: &mut Parser,
 	is_const: &Option<Range<usize>>,
@@ -749,53 +776,57 @@ pub(crate) fn variable_declarator(
 ) -> Option<CompletedMarker> {
 	let m = p.start();
 	p.state.should_record_names = is_const.is_some() || is_let;
-	let pat = if let Some(pattern) = pattern(p, false, false) {
+	let marker = if let Some(pattern) = pattern(p, false, false) {
 		pattern
 	} else {
 		m.abandon(p);
 		return None;
 	};
 
-	let pat_m = pat.undo_completion(p);
 	p.state.should_record_names = false;
-	let kind = pat.kind();
 
-	let cur = p.cur_tok().range;
-	let opt = p.eat(T![!]);
-	if opt && !p.typescript() {
-		let err = p
-			.err_builder("definite assignment assertions can only be used in TypeScript files")
-			.primary(cur, "");
-
-		p.error(err);
-	}
+	// test_err definite_assignment_js
+	// let a!;
+	// let b!: number;
 
-	if p.eat(T![:]) {
-		let start = p.cur_tok().range.start;
-		let ty = ts_type(p);
-		let end = ty
-			.map(|x| usize::from(x.range(p).end()))
-			.unwrap_or(p.cur_tok().range.start);
+	let definite_range = if p.at(T![!]) {
+		let range = p.cur_tok().range;
 		if !p.typescript() {
 			let err = p
-				.err_builder("type annotations can only be used in TypeScript files")
-				.primary(start..end, "");
+				.err_builder("definite assignment assertions can only be used in TypeScript files")
+				.primary(range.clone(), "");
 
 			p.error(err);
 		}
+		p.bump_any();
+		Some(range)
+	} else {
+		None
+	};
+
+	let type_range = maybe_ts_type_annotation(p);
+	if let Some(range) = type_range.clone() {
 		if p.typescript() && for_stmt {
 			let err = p
 				.err_builder("`for` statement declarators cannot have a type annotation")
-				.primary(start..end, "");
+				.primary(range, "");
 
 			p.state.for_head_error = Some(err);
 		}
 	}
 
-	let marker = pat_m.complete(p, kind);
+	let initializer = if p.at(T![=]) {
+		Some(variable_initializer(p))
+	} else {
+		None
+	};
+
+	if let Some(definite_range) = definite_range {
+		definite_assignment_errors(p, definite_range, type_range, initializer);
+	}
 
-	if p.at(T![=]) {
-		variable_initializer(p);
+	if initializer.is_some() {
+		// Nothing to check
 	} else if marker.kind() != SINGLE_PATTERN && !for_stmt && !p.state.in_declare {
 		let err = p
 			.err_builder("Object and Array patterns require initializers")
@@ -818,13 +849,13 @@ pub(crate) fn variable_declarator(
 }
 
 #[allow(deprecated)]
-fn variable_initializer(p: &mut Parser) {
+fn variable_initializer(p: &mut Parser) -> CompletedMarker {
 	let m = p.start();
 
 	p.expect_required(T![=]);
 	p.expr_with_semi_recovery(true);
 
-	m.complete(p, SyntaxKind::JS_EQUAL_VALUE_CLAUSE);
+	m.complete(p, SyntaxKind::JS_EQUAL_VALUE_CLAUSE)
 }
 
 // A do.. while statement, such as `do {} while (true)`
@@ -1112,7 +1143,7 @@ fn catch_declaration(p: &mut Parser) -> ParsedSyntax {
 		p.bump_any();
 		let ty = ts_type(p);
 		if !matches!(
-			ty.as_ref().map(|x| p.span_text(x.range(p))),
+			ty.as_ref().map(|x| p.span_text(x.range(p))).as_deref(),
 			Some("unknown") | Some("any")
 		) && p.typescript()
 			&& ty.is_some()
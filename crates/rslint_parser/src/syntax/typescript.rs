@@ -1,7 +1,9 @@
 //! TypeScript specific functions.
 
 use super::decl::*;
-use super::expr::{assign_expr, identifier_name, lhs_expr, literal_expression};
+use super::expr::{
+	assign_expr, check_template_chunk_escapes, identifier_name, lhs_expr, literal_expression,
+};
 use super::stmt::{semi, statements, variable_declaration_statement};
 #[allow(deprecated)]
 use crate::parser::SingleTokenParseRecovery;
@@ -51,28 +53,84 @@ macro_rules! no_recover {
 	};
 }
 
-pub(crate) fn abstract_readonly_modifiers(
-	p: &mut Parser,
-) -> (Option<Range<usize>>, Option<Range<usize>>) {
-	let (mut abstract_, mut readonly) = (None, None);
-	for _ in 0..2 {
-		match p.cur_src() {
-			"abstract" if abstract_.is_none() => {
-				abstract_ = ts_modifier(p, &["abstract"]);
-				if abstract_.is_none() {
-					return (abstract_, readonly);
-				}
-			}
-			"readonly" if readonly.is_none() => {
-				readonly = ts_modifier(p, &["readonly"]);
-				if readonly.is_none() {
-					return (abstract_, readonly);
-				}
-			}
-			_ => return (abstract_, readonly),
+/// The ranges of the `abstract`, `readonly` and `override` modifiers of a class member
+pub(crate) struct ClassMemberModifiers {
+	pub abstract_: Option<Range<usize>>,
+	pub readonly: Option<Range<usize>>,
+	pub override_: Option<Range<usize>>,
+}
+
+// test ts_override_modifiers
+// // TYPESCRIPT
+// class A extends B {
+//   override a = 1;
+//   public override b() {}
+//   protected static override readonly c: number;
+//   abstract override d: string;
+//   override get e() { return 1; }
+//   override set e(v) {}
+//   override accessor f = 1;
+//   override() {}
+//   override = 2;
+// }
+
+// test_err ts_override_modifiers_err
+// // TYPESCRIPT
+// class A extends B {
+//   readonly override a;
+//   override abstract b: number;
+//   override public c() {}
+//   override static d = 1;
+//   override constructor() {}
+// }
+
+// test_err override_modifier_js
+// class A extends B { override a() {} }
+/// Parses the `abstract`, `readonly` and `override` modifiers of a class member in any order.
+/// `override` must come after `abstract` and before `readonly`.
+pub(crate) fn abstract_readonly_override_modifiers(p: &mut Parser) -> ClassMemberModifiers {
+	let (mut abstract_, mut readonly, mut override_) = (None, None, None);
+	for _ in 0..3 {
+		let modifier = match p.cur_src() {
+			"abstract" if abstract_.is_none() => &mut abstract_,
+			"readonly" if readonly.is_none() => &mut readonly,
+			"override" if override_.is_none() => &mut override_,
+			_ => break,
+		};
+
+		match ts_modifier(p, &["abstract", "readonly", "override"]) {
+			Some(range) => *modifier = Some(range),
+			None => break,
 		}
 	}
-	(abstract_, readonly)
+
+	let misplaced = match (&abstract_, &readonly, &override_) {
+		(Some(abstract_), _, Some(override_)) if abstract_.start > override_.start => {
+			Some(("abstract", abstract_, "override", override_))
+		}
+		(_, Some(readonly), Some(override_)) if override_.start > readonly.start => {
+			Some(("override", override_, "readonly", readonly))
+		}
+		_ => None,
+	};
+
+	if let Some((modifier, range, successor, successor_range)) = misplaced {
+		let err = p
+			.err_builder(&format!(
+				"`{}` modifier must precede `{}` modifier",
+				modifier, successor
+			))
+			.primary(range.clone(), "")
+			.secondary(successor_range.clone(), "");
+
+		p.error(err);
+	}
+
+	ClassMemberModifiers {
+		abstract_,
+		readonly,
+		override_,
+	}
 }
 
 pub fn ts_modifier(p: &mut Parser, modifiers: &[&'static str]) -> Option<Range<usize>> {
@@ -91,6 +149,7 @@ pub fn ts_modifier(p: &mut Parser, modifiers: &[&'static str]) -> Option<Range<u
 	let kind = match p.cur_src() {
 		"abstract" => T![abstract],
 		"readonly" => T![readonly],
+		"override" => T![override],
 		_ => unreachable!("unknown modifier"),
 	};
 	p.bump_remap(kind);
@@ -126,6 +185,47 @@ pub(crate) fn maybe_ts_type_annotation(p: &mut Parser) -> Option<Range<usize>> {
 	}
 }
 
+// test ts_definite_assignment_assertions
+// // TYPESCRIPT
+// let a!: number, b: string;
+// class A { c!: string; d?: number; }
+
+// test_err ts_definite_assignment_assertions_err
+// // TYPESCRIPT
+// let a!: number = 1;
+// let b!;
+// class C { a!: number = 1; }
+// class D { a!; }
+/// Reports a definite assignment assertion (`let a!: number`, `class A { a!: number }`) that isn't
+/// followed by a type annotation or that is combined with an initializer
+pub(crate) fn definite_assignment_errors(
+	p: &mut Parser,
+	definite_range: Range<usize>,
+	type_range: Option<Range<usize>>,
+	initializer: Option<CompletedMarker>,
+) {
+	if !p.typescript() {
+		return;
+	}
+
+	let err = if let Some(initializer) = initializer {
+		p.err_builder(
+			"declarations with initializers cannot also have definite assignment assertions",
+		)
+		.primary(definite_range, "")
+		.secondary(initializer.range(p), "")
+	} else if type_range.is_none() {
+		p.err_builder(
+			"declarations with definite assignment assertions must also have type annotations",
+		)
+		.primary(definite_range, "")
+	} else {
+		return;
+	};
+
+	p.error(err);
+}
+
 pub(crate) fn ts_expr_stmt(p: &mut Parser) -> Option<CompletedMarker> {
 	match p.cur_src() {
 		"declare" => ts_declare(p),
@@ -147,12 +247,13 @@ pub(crate) fn ts_declare(p: &mut Parser) -> Option<CompletedMarker> {
 		..p.state.clone()
 	});
 	Some(match p.nth(1) {
+		// the function parser eats the `declare` keyword itself
 		T![function] => function_declaration(p),
 		T![class] => {
 			let m = p.start();
 			p.bump_remap(T![declare]);
 			class_declaration(p).undo_completion(p).abandon(p);
-			m.complete(p, JS_LITERAL_MEMBER_NAME)
+			m.complete(p, JS_CLASS_DECLARATION)
 		}
 		t if (t == T![const] && p.nth_at(2, T![enum])) || t == T![enum] => {
 			let m = p.start();
@@ -216,7 +317,7 @@ pub(crate) fn ts_decl(p: &mut Parser) -> Option<CompletedMarker> {
 			return None;
 		}
 		class_declaration(p).undo_completion(p).abandon(p);
-		return Some(m.complete(p, JS_LITERAL_MEMBER_NAME));
+		return Some(m.complete(p, JS_CLASS_DECLARATION));
 	}
 
 	if p.at(T![enum]) {
@@ -260,7 +361,7 @@ pub fn ts_type_alias_decl(p: &mut Parser) -> Option<CompletedMarker> {
 	p.bump_any();
 	no_recover!(p, m, t, identifier_name(p));
 	if p.at(T![<]) {
-		no_recover!(p, ts_type_params(p));
+		no_recover!(p, ts_type_params(p, false));
 	}
 
 	let end = p.cur_tok().range.end;
@@ -377,7 +478,7 @@ pub fn ts_interface(p: &mut Parser) -> Option<CompletedMarker> {
 	}
 	identifier_name(p);
 	if p.at(T![<]) {
-		ts_type_params(p);
+		ts_type_params(p, false);
 	}
 
 	let mut extends_list = None;
@@ -461,6 +562,10 @@ pub fn ts_type_member(p: &mut Parser) -> Option<CompletedMarker> {
 		return ts_signature_member(p, true);
 	}
 
+	if is_at_abstract_new(p) && token_set!(T![<], T!['(']).contains(p.nth(2)) {
+		return ts_signature_member(p, true);
+	}
+
 	let (m, readonly) = if p.cur_src() == "readonly" {
 		let m = p.start();
 		p.bump_remap(T![readonly]);
@@ -501,7 +606,7 @@ fn ts_property_or_method_sig(p: &mut Parser, m: Marker, readonly: bool) -> Optio
 	p.eat(T![?]);
 	Some(if !readonly && p.at_ts(token_set![T!['('], T![<]]) {
 		if p.at(T![<]) {
-			no_recover!(p, ts_type_params(p));
+			no_recover!(p, ts_type_params(p, true));
 		}
 		parameter_list(p);
 		if p.at(T![:]) {
@@ -561,11 +666,14 @@ pub(crate) fn try_parse_index_signature(
 pub fn ts_signature_member(p: &mut Parser, construct_sig: bool) -> Option<CompletedMarker> {
 	let m = p.start();
 	if construct_sig {
+		if is_at_abstract_new(p) {
+			p.bump_remap(T![abstract]);
+		}
 		p.expect_required(T![new]);
 	}
 
 	if p.at(T![<]) {
-		no_recover!(p, ts_type_params(p));
+		no_recover!(p, ts_type_params(p, true));
 	}
 
 	parameter_list(&mut *p.with_state(ParserState {
@@ -594,13 +702,26 @@ fn type_member_semi(p: &mut Parser) {
 	}
 }
 
+// test ts_enum_members
+// // TYPESCRIPT
+// const enum A { B = 1, C = B << 2, D = (C + 1) * -B, E = "e", F = A.B | A["C"], G }
+// enum H { I = foo(), J = "j", K = 2, L }
+
+// test_err ts_enum_members_err
+// // TYPESCRIPT
+// const enum A { B = foo(), C = "c".length, D = 1 + 1 }
+// enum E { F, F, G, "G" }
+// enum H { I = "i", J }
 pub fn ts_enum(p: &mut Parser) -> CompletedMarker {
 	let m = p.start();
-	p.eat(T![const]);
+	let is_const = p.eat(T![const]);
 	p.expect_required(T![enum]);
 	identifier_name(p);
 	p.expect_required(T!['{']);
 	let mut first = true;
+	let mut names: Vec<(String, Range<usize>)> = vec![];
+	// The initializer of the previous member if it is a string
+	let mut previous_string: Option<TextRange> = None;
 
 	let members_list = p.start();
 
@@ -615,6 +736,7 @@ pub fn ts_enum(p: &mut Parser) -> CompletedMarker {
 		}
 
 		let member = p.start();
+		let name_range = p.cur_tok().range;
 		let err_occured = if !p.at_ts(token_set![T![ident], T![yield], T![await]])
 			&& !p.cur().is_keyword()
 			&& !p.at(JS_STRING_LITERAL)
@@ -632,6 +754,22 @@ pub fn ts_enum(p: &mut Parser) -> CompletedMarker {
 			.recover(p);
 			true
 		} else {
+			let name = match p.cur_src() {
+				string if p.at(JS_STRING_LITERAL) => string[1..string.len() - 1].to_string(),
+				ident => ident.to_string(),
+			};
+
+			if let Some((_, first_range)) = names.iter().find(|(existing, _)| *existing == name) {
+				let err = p
+					.err_builder(&format!("duplicate enum member `{}`", name))
+					.primary(name_range.clone(), "")
+					.secondary(first_range.clone(), "first defined here");
+
+				p.error(err);
+			} else {
+				names.push((name, name_range.clone()));
+			}
+
 			if !p.eat(JS_STRING_LITERAL) {
 				identifier_name(p).unwrap().undo_completion(p).abandon(p);
 			}
@@ -639,11 +777,38 @@ pub fn ts_enum(p: &mut Parser) -> CompletedMarker {
 		};
 
 		if p.eat(T![=]) {
-			assign_expr(p);
+			let start_event = p.events.len();
+			let initializer = assign_expr(p);
+
+			if let Some(initializer) = initializer {
+				if is_const && !is_constant_enum_initializer(p, start_event) {
+					let err = p
+						.err_builder("const enum member initializers must be constant expressions")
+						.primary(initializer.range(p), "");
+
+					p.error(err);
+				}
+			}
+
+			previous_string = initializer
+				.filter(|initializer| {
+					matches!(initializer.kind(), JS_STRING_LITERAL_EXPRESSION | TEMPLATE)
+				})
+				.map(|initializer| initializer.range(p));
 			member.complete(p, TS_ENUM_MEMBER);
 		} else if err_occured {
 			member.abandon(p);
 		} else {
+			if let Some(string_range) = previous_string.take() {
+				let err = p
+					.err_builder(
+						"enum members following a member initialized with a string must have an initializer",
+					)
+					.primary(name_range, "")
+					.secondary(string_range, "");
+
+				p.error(err);
+			}
 			member.complete(p, TS_ENUM_MEMBER);
 		}
 	}
@@ -654,6 +819,51 @@ pub fn ts_enum(p: &mut Parser) -> CompletedMarker {
 	m.complete(p, TS_ENUM)
 }
 
+/// Whether the events starting at `start_event` form a constant enum expression, which only consists of
+/// literals, references to (other) enum members, parentheses and arithmetic or bitwise operators
+fn is_constant_enum_initializer(p: &Parser, start_event: usize) -> bool {
+	let mut previous_token = None;
+
+	p.events[start_event..].iter().all(|event| match event {
+		Event::Start { kind, .. } => matches!(
+			kind,
+			TOMBSTONE
+				| LIST | NAME | JS_NUMBER_LITERAL_EXPRESSION
+				| JS_STRING_LITERAL_EXPRESSION
+				| JS_REFERENCE_IDENTIFIER_EXPRESSION
+				| JS_STATIC_MEMBER_EXPRESSION
+				| JS_COMPUTED_MEMBER_EXPRESSION
+				| JS_REFERENCE_IDENTIFIER_MEMBER
+				| JS_PARENTHESIZED_EXPRESSION
+				| JS_UNARY_EXPRESSION
+				| JS_BINARY_EXPRESSION
+				| TEMPLATE
+		),
+		Event::Token { kind, .. } => {
+			// Only members of enums can be accessed, `"a".length` or `(A).B` aren't constant
+			let is_member_access = matches!(kind, T![.] | T!['[']);
+			let is_valid = if is_member_access {
+				matches!(previous_token, Some(T![ident]) | Some(T![']']))
+			} else {
+				matches!(
+					kind,
+					JS_NUMBER_LITERAL
+						| JS_STRING_LITERAL | T![ident]
+						| T![']'] | T!['('] | T![')']
+						| T![+] | T![-] | T![*] | T![/]
+						| T![%] | T![**] | T![<<]
+						| T![>>] | T![>>>] | T![&]
+						| T![|] | T![^] | T![~] | BACKTICK
+						| TEMPLATE_CHUNK
+				)
+			};
+			previous_token = Some(*kind);
+			is_valid
+		}
+		_ => true,
+	})
+}
+
 pub fn try_parse_ts(
 	p: &mut Parser,
 	func: impl FnOnce(&mut Parser) -> Option<CompletedMarker>,
@@ -694,13 +904,18 @@ pub fn ts_type(p: &mut Parser) -> Option<CompletedMarker> {
 
 pub fn ts_fn_or_constructor_type(p: &mut Parser, fn_type: bool) -> Option<CompletedMarker> {
 	let m = p.start();
+	// `abstract new () => T`
+	if !fn_type && is_at_abstract_new(p) {
+		p.bump_remap(T![abstract]);
+	}
+
 	if !fn_type && p.expect_no_recover(T![new]).is_none() {
 		m.abandon(p);
 		return None;
 	}
 
 	if p.at(T![<]) {
-		ts_type_params(p);
+		ts_type_params(p, true);
 	}
 	parameter_list(p);
 	if ts_type_or_type_predicate_ann(p, T![=>]).is_none() && p.state.no_recovery {
@@ -740,13 +955,24 @@ pub fn ts_non_conditional_type(p: &mut Parser) -> Option<CompletedMarker> {
 		return ts_fn_or_constructor_type(p, true);
 	}
 
-	if p.at(T![new]) {
+	if p.at(T![new]) || is_at_abstract_new(p) {
 		return ts_fn_or_constructor_type(p, false);
 	}
 
 	intersection_or_union(p, false, ts_intersection_type_or_higher, T![|])
 }
 
+// test ts_abstract_constructor_types
+// // TYPESCRIPT
+// type A = abstract new () => object;
+// type B = abstract new <T>(x: T) => T;
+// type C = { abstract new (): object; new (): object };
+// let d: abstract new () => void;
+/// Whether the parser is at the `abstract` modifier of an abstract constructor type or construct signature
+fn is_at_abstract_new(p: &Parser) -> bool {
+	p.cur_src() == "abstract" && p.nth_at(1, T![new]) && !p.has_linebreak_before_n(1)
+}
+
 fn ts_intersection_type_or_higher(p: &mut Parser) -> Option<CompletedMarker> {
 	intersection_or_union(p, true, ts_type_operator_or_higher, T![&])
 }
@@ -901,45 +1127,106 @@ pub fn ts_array_type_or_higher(p: &mut Parser) -> Option<CompletedMarker> {
 	ty
 }
 
+// test ts_tuple_types
+// // TYPESCRIPT
+// type A = [head: string, tail?: number, ...rest: boolean[]];
+// type B = [...string[], number];
+// type C = [string, number?];
+
+// test_err ts_tuple_types_err
+// // TYPESCRIPT
+// type A = [...rest?: string[]];
+// type B = [string?, number];
+// type C = [...string[], ...number[]];
+// type D = [...string[], number?];
 pub fn ts_tuple(p: &mut Parser) -> Option<CompletedMarker> {
 	let m = p.start();
 	p.expect_no_recover(T!['['])?;
 
+	let elements = p.start();
+	let mut first_optional: Option<Range<usize>> = None;
+	let mut first_rest: Option<Range<usize>> = None;
+
 	while !p.at(EOF) && !p.at(T![']']) {
-		let m = p.start();
+		let element = p.start();
+		let start = p.cur_tok().range.start;
 		let rest_range = p.cur_tok().range;
 		let rest = p.eat(T![...]);
-		let name = if crate::at_ident_name!(p)
-			&& !DISALLOWED_TYPE_NAMES.contains(&p.cur_src())
-			&& (p.nth_at(1, T![:]) || (p.nth_at(1, T![?]) && p.nth_at(2, T![:])))
-		{
+		let named = crate::at_ident_name!(p)
+			&& (p.nth_at(1, T![:]) || (p.nth_at(1, T![?]) && p.nth_at(2, T![:])));
+
+		let mut optional_range = None;
+		let kind = if named {
 			identifier_name(p);
-			true
+			if p.at(T![?]) {
+				optional_range = Some(p.cur_tok().range);
+				p.bump_any();
+			}
+			p.expect_required(T![:]);
+			no_recover!(p, ts_type(p));
+			Some(TS_NAMED_TUPLE_TYPE_ELEMENT)
 		} else {
-			false
+			no_recover!(p, ts_type(p));
+			if p.at(T![?]) {
+				optional_range = Some(p.cur_tok().range);
+				p.bump_any();
+			}
+
+			match (rest, &optional_range) {
+				(true, _) => Some(TS_REST_TUPLE_TYPE_ELEMENT),
+				(false, Some(_)) => Some(TS_OPTIONAL_TUPLE_TYPE_ELEMENT),
+				(false, None) => None,
+			}
 		};
 
-		let opt_range = p.cur_tok().range;
-		let is_opt = name && p.eat(T![?]);
-		if name {
-			p.expect_required(T![:]);
-		}
-		no_recover!(p, ts_type(p));
-		if !name && p.at(T![?]) {
-			p.eat(T![?]);
+		let range = start..p.cur_tok().range.start;
+		match kind {
+			Some(kind) => {
+				element.complete(p, kind);
+			}
+			None => element.abandon(p),
 		}
-		m.complete(p, TS_TUPLE_ELEMENT);
-		if is_opt && rest {
-			let err = p
-				.err_builder("a tuple element cannot be both rest and optional")
-				.secondary(rest_range, "")
-				.primary(opt_range, "");
 
+		let err = match (&optional_range, &first_optional, &first_rest) {
+			(Some(optional), _, _) if rest => Some(
+				p.err_builder("a tuple element cannot be both rest and optional")
+					.secondary(rest_range.clone(), "")
+					.primary(optional.clone(), ""),
+			),
+			(_, _, Some(first_rest)) if rest => Some(
+				p.err_builder("a rest element cannot follow another rest element")
+					.primary(range.clone(), "")
+					.secondary(first_rest.clone(), ""),
+			),
+			(Some(_), _, Some(first_rest)) => Some(
+				p.err_builder("an optional element cannot follow a rest element")
+					.primary(range.clone(), "")
+					.secondary(first_rest.clone(), ""),
+			),
+			(None, Some(first_optional), _) if !rest => Some(
+				p.err_builder("a required element cannot follow an optional element")
+					.primary(range.clone(), "")
+					.secondary(first_optional.clone(), ""),
+			),
+			_ => None,
+		};
+
+		if let Some(err) = err {
 			p.error(err);
 		}
-		p.eat(T![,]);
+
+		if rest {
+			first_rest.get_or_insert(range);
+		} else if optional_range.is_some() {
+			first_optional.get_or_insert(range);
+		}
+
+		if !p.eat(T![,]) {
+			break;
+		}
 	}
 
+	elements.complete(p, LIST);
 	p.expect_no_recover(T![']'])?;
 	Some(m.complete(p, TS_TUPLE))
 }
@@ -986,6 +1273,15 @@ pub fn ts_non_array_type(p: &mut Parser) -> Option<CompletedMarker> {
 				.precede(p)
 				.complete(p, TS_LITERAL),
 		),
+		// test ts_template_literal_types
+		// // TYPESCRIPT
+		// type A = `a-${B}-c`;
+		// type D = `${Uppercase<E>}_${`nested-${F}`}`;
+		// type G = `${Capitalize<keyof H & string>}Changed`;
+
+		// test_err ts_template_literal_type_invalid_escape
+		// // TYPESCRIPT
+		// type A = `\x4${B}`;
 		BACKTICK => {
 			let m = p.start();
 			p.bump_any();
@@ -993,7 +1289,10 @@ pub fn ts_non_array_type(p: &mut Parser) -> Option<CompletedMarker> {
 			let elements_list = p.start();
 			while !p.at(EOF) && !p.at(BACKTICK) {
 				match p.cur() {
-                    TEMPLATE_CHUNK => p.bump_any(),
+                    TEMPLATE_CHUNK => {
+                        check_template_chunk_escapes(p);
+                        p.bump_any()
+                    },
                     DOLLARCURLY => {
                         let e = p.start();
                         p.bump_any();
@@ -1143,8 +1442,12 @@ pub fn ts_type_args(p: &mut Parser) -> Option<CompletedMarker> {
 	}
 }
 
+/// Parses a list of type parameters like `<T, U extends string = "a">`.
+///
+/// `allow_const` is whether the parameters may have a `const` modifier, which is only
+/// the case for functions, methods, classes and signatures but not for interfaces and type aliases.
 // FIXME: `<T() => {}` causes infinite recursion if the parser isnt being run with `no_recovery`
-pub fn ts_type_params(p: &mut Parser) -> Option<CompletedMarker> {
+pub fn ts_type_params(p: &mut Parser, allow_const: bool) -> Option<CompletedMarker> {
 	let m = p.start();
 	p.expect_no_recover(T![<])?;
 	let mut first = true;
@@ -1160,7 +1463,7 @@ pub fn ts_type_params(p: &mut Parser) -> Option<CompletedMarker> {
 			}
 			p.expect_no_recover(T![,])?;
 		}
-		no_recover!(p, type_param(p));
+		no_recover!(p, type_param(p, allow_const));
 	}
 	params_list.complete(p, LIST);
 
@@ -1168,8 +1471,41 @@ pub fn ts_type_params(p: &mut Parser) -> Option<CompletedMarker> {
 	Some(m.complete(p, TS_TYPE_PARAMS))
 }
 
-fn type_param(p: &mut Parser) -> Option<CompletedMarker> {
+// test ts_const_type_params
+// // TYPESCRIPT
+// function a<const T>(x: T) {}
+// class B<const T, const U extends string[] = []> {
+//   method<const V>() {}
+// }
+// let c = <const T,>(x: T) => x;
+// type D = <const T>() => T;
+// interface E {
+//   m<const T extends readonly unknown[]>(x: T): T;
+//   new <const T>(): T;
+// }
+
+// test tsx_const_arrow_type_params
+// // TSX
+// let a = <const T>(x: T) => x;
+
+// test_err ts_const_type_params_in_disallowed_positions
+// // TYPESCRIPT
+// interface A<const T> {}
+// type B<T, const U> = U;
+fn type_param(p: &mut Parser, allow_const: bool) -> Option<CompletedMarker> {
 	let m = p.start();
+	if p.at(T![const]) && (p.nth_at(1, T![ident]) || p.nth(1).is_keyword()) {
+		if !allow_const {
+			let err = p
+				.err_builder(
+					"`const` modifiers can only appear on a type parameter of a function, method or class",
+				)
+				.primary(p.cur_tok().range, "");
+			p.error(err);
+		}
+		p.bump_any();
+	}
+
 	let mut should_complete =
 		if p.at_ts(token_set![T![ident], T![await], T![yield]]) || p.cur().is_keyword() {
 			p.bump_remap(T![ident]);
@@ -1238,6 +1574,15 @@ pub fn ts_type_query(p: &mut Parser) -> Option<CompletedMarker> {
 	Some(m.complete(p, TS_TYPE_QUERY))
 }
 
+// test ts_mapped_types
+// // TYPESCRIPT
+// type A = { [K in keyof T as `get${Capitalize<K & string>}`]: () => T[K] };
+// type B = { -readonly [K in keyof T]-?: T[K] };
+// type C = { +readonly [K in T]+?: K };
+
+// test_err ts_mapped_type_modifier_without_question_mark
+// // TYPESCRIPT
+// type A = { [K in T]-: K };
 pub fn ts_mapped_type(p: &mut Parser) -> Option<CompletedMarker> {
 	let m = p.start();
 	p.expect_no_recover(T!['{'])?;
@@ -1277,9 +1622,12 @@ pub fn ts_mapped_type(p: &mut Parser) -> Option<CompletedMarker> {
 		p.bump_any();
 	}
 	no_recover!(p, ts_type(p));
+	// Key remapping, `[K in keyof T as Uppercase<K>]`
 	if p.cur_src() == "as" {
-		p.bump_any();
-		ts_type(p);
+		let as_clause = p.start();
+		p.bump_remap(T![as]);
+		no_recover!(p, ts_type(p));
+		as_clause.complete(p, TS_MAPPED_TYPE_AS_CLAUSE);
 	}
 	p.expect_no_recover(T![']'])?;
 	param.complete(p, TS_MAPPED_TYPE_PARAM);
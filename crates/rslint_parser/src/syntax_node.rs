@@ -7,10 +7,57 @@
 
 use crate::SyntaxKind;
 use rome_rowan::{Language, TreeBuilder};
+use std::fmt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct JsLanguage;
 
+impl JsLanguage {
+	/// Prints the raw kinds of all trees by their JavaScript names in debug output, including trees
+	/// of [rome_rowan::api::RawLanguage], see [rome_rowan::register_kind_formatter].
+	///
+	/// ```
+	/// use rslint_parser::JsLanguage;
+	///
+	/// JsLanguage::register_kind_names();
+	/// assert_eq!("IDENT", format!("{:?}", rome_rowan::SyntaxKind(rslint_parser::SyntaxKind::IDENT.into())));
+	/// ```
+	pub fn register_kind_names() {
+		rome_rowan::register_kind_formatter(|kind, f| {
+			if kind.0 < SyntaxKind::__LAST as u16 {
+				fmt::Debug::fmt(&SyntaxKind::from(kind.0), f)
+			} else {
+				write!(f, "SyntaxKind({})", kind.0)
+			}
+		});
+	}
+
+	/// Shares the punctuation and keyword tokens without trivia between all trees of the process,
+	/// see [rome_rowan::register_common_tokens]. The parser registers them before building a tree.
+	pub fn register_common_tokens() {
+		rome_rowan::register_common_tokens(
+			(0..SyntaxKind::__LAST as u16)
+				.map(SyntaxKind::from)
+				.filter_map(|kind| {
+					let text = common_token_text(kind)?;
+					Some((rome_rowan::SyntaxKind(kind.into()), text))
+				}),
+		);
+	}
+}
+
+/// Returns the text of a punctuation or keyword token
+fn common_token_text(kind: SyntaxKind) -> Option<String> {
+	if kind.is_punct() {
+		// `to_string` quotes the texts of some punctuation, like `'('`
+		Some(kind.to_string()?.trim_matches('\'').to_string())
+	} else if kind.is_keyword() {
+		Some(format!("{:?}", kind).trim_end_matches("_KW").to_lowercase())
+	} else {
+		None
+	}
+}
+
 impl Language for JsLanguage {
 	type Kind = SyntaxKind;
 
@@ -25,6 +72,22 @@ impl Language for JsLanguage {
 	fn list_kind() -> Self::Kind {
 		SyntaxKind::LIST
 	}
+
+	fn is_trivia(kind: SyntaxKind) -> bool {
+		kind.is_trivia()
+	}
+
+	fn is_keyword(kind: SyntaxKind) -> bool {
+		kind.is_keyword()
+	}
+
+	fn is_punct(kind: SyntaxKind) -> bool {
+		kind.is_punct()
+	}
+
+	fn slot_name(kind: SyntaxKind, index: usize) -> Option<&'static str> {
+		crate::ast::slot_name(kind, index)
+	}
 }
 
 pub type SyntaxNode = rome_rowan::SyntaxNode<JsLanguage>;
@@ -36,4 +99,69 @@ pub type SyntaxList = rome_rowan::SyntaxList<JsLanguage>;
 
 pub use rome_rowan::{Direction, NodeOrToken};
 
-pub type SyntaxTreeBuilder = TreeBuilder<'static, JsLanguage>;
+pub type SyntaxTreeBuilder<'cache> = TreeBuilder<'cache, JsLanguage>;
+
+#[cfg(test)]
+mod tests {
+	use super::common_token_text;
+	use crate::{parse_text, JsLanguage, SyntaxKind};
+	use rome_rowan::Language;
+
+	#[test]
+	fn common_token_texts() {
+		for kind in (0..SyntaxKind::__LAST as u16).map(SyntaxKind::from) {
+			match common_token_text(kind) {
+				Some(text) if kind.is_keyword() => {
+					assert_eq!(Some(kind), SyntaxKind::from_keyword(&text))
+				}
+				Some(text) => assert!(SyntaxKind::PUNCTUATORS.contains(&text.as_str()), "{}", text),
+				None => assert!(!kind.is_punct() && !kind.is_keyword()),
+			}
+		}
+	}
+
+	#[test]
+	fn classifies_tokens() {
+		let root = parse_text("let a = 1;", 0).syntax();
+		let classes: Vec<_> = root
+			.descendants_tokens()
+			.map(|token| {
+				let kind = token.kind();
+				(
+					JsLanguage::is_keyword(kind),
+					JsLanguage::is_punct(kind),
+					JsLanguage::is_trivia(kind),
+				)
+			})
+			.collect();
+
+		assert_eq!(
+			vec![
+				(true, false, false),
+				(false, false, false),
+				(false, true, false),
+				(false, false, false),
+				(false, true, false),
+				// EOF
+				(false, false, false),
+			],
+			classes
+		);
+	}
+
+	#[test]
+	fn slots_are_named_after_their_accessors() {
+		let root = parse_text("if (a) b; else c;", 0).syntax();
+		let if_statement = root
+			.descendants()
+			.find(|node| node.kind() == SyntaxKind::JS_IF_STATEMENT)
+			.unwrap();
+
+		let test = if_statement.element_by_slot_name("test").unwrap();
+		assert_eq!("a", test.to_string());
+		let consequent = if_statement.element_by_slot_name("consequent").unwrap();
+		assert_eq!("b; ", consequent.to_string());
+		assert!(if_statement.element_by_slot_name("body").is_none());
+		assert_eq!(None, JsLanguage::slot_name(SyntaxKind::JS_IF_STATEMENT, 6));
+	}
+}
@@ -1,5 +1,9 @@
-use crate::ast::{ArgList, JsRoot};
-use crate::{parse_module, parse_text, AstNode, Parse, ParserError, SyntaxNode, SyntaxToken};
+use crate::ast::{ArgList, JsAnyExpression, JsAnyLiteralExpression, JsRoot};
+use crate::{
+	parse, parse_cancellable, parse_expression, parse_module, parse_statement, parse_text,
+	parse_type, tokenize, AstNode, CancellationToken, Cancelled, EcmaVersion, Event, FileKind,
+	Parse, Parser, ParserError, Syntax, SyntaxNode, SyntaxToken, TokenSource, T,
+};
 use expect_test::expect_file;
 use rome_rowan::TextSize;
 use rslint_errors::file::SimpleFile;
@@ -52,6 +56,8 @@ fn try_parse(path: &str, text: &str) -> Parse<JsRoot> {
 		// This is needed to test features that are restricted in strict mode.
 		if text.contains("// SCRIPT") {
 			parse_text(text, 0)
+		} else if let Some(syntax) = marked_syntax(text) {
+			parse(text, 0, syntax)
 		} else {
 			parse_module(text, 0)
 		}
@@ -64,6 +70,23 @@ fn try_parse(path: &str, text: &str) -> Parse<JsRoot> {
 	res.unwrap()
 }
 
+/// Returns the syntax of the files containing a comment that selects a syntax other than
+/// the syntax of a module, e.g. `// TYPESCRIPT` to test TypeScript only syntax.
+fn marked_syntax(text: &str) -> Option<Syntax> {
+	let syntax = if text.contains("// TYPESCRIPT") {
+		Syntax::default().typescript()
+	} else if text.contains("// TSX") {
+		Syntax::default().tsx()
+	} else if text.contains("// DECORATORS") {
+		Syntax::default().module().decorators()
+	} else if text.contains("// VALIDATE_REGEX") {
+		Syntax::default().module().validate_regex()
+	} else {
+		return None;
+	};
+	Some(syntax)
+}
+
 #[test]
 fn parser_tests() {
 	dir_tests(&test_data_dir(), &["inline/ok"], "rast", |text, path| {
@@ -106,12 +129,18 @@ where
 	F: Fn(&str, &Path) -> String,
 {
 	for (path, input_code) in collect_js_files(test_data_dir, paths) {
-		let actual = f(&input_code, &path);
+		let actual = normalize_line_endings(&f(&input_code, &path));
 		let path = path.with_extension(outfile_extension);
 		expect_file![path].assert_eq(&actual)
 	}
 }
 
+/// Git may check out the test files with `\r\n` line endings on Windows, normalize them
+/// so that the recorded trees and ranges are the same on every platform.
+fn normalize_line_endings(text: &str) -> String {
+	text.replace("\r\n", "\n")
+}
+
 pub fn project_dir() -> PathBuf {
 	let dir = env!("CARGO_MANIFEST_DIR");
 	PathBuf::from(dir).parent().unwrap().to_path_buf()
@@ -126,7 +155,7 @@ fn collect_js_files(root_dir: &Path, paths: &[&str]) -> Vec<(PathBuf, String)> {
 		})
 		.map(|path| {
 			let text = fs::read_to_string(&path).expect("Could not read js file");
-			(path, text)
+			(path, normalize_line_endings(&text))
 		})
 		.collect()
 }
@@ -199,10 +228,11 @@ pub fn test_trivia_attached_to_tokens() {
 	// second let leading trivia asserts
 	let second_let = tokens.iter().filter(is_let).nth(1).unwrap();
 	let pieces: Vec<_> = second_let.leading_trivia().pieces().collect();
-	assert_eq!(3, pieces.len());
-	matches!(pieces.get(0).map(|x| x.text()), Some("\n "));
-	matches!(pieces.get(1).map(|x| x.text()), Some("/*hey*/"));
-	matches!(pieces.get(2).map(|x| x.text()), Some(" "));
+	assert_eq!(4, pieces.len());
+	matches!(pieces.get(0).map(|x| x.text()), Some("\n"));
+	matches!(pieces.get(1).map(|x| x.text()), Some(" "));
+	matches!(pieces.get(2).map(|x| x.text()), Some("/*hey*/"));
+	matches!(pieces.get(3).map(|x| x.text()), Some(" "));
 
 	// second let trailing trivia asserts
 	let pieces: Vec<_> = second_let.trailing_trivia().pieces().collect();
@@ -210,6 +240,32 @@ pub fn test_trivia_attached_to_tokens() {
 	matches!(pieces.get(0).map(|x| x.text()), Some(" \t "));
 }
 
+#[test]
+pub fn test_newlines_are_newline_trivia() {
+	let text = "a; // a\r\n\n\t/* b */ b;";
+	let root = parse_module(text, 0).syntax();
+	let b = root
+		.descendants_tokens()
+		.find(|token| token.text_trimmed() == "b")
+		.unwrap();
+
+	let leading = b.leading_trivia();
+	assert!(leading.has_newline());
+	let pieces: Vec<_> = leading.pieces().collect();
+	assert_eq!(4, pieces.len());
+	let newline = pieces[0].as_newline().unwrap();
+	assert_eq!("\r\n\n", newline.text());
+	assert_eq!(2, newline.lines());
+	assert_eq!("\t", pieces[1].text());
+	assert!(pieces[1].as_newline().is_none());
+	assert_eq!("/* b */", pieces[2].text());
+
+	// The line break ends the trailing trivia of the previous token
+	let semicolon = b.prev_token().unwrap();
+	assert!(!semicolon.trailing_trivia().has_newline());
+	assert_eq!(" // a", semicolon.trailing_trivia().text());
+}
+
 #[test]
 pub fn jsroot_display_text_and_trimmed() {
 	let code = " let a = 1; \n ";
@@ -304,3 +360,447 @@ pub fn just_trivia_must_be_appended_to_eof() {
 	assert_eq!(TextSize::from(0), start);
 	assert_eq!(TextSize::from(34), end);
 }
+
+#[test]
+pub fn cancelled_parser_stops_at_the_next_statement() {
+	let text = "let a = 1;\n{ let b = 2; }";
+	let (tokens, _) = tokenize(text, 0);
+	let cancellation = CancellationToken::new();
+	cancellation.cancel();
+
+	let mut parser = Parser::new(TokenSource::new(text, &tokens), 0, Syntax::default())
+		.with_cancellation(cancellation);
+	crate::syntax::program::parse(&mut parser);
+	let (events, errors) = parser.finish();
+
+	assert!(errors.is_empty());
+	assert!(!events
+		.iter()
+		.any(|event| matches!(event, Event::Token { .. })));
+}
+
+#[test]
+pub fn cancellable_parse_with_syntax() {
+	let text = "let a: number = 1;";
+	let syntax = Syntax::default().typescript();
+	let cancellation = CancellationToken::new();
+	let parse = parse_cancellable(text, 0, syntax, &cancellation).unwrap();
+	assert!(parse.errors().is_empty());
+
+	cancellation.cancel();
+	assert_eq!(
+		Err(Cancelled),
+		parse_cancellable(text, 0, syntax, &cancellation).map(|_| ())
+	);
+}
+
+#[test]
+pub fn skipped_tokens_as_trivia() {
+	let text = "class A { ) foo() {} }\na; ) ] b;";
+	let with_unknown = parse(text, 0, Syntax::default());
+	let parse = parse(text, 0, Syntax::default().skipped_trivia());
+
+	assert_eq!(text, parse.syntax().to_string());
+	assert_eq!(with_unknown.errors(), parse.errors());
+	assert!(with_unknown
+		.syntax()
+		.descendants()
+		.any(|node| node.kind() == SyntaxKind::JS_UNKNOWN_STATEMENT));
+	assert!(!parse.syntax().descendants().any(|node| matches!(
+		node.kind(),
+		SyntaxKind::ERROR | SyntaxKind::JS_UNKNOWN_STATEMENT | SyntaxKind::JS_UNKNOWN_MEMBER
+	)));
+
+	let skipped = |name: &str| {
+		let token = parse
+			.syntax()
+			.descendants_tokens()
+			.find(|token| token.text_trimmed() == name)
+			.unwrap();
+		token
+			.leading_trivia()
+			.pieces()
+			.filter(|piece| piece.as_skipped().is_some())
+			.map(|piece| piece.text().to_string())
+			.collect::<Vec<_>>()
+	};
+	assert_eq!(vec![")"], skipped("foo"));
+	assert_eq!(vec![")", "]"], skipped("b"));
+}
+
+#[test]
+pub fn union_nodes_convert_from_nested_unions() {
+	let parse = parse_text("a = 1;", 0);
+	let literal = parse
+		.syntax()
+		.descendants()
+		.find_map(JsAnyLiteralExpression::cast)
+		.unwrap();
+
+	let expression = JsAnyExpression::from(literal.clone());
+	assert!(matches!(
+		expression,
+		JsAnyExpression::JsAnyLiteralExpression(_)
+	));
+
+	let syntax: SyntaxNode = expression.into();
+	assert_eq!(literal.syntax(), &syntax);
+}
+
+fn parse_with_unknown_node_context(text: &str) -> Vec<ParserError> {
+	let (tokens, _) = tokenize(text, 0);
+	let mut parser = Parser::new(
+		TokenSource::new(text, &tokens),
+		0,
+		Syntax::default().module().unknown_node_context(),
+	);
+	crate::syntax::program::parse(&mut parser);
+	parser.finish().1
+}
+
+fn while_parsing_notes(errors: &[ParserError]) -> Vec<&str> {
+	errors
+		.iter()
+		.flat_map(|err| err.footers.iter())
+		.map(|footer| footer.msg.as_str())
+		.filter(|msg| msg.starts_with("while parsing"))
+		.collect()
+}
+
+#[test]
+pub fn unknown_node_diagnostics_name_the_production() {
+	let errors = parse_with_unknown_node_context("class A { 5 + 3 }");
+	assert_eq!(
+		vec!["while parsing a class member"],
+		while_parsing_notes(&errors)
+	);
+
+	let errors = parse_with_unknown_node_context("let a = { /: 6 }");
+	assert_eq!(
+		vec!["while parsing an object member"],
+		while_parsing_notes(&errors)
+	);
+
+	let errors = parse_with_unknown_node_context("if (true)}");
+	assert_eq!(
+		vec!["while parsing a statement"],
+		while_parsing_notes(&errors)
+	);
+}
+
+#[test]
+pub fn unknown_node_context_is_opt_in() {
+	let text = "class A { 5 + 3 }";
+	let parse = parse_module(text, 0);
+	let errors = parse.errors();
+	assert!(!errors.is_empty());
+	assert!(while_parsing_notes(errors).is_empty());
+}
+
+#[test]
+pub fn standalone_entry_points() {
+	let text = "  a + b // sum\n";
+	let expr = parse_expression(text, 0, Syntax::default());
+	assert!(expr.errors().is_empty());
+	assert!(matches!(
+		expr.tree(),
+		JsAnyExpression::JsBinaryExpression(_)
+	));
+	assert_eq!(text, expr.syntax().to_string());
+
+	let text = "a b c";
+	let expr = parse_expression(text, 0, Syntax::default());
+	assert!(matches!(
+		expr.tree(),
+		JsAnyExpression::JsUnknownExpression(_)
+	));
+	assert_eq!(text, expr.syntax().to_string());
+	assert_eq!(
+		vec!["expected the end of the input, but found more tokens"],
+		expr.errors()
+			.iter()
+			.map(|e| e.title.as_str())
+			.collect::<Vec<_>>()
+	);
+
+	let stmt = parse_statement("let a: number = 1;", 0, Syntax::default().typescript());
+	assert!(stmt.errors().is_empty());
+	assert_eq!("let a: number = 1;", stmt.tree().syntax().to_string());
+	assert!(parse_statement("a; b;", 0, Syntax::default()).ok().is_err());
+
+	let ty = parse_type("{ a: string }[]", 0);
+	assert!(ty.errors().is_empty());
+	assert!(ty.try_tree().is_some());
+	assert!(parse_type("", 0).try_tree().is_none());
+}
+
+#[test]
+pub fn ecma_version_gating() {
+	let text = "class A { #a = 1 }\nconst b = a?.b ?? 2n ** 2n;\nc ||= `${d}`;";
+	assert!(parse(text, 0, Syntax::default().class_fields())
+		.errors()
+		.is_empty());
+
+	let errors = |version| {
+		parse(
+			text,
+			0,
+			Syntax::default().class_fields().ecma_version(version),
+		)
+		.errors()
+		.iter()
+		.map(|e| e.title.clone())
+		.collect::<Vec<_>>()
+	};
+	assert_eq!(
+		vec![
+			"a class field requires ES2022 or later, but the configured version is ES2021",
+			"a private class member requires ES2022 or later, but the configured version is ES2021"
+		],
+		errors(EcmaVersion::Es2021)
+	);
+	assert_eq!(
+		vec![
+			"a class field requires ES2022 or later, but the configured version is ES2019",
+			"a private class member requires ES2022 or later, but the configured version is ES2019",
+			"the nullish coalescing operator requires ES2020 or later, but the configured version is ES2019",
+			"optional chaining requires ES2020 or later, but the configured version is ES2019",
+			"a BigInt literal requires ES2020 or later, but the configured version is ES2019",
+			"a BigInt literal requires ES2020 or later, but the configured version is ES2019",
+			"a logical assignment operator requires ES2021 or later, but the configured version is ES2019",
+		],
+		errors(EcmaVersion::Es2019)
+	);
+	assert_eq!(11, errors(EcmaVersion::Es5).len());
+}
+
+#[test]
+pub fn ecma_version_is_not_checked_in_typescript_files() {
+	let text = "class A { #a = 1; static {} }
+const b = a?.b ?? 2n ** 2n;
+c ||= `${d}`;
+async function* e() { for await (const f of g) {} }
+try {} catch {}
+let { ...h } = { ...i };";
+	let errors = |syntax: Syntax| {
+		parse(text, 0, syntax.ecma_version(EcmaVersion::Es5))
+			.errors()
+			.iter()
+			.map(|e| e.title.clone())
+			.collect::<Vec<_>>()
+	};
+
+	assert!(!errors(Syntax::default().module()).is_empty());
+	assert_eq!(Vec::<String>::new(), errors(Syntax::default().typescript()));
+	assert_eq!(Vec::<String>::new(), errors(Syntax::default().tsx()));
+	assert_eq!(
+		Vec::<String>::new(),
+		errors(Syntax::new(FileKind::TypeScript))
+	);
+}
+
+#[test]
+pub fn ecma_version_boundaries() {
+	use EcmaVersion::*;
+
+	let versions = [
+		Es5, Es2015, Es2016, Es2017, Es2018, Es2019, Es2020, Es2021, Es2022,
+	];
+	let cases = [
+		("class A {}", Es2015, "a class"),
+		("() => {};", Es2015, "an arrow function"),
+		("`a`;", Es2015, "a template literal"),
+		("f(...a);", Es2015, "a spread element"),
+		("function f(...a) {}", Es2015, "a rest parameter"),
+		("var [...a] = b;", Es2015, "a rest pattern"),
+		("let a;", Es2015, "a lexical declaration"),
+		("function* f() {}", Es2015, "a generator"),
+		("for (a of b);", Es2015, "a for...of statement"),
+		("import a from \"a\";", Es2015, "an import declaration"),
+		("export {};", Es2015, "an export declaration"),
+		("2 ** 2;", Es2016, "the exponentiation operator"),
+		("async function f() {}", Es2017, "an async function"),
+		("({ ...a });", Es2018, "an object spread"),
+		("var { ...a } = b;", Es2018, "an object rest pattern"),
+		("async function* f() {}", Es2018, "an async generator"),
+		(
+			"async function f() { for await (a of b); }",
+			Es2018,
+			"a for await...of statement",
+		),
+		("try {} catch {}", Es2019, "an optional catch binding"),
+		("a?.b;", Es2020, "optional chaining"),
+		("a ?? b;", Es2020, "the nullish coalescing operator"),
+		("1n;", Es2020, "a BigInt literal"),
+		("import(\"a\");", Es2020, "a dynamic import"),
+		("import.meta;", Es2020, "`import.meta`"),
+		("a ||= b;", Es2021, "a logical assignment operator"),
+		("1_000;", Es2021, "a numeric separator"),
+		("class A { a = 1 }", Es2022, "a class field"),
+		("class A { #a() {} }", Es2022, "a private class member"),
+		(
+			"class A { static {} }",
+			Es2022,
+			"a static initialization block",
+		),
+	];
+
+	for (text, required, feature) in cases {
+		let errors = |version| {
+			parse(text, 0, Syntax::default().module().ecma_version(version))
+				.errors()
+				.iter()
+				.map(|e| e.title.clone())
+				.collect::<Vec<_>>()
+		};
+		let previous = versions[versions.iter().position(|v| *v == required).unwrap() - 1];
+
+		assert!(
+			errors(required).is_empty(),
+			"{}: {:?}",
+			text,
+			errors(required)
+		);
+		assert_eq!(
+			vec![format!(
+				"{} requires {} or later, but the configured version is {}",
+				feature,
+				required.name(),
+				previous.name()
+			)],
+			errors(previous),
+			"{}",
+			text
+		);
+	}
+}
+
+#[test]
+pub fn strict_mode_queries() {
+	let text =
+		"a;\nclass B { m() { b; } }\nfunction c(d) { 'use strict'; e; }\nfunction f() { g; }";
+	let strict_idents = |parse: Parse<JsRoot>| {
+		parse
+			.syntax()
+			.descendants_tokens()
+			.filter(|token| token.kind() == T![ident] && parse.is_strict(&token.parent().unwrap()))
+			.map(|token| token.text_trimmed().to_string())
+			.collect::<Vec<_>>()
+	};
+
+	assert_eq!(
+		vec!["B", "m", "b", "c", "d", "e"],
+		strict_idents(parse_text(text, 0))
+	);
+	assert_eq!(
+		vec!["a", "B", "m", "b", "c", "d", "e", "f", "g"],
+		strict_idents(parse_text(&format!("'use strict';\n{}", text), 0))
+	);
+	assert_eq!(9, strict_idents(parse_module(text, 0)).len());
+}
+
+#[test]
+pub fn full_reparse_reuses_unchanged_subtrees() {
+	let body = |parse: &Parse<JsRoot>| {
+		let function = parse
+			.syntax()
+			.descendants()
+			.find(|node| node.kind() == SyntaxKind::JS_FUNCTION_DECLARATION)
+			.unwrap();
+		function.last_child().unwrap()
+	};
+
+	let mut cache = rome_rowan::NodeCache::default();
+	let text = "function f(a) { a; a; a; a; }\nx;";
+	let old = crate::parse::parse_with_cache(text, 0, Syntax::default(), &mut cache, None);
+
+	let mut cache = rome_rowan::NodeCache::default();
+	let text = "function f(a) { a; a; a; a; }\ny;";
+	let new =
+		crate::parse::parse_with_cache(text, 0, Syntax::default(), &mut cache, Some(&old.syntax()));
+
+	assert_eq!("function f(a) { a; a; a; a; }\ny;", new.syntax().text());
+	assert!(body(&old).shares_green(&body(&new)));
+}
+
+#[cfg(feature = "arena")]
+#[test]
+pub fn parse_in_arena_builds_the_same_tree_as_parse() {
+	let arena = rome_rowan::arena::GreenArena::new();
+	let text = "/* a */ let a = 1;\nclass B { m() { return a + ; } }\n";
+	let syntax = Syntax::default().skipped_trivia();
+	let (root, errors) = crate::parse_in_arena(text, 0, syntax, &arena);
+	let parse = parse(text, 0, syntax);
+
+	assert_eq!(parse.errors().len(), errors.len());
+	let from_arena = root.to_syntax::<crate::JsLanguage>();
+	assert_eq!(
+		format!("{:#?}", parse.syntax()),
+		format!("{:#?}", from_arena)
+	);
+	assert_eq!(
+		parse
+			.syntax()
+			.descendants()
+			.flat_map(|node| node.errors())
+			.count(),
+		from_arena
+			.descendants()
+			.flat_map(|node| node.errors())
+			.count()
+	);
+}
+
+#[test]
+pub fn parser_errors_are_recorded_on_the_tree() {
+	let parse = parse_module("let a = ;\nlet b = 1;", 0);
+	assert_eq!(1, parse.errors().len());
+
+	let (node, error) = parse
+		.syntax()
+		.descendants()
+		.find_map(|node| Some((node.clone(), node.errors().next()?)))
+		.unwrap();
+	assert_eq!(parse.errors()[0].title, error.message());
+	assert!(node.text_range().contains_range(error.range()));
+	// The error is recorded on the innermost node that contains it
+	assert!(node
+		.children()
+		.all(|child| !child.text_range().contains_range(error.range())));
+	assert!(node
+		.ancestors()
+		.any(|node| node.kind() == SyntaxKind::JS_VARIABLE_DECLARATOR));
+}
+
+#[test]
+pub fn parse_chunks_matches_parse() {
+	let text = "#!/usr/bin/env node\nlet a = `ab ${ b /* c d */ } e`; // f g\r\nclass A { #b = 1 }\nc = a / 2 / ( /é f/g.test(\"h i\"));\n\"\\u{zz} j\" ?. 1 ...";
+	let expected = parse(text, 0, Syntax::default());
+	let check = |chunks: Vec<&str>| {
+		let parse = crate::parse_chunks(chunks.iter().copied(), 0, Syntax::default());
+		assert_eq!(
+			format!("{:#?}", expected.syntax()),
+			format!("{:#?}", parse.syntax()),
+			"{:?}",
+			chunks
+		);
+		assert_eq!(expected.errors(), parse.errors(), "{:?}", chunks);
+	};
+
+	let boundaries: Vec<_> = (0..=text.len())
+		.filter(|idx| text.is_char_boundary(*idx))
+		.collect();
+	for idx in &boundaries {
+		check(vec![&text[..*idx], &text[*idx..]]);
+	}
+	for size in 1..8 {
+		let mut chunks = vec![];
+		let mut start = 0;
+		for end in boundaries.iter().copied().step_by(size).skip(1) {
+			chunks.push(&text[start..end]);
+			start = end;
+		}
+		chunks.push(&text[start..]);
+		check(chunks);
+	}
+}
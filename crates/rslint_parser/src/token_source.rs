@@ -1,11 +1,97 @@
 use crate::{SyntaxKind::EOF, TextRange, TextSize, Token};
 use rslint_lexer::is_linebreak;
-use std::collections::HashSet;
+use std::{borrow::Cow, collections::HashSet, ops::Range};
+
+/// The text of a file, either contiguous or in pieces, e.g. the chunks of the rope of an editor buffer.
+///
+/// No token spans two pieces, the text of a token can always be borrowed from its piece.
+/// The text of a range that spans several pieces, e.g. of an expression, is copied.
+#[derive(Debug, Clone)]
+pub struct SourceText<'t> {
+	/// The offsets of the pieces and their text, sorted by offset
+	pieces: Vec<(TextSize, Cow<'t, str>)>,
+	len: TextSize,
+}
+
+impl<'t> SourceText<'t> {
+	pub(crate) fn new() -> Self {
+		Self {
+			pieces: vec![],
+			len: 0.into(),
+		}
+	}
+
+	/// Appends a piece to the end of the text
+	pub(crate) fn push(&mut self, piece: Cow<'t, str>) {
+		let len = TextSize::of(piece.as_ref());
+		self.pieces.push((self.len, piece));
+		self.len += len;
+	}
+
+	pub fn len(&self) -> TextSize {
+		self.len
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len == 0.into()
+	}
+
+	/// The text of `range` if it's inside of a single piece, which is always the case for the range of a token.
+	/// Returns `None` if the range is out of bounds or spans several pieces.
+	pub fn get(&self, range: Range<usize>) -> Option<&str> {
+		let idx = self
+			.pieces
+			.partition_point(|(start, _)| usize::from(*start) <= range.start)
+			.checked_sub(1)?;
+		let (start, piece) = &self.pieces[idx];
+		let start = usize::from(*start);
+		piece.get(range.start - start..range.end.checked_sub(start)?)
+	}
+
+	/// The text of `range`, which is only copied if it spans several pieces.
+	///
+	/// # Panics
+	/// Panics if the range is out of bounds
+	pub fn slice(&self, range: TextRange) -> Cow<'_, str> {
+		if let Some(text) = self.get(range.into()) {
+			return Cow::Borrowed(text);
+		}
+
+		assert!(range.end() <= self.len, "{:?} is out of bounds", range);
+		let mut text = String::with_capacity(range.len().into());
+		for (start, piece) in &self.pieces {
+			if let Some(piece_range) =
+				range.intersect(TextRange::at(*start, TextSize::of(piece.as_ref())))
+			{
+				text.push_str(&piece[piece_range - *start]);
+			}
+		}
+		Cow::Owned(text)
+	}
+}
+
+impl<'t> From<&'t str> for SourceText<'t> {
+	fn from(text: &'t str) -> Self {
+		let mut source = Self::new();
+		source.push(Cow::Borrowed(text));
+		source
+	}
+}
+
+impl std::ops::Index<TextRange> for SourceText<'_> {
+	type Output = str;
+
+	/// The text of a range inside of a single piece, see [`SourceText::get`]
+	fn index(&self, range: TextRange) -> &str {
+		self.get(range.into())
+			.unwrap_or_else(|| panic!("{:?} is out of bounds or spans several pieces", range))
+	}
+}
 
 /// The source of tokens for the parser
 #[derive(Clone)]
 pub struct TokenSource<'t> {
-	source: &'t str,
+	source: SourceText<'t>,
 	/// Hashset of offsets for tokens which occur after a linebreak.
 	/// This is required for things such as ASI and postfix expressions
 	tokens_after_linebreaks: HashSet<TextSize>,
@@ -55,7 +141,11 @@ impl<'t> TokenSource<'t> {
 	/// # Panics
 	/// This method will panic in case the source and raw tokens do not match
 	/// as it relies on the source code for checking if trivia contains linebreaks
-	pub fn new(source: &'t str, raw_tokens: &'t [rslint_lexer::Token]) -> TokenSource<'t> {
+	pub fn new(
+		source: impl Into<SourceText<'t>>,
+		raw_tokens: &'t [rslint_lexer::Token],
+	) -> TokenSource<'t> {
+		let source = source.into();
 		let mut tokens_after_linebreaks = HashSet::new();
 		let mut token_offset_pairs = Vec::with_capacity(raw_tokens.len() / 2);
 
@@ -107,8 +197,8 @@ impl<'t> TokenSource<'t> {
 		self.cur.0.to_owned()
 	}
 
-	pub fn source(&self) -> &str {
-		self.source
+	pub fn source(&self) -> &SourceText<'t> {
+		&self.source
 	}
 
 	pub fn lookahead_nth(&self, n: usize) -> Token {
@@ -124,6 +214,12 @@ impl<'t> TokenSource<'t> {
 		self.cur = (mk_token(pos, &self.token_offset_pairs), pos);
 	}
 
+	/// Ends the source at the current token, every following token is [EOF].
+	pub fn truncate(&mut self) {
+		self.token_offset_pairs.truncate(self.cur.1);
+		self.cur = (mk_token(self.cur.1, &self.token_offset_pairs), self.cur.1);
+	}
+
 	pub fn is_keyword(&self, kw: &str) -> bool {
 		self.token_offset_pairs
 			.get(self.cur.1)
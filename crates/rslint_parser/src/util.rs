@@ -1,5 +1,7 @@
 //! Extra utlities for untyped syntax nodes, syntax tokens, and AST nodes.
 
+pub mod quotes;
+
 use crate::*;
 
 pub use rslint_lexer::color;
@@ -282,6 +284,23 @@ pub trait SyntaxTokenExt {
 	fn in_ts(&self, set: TokenSet) -> bool {
 		set.contains(self.to_token().kind())
 	}
+
+	/// Compute the value of a string literal token by decoding its escape sequences,
+	/// the ranges of malformed escapes are absolute ranges inside the file.
+	fn string_value(&self) -> Option<Result<String, Vec<EscapeError>>> {
+		let token = self.to_token();
+		if token.kind() != SyntaxKind::JS_STRING_LITERAL {
+			return None;
+		}
+
+		let offset = token.text_trimmed_range().start();
+		Some(parse_js_string(token.text_trimmed()).map_err(|errors| {
+			errors
+				.into_iter()
+				.map(|error| error.with_offset(offset))
+				.collect()
+		}))
+	}
 }
 
 impl SyntaxTokenExt for SyntaxToken {
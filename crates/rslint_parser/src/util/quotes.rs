@@ -0,0 +1,250 @@
+//! Utilities to convert the raw text of string literal tokens between quote styles.
+
+use std::{borrow::Cow, iter::Peekable, str::Chars};
+
+/// The quotes delimiting a string or template literal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuoteStyle {
+	/// `"`
+	Double,
+	/// `'`
+	Single,
+	/// `` ` ``, converting to this style turns the string into a template literal
+	Backtick,
+}
+
+impl QuoteStyle {
+	pub fn as_char(&self) -> char {
+		match self {
+			QuoteStyle::Double => '"',
+			QuoteStyle::Single => '\'',
+			QuoteStyle::Backtick => '`',
+		}
+	}
+
+	/// Returns the quote style of a raw string literal, for example `'abcd'` returns [QuoteStyle::Single].
+	///
+	/// Returns [None] if the text isn't delimited by matching quotes.
+	pub fn of_literal(raw: &str) -> Option<QuoteStyle> {
+		let style = match raw.chars().next()? {
+			'"' => QuoteStyle::Double,
+			'\'' => QuoteStyle::Single,
+			'`' => QuoteStyle::Backtick,
+			_ => return None,
+		};
+
+		if raw.len() >= 2 && raw.ends_with(style.as_char()) {
+			Some(style)
+		} else {
+			None
+		}
+	}
+}
+
+/// Converts the raw text of a string literal token (including its quotes) to the given quote style.
+///
+/// Escapes are rewritten so that the value of the literal stays the same:
+/// - escaped quotes that no longer need escaping lose their backslash (`'it\'s'` -> `"it's"`)
+/// - quotes that now delimit the literal get escaped (`'say "hi"'` -> `"say \"hi\""`)
+/// - all other escape sequences (`\n`, `\\`, `\u{..}`, line continuations, ...) are preserved as is
+/// - converting to a template literal additionally escapes `` ` `` and `${`, and rewrites the legacy
+///   octal escapes (`\012`) as `\x0a` and `\8` and `\9` as `8` and `9`, because these escapes are
+///   syntax errors in templates
+///
+/// Returns the text unchanged if it isn't a `'` or `"` delimited string literal.
+///
+/// ```
+/// use rslint_parser::util::quotes::{convert_string_literal, QuoteStyle};
+///
+/// assert_eq!(convert_string_literal("'it\\'s'", QuoteStyle::Double), "\"it's\"");
+/// assert_eq!(convert_string_literal("'say \"hi\"'", QuoteStyle::Double), "\"say \\\"hi\\\"\"");
+/// assert_eq!(convert_string_literal("\"a\\nb\"", QuoteStyle::Single), "'a\\nb'");
+/// assert_eq!(convert_string_literal("'${a}`'", QuoteStyle::Backtick), "`\\${a}\\``");
+/// assert_eq!(convert_string_literal("'\\101\\8'", QuoteStyle::Backtick), "`\\x418`");
+/// ```
+pub fn convert_string_literal(raw: &str, to: QuoteStyle) -> Cow<'_, str> {
+	let from = match QuoteStyle::of_literal(raw) {
+		Some(QuoteStyle::Backtick) | None => return Cow::Borrowed(raw),
+		Some(from) if from == to => return Cow::Borrowed(raw),
+		Some(from) => from,
+	};
+
+	let inner = &raw[1..raw.len() - 1];
+	let target = to.as_char();
+	let mut result = String::with_capacity(raw.len() + 2);
+	result.push(target);
+
+	let mut chars = inner.chars().peekable();
+	while let Some(chr) = chars.next() {
+		match chr {
+			'\\' => match chars.next() {
+				// The old quote doesn't need to be escaped anymore, unless it's also used for the template
+				Some(escaped) if escaped == from.as_char() && escaped != target => {
+					result.push(escaped)
+				}
+				Some(escaped @ ('8' | '9')) if to == QuoteStyle::Backtick => result.push(escaped),
+				Some(escaped @ '0'..='7') if to == QuoteStyle::Backtick => {
+					push_octal_escape(&mut result, escaped, &mut chars)
+				}
+				Some(escaped) => {
+					result.push('\\');
+					result.push(escaped);
+				}
+				None => result.push('\\'),
+			},
+			chr if chr == target => {
+				result.push('\\');
+				result.push(chr);
+			}
+			'$' if to == QuoteStyle::Backtick && chars.peek() == Some(&'{') => {
+				result.push_str("\\$");
+			}
+			chr => result.push(chr),
+		}
+	}
+
+	result.push(target);
+	Cow::Owned(result)
+}
+
+/// Pushes the escape starting with the octal digit `first` as an escape that is valid in a template.
+/// `\0` not followed by a digit is the only octal escape allowed in templates, the legacy octal escapes
+/// are rewritten as hexadecimal escapes.
+fn push_octal_escape(result: &mut String, first: char, chars: &mut Peekable<Chars<'_>>) {
+	// `\8` and `\9` become digits, `\0\8` must not become `\08`
+	let mut rest = chars.clone();
+	let followed_by_digit = match rest.next() {
+		Some('0'..='9') => true,
+		Some('\\') => matches!(rest.next(), Some('8' | '9')),
+		_ => false,
+	};
+	if first == '0' && !followed_by_digit {
+		result.push_str("\\0");
+		return;
+	}
+
+	// `\0` to `\377`, the escapes starting with 4 to 7 have at most two digits
+	let max_len = if first <= '3' { 3 } else { 2 };
+	let mut value = first.to_digit(8).unwrap();
+	for _ in 1..max_len {
+		match chars.peek().and_then(|chr| chr.to_digit(8)) {
+			Some(digit) => {
+				value = value * 8 + digit;
+				chars.next();
+			}
+			None => break,
+		}
+	}
+	result.push_str(&format!("\\x{:02x}", value));
+}
+
+/// Returns the number of quotes in the raw text of a string literal that must be escaped
+/// when the literal is delimited by `quote`.
+fn escapes_needed(inner: &str, quote: char) -> usize {
+	let mut count = 0;
+	let mut chars = inner.chars();
+	while let Some(chr) = chars.next() {
+		match chr {
+			// The guard always consumes the escaped character: it's either an escaped quote,
+			// which still needs its escape, or any other escape that is preserved as is.
+			'\\' if chars.next() == Some(quote) => count += 1,
+			chr if chr == quote => count += 1,
+			_ => {}
+		}
+	}
+	count
+}
+
+/// Returns the quote style to use for a string literal: the `preferred` style, unless
+/// the other style requires fewer escaped quotes.
+///
+/// ```
+/// use rslint_parser::util::quotes::{preferred_quote_style, QuoteStyle};
+///
+/// assert_eq!(preferred_quote_style("'abcd'", QuoteStyle::Double), QuoteStyle::Double);
+/// assert_eq!(preferred_quote_style("'say \"hi\"'", QuoteStyle::Double), QuoteStyle::Single);
+/// ```
+pub fn preferred_quote_style(raw: &str, preferred: QuoteStyle) -> QuoteStyle {
+	let alternate = match preferred {
+		QuoteStyle::Double => QuoteStyle::Single,
+		QuoteStyle::Single => QuoteStyle::Double,
+		QuoteStyle::Backtick => return preferred,
+	};
+
+	let inner = match QuoteStyle::of_literal(raw) {
+		Some(QuoteStyle::Double) | Some(QuoteStyle::Single) => &raw[1..raw.len() - 1],
+		_ => return preferred,
+	};
+
+	if escapes_needed(inner, alternate.as_char()) < escapes_needed(inner, preferred.as_char()) {
+		alternate
+	} else {
+		preferred
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{convert_string_literal, QuoteStyle};
+
+	#[test]
+	fn same_quote_style_is_unchanged() {
+		assert_eq!(
+			convert_string_literal("'it\\'s'", QuoteStyle::Single),
+			"'it\\'s'"
+		);
+		assert_eq!(
+			convert_string_literal("not a string", QuoteStyle::Single),
+			"not a string"
+		);
+		assert_eq!(convert_string_literal("`tpl`", QuoteStyle::Single), "`tpl`");
+	}
+
+	#[test]
+	fn rewrites_quote_escapes() {
+		assert_eq!(
+			convert_string_literal("\"it's \\\"quoted\\\"\"", QuoteStyle::Single),
+			"'it\\'s \"quoted\"'"
+		);
+		assert_eq!(
+			convert_string_literal("'\\\\\\''", QuoteStyle::Double),
+			"\"\\\\'\""
+		);
+	}
+
+	#[test]
+	fn preserves_other_escapes() {
+		assert_eq!(
+			convert_string_literal("'a\\n\\t\\u{1F600}\\x41\\\nb'", QuoteStyle::Double),
+			"\"a\\n\\t\\u{1F600}\\x41\\\nb\""
+		);
+	}
+
+	#[test]
+	fn template_unsafe_characters() {
+		assert_eq!(
+			convert_string_literal("'a $b ${c} `d` \\'e\\''", QuoteStyle::Backtick),
+			"`a $b \\${c} \\`d\\` 'e'`"
+		);
+		assert_eq!(
+			convert_string_literal("\"\\`\"", QuoteStyle::Backtick),
+			"`\\``"
+		);
+	}
+
+	#[test]
+	fn template_legacy_octal_escapes() {
+		assert_eq!(
+			convert_string_literal(
+				"'\\0 \\00 \\08 \\7 \\101 \\477 \\3777 \\8\\9 \\0\\8'",
+				QuoteStyle::Backtick
+			),
+			"`\\0 \\x00 \\x008 \\x07 \\x41 \\x277 \\xff7 89 \\x008`"
+		);
+		// The escapes are allowed in strings
+		assert_eq!(
+			convert_string_literal("'\\101\\8'", QuoteStyle::Double),
+			"\"\\101\\8\""
+		);
+	}
+}
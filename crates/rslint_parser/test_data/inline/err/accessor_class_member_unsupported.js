@@ -0,0 +1,3 @@
+class A {
+  accessor a = 1;
+}
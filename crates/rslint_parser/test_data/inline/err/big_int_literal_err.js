@@ -0,0 +1,5 @@
+1.5n;
+.5n;
+1e10n;
+017n;
+08n;
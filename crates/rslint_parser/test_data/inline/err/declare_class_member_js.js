@@ -0,0 +1,4 @@
+class A {
+  declare a;
+  static declare b;
+}
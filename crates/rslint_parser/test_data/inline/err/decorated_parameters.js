@@ -0,0 +1,4 @@
+class A {
+  constructor(@inject a) {}
+  m(@required b) {}
+}
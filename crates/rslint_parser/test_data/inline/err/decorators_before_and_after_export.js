@@ -0,0 +1,2 @@
+// DECORATORS
+@sealed export @log class A {}
@@ -0,0 +1,2 @@
+if (a) @sealed export class A {}
+let b = @sealed export class {};
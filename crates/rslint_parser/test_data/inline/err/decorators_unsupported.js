@@ -0,0 +1,4 @@
+@sealed class A {
+  @log m() {}
+}
+let b = @(decorators[0]) @foo.bar() class {};
@@ -0,0 +1,3 @@
+export * as ns "mod";
+export * as ns;
+export * "mod";
@@ -0,0 +1,4 @@
+import a from "./foo.json" with { type: "json", type: "css" };
+import b from "./foo.json" with { type: json };
+import c from "./foo.json" with { 5: "json" };
+import d from "./foo.json" assert;
@@ -0,0 +1,6 @@
+let a = {
+  override foo() {},
+  override bar: 1,
+  override: 2,
+  override() {}
+}
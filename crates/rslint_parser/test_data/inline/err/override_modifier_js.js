@@ -0,0 +1 @@
+class A extends B { override a() {} }
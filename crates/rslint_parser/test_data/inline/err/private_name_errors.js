@@ -0,0 +1,20 @@
+class A {
+  #a;
+  #a() {}
+  get #b() {}
+  set #b(v) {}
+  get #b() {}
+  #constructor() {}
+  static get #f() {}
+  set #f(v) {}
+  m() {
+    this.#a;
+    this.#c;
+    class B {
+      #d;
+      n() { this.#a; this.#b; this.#d; this.#e; }
+    }
+    this.#d;
+  }
+}
+a.#g;
@@ -0,0 +1,5 @@
+// VALIDATE_REGEX
+let a = /(ab/;
+let b = /\p{Foo}/u;
+let c = /a{2,1}/;
+let d = /(a)[b]\1/gu;
@@ -0,0 +1,8 @@
+class A {
+  static {
+    return;
+  }
+  static {
+    break;
+  }
+}
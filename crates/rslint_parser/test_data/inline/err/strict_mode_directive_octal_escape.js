@@ -0,0 +1,2 @@
+function a() { "\07"; "use strict"; }
+function b() { "use strict"; "\08"; }
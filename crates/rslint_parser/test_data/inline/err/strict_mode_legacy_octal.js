@@ -0,0 +1,5 @@
+"use strict";
+010;
+08;
+let a = { 07: 1 };
+class A { b = 00 }
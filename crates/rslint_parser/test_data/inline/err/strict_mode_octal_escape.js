@@ -0,0 +1,5 @@
+"use strict";
+"\07";
+"a\1b\377\477";
+"\8\9";
+"\0 \\07";
@@ -0,0 +1,3 @@
+`\x4`;
+`${a}\u{110000}`;
+`\07 \8`;
@@ -0,0 +1,6 @@
+// TYPESCRIPT
+class A {
+  readonly accessor a;
+  accessor get b() {}
+  accessor c() {}
+}
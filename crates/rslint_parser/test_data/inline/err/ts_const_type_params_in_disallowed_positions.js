@@ -0,0 +1,3 @@
+// TYPESCRIPT
+interface A<const T> {}
+type B<T, const U> = U;
@@ -0,0 +1,5 @@
+// TYPESCRIPT
+class A { declare a = 1; }
+class B { declare get a() { return 1; } }
+abstract class C { declare abstract a: string; }
+class D { declare #a: string; }
@@ -0,0 +1,5 @@
+// TYPESCRIPT
+let a!: number = 1;
+let b!;
+class C { a!: number = 1; }
+class D { a!; }
@@ -0,0 +1,4 @@
+// TYPESCRIPT
+const enum A { B = foo(), C = "c".length, D = 1 + 1 }
+enum E { F, F, G, "G" }
+enum H { I = "i", J }
@@ -0,0 +1,2 @@
+// TYPESCRIPT
+f<string>.a;
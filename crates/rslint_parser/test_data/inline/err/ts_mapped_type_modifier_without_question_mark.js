@@ -0,0 +1,2 @@
+// TYPESCRIPT
+type A = { [K in T]-: K };
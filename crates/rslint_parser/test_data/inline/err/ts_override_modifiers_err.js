@@ -0,0 +1,8 @@
+// TYPESCRIPT
+class A extends B {
+  readonly override a;
+  override abstract b: number;
+  override public c() {}
+  override static d = 1;
+  override constructor() {}
+}
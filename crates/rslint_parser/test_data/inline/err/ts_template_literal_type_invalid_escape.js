@@ -0,0 +1,2 @@
+// TYPESCRIPT
+type A = `\x4${B}`;
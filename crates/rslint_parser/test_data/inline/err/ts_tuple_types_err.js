@@ -0,0 +1,5 @@
+// TYPESCRIPT
+type A = [...rest?: string[]];
+type B = [string?, number];
+type C = [...string[], ...number[]];
+type D = [...string[], number?];
@@ -0,0 +1,4 @@
+// TYPESCRIPT
+import type { type A } from "a";
+export type { type B };
+import type C, { D } from "c";
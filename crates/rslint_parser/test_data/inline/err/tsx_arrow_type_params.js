@@ -0,0 +1,2 @@
+// TSX
+let a = <T>(x: T) => x;
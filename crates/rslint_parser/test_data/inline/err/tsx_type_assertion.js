@@ -0,0 +1,3 @@
+// TSX
+let a = <T>b;
+let c = <const>[1];
@@ -0,0 +1,5 @@
+import type { A } from "a";
+import { type B, C } from "b";
+export type { D };
+export { type E };
+export type * from "f";
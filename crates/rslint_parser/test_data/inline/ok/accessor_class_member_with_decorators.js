@@ -0,0 +1,2 @@
+// DECORATORS
+class A { accessor a }
@@ -0,0 +1,10 @@
+// DECORATORS
+@sealed @(decorators[0]) class A {
+  @log m() {}
+  @foo.bar() get a() { return 1; }
+  @dec set a(v) {}
+  @observable field = 1;
+}
+export @dec class B {}
+export default @dec class {}
+let c = @dec class {};
@@ -0,0 +1,4 @@
+// DECORATORS
+@sealed export class A {}
+@sealed @log() export default class {}
+export @dec class B {}
@@ -0,0 +1,2 @@
+export * as ns from "mod";
+export * as default from "mod";
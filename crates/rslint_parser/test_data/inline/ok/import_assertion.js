@@ -0,0 +1,10 @@
+import json from "./foo.json" with { type: "json" };
+import "./foo.json" with { type: "json" };
+import { a } from "./foo.json" with { type: "json", "other": "value", };
+import b from "./foo.json" assert { type: "json" };
+import c from "./foo.json" with {};
+export * from "./foo.json" with { type: "json" };
+export * as ns from "./foo.json" with { type: "json" };
+export { a } from "./foo.json" with { type: "json" };
+import d from "./foo.json"
+assert({ type: "json" })
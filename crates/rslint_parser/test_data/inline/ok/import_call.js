@@ -1 +1,3 @@
 import("foo")
+import("foo", { with: { type: "json" } })
+import("foo", { assert: { type: "json" } },)
@@ -0,0 +1,5 @@
+// SCRIPT
+010;
+08;
+'\07';
+'\8';
@@ -0,0 +1,3 @@
+let a = /(ab/;
+let b = /\p{Foo}/u;
+let c = /a{2,1}/;
@@ -0,0 +1,7 @@
+class A {
+  static a;
+  static {
+    this.a = 1;
+  }
+  static {}
+}
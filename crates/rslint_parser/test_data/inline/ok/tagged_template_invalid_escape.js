@@ -0,0 +1,2 @@
+tag`\x4 \u{110000} \07`;
+tag`${a}\unicode`;
@@ -0,0 +1,5 @@
+// TYPESCRIPT
+type A = abstract new () => object;
+type B = abstract new <T>(x: T) => T;
+type C = { abstract new (): object; new (): object };
+let d: abstract new () => void;
@@ -0,0 +1,13 @@
+// TYPESCRIPT
+class A {
+  accessor a;
+  accessor b: number = 1;
+  static accessor c = 2;
+  public abstract accessor #d;
+  @dec accessor ["e"] = 3;
+  accessor;
+  accessor = 1;
+  accessor() {}
+  accessor
+  f = 1;
+}
@@ -0,0 +1,2 @@
+// TYPESCRIPT
+let a = <T>(x: T) => x;
@@ -0,0 +1,11 @@
+// TYPESCRIPT
+function a<const T>(x: T) {}
+class B<const T, const U extends string[] = []> {
+  method<const V>() {}
+}
+let c = <const T,>(x: T) => x;
+type D = <const T>() => T;
+interface E {
+  m<const T extends readonly unknown[]>(x: T): T;
+  new <const T>(): T;
+}
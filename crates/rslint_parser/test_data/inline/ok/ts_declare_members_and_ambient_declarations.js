@@ -0,0 +1,15 @@
+// TYPESCRIPT
+class A {
+  declare a: string;
+  static declare b: number;
+  declare private c?: string;
+  private declare: number;
+  declare() {}
+}
+declare class B {}
+declare abstract class C {}
+declare function d(): void;
+namespace E {
+  declare function f(): void;
+  export declare class G {}
+}
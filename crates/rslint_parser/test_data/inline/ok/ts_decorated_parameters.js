@@ -0,0 +1,5 @@
+// TYPESCRIPT
+class A {
+  constructor(@inject(TOKEN) private a: A, @optional b) {}
+  m(@required c) {}
+}
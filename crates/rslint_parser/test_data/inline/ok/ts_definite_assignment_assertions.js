@@ -0,0 +1,3 @@
+// TYPESCRIPT
+let a!: number, b: string;
+class A { c!: string; d?: number; }
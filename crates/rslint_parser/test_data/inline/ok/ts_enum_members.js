@@ -0,0 +1,3 @@
+// TYPESCRIPT
+const enum A { B = 1, C = B << 2, D = (C + 1) * -B, E = "e", F = A.B | A["C"], G }
+enum H { I = foo(), J = "j", K = 2, L }
@@ -0,0 +1,9 @@
+// TYPESCRIPT
+const a = f<number>;
+const b = f<number>
+g<string>(b);
+const c = f<string> as any;
+const d = f<string> == g;
+const e = h<string>(1).i;
+a<b>c;
+a < b > -c;
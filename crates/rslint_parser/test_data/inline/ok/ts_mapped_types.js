@@ -0,0 +1,4 @@
+// TYPESCRIPT
+type A = { [K in keyof T as `get${Capitalize<K & string>}`]: () => T[K] };
+type B = { -readonly [K in keyof T]-?: T[K] };
+type C = { +readonly [K in T]+?: K };
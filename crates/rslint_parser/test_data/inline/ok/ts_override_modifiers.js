@@ -0,0 +1,12 @@
+// TYPESCRIPT
+class A extends B {
+  override a = 1;
+  public override b() {}
+  protected static override readonly c: number;
+  abstract override d: string;
+  override get e() { return 1; }
+  override set e(v) {}
+  override accessor f = 1;
+  override() {}
+  override = 2;
+}
@@ -0,0 +1,4 @@
+// TYPESCRIPT
+type A = `a-${B}-c`;
+type D = `${Uppercase<E>}_${`nested-${F}`}`;
+type G = `${Capitalize<keyof H & string>}Changed`;
@@ -0,0 +1,4 @@
+// TYPESCRIPT
+type A = [head: string, tail?: number, ...rest: boolean[]];
+type B = [...string[], number];
+type C = [string, number?];
@@ -0,0 +1,3 @@
+// TYPESCRIPT
+let a = <T>b;
+let c = <const>[1];
@@ -0,0 +1,8 @@
+// TYPESCRIPT
+import type { A } from "a";
+import type B from "b";
+import { type C, D, type as, type as as E, type as F } from "c";
+export type { G };
+export { type H, I };
+export type * from "j";
+export * from "k";
@@ -0,0 +1,4 @@
+// TSX
+let a = <T,>(x: T) => x;
+let b = <T extends U>(x: T) => x;
+let c = <T = U>(x: T) => x;
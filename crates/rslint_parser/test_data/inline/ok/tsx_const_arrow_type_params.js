@@ -0,0 +1,2 @@
+// TSX
+let a = <const T>(x: T) => x;
@@ -119,6 +119,8 @@ pub enum SyntaxKind {
 	UNIQUE_KW,
 	DECLARE_KW,
 	ABSTRACT_KW,
+	ACCESSOR_KW,
+	OVERRIDE_KW,
 	STATIC_KW,
 	ASYNC_KW,
 	TYPE_KW,
@@ -151,6 +153,7 @@ pub enum SyntaxKind {
 	ERROR_TOKEN,
 	IDENT,
 	WHITESPACE,
+	NEWLINE,
 	COMMENT,
 	JS_SHEBANG,
 	JS_ROOT,
@@ -252,11 +255,15 @@ pub enum SyntaxKind {
 	JS_CONSTRUCTOR_PARAMETER_LIST,
 	JS_CONSTRUCTOR_PARAMETER,
 	JS_PROPERTY_CLASS_MEMBER,
+	JS_ACCESSOR_PROPERTY_CLASS_MEMBER,
 	JS_METHOD_CLASS_MEMBER,
 	JS_GETTER_CLASS_MEMBER,
 	JS_SETTER_CLASS_MEMBER,
 	JS_EMPTY_CLASS_MEMBER,
+	JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER,
 	IMPORT_DECL,
+	JS_IMPORT_ASSERTION,
+	JS_IMPORT_ASSERTION_ENTRY,
 	EXPORT_DECL,
 	EXPORT_NAMED,
 	EXPORT_DEFAULT_DECL,
@@ -287,7 +294,9 @@ pub enum SyntaxKind {
 	TS_LITERAL,
 	TS_PREDICATE,
 	TS_TUPLE,
-	TS_TUPLE_ELEMENT,
+	TS_NAMED_TUPLE_TYPE_ELEMENT,
+	TS_REST_TUPLE_TYPE_ELEMENT,
+	TS_OPTIONAL_TUPLE_TYPE_ELEMENT,
 	TS_PAREN,
 	TS_TYPE_REF,
 	TS_QUALIFIED_PATH,
@@ -296,6 +305,7 @@ pub enum SyntaxKind {
 	TS_TEMPLATE_ELEMENT,
 	TS_MAPPED_TYPE,
 	TS_MAPPED_TYPE_PARAM,
+	TS_MAPPED_TYPE_AS_CLAUSE,
 	TS_MAPPED_TYPE_READONLY,
 	TS_TYPE_QUERY,
 	TS_TYPE_QUERY_EXPR,
@@ -318,6 +328,7 @@ pub enum SyntaxKind {
 	TS_NON_NULL,
 	TS_ASSERTION,
 	TS_CONST_ASSERTION,
+	TS_INSTANTIATION_EXPR,
 	TS_ENUM,
 	TS_ENUM_MEMBER,
 	TS_TYPE_ALIAS_DECL,
@@ -340,6 +351,7 @@ pub enum SyntaxKind {
 	TS_EXPORT_ASSIGNMENT,
 	TS_NAMESPACE_EXPORT_DECL,
 	TS_DECORATOR,
+	TS_DECORATED_PARAMETER,
 	DEFAULT_CASE,
 	TS_INFER,
 	NULL,
@@ -371,10 +383,10 @@ impl SyntaxKind {
 			| PACKAGE_KW | PRIVATE_KW | PROTECTED_KW | PUBLIC_KW | RETURN_KW | SUPER_KW
 			| SWITCH_KW | THIS_KW | THROW_KW | TRY_KW | TRUE_KW | TYPEOF_KW | VAR_KW | VOID_KW
 			| WHILE_KW | WITH_KW | YIELD_KW | READONLY_KW | KEYOF_KW | UNIQUE_KW | DECLARE_KW
-			| ABSTRACT_KW | STATIC_KW | ASYNC_KW | TYPE_KW | FROM_KW | AS_KW | REQUIRE_KW
-			| NAMESPACE_KW | ASSERT_KW | MODULE_KW | GLOBAL_KW | INFER_KW | GET_KW | SET_KW
-			| OF_KW | TARGET_KW | NEVER_KW | UNKNOWN_KW | ANY_KW | UNDEFINED_KW | LET_KW
-			| FLOAT_KW | NUMBER_KW => true,
+			| ABSTRACT_KW | ACCESSOR_KW | OVERRIDE_KW | STATIC_KW | ASYNC_KW | TYPE_KW
+			| FROM_KW | AS_KW | REQUIRE_KW | NAMESPACE_KW | ASSERT_KW | MODULE_KW | GLOBAL_KW
+			| INFER_KW | GET_KW | SET_KW | OF_KW | TARGET_KW | NEVER_KW | UNKNOWN_KW | ANY_KW
+			| UNDEFINED_KW | LET_KW | FLOAT_KW | NUMBER_KW => true,
 			_ => false,
 		}
 	}
@@ -457,6 +469,8 @@ impl SyntaxKind {
 			"unique" => UNIQUE_KW,
 			"declare" => DECLARE_KW,
 			"abstract" => ABSTRACT_KW,
+			"accessor" => ACCESSOR_KW,
+			"override" => OVERRIDE_KW,
 			"static" => STATIC_KW,
 			"async" => ASYNC_KW,
 			"type" => TYPE_KW,
@@ -580,7 +594,15 @@ impl SyntaxKind {
 		};
 		Some(tok)
 	}
+	#[doc = r" The text of every punctuation token. The lexer picks the longest of them, which"]
+	#[doc = r" is why two punctuation tokens must be separated if their texts form another one."]
+	pub const PUNCTUATORS: &'static [&'static str] = &[
+		";", ",", "(", ")", "{", "}", "[", "]", "<", ">", "~", "?", "??", "?.", "&", "|", "+",
+		"++", "*", "**", "/", "^", "%", ".", "...", ":", "=", "==", "===", "=>", "!", "!=", "!==",
+		"-", "--", "<=", ">=", "+=", "-=", "|=", "&=", "^=", "/=", "*=", "%=", "&&", "||", "<<",
+		">>", ">>>", "<<=", ">>=", ">>>=", "&&=", "||=", "**=", "??=", "@", "`",
+	];
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [;] => { $ crate :: SyntaxKind :: SEMICOLON } ; [,] => { $ crate :: SyntaxKind :: COMMA } ; ['('] => { $ crate :: SyntaxKind :: L_PAREN } ; [')'] => { $ crate :: SyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: SyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: SyntaxKind :: R_CURLY } ; ['['] => { $ crate :: SyntaxKind :: L_BRACK } ; [']'] => { $ crate :: SyntaxKind :: R_BRACK } ; [<] => { $ crate :: SyntaxKind :: L_ANGLE } ; [>] => { $ crate :: SyntaxKind :: R_ANGLE } ; [~] => { $ crate :: SyntaxKind :: TILDE } ; [?] => { $ crate :: SyntaxKind :: QUESTION } ; [??] => { $ crate :: SyntaxKind :: QUESTION2 } ; [?.] => { $ crate :: SyntaxKind :: QUESTIONDOT } ; [&] => { $ crate :: SyntaxKind :: AMP } ; [|] => { $ crate :: SyntaxKind :: PIPE } ; [+] => { $ crate :: SyntaxKind :: PLUS } ; [++] => { $ crate :: SyntaxKind :: PLUS2 } ; [*] => { $ crate :: SyntaxKind :: STAR } ; [**] => { $ crate :: SyntaxKind :: STAR2 } ; [/] => { $ crate :: SyntaxKind :: SLASH } ; [^] => { $ crate :: SyntaxKind :: CARET } ; [%] => { $ crate :: SyntaxKind :: PERCENT } ; [.] => { $ crate :: SyntaxKind :: DOT } ; [...] => { $ crate :: SyntaxKind :: DOT2 } ; [:] => { $ crate :: SyntaxKind :: COLON } ; [=] => { $ crate :: SyntaxKind :: EQ } ; [==] => { $ crate :: SyntaxKind :: EQ2 } ; [===] => { $ crate :: SyntaxKind :: EQ3 } ; [=>] => { $ crate :: SyntaxKind :: FAT_ARROW } ; [!] => { $ crate :: SyntaxKind :: BANG } ; [!=] => { $ crate :: SyntaxKind :: NEQ } ; [!==] => { $ crate :: SyntaxKind :: NEQ2 } ; [-] => { $ crate :: SyntaxKind :: MINUS } ; [--] => { $ crate :: SyntaxKind :: MINUS2 } ; [<=] => { $ crate :: SyntaxKind :: LTEQ } ; [>=] => { $ crate :: SyntaxKind :: GTEQ } ; [+=] => { $ crate :: SyntaxKind :: PLUSEQ } ; [-=] => { $ crate :: SyntaxKind :: MINUSEQ } ; [|=] => { $ crate :: SyntaxKind :: PIPEEQ } ; [&=] => { $ crate :: SyntaxKind :: AMPEQ } ; [^=] => { $ crate :: SyntaxKind :: CARETEQ } ; [/=] => { $ crate :: SyntaxKind :: SLASHEQ } ; [*=] => { $ crate :: SyntaxKind :: STAREQ } ; [%=] => { $ crate :: SyntaxKind :: PERCENTEQ } ; [&&] => { $ crate :: SyntaxKind :: AMP2 } ; [||] => { $ crate :: SyntaxKind :: PIPE2 } ; [<<] => { $ crate :: SyntaxKind :: SHL } ; [>>] => { $ crate :: SyntaxKind :: SHR } ; [>>>] => { $ crate :: SyntaxKind :: USHR } ; [<<=] => { $ crate :: SyntaxKind :: SHLEQ } ; [>>=] => { $ crate :: SyntaxKind :: SHREQ } ; [>>>=] => { $ crate :: SyntaxKind :: USHREQ } ; [&&=] => { $ crate :: SyntaxKind :: AMP2EQ } ; [||=] => { $ crate :: SyntaxKind :: PIPE2EQ } ; [**=] => { $ crate :: SyntaxKind :: STAR2EQ } ; [??=] => { $ crate :: SyntaxKind :: QUESTION2EQ } ; [@] => { $ crate :: SyntaxKind :: AT } ; ['`'] => { $ crate :: SyntaxKind :: BACKTICK } ; [await] => { $ crate :: SyntaxKind :: AWAIT_KW } ; [break] => { $ crate :: SyntaxKind :: BREAK_KW } ; [case] => { $ crate :: SyntaxKind :: CASE_KW } ; [catch] => { $ crate :: SyntaxKind :: CATCH_KW } ; [class] => { $ crate :: SyntaxKind :: CLASS_KW } ; [const] => { $ crate :: SyntaxKind :: CONST_KW } ; [continue] => { $ crate :: SyntaxKind :: CONTINUE_KW } ; [debugger] => { $ crate :: SyntaxKind :: DEBUGGER_KW } ; [default] => { $ crate :: SyntaxKind :: DEFAULT_KW } ; [delete] => { $ crate :: SyntaxKind :: DELETE_KW } ; [do] => { $ crate :: SyntaxKind :: DO_KW } ; [else] => { $ crate :: SyntaxKind :: ELSE_KW } ; [enum] => { $ crate :: SyntaxKind :: ENUM_KW } ; [export] => { $ crate :: SyntaxKind :: EXPORT_KW } ; [extends] => { $ crate :: SyntaxKind :: EXTENDS_KW } ; [false] => { $ crate :: SyntaxKind :: FALSE_KW } ; [finally] => { $ crate :: SyntaxKind :: FINALLY_KW } ; [for] => { $ crate :: SyntaxKind :: FOR_KW } ; [function] => { $ crate :: SyntaxKind :: FUNCTION_KW } ; [if] => { $ crate :: SyntaxKind :: IF_KW } ; [in] => { $ crate :: SyntaxKind :: IN_KW } ; [instanceof] => { $ crate :: SyntaxKind :: INSTANCEOF_KW } ; [interface] => { $ crate :: SyntaxKind :: INTERFACE_KW } ; [import] => { $ crate :: SyntaxKind :: IMPORT_KW } ; [implements] => { $ crate :: SyntaxKind :: IMPLEMENTS_KW } ; [new] => { $ crate :: SyntaxKind :: NEW_KW } ; [null] => { $ crate :: SyntaxKind :: NULL_KW } ; [package] => { $ crate :: SyntaxKind :: PACKAGE_KW } ; [private] => { $ crate :: SyntaxKind :: PRIVATE_KW } ; [protected] => { $ crate :: SyntaxKind :: PROTECTED_KW } ; [public] => { $ crate :: SyntaxKind :: PUBLIC_KW } ; [return] => { $ crate :: SyntaxKind :: RETURN_KW } ; [super] => { $ crate :: SyntaxKind :: SUPER_KW } ; [switch] => { $ crate :: SyntaxKind :: SWITCH_KW } ; [this] => { $ crate :: SyntaxKind :: THIS_KW } ; [throw] => { $ crate :: SyntaxKind :: THROW_KW } ; [try] => { $ crate :: SyntaxKind :: TRY_KW } ; [true] => { $ crate :: SyntaxKind :: TRUE_KW } ; [typeof] => { $ crate :: SyntaxKind :: TYPEOF_KW } ; [var] => { $ crate :: SyntaxKind :: VAR_KW } ; [void] => { $ crate :: SyntaxKind :: VOID_KW } ; [while] => { $ crate :: SyntaxKind :: WHILE_KW } ; [with] => { $ crate :: SyntaxKind :: WITH_KW } ; [yield] => { $ crate :: SyntaxKind :: YIELD_KW } ; [readonly] => { $ crate :: SyntaxKind :: READONLY_KW } ; [keyof] => { $ crate :: SyntaxKind :: KEYOF_KW } ; [unique] => { $ crate :: SyntaxKind :: UNIQUE_KW } ; [declare] => { $ crate :: SyntaxKind :: DECLARE_KW } ; [abstract] => { $ crate :: SyntaxKind :: ABSTRACT_KW } ; [static] => { $ crate :: SyntaxKind :: STATIC_KW } ; [async] => { $ crate :: SyntaxKind :: ASYNC_KW } ; [type] => { $ crate :: SyntaxKind :: TYPE_KW } ; [from] => { $ crate :: SyntaxKind :: FROM_KW } ; [as] => { $ crate :: SyntaxKind :: AS_KW } ; [require] => { $ crate :: SyntaxKind :: REQUIRE_KW } ; [namespace] => { $ crate :: SyntaxKind :: NAMESPACE_KW } ; [assert] => { $ crate :: SyntaxKind :: ASSERT_KW } ; [module] => { $ crate :: SyntaxKind :: MODULE_KW } ; [global] => { $ crate :: SyntaxKind :: GLOBAL_KW } ; [infer] => { $ crate :: SyntaxKind :: INFER_KW } ; [get] => { $ crate :: SyntaxKind :: GET_KW } ; [set] => { $ crate :: SyntaxKind :: SET_KW } ; [of] => { $ crate :: SyntaxKind :: OF_KW } ; [target] => { $ crate :: SyntaxKind :: TARGET_KW } ; [never] => { $ crate :: SyntaxKind :: NEVER_KW } ; [unknown] => { $ crate :: SyntaxKind :: UNKNOWN_KW } ; [any] => { $ crate :: SyntaxKind :: ANY_KW } ; [undefined] => { $ crate :: SyntaxKind :: UNDEFINED_KW } ; [let] => { $ crate :: SyntaxKind :: LET_KW } ; [float] => { $ crate :: SyntaxKind :: FLOAT_KW } ; [number] => { $ crate :: SyntaxKind :: NUMBER_KW } ; [ident] => { $ crate :: SyntaxKind :: IDENT } ; [js_shebang] => { $ crate :: SyntaxKind :: JS_SHEBANG } ; [js_string_literal] => { $ crate :: SyntaxKind :: JS_STRING_LITERAL } ; [js_number_literal] => { $ crate :: SyntaxKind :: JS_NUMBER_LITERAL } ; [js_big_int_literal] => { $ crate :: SyntaxKind :: JS_BIG_INT_LITERAL } ; [js_regex_literal] => { $ crate :: SyntaxKind :: JS_REGEX_LITERAL } ; [#] => { $ crate :: SyntaxKind :: HASH } ; }
+macro_rules ! T { [;] => { $ crate :: SyntaxKind :: SEMICOLON } ; [,] => { $ crate :: SyntaxKind :: COMMA } ; ['('] => { $ crate :: SyntaxKind :: L_PAREN } ; [')'] => { $ crate :: SyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: SyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: SyntaxKind :: R_CURLY } ; ['['] => { $ crate :: SyntaxKind :: L_BRACK } ; [']'] => { $ crate :: SyntaxKind :: R_BRACK } ; [<] => { $ crate :: SyntaxKind :: L_ANGLE } ; [>] => { $ crate :: SyntaxKind :: R_ANGLE } ; [~] => { $ crate :: SyntaxKind :: TILDE } ; [?] => { $ crate :: SyntaxKind :: QUESTION } ; [??] => { $ crate :: SyntaxKind :: QUESTION2 } ; [?.] => { $ crate :: SyntaxKind :: QUESTIONDOT } ; [&] => { $ crate :: SyntaxKind :: AMP } ; [|] => { $ crate :: SyntaxKind :: PIPE } ; [+] => { $ crate :: SyntaxKind :: PLUS } ; [++] => { $ crate :: SyntaxKind :: PLUS2 } ; [*] => { $ crate :: SyntaxKind :: STAR } ; [**] => { $ crate :: SyntaxKind :: STAR2 } ; [/] => { $ crate :: SyntaxKind :: SLASH } ; [^] => { $ crate :: SyntaxKind :: CARET } ; [%] => { $ crate :: SyntaxKind :: PERCENT } ; [.] => { $ crate :: SyntaxKind :: DOT } ; [...] => { $ crate :: SyntaxKind :: DOT2 } ; [:] => { $ crate :: SyntaxKind :: COLON } ; [=] => { $ crate :: SyntaxKind :: EQ } ; [==] => { $ crate :: SyntaxKind :: EQ2 } ; [===] => { $ crate :: SyntaxKind :: EQ3 } ; [=>] => { $ crate :: SyntaxKind :: FAT_ARROW } ; [!] => { $ crate :: SyntaxKind :: BANG } ; [!=] => { $ crate :: SyntaxKind :: NEQ } ; [!==] => { $ crate :: SyntaxKind :: NEQ2 } ; [-] => { $ crate :: SyntaxKind :: MINUS } ; [--] => { $ crate :: SyntaxKind :: MINUS2 } ; [<=] => { $ crate :: SyntaxKind :: LTEQ } ; [>=] => { $ crate :: SyntaxKind :: GTEQ } ; [+=] => { $ crate :: SyntaxKind :: PLUSEQ } ; [-=] => { $ crate :: SyntaxKind :: MINUSEQ } ; [|=] => { $ crate :: SyntaxKind :: PIPEEQ } ; [&=] => { $ crate :: SyntaxKind :: AMPEQ } ; [^=] => { $ crate :: SyntaxKind :: CARETEQ } ; [/=] => { $ crate :: SyntaxKind :: SLASHEQ } ; [*=] => { $ crate :: SyntaxKind :: STAREQ } ; [%=] => { $ crate :: SyntaxKind :: PERCENTEQ } ; [&&] => { $ crate :: SyntaxKind :: AMP2 } ; [||] => { $ crate :: SyntaxKind :: PIPE2 } ; [<<] => { $ crate :: SyntaxKind :: SHL } ; [>>] => { $ crate :: SyntaxKind :: SHR } ; [>>>] => { $ crate :: SyntaxKind :: USHR } ; [<<=] => { $ crate :: SyntaxKind :: SHLEQ } ; [>>=] => { $ crate :: SyntaxKind :: SHREQ } ; [>>>=] => { $ crate :: SyntaxKind :: USHREQ } ; [&&=] => { $ crate :: SyntaxKind :: AMP2EQ } ; [||=] => { $ crate :: SyntaxKind :: PIPE2EQ } ; [**=] => { $ crate :: SyntaxKind :: STAR2EQ } ; [??=] => { $ crate :: SyntaxKind :: QUESTION2EQ } ; [@] => { $ crate :: SyntaxKind :: AT } ; ['`'] => { $ crate :: SyntaxKind :: BACKTICK } ; [await] => { $ crate :: SyntaxKind :: AWAIT_KW } ; [break] => { $ crate :: SyntaxKind :: BREAK_KW } ; [case] => { $ crate :: SyntaxKind :: CASE_KW } ; [catch] => { $ crate :: SyntaxKind :: CATCH_KW } ; [class] => { $ crate :: SyntaxKind :: CLASS_KW } ; [const] => { $ crate :: SyntaxKind :: CONST_KW } ; [continue] => { $ crate :: SyntaxKind :: CONTINUE_KW } ; [debugger] => { $ crate :: SyntaxKind :: DEBUGGER_KW } ; [default] => { $ crate :: SyntaxKind :: DEFAULT_KW } ; [delete] => { $ crate :: SyntaxKind :: DELETE_KW } ; [do] => { $ crate :: SyntaxKind :: DO_KW } ; [else] => { $ crate :: SyntaxKind :: ELSE_KW } ; [enum] => { $ crate :: SyntaxKind :: ENUM_KW } ; [export] => { $ crate :: SyntaxKind :: EXPORT_KW } ; [extends] => { $ crate :: SyntaxKind :: EXTENDS_KW } ; [false] => { $ crate :: SyntaxKind :: FALSE_KW } ; [finally] => { $ crate :: SyntaxKind :: FINALLY_KW } ; [for] => { $ crate :: SyntaxKind :: FOR_KW } ; [function] => { $ crate :: SyntaxKind :: FUNCTION_KW } ; [if] => { $ crate :: SyntaxKind :: IF_KW } ; [in] => { $ crate :: SyntaxKind :: IN_KW } ; [instanceof] => { $ crate :: SyntaxKind :: INSTANCEOF_KW } ; [interface] => { $ crate :: SyntaxKind :: INTERFACE_KW } ; [import] => { $ crate :: SyntaxKind :: IMPORT_KW } ; [implements] => { $ crate :: SyntaxKind :: IMPLEMENTS_KW } ; [new] => { $ crate :: SyntaxKind :: NEW_KW } ; [null] => { $ crate :: SyntaxKind :: NULL_KW } ; [package] => { $ crate :: SyntaxKind :: PACKAGE_KW } ; [private] => { $ crate :: SyntaxKind :: PRIVATE_KW } ; [protected] => { $ crate :: SyntaxKind :: PROTECTED_KW } ; [public] => { $ crate :: SyntaxKind :: PUBLIC_KW } ; [return] => { $ crate :: SyntaxKind :: RETURN_KW } ; [super] => { $ crate :: SyntaxKind :: SUPER_KW } ; [switch] => { $ crate :: SyntaxKind :: SWITCH_KW } ; [this] => { $ crate :: SyntaxKind :: THIS_KW } ; [throw] => { $ crate :: SyntaxKind :: THROW_KW } ; [try] => { $ crate :: SyntaxKind :: TRY_KW } ; [true] => { $ crate :: SyntaxKind :: TRUE_KW } ; [typeof] => { $ crate :: SyntaxKind :: TYPEOF_KW } ; [var] => { $ crate :: SyntaxKind :: VAR_KW } ; [void] => { $ crate :: SyntaxKind :: VOID_KW } ; [while] => { $ crate :: SyntaxKind :: WHILE_KW } ; [with] => { $ crate :: SyntaxKind :: WITH_KW } ; [yield] => { $ crate :: SyntaxKind :: YIELD_KW } ; [readonly] => { $ crate :: SyntaxKind :: READONLY_KW } ; [keyof] => { $ crate :: SyntaxKind :: KEYOF_KW } ; [unique] => { $ crate :: SyntaxKind :: UNIQUE_KW } ; [declare] => { $ crate :: SyntaxKind :: DECLARE_KW } ; [abstract] => { $ crate :: SyntaxKind :: ABSTRACT_KW } ; [accessor] => { $ crate :: SyntaxKind :: ACCESSOR_KW } ; [override] => { $ crate :: SyntaxKind :: OVERRIDE_KW } ; [static] => { $ crate :: SyntaxKind :: STATIC_KW } ; [async] => { $ crate :: SyntaxKind :: ASYNC_KW } ; [type] => { $ crate :: SyntaxKind :: TYPE_KW } ; [from] => { $ crate :: SyntaxKind :: FROM_KW } ; [as] => { $ crate :: SyntaxKind :: AS_KW } ; [require] => { $ crate :: SyntaxKind :: REQUIRE_KW } ; [namespace] => { $ crate :: SyntaxKind :: NAMESPACE_KW } ; [assert] => { $ crate :: SyntaxKind :: ASSERT_KW } ; [module] => { $ crate :: SyntaxKind :: MODULE_KW } ; [global] => { $ crate :: SyntaxKind :: GLOBAL_KW } ; [infer] => { $ crate :: SyntaxKind :: INFER_KW } ; [get] => { $ crate :: SyntaxKind :: GET_KW } ; [set] => { $ crate :: SyntaxKind :: SET_KW } ; [of] => { $ crate :: SyntaxKind :: OF_KW } ; [target] => { $ crate :: SyntaxKind :: TARGET_KW } ; [never] => { $ crate :: SyntaxKind :: NEVER_KW } ; [unknown] => { $ crate :: SyntaxKind :: UNKNOWN_KW } ; [any] => { $ crate :: SyntaxKind :: ANY_KW } ; [undefined] => { $ crate :: SyntaxKind :: UNDEFINED_KW } ; [let] => { $ crate :: SyntaxKind :: LET_KW } ; [float] => { $ crate :: SyntaxKind :: FLOAT_KW } ; [number] => { $ crate :: SyntaxKind :: NUMBER_KW } ; [ident] => { $ crate :: SyntaxKind :: IDENT } ; [js_shebang] => { $ crate :: SyntaxKind :: JS_SHEBANG } ; [js_string_literal] => { $ crate :: SyntaxKind :: JS_STRING_LITERAL } ; [js_number_literal] => { $ crate :: SyntaxKind :: JS_NUMBER_LITERAL } ; [js_big_int_literal] => { $ crate :: SyntaxKind :: JS_BIG_INT_LITERAL } ; [js_regex_literal] => { $ crate :: SyntaxKind :: JS_REGEX_LITERAL } ; [#] => { $ crate :: SyntaxKind :: HASH } ; }
@@ -22,6 +22,9 @@ impl From<SyntaxKind> for u16 {
 
 impl SyntaxKind {
 	pub fn is_trivia(self) -> bool {
-		matches!(self, SyntaxKind::WHITESPACE | SyntaxKind::COMMENT)
+		matches!(
+			self,
+			SyntaxKind::WHITESPACE | SyntaxKind::NEWLINE | SyntaxKind::COMMENT
+		)
 	}
 }
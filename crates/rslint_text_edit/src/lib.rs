@@ -142,6 +142,240 @@ impl TextEdit {
 		}
 		Some(res)
 	}
+
+	/// Returns an edit that has the same effect as applying this edit and then `other`, where the
+	/// offsets of `other` refer to the text after applying this edit.
+	///
+	/// ```
+	/// use rslint_text_edit::{TextEdit, TextRange};
+	///
+	/// let mut text = String::from("let a = 1;");
+	/// let rename = TextEdit::replace(TextRange::new(4.into(), 5.into()), String::from("value"));
+	/// let semicolon = TextEdit::delete(TextRange::new(13.into(), 14.into()));
+	///
+	/// rename.compose(&semicolon).apply(&mut text);
+	/// assert_eq!("let value = 1", text);
+	/// ```
+	pub fn compose(&self, other: &TextEdit) -> TextEdit {
+		// The text after applying this edit, as pieces of the original text and inserted text,
+		// up to the end of the last indel of either edit
+		let mut pieces = Vec::new();
+		let mut original_end = TextSize::from(0);
+		for indel in &self.indels {
+			if indel.delete.start() > original_end {
+				pieces.push(Piece::Original(TextRange::new(
+					original_end,
+					indel.delete.start(),
+				)));
+			}
+			if !indel.insert.is_empty() {
+				pieces.push(Piece::Inserted(&indel.insert));
+			}
+			original_end = indel.delete.end();
+		}
+
+		let edited_end: TextSize = pieces.iter().map(Piece::len).sum();
+		let other_end = other
+			.indels
+			.last()
+			.map_or_else(TextSize::default, |indel| indel.delete.end());
+		if other_end > edited_end {
+			let end = original_end + (other_end - edited_end);
+			pieces.push(Piece::Original(TextRange::new(original_end, end)));
+			original_end = end;
+		}
+
+		// Applies `other` to the pieces
+		let mut cursor = PieceCursor {
+			pieces: pieces.into_iter(),
+			front: None,
+		};
+		let mut composed = Vec::new();
+		let mut position = TextSize::from(0);
+		for indel in &other.indels {
+			cursor.take(indel.delete.start() - position, Some(&mut composed));
+			cursor.take(indel.delete.len(), None);
+			if !indel.insert.is_empty() {
+				composed.push(Piece::Inserted(&indel.insert));
+			}
+			position = indel.delete.end();
+		}
+		composed.extend(cursor.front.take());
+		composed.extend(cursor.pieces);
+
+		// Every gap between the original pieces is a deletion, replaced by the text inserted in between
+		let mut indels = Vec::new();
+		let mut deleted_start = TextSize::from(0);
+		let mut inserted = String::new();
+		for piece in composed {
+			match piece {
+				Piece::Original(range) => {
+					if range.start() > deleted_start || !inserted.is_empty() {
+						indels.push(Indel::replace(
+							TextRange::new(deleted_start, range.start()),
+							std::mem::take(&mut inserted),
+						));
+					}
+					deleted_start = range.end();
+				}
+				Piece::Inserted(text) => inserted.push_str(text),
+			}
+		}
+		if original_end > deleted_start || !inserted.is_empty() {
+			indels.push(Indel::replace(
+				TextRange::new(deleted_start, original_end),
+				inserted,
+			));
+		}
+
+		TextEdit { indels }
+	}
+
+	/// Maps a range of the original text to the range of the same text after applying this edit.
+	/// The mapped range covers everything that replaced the original range: it includes the text
+	/// inserted at its boundaries and the replacements of indels that overlap it.
+	///
+	/// ```
+	/// use rslint_text_edit::{TextEdit, TextRange};
+	///
+	/// let edit = TextEdit::insert(0.into(), String::from("const "));
+	/// let name = TextRange::new(4.into(), 5.into());
+	/// assert_eq!(TextRange::new(10.into(), 11.into()), edit.map_range_forward(name));
+	/// ```
+	pub fn map_range_forward(&self, range: TextRange) -> TextRange {
+		TextRange::new(
+			map_offset(self.replaced_ranges(), range.start(), Bias::Start),
+			map_offset(self.replaced_ranges(), range.end(), Bias::End),
+		)
+	}
+
+	/// Maps a range of the text after applying this edit back to the range of the original text,
+	/// the inverse of [TextEdit::map_range_forward]. A range inside inserted text maps to the
+	/// range that the text replaced.
+	pub fn map_range_backward(&self, range: TextRange) -> TextRange {
+		let inserted_ranges = || {
+			self.replaced_ranges()
+				.map(|(deleted, inserted)| (inserted, deleted))
+		};
+		TextRange::new(
+			map_offset(inserted_ranges(), range.start(), Bias::Start),
+			map_offset(inserted_ranges(), range.end(), Bias::End),
+		)
+	}
+
+	/// Returns the range that every indel deletes in the original text together with the range of
+	/// its inserted text in the edited text
+	fn replaced_ranges(&self) -> impl Iterator<Item = (TextRange, TextRange)> + '_ {
+		let mut deleted = TextSize::from(0);
+		let mut inserted = TextSize::from(0);
+		self.indels.iter().map(move |indel| {
+			let start = indel.delete.start() - deleted + inserted;
+			deleted += indel.delete.len();
+			inserted += TextSize::of(&indel.insert);
+			(
+				indel.delete,
+				TextRange::at(start, TextSize::of(&indel.insert)),
+			)
+		})
+	}
+}
+
+/// A part of the text after applying an edit, see [TextEdit::compose]
+#[derive(Debug, Clone, Copy)]
+enum Piece<'a> {
+	/// Text that is unchanged from the original text
+	Original(TextRange),
+	Inserted(&'a str),
+}
+
+impl Piece<'_> {
+	fn len(&self) -> TextSize {
+		match self {
+			Piece::Original(range) => range.len(),
+			Piece::Inserted(text) => TextSize::of(*text),
+		}
+	}
+
+	fn split_at(self, offset: TextSize) -> (Self, Self) {
+		match self {
+			Piece::Original(range) => {
+				let middle = range.start() + offset;
+				(
+					Piece::Original(TextRange::new(range.start(), middle)),
+					Piece::Original(TextRange::new(middle, range.end())),
+				)
+			}
+			Piece::Inserted(text) => {
+				let (head, tail) = text.split_at(offset.into());
+				(Piece::Inserted(head), Piece::Inserted(tail))
+			}
+		}
+	}
+}
+
+struct PieceCursor<'a> {
+	pieces: std::vec::IntoIter<Piece<'a>>,
+	/// The rest of a piece that was split
+	front: Option<Piece<'a>>,
+}
+
+impl<'a> PieceCursor<'a> {
+	/// Advances the cursor by `len` bytes and appends the passed pieces to `out`, if any
+	fn take(&mut self, mut len: TextSize, mut out: Option<&mut Vec<Piece<'a>>>) {
+		while len > TextSize::from(0) {
+			let piece = self
+				.front
+				.take()
+				.or_else(|| self.pieces.next())
+				.expect("indel outside of the text");
+			let piece = if piece.len() > len {
+				let (head, tail) = piece.split_at(len);
+				self.front = Some(tail);
+				head
+			} else {
+				piece
+			};
+
+			len -= piece.len();
+			if let Some(out) = out.as_mut() {
+				out.push(piece);
+			}
+		}
+	}
+}
+
+/// Which boundary of a replacement an offset inside the replaced text maps to
+#[derive(Debug, Clone, Copy)]
+enum Bias {
+	Start,
+	End,
+}
+
+/// Maps `offset` from the `from` ranges to the `to` ranges, which must be sorted
+fn map_offset(
+	ranges: impl Iterator<Item = (TextRange, TextRange)>,
+	offset: TextSize,
+	bias: Bias,
+) -> TextSize {
+	let mut from_anchor = TextSize::from(0);
+	let mut to_anchor = TextSize::from(0);
+	for (from, to) in ranges {
+		if offset < from.start() || (offset == from.start() && !from.is_empty()) {
+			break;
+		}
+		if offset > from.end() || (offset == from.end() && !from.is_empty()) {
+			from_anchor = from.end();
+			to_anchor = to.end();
+			continue;
+		}
+
+		return match bias {
+			Bias::Start => to.start(),
+			Bias::End => to.end(),
+		};
+	}
+
+	offset - from_anchor + to_anchor
 }
 
 impl IntoIterator for TextEdit {
@@ -200,3 +434,67 @@ fn check_disjoint(indels: &mut [impl std::borrow::Borrow<Indel>]) -> bool {
 		.zip(indels.iter().skip(1))
 		.all(|(l, r)| l.borrow().delete.end() <= r.borrow().delete.start())
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{TextEdit, TextRange, TextSize};
+
+	fn range(start: u32, end: u32) -> TextRange {
+		TextRange::new(TextSize::from(start), TextSize::from(end))
+	}
+
+	fn edit(indels: &[(u32, u32, &str)]) -> TextEdit {
+		let mut builder = TextEdit::builder();
+		for (start, end, text) in indels {
+			builder.replace(range(*start, *end), text.to_string());
+		}
+		builder.finish()
+	}
+
+	#[test]
+	fn compose_matches_sequential_application() {
+		let text = "function f(a, b) { return a + b; }";
+		let cases = [
+			(
+				edit(&[(9, 10, "add"), (17, 18, "{\n")]),
+				edit(&[(0, 8, "const"), (15, 17, ""), (20, 26, "")]),
+			),
+			(edit(&[(0, 0, "/* a */ ")]), edit(&[(3, 10, "b")])),
+			(
+				edit(&[(11, 12, "first")]),
+				edit(&[(14, 15, ""), (16, 17, "second"), (38, 38, "\n")]),
+			),
+			(edit(&[(29, 34, "")]), edit(&[(28, 29, "}")])),
+			(TextEdit::default(), edit(&[(2, 4, "xy")])),
+			(edit(&[(2, 4, "xy")]), TextEdit::default()),
+		];
+
+		for (first, second) in cases.iter() {
+			let mut expected = text.to_string();
+			first.apply(&mut expected);
+			second.apply(&mut expected);
+
+			let mut composed = text.to_string();
+			first.compose(second).apply(&mut composed);
+			assert_eq!(expected, composed, "{:?} {:?}", first, second);
+		}
+	}
+
+	#[test]
+	fn map_ranges() {
+		// "let a = 1;" -> "let value = 10;"
+		let edit = edit(&[(4, 5, "value"), (9, 9, "0")]);
+
+		assert_eq!(range(0, 3), edit.map_range_forward(range(0, 3)));
+		assert_eq!(range(4, 9), edit.map_range_forward(range(4, 5)));
+		assert_eq!(range(12, 15), edit.map_range_forward(range(8, 10)));
+		assert_eq!(range(4, 4), edit.map_range_forward(range(4, 4)));
+		// Includes the text inserted at the empty range
+		assert_eq!(range(13, 14), edit.map_range_forward(range(9, 9)));
+
+		assert_eq!(range(4, 5), edit.map_range_backward(range(4, 9)));
+		assert_eq!(range(4, 5), edit.map_range_backward(range(6, 7)));
+		assert_eq!(range(8, 10), edit.map_range_backward(range(12, 15)));
+		assert_eq!(range(9, 9), edit.map_range_backward(range(13, 14)));
+	}
+}
@@ -15,6 +15,9 @@ struct Variables {
 	test_name: String,
 	test_full_path: String,
 	test_expected_fullpath: String,
+	/// The path of a platform specific expected file without the platform name, e.g. `test.expected.` and `.js`
+	/// for `test.expected.windows.js`
+	test_platform_expected_fullpath: (String, String),
 }
 
 struct AllFiles(GlobWalker);
@@ -70,7 +73,7 @@ impl Arguments {
 		let file_stem = file_stem.to_str()?;
 		let test_name = file_stem.to_snake();
 
-		let test_full_path = path.display().to_string();
+		let test_full_path = normalize_path(path);
 
 		let extension = match path.extension() {
 			Some(ext) => format!(".{}", ext.to_str().unwrap_or("")),
@@ -80,12 +83,17 @@ impl Arguments {
 		let mut test_expected_file = path.to_path_buf();
 		test_expected_file.pop();
 		test_expected_file.push(format!("{}.expected{}", file_stem, extension));
-		let test_expected_fullpath = test_expected_file.display().to_string();
+		let test_expected_fullpath = normalize_path(&test_expected_file);
+
+		test_expected_file.pop();
+		test_expected_file.push(format!("{}.expected.", file_stem));
+		let test_platform_expected_fullpath = (normalize_path(&test_expected_file), extension);
 
 		Some(Variables {
 			test_name,
 			test_full_path,
 			test_expected_fullpath,
+			test_platform_expected_fullpath,
 		})
 	}
 
@@ -98,6 +106,7 @@ impl Arguments {
 				test_name,
 				test_full_path,
 				test_expected_fullpath,
+				test_platform_expected_fullpath: (platform_prefix, platform_suffix),
 			} = Arguments::get_variables(&file).ok_or("Cannot generate variables for this file")?;
 
 			let span = self.pattern.lit.span();
@@ -107,7 +116,13 @@ impl Arguments {
 				#[test]
 				pub fn #test_name () {
 					let test_file = #test_full_path;
-					let test_expected_file = #test_expected_fullpath;
+					let test_platform_expected_file =
+						[#platform_prefix, std::env::consts::OS, #platform_suffix].concat();
+					let test_expected_file = if std::path::Path::new(&test_platform_expected_file).is_file() {
+						test_platform_expected_file.as_str()
+					} else {
+						#test_expected_fullpath
+					};
 					#f(test_file, test_expected_file);
 				}
 			});
@@ -117,6 +132,16 @@ impl Arguments {
 	}
 }
 
+/// Uses `/` as path separator on all platforms so that paths recorded in test outputs are the same everywhere
+fn normalize_path(path: &Path) -> String {
+	let path = path.display().to_string();
+	if cfg!(windows) {
+		path.replace('\\', "/")
+	} else {
+		path
+	}
+}
+
 impl syn::parse::Parse for Arguments {
 	fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
 		let path: syn::ExprLit = input.parse()?;
@@ -158,6 +158,32 @@ pub fn generate_nodes(ast: &AstSrc) -> Result<String> {
 		})
 		.unzip();
 
+	// the names of the slots of a node are the names of its accessors, in the order of the grammar
+	let slot_names: Vec<_> = filtered_nodes
+		.iter()
+		.filter(|node| {
+			!node.fields.is_empty()
+				&& !node.fields.iter().any(
+					|field| matches!(field, Field::Node { ty, .. } if ty.as_str() == BUILT_IN_TYPE),
+				)
+		})
+		.map(|node| {
+			let node_kind = format_ident!("{}", to_upper_snake_case(node.name.as_str()));
+			let names = node.fields.iter().map(|field| match field {
+				Field::Token {
+					name,
+					kind: TokenKind::Many(_),
+					..
+				} => name.clone(),
+				_ => field.method_name().to_string(),
+			});
+
+			quote! {
+				#node_kind => &[#(#names),*],
+			}
+		})
+		.collect();
+
 	// it maps enum name A and its corresponding variants
 	let name_to_variants: HashMap<_, _> = ast
 		.enums
@@ -200,6 +226,11 @@ pub fn generate_nodes(ast: &AstSrc) -> Result<String> {
 				.map(|var| format_ident!("{}", var))
 				.collect();
 
+			let nested_variants: Vec<_> = variant_of_variants
+				.iter()
+				.map(|var| format_ident!("{}", var))
+				.collect();
+
 			let name = format_ident!("{}", en.name);
 			let kinds: Vec<_> = variants
 				.iter()
@@ -334,6 +365,14 @@ pub fn generate_nodes(ast: &AstSrc) -> Result<String> {
 					}
 					)*
 
+					#(
+					impl From<#nested_variants> for #name {
+						fn from(node: #nested_variants) -> #name {
+							#name::#nested_variants(node)
+						}
+					}
+					)*
+
 					impl AstNode for #name {
 						fn can_cast(kind: SyntaxKind) -> bool {
 							#can_cast_fn
@@ -362,6 +401,7 @@ pub fn generate_nodes(ast: &AstSrc) -> Result<String> {
 	let node_names = filtered_nodes.iter().map(|it| &it.name);
 
 	let display_impls = enum_names
+		.clone()
 		.chain(node_names.clone())
 		.map(|it| format_ident!("{}", it))
 		.map(|name| {
@@ -374,6 +414,20 @@ pub fn generate_nodes(ast: &AstSrc) -> Result<String> {
 			}
 		});
 
+	// lets the nodes and unions be used wherever an untyped node is expected
+	let into_syntax_impls = enum_names
+		.chain(node_names)
+		.map(|it| format_ident!("{}", it))
+		.map(|name| {
+			quote! {
+				impl From<#name> for SyntaxNode {
+					fn from(node: #name) -> SyntaxNode {
+						node.syntax().clone()
+					}
+				}
+			}
+		});
+
 	let ast = quote! {
 	#![allow(clippy::enum_variant_names)]
 	// sometimes we generate comparison of simple tokens
@@ -392,6 +446,16 @@ pub fn generate_nodes(ast: &AstSrc) -> Result<String> {
 		#(#node_boilerplate_impls)*
 		#(#enum_boilerplate_impls)*
 		#(#display_impls)*
+		#(#into_syntax_impls)*
+
+		/// The name of the slot `index` of the nodes of the kind `kind`, see [rome_rowan::Language::slot_name]
+		pub(crate) fn slot_name(kind: SyntaxKind, index: usize) -> Option<&'static str> {
+			let names: &[&str] = match kind {
+				#(#slot_names)*
+				_ => return None,
+			};
+			names.get(index).copied()
+		}
 	};
 
 	let ast = ast
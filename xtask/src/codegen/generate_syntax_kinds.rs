@@ -26,6 +26,8 @@ pub fn generate_syntax_kinds(grammar: KindsSrc) -> Result<String> {
 	});
 	let punctuation_strings = punctuation_values.clone().map(|name| name.to_string());
 
+	let punctuation_texts = grammar.punct.iter().map(|(token, _name)| *token);
+
 	let punctuation = grammar
 		.punct
 		.iter()
@@ -145,6 +147,10 @@ pub fn generate_syntax_kinds(grammar: KindsSrc) -> Result<String> {
 				};
 				Some(tok)
 			}
+
+			/// The text of every punctuation token. The lexer picks the longest of them, which
+			/// is why two punctuation tokens must be separated if their texts form another one.
+			pub const PUNCTUATORS: &'static [&'static str] = &[#(#punctuation_texts),*];
 		}
 
 		/// Utility macro for creating a SyntaxKind through simple macro syntax
@@ -128,6 +128,8 @@ pub const KINDS_SRC: KindsSrc = KindsSrc {
 		"unique",
 		"declare",
 		"abstract",
+		"accessor",
+		"override",
 		"static",
 		"async",
 		"type",
@@ -164,6 +166,7 @@ pub const KINDS_SRC: KindsSrc = KindsSrc {
 		"ERROR_TOKEN",
 		"IDENT",
 		"WHITESPACE",
+		"NEWLINE",
 		"COMMENT",
 		"JS_SHEBANG",
 	],
@@ -267,11 +270,15 @@ pub const KINDS_SRC: KindsSrc = KindsSrc {
 		"JS_CONSTRUCTOR_PARAMETER_LIST",
 		"JS_CONSTRUCTOR_PARAMETER",
 		"JS_PROPERTY_CLASS_MEMBER",
+		"JS_ACCESSOR_PROPERTY_CLASS_MEMBER",
 		"JS_METHOD_CLASS_MEMBER",
 		"JS_GETTER_CLASS_MEMBER",
 		"JS_SETTER_CLASS_MEMBER",
 		"JS_EMPTY_CLASS_MEMBER",
+		"JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER",
 		"IMPORT_DECL",
+		"JS_IMPORT_ASSERTION",
+		"JS_IMPORT_ASSERTION_ENTRY",
 		"EXPORT_DECL",
 		"EXPORT_NAMED",
 		"EXPORT_DEFAULT_DECL",
@@ -306,7 +313,9 @@ pub const KINDS_SRC: KindsSrc = KindsSrc {
 		"TS_LITERAL",
 		"TS_PREDICATE",
 		"TS_TUPLE",
-		"TS_TUPLE_ELEMENT",
+		"TS_NAMED_TUPLE_TYPE_ELEMENT",
+		"TS_REST_TUPLE_TYPE_ELEMENT",
+		"TS_OPTIONAL_TUPLE_TYPE_ELEMENT",
 		"TS_PAREN",
 		"TS_TYPE_REF",
 		"TS_QUALIFIED_PATH",
@@ -315,6 +324,7 @@ pub const KINDS_SRC: KindsSrc = KindsSrc {
 		"TS_TEMPLATE_ELEMENT",
 		"TS_MAPPED_TYPE",
 		"TS_MAPPED_TYPE_PARAM",
+		"TS_MAPPED_TYPE_AS_CLAUSE",
 		"TS_MAPPED_TYPE_READONLY",
 		"TS_TYPE_QUERY",
 		"TS_TYPE_QUERY_EXPR",
@@ -337,6 +347,7 @@ pub const KINDS_SRC: KindsSrc = KindsSrc {
 		"TS_NON_NULL",
 		"TS_ASSERTION",
 		"TS_CONST_ASSERTION",
+		"TS_INSTANTIATION_EXPR",
 		"TS_ENUM",
 		"TS_ENUM_MEMBER",
 		"TS_TYPE_ALIAS_DECL",
@@ -359,6 +370,7 @@ pub const KINDS_SRC: KindsSrc = KindsSrc {
 		"TS_EXPORT_ASSIGNMENT",
 		"TS_NAMESPACE_EXPORT_DECL",
 		"TS_DECORATOR",
+		"TS_DECORATED_PARAMETER",
 		"DEFAULT_CASE",
 		"TS_INFER",
 		"NULL",